@@ -1,10 +1,34 @@
-use crate::SYNC_TIMEOUT;
+use crate::{
+    PAGE_SIZE,
+    SYNC_TIMEOUT,
+};
+use anyhow::{
+    anyhow,
+    Context,
+};
+use fuel_core_client::client::FuelClient;
 use fuel_core_types::fuel_vm::SecretKey;
 use serde::{
     Deserialize,
     Serialize,
 };
-use std::time::Duration;
+use std::{
+    env,
+    fs,
+    path::Path,
+    time::Duration,
+};
+
+/// Overrides [`SuiteConfig::endpoint`].
+pub const ENDPOINT_ENV_KEY: &str = "FUEL_ENDPOINT";
+/// Overrides the secret of the wallet A.
+pub const WALLET_A_SECRET_ENV_KEY: &str = "FUEL_WALLET_A_SECRET";
+/// Overrides the endpoint of the wallet A.
+pub const WALLET_A_ENDPOINT_ENV_KEY: &str = "FUEL_WALLET_A_ENDPOINT";
+/// Overrides the secret of the wallet B.
+pub const WALLET_B_SECRET_ENV_KEY: &str = "FUEL_WALLET_B_SECRET";
+/// Overrides the endpoint of the wallet B.
+pub const WALLET_B_ENDPOINT_ENV_KEY: &str = "FUEL_WALLET_B_ENDPOINT";
 
 #[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 pub struct SuiteConfig {
@@ -16,6 +40,10 @@ pub struct SuiteConfig {
     pub wallet_sync_timeout: Duration,
     /// Enable slower but more stressful tests. Should be used in full E2E tests but not in CI.
     pub full_test: bool,
+    /// The number of results requested per page by the paginated queries of the wallets.
+    /// Default is [`PAGE_SIZE`](crate::PAGE_SIZE)
+    #[serde(default = "default_page_size")]
+    pub page_size: usize,
     /// Wallet A must contain pre-existing funds
     pub wallet_a: ClientConfig,
     pub wallet_b: ClientConfig,
@@ -25,6 +53,86 @@ impl SuiteConfig {
     pub fn sync_timeout(&self) -> Duration {
         self.wallet_sync_timeout
     }
+
+    /// Loads the config from the file and overrides its values with the environment
+    /// variables. The environment variables take precedence over the file.
+    pub fn from_env_and_file(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let file = fs::read_to_string(path)
+            .with_context(|| format!("failed to read config file {}", path.display()))?;
+        let value: toml::Value = toml::from_str(&file)
+            .with_context(|| format!("failed to parse config file {}", path.display()))?;
+        Self::from_value_and_env(value, |key| env::var(key).ok())
+    }
+
+    /// Overrides the default config with the environment variables.
+    pub fn from_env() -> anyhow::Result<Self> {
+        let value = toml::Value::try_from(Self::default())?;
+        Self::from_value_and_env(value, |key| env::var(key).ok())
+    }
+
+    fn from_value_and_env(
+        mut value: toml::Value,
+        var: impl Fn(&str) -> Option<String>,
+    ) -> anyhow::Result<Self> {
+        let overrides = [
+            (None, "endpoint", ENDPOINT_ENV_KEY),
+            (Some("wallet_a"), "secret", WALLET_A_SECRET_ENV_KEY),
+            (Some("wallet_a"), "endpoint", WALLET_A_ENDPOINT_ENV_KEY),
+            (Some("wallet_b"), "secret", WALLET_B_SECRET_ENV_KEY),
+            (Some("wallet_b"), "endpoint", WALLET_B_ENDPOINT_ENV_KEY),
+        ];
+        for (table, field, key) in overrides {
+            let env_value = match var(key) {
+                Some(env_value) => env_value,
+                None => continue,
+            };
+            let root = value
+                .as_table_mut()
+                .ok_or_else(|| anyhow!("the config should be a table"))?;
+            let table = match table {
+                Some(name) => root
+                    .entry(name)
+                    .or_insert_with(|| toml::Value::Table(Default::default()))
+                    .as_table_mut()
+                    .ok_or_else(|| anyhow!("`{name}` should be a table"))?,
+                None => root,
+            };
+            table.insert(field.to_string(), toml::Value::String(env_value));
+        }
+
+        for (wallet, key) in [
+            ("wallet_a", WALLET_A_SECRET_ENV_KEY),
+            ("wallet_b", WALLET_B_SECRET_ENV_KEY),
+        ] {
+            if value.get(wallet).and_then(|w| w.get("secret")).is_none() {
+                return Err(anyhow!(
+                    "`{wallet}` doesn't have a secret, set it in the config file or via `{key}`"
+                ))
+            }
+        }
+
+        let config: Self = value.try_into().context("invalid suite config")?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Checks that each wallet has a resolvable endpoint.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        for (wallet, config) in
+            [("wallet_a", &self.wallet_a), ("wallet_b", &self.wallet_b)]
+        {
+            let endpoint = config.endpoint.as_ref().unwrap_or(&self.endpoint);
+            if endpoint.is_empty() {
+                return Err(anyhow!(
+                    "`{wallet}` doesn't have an endpoint, set it in the config file or via `{ENDPOINT_ENV_KEY}`"
+                ))
+            }
+            FuelClient::new(endpoint)
+                .with_context(|| format!("`{wallet}` has invalid endpoint"))?;
+        }
+        Ok(())
+    }
 }
 
 impl Default for SuiteConfig {
@@ -33,6 +141,7 @@ impl Default for SuiteConfig {
             endpoint: "http://localhost:4000".to_string(),
             wallet_sync_timeout: SYNC_TIMEOUT,
             full_test: false,
+            page_size: PAGE_SIZE,
             wallet_a: ClientConfig {
                 endpoint: None,
                 secret:
@@ -51,6 +160,10 @@ impl Default for SuiteConfig {
     }
 }
 
+fn default_page_size() -> usize {
+    PAGE_SIZE
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 pub struct ClientConfig {
     // overrides the default endpoint for the suite
@@ -70,6 +183,59 @@ mod tests {
         insta::assert_snapshot!(serialized);
     }
 
+    #[test]
+    fn env_overrides_file_values() {
+        let file = toml::Value::try_from(SuiteConfig::default()).unwrap();
+        let secret = "0000000000000000000000000000000000000000000000000000000000000001";
+        let config = SuiteConfig::from_value_and_env(file, |key| match key {
+            ENDPOINT_ENV_KEY => Some("http://127.0.0.1:4001".to_string()),
+            WALLET_B_SECRET_ENV_KEY => Some(secret.to_string()),
+            _ => None,
+        })
+        .unwrap();
+
+        let default = SuiteConfig::default();
+        assert_eq!(config.endpoint, "http://127.0.0.1:4001");
+        assert_eq!(config.wallet_a, default.wallet_a);
+        assert_eq!(config.wallet_b.secret, secret.parse().unwrap());
+    }
+
+    #[test]
+    fn missing_secret_is_an_error() {
+        let file: toml::Value = toml::from_str(
+            r#"
+            endpoint = "http://localhost:4000"
+            wallet_sync_timeout = "10s"
+            full_test = false
+
+            [wallet_a]
+            secret = "de97d8624a438121b86a1956544bd72ed68cd69f2c99555b08b1e8c51ffd511c"
+            "#,
+        )
+        .unwrap();
+
+        let err = SuiteConfig::from_value_and_env(file, |_| None).unwrap_err();
+        assert!(err.to_string().contains(WALLET_B_SECRET_ENV_KEY));
+    }
+
+    #[test]
+    fn missing_page_size_is_defaulted() {
+        let mut file = toml::Value::try_from(SuiteConfig::default()).unwrap();
+        file.as_table_mut().unwrap().remove("page_size");
+
+        let config = SuiteConfig::from_value_and_env(file, |_| None).unwrap();
+        assert_eq!(config.page_size, PAGE_SIZE);
+    }
+
+    #[test]
+    fn missing_endpoint_is_an_error() {
+        let config = SuiteConfig {
+            endpoint: "".to_string(),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn can_roundtrip_config() {
         let config = SuiteConfig::default();
@@ -16,6 +16,7 @@ use std::{
 
 pub const CONFIG_FILE_KEY: &str = "FUEL_CORE_E2E_CONFIG";
 pub const SYNC_TIMEOUT: Duration = Duration::from_secs(10);
+pub const PAGE_SIZE: usize = 100;
 
 pub mod config;
 pub mod test_context;
@@ -38,7 +39,7 @@ pub fn main_body(config: SuiteConfig, mut args: Arguments) {
             "can transfer from alice to bob",
             with_cloned(&config, |config| {
                 async_execute(async {
-                    let ctx = TestContext::new(config).await;
+                    let ctx = TestContext::new(config).await?;
                     tests::transfers::basic_transfer(&ctx).await
                 })
             }),
@@ -47,7 +48,7 @@ pub fn main_body(config: SuiteConfig, mut args: Arguments) {
             "can transfer from alice to bob and back",
             with_cloned(&config, |config| {
                 async_execute(async {
-                    let ctx = TestContext::new(config).await;
+                    let ctx = TestContext::new(config).await?;
                     tests::transfers::transfer_back(&ctx).await
                 })
             }),
@@ -56,7 +57,7 @@ pub fn main_body(config: SuiteConfig, mut args: Arguments) {
             "can execute script and get receipts",
             with_cloned(&config, |config| {
                 async_execute(async {
-                    let ctx = TestContext::new(config).await;
+                    let ctx = TestContext::new(config).await?;
                     tests::transfers::transfer_back(&ctx).await
                 })
             }),
@@ -65,7 +66,7 @@ pub fn main_body(config: SuiteConfig, mut args: Arguments) {
             "can dry run transfer script and get receipts",
             with_cloned(&config, |config| {
                 async_execute(async {
-                    let ctx = TestContext::new(config).await;
+                    let ctx = TestContext::new(config).await?;
                     tests::script::dry_run(&ctx).await
                 })?;
                 Ok(())
@@ -75,7 +76,7 @@ pub fn main_body(config: SuiteConfig, mut args: Arguments) {
             "dry run script that touches the contract with large state",
             with_cloned(&config, |config| {
                 async_execute(async {
-                    let ctx = TestContext::new(config).await;
+                    let ctx = TestContext::new(config).await?;
                     tests::script::run_contract_large_state(&ctx).await
                 })?;
                 Ok(())
@@ -85,7 +86,7 @@ pub fn main_body(config: SuiteConfig, mut args: Arguments) {
             "dry run transaction from `non_specific_tx.raw` file",
             with_cloned(&config, |config| {
                 async_execute(async {
-                    let ctx = TestContext::new(config).await;
+                    let ctx = TestContext::new(config).await?;
                     tests::script::non_specific_transaction(&ctx).await
                 })?;
                 Ok(())
@@ -95,7 +96,7 @@ pub fn main_body(config: SuiteConfig, mut args: Arguments) {
             "can deploy a large contract",
             with_cloned(&config, |config| {
                 async_execute(async {
-                    let ctx = TestContext::new(config).await;
+                    let ctx = TestContext::new(config).await?;
                     tests::transfers::transfer_back(&ctx).await
                 })
             }),
@@ -106,10 +107,11 @@ pub fn main_body(config: SuiteConfig, mut args: Arguments) {
 }
 
 pub fn load_config_env() -> SuiteConfig {
-    // load from env var
+    // load from env var and override values from the env
     env::var_os(CONFIG_FILE_KEY)
-        .map(|path| load_config(path.to_string_lossy().to_string()))
-        .unwrap_or_default()
+        .map(SuiteConfig::from_env_and_file)
+        .unwrap_or_else(SuiteConfig::from_env)
+        .expect("invalid suite config")
 }
 
 pub fn load_config(path: String) -> SuiteConfig {
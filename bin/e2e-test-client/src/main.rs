@@ -2,7 +2,15 @@
 
 pub use fuel_core_e2e_client::*;
 use libtest_mimic::Arguments;
+use tracing_subscriber::EnvFilter;
 
 pub fn main() {
+    // the logs of the wallets are enabled via `RUST_LOG`
+    if std::env::var_os("RUST_LOG").is_some() {
+        tracing_subscriber::fmt()
+            .with_env_filter(EnvFilter::from_default_env())
+            .with_writer(std::io::stderr)
+            .init();
+    }
     main_body(load_config_env(), Arguments::from_args())
 }
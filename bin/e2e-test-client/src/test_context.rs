@@ -4,14 +4,29 @@ use anyhow::{
     anyhow,
     Context,
 };
-use fuel_core_chain_config::ContractConfig;
+#[cfg(feature = "local-node")]
+use fuel_core::service::{
+    Config as NodeConfig,
+    FuelService,
+};
+#[cfg(feature = "local-node")]
+use fuel_core_chain_config::TESTNET_INITIAL_BALANCE;
+use fuel_core_chain_config::{
+    ChainConfig,
+    CoinConfig,
+    ContractConfig,
+    StateConfig,
+};
 use fuel_core_client::client::{
     pagination::{
         PageDirection,
         PaginationRequest,
     },
     types::{
+        Coin,
         CoinType,
+        FeeConfidence,
+        Receipts,
         TransactionStatus,
     },
     FuelClient,
@@ -19,11 +34,25 @@ use fuel_core_client::client::{
 use fuel_core_types::{
     fuel_crypto::PublicKey,
     fuel_tx::{
+        field::{
+            GasLimit,
+            GasPrice,
+            Inputs,
+            Outputs,
+            Script as ScriptField,
+            ScriptData,
+        },
+        input::coin::CoinSigned,
+        Buildable,
         ConsensusParameters,
         Contract,
+        Create,
+        FeeParameters,
         Finalizable,
         Input,
         Output,
+        Receipt,
+        Script,
         StorageSlot,
         Transaction,
         TransactionBuilder,
@@ -35,32 +64,154 @@ use fuel_core_types::{
         bytes::SizedBytes,
         Address,
         AssetId,
+        ContractId,
     },
     fuel_vm::SecretKey,
 };
+use parking_lot::Mutex;
+use std::{
+    collections::{
+        BTreeSet,
+        HashSet,
+    },
+    sync::Arc,
+    time::Duration,
+};
+use tokio::time::Instant;
 
-use crate::config::{
-    ClientConfig,
-    SuiteConfig,
+use crate::{
+    config::{
+        ClientConfig,
+        SuiteConfig,
+    },
+    PAGE_SIZE,
+};
+
+mod multisig;
+
+pub use multisig::{
+    MultisigWallet,
+    MAX_OWNERS,
 };
 
 // The base amount needed to cover the cost of a simple transaction
 pub const BASE_AMOUNT: u64 = 10_000;
 
+// The error returned by the node when the coins don't fit into the maximum number of inputs
+const MAX_COINS_REACHED: &str = "max number of coins is reached";
+
 pub struct TestContext {
     pub alice: Wallet,
     pub bob: Wallet,
     pub config: SuiteConfig,
+    /// The in-process node used by the context, if any. The node is stopped on drop.
+    #[cfg(feature = "local-node")]
+    pub local_node: Option<FuelService>,
 }
 
 impl TestContext {
-    pub async fn new(config: SuiteConfig) -> Self {
+    /// Connects to the nodes from the `config`. If the config doesn't contain any
+    /// endpoint, starts an in-process node via [`TestContext::new_with_local_node`].
+    pub async fn new(config: SuiteConfig) -> anyhow::Result<Self> {
+        let has_endpoint = !config.endpoint.is_empty()
+            || config.wallet_a.endpoint.is_some()
+            || config.wallet_b.endpoint.is_some();
+        if !has_endpoint {
+            #[cfg(feature = "local-node")]
+            return Self::new_with_local_node(config).await;
+            #[cfg(not(feature = "local-node"))]
+            return Err(anyhow!(
+                "the endpoint is not specified, enable the `local-node` feature to use an in-process node"
+            ))
+        }
+
+        Self::connect(config).await
+    }
+
+    async fn connect(config: SuiteConfig) -> anyhow::Result<Self> {
         let alice_client = Self::new_client(config.endpoint.clone(), &config.wallet_a);
         let bob_client = Self::new_client(config.endpoint.clone(), &config.wallet_b);
-        Self {
-            alice: Wallet::new(config.wallet_a.secret, alice_client).await,
-            bob: Wallet::new(config.wallet_b.secret, bob_client).await,
+        // wait for the nodes to be ready to give a clean error instead of a panic
+        for client in [&alice_client, &bob_client] {
+            client
+                .await_healthy(config.sync_timeout())
+                .await
+                .context("node not ready")?;
+        }
+        Ok(Self {
+            alice: Wallet::new(config.wallet_a.secret, alice_client)
+                .await
+                .with_page_size(config.page_size),
+            bob: Wallet::new(config.wallet_b.secret, bob_client)
+                .await
+                .with_page_size(config.page_size),
             config,
+            #[cfg(feature = "local-node")]
+            local_node: None,
+        })
+    }
+
+    /// Starts an in-process node with funded genesis for Alice and Bob and connects
+    /// both wallets to it. The bound address of the node is stored in the
+    /// [`SuiteConfig::endpoint`]. The node is stopped when the context is dropped.
+    #[cfg(feature = "local-node")]
+    pub async fn new_with_local_node(mut config: SuiteConfig) -> anyhow::Result<Self> {
+        let coins = [&config.wallet_a, &config.wallet_b]
+            .into_iter()
+            .map(|wallet| {
+                let public_key: PublicKey = (&wallet.secret).into();
+                (
+                    Input::owner(&public_key),
+                    AssetId::BASE,
+                    TESTNET_INITIAL_BALANCE,
+                )
+            })
+            .collect();
+        let mut chain_config = Self::funded_chain_config(coins);
+        // The `run_contract_large_state` test creates a contract with a huge state
+        chain_config
+            .consensus_parameters
+            .contract_params
+            .max_storage_slots = 1 << 17;
+
+        let mut node_config = NodeConfig::local_node();
+        node_config.chain_conf = chain_config.clone();
+        node_config.txpool.chain_config = chain_config;
+        let node = FuelService::new_node(node_config)
+            .await
+            .context("failed to start the local node")?;
+
+        config.endpoint = node.bound_address.to_string();
+        config.wallet_a.endpoint = None;
+        config.wallet_b.endpoint = None;
+        let mut context = Self::connect(config).await?;
+        context.local_node = Some(node);
+        Ok(context)
+    }
+
+    /// Creates the local testnet chain config where the genesis contains only the
+    /// `coins`. It allows starting a node with a known state.
+    pub fn funded_chain_config(coins: Vec<(Address, AssetId, u64)>) -> ChainConfig {
+        let coins = coins
+            .into_iter()
+            .map(|(owner, asset_id, amount)| CoinConfig {
+                tx_id: None,
+                output_index: None,
+                tx_pointer_block_height: None,
+                tx_pointer_tx_idx: None,
+                maturity: None,
+                owner,
+                amount,
+                asset_id,
+            })
+            .collect();
+
+        ChainConfig {
+            initial_state: Some(StateConfig {
+                coins: Some(coins),
+                ..StateConfig::default()
+            }),
+            ..ChainConfig::local_testnet()
         }
     }
 
@@ -69,12 +220,18 @@ impl TestContext {
     }
 }
 
+/// The wallet reserves the coins selected for its transactions until they are
+/// committed or rejected, so concurrent transfers don't spend the same coins.
+/// A cloned wallet shares the same set of reserved coins with the original.
 #[derive(Debug, Clone)]
 pub struct Wallet {
     pub secret: SecretKey,
     pub address: Address,
     pub client: FuelClient,
     pub consensus_params: ConsensusParameters,
+    /// The number of results requested per page by the paginated queries.
+    pub page_size: usize,
+    reserved_coins: CoinReservations,
 }
 
 impl Wallet {
@@ -94,9 +251,17 @@ impl Wallet {
             address,
             client,
             consensus_params,
+            page_size: PAGE_SIZE,
+            reserved_coins: Default::default(),
         }
     }
 
+    /// Sets the number of results requested per page by the paginated queries.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
     /// returns the balance associated with a wallet
     pub async fn balance(&self, asset_id: Option<AssetId>) -> anyhow::Result<u64> {
         self.client
@@ -105,36 +270,135 @@ impl Wallet {
             .context("failed to retrieve balance")
     }
 
+    /// Polls the balance of the wallet until it reaches the `target` or the
+    /// `timeout` elapses. Returns the last seen balance.
+    pub async fn await_balance_at_least(
+        &self,
+        asset_id: Option<AssetId>,
+        target: u64,
+        timeout: Duration,
+    ) -> anyhow::Result<u64> {
+        const MAX_BACKOFF: Duration = Duration::from_secs(1);
+
+        let deadline = Instant::now() + timeout;
+        let mut backoff = Duration::from_millis(50);
+        loop {
+            let balance = self.balance(asset_id).await?;
+            if balance >= target || Instant::now() >= deadline {
+                return Ok(balance)
+            }
+            tokio::time::sleep(backoff.min(deadline - Instant::now())).await;
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
+    }
+
     /// Checks if wallet has a coin (regardless of spent status)
     pub async fn owns_coin(&self, utxo_id: UtxoId) -> anyhow::Result<bool> {
-        let mut first_page = true;
-        let mut results = vec![];
+        let mut cursor = None;
 
-        while first_page || !results.is_empty() {
-            first_page = false;
-            results = self
+        loop {
+            let page = self
                 .client
                 .coins(
                     &self.address,
                     None,
                     PaginationRequest {
-                        cursor: None,
-                        results: 100,
+                        cursor,
+                        results: self.page_size,
                         direction: PageDirection::Forward,
                     },
                 )
-                .await?
-                .results;
+                .await?;
             // check if page has the utxos we're looking for
-            if results.iter().any(|coin| coin.utxo_id == utxo_id) {
+            if page.results.iter().any(|coin| coin.utxo_id == utxo_id) {
                 return Ok(true)
             }
+            if !page.has_next_page {
+                return Ok(false)
+            }
+            cursor = page.cursor;
         }
+    }
 
-        Ok(false)
+    /// Selects coins to spend the `(asset_id, amount)` pairs and reserves them until
+    /// [`Wallet::release_coins`] is called.
+    async fn select_coins(
+        &self,
+        spend: &[(AssetId, u64)],
+    ) -> anyhow::Result<Vec<CoinType>> {
+        self.reserved_coins
+            .select(&self.client, &self.address, spend)
+            .await
+            .map_err(|e| {
+                if e.to_string().contains(MAX_COINS_REACHED) {
+                    e.context(
+                        "the balance is split into too many small coins, \
+                        consolidate them with `Wallet::consolidate` first",
+                    )
+                } else {
+                    e
+                }
+            })
     }
 
-    /// Creates the transfer transaction.
+    /// Selects the coins of the `asset_id` to cover the `total_needed` and the coins
+    /// of the base asset to cover the fee. The fee depends on the inputs, so
+    /// `fee_for(coins)` returns the fee of the transaction spending the `coins`, and
+    /// the coins are selected again until they cover the fee of the transaction
+    /// spending them.
+    async fn select_coins_with_fee(
+        &self,
+        asset_id: AssetId,
+        total_needed: u64,
+        fee_for: impl Fn(&[CoinType]) -> anyhow::Result<u64>,
+    ) -> anyhow::Result<Vec<CoinType>> {
+        let mut fee = fee_for(&[])?;
+        loop {
+            let coins = self
+                .select_coins(&spend_query(asset_id, total_needed, fee))
+                .await?;
+            // the coins are released if they are selected again
+            let reserved = self.reserved_coins.guard(coin_utxo_ids(&coins));
+            let required = fee_for(&coins)?;
+            if required <= fee {
+                reserved.keep();
+                return Ok(coins)
+            }
+            fee = required;
+        }
+    }
+
+    /// Adds the `coins` of the wallet as the inputs of the `tx`.
+    fn add_coin_inputs<Tx: Buildable>(
+        &self,
+        mut tx: TransactionBuilder<Tx>,
+        coins: &[CoinType],
+    ) -> TransactionBuilder<Tx> {
+        for coin in coins {
+            if let CoinType::Coin(coin) = coin {
+                tx.add_unsigned_coin_input(
+                    self.secret,
+                    coin.utxo_id,
+                    coin.amount,
+                    coin.asset_id,
+                    Default::default(),
+                    coin.maturity.into(),
+                );
+            }
+        }
+        tx
+    }
+
+    /// Releases the coins reserved by the transaction. It should be called
+    /// when the transaction is committed or rejected.
+    pub fn release_coins(&self, tx: &Transaction) {
+        self.reserved_coins.release(tx)
+    }
+
+    /// Creates the transfer transaction. The coins used by the transaction are
+    /// reserved until [`Wallet::release_coins`] is called, or released right away
+    /// if the transaction can't be built.
+    #[tracing::instrument(skip(self))]
     pub async fn transfer_tx(
         &self,
         destination: Address,
@@ -142,18 +406,180 @@ impl Wallet {
         asset_id: Option<AssetId>,
     ) -> anyhow::Result<Transaction> {
         let asset_id = asset_id.unwrap_or_default();
-        let total_amount = transfer_amount + BASE_AMOUNT;
-        // select coins
-        let coins = &self
-            .client
-            .coins_to_spend(&self.address, vec![(asset_id, total_amount, None)], None)
-            .await?[0];
+        let outputs = vec![Output::coin(destination, transfer_amount, asset_id)];
+        self.build_tx(outputs, asset_id, transfer_amount).await
+    }
 
-        // build transaction
+    /// Creates the transaction with the `outputs` spending the coins of the wallet.
+    /// Coins of the `inputs_asset` are selected to cover the `total_needed`, and coins
+    /// of the base asset are selected to cover the fee of the whole transaction
+    /// estimated by [`Wallet::base_amount_for`]. A change output is appended for each
+    /// asset of the selected coins.
+    ///
+    /// The coins used by the transaction are reserved until
+    /// [`Wallet::release_coins`] is called, or released right away if the
+    /// transaction can't be built.
+    #[tracing::instrument(skip(self, outputs), fields(tx_id = tracing::field::Empty))]
+    pub async fn build_tx(
+        &self,
+        outputs: Vec<Output>,
+        inputs_asset: AssetId,
+        total_needed: u64,
+    ) -> anyhow::Result<Transaction> {
         let mut tx = TransactionBuilder::script(Default::default(), Default::default());
-        tx.gas_price(1);
+        tx.gas_price(recommended_gas_price(&self.client).await?);
+        for output in outputs {
+            tx.add_output(output);
+        }
         tx.gas_limit(BASE_AMOUNT);
+        let spending = |mut tx: TransactionBuilder<Script>, coins: &[CoinType]| {
+            for output in change_outputs(self.address, coins) {
+                tx.add_output(output);
+            }
+            self.add_coin_inputs(tx, coins)
+        };
+
+        // select coins
+        let coins = self
+            .select_coins_with_fee(inputs_asset, total_needed, |coins| {
+                self.base_amount_for(&spending(tx.clone(), coins))
+            })
+            .await?;
+        let reserved = self.reserved_coins.guard(coin_utxo_ids(&coins));
+        let mut tx = spending(tx, &coins);
+        tx.with_params(self.consensus_params.clone());
+
+        let tx = tx.finalize_as_transaction();
+        tracing::Span::current().record(
+            "tx_id",
+            tracing::field::display(tx.id(&self.consensus_params.chain_id)),
+        );
+        reserved.keep();
+        Ok(tx)
+    }
+
+    /// Returns the amount of the base asset required to cover the max fee of the
+    /// transaction built by the `tx`: the gas limit of the transaction and the gas
+    /// of its serialized bytes at its gas price.
+    ///
+    /// The bytecode and the storage slots of the create transaction are the part of
+    /// its size, so deployments select more coins than simple transfers. Returns an
+    /// error if the fee overflows.
+    pub fn base_amount_for<Tx>(&self, tx: &TransactionBuilder<Tx>) -> anyhow::Result<u64>
+    where
+        TransactionBuilder<Tx>: Clone + Finalizable<Tx>,
+        Tx: Into<Transaction>,
+    {
+        // the signatures are the part of the size
+        let tx: Transaction = tx.clone().finalize().into();
+        match fee_values(&tx) {
+            Some((size, gas_limit, gas_price)) => max_fee(
+                size,
+                gas_limit,
+                gas_price,
+                &self.consensus_params.fee_params,
+            ),
+            None => Ok(0),
+        }
+    }
+
+    /// Transfers coins from this wallet to another
+    #[tracing::instrument(skip(self))]
+    pub async fn transfer(
+        &self,
+        destination: Address,
+        transfer_amount: u64,
+        asset_id: Option<AssetId>,
+    ) -> anyhow::Result<TransferResult> {
+        let tx = self
+            .transfer_tx(destination, transfer_amount, asset_id)
+            .await?;
+        self.submit(tx).await
+    }
+
+    /// Transfers coins of the `asset_id` from this wallet to several destinations
+    /// in a single transaction. The outputs follow the order of the `transfers`.
+    #[tracing::instrument(skip(self, transfers))]
+    pub async fn transfer_multiple(
+        &self,
+        transfers: &[(Address, u64)],
+        asset_id: Option<AssetId>,
+    ) -> anyhow::Result<TransferResult> {
+        let asset_id = asset_id.unwrap_or_default();
+        let mut total_needed = 0u64;
+        for (_, amount) in transfers {
+            total_needed = total_needed
+                .checked_add(*amount)
+                .ok_or_else(|| anyhow!("the total amount of the transfers overflows"))?;
+        }
+        let outputs = transfers
+            .iter()
+            .map(|(destination, amount)| Output::coin(*destination, *amount, asset_id))
+            .collect();
+        let tx = self.build_tx(outputs, asset_id, total_needed).await?;
+        self.submit(tx).await
+    }
 
+    /// Funds each of the `recipients` with the `amount_each` of the `asset_id` in
+    /// a single transaction and waits for its commit.
+    ///
+    /// Returns the coin created for each recipient in the order of the `recipients`.
+    pub async fn faucet(
+        &self,
+        recipients: &[Address],
+        amount_each: u64,
+        asset_id: Option<AssetId>,
+    ) -> anyhow::Result<Vec<UtxoId>> {
+        let transfers = recipients
+            .iter()
+            .map(|recipient| (*recipient, amount_each))
+            .collect::<Vec<_>>();
+        let result = self.transfer_multiple(&transfers, asset_id).await?;
+        if !result.success {
+            return Err(anyhow!(
+                "the faucet transaction {} failed: {:?}",
+                result.tx_id,
+                result.status
+            ))
+        }
+        let utxo_ids = (0..recipients.len())
+            .map(|index| UtxoId::new(result.tx_id, index as u8))
+            .collect();
+        Ok(utxo_ids)
+    }
+
+    /// Calls the contract with the `script` and the `script_data`. The `amount` of
+    /// the `asset_id` is attached to the transaction as coin inputs, so the script
+    /// can forward it to the contract.
+    ///
+    /// The `transferred_utxo` of the result points to the contract output.
+    pub async fn call_contract(
+        &self,
+        contract_id: ContractId,
+        script: Vec<u8>,
+        script_data: Vec<u8>,
+        amount: u64,
+        asset_id: Option<AssetId>,
+    ) -> anyhow::Result<TransferResult> {
+        let asset_id = asset_id.unwrap_or_default();
+        let coins = self
+            .select_coins(&transfer_spend_query(asset_id, amount))
+            .await?;
+        // The coins are released if the transaction is not submitted
+        let _reserved = self.reserved_coins.guard(coin_utxo_ids(&coins));
+        let change_outputs = change_outputs(self.address, &coins);
+
+        let mut tx = TransactionBuilder::script(script, script_data);
+        tx.gas_price(recommended_gas_price(&self.client).await?);
+        tx.gas_limit(BASE_AMOUNT);
+        tx.add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ));
+        tx.add_output(Output::contract(0, Default::default(), Default::default()));
         for coin in coins {
             if let CoinType::Coin(coin) = coin {
                 tx.add_unsigned_coin_input(
@@ -166,55 +592,288 @@ impl Wallet {
                 );
             }
         }
-        tx.add_output(Output::Coin {
-            to: destination,
-            amount: transfer_amount,
-            asset_id,
-        });
-        tx.add_output(Output::Change {
-            to: self.address,
-            amount: 0,
-            asset_id,
-        });
+        for output in change_outputs {
+            tx.add_output(output);
+        }
         tx.with_params(self.consensus_params.clone());
 
-        Ok(tx.finalize_as_transaction())
+        self.submit(tx.finalize_as_transaction()).await
     }
 
-    /// Transfers coins from this wallet to another
-    pub async fn transfer(
+    /// Replaces the pending transaction of the wallet with the same transaction
+    /// paying the `new_gas_price`. The transaction pool accepts the replacement
+    /// because it spends the same coins with a higher gas price.
+    ///
+    /// Returns an error if the transaction is already committed or unknown.
+    pub async fn bump_fee(
+        &self,
+        tx_id: TxId,
+        new_gas_price: u64,
+    ) -> anyhow::Result<TransferResult> {
+        let response = self
+            .client
+            .transaction(&tx_id)
+            .await?
+            .ok_or_else(|| anyhow!("the transaction {tx_id} is unknown"))?;
+        if !matches!(response.status, TransactionStatus::Submitted { .. }) {
+            return Err(anyhow!(
+                "the transaction {tx_id} is not pending anymore: {:?}",
+                response.status
+            ))
+        }
+        let script = match response.transaction {
+            Transaction::Script(script) => script,
+            _ => return Err(anyhow!("only script transactions can be replaced")),
+        };
+        if new_gas_price <= *script.gas_price() {
+            return Err(anyhow!(
+                "the new gas price {new_gas_price} should be higher than {}",
+                script.gas_price()
+            ))
+        }
+
+        let mut tx = TransactionBuilder::script(
+            script.script().clone(),
+            script.script_data().clone(),
+        );
+        tx.gas_price(new_gas_price);
+        tx.gas_limit(*script.gas_limit());
+        for input in script.inputs() {
+            match input {
+                Input::CoinSigned(CoinSigned {
+                    utxo_id,
+                    owner,
+                    amount,
+                    asset_id,
+                    tx_pointer,
+                    maturity,
+                    ..
+                }) if *owner == self.address => {
+                    tx.add_unsigned_coin_input(
+                        self.secret,
+                        *utxo_id,
+                        *amount,
+                        *asset_id,
+                        *tx_pointer,
+                        *maturity,
+                    );
+                }
+                Input::Contract(_) => {
+                    tx.add_input(input.clone());
+                }
+                _ => {
+                    return Err(anyhow!(
+                        "the transaction {tx_id} spends inputs not owned by the wallet"
+                    ))
+                }
+            }
+        }
+        for output in script.outputs().iter().cloned() {
+            tx.add_output(output);
+        }
+        tx.with_params(self.consensus_params.clone());
+
+        self.submit(tx.finalize_as_transaction()).await
+    }
+
+    /// Sweeps the coins of the `asset_id` into a single coin via a self-transfer.
+    /// It helps when the balance is split into so many small coins that the amount
+    /// can't be selected within the maximum number of inputs of the transaction.
+    ///
+    /// One call sweeps at most `max_inputs` coins. If the asset is not the base
+    /// asset, the fee is paid by a separate base asset coin.
+    ///
+    /// The `transferred_utxo` of the result points to the consolidated coin.
+    #[tracing::instrument(skip(self))]
+    pub async fn consolidate(
         &self,
-        destination: Address,
-        transfer_amount: u64,
         asset_id: Option<AssetId>,
     ) -> anyhow::Result<TransferResult> {
-        let tx = self
-            .transfer_tx(destination, transfer_amount, asset_id)
-            .await?;
-        let tx_id = tx.id(&self.consensus_params.chain_id);
-        let status = self.client.submit_and_await_commit(&tx).await?;
+        let asset_id = asset_id.unwrap_or_default();
+        let max_inputs = self.consensus_params.tx_params.max_inputs as usize;
+        let max_coins = if asset_id == AssetId::BASE {
+            max_inputs
+        } else {
+            max_inputs - 1
+        };
+        let coins = self
+            .client
+            .coins(
+                &self.address,
+                Some(&asset_id),
+                PaginationRequest {
+                    cursor: None,
+                    results: max_coins,
+                    direction: PageDirection::Forward,
+                },
+            )
+            .await?
+            .results;
+        let current_height = self.client.chain_info().await?.latest_block.header.height;
+        let coins = coins
+            .into_iter()
+            .filter(|coin| coin.maturity <= current_height)
+            .collect();
+        let coins = self.reserved_coins.reserve(coins);
+        if coins.len() < 2 {
+            self.reserved_coins
+                .release_utxo_ids(coins.iter().map(|coin| &coin.utxo_id));
+            return Err(anyhow!(
+                "the wallet has no coins of {asset_id} to consolidate"
+            ))
+        }
 
-        // we know the transferred coin should be output 0 from above
-        let transferred_utxo = UtxoId::new(tx_id, 0);
+        let mut tx = TransactionBuilder::script(Default::default(), Default::default());
+        tx.gas_price(recommended_gas_price(&self.client).await?);
+        tx.gas_limit(BASE_AMOUNT);
+        // the consolidated coin is the first output
+        tx.add_output(Output::change(self.address, 0, asset_id));
+        if asset_id != AssetId::BASE {
+            let fee_coins = self.select_coins(&[(AssetId::BASE, BASE_AMOUNT)]).await;
+            let fee_coins = match fee_coins {
+                Ok(fee_coins) => fee_coins,
+                Err(e) => {
+                    self.reserved_coins
+                        .release_utxo_ids(coins.iter().map(|coin| &coin.utxo_id));
+                    return Err(e)
+                }
+            };
+            for output in change_outputs(self.address, &fee_coins) {
+                tx.add_output(output);
+            }
+            for coin in fee_coins {
+                if let CoinType::Coin(coin) = coin {
+                    tx.add_unsigned_coin_input(
+                        self.secret,
+                        coin.utxo_id,
+                        coin.amount,
+                        coin.asset_id,
+                        Default::default(),
+                        coin.maturity.into(),
+                    );
+                }
+            }
+        }
+        for coin in coins {
+            tx.add_unsigned_coin_input(
+                self.secret,
+                coin.utxo_id,
+                coin.amount,
+                coin.asset_id,
+                Default::default(),
+                coin.maturity.into(),
+            );
+        }
+        tx.with_params(self.consensus_params.clone());
 
-        // get status and return the utxo id of transferred coin
-        Ok(TransferResult {
-            tx_id,
-            transferred_utxo,
-            success: matches!(status, TransactionStatus::Success { .. }),
-            status,
-        })
+        self.submit(tx.finalize_as_transaction()).await
     }
 
-    pub async fn deploy_contract(&self, config: ContractConfig) -> anyhow::Result<()> {
-        let asset_id = AssetId::zeroed();
-        let total_amount = BASE_AMOUNT;
+    /// Returns the receipts of the already executed transaction of the wallet,
+    /// or `None` if the transaction is unknown or is not executed yet.
+    pub async fn receipts(&self, tx_id: &TxId) -> anyhow::Result<Option<Receipts>> {
+        let receipts = self.client.transaction_receipts(tx_id).await?;
+        Ok(receipts.map(Into::into))
+    }
+
+    async fn submit(&self, tx: Transaction) -> anyhow::Result<TransferResult> {
+        self.reserved_coins
+            .submit(&self.client, &self.consensus_params, tx)
+            .await
+    }
+
+    #[tracing::instrument(
+        skip_all,
+        fields(contract_id = %config.contract_id, tx_id = tracing::field::Empty)
+    )]
+    pub async fn deploy_contract(&self, config: ContractConfig) -> anyhow::Result<TxId> {
+        let gas_price = recommended_gas_price(&self.client).await?;
+        let tx = self.create_tx(config, gas_price);
         // select coins
-        let coins = &self
-            .client
-            .coins_to_spend(&self.address, vec![(asset_id, total_amount, None)], None)
-            .await?[0];
+        let coins = self
+            .select_coins_with_fee(AssetId::BASE, 0, |coins| {
+                self.base_amount_for(&self.add_coin_inputs(tx.clone(), coins))
+            })
+            .await?;
+        self.deploy_with_coins(tx, coins).await
+    }
 
+    /// Deploys the contracts one by one. The coins are selected only for the first
+    /// deployment, each next deployment is funded by the change of the previous one.
+    ///
+    /// Returns the ids of the contracts and of their deployments. If a deployment
+    /// fails, the [`PartialDeployment`] error contains the contracts deployed before.
+    #[tracing::instrument(skip_all, fields(contracts = configs.len()))]
+    pub async fn deploy_contracts(
+        &self,
+        configs: Vec<ContractConfig>,
+    ) -> anyhow::Result<Vec<(ContractId, TxId)>> {
+        if configs.is_empty() {
+            return Ok(vec![])
+        }
+        let gas_price = recommended_gas_price(&self.client).await?;
+        let txs = configs
+            .into_iter()
+            .map(|config| (config.contract_id, self.create_tx(config, gas_price)))
+            .collect::<Vec<_>>();
+        // each next deployment spends the single change of the previous one
+        let change = [CoinType::Coin(Coin {
+            amount: 0,
+            block_created: 0,
+            asset_id: AssetId::BASE,
+            utxo_id: Default::default(),
+            maturity: 0,
+            owner: self.address,
+        })];
+        let next_amount = txs[1..].iter().try_fold(0u64, |total, (_, tx)| {
+            let amount =
+                self.base_amount_for(&self.add_coin_inputs(tx.clone(), &change))?;
+            total
+                .checked_add(amount)
+                .ok_or_else(|| anyhow!("the fee of the deployments overflows"))
+        })?;
+        let mut coins = self
+            .select_coins_with_fee(AssetId::BASE, next_amount, |coins| {
+                self.base_amount_for(&self.add_coin_inputs(txs[0].1.clone(), coins))
+            })
+            .await?;
+
+        let mut deployed = Vec::with_capacity(txs.len());
+        for (contract_id, tx) in txs {
+            if let Some(&(_, tx_id)) = deployed.last() {
+                coins = match self.deployment_change(tx_id).await {
+                    Ok(change) => vec![CoinType::Coin(change)],
+                    Err(error) => {
+                        return Err(PartialDeployment { deployed, error }.into())
+                    }
+                };
+            }
+            match self.deploy_with_coins(tx, std::mem::take(&mut coins)).await {
+                Ok(tx_id) => deployed.push((contract_id, tx_id)),
+                Err(error) => return Err(PartialDeployment { deployed, error }.into()),
+            }
+        }
+        Ok(deployed)
+    }
+
+    /// Returns the change coin of the deployment and reserves it.
+    async fn deployment_change(&self, tx_id: TxId) -> anyhow::Result<Coin> {
+        // the change is the second output of the deployment
+        let utxo_id = UtxoId::new(tx_id, 1);
+        let change = self.client.coin(&utxo_id).await?.ok_or_else(|| {
+            anyhow!("the change {utxo_id:#x} of the deployment is not found")
+        })?;
+        self.reserved_coins.reserve(vec![change]).pop().ok_or_else(|| {
+            anyhow!("the change {utxo_id:#x} of the deployment is reserved by another task")
+        })
+    }
+
+    /// Creates the deployment of the contract without inputs at the `gas_price`.
+    fn create_tx(
+        &self,
+        config: ContractConfig,
+        gas_price: u64,
+    ) -> TransactionBuilder<Create> {
         let ContractConfig {
             contract_id,
             code: bytes,
@@ -229,21 +888,7 @@ impl Wallet {
             .collect::<Vec<_>>();
         let state_root = Contract::initial_state_root(slots.iter());
         let mut tx = TransactionBuilder::create(bytes.into(), salt, slots);
-        tx.gas_price(1);
-        tx.gas_limit(BASE_AMOUNT);
-
-        for coin in coins {
-            if let CoinType::Coin(coin) = coin {
-                tx.add_unsigned_coin_input(
-                    self.secret,
-                    coin.utxo_id,
-                    coin.amount,
-                    coin.asset_id,
-                    Default::default(),
-                    coin.maturity.into(),
-                );
-            }
-        }
+        tx.gas_price(gas_price);
         tx.add_output(Output::ContractCreated {
             contract_id,
             state_root,
@@ -251,31 +896,703 @@ impl Wallet {
         tx.add_output(Output::Change {
             to: self.address,
             amount: 0,
-            asset_id,
+            asset_id: AssetId::BASE,
         });
+        tx.gas_limit(BASE_AMOUNT);
+        tx
+    }
 
-        let tx = tx.finalize();
-        println!("The size of the transaction is {}", tx.serialized_size());
+    /// Deploys the contract spending the `coins`. The coins are released afterwards.
+    async fn deploy_with_coins(
+        &self,
+        tx: TransactionBuilder<Create>,
+        coins: Vec<CoinType>,
+    ) -> anyhow::Result<TxId> {
+        let _reserved = self.reserved_coins.guard(coin_utxo_ids(&coins));
+        let tx = self.add_coin_inputs(tx, &coins).finalize();
+        let tx_id = tx.id(&self.consensus_params.chain_id);
+        tracing::Span::current().record("tx_id", tracing::field::display(tx_id));
+        tracing::debug!("The size of the transaction is {}", tx.serialized_size());
+        let tx = tx.into();
 
-        let status = self
-            .client
-            .submit_and_await_commit(&tx.clone().into())
-            .await?;
+        tracing::info!("submitting the transaction");
+        let status = self.client.submit_and_await_commit(&tx).await?;
 
         // check status of contract deployment
-        if let TransactionStatus::Failure { .. } | TransactionStatus::SqueezedOut { .. } =
-            &status
-        {
+        if !is_successful(&status)? {
+            tracing::warn!(?status, "the contract deployment is not successful");
             return Err(anyhow!(format!("unexpected transaction status {status:?}")))
         }
 
+        Ok(tx_id)
+    }
+
+    /// Deploys the contract via [`Wallet::deploy_contract`] and verifies that the
+    /// node stores the contract from the `config`: the code root and the salt of the
+    /// deployed contract, and the state root of the committed transaction should
+    /// match the expected ones.
+    pub async fn deploy_and_verify(&self, config: ContractConfig) -> anyhow::Result<()> {
+        let contract_id = config.contract_id;
+        let salt = config.salt;
+        let code_root = Contract::root_from_code(&config.code);
+        let slots = config
+            .state
+            .clone()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(key, value)| StorageSlot::new(key, value))
+            .collect::<Vec<_>>();
+        let state_root = Contract::initial_state_root(slots.iter());
+
+        let tx_id = self.deploy_contract(config).await?;
+
+        let contract =
+            self.client.contract(&contract_id).await?.ok_or_else(|| {
+                anyhow!("the deployed contract {contract_id} is not found")
+            })?;
+        let deployed_code_root = Contract::root_from_code(&contract.bytecode);
+        if deployed_code_root != code_root {
+            return Err(anyhow!(
+                "the code root {deployed_code_root} of the deployed contract {contract_id} \
+                doesn't match the expected {code_root}"
+            ))
+        }
+        if contract.salt != salt {
+            return Err(anyhow!(
+                "the salt {} of the deployed contract {contract_id} doesn't match the expected {salt}",
+                contract.salt
+            ))
+        }
+
+        let deployed_state_root = self
+            .client
+            .transaction(&tx_id)
+            .await?
+            .and_then(|response| match response.transaction {
+                Transaction::Create(create) => {
+                    create.outputs().iter().find_map(|output| match output {
+                        Output::ContractCreated {
+                            contract_id: id,
+                            state_root,
+                        } if *id == contract_id => Some(*state_root),
+                        _ => None,
+                    })
+                }
+                _ => None,
+            })
+            .ok_or_else(|| {
+                anyhow!(
+                    "the deployment {tx_id} doesn't create the contract {contract_id}"
+                )
+            })?;
+        if deployed_state_root != state_root {
+            return Err(anyhow!(
+                "the state root {deployed_state_root} of the deployed contract {contract_id} \
+                doesn't match the expected {state_root}"
+            ))
+        }
+
         Ok(())
     }
 }
 
+/// Returns the gas price recommended by the node for the transactions to be included
+/// into the upcoming blocks.
+pub(crate) async fn recommended_gas_price(client: &FuelClient) -> anyhow::Result<u64> {
+    // The gas price doesn't depend on the transaction, so the empty script is
+    // estimated without the validation of the inputs.
+    let mut probe = TransactionBuilder::script(Default::default(), Default::default());
+    probe.gas_limit(BASE_AMOUNT);
+    let estimate = client
+        .estimate_fees_opt(
+            &probe.finalize_as_transaction(),
+            FeeConfidence::Medium,
+            Some(false),
+        )
+        .await
+        .context("failed to estimate the gas price")?;
+    Ok(estimate.gas_price)
+}
+
+/// Returns the assets and amounts required to transfer the `amount` of the
+/// `asset_id`. The fee is paid in the base asset.
+pub(crate) fn transfer_spend_query(
+    asset_id: AssetId,
+    amount: u64,
+) -> Vec<(AssetId, u64)> {
+    spend_query(asset_id, amount, BASE_AMOUNT)
+}
+
+/// Returns the assets and amounts required to spend the `total_needed` of the
+/// `asset_id` and to pay the `fee` in the base asset.
+pub(crate) fn spend_query(
+    asset_id: AssetId,
+    total_needed: u64,
+    fee: u64,
+) -> Vec<(AssetId, u64)> {
+    if asset_id == AssetId::BASE {
+        vec![(asset_id, total_needed + fee)]
+    } else if total_needed == 0 {
+        vec![(AssetId::BASE, fee)]
+    } else {
+        vec![(asset_id, total_needed), (AssetId::BASE, fee)]
+    }
+}
+
+/// Creates a change output for each distinct asset among the `coins`.
+pub(crate) fn change_outputs(owner: Address, coins: &[CoinType]) -> Vec<Output> {
+    coins
+        .iter()
+        .filter_map(|coin| match coin {
+            CoinType::Coin(coin) => Some(coin.asset_id),
+            _ => None,
+        })
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|asset_id| Output::change(owner, 0, asset_id))
+        .collect()
+}
+
+/// Returns whether the transaction with the final `status` is successful. Returns an
+/// error if the transaction is still pending.
+///
+/// All variants are matched explicitly, so a new status of the node has to be
+/// classified here.
+pub(crate) fn is_successful(status: &TransactionStatus) -> anyhow::Result<bool> {
+    match status {
+        TransactionStatus::Success { .. } => Ok(true),
+        TransactionStatus::Failure { .. } | TransactionStatus::SqueezedOut { .. } => {
+            Ok(false)
+        }
+        TransactionStatus::Submitted { .. } => {
+            Err(anyhow!("the transaction is still pending: {status:?}"))
+        }
+    }
+}
+
+/// Returns the fee charged by the chain with the `fee_params` for the executed `tx`
+/// with the `receipts`. The max fee covering the bytes and the gas limit of the
+/// transaction is charged, and the unused gas of the script is refunded, rounded
+/// down. The create transaction is charged only for its bytes.
+pub(crate) fn fee_paid(
+    tx: &Transaction,
+    receipts: &[Receipt],
+    fee_params: &FeeParameters,
+) -> anyhow::Result<u64> {
+    let (size, gas_limit, gas_price) = match fee_values(tx) {
+        Some(values) => values,
+        None => return Ok(0),
+    };
+    let gas_used = receipts.iter().find_map(|receipt| match receipt {
+        Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+        _ => None,
+    });
+    let gas_used = match gas_used {
+        Some(gas_used) => gas_used,
+        None => return max_fee(size, 0, gas_price, fee_params),
+    };
+    let max_fee = max_fee(size, gas_limit, gas_price, fee_params)?;
+    let refund = gas_limit
+        .saturating_sub(gas_used)
+        .checked_mul(gas_price)
+        .map(|refund| refund / fee_params.gas_price_factor)
+        .ok_or_else(|| anyhow!("the refund of the transaction overflows"))?;
+    Ok(max_fee.saturating_sub(refund))
+}
+
+/// Returns the serialized size, the gas limit, and the gas price of the `tx`,
+/// or `None` if the transaction doesn't pay the fee.
+fn fee_values(tx: &Transaction) -> Option<(u64, u64, u64)> {
+    match tx {
+        Transaction::Script(script) => Some((
+            script.serialized_size() as u64,
+            *script.gas_limit(),
+            *script.gas_price(),
+        )),
+        Transaction::Create(create) => Some((
+            create.serialized_size() as u64,
+            *create.gas_limit(),
+            *create.gas_price(),
+        )),
+        Transaction::Mint(_) => None,
+    }
+}
+
+/// Returns the max fee of the transaction of `size` bytes with the `gas_limit` at the
+/// `gas_price`, rounded up the same way as the fee charged by the node. Returns an
+/// error if the fee overflows.
+pub(crate) fn max_fee(
+    size: u64,
+    gas_limit: u64,
+    gas_price: u64,
+    fee_params: &FeeParameters,
+) -> anyhow::Result<u64> {
+    let overflow = || anyhow!("the fee of the transaction overflows");
+    let gas = size
+        .checked_mul(fee_params.gas_per_byte)
+        .and_then(|bytes_gas| bytes_gas.checked_add(gas_limit))
+        .ok_or_else(overflow)?;
+    let fee = gas.checked_mul(gas_price).ok_or_else(overflow)?;
+    let factor = fee_params.gas_price_factor;
+    Ok(fee / factor + u64::from(fee % factor != 0))
+}
+
+/// The set of coins reserved by the wallet and its clones.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CoinReservations(Arc<Mutex<HashSet<UtxoId>>>);
+
+impl CoinReservations {
+    /// Selects coins of the `owner` to spend the `(asset_id, amount)` pairs and
+    /// reserves them.
+    #[tracing::instrument(skip(self, client))]
+    pub(crate) async fn select(
+        &self,
+        client: &FuelClient,
+        owner: &Address,
+        spend: &[(AssetId, u64)],
+    ) -> anyhow::Result<Vec<CoinType>> {
+        let spend_query = spend
+            .iter()
+            .map(|(asset_id, amount)| (*asset_id, *amount, None))
+            .collect::<Vec<_>>();
+        // The node doesn't check the maturity of the coins, so the coins that can't
+        // be spent at the current height are excluded from the next selections.
+        let current_height = client.chain_info().await?.latest_block.header.height;
+        let mut immature = vec![];
+        loop {
+            let excluded = self
+                .0
+                .lock()
+                .iter()
+                .chain(immature.iter())
+                .copied()
+                .collect();
+            let coins = match client
+                .coins_to_spend(owner, spend_query.clone(), Some((excluded, vec![])))
+                .await
+            {
+                Ok(coins) => coins.into_iter().flatten().collect::<Vec<_>>(),
+                Err(e) if !immature.is_empty() => {
+                    tracing::debug!("failed to select mature coins: {e}");
+                    return Err(InsufficientMatureFunds {
+                        current_height,
+                        immature_coins: immature.len(),
+                    }
+                    .into())
+                }
+                Err(e) => return Err(e.into()),
+            };
+
+            let immature_coins = coins
+                .iter()
+                .filter_map(|coin| match coin {
+                    CoinType::Coin(coin) if coin.maturity > current_height => {
+                        Some(coin.utxo_id)
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            if !immature_coins.is_empty() {
+                tracing::debug!(?immature_coins, "selected coins are immature, retrying");
+                immature.extend(immature_coins);
+                continue
+            }
+
+            let utxo_ids = coin_utxo_ids(&coins);
+            let mut reserved = self.0.lock();
+            // Another task could reserve some of these coins while we were waiting
+            // for the response. In this case, we need to select coins again.
+            if utxo_ids.iter().all(|utxo_id| !reserved.contains(utxo_id)) {
+                tracing::debug!(?utxo_ids, "reserved coins");
+                reserved.extend(utxo_ids);
+                return Ok(coins)
+            }
+            tracing::debug!("selected coins are reserved by another task, retrying");
+        }
+    }
+
+    /// Reserves the `coins` that are not reserved yet and returns them.
+    pub(crate) fn reserve(&self, coins: Vec<Coin>) -> Vec<Coin> {
+        let mut reserved = self.0.lock();
+        coins
+            .into_iter()
+            .filter(|coin| reserved.insert(coin.utxo_id))
+            .collect()
+    }
+
+    /// Releases the coins used by the transaction.
+    pub(crate) fn release(&self, tx: &Transaction) {
+        self.release_utxo_ids(tx_utxo_ids(tx).iter())
+    }
+
+    /// Returns the guard releasing the reserved coins with the `utxo_ids` when
+    /// dropped, so the coins are released if the transaction isn't built or
+    /// submitted because of an error or the cancellation.
+    pub(crate) fn guard(&self, utxo_ids: Vec<UtxoId>) -> ReservationGuard<'_> {
+        ReservationGuard {
+            reservations: self,
+            utxo_ids,
+        }
+    }
+
+    /// Releases the coins with the `utxo_ids`.
+    pub(crate) fn release_utxo_ids<'a>(
+        &self,
+        utxo_ids: impl IntoIterator<Item = &'a UtxoId>,
+    ) {
+        let mut reserved = self.0.lock();
+        for utxo_id in utxo_ids {
+            reserved.remove(utxo_id);
+        }
+    }
+
+    /// Submits the transaction and waits for its commit. Releases the coins
+    /// used by the transaction afterwards.
+    #[tracing::instrument(
+        level = "info",
+        skip_all,
+        fields(tx_id = %tx.id(&consensus_params.chain_id))
+    )]
+    pub(crate) async fn submit(
+        &self,
+        client: &FuelClient,
+        consensus_params: &ConsensusParameters,
+        tx: Transaction,
+    ) -> anyhow::Result<TransferResult> {
+        let tx_id = tx.id(&consensus_params.chain_id);
+        let reserved = self.guard(tx_utxo_ids(&tx));
+        tracing::info!("submitting the transaction");
+        let status = client.submit_and_await_commit(&tx).await;
+        drop(reserved);
+        let status = status?;
+        let success = is_successful(&status)?;
+        if !success {
+            tracing::warn!(?status, "the transaction is not successful");
+        }
+        // the squeezed out transaction isn't executed and doesn't pay the fee
+        let (receipts, fee_paid) = match &status {
+            TransactionStatus::Success { .. } | TransactionStatus::Failure { .. } => {
+                let receipts = client
+                    .transaction_receipts(&tx_id)
+                    .await?
+                    .unwrap_or_default();
+                let fee_paid = fee_paid(&tx, &receipts, &consensus_params.fee_params)?;
+                (receipts, fee_paid)
+            }
+            _ => (vec![], 0),
+        };
+
+        // we know the transferred coin should be output 0
+        let transferred_utxo = UtxoId::new(tx_id, 0);
+
+        // get status and return the utxo id of transferred coin
+        Ok(TransferResult {
+            tx_id,
+            transferred_utxo,
+            success,
+            status,
+            receipts: receipts.into(),
+            fee_paid,
+        })
+    }
+}
+
+/// Releases the reserved coins when dropped, unless they are kept for the built
+/// transaction via [`ReservationGuard::keep`].
+#[must_use]
+pub(crate) struct ReservationGuard<'a> {
+    reservations: &'a CoinReservations,
+    utxo_ids: Vec<UtxoId>,
+}
+
+impl ReservationGuard<'_> {
+    /// Keeps the coins reserved. They are released after the submission of the
+    /// transaction spending them.
+    pub(crate) fn keep(mut self) {
+        self.utxo_ids.clear();
+    }
+}
+
+impl Drop for ReservationGuard<'_> {
+    fn drop(&mut self) {
+        if !self.utxo_ids.is_empty() {
+            tracing::debug!(utxo_ids = ?self.utxo_ids, "released coins");
+            self.reservations.release_utxo_ids(self.utxo_ids.iter());
+        }
+    }
+}
+
+/// Returns the ids of the coins spent by the transaction.
+fn tx_utxo_ids(tx: &Transaction) -> Vec<UtxoId> {
+    let inputs = match tx {
+        Transaction::Script(script) => script.inputs(),
+        Transaction::Create(create) => create.inputs(),
+        Transaction::Mint(_) => return vec![],
+    };
+    inputs.iter().filter_map(Input::utxo_id).copied().collect()
+}
+
+/// Returns the ids of the selected coins.
+pub(crate) fn coin_utxo_ids(coins: &[CoinType]) -> Vec<UtxoId> {
+    coins
+        .iter()
+        .filter_map(|coin| match coin {
+            CoinType::Coin(coin) => Some(coin.utxo_id),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The wallet has enough coins to cover the amount, but some of them can't be
+/// spent until the chain reaches their maturity height.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InsufficientMatureFunds {
+    /// The height of the latest block at the moment of the selection.
+    pub current_height: u32,
+    /// The number of selected coins that mature after the `current_height`.
+    pub immature_coins: usize,
+}
+
+impl std::fmt::Display for InsufficientMatureFunds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "not enough mature coins at height {}, {} coins mature later",
+            self.current_height, self.immature_coins
+        )
+    }
+}
+
+impl std::error::Error for InsufficientMatureFunds {}
+
+/// The batch deployment failed, but the contracts before the failed one are deployed.
+#[derive(Debug)]
+pub struct PartialDeployment {
+    /// The ids of the deployed contracts and of their deployments.
+    pub deployed: Vec<(ContractId, TxId)>,
+    /// The error of the failed deployment.
+    pub error: anyhow::Error,
+}
+
+impl std::fmt::Display for PartialDeployment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the deployment failed after {} deployed contracts",
+            self.deployed.len()
+        )
+    }
+}
+
+impl std::error::Error for PartialDeployment {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.error.as_ref())
+    }
+}
+
+/// The result of the submitted transaction. With the `serde` feature it can be serialized
+/// for the test reports: the `status` is represented by its kind and the optional reason
+/// of the failure, and the `receipts` are skipped.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TransferResult {
     pub tx_id: TxId,
     pub transferred_utxo: UtxoId,
     pub success: bool,
+    #[cfg_attr(feature = "serde", serde(with = "transfer_status"))]
     pub status: TransactionStatus,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub receipts: Receipts,
+    /// The fee charged for the transaction, see [`fee_paid`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub fee_paid: u64,
+}
+
+/// Serializes [`TransactionStatus`] as the kind of the status with the optional reason.
+/// The block id, time and program state are not preserved.
+#[cfg(feature = "serde")]
+mod transfer_status {
+    use super::TransactionStatus;
+    use fuel_core_types::tai64::Tai64;
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+    };
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(tag = "kind", rename_all = "snake_case")]
+    enum TransferStatus {
+        Submitted,
+        Success,
+        SqueezedOut { reason: String },
+        Failure { reason: String },
+    }
+
+    pub fn serialize<S>(
+        status: &TransactionStatus,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let status = match status {
+            TransactionStatus::Submitted { .. } => TransferStatus::Submitted,
+            TransactionStatus::Success { .. } => TransferStatus::Success,
+            TransactionStatus::SqueezedOut { reason } => TransferStatus::SqueezedOut {
+                reason: reason.clone(),
+            },
+            TransactionStatus::Failure { reason, .. } => TransferStatus::Failure {
+                reason: reason.clone(),
+            },
+        };
+        status.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<TransactionStatus, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let status = match TransferStatus::deserialize(deserializer)? {
+            TransferStatus::Submitted => TransactionStatus::Submitted {
+                submitted_at: Tai64::UNIX_EPOCH,
+            },
+            TransferStatus::Success => TransactionStatus::Success {
+                block_id: Default::default(),
+                time: Tai64::UNIX_EPOCH,
+                program_state: None,
+            },
+            TransferStatus::SqueezedOut { reason } => {
+                TransactionStatus::SqueezedOut { reason }
+            }
+            TransferStatus::Failure { reason } => TransactionStatus::Failure {
+                block_id: Default::default(),
+                time: Tai64::UNIX_EPOCH,
+                reason,
+                program_state: None,
+            },
+        };
+        Ok(status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::{
+        fuel_tx::ScriptExecutionResult,
+        tai64::Tai64,
+    };
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn transfer_result_round_trips_through_json() {
+        let tx_id = TxId::from([1; 32]);
+        let result = TransferResult {
+            tx_id,
+            transferred_utxo: UtxoId::new(tx_id, 0),
+            success: false,
+            status: TransactionStatus::Failure {
+                block_id: "0x01".to_string(),
+                time: Tai64::UNIX_EPOCH,
+                reason: "Revert(42)".to_string(),
+                program_state: None,
+            },
+            receipts: Default::default(),
+            fee_paid: 42,
+        };
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(
+            json["status"],
+            serde_json::json!({ "kind": "failure", "reason": "Revert(42)" })
+        );
+
+        let decoded: TransferResult = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(decoded.tx_id, result.tx_id);
+        assert_eq!(decoded.transferred_utxo, result.transferred_utxo);
+        assert_eq!(decoded.success, result.success);
+        assert_eq!(decoded.fee_paid, result.fee_paid);
+        assert!(matches!(
+            decoded.status,
+            TransactionStatus::Failure { reason, .. } if reason == "Revert(42)"
+        ));
+        assert_eq!(serde_json::to_value(&decoded).unwrap(), json);
+    }
+
+    #[test]
+    fn guard_releases_coins_unless_kept() {
+        let reservations = CoinReservations::default();
+        let kept = UtxoId::new(TxId::from([1; 32]), 0);
+        let released = UtxoId::new(TxId::from([2; 32]), 0);
+        reservations.0.lock().extend([kept, released]);
+
+        reservations.guard(vec![kept]).keep();
+        drop(reservations.guard(vec![released]));
+
+        assert_eq!(*reservations.0.lock(), HashSet::from([kept]));
+    }
+
+    #[test]
+    fn only_final_statuses_are_classified() {
+        let success = TransactionStatus::Success {
+            block_id: Default::default(),
+            time: Tai64::UNIX_EPOCH,
+            program_state: None,
+        };
+        let failure = TransactionStatus::Failure {
+            block_id: Default::default(),
+            time: Tai64::UNIX_EPOCH,
+            reason: "Revert(42)".to_string(),
+            program_state: None,
+        };
+        let squeezed_out = TransactionStatus::SqueezedOut {
+            reason: "too cheap".to_string(),
+        };
+        let submitted = TransactionStatus::Submitted {
+            submitted_at: Tai64::UNIX_EPOCH,
+        };
+
+        assert!(is_successful(&success).unwrap());
+        assert!(!is_successful(&failure).unwrap());
+        assert!(!is_successful(&squeezed_out).unwrap());
+        assert!(is_successful(&submitted).is_err());
+    }
+
+    #[test]
+    fn max_fee_is_rounded_up_and_checked() {
+        let mut fee_params = FeeParameters::default();
+        fee_params.gas_per_byte = 4;
+        fee_params.gas_price_factor = 10;
+
+        // (100 * 4 + 1000) * 3 / 10 = 420
+        assert_eq!(max_fee(100, 1000, 3, &fee_params).unwrap(), 420);
+        // (1 * 4 + 0) * 1 / 10 is rounded up
+        assert_eq!(max_fee(1, 0, 1, &fee_params).unwrap(), 1);
+        assert_eq!(max_fee(100, 1000, 0, &fee_params).unwrap(), 0);
+        assert!(max_fee(u64::MAX, 0, 1, &fee_params).is_err());
+        assert!(max_fee(1, u64::MAX, 1, &fee_params).is_err());
+        assert!(max_fee(100, 1000, u64::MAX, &fee_params).is_err());
+    }
+
+    #[test]
+    fn fee_paid_refunds_the_unused_gas() {
+        let mut fee_params = FeeParameters::default();
+        fee_params.gas_per_byte = 4;
+        fee_params.gas_price_factor = 10;
+        let mut tx = TransactionBuilder::script(Default::default(), Default::default());
+        tx.gas_price(3);
+        tx.gas_limit(1000);
+        let tx = tx.finalize_as_transaction();
+        let size = tx.as_script().unwrap().serialized_size() as u64;
+        let receipts = [Receipt::script_result(ScriptExecutionResult::Success, 100)];
+
+        // the unused 900 gas at the price 3 are refunded
+        assert_eq!(
+            fee_paid(&tx, &receipts, &fee_params).unwrap(),
+            max_fee(size, 1000, 3, &fee_params).unwrap() - 270
+        );
+        assert!(fee_paid(&tx, &receipts, &fee_params).unwrap() > 0);
+    }
 }
@@ -0,0 +1,317 @@
+//! The multisig wallet based on the predicate that verifies signatures of the owners.
+
+use super::{
+    change_outputs,
+    coin_utxo_ids,
+    recommended_gas_price,
+    transfer_spend_query,
+    CoinReservations,
+    TransferResult,
+    BASE_AMOUNT,
+};
+use anyhow::{
+    anyhow,
+    Context,
+};
+use fuel_core_client::client::{
+    types::CoinType,
+    FuelClient,
+};
+use fuel_core_types::{
+    fuel_asm::{
+        op,
+        GTFArgs,
+        RegId,
+    },
+    fuel_crypto::{
+        Message,
+        PublicKey,
+        Signature,
+    },
+    fuel_tx::{
+        field::Witnesses,
+        ConsensusParameters,
+        Finalizable,
+        Input,
+        Output,
+        Transaction,
+        TransactionBuilder,
+        UniqueIdentifier,
+    },
+    fuel_types::{
+        Address,
+        AssetId,
+    },
+    fuel_vm::SecretKey,
+};
+use std::collections::HashSet;
+
+/// The maximum number of owners supported by the predicate. The offsets used by the
+/// predicate should fit into the immediate values of the instructions.
+pub const MAX_OWNERS: usize = 10;
+
+const INSTRUCTION_SIZE: usize = 4;
+const PUBLIC_KEY_SIZE: usize = 64;
+const ADDRESS_SIZE: usize = 32;
+
+/// The wallet owned by several keys. The coins of the wallet are locked by
+/// the predicate which requires signatures from at least `threshold` owners.
+///
+/// A cloned wallet shares the same set of reserved coins with the original.
+#[derive(Debug, Clone)]
+pub struct MultisigWallet {
+    /// The keys of the owners available to sign transactions.
+    pub secrets: Vec<SecretKey>,
+    /// The addresses of all owners of the wallet.
+    pub owners: Vec<Address>,
+    /// The number of signatures required to spend the coins.
+    pub threshold: usize,
+    /// The bytecode of the predicate that verifies the signatures.
+    pub predicate: Vec<u8>,
+    /// The address of the predicate.
+    pub address: Address,
+    pub client: FuelClient,
+    pub consensus_params: ConsensusParameters,
+    reserved_coins: CoinReservations,
+}
+
+impl MultisigWallet {
+    /// Creates the `threshold`-of-`owners` wallet. The `secrets` are the keys of
+    /// the owners available to this wallet; at least `threshold` of them are required.
+    pub async fn new(
+        owners: Vec<Address>,
+        threshold: usize,
+        secrets: Vec<SecretKey>,
+        client: FuelClient,
+    ) -> anyhow::Result<Self> {
+        if owners.is_empty() || owners.len() > MAX_OWNERS {
+            return Err(anyhow!(
+                "the multisig supports from 1 to {MAX_OWNERS} owners, but {} were provided",
+                owners.len()
+            ))
+        }
+        if owners.iter().collect::<HashSet<_>>().len() != owners.len() {
+            return Err(anyhow!("the owners of the multisig should be unique"))
+        }
+        if threshold == 0 || threshold > owners.len() {
+            return Err(anyhow!(
+                "the threshold should be from 1 to {}, but it is {threshold}",
+                owners.len()
+            ))
+        }
+
+        let mut signers = Vec::<SecretKey>::with_capacity(secrets.len());
+        for secret in secrets {
+            let public_key: PublicKey = (&secret).into();
+            let address = Input::owner(&public_key);
+            if !owners.contains(&address) {
+                return Err(anyhow!(
+                    "the key of {address} is not an owner of the multisig"
+                ))
+            }
+            // the predicate counts each owner only once
+            if !signers.contains(&secret) {
+                signers.push(secret);
+            }
+        }
+        if signers.len() < threshold {
+            return Err(anyhow!(
+                "the multisig requires {threshold} signatures, but only {} keys of the owners are available",
+                signers.len()
+            ))
+        }
+
+        let consensus_params: ConsensusParameters =
+            client.chain_info().await?.consensus_parameters.into();
+        let predicate = Self::predicate(&owners, threshold);
+        let address = Input::predicate_owner(&predicate, &consensus_params.chain_id);
+
+        Ok(Self {
+            secrets: signers,
+            owners,
+            threshold,
+            predicate,
+            address,
+            client,
+            consensus_params,
+            reserved_coins: Default::default(),
+        })
+    }
+
+    /// Generates the predicate that succeeds if the first `threshold` witnesses of
+    /// the transaction contain signatures of different `owners` over the transaction id.
+    ///
+    /// The addresses of the owners are stored right after the code of the predicate.
+    pub fn predicate(owners: &[Address], threshold: usize) -> Vec<u8> {
+        const BASE: u8 = 0x10;
+        const INDEX: u8 = 0x11;
+        const SIGNATURE: u8 = 0x12;
+        const PUBLIC_KEY: u8 = 0x13;
+        const SIGNER: u8 = 0x14;
+        const OWNER: u8 = 0x15;
+        const FOUND: u8 = 0x16;
+        const EQUAL: u8 = 0x17;
+        const COUNT: u8 = 0x18;
+        const LEN: u8 = 0x19;
+        const RESULT: u8 = 0x1a;
+
+        let imm12 = |value: usize| -> u16 {
+            value
+                .try_into()
+                .expect("the multisig predicate is too large")
+        };
+        let signers_offset = threshold * PUBLIC_KEY_SIZE;
+        let code = |code_len: usize| {
+            let mut code = vec![
+                // the stack is used to store the recovered keys and the addresses of signers
+                op::move_(BASE, RegId::SSP),
+                op::cfei(
+                    (threshold * (PUBLIC_KEY_SIZE + ADDRESS_SIZE))
+                        .try_into()
+                        .expect("the threshold is limited by `MAX_OWNERS`"),
+                ),
+                op::movi(COUNT, 0),
+            ];
+            for witness in 0..threshold {
+                code.extend([
+                    op::movi(INDEX, witness as u32),
+                    op::gtf_args(SIGNATURE, INDEX, GTFArgs::WitnessData),
+                    op::addi(PUBLIC_KEY, BASE, imm12(witness * PUBLIC_KEY_SIZE)),
+                    // the transaction id is stored at the beginning of the memory
+                    op::ecr(PUBLIC_KEY, SIGNATURE, RegId::ZERO),
+                    op::addi(
+                        SIGNER,
+                        BASE,
+                        imm12(signers_offset + witness * ADDRESS_SIZE),
+                    ),
+                    op::movi(LEN, PUBLIC_KEY_SIZE as u32),
+                    op::s256(SIGNER, PUBLIC_KEY, LEN),
+                ]);
+            }
+            code.push(op::movi(LEN, ADDRESS_SIZE as u32));
+            for owner in 0..owners.len() {
+                code.extend([
+                    op::addi(OWNER, RegId::IS, imm12(code_len + owner * ADDRESS_SIZE)),
+                    op::movi(FOUND, 0),
+                ]);
+                for witness in 0..threshold {
+                    code.extend([
+                        op::addi(
+                            SIGNER,
+                            BASE,
+                            imm12(signers_offset + witness * ADDRESS_SIZE),
+                        ),
+                        op::meq(EQUAL, SIGNER, OWNER, LEN),
+                        op::or(FOUND, FOUND, EQUAL),
+                    ]);
+                }
+                code.push(op::add(COUNT, COUNT, FOUND));
+            }
+            code.extend([
+                op::movi(RESULT, threshold as u32),
+                // the predicate fails if `COUNT < threshold`
+                op::lt(RESULT, COUNT, RESULT),
+                op::eq(RESULT, RESULT, RegId::ZERO),
+                op::ret(RESULT),
+            ]);
+            code
+        };
+
+        let code_len = code(0).len() * INSTRUCTION_SIZE;
+        let mut predicate: Vec<u8> = code(code_len).into_iter().collect();
+        for owner in owners {
+            predicate.extend_from_slice(owner.as_ref());
+        }
+        predicate
+    }
+
+    /// returns the balance associated with a wallet
+    pub async fn balance(&self, asset_id: Option<AssetId>) -> anyhow::Result<u64> {
+        self.client
+            .balance(&self.address, Some(&asset_id.unwrap_or_default()))
+            .await
+            .context("failed to retrieve balance")
+    }
+
+    /// Creates the transfer transaction signed by the first `threshold` keys.
+    /// The coins used by the transaction are reserved until it is submitted
+    /// via [`MultisigWallet::transfer`]. They are released right away if the
+    /// transaction can't be built.
+    pub async fn transfer_tx(
+        &self,
+        destination: Address,
+        transfer_amount: u64,
+        asset_id: Option<AssetId>,
+    ) -> anyhow::Result<Transaction> {
+        let asset_id = asset_id.unwrap_or_default();
+        // select coins
+        let coins = self
+            .reserved_coins
+            .select(
+                &self.client,
+                &self.address,
+                &transfer_spend_query(asset_id, transfer_amount),
+            )
+            .await?;
+        let reserved = self.reserved_coins.guard(coin_utxo_ids(&coins));
+        let change_outputs = change_outputs(self.address, &coins);
+
+        // build transaction
+        let mut tx = TransactionBuilder::script(Default::default(), Default::default());
+        tx.gas_price(recommended_gas_price(&self.client).await?);
+        tx.gas_limit(BASE_AMOUNT);
+
+        for coin in coins {
+            if let CoinType::Coin(coin) = coin {
+                tx.add_input(Input::coin_predicate(
+                    coin.utxo_id,
+                    self.address,
+                    coin.amount,
+                    coin.asset_id,
+                    Default::default(),
+                    coin.maturity.into(),
+                    Default::default(),
+                    self.predicate.clone(),
+                    vec![],
+                ));
+            }
+        }
+        tx.add_output(Output::Coin {
+            to: destination,
+            amount: transfer_amount,
+            asset_id,
+        });
+        for output in change_outputs {
+            tx.add_output(output);
+        }
+        tx.with_params(self.consensus_params.clone());
+        let mut tx = tx.finalize();
+
+        // the predicate expects signatures of the owners to be the first witnesses
+        let message = Message::from_bytes(*tx.id(&self.consensus_params.chain_id));
+        for secret in self.secrets.iter().take(self.threshold) {
+            let signature = Signature::sign(secret, &message);
+            tx.witnesses_mut().push(signature.as_ref().to_vec().into());
+        }
+
+        let mut tx = tx.into();
+        self.client.estimate_predicates(&mut tx).await?;
+        reserved.keep();
+        Ok(tx)
+    }
+
+    /// Transfers coins from this wallet to another
+    pub async fn transfer(
+        &self,
+        destination: Address,
+        transfer_amount: u64,
+        asset_id: Option<AssetId>,
+    ) -> anyhow::Result<TransferResult> {
+        let tx = self
+            .transfer_tx(destination, transfer_amount, asset_id)
+            .await?;
+        self.reserved_coins
+            .submit(&self.client, &self.consensus_params, tx)
+            .await
+    }
+}
@@ -63,7 +63,10 @@ pub async fn dry_run(ctx: &TestContext) -> Result<(), Failed> {
     )
     .await??;
 
-    _dry_runs(ctx, &transaction, 1000, DryRunResult::Successful).await
+    let result = _dry_runs(ctx, &transaction, 1000, DryRunResult::Successful).await;
+    // the transaction is only dry run, so its coins can be spent by other tests
+    ctx.alice.release_coins(&transaction);
+    result
 }
 
 // Maybe deploy a contract with large state and execute the script
@@ -1,11 +1,66 @@
-use fuel_core::service::{
-    Config,
-    FuelService,
+use fuel_core::{
+    chain_config::{
+        ChainConfig,
+        ContractConfig,
+    },
+    service::{
+        config::Trigger,
+        Config,
+        FuelService,
+    },
 };
 
 // Add methods on commands
-use fuel_core_e2e_client::config::SuiteConfig;
-use std::fs;
+use fuel_core_client::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    types::TransactionStatus,
+    FuelClient,
+};
+use fuel_core_e2e_client::{
+    config::SuiteConfig,
+    test_context::{
+        InsufficientMatureFunds,
+        MultisigWallet,
+        TestContext,
+        Wallet,
+        BASE_AMOUNT,
+    },
+};
+use fuel_core_types::{
+    fuel_asm::{
+        op,
+        GTFArgs,
+        RegId,
+    },
+    fuel_crypto::{
+        rand::{
+            rngs::StdRng,
+            SeedableRng,
+        },
+        SecretKey,
+    },
+    fuel_tx::{
+        field::Inputs,
+        Contract,
+        Input,
+        Output,
+        UtxoId,
+    },
+    fuel_types::{
+        bytes::SerializableVec,
+        Address,
+        AssetId,
+        ChainId,
+    },
+    fuel_vm::Call,
+};
+use std::{
+    fs,
+    time::Duration,
+};
 use tempfile::TempDir; // Used for writing assertions // Run programs
 
 // Use Jemalloc
@@ -22,6 +77,272 @@ async fn works_in_local_env() {
     execute_suite(config.path).await
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn works_with_in_process_node() {
+    // no endpoint means that the context should start its own node
+    let config = SuiteConfig {
+        endpoint: "".to_string(),
+        ..Default::default()
+    };
+
+    let ctx = TestContext::new(config).await.unwrap();
+    assert!(ctx.local_node.is_some());
+    assert!(ctx.alice.balance(None).await.unwrap() > 0);
+    assert!(ctx.bob.balance(None).await.unwrap() > 0);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn funded_genesis_sets_exact_balances() {
+    let secret = SuiteConfig::default().wallet_a.secret;
+    let owner = Input::owner(&secret.public_key());
+    let asset_id = AssetId::new([1; 32]);
+    let chain_config = TestContext::funded_chain_config(vec![
+        (owner, AssetId::BASE, 1_000),
+        (owner, asset_id, 2_000),
+        (owner, asset_id, 3_000),
+    ]);
+
+    let srv = setup_funded_node(chain_config).await;
+    let wallet = Wallet::new(secret, FuelClient::from(srv.bound_address)).await;
+
+    assert_eq!(wallet.balance(None).await.unwrap(), 1_000);
+    assert_eq!(wallet.balance(Some(asset_id)).await.unwrap(), 5_000);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn transfer_of_non_base_asset_returns_change_for_each_asset() {
+    let config = SuiteConfig::default();
+    let alice = Input::owner(&config.wallet_a.secret.public_key());
+    let bob = Input::owner(&config.wallet_b.secret.public_key());
+    let asset_id = AssetId::new([1; 32]);
+    let chain_config = TestContext::funded_chain_config(vec![
+        (alice, AssetId::BASE, 2 * BASE_AMOUNT),
+        (alice, asset_id, 5_000),
+    ]);
+
+    let srv = setup_funded_node(chain_config).await;
+    let wallet =
+        Wallet::new(config.wallet_a.secret, FuelClient::from(srv.bound_address)).await;
+
+    let result = wallet.transfer(bob, 2_000, Some(asset_id)).await.unwrap();
+    assert!(result.success, "unexpected status {:?}", result.status);
+
+    // the remainder of the transferred asset and the base asset after the fee
+    // are returned to the sender by the separate change outputs
+    assert_eq!(wallet.balance(Some(asset_id)).await.unwrap(), 3_000);
+    let base_balance = wallet.balance(None).await.unwrap();
+    assert!(base_balance > 0 && base_balance < 2 * BASE_AMOUNT);
+    assert_eq!(
+        wallet.client.balance(&bob, Some(&asset_id)).await.unwrap(),
+        2_000
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn concurrent_transfers_do_not_double_spend() {
+    const TRANSFERS: u64 = 20;
+    const TRANSFER_AMOUNT: u64 = 100;
+
+    let config = SuiteConfig::default();
+    let alice = Input::owner(&config.wallet_a.secret.public_key());
+    let bob = Input::owner(&config.wallet_b.secret.public_key());
+    let coins = (0..2 * TRANSFERS)
+        .map(|_| (alice, AssetId::BASE, 2 * BASE_AMOUNT))
+        .collect();
+    let srv = setup_funded_node(TestContext::funded_chain_config(coins)).await;
+    let client = FuelClient::from(srv.bound_address);
+    let wallet = Wallet::new(config.wallet_a.secret, client.clone()).await;
+
+    let transfers = (0..TRANSFERS).map(|_| {
+        let wallet = wallet.clone();
+        tokio::spawn(async move { wallet.transfer(bob, TRANSFER_AMOUNT, None).await })
+    });
+    for result in futures::future::join_all(transfers).await {
+        let result = result.unwrap().expect("transfer should not double spend");
+        assert!(result.success, "unexpected status {:?}", result.status);
+    }
+
+    let bob = Wallet::new(config.wallet_b.secret, client).await;
+    let bob_balance = bob
+        .await_balance_at_least(None, TRANSFERS * TRANSFER_AMOUNT, config.sync_timeout())
+        .await
+        .unwrap();
+    assert_eq!(bob_balance, TRANSFERS * TRANSFER_AMOUNT);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn can_call_deployed_contract() {
+    let config = SuiteConfig {
+        endpoint: "".to_string(),
+        ..Default::default()
+    };
+    let ctx = TestContext::new(config).await.unwrap();
+
+    // the contract logs `0xca`
+    let contract = vec![
+        op::movi(0x10, 0xca),
+        op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let mut contract_config = ContractConfig {
+        contract_id: Default::default(),
+        code: contract.into_iter().collect(),
+        salt: Default::default(),
+        state: None,
+        balances: None,
+        tx_id: None,
+        output_index: None,
+        tx_pointer_block_height: None,
+        tx_pointer_tx_idx: None,
+    };
+    contract_config.calculate_contract_id();
+    let contract_id = contract_config.contract_id;
+    ctx.alice.deploy_contract(contract_config).await.unwrap();
+
+    let script = vec![
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let result = ctx
+        .alice
+        .call_contract(
+            contract_id,
+            script.into_iter().collect(),
+            Call::new(contract_id, 0, 0).to_bytes(),
+            0,
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert!(result.success, "unexpected status {:?}", result.status);
+    assert_eq!(result.receipts.logged_values(), vec![[0xca, 0, 0, 0]]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn deployed_contract_matches_the_config() {
+    let config = SuiteConfig {
+        endpoint: "".to_string(),
+        ..Default::default()
+    };
+    let ctx = TestContext::new(config).await.unwrap();
+
+    let code: Vec<u8> = vec![op::ret(RegId::ONE)].into_iter().collect();
+    let mut contract_config = ContractConfig {
+        contract_id: Default::default(),
+        code: code.clone(),
+        salt: [7; 32].into(),
+        state: Some(vec![([1; 32].into(), [2; 32].into())]),
+        balances: None,
+        tx_id: None,
+        output_index: None,
+        tx_pointer_block_height: None,
+        tx_pointer_tx_idx: None,
+    };
+    contract_config.calculate_contract_id();
+    let contract_id = contract_config.contract_id;
+    ctx.alice.deploy_and_verify(contract_config).await.unwrap();
+
+    let contract = ctx
+        .alice
+        .client
+        .contract(&contract_id)
+        .await
+        .unwrap()
+        .expect("the contract should be deployed");
+    assert_eq!(
+        Contract::root_from_code(&contract.bytecode),
+        Contract::root_from_code(&code)
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn can_deploy_several_contracts() {
+    let config = SuiteConfig {
+        endpoint: "".to_string(),
+        ..Default::default()
+    };
+    let ctx = TestContext::new(config).await.unwrap();
+
+    let configs = (0..3u8)
+        .map(|i| {
+            let mut contract_config = ContractConfig {
+                contract_id: Default::default(),
+                code: vec![op::ret(RegId::ONE)].into_iter().collect(),
+                salt: [i; 32].into(),
+                state: None,
+                balances: None,
+                tx_id: None,
+                output_index: None,
+                tx_pointer_block_height: None,
+                tx_pointer_tx_idx: None,
+            };
+            contract_config.calculate_contract_id();
+            contract_config
+        })
+        .collect::<Vec<_>>();
+    let contract_ids = configs
+        .iter()
+        .map(|config| config.contract_id)
+        .collect::<Vec<_>>();
+
+    let deployed = ctx.alice.deploy_contracts(configs).await.unwrap();
+
+    assert_eq!(
+        deployed.iter().map(|(id, _)| *id).collect::<Vec<_>>(),
+        contract_ids
+    );
+    for contract_id in contract_ids {
+        let contract = ctx.alice.client.contract(&contract_id).await.unwrap();
+        assert!(
+            contract.is_some(),
+            "the contract {contract_id} is not deployed"
+        );
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn can_transfer_from_2_of_3_multisig() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secrets: Vec<SecretKey> = (0..3).map(|_| SecretKey::random(&mut rng)).collect();
+    let owners: Vec<_> = secrets
+        .iter()
+        .map(|secret| Input::owner(&secret.public_key()))
+        .collect();
+    let predicate = MultisigWallet::predicate(&owners, 2);
+    let multisig = Input::predicate_owner(&predicate, &ChainId::default());
+    let bob = Input::owner(&SuiteConfig::default().wallet_b.secret.public_key());
+
+    let chain_config = TestContext::funded_chain_config(vec![(
+        multisig,
+        AssetId::BASE,
+        10 * BASE_AMOUNT,
+    )]);
+    let mut config = Config::local_node();
+    config.utxo_validation = true;
+    config.txpool.utxo_validation = true;
+    config.chain_conf = chain_config.clone();
+    config.txpool.chain_config = chain_config;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // a single key is not enough to sign transactions
+    let err =
+        MultisigWallet::new(owners.clone(), 2, secrets[..1].to_vec(), client.clone())
+            .await
+            .unwrap_err();
+    assert!(err.to_string().contains("requires 2 signatures"));
+
+    let wallet = MultisigWallet::new(owners, 2, secrets[1..].to_vec(), client.clone())
+        .await
+        .unwrap();
+    assert_eq!(wallet.address, multisig);
+    let result = wallet.transfer(bob, BASE_AMOUNT, None).await.unwrap();
+    assert!(result.success, "unexpected status {:?}", result.status);
+    assert_eq!(client.balance(&bob, None).await.unwrap(), BASE_AMOUNT);
+}
+
 // Spins up a node for each wallet and verifies that the suite works across multiple nodes
 #[cfg(feature = "p2p")]
 #[tokio::test(flavor = "multi_thread")]
@@ -95,6 +416,355 @@ async fn setup_local_node() -> FuelService {
     FuelService::new_node(config).await.unwrap()
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn can_build_tx_with_custom_outputs() {
+    let config = SuiteConfig::default();
+    let alice = Input::owner(&config.wallet_a.secret.public_key());
+    let bob = Input::owner(&config.wallet_b.secret.public_key());
+    let carol = Address::new([3; 32]);
+    let srv = setup_funded_node(TestContext::funded_chain_config(vec![(
+        alice,
+        AssetId::BASE,
+        2 * BASE_AMOUNT,
+    )]))
+    .await;
+    let client = FuelClient::from(srv.bound_address);
+    let wallet = Wallet::new(config.wallet_a.secret, client.clone()).await;
+
+    let outputs = vec![
+        Output::coin(bob, 100, AssetId::BASE),
+        Output::coin(carol, 200, AssetId::BASE),
+    ];
+    let tx = wallet.build_tx(outputs, AssetId::BASE, 300).await.unwrap();
+    let status = client.submit_and_await_commit(&tx).await;
+    wallet.release_coins(&tx);
+
+    assert!(
+        matches!(status, Ok(TransactionStatus::Success { .. })),
+        "unexpected status {status:?}"
+    );
+    assert_eq!(client.balance(&bob, None).await.unwrap(), 100);
+    assert_eq!(client.balance(&carol, None).await.unwrap(), 200);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn can_bump_fee_of_pending_transfer() {
+    let config = SuiteConfig::default();
+    let alice = Input::owner(&config.wallet_a.secret.public_key());
+    let bob = Input::owner(&config.wallet_b.secret.public_key());
+    let chain_config =
+        TestContext::funded_chain_config(vec![(alice, AssetId::BASE, 2 * BASE_AMOUNT)]);
+    let mut node_config = Config::local_node();
+    node_config.chain_conf = chain_config.clone();
+    node_config.txpool.chain_config = chain_config;
+    // the transaction should stay in the pool until the block is produced manually
+    node_config.block_production = Trigger::Never;
+    node_config.manual_blocks_enabled = true;
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let wallet = Wallet::new(config.wallet_a.secret, client.clone()).await;
+
+    let tx = wallet.transfer_tx(bob, 100, None).await.unwrap();
+    let tx_id = client.submit(&tx).await.unwrap();
+
+    let bump = tokio::spawn({
+        let wallet = wallet.clone();
+        async move { wallet.bump_fee(tx_id, 2).await }
+    });
+    // the original transaction is removed from the pool by the replacement
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while client.transaction(&tx_id).await.unwrap().is_some() {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("the transaction should be replaced");
+    client.produce_blocks(1, None).await.unwrap();
+
+    let result = bump.await.unwrap().unwrap();
+    assert!(result.success, "unexpected status {:?}", result.status);
+    assert_ne!(result.tx_id, tx_id);
+    assert_eq!(client.balance(&bob, None).await.unwrap(), 100);
+
+    let err = wallet.bump_fee(result.tx_id, 3).await.unwrap_err();
+    assert!(err.to_string().contains("not pending"), "{err}");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn can_transfer_fragmented_balance_after_consolidation() {
+    const COINS: usize = 200;
+    const COIN_AMOUNT: u64 = 1_000;
+    let config = SuiteConfig::default();
+    let alice = Input::owner(&config.wallet_a.secret.public_key());
+    let bob = Input::owner(&config.wallet_b.secret.public_key());
+    let mut chain_config =
+        TestContext::funded_chain_config(vec![
+            (alice, AssetId::BASE, COIN_AMOUNT);
+            COINS
+        ]);
+    // the transfer below requires more coins than fit into one transaction
+    chain_config.consensus_parameters.tx_params.max_inputs = 100;
+    let srv = setup_funded_node(chain_config).await;
+    let wallet =
+        Wallet::new(config.wallet_a.secret, FuelClient::from(srv.bound_address)).await;
+    let amount = 150 * COIN_AMOUNT;
+
+    let err = wallet.transfer(bob, amount, None).await.unwrap_err();
+    assert!(err.to_string().contains("consolidate"), "{err:?}");
+
+    let result = wallet.consolidate(None).await.unwrap();
+    assert!(result.success, "unexpected status {:?}", result.status);
+    assert!(wallet.owns_coin(result.transferred_utxo).await.unwrap());
+
+    let result = wallet.transfer(bob, amount, None).await.unwrap();
+    assert!(result.success, "unexpected status {:?}", result.status);
+    assert_eq!(wallet.client.balance(&bob, None).await.unwrap(), amount);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn immature_coins_are_not_selected_until_maturity() {
+    const MATURITY: u32 = 5;
+    let config = SuiteConfig::default();
+    let alice = Input::owner(&config.wallet_a.secret.public_key());
+    let bob = Input::owner(&config.wallet_b.secret.public_key());
+    let mut chain_config = TestContext::funded_chain_config(vec![
+        (alice, AssetId::BASE, 2 * BASE_AMOUNT),
+        (alice, AssetId::BASE, 10 * BASE_AMOUNT),
+    ]);
+    let immature_coin = chain_config
+        .initial_state
+        .as_mut()
+        .and_then(|state| state.coins.as_mut())
+        .and_then(|coins| coins.last_mut())
+        .unwrap();
+    immature_coin.maturity = Some(MATURITY.into());
+    let mut node_config = Config::local_node();
+    node_config.chain_conf = chain_config.clone();
+    node_config.txpool.chain_config = chain_config;
+    node_config.manual_blocks_enabled = true;
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let wallet = Wallet::new(config.wallet_a.secret, client.clone()).await;
+
+    // only the mature coin can be used
+    let tx = wallet.transfer_tx(bob, 100, None).await.unwrap();
+    let inputs = tx.as_script().unwrap().inputs();
+    assert_eq!(inputs.len(), 1);
+    assert_eq!(inputs[0].amount(), Some(2 * BASE_AMOUNT));
+    wallet.release_coins(&tx);
+
+    let err = wallet
+        .transfer_tx(bob, 5 * BASE_AMOUNT, None)
+        .await
+        .unwrap_err();
+    assert!(
+        err.downcast_ref::<InsufficientMatureFunds>().is_some(),
+        "{err:?}"
+    );
+
+    let height = client
+        .chain_info()
+        .await
+        .unwrap()
+        .latest_block
+        .header
+        .height;
+    client
+        .produce_blocks((MATURITY - height) as u64, None)
+        .await
+        .unwrap();
+
+    let result = wallet.transfer(bob, 5 * BASE_AMOUNT, None).await.unwrap();
+    assert!(result.success, "unexpected status {:?}", result.status);
+    assert_eq!(client.balance(&bob, None).await.unwrap(), 5 * BASE_AMOUNT);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn large_deployment_selects_more_coins_than_transfer() {
+    let config = SuiteConfig::default();
+    let alice = Input::owner(&config.wallet_a.secret.public_key());
+    let bob = Input::owner(&config.wallet_b.secret.public_key());
+    let coins = vec![(alice, AssetId::BASE, BASE_AMOUNT); 100];
+    let mut chain_config = TestContext::funded_chain_config(coins);
+    // each byte of the transaction costs a noticeable fee
+    chain_config
+        .consensus_parameters
+        .fee_params
+        .gas_price_factor = 1;
+    let srv = setup_funded_node(chain_config).await;
+    let wallet =
+        Wallet::new(config.wallet_a.secret, FuelClient::from(srv.bound_address)).await;
+
+    let transfer = wallet.transfer_tx(bob, 1, None).await.unwrap();
+    wallet.release_coins(&transfer);
+    let transfer_inputs = transfer.as_script().unwrap().inputs().len();
+
+    let mut contract_config = ContractConfig {
+        contract_id: Default::default(),
+        code: vec![0; 100 * 1024],
+        salt: Default::default(),
+        state: None,
+        balances: None,
+        tx_id: None,
+        output_index: None,
+        tx_pointer_block_height: None,
+        tx_pointer_tx_idx: None,
+    };
+    contract_config.calculate_contract_id();
+    let tx_id = wallet.deploy_contract(contract_config).await.unwrap();
+    let deployment = wallet
+        .client
+        .transaction(&tx_id)
+        .await
+        .unwrap()
+        .expect("the deployment should be committed")
+        .transaction;
+    let deployment_inputs = deployment.as_create().unwrap().inputs().len();
+
+    assert!(
+        deployment_inputs > transfer_inputs,
+        "the deployment spends {deployment_inputs} coins, the transfer spends {transfer_inputs}"
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn can_fetch_receipts_of_committed_transfer() {
+    let config = SuiteConfig::default();
+    let alice = Input::owner(&config.wallet_a.secret.public_key());
+    let bob = Input::owner(&config.wallet_b.secret.public_key());
+    let srv = setup_funded_node(TestContext::funded_chain_config(vec![(
+        alice,
+        AssetId::BASE,
+        2 * BASE_AMOUNT,
+    )]))
+    .await;
+    let wallet =
+        Wallet::new(config.wallet_a.secret, FuelClient::from(srv.bound_address)).await;
+
+    let result = wallet.transfer(bob, 100, None).await.unwrap();
+    assert!(result.success, "unexpected status {:?}", result.status);
+
+    let receipts = wallet
+        .receipts(&result.tx_id)
+        .await
+        .unwrap()
+        .expect("the transfer should be executed");
+    assert_eq!(receipts, result.receipts);
+    assert!(!receipts.is_empty());
+
+    let unknown = wallet.receipts(&[1; 32].into()).await.unwrap();
+    assert!(unknown.is_none());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn faucet_funds_several_recipients_in_one_transaction() {
+    const AMOUNT_EACH: u64 = 1_000;
+
+    let config = SuiteConfig::default();
+    let alice = Input::owner(&config.wallet_a.secret.public_key());
+    let srv = setup_funded_node(TestContext::funded_chain_config(vec![(
+        alice,
+        AssetId::BASE,
+        2 * BASE_AMOUNT,
+    )]))
+    .await;
+    let client = FuelClient::from(srv.bound_address);
+    let wallet = Wallet::new(config.wallet_a.secret, client.clone()).await;
+    let recipients = (1..=5u8).map(|i| Address::new([i; 32])).collect::<Vec<_>>();
+
+    let utxo_ids = wallet.faucet(&recipients, AMOUNT_EACH, None).await.unwrap();
+
+    assert_eq!(utxo_ids.len(), recipients.len());
+    let tx_id = *utxo_ids[0].tx_id();
+    for (recipient, utxo_id) in recipients.iter().zip(utxo_ids) {
+        assert_eq!(*utxo_id.tx_id(), tx_id, "all recipients are funded at once");
+        let coin = client
+            .coin(&utxo_id)
+            .await
+            .unwrap()
+            .expect("the recipient should receive the coin");
+        assert_eq!(coin.owner, *recipient);
+        assert_eq!(coin.amount, AMOUNT_EACH);
+        assert_eq!(client.balance(recipient, None).await.unwrap(), AMOUNT_EACH);
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn transfer_fee_paid_is_covered_by_the_reserved_amount() {
+    let config = SuiteConfig::default();
+    let alice = Input::owner(&config.wallet_a.secret.public_key());
+    let bob = Input::owner(&config.wallet_b.secret.public_key());
+    let mut chain_config =
+        TestContext::funded_chain_config(vec![
+            (alice, AssetId::BASE, 10 * BASE_AMOUNT);
+            2
+        ]);
+    // each unit of gas costs a noticeable fee
+    chain_config
+        .consensus_parameters
+        .fee_params
+        .gas_price_factor = 1;
+    let mut node_config = Config::local_node();
+    node_config.chain_conf = chain_config.clone();
+    node_config.txpool.chain_config = chain_config;
+    node_config.txpool.min_gas_price = 2;
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let wallet = Wallet::new(config.wallet_a.secret, client.clone()).await;
+    let balance_before = client.balance(&alice, None).await.unwrap();
+
+    let result = wallet.transfer(bob, 100, None).await.unwrap();
+
+    assert!(result.success, "unexpected status {:?}", result.status);
+    assert!(result.fee_paid > 0, "the fee is not charged");
+    let balance_after = client.balance(&alice, None).await.unwrap();
+    assert_eq!(balance_before - balance_after, 100 + result.fee_paid);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn owns_coin_follows_the_cursor_with_tiny_pages() {
+    let config = SuiteConfig::default();
+    let alice = Input::owner(&config.wallet_a.secret.public_key());
+    let srv = setup_funded_node(TestContext::funded_chain_config(vec![
+        (alice, AssetId::BASE, BASE_AMOUNT),
+        (alice, AssetId::BASE, BASE_AMOUNT),
+        (alice, AssetId::BASE, BASE_AMOUNT),
+        (alice, AssetId::BASE, BASE_AMOUNT),
+    ]))
+    .await;
+    let client = FuelClient::from(srv.bound_address);
+    let wallet = Wallet::new(config.wallet_a.secret, client.clone())
+        .await
+        .with_page_size(1);
+
+    let coins = client
+        .coins(
+            &alice,
+            None,
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap()
+        .results;
+    assert_eq!(coins.len(), 4);
+    for coin in coins {
+        assert!(wallet.owns_coin(coin.utxo_id).await.unwrap());
+    }
+    let unknown = UtxoId::new([1; 32].into(), 0);
+    assert!(!wallet.owns_coin(unknown).await.unwrap());
+}
+
+async fn setup_funded_node(chain_config: ChainConfig) -> FuelService {
+    let mut config = Config::local_node();
+    config.chain_conf = chain_config.clone();
+    config.txpool.chain_config = chain_config;
+    FuelService::new_node(config).await.unwrap()
+}
+
 fn generate_config_file(endpoint: String) -> TestConfig {
     // setup config for test env
     let config = SuiteConfig {
@@ -16,6 +16,9 @@ lazy_static::lazy_static! {
     pub static ref DEFAULT_DB_PATH: PathBuf = dirs::home_dir().unwrap().join(".fuel").join("db");
 }
 
+pub mod check_db;
+pub mod db;
+pub mod replay;
 pub mod run;
 pub mod snapshot;
 
@@ -36,6 +39,9 @@ pub struct Opt {
 pub enum Fuel {
     Run(run::Command),
     Snapshot(snapshot::Command),
+    Replay(replay::Command),
+    CheckDb(check_db::Command),
+    Db(db::Command),
 }
 
 pub const LOG_FILTER: &str = "RUST_LOG";
@@ -100,6 +106,9 @@ pub async fn run_cli() -> anyhow::Result<()> {
         Ok(opt) => match opt.command {
             Fuel::Run(command) => run::exec(command).await,
             Fuel::Snapshot(command) => snapshot::exec(command).await,
+            Fuel::Replay(command) => replay::exec(command).await,
+            Fuel::CheckDb(command) => check_db::exec(command).await,
+            Fuel::Db(command) => db::exec(command).await,
         },
         Err(e) => {
             // Prints the error and exits.
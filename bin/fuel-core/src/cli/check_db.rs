@@ -0,0 +1,70 @@
+use crate::cli::DEFAULT_DB_PATH;
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Check the internal consistency of the database: the blocks, the transactions,
+/// the receipts, and the indexes of the coins and the messages.
+/// The node must be stopped during the check.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+    )]
+    database_path: PathBuf,
+}
+
+#[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "Rocksdb must be enabled to use the database at {}",
+        command.database_path.display()
+    ))
+}
+
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    use crate::cli::init_logging;
+    use anyhow::Context;
+    use fuel_core::database::Database;
+    use std::sync::Arc;
+    init_logging().await?;
+    let path = command.database_path;
+    let data_source =
+        fuel_core::state::rocks_db::RocksDb::default_open(&path, None).context(
+            format!("failed to open database at path {}", path.display()),
+        )?;
+    let db = Database::new(Arc::new(data_source));
+
+    let report = db.check_integrity()?;
+    println!(
+        "Checked {} blocks, {} transactions, {} coins, and {} messages",
+        report.blocks, report.transactions, report.coins, report.messages
+    );
+    for inconsistency in report.inconsistencies.iter() {
+        let kind = if inconsistency.is_repairable() {
+            "repairable"
+        } else {
+            "unrepairable"
+        };
+        println!("[{kind}] {inconsistency}");
+    }
+
+    if report.is_consistent() {
+        println!("The database is consistent");
+        Ok(())
+    } else if report.is_repairable() {
+        Err(anyhow::anyhow!(
+            "Found {} repairable inconsistencies",
+            report.inconsistencies.len()
+        ))
+    } else {
+        Err(anyhow::anyhow!(
+            "Found {} inconsistencies, some of them are unrepairable",
+            report.inconsistencies.len()
+        ))
+    }
+}
@@ -0,0 +1,68 @@
+use crate::cli::DEFAULT_DB_PATH;
+use clap::{
+    Parser,
+    Subcommand,
+};
+use fuel_core::database::Column;
+use std::path::PathBuf;
+
+/// The maintenance of the database. The node must be stopped during the maintenance.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+    )]
+    database_path: PathBuf,
+
+    /// The sub-command of the database operation.
+    #[command(subcommand)]
+    subcommand: SubCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum SubCommands {
+    /// Compacts the database to reclaim the space of the removed entries.
+    Compact {
+        /// The compacted column, e.g. `receipts`. All columns are compacted if unset.
+        #[clap(long = "column")]
+        column: Option<Column>,
+    },
+}
+
+#[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "Rocksdb must be enabled to use the database at {}",
+        command.database_path.display()
+    ))
+}
+
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    use crate::cli::init_logging;
+    use anyhow::Context;
+    use fuel_core::database::Database;
+    use std::sync::Arc;
+    init_logging().await?;
+    let path = command.database_path;
+    let data_source =
+        fuel_core::state::rocks_db::RocksDb::default_open(&path, None).context(
+            format!("failed to open database at path {}", path.display()),
+        )?;
+    let db = Database::new(Arc::new(data_source));
+
+    match command.subcommand {
+        SubCommands::Compact { column } => {
+            db.compact(column)?;
+            match column {
+                Some(column) => println!("Compacted the column {column:?}"),
+                None => println!("Compacted the database"),
+            }
+        }
+    }
+    Ok(())
+}
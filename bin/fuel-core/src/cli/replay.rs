@@ -0,0 +1,123 @@
+use crate::cli::DEFAULT_DB_PATH;
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Re-execute the committed blocks and compare the results with the stored ones.
+/// The historical state of the blocks must be available, so the node should run
+/// in the archive mode.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+    )]
+    database_path: PathBuf,
+
+    /// Specify either an alias to a built-in configuration or filepath to a JSON file.
+    #[clap(name = "CHAIN_CONFIG", long = "chain", default_value = "local_testnet")]
+    chain_config: String,
+
+    /// The height of the first block to replay.
+    #[clap(long = "from")]
+    from: u32,
+
+    /// The height of the last block to replay. The latest block by default.
+    #[clap(long = "to")]
+    to: Option<u32>,
+
+    /// Validate the UTXOs of the transactions, the same as the node does.
+    #[clap(long = "utxo-validation")]
+    utxo_validation: bool,
+}
+
+#[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "Rocksdb must be enabled to use the database at {}",
+        command.database_path.display()
+    ))
+}
+
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    use crate::cli::init_logging;
+    use anyhow::Context;
+    use fuel_core::{
+        chain_config::ChainConfig,
+        database::Database,
+        executor::{
+            Config,
+            Divergence,
+            Executor,
+            ExecutorCache,
+        },
+    };
+    use std::sync::Arc;
+    init_logging().await?;
+    let path = command.database_path;
+    let data_source =
+        fuel_core::state::rocks_db::RocksDb::default_open(&path, None).context(
+            format!("failed to open database at path {}", path.display()),
+        )?;
+    let db = Database::new(Arc::new(data_source));
+    let chain_config: ChainConfig = command.chain_config.parse()?;
+
+    let to = match command.to {
+        Some(to) => to.into(),
+        None => db.latest_height()?,
+    };
+    let config = Config {
+        consensus_parameters: chain_config.consensus_parameters,
+        utxo_validation_default: command.utxo_validation,
+        ..Default::default()
+    };
+    let executor = Executor {
+        database: db.clone(),
+        relayer: db,
+        cache: Arc::new(ExecutorCache::new(&config)),
+        config: Arc::new(config),
+    };
+    let replayed = executor.replay(command.from.into(), to)?;
+
+    let mut diverged = 0;
+    for block in replayed.iter() {
+        if block.divergences.is_empty() {
+            tracing::info!("The block {} matches the stored one", block.height);
+            continue
+        }
+        diverged += 1;
+        for divergence in block.divergences.iter() {
+            match divergence {
+                Divergence::InvalidBlock(error) => {
+                    tracing::error!("The block {} is invalid: {}", block.height, error);
+                }
+                Divergence::Receipts {
+                    tx_id,
+                    expected,
+                    actual,
+                } => {
+                    tracing::error!(
+                        "The receipts of the transaction {} in the block {} differ: \
+                        expected {:?}, actual {:?}",
+                        tx_id,
+                        block.height,
+                        expected,
+                        actual
+                    );
+                }
+            }
+        }
+    }
+
+    if diverged > 0 {
+        return Err(anyhow::anyhow!(
+            "{} of {} replayed blocks diverged",
+            diverged,
+            replayed.len()
+        ))
+    }
+    Ok(())
+}
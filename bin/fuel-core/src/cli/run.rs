@@ -2,7 +2,12 @@
 use crate::{
     cli::{
         init_logging,
-        run::consensus::PoATriggerArgs,
+        run::{
+            consensus::PoATriggerArgs,
+            producer::TxSelectionArg,
+            rocksdb::RocksDbArgs,
+            snapshot_sync::SnapshotSyncArgs,
+        },
         DEFAULT_DB_PATH,
     },
     FuelService,
@@ -17,19 +22,43 @@ use fuel_core::{
         default_consensus_dev_key,
         ChainConfig,
     },
-    producer::Config as ProducerConfig,
+    executor::Config as ExecutorConfig,
+    fuel_core_graphql_api::{
+        auth::{
+            ApiRole,
+            ApiTokens,
+        },
+        QueryLimits,
+        TlsConfig,
+    },
+    importer::Config as ImporterConfig,
+    producer::{
+        Config as ProducerConfig,
+        GasPriceConfig,
+    },
     service::{
-        config::Trigger,
+        config::{
+            PruningConfig,
+            RemoteSigner,
+            TieringConfig,
+            Trigger,
+        },
         Config,
         DbType,
         RelayerVerifierConfig,
         ServiceTrait,
         VMConfig,
     },
-    txpool::Config as TxPoolConfig,
+    txpool::{
+        admission::AccessList,
+        Config as TxPoolConfig,
+    },
     types::{
         blockchain::primitives::SecretKeyWrapper,
-        fuel_tx::Address,
+        fuel_tx::{
+            Address,
+            ContractId,
+        },
         fuel_vm::SecretKey,
         secrecy::{
             ExposeSecret,
@@ -58,8 +87,11 @@ const DEFAULT_DATABASE_CACHE_SIZE: usize = 1024 * 1024 * 1024;
 mod p2p;
 
 mod consensus;
+mod producer;
 #[cfg(feature = "relayer")]
 mod relayer;
+mod rocksdb;
+mod snapshot_sync;
 
 /// Run the Fuel client node locally.
 #[derive(Debug, Clone, Parser)]
@@ -100,6 +132,9 @@ pub struct Command {
     )]
     pub database_type: DbType,
 
+    #[clap(flatten)]
+    pub rocksdb_args: RocksDbArgs,
+
     /// Specify either an alias to a built-in configuration or filepath to a JSON file.
     #[arg(
         name = "CHAIN_CONFIG",
@@ -109,14 +144,54 @@ pub struct Command {
     )]
     pub chain_config: String,
 
+    #[clap(flatten)]
+    pub snapshot_sync_args: SnapshotSyncArgs,
+
     /// Allows GraphQL Endpoints to arbitrarily advanced blocks. Should be used for local development only
     #[arg(long = "manual-blocks-enabled", env)]
     pub manual_blocks_enabled: bool,
 
+    /// Allows GraphQL endpoints to disconnect and ban peers. Should be exposed to the node operator only
+    #[arg(long = "peer-admin-enabled", env)]
+    pub peer_admin_enabled: bool,
+
+    /// Allows GraphQL endpoints to pause and resume the block production. Should be exposed to the node operator only
+    #[arg(long = "production-admin-enabled", env)]
+    pub production_admin_enabled: bool,
+
+    /// Allows GraphQL endpoints to trigger the compaction of the database. Should be exposed to the node operator only
+    #[arg(long = "database-admin-enabled", env)]
+    pub database_admin_enabled: bool,
+
+    /// The directory where GraphQL endpoints may create the checkpoints of the database. It must be
+    /// on the same file system as the database. The checkpoints can't be created if unset
+    #[arg(long = "database-checkpoint-dir", env)]
+    pub database_checkpoint_dir: Option<PathBuf>,
+
     /// Enable logging of backtraces from vm errors
     #[arg(long = "vm-backtrace", env)]
     pub vm_backtrace: bool,
 
+    /// Allows GraphQL endpoints to trace the execution of the transactions. The tracing is expensive
+    #[arg(long = "vm-tracing", env)]
+    pub vm_tracing: bool,
+
+    /// The number of threads executing the independent transactions of the block
+    /// in parallel. `0` and `1` mean the sequential execution.
+    #[cfg(feature = "parallel-executor")]
+    #[arg(long = "parallel-executor-workers", default_value = "0", env)]
+    pub parallel_executor_workers: usize,
+
+    /// The memory budget in bytes of the contract code cached by the executor.
+    /// `0` disables the cache.
+    #[arg(long = "contract-code-cache-size", default_value = "67108864", env)]
+    pub contract_code_cache_size: usize,
+
+    /// The number of transactions with the verified predicates cached by the executor.
+    /// `0` disables the cache.
+    #[arg(long = "predicate-cache-size", default_value = "10000", env)]
+    pub predicate_cache_size: usize,
+
     /// Enable full utxo stateful validation
     /// disabled by default until downstream consumers stabilize
     #[arg(long = "utxo-validation", env)]
@@ -131,6 +206,15 @@ pub struct Command {
     #[arg(long = "consensus-key", env)]
     pub consensus_key: Option<String>,
 
+    /// The JSON-RPC endpoint of the remote signer, e.g. a signing sidecar or an HSM.
+    /// If set, the produced blocks are signed remotely instead of with the consensus key.
+    #[arg(long = "remote-signer-url", requires = "remote_signer_address", env)]
+    pub remote_signer_url: Option<String>,
+
+    /// The address of the key held by the remote signer.
+    #[arg(long = "remote-signer-address", requires = "remote_signer_url", env)]
+    pub remote_signer_address: Option<String>,
+
     /// A new block is produced instantly when transactions are available.
     #[clap(flatten)]
     pub poa_trigger: PoATriggerArgs,
@@ -146,6 +230,38 @@ pub struct Command {
     #[arg(long = "coinbase-recipient", env)]
     pub coinbase_recipient: Option<String>,
 
+    /// The order in which the transactions from the pool are included into the block.
+    #[arg(
+        long = "tx-selection",
+        default_value = "highest-gas-price",
+        value_enum,
+        env
+    )]
+    pub tx_selection: TxSelectionArg,
+
+    /// Adjusts the minimum gas price to the fullness of the recent blocks,
+    /// similar to the base fee of EIP-1559. The `min-gas-price` is the lower bound.
+    #[arg(long = "dynamic-gas-price", env)]
+    pub dynamic_gas_price: bool,
+
+    /// The fullness of the block in percent that keeps the dynamic gas price the same.
+    #[arg(
+        long = "gas-price-target-block-fullness",
+        default_value = "50",
+        requires = "dynamic_gas_price",
+        env
+    )]
+    pub gas_price_target_block_fullness: u64,
+
+    /// The dynamic gas price changes by at most `1 / denominator` per block.
+    #[arg(
+        long = "gas-price-change-denominator",
+        default_value = "8",
+        requires = "dynamic_gas_price",
+        env
+    )]
+    pub gas_price_change_denominator: u64,
+
     #[cfg(feature = "relayer")]
     #[clap(flatten)]
     pub relayer_args: relayer::RelayerArgs,
@@ -175,6 +291,11 @@ pub struct Command {
     #[clap(long = "tx-max-number", default_value = "4064", env)]
     pub tx_max_number: usize,
 
+    /// The max number of transactions spending inputs of the same owner that the `TxPool`
+    /// can simultaneously store.
+    #[clap(long = "tx-max-per-owner", default_value = "4064", env)]
+    pub tx_max_per_owner: usize,
+
     /// The max depth of the dependent transactions that supported by the `TxPool`.
     #[clap(long = "tx-max-depth", default_value = "10", env)]
     pub tx_max_depth: usize,
@@ -183,6 +304,99 @@ pub struct Command {
     #[clap(long = "tx-number-active-subscriptions", default_value = "4064", env)]
     pub tx_number_active_subscriptions: usize,
 
+    /// The minimum gas price increase, in percent, required for a transaction to replace
+    /// the transaction spending the same inputs inside of the `TxPool`.
+    #[clap(long = "tx-replacement-gas-price-bump", default_value = "0", env)]
+    pub tx_replacement_gas_price_bump: u64,
+
+    /// The file where pending transactions of the `TxPool` are saved on shutdown and
+    /// restored from on startup. Transactions are not persisted if it is not set.
+    #[clap(long = "tx-pool-journal", value_parser, env)]
+    pub tx_pool_journal: Option<PathBuf>,
+
+    /// The max number of transactions per second that the `TxPool` accepts via gossip
+    /// from a single peer. The peers exceeding the limit are penalized.
+    #[clap(long = "tx-max-gossiped-per-peer", default_value = "100", env)]
+    pub tx_max_gossiped_per_peer: usize,
+
+    /// The contracts that transactions inside of the `TxPool` are allowed to use.
+    /// All contracts are allowed if it is not set.
+    #[clap(long = "tx-allowed-contracts", value_delimiter = ',', env)]
+    pub tx_allowed_contracts: Vec<ContractId>,
+
+    /// The contracts that transactions inside of the `TxPool` are not allowed to use.
+    #[clap(long = "tx-denied-contracts", value_delimiter = ',', env)]
+    pub tx_denied_contracts: Vec<ContractId>,
+
+    /// The owners whose coins and messages transactions inside of the `TxPool` are
+    /// allowed to spend. All owners are allowed if it is not set.
+    #[clap(long = "tx-allowed-owners", value_delimiter = ',', env)]
+    pub tx_allowed_owners: Vec<Address>,
+
+    /// The owners whose coins and messages transactions inside of the `TxPool` are
+    /// not allowed to spend.
+    #[clap(long = "tx-denied-owners", value_delimiter = ',', env)]
+    pub tx_denied_owners: Vec<Address>,
+
+    /// Sync and verify only the headers of the blocks without executing them.
+    /// The block production is disabled in this mode.
+    #[arg(long = "light-client", env)]
+    pub light_client: bool,
+
+    /// The max number of the latest blocks that can be rolled back
+    /// to switch to a competing chain.
+    #[clap(long = "max-reorg-depth", default_value = "10", env)]
+    pub max_reorg_depth: u32,
+
+    /// Keep the historical state of all blocks, so the state queries can be answered
+    /// at any block height.
+    #[arg(long = "archive", env)]
+    pub archive: bool,
+
+    /// Compute the state diff of every imported block and serve it via GraphQL.
+    #[arg(long = "state-diff", env)]
+    pub state_diff: bool,
+
+    /// Append the state diffs of the imported blocks to the file as JSON lines.
+    /// Implies `--state-diff`.
+    #[arg(long = "state-diff-sink", value_parser, env)]
+    pub state_diff_sink: Option<PathBuf>,
+
+    /// Index the receipts of the imported blocks by the contracts that emitted them
+    /// and serve them via GraphQL.
+    #[arg(long = "index-receipts", env)]
+    pub index_receipts: bool,
+
+    /// Index the supply and the holders of the assets and serve them via GraphQL.
+    /// The index is built incrementally, so it should be enabled since the genesis
+    /// or the checkpoint of the database.
+    #[arg(long = "index-assets", env)]
+    pub index_assets: bool,
+
+    /// Keep the history of only the latest N blocks: the rollback changes, the state
+    /// diffs, and the receipts of the older blocks are pruned in the background.
+    /// The blocks within `--max-reorg-depth` are never pruned.
+    #[arg(long = "history-retention", env)]
+    pub history_retention: Option<u32>,
+
+    /// The maximum number of blocks pruned at once.
+    #[arg(long = "pruning-batch-size", default_value = "100", env)]
+    pub pruning_batch_size: u32,
+
+    /// Move the blocks, transactions, and receipts older than `--cold-storage-threshold`
+    /// blocks to the separate database at the path, e.g. on the cheap disk. They are
+    /// still served to the historical queries.
+    #[arg(long = "cold-storage-path", value_parser, env)]
+    pub cold_storage_path: Option<PathBuf>,
+
+    /// The number of the latest blocks kept in the main database.
+    #[arg(long = "cold-storage-threshold", default_value = "100000", env)]
+    pub cold_storage_threshold: u32,
+
+    /// The maximum number of blocks moved to the cold storage at once.
+    #[arg(long = "cold-storage-batch-size", default_value = "100", env)]
+    pub cold_storage_batch_size: u32,
+
     /// The number of reserved peers to connect to before starting to sync.
     #[clap(long = "min-connected-reserved-peers", default_value = "0", env)]
     pub min_connected_reserved_peers: usize,
@@ -194,10 +408,58 @@ pub struct Command {
     /// Time to wait after submitting a query before debug info will be logged about query.
     #[clap(long = "query-log-threshold-time", default_value = "2s", env)]
     pub query_log_threshold_time: humantime::Duration,
+
+    /// The maximum depth of the nested fields of the GraphQL queries.
+    #[clap(long = "graphql-max-depth", default_value = "16", env)]
+    pub graphql_max_depth: usize,
+
+    /// The maximum complexity of the GraphQL queries. Every field costs one, and the
+    /// fields of the connections cost as much as the size of the requested page.
+    #[clap(long = "graphql-max-complexity", default_value = "80000", env)]
+    pub graphql_max_complexity: usize,
+
+    /// The timeout of the execution of the GraphQL queries and mutations.
+    /// The subscriptions aren't limited.
+    #[clap(long = "graphql-request-timeout", default_value = "30s", env)]
+    pub graphql_request_timeout: humantime::Duration,
+
+    /// The maximum number of the blocks between the latest block and the height of
+    /// the historical state requested by the GraphQL queries.
+    #[clap(long = "graphql-max-historical-depth", default_value = "1000", env)]
+    pub graphql_max_historical_depth: u32,
+
+    /// The tokens granting the access to the restricted GraphQL endpoints, as
+    /// `<role>:<token>`. The roles are `read-only`, `submit-tx`, and `admin`. The requests
+    /// pass the token as `Authorization: Bearer <token>`, and the requests without it
+    /// are read-only. All endpoints are public if no token is set.
+    #[clap(
+        long = "api-token",
+        value_parser = parse_api_token,
+        value_delimiter = ',',
+        env
+    )]
+    pub api_tokens: Vec<(ApiRole, String)>,
+
+    /// The PEM-encoded certificate chain to serve the GraphQL API over HTTPS.
+    #[arg(long = "tls-cert", requires = "tls_key", env)]
+    pub tls_cert: Option<PathBuf>,
+
+    /// The PEM-encoded private key of the TLS certificate.
+    #[arg(long = "tls-key", requires = "tls_cert", env)]
+    pub tls_key: Option<PathBuf>,
+
+    /// The interval of checking the TLS certificate and key files for the rotation.
+    #[clap(long = "tls-reload-interval", default_value = "1m", env)]
+    pub tls_reload_interval: humantime::Duration,
+
+    /// The origins allowed to make the cross-origin requests to the GraphQL API,
+    /// e.g. `https://app.example.com`. All origins are allowed if none is set.
+    #[clap(long = "cors-allowed-origins", value_delimiter = ',', env)]
+    pub cors_allowed_origins: Vec<String>,
 }
 
 impl Command {
-    pub fn get_config(self) -> anyhow::Result<Config> {
+    pub async fn get_config(self) -> anyhow::Result<Config> {
         let Command {
             ip,
             port,
@@ -205,15 +467,32 @@ impl Command {
             max_database_cache_size,
             database_path,
             database_type,
+            rocksdb_args,
             chain_config,
+            snapshot_sync_args,
             vm_backtrace,
+            vm_tracing,
+            #[cfg(feature = "parallel-executor")]
+            parallel_executor_workers,
+            contract_code_cache_size,
+            predicate_cache_size,
             manual_blocks_enabled,
+            peer_admin_enabled,
+            production_admin_enabled,
+            database_admin_enabled,
+            database_checkpoint_dir,
             utxo_validation,
             min_gas_price,
             consensus_key,
+            remote_signer_url,
+            remote_signer_address,
             poa_trigger,
             consensus_dev_key,
             coinbase_recipient,
+            tx_selection,
+            dynamic_gas_price,
+            gas_price_target_block_fullness,
+            gas_price_change_denominator,
             #[cfg(feature = "relayer")]
             relayer_args,
             #[cfg(feature = "p2p")]
@@ -225,31 +504,72 @@ impl Command {
             max_wait_time,
             tx_pool_ttl,
             tx_max_number,
+            tx_max_per_owner,
             tx_max_depth,
             tx_number_active_subscriptions,
+            tx_replacement_gas_price_bump,
+            tx_pool_journal,
+            tx_max_gossiped_per_peer,
+            tx_allowed_contracts,
+            tx_denied_contracts,
+            tx_allowed_owners,
+            tx_denied_owners,
+            light_client,
+            max_reorg_depth,
+            archive,
+            state_diff,
+            state_diff_sink,
+            index_receipts,
+            index_assets,
+            history_retention,
+            pruning_batch_size,
+            cold_storage_path,
+            cold_storage_threshold,
+            cold_storage_batch_size,
             min_connected_reserved_peers,
             time_until_synced,
             query_log_threshold_time,
+            graphql_max_depth,
+            graphql_max_complexity,
+            graphql_request_timeout,
+            graphql_max_historical_depth,
+            api_tokens,
+            tls_cert,
+            tls_key,
+            tls_reload_interval,
+            cors_allowed_origins,
         } = self;
 
         let addr = net::SocketAddr::new(ip, port);
 
         let chain_conf: ChainConfig = chain_config.as_str().parse()?;
+        let checkpoint = snapshot_sync_args.into_config().await?;
 
         #[cfg(feature = "p2p")]
         let p2p_cfg = p2p_args.into_config(metrics)?;
 
         let trigger: Trigger = poa_trigger.into();
 
-        if trigger != Trigger::Never {
+        if light_client && trigger != Trigger::Never {
+            warn!("Block production is disabled for the light client");
+        } else if trigger != Trigger::Never {
             info!("Block production mode: {:?}", &trigger);
         } else {
             info!("Block production disabled");
         }
 
+        let remote_signer = match (remote_signer_url, remote_signer_address) {
+            (Some(url), Some(address)) => {
+                let address =
+                    Address::from_str(address.as_str()).map_err(|err| anyhow!(err))?;
+                Some(RemoteSigner::new(url, address))
+            }
+            _ => None,
+        };
+
         // if consensus key is not configured, fallback to dev consensus key
         let consensus_key = load_consensus_key(consensus_key)?.or_else(|| {
-            if consensus_dev_key && trigger != Trigger::Never {
+            if consensus_dev_key && trigger != Trigger::Never && remote_signer.is_none() {
                 let key = default_consensus_dev_key();
                 warn!(
                     "Fuel Core is using an insecure test key for consensus. Public key: {}",
@@ -262,12 +582,16 @@ impl Command {
             }
         });
 
-        if consensus_key.is_some() && trigger == Trigger::Never {
+        if (consensus_key.is_some() || remote_signer.is_some())
+            && trigger == Trigger::Never
+        {
             warn!("Consensus key configured but block production is disabled!")
         }
 
         let coinbase_recipient = if let Some(coinbase_recipient) = coinbase_recipient {
             Address::from_str(coinbase_recipient.as_str()).map_err(|err| anyhow!(err))?
+        } else if let Some(remote_signer) = &remote_signer {
+            remote_signer.address()
         } else {
             consensus_key
                 .as_ref()
@@ -284,20 +608,31 @@ impl Command {
             max_wait_time: max_wait_time.into(),
         };
 
+        let compaction = rocksdb_args.compaction_config();
+
         Ok(Config {
             addr,
             max_database_cache_size,
             database_path,
             database_type,
+            rocksdb: rocksdb_args.into_config(),
             chain_conf: chain_conf.clone(),
+            checkpoint,
             utxo_validation,
             manual_blocks_enabled,
+            peer_admin_enabled,
+            production_admin_enabled,
+            database_admin_enabled,
+            database_checkpoint_dir,
             block_production: trigger,
+            light_client,
             vm: VMConfig {
                 backtrace: vm_backtrace,
+                tracing: vm_tracing,
             },
             txpool: TxPoolConfig::new(
                 tx_max_number,
+                tx_max_per_owner,
                 tx_max_depth,
                 chain_conf,
                 min_gas_price,
@@ -305,33 +640,89 @@ impl Command {
                 metrics,
                 tx_pool_ttl.into(),
                 tx_number_active_subscriptions,
+                tx_replacement_gas_price_bump,
+                tx_pool_journal,
+                tx_max_gossiped_per_peer,
+                AccessList::new(tx_allowed_contracts, tx_denied_contracts),
+                AccessList::new(tx_allowed_owners, tx_denied_owners),
             ),
             block_producer: ProducerConfig {
                 utxo_validation,
                 coinbase_recipient,
                 metrics,
+                tx_selection: tx_selection.into(),
+                dynamic_gas_price: dynamic_gas_price.then(|| GasPriceConfig {
+                    target_block_fullness: gas_price_target_block_fullness,
+                    max_change_denominator: gas_price_change_denominator,
+                    ..Default::default()
+                }),
+            },
+            block_executor: ExecutorConfig {
+                #[cfg(feature = "parallel-executor")]
+                parallel_workers: parallel_executor_workers,
+                contract_code_cache_size,
+                predicate_cache_size,
+                ..Default::default()
+            },
+            block_importer: ImporterConfig {
+                max_reorg_depth,
+                archive,
+                state_diff: state_diff || state_diff_sink.is_some(),
+                index_receipts,
+                index_assets,
+                ..Default::default()
             },
-            block_executor: Default::default(),
-            block_importer: Default::default(),
             #[cfg(feature = "relayer")]
             relayer: relayer_args.into(),
             #[cfg(feature = "p2p")]
             p2p: p2p_cfg,
             #[cfg(feature = "p2p")]
-            sync: sync_args.into(),
+            sync: fuel_core::sync::Config {
+                max_reorg_depth,
+                ..fuel_core::sync::Config::from(sync_args)
+            },
             consensus_key,
+            remote_signer,
             name,
             verifier,
             min_connected_reserved_peers,
             time_until_synced: time_until_synced.into(),
             query_log_threshold_time: query_log_threshold_time.into(),
+            query_limits: QueryLimits {
+                max_depth: graphql_max_depth,
+                max_complexity: graphql_max_complexity,
+                request_timeout: graphql_request_timeout.into(),
+                max_historical_depth: graphql_max_historical_depth,
+            },
+            api_tokens: ApiTokens::new(
+                api_tokens.into_iter().map(|(role, token)| (token, role)),
+            ),
+            tls: tls_cert
+                .zip(tls_key)
+                .map(|(cert_path, key_path)| TlsConfig {
+                    cert_path,
+                    key_path,
+                    reload_interval: tls_reload_interval.into(),
+                }),
+            cors_allowed_origins,
+            state_diff_sink,
+            pruning: history_retention.map(|retention| PruningConfig {
+                retention,
+                batch_size: pruning_batch_size,
+            }),
+            tiering: cold_storage_path.map(|path| TieringConfig {
+                path,
+                threshold: cold_storage_threshold,
+                batch_size: cold_storage_batch_size,
+            }),
+            compaction,
         })
     }
 }
 
 pub async fn exec(command: Command) -> anyhow::Result<()> {
     init_logging().await?;
-    let config = command.get_config()?;
+    let config = command.get_config().await?;
     let network_name = {
         #[cfg(feature = "p2p")]
         {
@@ -362,6 +753,21 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn parse_api_token(value: &str) -> Result<(ApiRole, String), String> {
+    let (role, token) = value
+        .split_once(':')
+        .ok_or_else(|| "Expected `<role>:<token>`".to_string())?;
+    let role = role
+        .trim()
+        .parse()
+        .map_err(|_| format!("Unknown role `{role}`"))?;
+    let token = token.trim();
+    if token.is_empty() {
+        return Err("The token is empty".to_string())
+    }
+    Ok((role, token.to_string()))
+}
+
 // Attempt to load the consensus key from cli arg first, otherwise check the env.
 fn load_consensus_key(
     cli_arg: Option<String>,
@@ -13,6 +13,8 @@ pub struct PoATriggerArgs {
     instant: Instant,
     #[clap(flatten)]
     interval: Interval,
+    #[clap(flatten)]
+    hybrid: Hybrid,
 }
 
 // Convert from arg struct to PoATrigger enum
@@ -21,10 +23,24 @@ impl From<PoATriggerArgs> for PoATrigger {
         match value {
             PoATriggerArgs {
                 interval: Interval { period: Some(p) },
+                hybrid:
+                    Hybrid {
+                        max_gas: None,
+                        max_bytes: None,
+                    },
                 ..
             } => PoATrigger::Interval {
                 block_time: p.into(),
             },
+            PoATriggerArgs {
+                interval: Interval { period: Some(p) },
+                hybrid: Hybrid { max_gas, max_bytes },
+                ..
+            } => PoATrigger::Hybrid {
+                block_time: p.into(),
+                max_gas: max_gas.unwrap_or(u64::MAX),
+                max_bytes: max_bytes.unwrap_or(usize::MAX),
+            },
             PoATriggerArgs { instant, .. } if instant.instant == Boolean::True => {
                 PoATrigger::Instant
             }
@@ -63,6 +79,18 @@ struct Interval {
     pub period: Option<Duration>,
 }
 
+#[derive(Debug, Clone, clap::Args)]
+struct Hybrid {
+    /// Hybrid trigger option. Requires the interval period.
+    /// Produces the block ahead of the interval once the pending txs consume this much gas.
+    #[clap(long = "poa-hybrid-max-gas", requires = "period", env)]
+    pub max_gas: Option<u64>,
+    /// Hybrid trigger option. Requires the interval period.
+    /// Produces the block ahead of the interval once the pending txs take this many bytes.
+    #[clap(long = "poa-hybrid-max-bytes", requires = "period", env)]
+    pub max_bytes: Option<usize>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -81,6 +109,9 @@ mod tests {
     #[test_case(&["", "--poa-instant=false"] => Ok(Trigger::Never); "never trigger if instant is explicitly disabled")]
     #[test_case(&["", "--poa-interval-period=1s"] => Ok(Trigger::Interval { block_time: StdDuration::from_secs(1)}); "uses interval mode if set")]
     #[test_case(&["", "--poa-instant=true", "--poa-interval-period=1s"] => Err(()); "can't set interval and instant at the same time")]
+    #[test_case(&["", "--poa-interval-period=1s", "--poa-hybrid-max-gas=100"] => Ok(Trigger::Hybrid { block_time: StdDuration::from_secs(1), max_gas: 100, max_bytes: usize::MAX }); "uses hybrid mode if gas limit is set")]
+    #[test_case(&["", "--poa-interval-period=1s", "--poa-hybrid-max-bytes=100"] => Ok(Trigger::Hybrid { block_time: StdDuration::from_secs(1), max_gas: u64::MAX, max_bytes: 100 }); "uses hybrid mode if bytes limit is set")]
+    #[test_case(&["", "--poa-hybrid-max-gas=100"] => Err(()); "can't set hybrid limits without interval")]
     fn parse(args: &[&str]) -> Result<Trigger, ()> {
         Command::try_parse_from(args)
             .map_err(|_| ())
@@ -1,16 +1,21 @@
 use anyhow::anyhow;
-use clap::Args;
+use clap::{
+    Args,
+    ValueEnum,
+};
 use fuel_core::{
     p2p::{
         config::{
             convert_to_libp2p_keypair,
             Config,
             NotInitialized,
+            TxPropagation,
             MAX_RESPONSE_SIZE,
         },
         gossipsub_config::default_gossipsub_builder,
         HeartbeatConfig,
         Multiaddr,
+        PeerId,
     },
     types::{
         fuel_crypto,
@@ -59,6 +64,29 @@ pub struct P2PArgs {
     #[clap(long = "peering-port", default_value = "30333", env)]
     pub peering_port: u16,
 
+    /// p2p network's UDP Port for the QUIC transport
+    #[clap(long = "quic-port", env)]
+    pub quic_port: Option<u16>,
+
+    /// Additional addresses to listen on. The transport is selected per address:
+    /// `/ip4/<ip>/tcp/<port>` for TCP and `/ip4/<ip>/udp/<port>/quic` for QUIC.
+    /// QUIC is disabled if neither `--quic-port` nor a QUIC address is set.
+    #[clap(long = "listen-addresses", value_delimiter = ',', env)]
+    pub listen_addresses: Vec<Multiaddr>,
+
+    /// Addresses of the relay nodes that reserve the circuits for this node, so the peers
+    /// can reach it behind the NAT. The relayed connections are upgraded to the direct
+    /// ones with the hole punching when it is possible.
+    /// They should contain PeerId within their `Multiaddr`
+    #[clap(long = "relay-nodes", value_delimiter = ',', env)]
+    pub relay_nodes: Vec<Multiaddr>,
+
+    /// Map the p2p ports on the UPnP IGD gateway of the local network, or on the NAT-PMP
+    /// gateway if the UPnP one is not found, and advertise the external addresses to the peers.
+    /// The NAT-PMP gateway is discovered only on Linux.
+    #[clap(long = "enable-port-mapping", env)]
+    pub enable_port_mapping: bool,
+
     /// Max Block size
     #[clap(long = "max-block-size", default_value = MAX_RESPONSE_SIZE_STR, env)]
     pub max_block_size: usize,
@@ -68,19 +96,34 @@ pub struct P2PArgs {
     #[clap(long = "bootstrap-nodes", value_delimiter = ',', env)]
     pub bootstrap_nodes: Vec<Multiaddr>,
 
+    /// Domain names whose `TXT` (`dnsaddr=<multiaddr>`) and `SRV` records are resolved
+    /// into the bootstrap nodes
+    #[clap(long = "bootstrap-dns-seeds", value_delimiter = ',', env)]
+    pub bootstrap_dns_seeds: Vec<String>,
+
+    /// How often the DNS seeds are resolved again, in seconds. Set to 0 to resolve only at startup
+    #[clap(long = "dns-seeds-refresh-interval", default_value = "3600", env)]
+    pub dns_seeds_refresh_interval: u64,
+
     /// Addresses of the reserved nodes
     /// They should contain PeerId within their `Multiaddr`
     #[clap(long = "reserved-nodes", value_delimiter = ',', env)]
     pub reserved_nodes: Vec<Multiaddr>,
 
     /// With this set to `true` you create a guarded node that is only ever connected to trusted, reserved nodes.    
-    #[clap(long = "reserved-nodes-only-mode", env)]
+    #[clap(long = "reserved-nodes-only-mode", alias = "reserved-nodes-only", env)]
     pub reserved_nodes_only_mode: bool,
 
     /// Allow nodes to be discoverable on the local network
     #[clap(long = "enable-mdns", env)]
     pub enable_mdns: bool,
 
+    /// Don't exchange the known peers with the connected peers.
+    /// By default, each new peer is asked for a sample of its peers,
+    /// which helps to discover the network without the DHT.
+    #[clap(long = "disable-peer-exchange", env)]
+    pub disable_peer_exchange: bool,
+
     /// Max number of unique peers connected
     /// This number should be at least number of `mesh_n` from `Gossipsub` configuration.
     /// The total number of connections will be `(max_peers_connected + reserved_nodes.len()) * max_connections_per_peer`
@@ -145,6 +188,18 @@ pub struct P2PArgs {
     #[clap(long = "max-transmit-size", default_value = MAX_RESPONSE_SIZE_STR, env)]
     pub max_transmit_size: usize,
 
+    /// Don't compress the gossiped transactions and blocks.
+    /// By default, they are compressed when all subscribed peers support the compression.
+    #[clap(long = "disable-gossip-compression", env)]
+    pub disable_gossip_compression: bool,
+
+    /// How the transactions are propagated through the network.
+    /// `full` gossips the whole transactions, `announce` gossips only their ids,
+    /// and peers request the bodies of the unknown ones.
+    /// All nodes of the network should use the same mode.
+    #[clap(long = "tx-propagation", default_value = "full", value_enum, env)]
+    pub tx_propagation: TxPropagationArg,
+
     /// Choose timeout for sent requests in RequestResponse protocol
     #[clap(long = "request-timeout", default_value = "20", env)]
     pub request_timeout: u64,
@@ -153,6 +208,16 @@ pub struct P2PArgs {
     #[clap(long = "connection-keep-alive", default_value = "20", env)]
     pub connection_keep_alive: u64,
 
+    /// Max number of the inbound requests of a single peer processed concurrently.
+    /// The requests above the limit are throttled and lower the reputation of the peer
+    #[clap(long = "max-concurrent-requests-per-peer", default_value = "20", env)]
+    pub max_concurrent_requests_per_peer: usize,
+
+    /// Max number of the inbound requests of a single peer per second.
+    /// The requests above the limit are throttled and lower the reputation of the peer
+    #[clap(long = "max-requests-per-second-per-peer", default_value = "100", env)]
+    pub max_requests_per_second_per_peer: usize,
+
     /// Sending of `BlockHeight` should not take longer than this duration, in seconds.
     #[clap(long = "heartbeat-send-duration", default_value = "2", env)]
     pub heartbeat_send_duration: u64,
@@ -176,6 +241,27 @@ pub struct SyncArgs {
     /// The maximum number of get transaction requests to make in a single batch.
     #[clap(long = "sync-max-get-txns", default_value = "10", env)]
     pub max_get_txns_requests: usize,
+    /// The number of headers requested from a single peer in one request.
+    #[clap(long = "sync-header-batch-size", default_value = "10", env)]
+    pub header_batch_size: u32,
+    /// The number of downloaded blocks buffered ahead of the execution.
+    #[clap(long = "sync-block-stream-buffer-size", default_value = "10", env)]
+    pub block_stream_buffer_size: usize,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum TxPropagationArg {
+    Full,
+    Announce,
+}
+
+impl From<TxPropagationArg> for TxPropagation {
+    fn from(value: TxPropagationArg) -> Self {
+        match value {
+            TxPropagationArg::Full => TxPropagation::Full,
+            TxPropagationArg::Announce => TxPropagation::Announce,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -208,6 +294,9 @@ impl From<SyncArgs> for fuel_core::sync::Config {
         Self {
             max_get_header_requests: value.max_get_header_requests,
             max_get_txns_requests: value.max_get_txns_requests,
+            header_batch_size: value.header_batch_size,
+            block_stream_buffer_size: value.block_stream_buffer_size,
+            ..Default::default()
         }
     }
 }
@@ -218,6 +307,19 @@ impl P2PArgs {
         metrics: bool,
     ) -> anyhow::Result<Option<Config<NotInitialized>>> {
         if self.enable_p2p {
+            for address in self.reserved_nodes.iter() {
+                if PeerId::try_from_multiaddr(address).is_none() {
+                    return Err(anyhow!(
+                        "the reserved node `{address}` doesn't contain the peer id"
+                    ))
+                }
+            }
+            if self.reserved_nodes_only_mode && self.reserved_nodes.is_empty() {
+                return Err(anyhow!(
+                    "`--reserved-nodes-only-mode` requires at least one reserved node"
+                ))
+            }
+
             let local_keypair = {
                 match self.keypair.expect("mandatory value") {
                     KeypairArg::Path(path) => {
@@ -253,6 +355,12 @@ impl P2PArgs {
                 Some(Duration::from_secs(self.random_walk))
             };
 
+            let dns_seeds_refresh_interval = if self.dns_seeds_refresh_interval == 0 {
+                None
+            } else {
+                Some(Duration::from_secs(self.dns_seeds_refresh_interval))
+            };
+
             let heartbeat_config = {
                 let send_duration = Duration::from_secs(self.heartbeat_send_duration);
                 let idle_duration = Duration::from_secs(self.heartbeat_idle_duration);
@@ -267,16 +375,24 @@ impl P2PArgs {
                 keypair: local_keypair,
                 network_name: self.network.expect("mandatory value"),
                 checksum: Default::default(),
+                chain_id: Default::default(),
                 address: self
                     .address
                     .unwrap_or_else(|| IpAddr::V4(Ipv4Addr::from([0, 0, 0, 0]))),
                 public_address: self.public_address,
                 tcp_port: self.peering_port,
+                quic_port: self.quic_port,
+                listen_addresses: self.listen_addresses,
+                relay_nodes: self.relay_nodes,
+                port_mapping: self.enable_port_mapping,
                 max_block_size: self.max_block_size,
                 bootstrap_nodes: self.bootstrap_nodes,
+                bootstrap_dns_seeds: self.bootstrap_dns_seeds,
+                dns_seeds_refresh_interval,
                 reserved_nodes: self.reserved_nodes,
                 reserved_nodes_only_mode: self.reserved_nodes_only_mode,
                 enable_mdns: self.enable_mdns,
+                peer_exchange: !self.disable_peer_exchange,
                 max_peers_connected: self.max_peers_connected,
                 max_connections_per_peer: self.max_connections_per_peer,
                 allow_private_addresses: self.allow_private_addresses,
@@ -285,11 +401,15 @@ impl P2PArgs {
                     self.connection_idle_timeout,
                 )),
                 gossipsub_config,
+                gossip_compression: !self.disable_gossip_compression,
+                tx_propagation: self.tx_propagation.into(),
                 heartbeat_config,
                 set_request_timeout: Duration::from_secs(self.request_timeout),
                 set_connection_keep_alive: Duration::from_secs(
                     self.connection_keep_alive,
                 ),
+                max_concurrent_requests_per_peer: self.max_concurrent_requests_per_peer,
+                max_requests_per_second_per_peer: self.max_requests_per_second_per_peer,
                 info_interval: Some(Duration::from_secs(self.info_interval)),
                 identify_interval: Some(Duration::from_secs(self.identify_interval)),
                 metrics,
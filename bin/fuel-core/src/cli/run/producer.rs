@@ -0,0 +1,19 @@
+use clap::ValueEnum;
+use fuel_core::producer::TxSelection;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum TxSelectionArg {
+    HighestGasPrice,
+    OldestFirst,
+    OwnerRoundRobin,
+}
+
+impl From<TxSelectionArg> for TxSelection {
+    fn from(value: TxSelectionArg) -> Self {
+        match value {
+            TxSelectionArg::HighestGasPrice => TxSelection::HighestGasPrice,
+            TxSelectionArg::OldestFirst => TxSelection::OldestFirst,
+            TxSelectionArg::OwnerRoundRobin => TxSelection::OwnerRoundRobin,
+        }
+    }
+}
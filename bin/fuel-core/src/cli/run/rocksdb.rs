@@ -0,0 +1,151 @@
+//! Clap configuration related to the tuning of the RocksDB
+
+use clap::Args;
+use fuel_core::{
+    database::Column,
+    service::config::CompactionConfig,
+    state::rocks_db_config::{
+        Compression,
+        RocksDbConfig,
+        RocksDbProfile,
+    },
+};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Args)]
+pub struct RocksDbArgs {
+    /// The preset of the RocksDB tuning. The options below override the preset.
+    #[arg(long = "db-profile", default_value = "default", value_enum, env)]
+    pub db_profile: RocksDbProfile,
+
+    /// The size of the block cache shared by all columns in bytes.
+    #[arg(long = "db-block-cache-size", env)]
+    pub db_block_cache_size: Option<usize>,
+
+    /// The size of the write buffer of each column in bytes.
+    #[arg(long = "db-write-buffer-size", env)]
+    pub db_write_buffer_size: Option<usize>,
+
+    /// The maximum number of the concurrent flushes and compactions.
+    #[arg(long = "db-max-background-jobs", env)]
+    pub db_max_background_jobs: Option<i32>,
+
+    /// The compression of the columns.
+    #[arg(long = "db-compression", value_enum, env)]
+    pub db_compression: Option<Compression>,
+
+    /// The compression of the specific columns, e.g. `receipts=zstd,coins=none`.
+    #[arg(
+        long = "db-column-compression",
+        value_parser = parse_column_compression,
+        value_delimiter = ',',
+        env
+    )]
+    pub db_column_compression: Vec<(Column, Compression)>,
+
+    /// The bits per key of the bloom filters. Zero disables the bloom filters.
+    #[arg(long = "db-bloom-filter-bits", env)]
+    pub db_bloom_filter_bits: Option<f64>,
+
+    /// Compacts the database every day at the time in UTC, e.g. `03:30`.
+    /// The compaction is heavy on the disk, so it should be scheduled for the off-peak hours.
+    #[arg(long = "db-compaction-time", value_parser = parse_time_of_day, env)]
+    pub db_compaction_time: Option<Duration>,
+
+    /// The columns compacted on the schedule, e.g. `receipts,coins`. All columns are compacted if unset.
+    #[arg(
+        long = "db-compaction-columns",
+        requires = "db_compaction_time",
+        value_delimiter = ',',
+        env
+    )]
+    pub db_compaction_columns: Vec<Column>,
+}
+
+impl RocksDbArgs {
+    pub fn compaction_config(&self) -> Option<CompactionConfig> {
+        self.db_compaction_time.map(|time_of_day| CompactionConfig {
+            time_of_day,
+            columns: self.db_compaction_columns.clone(),
+        })
+    }
+
+    pub fn into_config(self) -> RocksDbConfig {
+        let mut config = self.db_profile.config();
+        if let Some(block_cache_size) = self.db_block_cache_size {
+            config.block_cache_size = block_cache_size;
+        }
+        if let Some(write_buffer_size) = self.db_write_buffer_size {
+            config.write_buffer_size = write_buffer_size;
+        }
+        if let Some(max_background_jobs) = self.db_max_background_jobs {
+            config.max_background_jobs = max_background_jobs;
+        }
+        if let Some(compression) = self.db_compression {
+            config.compression = compression;
+        }
+        config.column_compression.extend(self.db_column_compression);
+        if let Some(bits) = self.db_bloom_filter_bits {
+            config.bloom_filter_bits_per_key = (bits > 0.0).then_some(bits);
+        }
+        config
+    }
+}
+
+fn parse_column_compression(value: &str) -> Result<(Column, Compression), String> {
+    let (column, compression) = value
+        .split_once('=')
+        .ok_or_else(|| format!("Expected `<column>=<compression>`, got `{value}`"))?;
+    let column = column
+        .trim()
+        .parse()
+        .map_err(|_| format!("Unknown column `{column}`"))?;
+    let compression = compression
+        .trim()
+        .parse()
+        .map_err(|_| format!("Unknown compression `{compression}`"))?;
+    Ok((column, compression))
+}
+
+fn parse_time_of_day(value: &str) -> Result<Duration, String> {
+    let invalid = || format!("Expected the time as `HH:MM`, got `{value}`");
+    let (hours, minutes) = value.split_once(':').ok_or_else(invalid)?;
+    let hours: u64 = hours.trim().parse().map_err(|_| invalid())?;
+    let minutes: u64 = minutes.trim().parse().map_err(|_| invalid())?;
+    if hours >= 24 || minutes >= 60 {
+        return Err(invalid())
+    }
+    Ok(Duration::from_secs((hours * 60 + minutes) * 60))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn column_compression_is_parsed() {
+        assert_eq!(
+            parse_column_compression("receipts=zstd"),
+            Ok((Column::Receipts, Compression::Zstd))
+        );
+        assert_eq!(
+            parse_column_compression("owned_coins = none"),
+            Ok((Column::OwnedCoins, Compression::None))
+        );
+        assert!(parse_column_compression("receipts").is_err());
+        assert!(parse_column_compression("unknown=zstd").is_err());
+        assert!(parse_column_compression("receipts=gzip").is_err());
+    }
+
+    #[test]
+    fn time_of_day_is_parsed() {
+        assert_eq!(parse_time_of_day("00:00"), Ok(Duration::ZERO));
+        assert_eq!(
+            parse_time_of_day("03:30"),
+            Ok(Duration::from_secs(3 * 60 * 60 + 30 * 60))
+        );
+        assert!(parse_time_of_day("24:00").is_err());
+        assert!(parse_time_of_day("12:60").is_err());
+        assert!(parse_time_of_day("12").is_err());
+    }
+}
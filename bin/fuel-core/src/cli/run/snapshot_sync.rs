@@ -0,0 +1,55 @@
+//! Clap configuration related to the snapshot sync
+
+use anyhow::Context;
+use clap::Args;
+use fuel_core::{
+    chain_config::Checkpoint,
+    service::CheckpointConfig,
+    types::fuel_types::Bytes32,
+};
+
+#[derive(Debug, Clone, Args)]
+pub struct SnapshotSyncArgs {
+    /// The path or the HTTP(S) URL of the checkpoint created by `fuel-core snapshot checkpoint`.
+    /// The empty database is initialized from the checkpoint instead of the genesis,
+    /// and only the blocks after the checkpoint are synced.
+    #[arg(long = "snapshot-sync", requires = "snapshot_sync_state_root", env)]
+    pub snapshot_sync: Option<String>,
+
+    /// The trusted state root of the checkpoint, printed by `fuel-core snapshot checkpoint`.
+    /// The checkpoint is rejected if its state doesn't match. The block headers don't commit
+    /// to the state yet, so this root replaces the validation against the header of the
+    /// checkpoint block: the operator is responsible for getting it from a trusted source.
+    #[arg(long = "snapshot-sync-state-root", requires = "snapshot_sync", env)]
+    pub snapshot_sync_state_root: Option<Bytes32>,
+}
+
+impl SnapshotSyncArgs {
+    /// Loads the checkpoint from the file or downloads it.
+    pub async fn into_config(self) -> anyhow::Result<Option<CheckpointConfig>> {
+        let (source, state_root) =
+            match (self.snapshot_sync, self.snapshot_sync_state_root) {
+                (Some(source), Some(state_root)) => (source, state_root),
+                _ => return Ok(None),
+            };
+
+        let bytes = if source.starts_with("http://") || source.starts_with("https://") {
+            reqwest::get(&source)
+                .await?
+                .error_for_status()?
+                .bytes()
+                .await?
+                .to_vec()
+        } else {
+            std::fs::read(&source)
+                .with_context(|| format!("failed to read the checkpoint {source}"))?
+        };
+        let checkpoint: Checkpoint = serde_json::from_slice(&bytes)
+            .with_context(|| format!("failed to parse the checkpoint {source}"))?;
+
+        Ok(Some(CheckpointConfig {
+            checkpoint,
+            state_root,
+        }))
+    }
+}
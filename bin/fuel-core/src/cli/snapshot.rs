@@ -1,4 +1,9 @@
 use crate::cli::DEFAULT_DB_PATH;
+use backup::{
+    BackupLocation,
+    NodeArgs,
+    S3Args,
+};
 use clap::{
     Parser,
     Subcommand,
@@ -6,6 +11,9 @@ use clap::{
 use fuel_core::types::fuel_types::ContractId;
 use std::path::PathBuf;
 
+mod backup;
+mod s3;
+
 /// Print a snapshot of blockchain state to stdout.
 #[derive(Debug, Clone, Parser)]
 pub struct Command {
@@ -32,6 +40,8 @@ pub enum SubCommands {
         #[clap(name = "CHAIN_CONFIG", long = "chain", default_value = "local_testnet")]
         chain_config: String,
     },
+    /// Creates a checkpoint at the latest block that new nodes can sync from.
+    Checkpoint,
     /// Creates a config for the contract.
     #[command(arg_required_else_help = true)]
     Contract {
@@ -39,6 +49,29 @@ pub enum SubCommands {
         #[clap(long = "id")]
         contract_id: ContractId,
     },
+    /// Backs up the checkpoint of the database created by the running node.
+    #[command(arg_required_else_help = true)]
+    Backup {
+        /// The directory or `s3://<bucket>/<prefix>` to back up to.
+        #[clap(long = "to")]
+        target: BackupLocation,
+
+        #[clap(flatten)]
+        node: NodeArgs,
+
+        #[clap(flatten)]
+        s3: S3Args,
+    },
+    /// Restores the database from the verified backup.
+    #[command(arg_required_else_help = true)]
+    Restore {
+        /// The directory or `s3://<bucket>/<prefix>` to restore from.
+        #[clap(long = "from")]
+        source: BackupLocation,
+
+        #[clap(flatten)]
+        s3: S3Args,
+    },
 }
 
 #[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
@@ -56,12 +89,24 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
     use fuel_core::{
         chain_config::{
             ChainConfig,
+            Checkpoint,
+            GenesisCommitment,
             StateConfig,
         },
         database::Database,
+        types::fuel_types::Bytes32,
     };
     init_logging().await?;
     let path = command.database_path;
+    match command.subcommand {
+        SubCommands::Backup { target, node, s3 } => {
+            return backup::backup(target, node, s3).await
+        }
+        SubCommands::Restore { source, s3 } => {
+            return backup::restore(&path, source, s3).await
+        }
+        _ => {}
+    }
     let data_source =
         fuel_core::state::rocks_db::RocksDb::default_open(&path, None).context(
             format!("failed to open database at path {}", path.display()),
@@ -83,6 +128,42 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
             serde_json::to_writer_pretty(stdout, &chain_conf)
                 .context("failed to dump snapshot to JSON")?;
         }
+        SubCommands::Checkpoint => {
+            let (height, block_id) = db
+                .ids_of_latest_block()?
+                .context("the database doesn't contain blocks")?;
+            let (genesis_height, genesis_id) = db.ids_of_genesis_block()?;
+            let block_ids = (*genesis_height + 1..*height)
+                .map(|height| {
+                    db.get_block_id(&height.into())?
+                        .with_context(|| format!("the block {height} is not found"))
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            let genesis = db
+                .get_sealed_compressed_block(&genesis_id)?
+                .context("the genesis block is not found")?;
+            let block = db
+                .get_sealed_compressed_block(&block_id)?
+                .context("the latest block is not found")?;
+            let state = StateConfig::generate_state_config(db)?;
+
+            let checkpoint = Checkpoint {
+                genesis,
+                block_ids,
+                block,
+                state,
+            };
+            tracing::info!(
+                "The state root of the checkpoint at height {}: {}",
+                height,
+                Bytes32::from(checkpoint.state.root()?)
+            );
+
+            let stdout = std::io::stdout().lock();
+
+            serde_json::to_writer_pretty(stdout, &checkpoint)
+                .context("failed to dump checkpoint to JSON")?;
+        }
         SubCommands::Contract { contract_id } => {
             let config = db.get_contract_config_by_id(contract_id)?;
             let stdout = std::io::stdout().lock();
@@ -90,6 +171,9 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
             serde_json::to_writer_pretty(stdout, &config)
                 .context("failed to dump contract snapshot to JSON")?;
         }
+        SubCommands::Backup { .. } | SubCommands::Restore { .. } => {
+            unreachable!("The backups don't open the database of the node")
+        }
     }
     Ok(())
 }
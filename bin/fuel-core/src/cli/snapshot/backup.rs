@@ -0,0 +1,360 @@
+//! The online backup and restore of the database.
+//!
+//! The running node creates the checkpoint of its database through the admin GraphQL
+//! endpoint, and the backup is taken from the checkpoint, so it doesn't interrupt the
+//! node. The checkpoint is hard linked to the files of the database, so it must be on
+//! the same host and file system. The backups are incremental: the files shared with
+//! the previous backups are neither copied nor uploaded again. The backup in the
+//! S3-compatible storage is mirrored by the local staging directory, and the
+//! `manifest.json` object lists the uploaded files.
+
+use super::s3::{
+    Credentials,
+    S3Bucket,
+};
+use anyhow::{
+    anyhow,
+    Context,
+};
+use clap::Args;
+use fuel_core_client::client::FuelClient;
+use reqwest::Url;
+use std::{
+    collections::BTreeMap,
+    path::{
+        Path,
+        PathBuf,
+    },
+    str::FromStr,
+};
+
+const MANIFEST: &str = "manifest.json";
+
+/// The location of the backup: the local directory or `s3://<bucket>/<prefix>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BackupLocation {
+    Directory(PathBuf),
+    S3 { bucket: String, prefix: String },
+}
+
+impl FromStr for BackupLocation {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix("s3://") {
+            Some(location) => {
+                let (bucket, prefix) = location.split_once('/').unwrap_or((location, ""));
+                if bucket.is_empty() {
+                    return Err(anyhow!("The bucket of {} is empty", s))
+                }
+                Ok(BackupLocation::S3 {
+                    bucket: bucket.to_string(),
+                    prefix: prefix.trim_matches('/').to_string(),
+                })
+            }
+            None => Ok(BackupLocation::Directory(PathBuf::from(s))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct NodeArgs {
+    /// The URL of the running node. The database administration must be enabled
+    /// on the node.
+    #[clap(long = "node-url", default_value = "http://127.0.0.1:4000", env)]
+    node_url: String,
+
+    /// The admin token of the node, if the node requires the tokens.
+    #[clap(
+        long = "api-token",
+        env = "FUEL_CORE_API_TOKEN",
+        hide_env_values = true
+    )]
+    api_token: Option<String>,
+
+    /// The checkpoint directory of the node, set by its `--database-checkpoint-dir`.
+    /// The node creates the checkpoint of the database in the new subdirectory of it,
+    /// and only this subdirectory is removed after the backup.
+    #[clap(long = "checkpoint-dir")]
+    checkpoint_dir: PathBuf,
+}
+
+impl NodeArgs {
+    fn client(&self) -> anyhow::Result<FuelClient> {
+        let client = FuelClient::new(&self.node_url)?;
+        Ok(match &self.api_token {
+            Some(token) => client.with_api_token(token.clone()),
+            None => client,
+        })
+    }
+
+    /// Returns the new subdirectory of the checkpoint directory for the checkpoint.
+    /// The name is unique, so the existing files are never touched.
+    fn checkpoint_path(&self) -> anyhow::Result<PathBuf> {
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)?
+            .as_nanos();
+        let path = self.checkpoint_dir.join(format!(
+            "fuel-core-checkpoint-{}-{}",
+            std::process::id(),
+            timestamp
+        ));
+        if path.exists() {
+            return Err(anyhow!(
+                "The checkpoint path {} already exists",
+                path.display()
+            ))
+        }
+        Ok(path)
+    }
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct S3Args {
+    /// The endpoint of the S3-compatible storage.
+    #[clap(
+        long = "s3-endpoint",
+        default_value = "https://s3.amazonaws.com",
+        env = "S3_ENDPOINT"
+    )]
+    endpoint: Url,
+
+    /// The region of the bucket.
+    #[clap(long = "s3-region", default_value = "us-east-1", env = "AWS_REGION")]
+    region: String,
+
+    /// The access key of the S3-compatible storage.
+    #[clap(
+        long = "s3-access-key",
+        env = "AWS_ACCESS_KEY_ID",
+        hide_env_values = true
+    )]
+    access_key: Option<String>,
+
+    /// The secret key of the S3-compatible storage.
+    #[clap(
+        long = "s3-secret-key",
+        env = "AWS_SECRET_ACCESS_KEY",
+        hide_env_values = true
+    )]
+    secret_key: Option<String>,
+
+    /// The local directory that mirrors the backup in the S3-compatible storage.
+    /// Keeping it between the runs makes the backups incremental.
+    #[clap(long = "staging-dir")]
+    staging_dir: Option<PathBuf>,
+}
+
+impl S3Args {
+    fn bucket(&self, bucket: String, prefix: String) -> anyhow::Result<S3Bucket> {
+        let credentials = Credentials {
+            access_key: self
+                .access_key
+                .clone()
+                .context("The S3 access key is required")?,
+            secret_key: self
+                .secret_key
+                .clone()
+                .context("The S3 secret key is required")?,
+        };
+        Ok(S3Bucket::new(
+            self.endpoint.clone(),
+            self.region.clone(),
+            bucket,
+            prefix,
+            credentials,
+        ))
+    }
+
+    fn staging_dir(&self) -> anyhow::Result<&Path> {
+        self.staging_dir
+            .as_deref()
+            .context("The staging directory is required for the S3 backups")
+    }
+}
+
+/// The files of the backup directory with their sizes, relative to the directory.
+type Manifest = BTreeMap<String, u64>;
+
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+pub async fn backup(
+    target: BackupLocation,
+    node: NodeArgs,
+    s3: S3Args,
+) -> anyhow::Result<()> {
+    use fuel_core::state::rocks_db::RocksDb;
+
+    let backup_dir = match &target {
+        BackupLocation::Directory(dir) => dir.as_path(),
+        BackupLocation::S3 { .. } => s3.staging_dir()?,
+    };
+    let checkpoint_dir = node.checkpoint_path()?;
+    let checkpoint_path = checkpoint_dir
+        .to_str()
+        .context("The checkpoint directory is not a UTF-8 path")?;
+    node.client()?
+        .create_database_checkpoint(checkpoint_path)
+        .await
+        .context("Failed to create the checkpoint of the database")?;
+    let info =
+        RocksDb::default_open(&checkpoint_dir, None).and_then(|db| db.backup(backup_dir));
+    std::fs::remove_dir_all(&checkpoint_dir).ok();
+    let info = info.with_context(|| {
+        format!(
+            "Failed to back up the checkpoint at {}",
+            checkpoint_dir.display()
+        )
+    })?;
+    tracing::info!(
+        "The backup {} of {} files ({} bytes) is created",
+        info.id,
+        info.num_files,
+        info.size
+    );
+
+    if let BackupLocation::S3 { bucket, prefix } = target {
+        let bucket = s3.bucket(bucket, prefix)?;
+        let mut manifest: Manifest = match bucket.get(MANIFEST).await? {
+            Some(manifest) => serde_json::from_slice(&manifest)?,
+            None => Manifest::default(),
+        };
+        let mut uploaded = 0;
+        for (file, size) in list_files(backup_dir)? {
+            if manifest.get(&file) == Some(&size) {
+                continue
+            }
+            bucket
+                .put(&file, std::fs::read(backup_dir.join(&file))?)
+                .await?;
+            manifest.insert(file, size);
+            uploaded += 1;
+        }
+        // The manifest is uploaded last, so the interrupted upload is resumed
+        // by the next backup.
+        bucket.put(MANIFEST, serde_json::to_vec(&manifest)?).await?;
+        tracing::info!("Uploaded {} new files of the backup", uploaded);
+    }
+    Ok(())
+}
+
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+pub async fn restore(
+    db_path: &Path,
+    source: BackupLocation,
+    s3: S3Args,
+) -> anyhow::Result<()> {
+    use fuel_core::state::rocks_db::RocksDb;
+
+    if std::fs::read_dir(db_path).map_or(false, |mut dir| dir.next().is_some()) {
+        return Err(anyhow!(
+            "The database at {} is not empty",
+            db_path.display()
+        ))
+    }
+
+    let backup_dir = match source {
+        BackupLocation::Directory(dir) => dir,
+        BackupLocation::S3 { bucket, prefix } => {
+            let staging_dir = s3.staging_dir()?.to_path_buf();
+            let bucket = s3.bucket(bucket, prefix)?;
+            let manifest: Manifest = match bucket.get(MANIFEST).await? {
+                Some(manifest) => serde_json::from_slice(&manifest)?,
+                None => return Err(anyhow!("The backup is not found")),
+            };
+            let local = list_files(&staging_dir).unwrap_or_default();
+            for (file, size) in manifest {
+                if local.get(&file) == Some(&size) {
+                    continue
+                }
+                let content = bucket.get(&file).await?.with_context(|| {
+                    format!("The file {file} of the backup is missing")
+                })?;
+                let path = staging_dir.join(&file);
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                std::fs::write(path, content)?;
+            }
+            staging_dir
+        }
+    };
+
+    let info = RocksDb::restore(db_path, &backup_dir).with_context(|| {
+        format!("Failed to restore the backup from {}", backup_dir.display())
+    })?;
+    tracing::info!(
+        "The backup {} is restored to {}",
+        info.id,
+        db_path.display()
+    );
+    Ok(())
+}
+
+/// Lists the files of the `dir` recursively.
+fn list_files(dir: &Path) -> anyhow::Result<Manifest> {
+    let mut files = Manifest::default();
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(next) = dirs.pop() {
+        for entry in std::fs::read_dir(&next)? {
+            let entry = entry?;
+            let metadata = entry.metadata()?;
+            if metadata.is_dir() {
+                dirs.push(entry.path());
+            } else {
+                let path = entry.path();
+                let relative = path
+                    .strip_prefix(dir)?
+                    .to_str()
+                    .context("The backup contains the non UTF-8 path")?
+                    .replace('\\', "/");
+                files.insert(relative, metadata.len());
+            }
+        }
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn location_is_parsed() {
+        assert_eq!(
+            "/var/backups/fuel".parse::<BackupLocation>().unwrap(),
+            BackupLocation::Directory("/var/backups/fuel".into())
+        );
+        assert_eq!(
+            "s3://backups/fuel/node-1/"
+                .parse::<BackupLocation>()
+                .unwrap(),
+            BackupLocation::S3 {
+                bucket: "backups".to_string(),
+                prefix: "fuel/node-1".to_string(),
+            }
+        );
+        assert_eq!(
+            "s3://backups".parse::<BackupLocation>().unwrap(),
+            BackupLocation::S3 {
+                bucket: "backups".to_string(),
+                prefix: String::new(),
+            }
+        );
+        assert!("s3:///fuel".parse::<BackupLocation>().is_err());
+    }
+
+    #[test]
+    fn checkpoint_is_created_in_the_new_subdirectory() {
+        let node = NodeArgs {
+            node_url: "http://127.0.0.1:4000".to_string(),
+            api_token: None,
+            checkpoint_dir: PathBuf::from("/var/lib/fuel/checkpoints"),
+        };
+
+        let first = node.checkpoint_path().unwrap();
+        let second = node.checkpoint_path().unwrap();
+
+        assert_eq!(first.parent(), Some(node.checkpoint_dir.as_path()));
+        assert_ne!(first, second);
+    }
+}
@@ -1,4 +1,5 @@
 mod chain;
+mod checkpoint;
 mod coin;
 mod consensus;
 mod contract;
@@ -6,6 +7,7 @@ mod message;
 mod state;
 
 pub use chain::*;
+pub use checkpoint::*;
 pub use coin::*;
 pub use consensus::*;
 pub use contract::*;
@@ -22,6 +24,7 @@ mod tests {
             UtxoId,
         },
         fuel_types::{
+            Address,
             AssetId,
             Bytes32,
         },
@@ -42,10 +45,12 @@ mod tests {
     use super::{
         chain::ChainConfig,
         coin::CoinConfig,
+        consensus::ConsensusConfig,
         contract::ContractConfig,
         message::MessageConfig,
         state::StateConfig,
     };
+    use crate::GenesisCommitment;
 
     #[test]
     fn from_str_loads_from_file() {
@@ -77,6 +82,21 @@ mod tests {
         assert_eq!(config, deserialized_config);
     }
 
+    #[test]
+    fn state_root_commits_to_the_state() {
+        let state = StateConfig {
+            height: Some(1u32.into()),
+            ..Default::default()
+        };
+        let other_state = StateConfig {
+            height: Some(2u32.into()),
+            ..Default::default()
+        };
+
+        assert_eq!(state.root().unwrap(), state.clone().root().unwrap());
+        assert_ne!(state.root().unwrap(), other_state.root().unwrap());
+    }
+
     #[test]
     fn snapshot_configurable_block_height() {
         let mut rng = StdRng::seed_from_u64(2);
@@ -107,6 +127,39 @@ mod tests {
         assert_eq!(config, deserialized_config);
     }
 
+    #[test]
+    fn can_roundtrip_serialize_round_robin_config() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let config = ChainConfig {
+            consensus: ConsensusConfig::RoundRobin {
+                signing_keys: vec![rng.gen(), rng.gen()],
+                skip_timeout: 10,
+            },
+            ..ChainConfig::local_testnet()
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        let deserialized_config: ChainConfig =
+            serde_json::from_str(json.as_str()).unwrap();
+        assert_eq!(config, deserialized_config);
+    }
+
+    #[test]
+    fn round_robin_slots_pass_to_the_next_authority_after_timeout() {
+        let signing_keys: Vec<Address> = vec![[1u8; 32].into(), [2u8; 32].into()];
+        let config = ConsensusConfig::RoundRobin {
+            signing_keys: signing_keys.clone(),
+            skip_timeout: 10,
+        };
+
+        let producer = |height: u32, delay| config.slot_producer(&height.into(), delay);
+        assert_eq!(producer(2, 0), Some(signing_keys[0]));
+        assert_eq!(producer(3, 9), Some(signing_keys[1]));
+        assert_eq!(producer(3, 10), Some(signing_keys[0]));
+        assert_eq!(producer(3, 25), Some(signing_keys[1]));
+        assert!(config.is_authority(&signing_keys[1]));
+        assert!(!config.is_authority(&Address::zeroed()));
+    }
+
     #[test]
     fn snapshot_simple_contract() {
         let config = test_config_contract(false, false, false, false);
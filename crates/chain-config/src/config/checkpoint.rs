@@ -0,0 +1,254 @@
+use crate::{
+    config::{
+        coin::CoinConfig,
+        contract::ContractConfig,
+        message::MessageConfig,
+        state::StateConfig,
+    },
+    GenesisCommitment,
+};
+use fuel_core_storage::MerkleRoot;
+use fuel_core_types::{
+    blockchain::{
+        block::CompressedBlock,
+        consensus::Sealed,
+        primitives::BlockId,
+    },
+    fuel_crypto::Hasher,
+    fuel_types::BlockHeight,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// The snapshot of the chain at the checkpoint height. A new node initializes
+/// the database from the checkpoint instead of the genesis and syncs only
+/// the blocks after the checkpoint.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct Checkpoint {
+    /// The genesis block of the network.
+    pub genesis: Sealed<CompressedBlock>,
+    /// The ids of the blocks between the genesis and the checkpoint block.
+    /// They restore the block header merkle tree that the next blocks commit to.
+    pub block_ids: Vec<BlockId>,
+    /// The block at the checkpoint height.
+    pub block: Sealed<CompressedBlock>,
+    /// The state of the chain after the checkpoint block.
+    pub state: StateConfig,
+}
+
+impl Checkpoint {
+    /// Returns the height of the checkpoint block.
+    pub fn height(&self) -> BlockHeight {
+        *self.block.entity.header().height()
+    }
+}
+
+/// The commitment to the state doesn't depend on the order of the entries
+/// or on the serialization format:
+///
+/// ```text
+/// root = sha256(height || entries_root(coins) || entries_root(contracts) || entries_root(messages))
+/// entries_root(entries) = sha256(count || sorted(entry_root(entry) for entry in entries))
+/// ```
+///
+/// The `count` is a big-endian `u64`, and the missing entries have the same
+/// commitment as the empty ones. The `entry_root` of the coin, the contract and
+/// the message hashes all their fields in the declaration order. The numbers are
+/// big-endian, the vectors are prefixed by the big-endian `u64` length, and the optional
+/// values are prefixed by `0` if absent or `1` if present. The storage slots and
+/// the balances of the contract are sorted by the key before hashing.
+impl GenesisCommitment for StateConfig {
+    fn root(&self) -> anyhow::Result<MerkleRoot> {
+        let Self {
+            coins,
+            contracts,
+            messages,
+            height,
+        } = self;
+
+        let hasher = chain_option(Hasher::default(), height.map(|h| h.to_be_bytes()));
+        let state_hash = *hasher
+            .chain(entries_root(coins.iter().flatten().map(coin_root)))
+            .chain(entries_root(contracts.iter().flatten().map(contract_root)))
+            .chain(entries_root(messages.iter().flatten().map(message_root)))
+            .finalize();
+
+        Ok(state_hash)
+    }
+}
+
+fn entries_root(entries: impl Iterator<Item = MerkleRoot>) -> MerkleRoot {
+    let mut roots = entries.collect::<Vec<_>>();
+    roots.sort();
+
+    let hasher = Hasher::default().chain((roots.len() as u64).to_be_bytes());
+    *roots
+        .into_iter()
+        .fold(hasher, |hasher, root| hasher.chain(root))
+        .finalize()
+}
+
+fn coin_root(coin: &CoinConfig) -> MerkleRoot {
+    let CoinConfig {
+        tx_id,
+        output_index,
+        tx_pointer_block_height,
+        tx_pointer_tx_idx,
+        maturity,
+        owner,
+        amount,
+        asset_id,
+    } = coin;
+
+    let hasher = chain_option(Hasher::default(), *tx_id);
+    let hasher = chain_option(hasher, output_index.map(|index| [index]));
+    let hasher = chain_option(hasher, tx_pointer_block_height.map(|h| h.to_be_bytes()));
+    let hasher = chain_option(hasher, tx_pointer_tx_idx.map(|idx| idx.to_be_bytes()));
+    let hasher = chain_option(hasher, maturity.map(|h| h.to_be_bytes()));
+    *hasher
+        .chain(owner)
+        .chain(amount.to_be_bytes())
+        .chain(asset_id)
+        .finalize()
+}
+
+fn contract_root(contract: &ContractConfig) -> MerkleRoot {
+    let ContractConfig {
+        contract_id,
+        code,
+        salt,
+        state,
+        balances,
+        tx_id,
+        output_index,
+        tx_pointer_block_height,
+        tx_pointer_tx_idx,
+    } = contract;
+
+    let hasher = Hasher::default()
+        .chain(contract_id)
+        .chain((code.len() as u64).to_be_bytes())
+        .chain(code.as_slice())
+        .chain(salt);
+
+    let state = state.as_ref().map(|slots| {
+        let mut slots = slots.clone();
+        slots.sort();
+        let hasher = Hasher::default().chain((slots.len() as u64).to_be_bytes());
+        slots
+            .into_iter()
+            .fold(hasher, |hasher, (key, value)| {
+                hasher.chain(key).chain(value)
+            })
+            .finalize()
+    });
+    let hasher = chain_option(hasher, state);
+
+    let balances = balances.as_ref().map(|balances| {
+        let mut balances = balances.clone();
+        balances.sort();
+        let hasher = Hasher::default().chain((balances.len() as u64).to_be_bytes());
+        balances
+            .into_iter()
+            .fold(hasher, |hasher, (asset_id, amount)| {
+                hasher.chain(asset_id).chain(amount.to_be_bytes())
+            })
+            .finalize()
+    });
+    let hasher = chain_option(hasher, balances);
+
+    let hasher = chain_option(hasher, *tx_id);
+    let hasher = chain_option(hasher, output_index.map(|index| [index]));
+    let hasher = chain_option(hasher, tx_pointer_block_height.map(|h| h.to_be_bytes()));
+    let hasher = chain_option(hasher, tx_pointer_tx_idx.map(|idx| idx.to_be_bytes()));
+    *hasher.finalize()
+}
+
+fn message_root(message: &MessageConfig) -> MerkleRoot {
+    let MessageConfig {
+        sender,
+        recipient,
+        nonce,
+        amount,
+        data,
+        da_height,
+    } = message;
+
+    *Hasher::default()
+        .chain(sender)
+        .chain(recipient)
+        .chain(nonce)
+        .chain(amount.to_be_bytes())
+        .chain((data.len() as u64).to_be_bytes())
+        .chain(data.as_slice())
+        .chain(da_height.to_be_bytes())
+        .finalize()
+}
+
+/// Hashes `0` if the `value` is absent, or `1` followed by the `value`.
+fn chain_option<T: AsRef<[u8]>>(hasher: Hasher, value: Option<T>) -> Hasher {
+    match value {
+        Some(value) => hasher.chain([1u8]).chain(value),
+        None => hasher.chain([0u8]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_types::{
+        Address,
+        AssetId,
+    };
+
+    fn coin(amount: u64) -> CoinConfig {
+        CoinConfig {
+            tx_id: None,
+            output_index: None,
+            tx_pointer_block_height: None,
+            tx_pointer_tx_idx: None,
+            maturity: None,
+            owner: Address::from([1; 32]),
+            amount,
+            asset_id: AssetId::BASE,
+        }
+    }
+
+    #[test]
+    fn state_root_does_not_depend_on_the_order_of_the_entries() {
+        let state = StateConfig {
+            coins: Some(vec![coin(1), coin(2)]),
+            ..Default::default()
+        };
+        let reordered_state = StateConfig {
+            coins: Some(vec![coin(2), coin(1)]),
+            ..Default::default()
+        };
+
+        assert_eq!(state.root().unwrap(), reordered_state.root().unwrap());
+    }
+
+    #[test]
+    fn state_root_commits_to_the_entries() {
+        let state = StateConfig {
+            coins: Some(vec![coin(1)]),
+            ..Default::default()
+        };
+        let other_state = StateConfig {
+            coins: Some(vec![coin(2)]),
+            ..Default::default()
+        };
+        let empty_state = StateConfig {
+            coins: Some(vec![]),
+            ..Default::default()
+        };
+
+        assert_ne!(state.root().unwrap(), other_state.root().unwrap());
+        assert_eq!(
+            empty_state.root().unwrap(),
+            StateConfig::default().root().unwrap()
+        );
+    }
+}
@@ -1,6 +1,9 @@
 use fuel_core_types::{
     fuel_tx::Input,
-    fuel_types::Address,
+    fuel_types::{
+        Address,
+        BlockHeight,
+    },
 };
 use serde::{
     Deserialize,
@@ -11,7 +14,17 @@ use crate::default_consensus_dev_key;
 
 #[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 pub enum ConsensusConfig {
-    PoA { signing_key: Address },
+    PoA {
+        signing_key: Address,
+    },
+    /// Several authorities take turns producing the blocks.
+    RoundRobin {
+        /// The authorities in the order of their slots.
+        signing_keys: Vec<Address>,
+        /// The number of seconds after which the slot of the inactive authority
+        /// passes to the next one.
+        skip_timeout: u64,
+    },
 }
 
 impl ConsensusConfig {
@@ -20,4 +33,35 @@ impl ConsensusConfig {
             signing_key: Input::owner(&default_consensus_dev_key().public_key()),
         }
     }
+
+    /// Returns `true` if the `address` is allowed to produce blocks.
+    pub fn is_authority(&self, address: &Address) -> bool {
+        match self {
+            ConsensusConfig::PoA { signing_key } => signing_key == address,
+            ConsensusConfig::RoundRobin { signing_keys, .. } => {
+                signing_keys.contains(address)
+            }
+        }
+    }
+
+    /// Returns the authority assigned to produce the block at the `height`
+    /// `delay` seconds after the previous block.
+    ///
+    /// The round-robin slots are assigned by the height of the block. Each time the
+    /// `skip_timeout` passes without a new block, the slot moves to the next authority.
+    pub fn slot_producer(&self, height: &BlockHeight, delay: u64) -> Option<Address> {
+        match self {
+            ConsensusConfig::PoA { signing_key } => Some(*signing_key),
+            ConsensusConfig::RoundRobin {
+                signing_keys,
+                skip_timeout,
+            } => {
+                let skipped = delay.checked_div(*skip_timeout).unwrap_or_default();
+                let slot = u64::from(**height)
+                    .wrapping_add(skipped)
+                    .checked_rem(signing_keys.len() as u64)?;
+                signing_keys.get(slot as usize).copied()
+            }
+        }
+    }
 }
@@ -1,6 +1,15 @@
 fn main() {
     println!("cargo:rerun-if-changed=./assets/debugAdapterProtocol.json");
 
+    // The `wasm32` target has no other transport than the one of the browser, while
+    // the subscriptions on the native target require the native transport
+    let wasm32 = std::env::var("CARGO_CFG_TARGET_ARCH").as_deref() == Ok("wasm32");
+    let feature =
+        |name: &str| std::env::var_os(format!("CARGO_FEATURE_{name}")).is_some();
+    if wasm32 || (feature("WASM_TRANSPORT") && !feature("SUBSCRIPTIONS")) {
+        println!("cargo:rustc-cfg=wasm_transport");
+    }
+
     #[cfg(feature = "dap")]
     generate_dap_schema();
 }
@@ -2,11 +2,18 @@ use crate::client::{
     schema::{
         block::BlockByHeightArgs,
         coins::{
+            CoinsConnectionArgs,
             ExcludeInput,
             SpendQueryElementInput,
         },
-        contract::ContractBalanceQueryArgs,
-        tx::DryRunArg,
+        contract::{
+            ContractBalanceQueryArgs,
+            ContractStateArgs,
+        },
+        tx::{
+            DryRunArg,
+            TraceTransactionArg,
+        },
         Tai64Timestamp,
         TransactionId,
     },
@@ -14,6 +21,7 @@ use crate::client::{
         Address,
         AssetId,
         BlockId,
+        Bytes32,
         ContractId,
         UtxoId,
     },
@@ -22,7 +30,6 @@ use anyhow::Context;
 #[cfg(feature = "subscriptions")]
 use cynic::StreamingOperation;
 use cynic::{
-    http::ReqwestExt,
     GraphQlResponse,
     Id,
     MutationBuilder,
@@ -47,6 +54,7 @@ use fuel_core_types::{
         MessageId,
         Nonce,
     },
+    services::executor::StateOverrides,
 };
 #[cfg(feature = "subscriptions")]
 use futures::StreamExt;
@@ -56,14 +64,24 @@ use pagination::{
     PaginatedResult,
     PaginationRequest,
 };
+use retry::{
+    RequestError,
+    RetryPolicy,
+};
 use schema::{
     balance::BalanceArgs,
     block::BlockByIdArgs,
+    chain::ChainStatsArgs,
     coins::CoinByIdArgs,
     contract::ContractByIdArgs,
+    gas_price::{
+        BlockHorizonArgs,
+        EstimateFeesArgs,
+    },
     tx::{
         TxArg,
         TxIdArgs,
+        TxsArg,
     },
     Bytes,
     ContinueTx,
@@ -84,6 +102,8 @@ use schema::{
 };
 #[cfg(feature = "subscriptions")]
 use std::future;
+#[cfg(not(wasm_transport))]
+use std::sync::Arc;
 use std::{
     convert::TryInto,
     io::{
@@ -95,7 +115,7 @@ use std::{
         self,
         FromStr,
     },
-    sync::Arc,
+    time::Duration,
 };
 use tai64::Tai64;
 use tracing as _;
@@ -105,12 +125,24 @@ use types::{
 };
 
 use self::schema::{
+    asset::AssetSupplyArgs,
     block::ProduceBlockArgs,
-    message::MessageProofArgs,
+    message::{
+        MessageProofArgs,
+        MessageProofInput,
+        MessageProofsArgs,
+    },
+    node_info::{
+        CompactDatabaseArgs,
+        DatabaseCheckpointArgs,
+        PeerIdArgs,
+    },
 };
 
 pub mod pagination;
+pub mod retry;
 pub mod schema;
+mod time;
 pub mod types;
 
 #[derive(Debug, Clone)]
@@ -119,6 +151,10 @@ pub struct FuelClient {
     #[cfg(feature = "subscriptions")]
     cookie: Arc<reqwest::cookie::Jar>,
     url: reqwest::Url,
+    /// The bearer token sent with every request, if any.
+    api_token: Option<String>,
+    /// The policy of retrying the failed queries and mutations.
+    retry_policy: RetryPolicy,
 }
 
 impl FromStr for FuelClient {
@@ -133,15 +169,20 @@ impl FromStr for FuelClient {
         let mut url = reqwest::Url::parse(&raw_url)
             .with_context(|| format!("Invalid fuel-core URL: {str}"))?;
         url.set_path("/graphql");
+        let client = reqwest::Client::builder();
+        // the `wasm-transport` uses the fetch API of the browser that manages cookies itself
+        #[cfg(not(wasm_transport))]
         let cookie = Arc::new(reqwest::cookie::Jar::default());
-        let client = reqwest::Client::builder()
-            .cookie_provider(cookie.clone())
-            .build()?;
+        #[cfg(not(wasm_transport))]
+        let client = client.cookie_provider(cookie.clone());
+        let client = client.build()?;
         Ok(Self {
             client,
             #[cfg(feature = "subscriptions")]
             cookie,
             url,
+            api_token: None,
+            retry_policy: RetryPolicy::NONE,
         })
     }
 }
@@ -174,6 +215,20 @@ impl FuelClient {
         Self::from_str(url.as_ref())
     }
 
+    /// Sends the `token` with every request to access the endpoints restricted
+    /// by the node.
+    pub fn with_api_token(mut self, token: impl Into<String>) -> Self {
+        self.api_token = Some(token.into());
+        self
+    }
+
+    /// Retries the failed requests according to the `policy`. The requests are
+    /// sent only once by default.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
     /// Send the GraphQL query to the client.
     pub async fn query<ResponseData, Vars>(
         &self,
@@ -183,14 +238,67 @@ impl FuelClient {
         Vars: serde::Serialize,
         ResponseData: serde::de::DeserializeOwned + 'static,
     {
-        let response = self
+        self.query_with_retry_policy(q, &self.retry_policy).await
+    }
+
+    /// Send the GraphQL query to the client, retrying it according to the `policy`
+    /// instead of the policy of the client.
+    pub async fn query_with_retry_policy<ResponseData, Vars>(
+        &self,
+        q: Operation<ResponseData, Vars>,
+        policy: &RetryPolicy,
+    ) -> io::Result<ResponseData>
+    where
+        Vars: serde::Serialize,
+        ResponseData: serde::de::DeserializeOwned + 'static,
+    {
+        let mutation = retry::is_mutation(&q.query);
+        let body = serde_json::to_vec(&q)?;
+        let mut attempt = 1;
+        loop {
+            match self.send::<ResponseData>(body.clone()).await {
+                Ok(response) => return Self::decode_response(response),
+                Err(e) if attempt < policy.max_attempts && e.is_retryable(mutation) => {
+                    let backoff = policy.backoff(attempt);
+                    tracing::debug!(
+                        "The attempt {} failed: {}; retrying in {:?}",
+                        attempt,
+                        e,
+                        backoff
+                    );
+                    time::sleep(backoff).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+
+    /// Sends the serialized GraphQL request once.
+    async fn send<R>(&self, body: Vec<u8>) -> Result<GraphQlResponse<R>, RequestError>
+    where
+        R: serde::de::DeserializeOwned + 'static,
+    {
+        let mut request = self
             .client
             .post(self.url.clone())
-            .run_graphql(q)
-            .await
-            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
-
-        Self::decode_response(response)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body);
+        if let Some(token) = &self.api_token {
+            request = request.bearer_auth(token);
+        }
+        let response = request.send().await?;
+        let status = response.status();
+        let body = response.bytes().await?;
+        // The GraphQL errors may be returned with any status, so the status matters
+        // only if the body isn't the GraphQL response.
+        serde_json::from_slice(&body).map_err(|e| {
+            if status.is_success() {
+                e.into()
+            } else {
+                RequestError::Status(status, String::from_utf8_lossy(&body).into_owned())
+            }
+        })
     }
 
     fn decode_response<R>(response: GraphQlResponse<R>) -> io::Result<R>
@@ -240,6 +348,20 @@ impl FuelClient {
                 )
             })?;
 
+        if let Some(token) = &self.api_token {
+            client_builder = client_builder
+                .header(
+                    reqwest::header::AUTHORIZATION.as_str(),
+                    &format!("Bearer {token}"),
+                )
+                .map_err(|e| {
+                    io::Error::new(
+                        io::ErrorKind::Other,
+                        format!("Failed to add the API token to client {e:?}"),
+                    )
+                })?;
+        }
+
         if let Some(value) = self.cookie.deref().cookies(&self.url) {
             let value = value.to_str().map_err(|e| {
                 io::Error::new(
@@ -321,16 +443,96 @@ impl FuelClient {
         self.query(query).await.map(|r| r.health)
     }
 
+    /// Polls the health endpoint of the node until it reports that it is ready.
+    /// Returns an error of kind [`ErrorKind::TimedOut`] if the node is not ready
+    /// within the `timeout`.
+    pub async fn await_healthy(&self, timeout: Duration) -> io::Result<()> {
+        const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+        let poll = async {
+            loop {
+                match self.health().await {
+                    Ok(true) => break,
+                    Ok(false) => tracing::debug!("the node is not healthy yet"),
+                    Err(e) => tracing::debug!("the node is not reachable yet: {e}"),
+                }
+                time::sleep(POLL_INTERVAL).await;
+            }
+        };
+
+        time::timeout(timeout, poll).await.map_err(|_| {
+            io::Error::new(
+                ErrorKind::TimedOut,
+                format!("the node at {} is not ready after {timeout:?}", self.url),
+            )
+        })
+    }
+
     pub async fn node_info(&self) -> io::Result<types::NodeInfo> {
         let query = schema::node_info::QueryNodeInfo::build(());
         self.query(query).await.map(|r| r.node_info.into())
     }
 
+    pub async fn connected_peers_info(&self) -> io::Result<Vec<types::PeerInfo>> {
+        let query = schema::node_info::QueryPeersInfo::build(());
+        self.query(query)
+            .await
+            .map(|r| r.node_info.peers.into_iter().map(Into::into).collect())
+    }
+
+    /// Closes all connections with the peer. The peer is free to reconnect.
+    pub async fn disconnect_peer(&self, peer_id: &str) -> io::Result<bool> {
+        let query = schema::node_info::DisconnectPeer::build(PeerIdArgs {
+            id: peer_id.to_string(),
+        });
+        self.query(query).await.map(|r| r.disconnect_peer)
+    }
+
+    /// Bans the peer, so no connections with it are allowed until the node restarts.
+    pub async fn ban_peer(&self, peer_id: &str) -> io::Result<bool> {
+        let query = schema::node_info::BanPeer::build(PeerIdArgs {
+            id: peer_id.to_string(),
+        });
+        self.query(query).await.map(|r| r.ban_peer)
+    }
+
+    /// Starts the compaction of the database `column`, or of the whole database
+    /// if `None`. The compaction runs in the background on the node.
+    pub async fn compact_database(&self, column: Option<&str>) -> io::Result<bool> {
+        let query = schema::node_info::CompactDatabase::build(CompactDatabaseArgs {
+            column: column.map(str::to_string),
+        });
+        self.query(query).await.map(|r| r.compact_database)
+    }
+
+    /// Creates the checkpoint of the database at the `path` on the file system of
+    /// the node. The `path` must not exist.
+    pub async fn create_database_checkpoint(&self, path: &str) -> io::Result<bool> {
+        let query =
+            schema::node_info::CreateDatabaseCheckpoint::build(DatabaseCheckpointArgs {
+                path: path.to_string(),
+            });
+        self.query(query)
+            .await
+            .map(|r| r.create_database_checkpoint)
+    }
+
     pub async fn chain_info(&self) -> io::Result<types::ChainInfo> {
         let query = schema::chain::ChainQuery::build(());
         self.query(query).await.map(|r| r.chain.into())
     }
 
+    /// Returns the rolling statistics of up to `window` latest blocks.
+    pub async fn chain_stats(
+        &self,
+        window: Option<u32>,
+    ) -> io::Result<types::ChainStats> {
+        let query = schema::chain::ChainStatsQuery::build(ChainStatsArgs {
+            window: window.map(Into::into),
+        });
+        self.query(query).await.map(|r| r.chain_stats.into())
+    }
+
     /// Default dry run, matching the exact configuration as the node
     pub async fn dry_run(&self, tx: &Transaction) -> io::Result<Vec<Receipt>> {
         self.dry_run_opt(tx, None).await
@@ -347,6 +549,29 @@ impl FuelClient {
         let query = schema::tx::DryRun::build(DryRunArg {
             tx: HexString(Bytes(tx)),
             utxo_validation,
+            state_overrides: None,
+        });
+        let receipts = self.query(query).await.map(|r| r.dry_run)?;
+        receipts
+            .into_iter()
+            .map(|receipt| receipt.try_into().map_err(Into::into))
+            .collect()
+    }
+
+    /// Dry run the transaction on top of the state with the `overrides` applied.
+    /// The overrides are visible only to this dry run.
+    pub async fn dry_run_with_overrides(
+        &self,
+        tx: &Transaction,
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+        overrides: StateOverrides,
+    ) -> io::Result<Vec<Receipt>> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::DryRun::build(DryRunArg {
+            tx: HexString(Bytes(tx)),
+            utxo_validation,
+            state_overrides: Some(overrides.into()),
         });
         let receipts = self.query(query).await.map(|r| r.dry_run)?;
         receipts
@@ -355,6 +580,25 @@ impl FuelClient {
             .collect()
     }
 
+    /// Dry run the transaction and return the trace of its execution. Requires the
+    /// node to be started with the VM tracing enabled.
+    pub async fn trace_transaction(
+        &self,
+        tx: &Transaction,
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+        full_opcode_stream: bool,
+    ) -> io::Result<types::TransactionTrace> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::TraceTransaction::build(TraceTransactionArg {
+            tx: HexString(Bytes(tx)),
+            utxo_validation,
+            full_opcode_stream: Some(full_opcode_stream),
+        });
+        let trace = self.query(query).await.map(|r| r.trace_transaction)?;
+        Ok(trace.try_into()?)
+    }
+
     /// Estimate predicates for the transaction
     pub async fn estimate_predicates(&self, tx: &mut Transaction) -> io::Result<()> {
         let serialized_tx = tx.to_bytes();
@@ -380,6 +624,43 @@ impl FuelClient {
         Ok(id)
     }
 
+    /// Submits the transaction without broadcasting it to the network, so only
+    /// this node can include it into the block.
+    pub async fn submit_local(
+        &self,
+        tx: &Transaction,
+    ) -> io::Result<types::primitives::TransactionId> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::SubmitLocal::build(TxArg {
+            tx: HexString(Bytes(tx)),
+        });
+
+        let id = self.query(query).await.map(|r| r.submit_local)?.id.into();
+        Ok(id)
+    }
+
+    /// Submits the transactions to the `TxPool` in their order, so later transactions
+    /// may depend on the earlier ones. Returns the result for each transaction.
+    pub async fn submit_batch(
+        &self,
+        txs: &[Transaction],
+    ) -> io::Result<Vec<types::BatchSubmitResult>> {
+        let txs = txs
+            .iter()
+            .map(|tx| HexString(Bytes(tx.clone().to_bytes())))
+            .collect();
+        let query = schema::tx::SubmitBatch::build(TxsArg { txs });
+
+        let results = self
+            .query(query)
+            .await?
+            .submit_batch
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        Ok(results)
+    }
+
     /// Submit the transaction and wait for it either to be included in
     /// a block or removed from `TxPool`.
     ///
@@ -576,6 +857,91 @@ impl FuelClient {
         Ok(stream)
     }
 
+    #[tracing::instrument(skip(self), level = "debug")]
+    #[cfg(feature = "subscriptions")]
+    /// Subscribe to the statuses of the transactions with the given ids.
+    /// Unlike [`FuelClient::subscribe_transaction_status`], the stream yields only
+    /// the updates after the subscription and doesn't end after the final status.
+    pub async fn subscribe_transactions_status(
+        &self,
+        ids: &[TxId],
+    ) -> io::Result<impl futures::Stream<Item = io::Result<(TxId, TransactionStatus)>>>
+    {
+        use cynic::SubscriptionBuilder;
+        use schema::tx::TxIdsArgs;
+        let ids = ids.iter().map(|id| (*id).into()).collect();
+        let s = schema::tx::StatusChangesSubscription::build(TxIdsArgs { ids });
+
+        let stream = self.subscribe(s).await?.map(|update| {
+            let update = update?.status_changes;
+            Ok((update.id.into(), update.status.try_into()?))
+        });
+
+        Ok(stream)
+    }
+
+    #[tracing::instrument(skip(self), level = "debug")]
+    #[cfg(feature = "subscriptions")]
+    /// Subscribe to the statuses of the transactions spending the inputs of the `owner`.
+    pub async fn subscribe_owner_transactions_status(
+        &self,
+        owner: &Address,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<(TxId, TransactionStatus)>>>
+    {
+        use cynic::SubscriptionBuilder;
+        use schema::tx::TxOwnerArgs;
+        let owner: schema::Address = (*owner).into();
+        let s =
+            schema::tx::StatusChangesByOwnerSubscription::build(TxOwnerArgs { owner });
+
+        let stream = self.subscribe(s).await?.map(|update| {
+            let update = update?.status_changes_by_owner;
+            Ok((update.id.into(), update.status.try_into()?))
+        });
+
+        Ok(stream)
+    }
+
+    #[tracing::instrument(skip(self), level = "debug")]
+    #[cfg(feature = "subscriptions")]
+    /// Subscribe to the headers of the blocks imported after the subscription.
+    /// The headers are skipped if the stream is consumed slower than the blocks are imported.
+    pub async fn subscribe_blocks(
+        &self,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<types::block::Header>>> {
+        use cynic::SubscriptionBuilder;
+        let s = schema::block::NewBlocksSubscription::build(());
+
+        let stream = self
+            .subscribe(s)
+            .await?
+            .map(|block| Ok(block?.new_blocks.into()));
+
+        Ok(stream)
+    }
+
+    #[tracing::instrument(skip(self), level = "debug")]
+    #[cfg(feature = "subscriptions")]
+    /// Subscribe to the changes of the coins of the `owner` made by the imported and
+    /// the reverted blocks. The changes are skipped if the stream is consumed slower
+    /// than the blocks are imported.
+    pub async fn subscribe_coin_changes(
+        &self,
+        owner: &Address,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<types::CoinEvent>>> {
+        use cynic::SubscriptionBuilder;
+        use schema::coins::CoinChangesArgs;
+        let owner: schema::Address = (*owner).into();
+        let s = schema::coins::CoinChangesSubscription::build(CoinChangesArgs { owner });
+
+        let stream = self
+            .subscribe(s)
+            .await?
+            .map(|event| Ok(event?.coin_changes.into()));
+
+        Ok(stream)
+    }
+
     #[cfg(feature = "subscriptions")]
     /// Awaits for the transaction to be committed into a block
     ///
@@ -629,6 +995,74 @@ impl FuelClient {
         Ok(transactions)
     }
 
+    /// Returns a paginated set of pending transactions from the `TxPool` sorted by their ids.
+    pub async fn tx_pool_transactions(
+        &self,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        let query = schema::txpool::TxPoolTransactionsQuery::build(request.into());
+        let transactions = self.query(query).await?.tx_pool_transactions.try_into()?;
+        Ok(transactions)
+    }
+
+    /// Returns the summary of the current content of the `TxPool`.
+    pub async fn tx_pool_info(&self) -> io::Result<types::TxPoolInfo> {
+        let query = schema::txpool::QueryTxPoolInfo::build(());
+        self.query(query).await.map(|r| r.tx_pool_info.into())
+    }
+
+    /// Returns the progress of the block sync, or `None` if the sync is disabled.
+    pub async fn sync_status(&self) -> io::Result<Option<types::SyncStatus>> {
+        let query = schema::sync::QuerySyncStatus::build(());
+        self.query(query)
+            .await
+            .map(|r| r.sync_status.map(Into::into))
+    }
+
+    /// Returns the minimum gas price accepted by the node for the next block.
+    pub async fn latest_gas_price(&self) -> io::Result<types::LatestGasPrice> {
+        let query = schema::gas_price::QueryLatestGasPrice::build(());
+        self.query(query).await.map(|r| r.latest_gas_price.into())
+    }
+
+    /// Estimates the minimum gas price after `block_horizon` blocks.
+    pub async fn estimate_gas_price(
+        &self,
+        block_horizon: u32,
+    ) -> io::Result<types::EstimateGasPrice> {
+        let query = schema::gas_price::QueryEstimateGasPrice::build(BlockHorizonArgs {
+            block_horizon: Some(block_horizon.into()),
+        });
+        self.query(query).await.map(|r| r.estimate_gas_price.into())
+    }
+
+    /// Estimates the gas price and the gas limit of the transaction to be included
+    /// with the `confidence`. The transaction is dry-run, so its gas limit should be
+    /// enough for the execution.
+    pub async fn estimate_fees(
+        &self,
+        tx: &Transaction,
+        confidence: types::FeeConfidence,
+    ) -> io::Result<types::FeeEstimate> {
+        self.estimate_fees_opt(tx, confidence, None).await
+    }
+
+    /// Estimates the fees with options to override the node behavior
+    pub async fn estimate_fees_opt(
+        &self,
+        tx: &Transaction,
+        confidence: types::FeeConfidence,
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+    ) -> io::Result<types::FeeEstimate> {
+        let query = schema::gas_price::QueryEstimateFees::build(EstimateFeesArgs {
+            tx: HexString(Bytes(tx.clone().to_bytes())),
+            confidence: Some(confidence.into()),
+            utxo_validation,
+        });
+        self.query(query).await.map(|r| r.estimate_fees.into())
+    }
+
     pub async fn receipts(&self, id: &TxId) -> io::Result<Option<Vec<Receipt>>> {
         let query = schema::tx::TransactionQuery::build(TxIdArgs { id: (*id).into() });
 
@@ -648,6 +1082,29 @@ impl FuelClient {
         Ok(receipts)
     }
 
+    /// Returns the receipts of the executed transaction. Unlike [`FuelClient::receipts`],
+    /// returns `None` if the transaction is unknown or is not executed yet.
+    pub async fn transaction_receipts(
+        &self,
+        id: &TxId,
+    ) -> io::Result<Option<Vec<Receipt>>> {
+        let query = schema::tx::TransactionQuery::build(TxIdArgs { id: (*id).into() });
+
+        let receipts = self
+            .query(query)
+            .await?
+            .transaction
+            .and_then(|tx| tx.receipts)
+            .map(|vec| {
+                let vec: Result<Vec<Receipt>, ConversionError> =
+                    vec.into_iter().map(TryInto::<Receipt>::try_into).collect();
+                vec
+            })
+            .transpose()?;
+
+        Ok(receipts)
+    }
+
     #[cfg(feature = "test-helpers")]
     pub async fn all_receipts(&self) -> io::Result<Vec<Receipt>> {
         let query = schema::tx::AllReceipts::build(());
@@ -677,6 +1134,25 @@ impl FuelClient {
         Ok(new_height.into())
     }
 
+    /// Pauses the block production after the block in progress.
+    pub async fn pause_block_production(&self) -> io::Result<bool> {
+        let query = schema::block::PauseBlockProduction::build(());
+        self.query(query).await.map(|r| r.pause_block_production)
+    }
+
+    /// Resumes the paused block production.
+    pub async fn resume_block_production(&self) -> io::Result<bool> {
+        let query = schema::block::ResumeBlockProduction::build(());
+        self.query(query).await.map(|r| r.resume_block_production)
+    }
+
+    /// Assembles the next block from the transactions of the pool without committing
+    /// or signing it.
+    pub async fn dry_run_block(&self) -> io::Result<types::DryRunBlock> {
+        let query = schema::block::DryRunBlockQuery::build(());
+        self.query(query).await.map(|r| r.dry_run_block.into())
+    }
+
     pub async fn block(&self, id: &BlockId) -> io::Result<Option<types::Block>> {
         let query = schema::block::BlockByIdQuery::build(BlockByIdArgs {
             id: Some((*id).into()),
@@ -697,6 +1173,24 @@ impl FuelClient {
         Ok(block)
     }
 
+    /// Retrieve the changes of the state made by the block at the `height`.
+    /// Returns `None` if the block doesn't exist or the node doesn't compute the state diffs.
+    pub async fn state_diff(&self, height: u64) -> io::Result<Option<types::StateDiff>> {
+        let query = schema::block::StateDiffQuery::build(BlockByHeightArgs {
+            height: Some(U64(height)),
+        });
+
+        let state_diff = self
+            .query(query)
+            .await?
+            .block
+            .and_then(|block| block.state_diff)
+            .map(TryInto::try_into)
+            .transpose()?;
+
+        Ok(state_diff)
+    }
+
     /// Retrieve multiple blocks
     pub async fn blocks(
         &self,
@@ -735,6 +1229,28 @@ impl FuelClient {
         Ok(coins)
     }
 
+    /// Retrieve a page of coins by their owner as they were at the block `height`.
+    /// Requires the node to keep the historical state of the block.
+    pub async fn coins_at_height(
+        &self,
+        owner: &Address,
+        asset_id: Option<&AssetId>,
+        request: PaginationRequest<String>,
+        height: BlockHeight,
+    ) -> io::Result<PaginatedResult<types::Coin, String>> {
+        let owner: schema::Address = (*owner).into();
+        let asset_id: schema::AssetId = match asset_id {
+            Some(asset_id) => (*asset_id).into(),
+            None => schema::AssetId::default(),
+        };
+        let mut args: CoinsConnectionArgs = (owner, asset_id, request).into();
+        args.block_height = Some(height.into());
+        let query = schema::coins::CoinsQuery::build(args);
+
+        let coins = self.query(query).await?.coins.into();
+        Ok(coins)
+    }
+
     /// Retrieve coins to spend in a transaction
     pub async fn coins_to_spend(
         &self,
@@ -781,11 +1297,44 @@ impl FuelClient {
     pub async fn contract(&self, id: &ContractId) -> io::Result<Option<types::Contract>> {
         let query = schema::contract::ContractByIdQuery::build(ContractByIdArgs {
             id: (*id).into(),
+            block_height: None,
         });
         let contract = self.query(query).await?.contract.map(Into::into);
         Ok(contract)
     }
 
+    /// Returns the contract if it existed at the block `height`.
+    /// Requires the node to keep the historical state of the block.
+    pub async fn contract_at_height(
+        &self,
+        id: &ContractId,
+        height: BlockHeight,
+    ) -> io::Result<Option<types::Contract>> {
+        let query = schema::contract::ContractByIdQuery::build(ContractByIdArgs {
+            id: (*id).into(),
+            block_height: Some(height.into()),
+        });
+        let contract = self.query(query).await?.contract.map(Into::into);
+        Ok(contract)
+    }
+
+    /// Returns the value of the storage slot of the contract at the block `height`,
+    /// or at the latest block if the height isn't specified.
+    pub async fn contract_state(
+        &self,
+        id: &ContractId,
+        key: &Bytes32,
+        height: Option<BlockHeight>,
+    ) -> io::Result<Option<Bytes32>> {
+        let query = schema::contract::ContractStateQuery::build(ContractStateArgs {
+            contract: (*id).into(),
+            key: (*key).into(),
+            block_height: height.map(Into::into),
+        });
+        let value = self.query(query).await?.contract_state.map(Into::into);
+        Ok(value)
+    }
+
     pub async fn contract_balance(
         &self,
         id: &ContractId,
@@ -817,7 +1366,33 @@ impl FuelClient {
             Some(asset_id) => (*asset_id).into(),
             None => schema::AssetId::default(),
         };
-        let query = schema::balance::BalanceQuery::build(BalanceArgs { owner, asset_id });
+        let query = schema::balance::BalanceQuery::build(BalanceArgs {
+            owner,
+            asset_id,
+            block_height: None,
+        });
+        let balance: types::Balance = self.query(query).await?.balance.into();
+        Ok(balance.amount)
+    }
+
+    /// Returns the balance of the `owner` at the block `height`.
+    /// Requires the node to keep the historical state of the block.
+    pub async fn balance_at_height(
+        &self,
+        owner: &Address,
+        asset_id: Option<&AssetId>,
+        height: BlockHeight,
+    ) -> io::Result<u64> {
+        let owner: schema::Address = (*owner).into();
+        let asset_id: schema::AssetId = match asset_id {
+            Some(asset_id) => (*asset_id).into(),
+            None => schema::AssetId::default(),
+        };
+        let query = schema::balance::BalanceQuery::build(BalanceArgs {
+            owner,
+            asset_id,
+            block_height: Some(height.into()),
+        });
         let balance: types::Balance = self.query(query).await?.balance.into();
         Ok(balance.amount)
     }
@@ -835,6 +1410,33 @@ impl FuelClient {
         Ok(balances)
     }
 
+    /// Returns the supply of the asset, if the node indexes the assets.
+    pub async fn asset_supply(
+        &self,
+        asset_id: &AssetId,
+    ) -> io::Result<Option<types::AssetSupply>> {
+        let query = schema::asset::AssetSupplyQuery::build(AssetSupplyArgs {
+            asset_id: (*asset_id).into(),
+        });
+
+        let supply = self.query(query).await?.asset_supply.map(Into::into);
+        Ok(supply)
+    }
+
+    /// Returns the addresses holding the asset from the largest balance,
+    /// if the node indexes the assets.
+    pub async fn asset_holders(
+        &self,
+        asset_id: &AssetId,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::AssetHolder, String>> {
+        let asset_id: schema::AssetId = (*asset_id).into();
+        let query = schema::asset::AssetHoldersQuery::build((asset_id, request).into());
+
+        let holders = self.query(query).await?.asset_holders.into();
+        Ok(holders)
+    }
+
     pub async fn contract_balances(
         &self,
         contract: &ContractId,
@@ -849,6 +1451,27 @@ impl FuelClient {
         Ok(balances)
     }
 
+    /// Returns the storage slots of the contract ordered by their keys, starting
+    /// from the `start_key` and ending before the `end_key`.
+    pub async fn contract_slots(
+        &self,
+        contract: &ContractId,
+        start_key: Option<&Bytes32>,
+        end_key: Option<&Bytes32>,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::ContractSlot, String>> {
+        let contract_id: schema::ContractId = (*contract).into();
+        let start_key = start_key.map(|key| (*key).into());
+        let end_key = end_key.map(|key| (*key).into());
+        let query = schema::contract::ContractSlotsQuery::build(
+            (contract_id, start_key, end_key, request).into(),
+        );
+
+        let slots = self.query(query).await?.contract_slots.into();
+
+        Ok(slots)
+    }
+
     pub async fn messages(
         &self,
         owner: Option<&Address>,
@@ -886,6 +1509,85 @@ impl FuelClient {
 
         Ok(proof)
     }
+
+    /// Request the merkle proofs of the output messages against the same commit block.
+    /// The proofs are returned in the order of the `messages`, the proof is `None`
+    /// if the message can't be proven.
+    pub async fn message_proofs(
+        &self,
+        messages: &[(TxId, MessageId)],
+        commit_block_id: Option<&BlockId>,
+        commit_block_height: Option<BlockHeight>,
+    ) -> io::Result<Vec<Option<types::MessageProof>>> {
+        let messages = messages
+            .iter()
+            .map(|(transaction_id, message_id)| MessageProofInput {
+                transaction_id: (*transaction_id).into(),
+                message_id: (*message_id).into(),
+            })
+            .collect();
+        let commit_block_id: Option<schema::BlockId> =
+            commit_block_id.map(|commit_block_id| (*commit_block_id).into());
+        let commit_block_height = commit_block_height.map(Into::into);
+        let query = schema::message::MessageProofsQuery::build(MessageProofsArgs {
+            messages,
+            commit_block_id,
+            commit_block_height,
+        });
+
+        let proofs = self
+            .query(query)
+            .await?
+            .message_proofs
+            .into_iter()
+            .map(|proof| proof.map(Into::into))
+            .collect();
+
+        Ok(proofs)
+    }
+
+    /// Request the merkle proofs of the output messages and aggregate them for the
+    /// submission to the L1 contract at once. Fails if any of the messages can't
+    /// be proven.
+    pub async fn message_proof_batch(
+        &self,
+        messages: &[(TxId, MessageId)],
+        commit_block_id: Option<&BlockId>,
+        commit_block_height: Option<BlockHeight>,
+    ) -> io::Result<types::MessageProofBatch> {
+        let proofs = self
+            .message_proofs(messages, commit_block_id, commit_block_height)
+            .await?
+            .into_iter()
+            .zip(messages)
+            .map(|(proof, (_, message_id))| {
+                proof.ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::NotFound,
+                        format!("The message {} can't be proven", message_id),
+                    )
+                })
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        types::MessageProofBatch::new(proofs).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "No messages to prove")
+        })
+    }
+
+    /// Verifies the message proof received from any source against the chain of the node.
+    /// The node only needs the headers of the chain, so it can be a light client.
+    pub async fn verify_message_proof(
+        &self,
+        proof: &types::MessageProof,
+    ) -> io::Result<bool> {
+        if !proof.verify() {
+            return Ok(false)
+        }
+
+        let commit_block = self.block(&proof.commit_block_header.id).await?;
+        Ok(commit_block.is_some())
+    }
 }
 
 #[cfg(any(test, feature = "test-helpers"))]
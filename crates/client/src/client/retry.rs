@@ -0,0 +1,153 @@
+//! The retries of the requests failed because of the transient errors, like the
+//! restarting node or the overloaded proxy in front of it.
+//!
+//! The queries don't change the state of the node, so they are retried on any
+//! transient error. The mutations are retried only if the request didn't reach the
+//! node, so the same transaction is never submitted twice by the client itself.
+
+use reqwest::StatusCode;
+use std::{
+    io,
+    time::Duration,
+};
+
+/// The default upper bound of the delay between the attempts.
+const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(10);
+
+/// The policy of retrying the failed requests with the exponential backoff.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The maximum number of the attempts, including the first one.
+    pub max_attempts: u32,
+    /// The delay after the first failed attempt. Every next delay is doubled.
+    pub initial_backoff: Duration,
+    /// The upper bound of the delay between the attempts.
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Sends every request only once.
+    pub const NONE: Self = Self {
+        max_attempts: 1,
+        initial_backoff: Duration::ZERO,
+        max_backoff: Duration::ZERO,
+    };
+
+    /// Makes up to `max_attempts` attempts, waiting `initial_backoff` after the
+    /// first failure and twice as long after every next one.
+    pub fn exponential(max_attempts: u32, initial_backoff: Duration) -> Self {
+        Self {
+            max_attempts,
+            initial_backoff,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+        }
+    }
+
+    /// Returns the delay after the failed `attempt`, counting from 1.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+/// The failure of the single attempt to send the GraphQL request.
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum RequestError {
+    #[error("Failed to send the request: {0}")]
+    Transport(#[from] reqwest::Error),
+    #[error("The node responded with {0}: {1}")]
+    Status(StatusCode, String),
+    #[error("Failed to decode the response: {0}")]
+    Decode(#[from] serde_json::Error),
+}
+
+impl RequestError {
+    /// Returns `true` if the request may succeed when sent again.
+    pub fn is_retryable(&self, mutation: bool) -> bool {
+        match self {
+            RequestError::Transport(e) if is_connect(e) => true,
+            RequestError::Transport(e) => !mutation && (e.is_timeout() || e.is_request()),
+            RequestError::Status(status, _) => {
+                !mutation
+                    && matches!(
+                        *status,
+                        StatusCode::TOO_MANY_REQUESTS
+                            | StatusCode::BAD_GATEWAY
+                            | StatusCode::SERVICE_UNAVAILABLE
+                            | StatusCode::GATEWAY_TIMEOUT
+                    )
+            }
+            RequestError::Decode(_) => false,
+        }
+    }
+}
+
+impl From<RequestError> for io::Error {
+    fn from(e: RequestError) -> Self {
+        io::Error::new(io::ErrorKind::Other, e)
+    }
+}
+
+/// The connection wasn't established, so the node hasn't received the request.
+#[cfg(not(wasm_transport))]
+fn is_connect(e: &reqwest::Error) -> bool {
+    e.is_connect()
+}
+
+/// The fetch API of the browser doesn't tell whether the request reached the node.
+#[cfg(wasm_transport)]
+fn is_connect(_: &reqwest::Error) -> bool {
+    false
+}
+
+/// Returns `true` if the GraphQL `query` is the mutation.
+pub(crate) fn is_mutation(query: &str) -> bool {
+    query.trim_start().starts_with("mutation")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_grows_exponentially_up_to_the_limit() {
+        let policy = RetryPolicy {
+            max_attempts: 10,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(1),
+        };
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(4), Duration::from_millis(800));
+        assert_eq!(policy.backoff(5), Duration::from_secs(1));
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn mutations_are_retried_only_if_not_received() {
+        let unavailable =
+            RequestError::Status(StatusCode::SERVICE_UNAVAILABLE, String::new());
+        assert!(unavailable.is_retryable(false));
+        assert!(!unavailable.is_retryable(true));
+
+        let rejected = RequestError::Status(StatusCode::BAD_REQUEST, String::new());
+        assert!(!rejected.is_retryable(false));
+    }
+
+    #[test]
+    fn mutations_are_detected_by_the_operation_type() {
+        assert!(is_mutation(
+            "mutation($tx: HexString!) { submit(tx: $tx) { id } }"
+        ));
+        assert!(!is_mutation("query { health }"));
+    }
+}
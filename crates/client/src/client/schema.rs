@@ -23,15 +23,19 @@ use crate::client::pagination::{
 };
 pub use primitives::*;
 
+pub mod asset;
 pub mod balance;
 pub mod block;
 pub mod chain;
 pub mod coins;
 pub mod contract;
+pub mod gas_price;
 pub mod message;
 pub mod node_info;
 pub mod primitives;
+pub mod sync;
 pub mod tx;
+pub mod txpool;
 
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
@@ -0,0 +1,131 @@
+use crate::client::{
+    schema::{
+        schema,
+        Address,
+        AssetId,
+        PageInfo,
+        U64,
+    },
+    PageDirection,
+    PaginationRequest,
+};
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct AssetSupplyArgs {
+    pub asset_id: AssetId,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "AssetSupplyArgs"
+)]
+pub struct AssetSupplyQuery {
+    #[arguments(assetId: $asset_id)]
+    pub asset_supply: Option<AssetSupply>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AssetSupply {
+    pub minted: U64,
+    pub burned: U64,
+    pub total: U64,
+    pub holders: U64,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct AssetHoldersConnectionArgs {
+    /// The asset held by the addresses
+    pub asset_id: AssetId,
+    /// Skip until the holder (forward pagination)
+    pub after: Option<String>,
+    /// Skip until the holder (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n holders in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n holders in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(AssetId, PaginationRequest<String>)> for AssetHoldersConnectionArgs {
+    fn from(r: (AssetId, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => AssetHoldersConnectionArgs {
+                asset_id: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results as i32),
+                last: None,
+            },
+            PageDirection::Backward => AssetHoldersConnectionArgs {
+                asset_id: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results as i32),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "AssetHoldersConnectionArgs"
+)]
+pub struct AssetHoldersQuery {
+    #[arguments(assetId: $asset_id, after: $after, before: $before, first: $first, last: $last)]
+    pub asset_holders: AssetHolderConnection,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AssetHolderConnection {
+    pub edges: Vec<AssetHolderEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AssetHolderEdge {
+    pub cursor: String,
+    pub node: AssetHolder,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AssetHolder {
+    pub owner: Address,
+    pub amount: U64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn asset_supply_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = AssetSupplyQuery::build(AssetSupplyArgs {
+            asset_id: AssetId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn asset_holders_connection_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = AssetHoldersQuery::build(AssetHoldersConnectionArgs {
+            asset_id: AssetId::default(),
+            after: None,
+            before: None,
+            first: None,
+            last: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+}
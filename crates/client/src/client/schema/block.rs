@@ -1,16 +1,21 @@
 use crate::client::schema::{
     schema,
+    Address,
+    AssetId,
     BlockId,
     ConnectionArgs,
+    ContractId,
     PageInfo,
     Signature,
     Tai64Timestamp,
+    TransactionId,
     U32,
     U64,
 };
 use fuel_core_types::fuel_crypto;
 
 use super::{
+    coins::Coin,
     tx::TransactionIdFragment,
     Bytes32,
 };
@@ -47,6 +52,50 @@ pub struct BlockByHeightQuery {
     pub block: Option<Block>,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "BlockByHeightArgs"
+)]
+pub struct StateDiffQuery {
+    #[arguments(height: $height)]
+    pub block: Option<BlockStateDiff>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Block")]
+pub struct BlockStateDiff {
+    pub state_diff: Option<StateDiff>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct StateDiff {
+    pub created_coins: Vec<Coin>,
+    pub spent_coins: Vec<Coin>,
+    pub contract_storage_writes: Vec<ContractStorageWrite>,
+    pub balance_deltas: Vec<BalanceDelta>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStorageWrite {
+    pub contract_id: ContractId,
+    pub key: Bytes32,
+    pub value: Option<Bytes32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BalanceDelta {
+    pub address: Option<Address>,
+    pub contract_id: Option<ContractId>,
+    pub asset_id: AssetId,
+    pub credit: U64,
+    pub debit: U64,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -104,6 +153,46 @@ pub struct BlockMutation {
     pub produce_blocks: U32,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Subscription")]
+pub struct NewBlocksSubscription {
+    pub new_blocks: Header,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Mutation")]
+pub struct PauseBlockProduction {
+    pub pause_block_production: bool,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Mutation")]
+pub struct ResumeBlockProduction {
+    pub resume_block_production: bool,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct DryRunBlockQuery {
+    pub dry_run_block: DryRunBlock,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DryRunBlock {
+    pub header: Header,
+    pub transactions: Vec<TransactionIdFragment>,
+    pub gas_used: U64,
+    pub excluded_transactions: Vec<ExcludedTransaction>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ExcludedTransaction {
+    pub id: TransactionId,
+    pub reason: String,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct Header {
@@ -181,6 +270,15 @@ mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn state_diff_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = StateDiffQuery::build(BlockByHeightArgs {
+            height: Some(U64(0)),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn block_mutation_query_gql_output() {
         use cynic::MutationBuilder;
@@ -191,6 +289,20 @@ mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn pause_block_production_mutation_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = PauseBlockProduction::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn dry_run_block_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = DryRunBlockQuery::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn blocks_connection_query_gql_output() {
         use cynic::QueryBuilder;
@@ -202,4 +314,11 @@ mod tests {
         });
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn new_blocks_subscription_gql_output() {
+        use cynic::SubscriptionBuilder;
+        let operation = NewBlocksSubscription::build(());
+        insta::assert_snapshot!(operation.query)
+    }
 }
@@ -284,6 +284,36 @@ pub struct ChainInfo {
     pub consensus_parameters: ConsensusParameters,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ChainStatsArgs {
+    pub window: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ChainStatsArgs"
+)]
+pub struct ChainStatsQuery {
+    #[arguments(window: $window)]
+    pub chain_stats: ChainStats,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ChainStats {
+    pub blocks: U32,
+    pub transactions: U64,
+    pub transactions_per_second: f64,
+    pub average_block_interval: f64,
+    pub p50_block_interval: U64,
+    pub p90_block_interval: U64,
+    pub p99_block_interval: U64,
+    pub average_gas_used: U64,
+    pub pool_depth: U64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -294,4 +324,13 @@ mod tests {
         let operation = ChainQuery::build(());
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn chain_stats_gql_query_output() {
+        use cynic::QueryBuilder;
+        let operation = ChainStatsQuery::build(ChainStatsArgs {
+            window: Some(10.into()),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
 }
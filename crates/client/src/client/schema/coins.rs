@@ -51,6 +51,8 @@ pub struct CoinsConnectionArgs {
     /// Retrieve the last n coins in order (backward pagination).
     /// Can't be used at the same time as `first`.
     pub last: Option<i32>,
+    /// Query the coins at the height of the block instead of the latest one
+    pub block_height: Option<U32>,
 }
 
 impl From<(Address, AssetId, PaginationRequest<String>)> for CoinsConnectionArgs {
@@ -65,6 +67,7 @@ impl From<(Address, AssetId, PaginationRequest<String>)> for CoinsConnectionArgs
                 before: None,
                 first: Some(r.2.results as i32),
                 last: None,
+                block_height: None,
             },
             PageDirection::Backward => CoinsConnectionArgs {
                 filter: CoinFilterInput {
@@ -75,6 +78,7 @@ impl From<(Address, AssetId, PaginationRequest<String>)> for CoinsConnectionArgs
                 before: r.2.cursor,
                 first: None,
                 last: Some(r.2.results as i32),
+                block_height: None,
             },
         }
     }
@@ -87,7 +91,14 @@ impl From<(Address, AssetId, PaginationRequest<String>)> for CoinsConnectionArgs
     variables = "CoinsConnectionArgs"
 )]
 pub struct CoinsQuery {
-    #[arguments(filter: $filter, after: $after, before: $before, first: $first, last: $last)]
+    #[arguments(
+        filter: $filter,
+        after: $after,
+        before: $before,
+        first: $first,
+        last: $last,
+        blockHeight: $block_height
+    )]
     pub coins: CoinConnection,
 }
 
@@ -212,6 +223,42 @@ pub struct CoinsToSpendQuery {
     pub coins_to_spend: Vec<Vec<CoinType>>,
 }
 
+#[derive(cynic::Enum, Copy, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum CoinEventKind {
+    Created,
+    Spent,
+    CreationReverted,
+    SpendReverted,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinEvent {
+    pub kind: CoinEventKind,
+    pub block_height: U32,
+    pub utxo_id: UtxoId,
+    pub owner: Address,
+    pub asset_id: AssetId,
+    pub amount: U64,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinChangesArgs {
+    pub owner: Address,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "CoinChangesArgs"
+)]
+pub struct CoinChangesSubscription {
+    #[arguments(owner: $owner)]
+    pub coin_changes: CoinEvent,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -237,6 +284,16 @@ mod tests {
             before: None,
             first: None,
             last: None,
+            block_height: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn coin_changes_subscription_gql_output() {
+        use cynic::SubscriptionBuilder;
+        let operation = CoinChangesSubscription::build(CoinChangesArgs {
+            owner: Address::default(),
         });
         insta::assert_snapshot!(operation.query)
     }
@@ -2,10 +2,12 @@ use crate::client::{
     schema::{
         schema,
         AssetId,
+        Bytes32,
         ContractId,
         HexString,
         PageInfo,
         Salt,
+        U32,
         U64,
     },
     PageDirection,
@@ -15,6 +17,7 @@ use crate::client::{
 #[derive(cynic::QueryVariables, Debug)]
 pub struct ContractByIdArgs {
     pub id: ContractId,
+    pub block_height: Option<U32>,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -24,10 +27,28 @@ pub struct ContractByIdArgs {
     variables = "ContractByIdArgs"
 )]
 pub struct ContractByIdQuery {
-    #[arguments(id: $id)]
+    #[arguments(id: $id, blockHeight: $block_height)]
     pub contract: Option<Contract>,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractStateArgs {
+    pub contract: ContractId,
+    pub key: Bytes32,
+    pub block_height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractStateArgs"
+)]
+pub struct ContractStateQuery {
+    #[arguments(contract: $contract, key: $key, blockHeight: $block_height)]
+    pub contract_state: Option<Bytes32>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct ContractBalance {
@@ -135,6 +156,96 @@ impl From<(ContractId, PaginationRequest<String>)> for ContractBalancesConnectio
     }
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractSlot {
+    pub key: Bytes32,
+    pub value: Bytes32,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractSlotsConnectionArgs {
+    pub contract_id: ContractId,
+    /// The key to start the slots from, if `after` is unset
+    pub start_key: Option<Bytes32>,
+    /// The key to end the slots before
+    pub end_key: Option<Bytes32>,
+    /// Skip until the key (forward pagination)
+    pub after: Option<String>,
+    /// Skip until the key (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n slots in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n slots in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractSlotEdge {
+    pub cursor: String,
+    pub node: ContractSlot,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractSlotConnection {
+    pub edges: Vec<ContractSlotEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractSlotsConnectionArgs"
+)]
+pub struct ContractSlotsQuery {
+    #[arguments(contractId: $contract_id, startKey: $start_key, endKey: $end_key, after: $after, before: $before, first: $first, last: $last)]
+    pub contract_slots: ContractSlotConnection,
+}
+
+impl
+    From<(
+        ContractId,
+        Option<Bytes32>,
+        Option<Bytes32>,
+        PaginationRequest<String>,
+    )> for ContractSlotsConnectionArgs
+{
+    fn from(
+        r: (
+            ContractId,
+            Option<Bytes32>,
+            Option<Bytes32>,
+            PaginationRequest<String>,
+        ),
+    ) -> Self {
+        let (contract_id, start_key, end_key, request) = r;
+        match request.direction {
+            PageDirection::Forward => ContractSlotsConnectionArgs {
+                contract_id,
+                start_key,
+                end_key,
+                after: request.cursor,
+                before: None,
+                first: Some(request.results as i32),
+                last: None,
+            },
+            PageDirection::Backward => ContractSlotsConnectionArgs {
+                contract_id,
+                start_key,
+                end_key,
+                after: None,
+                before: request.cursor,
+                first: None,
+                last: Some(request.results as i32),
+            },
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -144,6 +255,33 @@ mod tests {
         use cynic::QueryBuilder;
         let operation = ContractByIdQuery::build(ContractByIdArgs {
             id: ContractId::default(),
+            block_height: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn contract_state_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = ContractStateQuery::build(ContractStateArgs {
+            contract: ContractId::default(),
+            key: Bytes32::default(),
+            block_height: Some(1.into()),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn contract_slots_connection_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = ContractSlotsQuery::build(ContractSlotsConnectionArgs {
+            contract_id: ContractId::default(),
+            start_key: Some(Bytes32::default()),
+            end_key: None,
+            after: None,
+            before: None,
+            first: Some(10),
+            last: None,
         });
         insta::assert_snapshot!(operation.query)
     }
@@ -0,0 +1,114 @@
+use crate::client::schema::{
+    schema,
+    HexString,
+    U32,
+    U64,
+};
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct LatestGasPrice {
+    pub gas_price: U64,
+    pub block_height: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryLatestGasPrice {
+    pub latest_gas_price: LatestGasPrice,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct EstimateGasPrice {
+    pub gas_price: U64,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BlockHorizonArgs {
+    pub block_horizon: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "BlockHorizonArgs"
+)]
+pub struct QueryEstimateGasPrice {
+    #[arguments(blockHorizon: $block_horizon)]
+    pub estimate_gas_price: EstimateGasPrice,
+}
+
+#[derive(cynic::Enum, Copy, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum FeeConfidence {
+    Low,
+    Medium,
+    High,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct FeeEstimate {
+    pub confidence: FeeConfidence,
+    pub gas_price: U64,
+    pub gas_limit: U64,
+    pub gas_used: U64,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct EstimateFeesArgs {
+    pub tx: HexString,
+    pub confidence: Option<FeeConfidence>,
+    pub utxo_validation: Option<bool>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "EstimateFeesArgs"
+)]
+pub struct QueryEstimateFees {
+    #[arguments(tx: $tx, confidence: $confidence, utxoValidation: $utxo_validation)]
+    pub estimate_fees: FeeEstimate,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn latest_gas_price_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = QueryLatestGasPrice::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn estimate_gas_price_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = QueryEstimateGasPrice::build(BlockHorizonArgs {
+            block_horizon: Some(10u32.into()),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn estimate_fees_query_gql_output() {
+        use crate::client::schema::Bytes;
+        use cynic::QueryBuilder;
+        use fuel_core_types::{
+            fuel_tx,
+            fuel_types::bytes::SerializableVec,
+        };
+        let mut tx = fuel_tx::Transaction::default_test_tx();
+        let operation = QueryEstimateFees::build(EstimateFeesArgs {
+            tx: HexString(Bytes(tx.to_bytes())),
+            confidence: Some(FeeConfidence::High),
+            utxo_validation: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+}
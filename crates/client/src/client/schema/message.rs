@@ -140,6 +140,45 @@ pub struct MessageProofArgs {
     pub commit_block_height: Option<U32>,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "MessageProofsArgs"
+)]
+pub struct MessageProofsQuery {
+    #[arguments(
+        messages: $messages,
+        commitBlockId: $commit_block_id,
+        commitBlockHeight: $commit_block_height
+    )]
+    pub message_proofs: Vec<Option<MessageProof>>,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct MessageProofInput {
+    /// Transaction id that contains the output message.
+    pub transaction_id: TransactionId,
+    /// Message id of the output message that requires a proof.
+    pub message_id: MessageId,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct MessageProofsArgs {
+    /// The output messages that require the proofs.
+    pub messages: Vec<MessageProofInput>,
+
+    /// The query supports either `commit_block_id`, or `commit_block_height` set on, not both.
+
+    /// The block id of the commitment block.
+    /// If it is `None`, the `commit_block_height` should be `Some`.
+    pub commit_block_id: Option<BlockId>,
+    /// The block height of the commitment block.
+    /// If it is `None`, the `commit_block_id` should be `Some`.
+    pub commit_block_height: Option<U32>,
+}
+
 impl From<(Option<Address>, PaginationRequest<String>)> for OwnedMessagesConnectionArgs {
     fn from(r: (Option<Address>, PaginationRequest<String>)) -> Self {
         match r.1.direction {
@@ -179,4 +218,17 @@ mod tests {
 
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn message_proofs_query_gql_output() {
+        use cynic::QueryBuilder;
+
+        let operation = MessageProofsQuery::build(MessageProofsArgs {
+            messages: vec![],
+            commit_block_id: None,
+            commit_block_height: Some(1.into()),
+        });
+
+        insta::assert_snapshot!(operation.query)
+    }
 }
@@ -1,5 +1,6 @@
 use crate::client::schema::{
     schema,
+    U32,
     U64,
 };
 
@@ -20,6 +21,89 @@ pub struct QueryNodeInfo {
     pub node_info: NodeInfo,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "NodeInfo")]
+pub struct PeersInfo {
+    pub peers: Vec<PeerInfo>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryPeersInfo {
+    pub node_info: PeersInfo,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PeerInfo {
+    pub id: String,
+    pub addresses: Vec<String>,
+    pub client_version: Option<String>,
+    pub block_height: Option<U32>,
+    pub last_heartbeat_ms: Option<U64>,
+    pub connection_age_ms: U64,
+    pub app_score: f64,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct PeerIdArgs {
+    pub id: String,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "PeerIdArgs"
+)]
+pub struct DisconnectPeer {
+    #[arguments(id: $id)]
+    pub disconnect_peer: bool,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "PeerIdArgs"
+)]
+pub struct BanPeer {
+    #[arguments(id: $id)]
+    pub ban_peer: bool,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CompactDatabaseArgs {
+    pub column: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "CompactDatabaseArgs"
+)]
+pub struct CompactDatabase {
+    #[arguments(column: $column)]
+    pub compact_database: bool,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct DatabaseCheckpointArgs {
+    pub path: String,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DatabaseCheckpointArgs"
+)]
+pub struct CreateDatabaseCheckpoint {
+    #[arguments(path: $path)]
+    pub create_database_checkpoint: bool,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -30,4 +114,38 @@ mod tests {
         let operation = QueryNodeInfo::build(());
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn peers_info_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = QueryPeersInfo::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn ban_peer_mutation_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = BanPeer::build(PeerIdArgs {
+            id: String::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn compact_database_mutation_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = CompactDatabase::build(CompactDatabaseArgs {
+            column: Some(String::default()),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn create_database_checkpoint_mutation_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = CreateDatabaseCheckpoint::build(DatabaseCheckpointArgs {
+            path: String::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
 }
@@ -0,0 +1,32 @@
+use crate::client::schema::{
+    schema,
+    U32,
+    U64,
+};
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct SyncStatus {
+    pub current_height: U32,
+    pub highest_seen_height: U32,
+    pub peers_synced_against: U32,
+    pub estimated_seconds_remaining: Option<U64>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QuerySyncStatus {
+    pub sync_status: Option<SyncStatus>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sync_status_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = QuerySyncStatus::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+}
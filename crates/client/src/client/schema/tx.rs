@@ -4,12 +4,18 @@ use crate::client::{
         schema,
         tx::transparent_receipt::Receipt,
         Address,
+        AssetId,
+        Bytes,
         ConnectionArgs,
+        ContractId,
         ConversionError,
         HexString,
         PageInfo,
         Tai64Timestamp,
         TransactionId,
+        UtxoId,
+        U32,
+        U64,
     },
     types::TransactionResponse,
     PageDirection,
@@ -23,6 +29,7 @@ use fuel_core_types::{
         Bytes32,
     },
     fuel_vm,
+    services::executor::StateOverrides,
 };
 use std::convert::{
     TryFrom,
@@ -252,6 +259,45 @@ pub struct StatusChangeSubscription {
     pub status_change: TransactionStatus,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TxIdsArgs {
+    pub ids: Vec<TransactionId>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TxOwnerArgs {
+    pub owner: Address,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransactionStatusUpdate {
+    pub id: TransactionId,
+    pub status: TransactionStatus,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "TxIdsArgs"
+)]
+pub struct StatusChangesSubscription {
+    #[arguments(ids: $ids)]
+    pub status_changes: TransactionStatusUpdate,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "TxOwnerArgs"
+)]
+pub struct StatusChangesByOwnerSubscription {
+    #[arguments(owner: $owner)]
+    pub status_changes_by_owner: TransactionStatusUpdate,
+}
+
 // mutations
 
 #[derive(cynic::QueryVariables)]
@@ -274,6 +320,7 @@ pub struct EstimatePredicates {
 pub struct DryRunArg {
     pub tx: HexString,
     pub utxo_validation: Option<bool>,
+    pub state_overrides: Option<StateOverridesInput>,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -283,10 +330,163 @@ pub struct DryRunArg {
     variables = "DryRunArg"
 )]
 pub struct DryRun {
-    #[arguments(tx: $tx, utxoValidation: $utxo_validation)]
+    #[arguments(
+        tx: $tx,
+        utxoValidation: $utxo_validation,
+        stateOverrides: $state_overrides
+    )]
     pub dry_run: Vec<Receipt>,
 }
 
+/// The ephemeral changes of the state visible only to the dry run.
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct StateOverridesInput {
+    /// The coins added to the state, for example, to fund the spender.
+    pub coins: Option<Vec<CoinOverrideInput>>,
+    /// The new values of the contract storage slots.
+    pub contract_state: Option<Vec<ContractStateOverrideInput>>,
+    /// The bytecode that replaces the code of the contract.
+    pub contract_code: Option<Vec<ContractCodeOverrideInput>>,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinOverrideInput {
+    /// The UTXO id of the coin, referenced by the input of the transaction.
+    pub utxo_id: UtxoId,
+    /// The owner of the coin.
+    pub owner: Address,
+    /// The amount of the coin.
+    pub amount: U64,
+    /// The asset of the coin.
+    pub asset_id: AssetId,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStateOverrideInput {
+    /// The contract owning the storage slot.
+    pub contract_id: ContractId,
+    /// The key of the storage slot.
+    pub key: super::Bytes32,
+    /// The new value of the storage slot.
+    pub value: super::Bytes32,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractCodeOverrideInput {
+    /// The contract whose code is replaced.
+    pub contract_id: ContractId,
+    /// The new bytecode of the contract.
+    pub bytecode: HexString,
+}
+
+impl From<StateOverrides> for StateOverridesInput {
+    fn from(overrides: StateOverrides) -> Self {
+        let coins = overrides
+            .coins
+            .into_iter()
+            .map(|coin| CoinOverrideInput {
+                utxo_id: coin.utxo_id.into(),
+                owner: coin.owner.into(),
+                amount: coin.amount.into(),
+                asset_id: coin.asset_id.into(),
+            })
+            .collect();
+        let contract_state = overrides
+            .contract_state
+            .into_iter()
+            .map(|(contract_id, key, value)| ContractStateOverrideInput {
+                contract_id: contract_id.into(),
+                key: key.into(),
+                value: value.into(),
+            })
+            .collect();
+        let contract_code = overrides
+            .contract_code
+            .into_iter()
+            .map(|(contract_id, bytecode)| ContractCodeOverrideInput {
+                contract_id: contract_id.into(),
+                bytecode: HexString(Bytes(bytecode)),
+            })
+            .collect();
+        Self {
+            coins: Some(coins),
+            contract_state: Some(contract_state),
+            contract_code: Some(contract_code),
+        }
+    }
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TraceTransactionArg {
+    pub tx: HexString,
+    pub utxo_validation: Option<bool>,
+    pub full_opcode_stream: Option<bool>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "TraceTransactionArg"
+)]
+pub struct TraceTransaction {
+    #[arguments(
+        tx: $tx,
+        utxoValidation: $utxo_validation,
+        fullOpcodeStream: $full_opcode_stream
+    )]
+    pub trace_transaction: TransactionTrace,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransactionTrace {
+    pub receipts: Vec<Receipt>,
+    pub frames: Vec<CallFrameTrace>,
+    pub opcode_counts: Vec<OpcodeCount>,
+    pub opcodes: Option<Vec<TracedInstruction>>,
+    pub panic_registers: Option<Vec<U64>>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CallFrameTrace {
+    pub depth: U32,
+    pub contract_id: ContractId,
+    pub asset_id: AssetId,
+    pub amount: U64,
+    pub gas: U64,
+    pub outcome: CallOutcome,
+}
+
+#[derive(cynic::Enum, Copy, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum CallOutcome {
+    Return,
+    Panic,
+    Revert,
+    Unfinished,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct OpcodeCount {
+    pub opcode: String,
+    pub count: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TracedInstruction {
+    pub pc: U64,
+    pub is: U64,
+    pub opcode: Option<String>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -298,6 +498,40 @@ pub struct Submit {
     pub submit: TransactionIdFragment,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "TxArg"
+)]
+pub struct SubmitLocal {
+    #[arguments(tx: $tx)]
+    pub submit_local: TransactionIdFragment,
+}
+
+#[derive(cynic::QueryVariables)]
+pub struct TxsArg {
+    pub txs: Vec<HexString>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BatchSubmitResult {
+    pub id: TransactionId,
+    pub error: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "TxsArg"
+)]
+pub struct SubmitBatch {
+    #[arguments(txs: $txs)]
+    pub submit_batch: Vec<BatchSubmitResult>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -318,7 +552,6 @@ pub struct AllReceipts {
 #[cfg(test)]
 pub mod tests {
     use super::*;
-    use crate::client::schema::Bytes;
     use fuel_core_types::fuel_types::bytes::SerializableVec;
 
     #[test]
@@ -372,6 +605,19 @@ pub mod tests {
         let query = DryRun::build(DryRunArg {
             tx: HexString(Bytes(tx.to_bytes())),
             utxo_validation: None,
+            state_overrides: None,
+        });
+        insta::assert_snapshot!(query.query)
+    }
+
+    #[test]
+    fn trace_transaction_gql_output() {
+        use cynic::MutationBuilder;
+        let mut tx = fuel_tx::Transaction::default_test_tx();
+        let query = TraceTransaction::build(TraceTransactionArg {
+            tx: HexString(Bytes(tx.to_bytes())),
+            utxo_validation: None,
+            full_opcode_stream: Some(true),
         });
         insta::assert_snapshot!(query.query)
     }
@@ -385,4 +631,24 @@ pub mod tests {
         });
         insta::assert_snapshot!(query.query)
     }
+
+    #[test]
+    fn submit_local_tx_gql_output() {
+        use cynic::MutationBuilder;
+        let mut tx = fuel_tx::Transaction::default_test_tx();
+        let query = SubmitLocal::build(TxArg {
+            tx: HexString(Bytes(tx.to_bytes())),
+        });
+        insta::assert_snapshot!(query.query)
+    }
+
+    #[test]
+    fn submit_batch_gql_output() {
+        use cynic::MutationBuilder;
+        let mut tx = fuel_tx::Transaction::default_test_tx();
+        let query = SubmitBatch::build(TxsArg {
+            txs: vec![HexString(Bytes(tx.to_bytes()))],
+        });
+        insta::assert_snapshot!(query.query)
+    }
 }
@@ -0,0 +1,63 @@
+use crate::client::schema::{
+    schema,
+    tx::TransactionConnection,
+    Address,
+    ConnectionArgs,
+    U64,
+};
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ConnectionArgs"
+)]
+pub struct TxPoolTransactionsQuery {
+    #[arguments(after: $after, before: $before, first: $first, last: $last)]
+    pub tx_pool_transactions: TransactionConnection,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TxPoolInfo {
+    pub pending: U64,
+    pub total_gas: U64,
+    pub owners: Vec<TxPoolOwnerInfo>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TxPoolOwnerInfo {
+    pub owner: Address,
+    pub pending: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryTxPoolInfo {
+    pub tx_pool_info: TxPoolInfo,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tx_pool_transactions_connection_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TxPoolTransactionsQuery::build(ConnectionArgs {
+            after: None,
+            before: None,
+            first: None,
+            last: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn tx_pool_info_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = QueryTxPoolInfo::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+}
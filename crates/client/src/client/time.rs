@@ -0,0 +1,49 @@
+//! Timers used by the client. The native transport uses `tokio`, while the
+//! `wasm-transport` relies on the timers of the browser.
+
+use std::{
+    future::Future,
+    time::Duration,
+};
+
+/// The error returned when the future doesn't complete within the timeout.
+#[derive(Debug)]
+pub(crate) struct Elapsed;
+
+#[cfg(not(wasm_transport))]
+pub(crate) async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await
+}
+
+#[cfg(wasm_transport)]
+pub(crate) async fn sleep(duration: Duration) {
+    gloo_timers::future::sleep(duration).await
+}
+
+#[cfg(not(wasm_transport))]
+pub(crate) async fn timeout<F: Future>(
+    duration: Duration,
+    future: F,
+) -> Result<F::Output, Elapsed> {
+    tokio::time::timeout(duration, future)
+        .await
+        .map_err(|_| Elapsed)
+}
+
+#[cfg(wasm_transport)]
+pub(crate) async fn timeout<F: Future>(
+    duration: Duration,
+    future: F,
+) -> Result<F::Output, Elapsed> {
+    use futures::future::{
+        select,
+        Either,
+    };
+
+    let future = std::pin::pin!(future);
+    let sleep = std::pin::pin!(sleep(duration));
+    match select(future, sleep).await {
+        Either::Left((output, _)) => Ok(output),
+        Either::Right(_) => Err(Elapsed),
+    }
+}
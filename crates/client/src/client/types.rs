@@ -1,3 +1,4 @@
+pub mod asset;
 pub mod balance;
 pub mod block;
 pub mod chain_info;
@@ -5,18 +6,37 @@ pub mod coins;
 pub mod consensus_parameters;
 pub mod contract;
 pub mod gas_costs;
+pub mod gas_price;
 pub mod merkle_proof;
 pub mod message;
 pub mod node_info;
+pub mod receipts;
+pub mod sync;
+pub mod trace;
+pub mod txpool;
 
+pub use asset::{
+    AssetHolder,
+    AssetSupply,
+};
 pub use balance::Balance;
 pub use block::{
+    BalanceDelta,
+    BalanceOwner,
     Block,
     Consensus,
+    ContractStorageWrite,
+    DryRunBlock,
+    StateDiff,
+};
+pub use chain_info::{
+    ChainInfo,
+    ChainStats,
 };
-pub use chain_info::ChainInfo;
 pub use coins::{
     Coin,
+    CoinEvent,
+    CoinEventKind,
     CoinType,
     MessageCoin,
 };
@@ -24,17 +44,46 @@ pub use consensus_parameters::ConsensusParameters;
 pub use contract::{
     Contract,
     ContractBalance,
+    ContractSlot,
 };
 pub use gas_costs::{
     DependentCost,
     GasCosts,
 };
+pub use gas_price::{
+    EstimateGasPrice,
+    FeeConfidence,
+    FeeEstimate,
+    LatestGasPrice,
+};
 pub use merkle_proof::MerkleProof;
 pub use message::{
+    BatchedMessageProof,
     Message,
+    MessageBlockProof,
     MessageProof,
+    MessageProofBatch,
+};
+pub use node_info::{
+    NodeInfo,
+    PeerInfo,
+};
+pub use receipts::{
+    Receipts,
+    RevertReason,
+};
+pub use sync::SyncStatus;
+pub use trace::{
+    CallFrameTrace,
+    CallOutcome,
+    TracedInstruction,
+    TransactionTrace,
+};
+pub use txpool::{
+    BatchSubmitResult,
+    TxPoolInfo,
+    TxPoolOwnerInfo,
 };
-pub use node_info::NodeInfo;
 
 use crate::client::schema::{
     tx::{
@@ -0,0 +1,50 @@
+use crate::client::{
+    schema,
+    types::primitives::Address,
+    PaginatedResult,
+};
+
+pub struct AssetSupply {
+    pub minted: u64,
+    pub burned: u64,
+    pub total: u64,
+    pub holders: u64,
+}
+
+pub struct AssetHolder {
+    pub owner: Address,
+    pub amount: u64,
+}
+
+// GraphQL Translation
+
+impl From<schema::asset::AssetSupply> for AssetSupply {
+    fn from(value: schema::asset::AssetSupply) -> Self {
+        AssetSupply {
+            minted: value.minted.into(),
+            burned: value.burned.into(),
+            total: value.total.into(),
+            holders: value.holders.into(),
+        }
+    }
+}
+
+impl From<schema::asset::AssetHolder> for AssetHolder {
+    fn from(value: schema::asset::AssetHolder) -> Self {
+        AssetHolder {
+            owner: value.owner.into(),
+            amount: value.amount.into(),
+        }
+    }
+}
+
+impl From<schema::asset::AssetHolderConnection> for PaginatedResult<AssetHolder, String> {
+    fn from(conn: schema::asset::AssetHolderConnection) -> Self {
+        PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn.edges.into_iter().map(|e| e.node.into()).collect(),
+        }
+    }
+}
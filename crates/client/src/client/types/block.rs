@@ -1,15 +1,24 @@
 use crate::client::{
     schema,
-    types::primitives::{
-        BlockId,
-        Hash,
-        MerkleRoot,
-        PublicKey,
-        Signature,
-        TransactionId,
+    schema::ConversionError,
+    types::{
+        primitives::{
+            Address,
+            AssetId,
+            BlockId,
+            Bytes32,
+            ContractId,
+            Hash,
+            MerkleRoot,
+            PublicKey,
+            Signature,
+            TransactionId,
+        },
+        Coin,
     },
     PaginatedResult,
 };
+use fuel_core_types::fuel_crypto::Hasher;
 use tai64::Tai64;
 
 #[derive(Debug)]
@@ -41,6 +50,70 @@ pub struct Header {
     pub application_hash: Hash,
 }
 
+impl Header {
+    /// Computes the id of the block from the fields of the header.
+    /// It allows to verify the header without trusting the `id` received from the node.
+    pub fn hash(&self) -> BlockId {
+        // Order matters and is the same as the spec.
+        let mut hasher = Hasher::default();
+        hasher.input(self.da_height.to_be_bytes());
+        hasher.input(self.transactions_count.to_be_bytes());
+        hasher.input(self.message_receipt_count.to_be_bytes());
+        hasher.input(self.transactions_root.as_ref());
+        hasher.input(self.message_receipt_root.as_ref());
+        let application_hash = hasher.digest();
+
+        let mut hasher = Hasher::default();
+        hasher.input(self.prev_root.as_ref());
+        hasher.input(self.height.to_be_bytes());
+        hasher.input(self.time.0.to_be_bytes());
+        hasher.input(application_hash.as_ref());
+        hasher.digest()
+    }
+}
+
+/// The next block assembled by the node without committing or signing it.
+#[derive(Debug)]
+pub struct DryRunBlock {
+    pub header: Header,
+    pub transactions: Vec<TransactionId>,
+    pub gas_used: u64,
+    /// The transactions of the pool left out of the block with the reasons.
+    pub excluded_transactions: Vec<(TransactionId, String)>,
+}
+
+/// The changes of the state made by the block.
+#[derive(Debug, PartialEq)]
+pub struct StateDiff {
+    pub created_coins: Vec<Coin>,
+    pub spent_coins: Vec<Coin>,
+    pub contract_storage_writes: Vec<ContractStorageWrite>,
+    pub balance_deltas: Vec<BalanceDelta>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractStorageWrite {
+    pub contract_id: ContractId,
+    pub key: Bytes32,
+    /// `None` if the storage slot was cleared.
+    pub value: Option<Bytes32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BalanceOwner {
+    Address(Address),
+    Contract(ContractId),
+}
+
+/// The balance of the `owner` is increased by the `credit` and decreased by the `debit`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BalanceDelta {
+    pub owner: BalanceOwner,
+    pub asset_id: AssetId,
+    pub credit: u64,
+    pub debit: u64,
+}
+
 #[derive(Debug)]
 pub enum Consensus {
     Genesis(Genesis),
@@ -80,6 +153,49 @@ impl From<schema::block::Header> for Header {
     }
 }
 
+impl TryFrom<schema::block::StateDiff> for StateDiff {
+    type Error = ConversionError;
+
+    fn try_from(value: schema::block::StateDiff) -> Result<Self, Self::Error> {
+        Ok(Self {
+            created_coins: value.created_coins.into_iter().map(Into::into).collect(),
+            spent_coins: value.spent_coins.into_iter().map(Into::into).collect(),
+            contract_storage_writes: value
+                .contract_storage_writes
+                .into_iter()
+                .map(|write| ContractStorageWrite {
+                    contract_id: write.contract_id.into(),
+                    key: write.key.into(),
+                    value: write.value.map(Into::into),
+                })
+                .collect(),
+            balance_deltas: value
+                .balance_deltas
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl TryFrom<schema::block::BalanceDelta> for BalanceDelta {
+    type Error = ConversionError;
+
+    fn try_from(value: schema::block::BalanceDelta) -> Result<Self, Self::Error> {
+        let owner = match (value.address, value.contract_id) {
+            (Some(address), None) => BalanceOwner::Address(address.into()),
+            (None, Some(contract_id)) => BalanceOwner::Contract(contract_id.into()),
+            _ => return Err(ConversionError::MissingField("owner".to_string())),
+        };
+        Ok(Self {
+            owner,
+            asset_id: value.asset_id.into(),
+            credit: value.credit.into(),
+            debit: value.debit.into(),
+        })
+    }
+}
+
 impl From<schema::block::Consensus> for Consensus {
     fn from(value: schema::block::Consensus) -> Self {
         match value {
@@ -133,6 +249,25 @@ impl From<schema::block::Block> for Block {
     }
 }
 
+impl From<schema::block::DryRunBlock> for DryRunBlock {
+    fn from(value: schema::block::DryRunBlock) -> Self {
+        Self {
+            header: value.header.into(),
+            transactions: value
+                .transactions
+                .into_iter()
+                .map(|tx| tx.id.into())
+                .collect(),
+            gas_used: value.gas_used.into(),
+            excluded_transactions: value
+                .excluded_transactions
+                .into_iter()
+                .map(|tx| (tx.id.into(), tx.reason))
+                .collect(),
+        }
+    }
+}
+
 impl From<schema::block::BlockConnection> for PaginatedResult<Block, String> {
     fn from(conn: schema::block::BlockConnection) -> Self {
         PaginatedResult {
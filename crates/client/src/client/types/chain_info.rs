@@ -14,6 +14,22 @@ pub struct ChainInfo {
     pub consensus_parameters: ConsensusParameters,
 }
 
+/// The rolling statistics of the latest blocks imported by the node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainStats {
+    pub blocks: u32,
+    pub transactions: u64,
+    pub transactions_per_second: f64,
+    /// The average interval between the blocks in seconds.
+    pub average_block_interval: f64,
+    pub p50_block_interval: u64,
+    pub p90_block_interval: u64,
+    pub p99_block_interval: u64,
+    pub average_gas_used: u64,
+    /// The number of the pending transactions in the pool.
+    pub pool_depth: u64,
+}
+
 // GraphQL Translation
 
 impl From<schema::chain::ChainInfo> for ChainInfo {
@@ -27,3 +43,19 @@ impl From<schema::chain::ChainInfo> for ChainInfo {
         }
     }
 }
+
+impl From<schema::chain::ChainStats> for ChainStats {
+    fn from(value: schema::chain::ChainStats) -> Self {
+        Self {
+            blocks: value.blocks.into(),
+            transactions: value.transactions.into(),
+            transactions_per_second: value.transactions_per_second,
+            average_block_interval: value.average_block_interval,
+            p50_block_interval: value.p50_block_interval.into(),
+            p90_block_interval: value.p90_block_interval.into(),
+            p99_block_interval: value.p99_block_interval.into(),
+            average_gas_used: value.average_gas_used.into(),
+            pool_depth: value.pool_depth.into(),
+        }
+    }
+}
@@ -45,6 +45,24 @@ pub struct MessageCoin {
     pub da_height: u64,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoinEventKind {
+    Created,
+    Spent,
+    CreationReverted,
+    SpendReverted,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CoinEvent {
+    pub kind: CoinEventKind,
+    pub block_height: u32,
+    pub utxo_id: UtxoId,
+    pub owner: Address,
+    pub asset_id: AssetId,
+    pub amount: u64,
+}
+
 // GraphQL Translation
 
 impl From<schema::coins::CoinType> for CoinType {
@@ -84,6 +102,30 @@ impl From<schema::coins::MessageCoin> for MessageCoin {
     }
 }
 
+impl From<schema::coins::CoinEventKind> for CoinEventKind {
+    fn from(value: schema::coins::CoinEventKind) -> Self {
+        match value {
+            schema::coins::CoinEventKind::Created => Self::Created,
+            schema::coins::CoinEventKind::Spent => Self::Spent,
+            schema::coins::CoinEventKind::CreationReverted => Self::CreationReverted,
+            schema::coins::CoinEventKind::SpendReverted => Self::SpendReverted,
+        }
+    }
+}
+
+impl From<schema::coins::CoinEvent> for CoinEvent {
+    fn from(value: schema::coins::CoinEvent) -> Self {
+        Self {
+            kind: value.kind.into(),
+            block_height: value.block_height.into(),
+            utxo_id: value.utxo_id.into(),
+            owner: value.owner.into(),
+            asset_id: value.asset_id.into(),
+            amount: value.amount.into(),
+        }
+    }
+}
+
 impl From<schema::coins::CoinConnection> for PaginatedResult<Coin, String> {
     fn from(conn: schema::coins::CoinConnection) -> Self {
         PaginatedResult {
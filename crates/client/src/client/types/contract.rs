@@ -3,6 +3,7 @@ use crate::client::{
     types::primitives::{
         AssetId,
         Bytes,
+        Bytes32,
         ContractId,
         Salt,
     },
@@ -22,6 +23,12 @@ pub struct ContractBalance {
     pub asset_id: AssetId,
 }
 
+#[derive(Debug)]
+pub struct ContractSlot {
+    pub key: Bytes32,
+    pub value: Bytes32,
+}
+
 // GraphQL Translation
 
 impl From<schema::contract::Contract> for Contract {
@@ -56,3 +63,25 @@ impl From<schema::contract::ContractBalanceConnection>
         }
     }
 }
+
+impl From<schema::contract::ContractSlot> for ContractSlot {
+    fn from(value: schema::contract::ContractSlot) -> Self {
+        Self {
+            key: value.key.into(),
+            value: value.value.into(),
+        }
+    }
+}
+
+impl From<schema::contract::ContractSlotConnection>
+    for PaginatedResult<ContractSlot, String>
+{
+    fn from(conn: schema::contract::ContractSlotConnection) -> Self {
+        PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn.edges.into_iter().map(|e| e.node.into()).collect(),
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use crate::client::schema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LatestGasPrice {
+    pub gas_price: u64,
+    pub block_height: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EstimateGasPrice {
+    pub gas_price: u64,
+}
+
+/// The likelihood of the transaction to be included into the upcoming blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeeConfidence {
+    Low,
+    Medium,
+    High,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeEstimate {
+    pub confidence: FeeConfidence,
+    pub gas_price: u64,
+    pub gas_limit: u64,
+    pub gas_used: u64,
+}
+
+// GraphQL Translation
+
+impl From<schema::gas_price::LatestGasPrice> for LatestGasPrice {
+    fn from(value: schema::gas_price::LatestGasPrice) -> Self {
+        Self {
+            gas_price: value.gas_price.into(),
+            block_height: value.block_height.into(),
+        }
+    }
+}
+
+impl From<schema::gas_price::EstimateGasPrice> for EstimateGasPrice {
+    fn from(value: schema::gas_price::EstimateGasPrice) -> Self {
+        Self {
+            gas_price: value.gas_price.into(),
+        }
+    }
+}
+
+impl From<FeeConfidence> for schema::gas_price::FeeConfidence {
+    fn from(value: FeeConfidence) -> Self {
+        match value {
+            FeeConfidence::Low => Self::Low,
+            FeeConfidence::Medium => Self::Medium,
+            FeeConfidence::High => Self::High,
+        }
+    }
+}
+
+impl From<schema::gas_price::FeeConfidence> for FeeConfidence {
+    fn from(value: schema::gas_price::FeeConfidence) -> Self {
+        match value {
+            schema::gas_price::FeeConfidence::Low => Self::Low,
+            schema::gas_price::FeeConfidence::Medium => Self::Medium,
+            schema::gas_price::FeeConfidence::High => Self::High,
+        }
+    }
+}
+
+impl From<schema::gas_price::FeeEstimate> for FeeEstimate {
+    fn from(value: schema::gas_price::FeeEstimate) -> Self {
+        Self {
+            confidence: value.confidence.into(),
+            gas_price: value.gas_price.into(),
+            gas_limit: value.gas_limit.into(),
+            gas_used: value.gas_used.into(),
+        }
+    }
+}
@@ -5,12 +5,17 @@ use crate::client::{
         primitives::{
             Address,
             Bytes,
+            MerkleRoot,
             Nonce,
         },
         MerkleProof,
     },
     PaginatedResult,
 };
+use fuel_core_types::{
+    fuel_merkle,
+    fuel_tx::input::message::compute_message_id,
+};
 
 #[derive(Debug)]
 pub struct Message {
@@ -46,6 +51,172 @@ pub struct MessageProof {
     pub data: Bytes,
 }
 
+impl MessageProof {
+    /// Verifies that the message is included into the message block and the message
+    /// block is included into the history of the commit block. The ids of the headers
+    /// are recalculated, so only the commit block header should be trusted.
+    pub fn verify(&self) -> bool {
+        let message_id = compute_message_id(
+            &self.sender,
+            &self.recipient,
+            &self.nonce,
+            self.amount,
+            &self.data,
+        );
+        let message_block_id = self.message_block_header.hash();
+        let commit_block_id = self.commit_block_header.hash();
+
+        message_block_id == self.message_block_header.id
+            && commit_block_id == self.commit_block_header.id
+            && verify_merkle(
+                &self.message_block_header.message_receipt_root,
+                &message_id,
+                &self.message_proof,
+                self.message_block_header.message_receipt_count,
+            )
+            && verify_merkle(
+                &self.commit_block_header.prev_root,
+                &message_block_id,
+                &self.block_proof,
+                self.commit_block_header.height as u64,
+            )
+    }
+}
+
+/// The proofs of the output messages against the same commit block, aggregated for
+/// the submission to the L1 contract at once. The headers shared by the messages
+/// are included only once.
+#[derive(Debug)]
+pub struct MessageProofBatch {
+    /// The consensus header associated with the finalized commit being used
+    /// as the root of the block proofs.
+    pub commit_block_header: Header,
+    /// The distinct blocks that contain the messages.
+    pub message_blocks: Vec<MessageBlockProof>,
+    /// The proven messages.
+    pub messages: Vec<BatchedMessageProof>,
+}
+
+#[derive(Debug)]
+pub struct MessageBlockProof {
+    /// The previous fuel block header that contains the messages. Message block
+    /// height < commit block height.
+    pub header: Header,
+    /// Proof that the block header is contained within the blockchain history.
+    pub block_proof: MerkleProof,
+}
+
+#[derive(Debug)]
+pub struct BatchedMessageProof {
+    /// The index of the block containing the message in the `message_blocks`.
+    pub message_block_index: usize,
+    /// Proof that message is contained within the header of its block.
+    pub message_proof: MerkleProof,
+    /// The messages sender address.
+    pub sender: Address,
+    /// The messages recipient address.
+    pub recipient: Address,
+    /// The nonce from the message.
+    pub nonce: Nonce,
+    /// The amount from the message.
+    pub amount: u64,
+    /// The data from the message.
+    pub data: Bytes,
+}
+
+impl MessageProofBatch {
+    /// Aggregates the proofs of the messages in their order. Returns `None` if the
+    /// `proofs` are empty or don't share the commit block.
+    pub fn new(proofs: Vec<MessageProof>) -> Option<Self> {
+        let mut commit_block_header: Option<Header> = None;
+        let mut message_blocks: Vec<MessageBlockProof> = vec![];
+        let mut messages = Vec::with_capacity(proofs.len());
+
+        for proof in proofs {
+            match commit_block_header.as_ref().map(|header| header.id) {
+                Some(id) if id != proof.commit_block_header.id => return None,
+                Some(_) => {}
+                None => commit_block_header = Some(proof.commit_block_header),
+            }
+
+            let message_block_index = match message_blocks
+                .iter()
+                .position(|block| block.header.id == proof.message_block_header.id)
+            {
+                Some(index) => index,
+                None => {
+                    message_blocks.push(MessageBlockProof {
+                        header: proof.message_block_header,
+                        block_proof: proof.block_proof,
+                    });
+                    message_blocks.len() - 1
+                }
+            };
+
+            messages.push(BatchedMessageProof {
+                message_block_index,
+                message_proof: proof.message_proof,
+                sender: proof.sender,
+                recipient: proof.recipient,
+                nonce: proof.nonce,
+                amount: proof.amount,
+                data: proof.data,
+            });
+        }
+
+        Some(Self {
+            commit_block_header: commit_block_header?,
+            message_blocks,
+            messages,
+        })
+    }
+
+    /// Verifies every proof of the batch the same way as [`MessageProof::verify`].
+    pub fn verify(&self) -> bool {
+        let commit_block = &self.commit_block_header;
+        let blocks_are_valid = self.message_blocks.iter().all(|block| {
+            let block_id = block.header.hash();
+            block_id == block.header.id
+                && verify_merkle(
+                    &commit_block.prev_root,
+                    &block_id,
+                    &block.block_proof,
+                    commit_block.height as u64,
+                )
+        });
+        let messages_are_valid = self.messages.iter().all(|message| {
+            let message_id = compute_message_id(
+                &message.sender,
+                &message.recipient,
+                &message.nonce,
+                message.amount,
+                &message.data,
+            );
+            match self.message_blocks.get(message.message_block_index) {
+                Some(block) => verify_merkle(
+                    &block.header.message_receipt_root,
+                    &message_id,
+                    &message.message_proof,
+                    block.header.message_receipt_count,
+                ),
+                None => false,
+            }
+        });
+
+        commit_block.hash() == commit_block.id && blocks_are_valid && messages_are_valid
+    }
+}
+
+fn verify_merkle<D: AsRef<[u8]>>(
+    root: &MerkleRoot,
+    data: &D,
+    proof: &MerkleProof,
+    leaves_count: u64,
+) -> bool {
+    let proof_set: Vec<_> = proof.proof_set.iter().map(|node| **node).collect();
+    fuel_merkle::binary::verify(root, data, &proof_set, proof.proof_index, leaves_count)
+}
+
 // GraphQL Translation
 
 impl From<schema::message::Message> for Message {
@@ -1,4 +1,5 @@
 use crate::client::schema;
+use std::time::Duration;
 
 pub struct NodeInfo {
     pub utxo_validation: bool,
@@ -9,6 +10,16 @@ pub struct NodeInfo {
     pub node_version: String,
 }
 
+pub struct PeerInfo {
+    pub id: String,
+    pub addresses: Vec<String>,
+    pub client_version: Option<String>,
+    pub block_height: Option<u32>,
+    pub last_heartbeat: Option<Duration>,
+    pub connection_age: Duration,
+    pub app_score: f64,
+}
+
 // GraphQL Translation
 
 impl From<schema::node_info::NodeInfo> for NodeInfo {
@@ -23,3 +34,19 @@ impl From<schema::node_info::NodeInfo> for NodeInfo {
         }
     }
 }
+
+impl From<schema::node_info::PeerInfo> for PeerInfo {
+    fn from(value: schema::node_info::PeerInfo) -> Self {
+        Self {
+            id: value.id,
+            addresses: value.addresses,
+            client_version: value.client_version,
+            block_height: value.block_height.map(Into::into),
+            last_heartbeat: value
+                .last_heartbeat_ms
+                .map(|ms| Duration::from_millis(ms.into())),
+            connection_age: Duration::from_millis(value.connection_age_ms.into()),
+            app_score: value.app_score,
+        }
+    }
+}
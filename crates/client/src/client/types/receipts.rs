@@ -0,0 +1,136 @@
+use fuel_core_types::{
+    fuel_asm::{
+        PanicReason,
+        Word,
+    },
+    fuel_tx::Receipt,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// A list of receipts produced by the execution of a transaction with
+/// helpers to extract the commonly needed pieces.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Receipts(pub Vec<Receipt>);
+
+/// The reason why the execution of the transaction was interrupted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RevertReason {
+    /// The `rvrt` instruction was executed with the value.
+    Revert(Word),
+    /// The VM panicked.
+    Panic(PanicReason),
+}
+
+impl Receipts {
+    /// Returns the values of the registers logged by the `log` instruction.
+    pub fn logged_values(&self) -> Vec<[Word; 4]> {
+        self.0
+            .iter()
+            .filter_map(|receipt| match receipt {
+                Receipt::Log { ra, rb, rc, rd, .. } => Some([*ra, *rb, *rc, *rd]),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the data logged by the `logd` instruction.
+    pub fn logged_data(&self) -> Vec<&[u8]> {
+        self.0
+            .iter()
+            .filter_map(|receipt| match receipt {
+                Receipt::LogData { data, .. } => data.as_deref(),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the data returned by the last `retd` instruction.
+    pub fn return_data(&self) -> Option<&[u8]> {
+        self.0.iter().rev().find_map(|receipt| match receipt {
+            Receipt::ReturnData { data, .. } => data.as_deref(),
+            _ => None,
+        })
+    }
+
+    /// Returns the reason of the revert or panic, if any.
+    pub fn revert_reason(&self) -> Option<RevertReason> {
+        self.0.iter().find_map(|receipt| match receipt {
+            Receipt::Revert { ra, .. } => Some(RevertReason::Revert(*ra)),
+            Receipt::Panic { reason, .. } => Some(RevertReason::Panic(*reason.reason())),
+            _ => None,
+        })
+    }
+
+    pub fn into_inner(self) -> Vec<Receipt> {
+        self.0
+    }
+}
+
+impl From<Vec<Receipt>> for Receipts {
+    fn from(receipts: Vec<Receipt>) -> Self {
+        Self(receipts)
+    }
+}
+
+impl From<Receipts> for Vec<Receipt> {
+    fn from(receipts: Receipts) -> Self {
+        receipts.0
+    }
+}
+
+impl core::ops::Deref for Receipts {
+    type Target = [Receipt];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl IntoIterator for Receipts {
+    type Item = Receipt;
+    type IntoIter = std::vec::IntoIter<Receipt>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_asm::PanicInstruction;
+
+    #[test]
+    fn extracts_logs_and_return_data() {
+        let receipts = Receipts(vec![
+            Receipt::log(Default::default(), 1, 2, 3, 4, 0, 0),
+            Receipt::log_data(Default::default(), 0, 0, 0, 0, 0, vec![1, 2, 3]),
+            Receipt::return_data(Default::default(), 0, 0, 0, vec![4, 5]),
+        ]);
+
+        assert_eq!(receipts.logged_values(), vec![[1, 2, 3, 4]]);
+        assert_eq!(receipts.logged_data(), vec![&[1u8, 2, 3][..]]);
+        assert_eq!(receipts.return_data(), Some(&[4u8, 5][..]));
+        assert_eq!(receipts.revert_reason(), None);
+    }
+
+    #[test]
+    fn extracts_revert_reason() {
+        let reverted = Receipts(vec![Receipt::revert(Default::default(), 42, 0, 0)]);
+        assert_eq!(reverted.revert_reason(), Some(RevertReason::Revert(42)));
+
+        let panicked = Receipts(vec![Receipt::panic(
+            Default::default(),
+            PanicInstruction::error(PanicReason::MemoryOverflow, 0),
+            0,
+            0,
+        )]);
+        assert_eq!(
+            panicked.revert_reason(),
+            Some(RevertReason::Panic(PanicReason::MemoryOverflow))
+        );
+    }
+}
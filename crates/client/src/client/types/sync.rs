@@ -0,0 +1,33 @@
+use crate::client::schema;
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncStatus {
+    pub current_height: u32,
+    pub highest_seen_height: u32,
+    pub peers_synced_against: u32,
+    /// `None` until the node knows its import rate.
+    pub estimated_time_remaining: Option<Duration>,
+}
+
+impl SyncStatus {
+    /// Returns `true` if the node has committed the highest seen height.
+    pub fn is_synced(&self) -> bool {
+        self.current_height >= self.highest_seen_height
+    }
+}
+
+// GraphQL Translation
+
+impl From<schema::sync::SyncStatus> for SyncStatus {
+    fn from(value: schema::sync::SyncStatus) -> Self {
+        Self {
+            current_height: value.current_height.into(),
+            highest_seen_height: value.highest_seen_height.into(),
+            peers_synced_against: value.peers_synced_against.into(),
+            estimated_time_remaining: value
+                .estimated_seconds_remaining
+                .map(|seconds| Duration::from_secs(seconds.into())),
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use crate::client::{
+    schema,
+    schema::ConversionError,
+    types::{
+        primitives::{
+            AssetId,
+            ContractId,
+        },
+        Receipts,
+    },
+};
+use fuel_core_types::fuel_asm::Word;
+
+/// The trace of the transaction execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionTrace {
+    pub receipts: Receipts,
+    /// The contract calls made by the transaction in the order of the calls.
+    pub frames: Vec<CallFrameTrace>,
+    /// The number of executions of each opcode, sorted by the opcode name.
+    pub opcode_counts: Vec<(String, u64)>,
+    /// All executed instructions, if the full opcode stream was requested.
+    pub opcodes: Option<Vec<TracedInstruction>>,
+    /// The values of the registers at the moment of the panic, if any.
+    pub panic_registers: Option<Vec<Word>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallFrameTrace {
+    pub depth: u32,
+    pub contract_id: ContractId,
+    pub asset_id: AssetId,
+    pub amount: u64,
+    pub gas: u64,
+    pub outcome: CallOutcome,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallOutcome {
+    Return,
+    Panic,
+    Revert,
+    Unfinished,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TracedInstruction {
+    pub pc: u64,
+    pub is: u64,
+    pub opcode: Option<String>,
+}
+
+// GraphQL Translation
+
+impl TryFrom<schema::tx::TransactionTrace> for TransactionTrace {
+    type Error = ConversionError;
+
+    fn try_from(value: schema::tx::TransactionTrace) -> Result<Self, Self::Error> {
+        let receipts = value
+            .receipts
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            receipts: receipts.into(),
+            frames: value.frames.into_iter().map(Into::into).collect(),
+            opcode_counts: value
+                .opcode_counts
+                .into_iter()
+                .map(|count| (count.opcode, count.count.into()))
+                .collect(),
+            opcodes: value
+                .opcodes
+                .map(|opcodes| opcodes.into_iter().map(Into::into).collect()),
+            panic_registers: value
+                .panic_registers
+                .map(|registers| registers.into_iter().map(Into::into).collect()),
+        })
+    }
+}
+
+impl From<schema::tx::CallFrameTrace> for CallFrameTrace {
+    fn from(value: schema::tx::CallFrameTrace) -> Self {
+        Self {
+            depth: value.depth.into(),
+            contract_id: value.contract_id.into(),
+            asset_id: value.asset_id.into(),
+            amount: value.amount.into(),
+            gas: value.gas.into(),
+            outcome: value.outcome.into(),
+        }
+    }
+}
+
+impl From<schema::tx::CallOutcome> for CallOutcome {
+    fn from(value: schema::tx::CallOutcome) -> Self {
+        match value {
+            schema::tx::CallOutcome::Return => CallOutcome::Return,
+            schema::tx::CallOutcome::Panic => CallOutcome::Panic,
+            schema::tx::CallOutcome::Revert => CallOutcome::Revert,
+            schema::tx::CallOutcome::Unfinished => CallOutcome::Unfinished,
+        }
+    }
+}
+
+impl From<schema::tx::TracedInstruction> for TracedInstruction {
+    fn from(value: schema::tx::TracedInstruction) -> Self {
+        Self {
+            pc: value.pc.into(),
+            is: value.is.into(),
+            opcode: value.opcode,
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::client::{
+    schema,
+    types::primitives::{
+        Address,
+        TransactionId,
+    },
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxPoolInfo {
+    pub pending: u64,
+    pub total_gas: u64,
+    pub owners: Vec<TxPoolOwnerInfo>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxPoolOwnerInfo {
+    pub owner: Address,
+    pub pending: u64,
+}
+
+/// The result of the insertion of the transaction submitted within the batch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchSubmitResult {
+    pub id: TransactionId,
+    /// The reason why the transaction was rejected by the `TxPool`.
+    pub error: Option<String>,
+}
+
+// GraphQL Translation
+
+impl From<schema::txpool::TxPoolInfo> for TxPoolInfo {
+    fn from(value: schema::txpool::TxPoolInfo) -> Self {
+        Self {
+            pending: value.pending.into(),
+            total_gas: value.total_gas.into(),
+            owners: value.owners.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<schema::tx::BatchSubmitResult> for BatchSubmitResult {
+    fn from(value: schema::tx::BatchSubmitResult) -> Self {
+        Self {
+            id: value.id.into(),
+            error: value.error,
+        }
+    }
+}
+
+impl From<schema::txpool::TxPoolOwnerInfo> for TxPoolOwnerInfo {
+    fn from(value: schema::txpool::TxPoolOwnerInfo) -> Self {
+        Self {
+            owner: value.owner.into(),
+            pending: value.pending.into(),
+        }
+    }
+}
@@ -1,5 +1,17 @@
 #![deny(unused_crate_dependencies)]
 #![deny(warnings)]
+
+#[cfg(all(target_arch = "wasm32", feature = "subscriptions"))]
+compile_error!(
+    "the `subscriptions` feature is not supported on `wasm32`, use `default-features = false`"
+);
+
+// Only one of the transports is used on the native target
+#[cfg(all(feature = "wasm-transport", not(wasm_transport)))]
+use gloo_timers as _;
+#[cfg(all(wasm_transport, not(target_arch = "wasm32")))]
+use tokio as _;
+
 pub mod client;
 #[cfg(feature = "dap")]
 pub mod schema;
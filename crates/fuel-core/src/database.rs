@@ -45,8 +45,10 @@ type DatabaseResult<T> = Result<T>;
 
 // TODO: Extract `Database` and all belongs into `fuel-core-database`.
 #[cfg(feature = "rocksdb")]
-use crate::state::rocks_db::RocksDb;
-#[cfg(feature = "rocksdb")]
+use crate::state::{
+    rocks_db::RocksDb,
+    rocks_db_config::RocksDbConfig,
+};
 use std::path::Path;
 use strum::EnumCount;
 #[cfg(feature = "rocksdb")]
@@ -54,20 +56,27 @@ use tempfile::TempDir;
 
 // Storages implementation
 // TODO: Move to separate `database/storage` folder, because it is only implementation of storages traits.
+mod assets;
 mod block;
 mod code_root;
 mod contracts;
 mod message;
+mod pruning;
 mod receipts;
 #[cfg(feature = "relayer")]
 mod relayer;
+mod rollback;
 mod sealed_block;
 mod state;
+mod state_diff;
+mod tiering;
 
 pub(crate) mod coin;
 
 pub mod balances;
+pub mod integrity;
 pub mod metadata;
+pub mod migration;
 pub mod storage;
 pub mod transaction;
 pub mod transactions;
@@ -76,8 +85,17 @@ pub mod vm_database;
 /// Database tables column ids to the corresponding [`fuel_core_storage::Mappable`] table.
 #[repr(u32)]
 #[derive(
-    Copy, Clone, Debug, strum_macros::EnumCount, PartialEq, Eq, enum_iterator::Sequence,
+    Copy,
+    Clone,
+    Debug,
+    strum_macros::EnumCount,
+    strum_macros::EnumString,
+    strum_macros::IntoStaticStr,
+    PartialEq,
+    Eq,
+    enum_iterator::Sequence,
 )]
+#[strum(serialize_all = "snake_case")]
 pub enum Column {
     /// The column id of metadata about the blockchain
     Metadata = 0,
@@ -132,6 +150,23 @@ pub enum Column {
     ContractsStateMerkleData = 23,
     /// See [`ContractsStateMerkleMetadata`](storage::ContractsStateMerkleMetadata)
     ContractsStateMerkleMetadata = 24,
+    /// The changes that revert the block at the height.
+    /// See [`Database::rollback_last_block`](Database::rollback_last_block)
+    RollbackChanges = 25,
+    /// The changes of the state made by the block at the height.
+    /// See [`Database::get_state_diff`](Database::get_state_diff)
+    StateDiffs = 26,
+    /// The receipts emitted by the contracts.
+    /// See [`Database::contract_receipts`](Database::contract_receipts)
+    ReceiptsByContract = 27,
+    /// The supply of the assets.
+    /// See [`Database::asset_supply`](Database::asset_supply)
+    AssetSupply = 28,
+    /// The balances of the holders of the assets.
+    AssetHolders = 29,
+    /// The holders of the assets sorted by their balances.
+    /// See [`Database::asset_holders`](Database::asset_holders)
+    AssetHoldersByBalance = 30,
 }
 
 impl Column {
@@ -192,9 +227,14 @@ impl Database {
     }
 
     #[cfg(feature = "rocksdb")]
-    pub fn open(path: &Path, capacity: impl Into<Option<usize>>) -> DatabaseResult<Self> {
+    pub fn open(
+        path: &Path,
+        capacity: impl Into<Option<usize>>,
+        config: &RocksDbConfig,
+    ) -> DatabaseResult<Self> {
         use anyhow::Context;
-        let db = RocksDb::default_open(path, capacity.into()).context("Failed to open rocksdb, you may need to wipe a pre-existing incompatible db `rm -rf ~/.fuel/db`")?;
+        let columns = enum_iterator::all::<Column>().collect::<Vec<_>>();
+        let db = RocksDb::open(path, columns, capacity.into(), config).context("Failed to open rocksdb, you may need to wipe a pre-existing incompatible db `rm -rf ~/.fuel/db`")?;
 
         Ok(Database {
             data: Arc::new(db),
@@ -202,6 +242,34 @@ impl Database {
         })
     }
 
+    /// Opens the database with the historical blocks and receipts kept in the cold
+    /// storage at the `cold_path`, see [`TieredStore`](crate::state::tiered::TieredStore).
+    #[cfg(feature = "rocksdb")]
+    pub fn open_tiered(
+        path: &Path,
+        cold_path: &Path,
+        capacity: impl Into<Option<usize>>,
+        config: &RocksDbConfig,
+    ) -> DatabaseResult<Self> {
+        use crate::state::tiered::{
+            TieredStore,
+            COLD_COLUMNS,
+        };
+        use anyhow::Context;
+        let columns = enum_iterator::all::<Column>().collect::<Vec<_>>();
+        let hot = RocksDb::open(path, columns, capacity.into(), config)
+            .context("Failed to open rocksdb")?;
+        let cold = RocksDb::open(cold_path, COLD_COLUMNS.to_vec(), None, config)
+            .context("Failed to open the cold storage")?;
+        #[cfg(feature = "metrics")]
+        let cold = cold.without_state_metrics();
+
+        Ok(Database {
+            data: Arc::new(TieredStore::new(Arc::new(hot), Arc::new(cold))),
+            _drop: Default::default(),
+        })
+    }
+
     pub fn in_memory() -> Self {
         Self {
             data: Arc::new(MemoryStore::default()),
@@ -230,6 +298,18 @@ impl Database {
     pub fn transaction(&self) -> DatabaseTransaction {
         self.into()
     }
+
+    /// Compacts the `column` or all columns if unset. The compaction reclaims the space
+    /// of the removed entries and speeds up the reads, but it is long and heavy on IO.
+    pub fn compact(&self, column: Option<Column>) -> DatabaseResult<()> {
+        self.data.compact(column)
+    }
+
+    /// Creates the checkpoint of the database at the `path`, which can be opened as
+    /// a separate database, e.g. to back it up while the node is running.
+    pub fn checkpoint(&self, path: &Path) -> DatabaseResult<()> {
+        self.data.checkpoint(path)
+    }
 }
 
 /// Mutable methods.
@@ -0,0 +1,384 @@
+use crate::database::{
+    coin::utxo_id_to_bytes,
+    Column,
+    Database,
+    Error as DatabaseError,
+    Result as DatabaseResult,
+};
+use fuel_core_storage::iter::IterDirection;
+use fuel_core_types::{
+    entities::coins::coin::CompressedCoin,
+    fuel_tx::{
+        field::{
+            Inputs,
+            Outputs,
+        },
+        input::coin::{
+            CoinPredicate,
+            CoinSigned,
+        },
+        Bytes32,
+        ContractIdExt,
+        Input,
+        Output,
+        Receipt,
+        Transaction,
+        UtxoId,
+    },
+    fuel_types::{
+        Address,
+        AssetId,
+    },
+    services::graphql_api::{
+        AddressBalance,
+        AssetSupply,
+    },
+};
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    mem::size_of,
+};
+
+/// The changes of the balances of the holders, keyed by `asset id + owner`.
+type BalanceChanges = HashMap<(AssetId, Address), i128>;
+
+/// The changes of the supply of the asset made by the block.
+#[derive(Default)]
+struct SupplyChange {
+    minted: u64,
+    burned: u64,
+    holders: i64,
+}
+
+impl Database {
+    /// Updates the supply and the holders of the assets by the transactions of the
+    /// imported block. The `tx_ids` are ordered as the transactions of the block.
+    pub fn index_assets(&self, tx_ids: &[Bytes32]) -> DatabaseResult<()> {
+        let transactions = tx_ids
+            .iter()
+            .map(|tx_id| {
+                self.get::<Transaction>(tx_id.as_ref(), Column::Transactions)?
+                    .ok_or_else(|| {
+                        DatabaseError::Other(anyhow::anyhow!(
+                            "The transaction {} of the block is not found",
+                            tx_id
+                        ))
+                    })
+            })
+            .collect::<DatabaseResult<Vec<_>>>()?;
+
+        // The coins created and spent within the block don't change the balances.
+        let created: HashSet<UtxoId> = tx_ids
+            .iter()
+            .zip(transactions.iter())
+            .flat_map(|(tx_id, tx)| {
+                let (_, outputs) = inputs_and_outputs(tx);
+                (0..outputs.len()).map(|idx| UtxoId::new(*tx_id, idx as u8))
+            })
+            .collect();
+
+        let mut balances = BalanceChanges::new();
+        let mut supply = HashMap::<AssetId, SupplyChange>::new();
+        for (tx_id, tx) in tx_ids.iter().zip(transactions.iter()) {
+            let (inputs, outputs) = inputs_and_outputs(tx);
+            for input in inputs {
+                match input {
+                    Input::CoinSigned(CoinSigned {
+                        utxo_id,
+                        owner,
+                        amount,
+                        asset_id,
+                        ..
+                    })
+                    | Input::CoinPredicate(CoinPredicate {
+                        utxo_id,
+                        owner,
+                        amount,
+                        asset_id,
+                        ..
+                    }) if !created.contains(utxo_id) => {
+                        *balances.entry((*asset_id, *owner)).or_default() -=
+                            *amount as i128;
+                    }
+                    _ => {}
+                }
+            }
+
+            for idx in 0..outputs.len() {
+                let utxo_id = UtxoId::new(*tx_id, idx as u8);
+                let coin: Option<CompressedCoin> =
+                    self.get(&utxo_id_to_bytes(&utxo_id), Column::Coins)?;
+                if let Some(coin) = coin {
+                    *balances.entry((coin.asset_id, coin.owner)).or_default() +=
+                        coin.amount as i128;
+                }
+            }
+
+            let receipts: Vec<Receipt> = self
+                .get(tx_id.as_ref(), Column::Receipts)?
+                .unwrap_or_default();
+            // The assets minted by the reverted transaction are discarded.
+            let reverted = receipts
+                .iter()
+                .any(|r| matches!(r, Receipt::Revert { .. } | Receipt::Panic { .. }));
+            if reverted {
+                continue
+            }
+            for receipt in receipts {
+                match receipt {
+                    Receipt::Mint {
+                        sub_id,
+                        contract_id,
+                        val,
+                        ..
+                    } => {
+                        let change =
+                            supply.entry(contract_id.asset_id(&sub_id)).or_default();
+                        change.minted = change.minted.saturating_add(val);
+                    }
+                    Receipt::Burn {
+                        sub_id,
+                        contract_id,
+                        val,
+                        ..
+                    } => {
+                        let change =
+                            supply.entry(contract_id.asset_id(&sub_id)).or_default();
+                        change.burned = change.burned.saturating_add(val);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        self.apply_asset_changes(balances, supply)
+    }
+
+    /// Indexes the holders of the coins of the initial state. The supply minted
+    /// before the genesis or the checkpoint is unknown to the index.
+    pub fn index_genesis_assets(&self) -> DatabaseResult<()> {
+        let mut balances = BalanceChanges::new();
+        for coin in self.iter_all::<Vec<u8>, CompressedCoin>(Column::Coins, None) {
+            let (_, coin) = coin?;
+            *balances.entry((coin.asset_id, coin.owner)).or_default() +=
+                coin.amount as i128;
+        }
+        self.apply_asset_changes(balances, HashMap::new())
+    }
+
+    /// Returns the supply of the asset, if the asset is known to the index.
+    pub fn asset_supply(
+        &self,
+        asset_id: &AssetId,
+    ) -> DatabaseResult<Option<AssetSupply>> {
+        self.get(asset_id.as_ref(), Column::AssetSupply)
+    }
+
+    /// Iterates over the holders of the asset from the largest balance to the
+    /// smallest one, starting from the holder with the `start` balance and owner.
+    pub fn asset_holders(
+        &self,
+        asset_id: AssetId,
+        start: Option<(u64, Address)>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = DatabaseResult<AddressBalance>> + '_ {
+        let start = start.map(|(amount, owner)| rank_key(&asset_id, amount, &owner));
+        self.iter_all_filtered::<Vec<u8>, u64, _, _>(
+            Column::AssetHoldersByBalance,
+            Some(asset_id),
+            start,
+            direction,
+        )
+        .map(move |res| {
+            res.and_then(|(key, amount)| {
+                Ok(AddressBalance {
+                    owner: Address::try_from(&key[AssetId::LEN + AMOUNT_SIZE..])?,
+                    amount,
+                    asset_id,
+                })
+            })
+        })
+    }
+
+    fn apply_asset_changes(
+        &self,
+        balances: BalanceChanges,
+        mut supply: HashMap<AssetId, SupplyChange>,
+    ) -> DatabaseResult<()> {
+        for ((asset_id, owner), change) in balances {
+            let holders = self.update_holder_balance(&asset_id, &owner, change)?;
+            supply.entry(asset_id).or_default().holders += holders;
+        }
+
+        for (asset_id, change) in supply {
+            if change.minted == 0 && change.burned == 0 && change.holders == 0 {
+                continue
+            }
+            let mut asset_supply = self.asset_supply(&asset_id)?.unwrap_or_default();
+            asset_supply.minted = asset_supply.minted.saturating_add(change.minted);
+            asset_supply.burned = asset_supply.burned.saturating_add(change.burned);
+            asset_supply.holders = (asset_supply.holders as i64)
+                .saturating_add(change.holders)
+                .max(0) as u64;
+            let _: Option<AssetSupply> =
+                self.insert(asset_id, Column::AssetSupply, &asset_supply)?;
+        }
+        Ok(())
+    }
+
+    /// Applies the `change` to the balance of the holder. Returns the change of the
+    /// number of the holders of the asset.
+    fn update_holder_balance(
+        &self,
+        asset_id: &AssetId,
+        owner: &Address,
+        change: i128,
+    ) -> DatabaseResult<i64> {
+        let key = holder_key(asset_id, owner);
+        let old: u64 = self.get(&key, Column::AssetHolders)?.unwrap_or_default();
+        let new = (old as i128 + change).clamp(0, u64::MAX as i128) as u64;
+        if old == new {
+            return Ok(0)
+        }
+
+        if old > 0 {
+            let _: Option<u64> = self.remove(
+                &rank_key(asset_id, old, owner),
+                Column::AssetHoldersByBalance,
+            )?;
+        }
+        if new > 0 {
+            let _: Option<u64> = self.insert(key, Column::AssetHolders, &new)?;
+            let _: Option<u64> = self.insert(
+                rank_key(asset_id, new, owner),
+                Column::AssetHoldersByBalance,
+                &new,
+            )?;
+        } else {
+            let _: Option<u64> = self.remove(&key, Column::AssetHolders)?;
+        }
+
+        match (old, new) {
+            (0, _) => Ok(1),
+            (_, 0) => Ok(-1),
+            _ => Ok(0),
+        }
+    }
+}
+
+fn inputs_and_outputs(tx: &Transaction) -> (&[Input], &[Output]) {
+    match tx {
+        Transaction::Script(tx) => (tx.inputs(), tx.outputs()),
+        Transaction::Create(tx) => (tx.inputs(), tx.outputs()),
+        Transaction::Mint(tx) => (&[], tx.outputs()),
+    }
+}
+
+const AMOUNT_SIZE: usize = size_of::<u64>();
+
+/// The key of the balance of the holder is `asset id + owner`.
+fn holder_key(asset_id: &AssetId, owner: &Address) -> [u8; AssetId::LEN + Address::LEN] {
+    let mut key = [0u8; AssetId::LEN + Address::LEN];
+    key[..AssetId::LEN].copy_from_slice(asset_id.as_ref());
+    key[AssetId::LEN..].copy_from_slice(owner.as_ref());
+    key
+}
+
+/// The key of the rank of the holder is `asset id + inverted amount + owner`, so
+/// the holders of the asset are sorted from the largest balance.
+fn rank_key(
+    asset_id: &AssetId,
+    amount: u64,
+    owner: &Address,
+) -> [u8; AssetId::LEN + AMOUNT_SIZE + Address::LEN] {
+    let mut key = [0u8; AssetId::LEN + AMOUNT_SIZE + Address::LEN];
+    let (asset_bytes, rest) = key.split_at_mut(AssetId::LEN);
+    asset_bytes.copy_from_slice(asset_id.as_ref());
+    let (amount_bytes, owner_bytes) = rest.split_at_mut(AMOUNT_SIZE);
+    amount_bytes.copy_from_slice(&(u64::MAX - amount).to_be_bytes());
+    owner_bytes.copy_from_slice(owner.as_ref());
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn holders(database: &Database, asset_id: AssetId) -> Vec<(Address, u64)> {
+        database
+            .asset_holders(asset_id, None, None)
+            .map(|holder| holder.map(|holder| (holder.owner, holder.amount)).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn holders_are_sorted_by_balance() {
+        let database = Database::default();
+        let asset_id = AssetId::from([1; 32]);
+        let alice = Address::from([2; 32]);
+        let bob = Address::from([3; 32]);
+        let carol = Address::from([4; 32]);
+
+        let balances = BalanceChanges::from([
+            ((asset_id, alice), 10),
+            ((asset_id, bob), 30),
+            ((asset_id, carol), 20),
+            ((AssetId::from([5; 32]), alice), 100),
+        ]);
+        database
+            .apply_asset_changes(balances, HashMap::new())
+            .unwrap();
+
+        assert_eq!(
+            holders(&database, asset_id),
+            vec![(bob, 30), (carol, 20), (alice, 10)]
+        );
+        assert_eq!(
+            database.asset_supply(&asset_id).unwrap().unwrap().holders,
+            3
+        );
+
+        // The start is inclusive.
+        let from_carol: Vec<_> = database
+            .asset_holders(asset_id, Some((20, carol)), None)
+            .map(|holder| holder.unwrap().owner)
+            .collect();
+        assert_eq!(from_carol, vec![carol, alice]);
+    }
+
+    #[test]
+    fn holders_without_balance_are_removed() {
+        let database = Database::default();
+        let asset_id = AssetId::from([1; 32]);
+        let alice = Address::from([2; 32]);
+        let bob = Address::from([3; 32]);
+        database
+            .apply_asset_changes(
+                BalanceChanges::from([((asset_id, alice), 10), ((asset_id, bob), 5)]),
+                HashMap::new(),
+            )
+            .unwrap();
+
+        database
+            .apply_asset_changes(
+                BalanceChanges::from([((asset_id, alice), -10), ((asset_id, bob), 10)]),
+                HashMap::from([(
+                    asset_id,
+                    SupplyChange {
+                        minted: 7,
+                        burned: 2,
+                        holders: 0,
+                    },
+                )]),
+            )
+            .unwrap();
+
+        assert_eq!(holders(&database, asset_id), vec![(bob, 15)]);
+        let supply = database.asset_supply(&asset_id).unwrap().unwrap();
+        assert_eq!(supply.holders, 1);
+        assert_eq!(supply.total(), 5);
+    }
+}
@@ -19,6 +19,7 @@ use fuel_core_storage::{
         Transactions,
     },
     Error as StorageError,
+    MerkleRoot,
     MerkleRootStorage,
     Result as StorageResult,
     StorageAsMut,
@@ -210,6 +211,46 @@ impl Database {
         Ok(ids)
     }
 
+    /// Appends the `block_ids` to the merkle tree of the blocks without inserting
+    /// the blocks. It allows continuing the chain from a checkpoint without its history.
+    /// The root of the updated tree is stored as the root at the `height`.
+    pub fn append_block_merkle_leaves(
+        &mut self,
+        height: &BlockHeight,
+        block_ids: &[BlockId],
+    ) -> StorageResult<MerkleRoot> {
+        // Get latest metadata entry
+        let prev_metadata = self
+            .iter_all::<Vec<u8>, DenseMerkleMetadata>(
+                Column::FuelBlockMerkleMetadata,
+                Some(IterDirection::Reverse),
+            )
+            .next()
+            .transpose()?
+            .map(|(_, metadata)| metadata)
+            .unwrap_or_default();
+
+        if block_ids.is_empty() {
+            return Ok(prev_metadata.root)
+        }
+
+        let storage = self.borrow_mut();
+        let mut tree: MerkleTree<FuelBlockMerkleData, _> =
+            MerkleTree::load(storage, prev_metadata.version)
+                .map_err(|err| StorageError::Other(err.into()))?;
+        for block_id in block_ids {
+            tree.push(block_id.as_slice())?;
+        }
+
+        let version = tree.leaves_count();
+        let root = tree.root();
+        let metadata = DenseMerkleMetadata { version, root };
+        self.storage::<FuelBlockMerkleMetadata>()
+            .insert(height, &metadata)?;
+
+        Ok(root)
+    }
+
     /// Retrieve the full block and all associated transactions
     pub(crate) fn get_full_block(
         &self,
@@ -359,6 +400,65 @@ mod tests {
         }
     }
 
+    #[test]
+    fn appended_merkle_leaves_match_inserted_blocks() {
+        let mut database = Database::default();
+        let mut checkpoint_database = Database::default();
+        let blocks = (0..10u32)
+            .map(|height| {
+                let header = PartialBlockHeader {
+                    application: Default::default(),
+                    consensus: ConsensusHeader::<Empty> {
+                        height: height.into(),
+                        ..Default::default()
+                    },
+                };
+                let block = PartialFuelBlock::new(header, vec![]);
+                block.generate(&[])
+            })
+            .collect::<Vec<_>>();
+        for block in &blocks {
+            StorageMutate::<FuelBlocks>::insert(
+                &mut database,
+                &block.id(),
+                &block.compress(&ChainId::default()),
+            )
+            .unwrap();
+        }
+
+        // Insert only the first and the last blocks and append the ids of the others.
+        let (first, rest) = blocks.split_first().unwrap();
+        let (last, middle) = rest.split_last().unwrap();
+        StorageMutate::<FuelBlocks>::insert(
+            &mut checkpoint_database,
+            &first.id(),
+            &first.compress(&ChainId::default()),
+        )
+        .unwrap();
+        let middle_ids = middle.iter().map(|block| block.id()).collect::<Vec<_>>();
+        let prev_root = checkpoint_database
+            .append_block_merkle_leaves(&8u32.into(), &middle_ids)
+            .unwrap();
+        StorageMutate::<FuelBlocks>::insert(
+            &mut checkpoint_database,
+            &last.id(),
+            &last.compress(&ChainId::default()),
+        )
+        .unwrap();
+
+        assert_eq!(
+            prev_root,
+            database.storage::<FuelBlocks>().root(&8u32.into()).unwrap()
+        );
+        assert_eq!(
+            checkpoint_database
+                .storage::<FuelBlocks>()
+                .root(&9u32.into())
+                .unwrap(),
+            database.storage::<FuelBlocks>().root(&9u32.into()).unwrap()
+        );
+    }
+
     #[test]
     fn get_merkle_root_with_no_blocks_returns_not_found_error() {
         let database = Database::default();
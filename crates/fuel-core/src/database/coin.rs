@@ -37,7 +37,7 @@ pub fn owner_coin_id_key(owner: &Address, coin_id: &UtxoId) -> OwnedCoinKey {
     default
 }
 
-fn utxo_id_to_bytes(utxo_id: &UtxoId) -> [u8; TxId::LEN + 1] {
+pub(crate) fn utxo_id_to_bytes(utxo_id: &UtxoId) -> [u8; TxId::LEN + 1] {
     let mut default = [0; TxId::LEN + 1];
     default[0..TxId::LEN].copy_from_slice(utxo_id.tx_id().as_ref());
     default[TxId::LEN] = utxo_id.output_index();
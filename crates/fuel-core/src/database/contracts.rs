@@ -14,6 +14,7 @@ use fuel_core_storage::{
         ContractsRawCode,
     },
     ContractsAssetKey,
+    ContractsStateKey,
     Error as StorageError,
     Mappable,
     Result as StorageResult,
@@ -245,6 +246,25 @@ impl Database {
         })
     }
 
+    /// Iterates over the storage slots of the contract ordered by their keys,
+    /// starting from the slot with the `start_key`.
+    pub fn contract_slots(
+        &self,
+        contract: ContractId,
+        start_key: Option<Bytes32>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = DatabaseResult<(Bytes32, Bytes32)>> + '_ {
+        self.iter_all_filtered::<Vec<u8>, Bytes32, _, _>(
+            Column::ContractsState,
+            Some(contract),
+            start_key.map(|key| ContractsStateKey::new(&contract, &key)),
+            direction,
+        )
+        .map(|res| {
+            res.map(|(key, value)| (Bytes32::new(key[32..].try_into().unwrap()), value))
+        })
+    }
+
     pub fn get_contract_config(&self) -> StorageResult<Option<Vec<ContractConfig>>> {
         let configs = self
             .iter_all::<Vec<u8>, Word>(Column::ContractsRawCode, None)
@@ -0,0 +1,455 @@
+//! Checks the internal consistency of the database, e.g. after the crash of the node.
+//!
+//! The blocks are checked against their headers, the merkle tree of the blocks, and
+//! the stored transactions. The coins and the messages are checked against the
+//! indexes by their owners. The inconsistency is repairable if it can be fixed from
+//! the rest of the database: the indexes can be rebuilt from the primary records,
+//! and the receipts can be restored by the replay of the block.
+
+use crate::database::{
+    coin::owner_coin_id_key,
+    message::owner_msg_id_key,
+    state_diff::utxo_id_from_bytes,
+    storage::{
+        FuelBlockMerkleData,
+        FuelBlockMerkleMetadata,
+    },
+    Column,
+    Database,
+    Error as DatabaseError,
+};
+use derive_more::Display;
+use fuel_core_storage::{
+    iter::IterDirection,
+    tables::{
+        FuelBlocks,
+        Receipts,
+        Transactions,
+    },
+    Error as StorageError,
+    Result as StorageResult,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    blockchain::primitives::BlockId,
+    entities::{
+        coins::coin::CompressedCoin,
+        message::Message,
+    },
+    fuel_merkle::binary::MerkleTree,
+    fuel_tx::{
+        TxId,
+        UtxoId,
+    },
+    fuel_types::{
+        Address,
+        BlockHeight,
+        Bytes32,
+        Nonce,
+    },
+};
+use std::borrow::Borrow;
+
+#[derive(Debug, Clone, PartialEq, Eq, Display)]
+pub enum Inconsistency {
+    #[display(fmt = "The block {} at height {} is missing", block_id, height)]
+    MissingBlock {
+        height: BlockHeight,
+        block_id: BlockId,
+    },
+    #[display(fmt = "The block {} at height {} is mismatched", block_id, height)]
+    BlockMismatch {
+        height: BlockHeight,
+        block_id: BlockId,
+    },
+    #[display(
+        fmt = "The application hash of the block at height {} is invalid",
+        height
+    )]
+    ApplicationHashMismatch { height: BlockHeight },
+    #[display(fmt = "The merkle root of the blocks at height {} is invalid", height)]
+    BlockRootMismatch { height: BlockHeight },
+    #[display(fmt = "The previous root of the block at height {} is invalid", height)]
+    PrevRootMismatch { height: BlockHeight },
+    #[display(fmt = "The transaction {} at height {} is missing", tx_id, height)]
+    MissingTransaction { height: BlockHeight, tx_id: TxId },
+    #[display(
+        fmt = "The transactions root of the block at height {} is invalid",
+        height
+    )]
+    TransactionsRootMismatch { height: BlockHeight },
+    #[display(fmt = "The receipts of the transaction {} are missing", tx_id)]
+    MissingReceipts { height: BlockHeight, tx_id: TxId },
+    #[display(fmt = "The coin {:#x} isn't indexed by its owner", utxo_id)]
+    UnindexedCoin { utxo_id: UtxoId },
+    #[display(
+        fmt = "The index of {} refers to the foreign coin {:#x}",
+        owner,
+        utxo_id
+    )]
+    StaleCoinIndex { owner: Address, utxo_id: UtxoId },
+    #[display(fmt = "The message {} isn't indexed by its recipient", nonce)]
+    UnindexedMessage { nonce: Nonce },
+    #[display(fmt = "The index of {} refers to the foreign message {}", owner, nonce)]
+    StaleMessageIndex { owner: Address, nonce: Nonce },
+}
+
+impl Inconsistency {
+    /// Returns `true` if the inconsistency can be fixed from the rest of the database.
+    pub fn is_repairable(&self) -> bool {
+        matches!(
+            self,
+            Inconsistency::MissingReceipts { .. }
+                | Inconsistency::UnindexedCoin { .. }
+                | Inconsistency::StaleCoinIndex { .. }
+                | Inconsistency::UnindexedMessage { .. }
+                | Inconsistency::StaleMessageIndex { .. }
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct IntegrityReport {
+    pub blocks: usize,
+    pub transactions: usize,
+    pub coins: usize,
+    pub messages: usize,
+    pub inconsistencies: Vec<Inconsistency>,
+}
+
+impl IntegrityReport {
+    pub fn is_consistent(&self) -> bool {
+        self.inconsistencies.is_empty()
+    }
+
+    pub fn is_repairable(&self) -> bool {
+        self.inconsistencies
+            .iter()
+            .all(Inconsistency::is_repairable)
+    }
+}
+
+impl Database {
+    /// Walks the whole database and reports the inconsistencies between the tables.
+    pub fn check_integrity(&self) -> StorageResult<IntegrityReport> {
+        let mut report = IntegrityReport::default();
+        self.check_blocks(&mut report)?;
+        self.check_coins(&mut report)?;
+        self.check_messages(&mut report)?;
+        Ok(report)
+    }
+
+    fn check_blocks(&self, report: &mut IntegrityReport) -> StorageResult<()> {
+        // The receipts of the pruned blocks are removed on purpose.
+        let pruned_height = self.pruned_height()?;
+        for ids in self.all_block_ids(None, IterDirection::Forward) {
+            let (height, block_id) = ids?;
+            report.blocks += 1;
+            let inconsistencies = &mut report.inconsistencies;
+            let block = match self.storage::<FuelBlocks>().get(&block_id)? {
+                Some(block) => block.into_owned(),
+                None => {
+                    inconsistencies
+                        .push(Inconsistency::MissingBlock { height, block_id });
+                    continue
+                }
+            };
+
+            let header = block.header();
+            if header.consensus.application_hash != header.application.hash() {
+                inconsistencies.push(Inconsistency::ApplicationHashMismatch { height });
+            } else if header.height() != &height || header.id() != block_id {
+                inconsistencies.push(Inconsistency::BlockMismatch { height, block_id });
+            }
+
+            if !self.block_root_is_valid(&height)? {
+                inconsistencies.push(Inconsistency::BlockRootMismatch { height });
+            }
+            // The history before the checkpoint has no roots of the previous blocks.
+            if let Some(previous) = height.checked_sub(1) {
+                let previous = self
+                    .storage::<FuelBlockMerkleMetadata>()
+                    .get(&previous.into())?;
+                if let Some(previous) = previous {
+                    if Bytes32::from(previous.root) != *header.prev_root() {
+                        inconsistencies.push(Inconsistency::PrevRootMismatch { height });
+                    }
+                }
+            }
+
+            let mut transactions = Vec::with_capacity(block.transactions().len());
+            for tx_id in block.transactions() {
+                report.transactions += 1;
+                match self.storage::<Transactions>().get(tx_id)? {
+                    Some(tx) => transactions.push(tx.into_owned()),
+                    None => inconsistencies.push(Inconsistency::MissingTransaction {
+                        height,
+                        tx_id: *tx_id,
+                    }),
+                }
+                let pruned = pruned_height.map_or(false, |pruned| height <= pruned);
+                if !pruned && !self.storage::<Receipts>().contains_key(tx_id)? {
+                    inconsistencies.push(Inconsistency::MissingReceipts {
+                        height,
+                        tx_id: *tx_id,
+                    });
+                }
+            }
+            if transactions.len() == block.transactions().len()
+                && !header.validate_transactions(&transactions)
+            {
+                inconsistencies.push(Inconsistency::TransactionsRootMismatch { height });
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the stored root of the blocks at the `height` matches
+    /// the merkle tree of the blocks.
+    fn block_root_is_valid(&self, height: &BlockHeight) -> StorageResult<bool> {
+        let metadata = match self.storage::<FuelBlockMerkleMetadata>().get(height)? {
+            Some(metadata) => metadata.into_owned(),
+            None => return Ok(false),
+        };
+        let storage = self.borrow();
+        let tree: MerkleTree<FuelBlockMerkleData, _> =
+            match MerkleTree::load(storage, metadata.version) {
+                Ok(tree) => tree,
+                Err(_) => return Ok(false),
+            };
+        Ok(tree.root() == metadata.root)
+    }
+
+    fn check_coins(&self, report: &mut IntegrityReport) -> StorageResult<()> {
+        for coin in self.iter_all::<Vec<u8>, CompressedCoin>(Column::Coins, None) {
+            let (key, coin) = coin?;
+            report.coins += 1;
+            let utxo_id = utxo_id_from_bytes(&key)?;
+            let index = owner_coin_id_key(&coin.owner, &utxo_id);
+            if !self.contains_key(&index, Column::OwnedCoins)? {
+                report
+                    .inconsistencies
+                    .push(Inconsistency::UnindexedCoin { utxo_id });
+            }
+        }
+
+        for index in self.iter_all::<Vec<u8>, bool>(Column::OwnedCoins, None) {
+            let (key, _) = index?;
+            let (owner, utxo_id) = split_owner_key(&key)?;
+            let utxo_id = utxo_id_from_bytes(utxo_id)?;
+            let coin: Option<CompressedCoin> =
+                self.get(&key[Address::LEN..], Column::Coins)?;
+            if coin.map_or(true, |coin| coin.owner != owner) {
+                report
+                    .inconsistencies
+                    .push(Inconsistency::StaleCoinIndex { owner, utxo_id });
+            }
+        }
+        Ok(())
+    }
+
+    fn check_messages(&self, report: &mut IntegrityReport) -> StorageResult<()> {
+        for message in self.iter_all::<Vec<u8>, Message>(Column::Messages, None) {
+            let (key, message) = message?;
+            report.messages += 1;
+            let nonce =
+                Nonce::try_from(key.as_slice()).map_err(|_| DatabaseError::Codec)?;
+            let index = owner_msg_id_key(&message.recipient, &nonce);
+            if !self.contains_key(&index, Column::OwnedMessageIds)? {
+                report
+                    .inconsistencies
+                    .push(Inconsistency::UnindexedMessage { nonce });
+            }
+        }
+
+        for index in self.iter_all::<Vec<u8>, bool>(Column::OwnedMessageIds, None) {
+            let (key, _) = index?;
+            let (owner, nonce) = split_owner_key(&key)?;
+            let nonce = Nonce::try_from(nonce).map_err(|_| DatabaseError::Codec)?;
+            let message: Option<Message> = self.get(nonce.as_ref(), Column::Messages)?;
+            if message.map_or(true, |message| message.recipient != owner) {
+                report
+                    .inconsistencies
+                    .push(Inconsistency::StaleMessageIndex { owner, nonce });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Splits the key of the owner's index into the owner and the key of the record.
+fn split_owner_key(key: &[u8]) -> StorageResult<(Address, &[u8])> {
+    if key.len() < Address::LEN {
+        return Err(StorageError::from(DatabaseError::Codec))
+    }
+    let (owner, record) = key.split_at(Address::LEN);
+    let owner = Address::try_from(owner).map_err(|_| DatabaseError::Codec)?;
+    Ok((owner, record))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::{
+        tables::{
+            Coins,
+            Messages,
+        },
+        MerkleRootStorage,
+        StorageAsMut,
+    };
+    use fuel_core_types::{
+        blockchain::{
+            block::PartialFuelBlock,
+            header::{
+                ConsensusHeader,
+                PartialBlockHeader,
+            },
+            primitives::Empty,
+        },
+        fuel_tx::{
+            Receipt,
+            TransactionBuilder,
+            UniqueIdentifier,
+        },
+        fuel_types::ChainId,
+    };
+
+    /// Inserts the chain of the `blocks` blocks with one transaction each.
+    fn chain(blocks: u32) -> (Database, Vec<TxId>) {
+        let mut database = Database::default();
+        let mut tx_ids = vec![];
+        for height in 0..blocks {
+            let prev_root = match height.checked_sub(1) {
+                Some(previous) => MerkleRootStorage::<BlockHeight, FuelBlocks>::root(
+                    &database,
+                    &previous.into(),
+                )
+                .unwrap()
+                .into(),
+                None => Default::default(),
+            };
+            let tx = TransactionBuilder::script(vec![], vec![])
+                .gas_price(height as u64)
+                .finalize_as_transaction();
+            let tx_id = tx.id(&ChainId::default());
+            let header = PartialBlockHeader {
+                application: Default::default(),
+                consensus: ConsensusHeader::<Empty> {
+                    height: height.into(),
+                    prev_root,
+                    ..Default::default()
+                },
+            };
+            let block = PartialFuelBlock::new(header, vec![tx.clone()]).generate(&[]);
+            database
+                .storage::<FuelBlocks>()
+                .insert(&block.id(), &block.compress(&ChainId::default()))
+                .unwrap();
+            database
+                .storage::<Transactions>()
+                .insert(&tx_id, &tx)
+                .unwrap();
+            database
+                .storage::<Receipts>()
+                .insert(&tx_id, &[Receipt::ret(Default::default(), 1, 0, 0)])
+                .unwrap();
+            tx_ids.push(tx_id);
+        }
+        (database, tx_ids)
+    }
+
+    fn coin(owner: Address) -> CompressedCoin {
+        CompressedCoin {
+            owner,
+            amount: 10,
+            asset_id: Default::default(),
+            maturity: Default::default(),
+            tx_pointer: Default::default(),
+        }
+    }
+
+    #[test]
+    fn consistent_database_has_no_inconsistencies() {
+        let (mut database, _) = chain(3);
+        database
+            .storage::<Coins>()
+            .insert(&UtxoId::default(), &coin(Address::from([1; 32])))
+            .unwrap();
+        let message = Message {
+            recipient: Address::from([2; 32]),
+            ..Default::default()
+        };
+        database
+            .storage::<Messages>()
+            .insert(message.id(), &message)
+            .unwrap();
+
+        let report = database.check_integrity().unwrap();
+
+        assert!(report.is_consistent(), "{:?}", report.inconsistencies);
+        assert_eq!(report.blocks, 3);
+        assert_eq!(report.transactions, 3);
+        assert_eq!(report.coins, 1);
+        assert_eq!(report.messages, 1);
+    }
+
+    #[test]
+    fn broken_indexes_are_repairable() {
+        let (mut database, _) = chain(1);
+        let owner = Address::from([1; 32]);
+        let utxo_id = UtxoId::default();
+        database
+            .storage::<Coins>()
+            .insert(&utxo_id, &coin(owner))
+            .unwrap();
+        let _: Option<bool> = database
+            .remove(&owner_coin_id_key(&owner, &utxo_id), Column::OwnedCoins)
+            .unwrap();
+        let stale = UtxoId::new([3; 32].into(), 0);
+        let _: Option<bool> = database
+            .insert(owner_coin_id_key(&owner, &stale), Column::OwnedCoins, &true)
+            .unwrap();
+
+        let report = database.check_integrity().unwrap();
+
+        assert_eq!(
+            report.inconsistencies,
+            vec![
+                Inconsistency::UnindexedCoin { utxo_id },
+                Inconsistency::StaleCoinIndex {
+                    owner,
+                    utxo_id: stale
+                },
+            ]
+        );
+        assert!(report.is_repairable());
+    }
+
+    #[test]
+    fn missing_transaction_is_not_repairable() {
+        let (mut database, tx_ids) = chain(2);
+        database
+            .storage::<Transactions>()
+            .remove(&tx_ids[1])
+            .unwrap();
+        database.storage::<Receipts>().remove(&tx_ids[0]).unwrap();
+
+        let report = database.check_integrity().unwrap();
+
+        assert_eq!(
+            report.inconsistencies,
+            vec![
+                Inconsistency::MissingReceipts {
+                    height: 0u32.into(),
+                    tx_id: tx_ids[0],
+                },
+                Inconsistency::MissingTransaction {
+                    height: 1u32.into(),
+                    tx_id: tx_ids[1],
+                },
+            ]
+        );
+        assert!(!report.is_repairable());
+    }
+}
@@ -157,7 +157,10 @@ impl Database {
 
 // TODO: Reuse `fuel_vm::storage::double_key` macro.
 /// Get a Key by chaining Owner + Nonce
-fn owner_msg_id_key(owner: &Address, nonce: &Nonce) -> [u8; Address::LEN + Nonce::LEN] {
+pub(super) fn owner_msg_id_key(
+    owner: &Address,
+    nonce: &Nonce,
+) -> [u8; Address::LEN + Nonce::LEN] {
     let mut default = [0u8; Address::LEN + Nonce::LEN];
     default[0..Address::LEN].copy_from_slice(owner.as_ref());
     default[Address::LEN..].copy_from_slice(nonce.as_ref());
@@ -5,15 +5,22 @@ use crate::database::{
     Result as DatabaseResult,
 };
 use fuel_core_chain_config::ChainConfig;
+use std::time::{
+    SystemTime,
+    UNIX_EPOCH,
+};
 
 pub(crate) const DB_VERSION_KEY: &[u8] = b"version";
 pub(crate) const CHAIN_NAME_KEY: &[u8] = b"chain_name";
+pub(crate) const HEALTH_CHECK_KEY: &[u8] = b"health_check";
 
-/// Can be used to perform migrations in the future.
+/// The version of the database schema. Bumping it requires the migration from the
+/// previous version in [`MIGRATIONS`](crate::database::migration::MIGRATIONS).
 pub(crate) const DB_VERSION: u32 = 0x00;
 
 impl Database {
-    /// Ensures the database is initialized and that the database version is correct
+    /// Ensures the database is initialized and migrates it to the current version.
+    /// Fails if the database was created by the newer version of the node.
     pub fn init(&self, config: &ChainConfig) -> DatabaseResult<()> {
         // initialize chain name if not set
         if self.get_chain_name()?.is_none() {
@@ -27,14 +34,9 @@ impl Database {
                 })?;
         }
 
-        // Ensure the database version is correct
-        if let Some(version) = self.get::<u32>(DB_VERSION_KEY, Column::Metadata)? {
-            if version != DB_VERSION {
-                return Err(DatabaseError::InvalidDatabaseVersion {
-                    found: version,
-                    expected: DB_VERSION,
-                })?
-            }
+        // Migrate the existing database to the current version
+        if self.get::<u32>(DB_VERSION_KEY, Column::Metadata)?.is_some() {
+            self.migrate()?;
         } else {
             let _: Option<u32> =
                 self.insert(DB_VERSION_KEY, Column::Metadata, &DB_VERSION)?;
@@ -45,4 +47,14 @@ impl Database {
     pub fn get_chain_name(&self) -> DatabaseResult<Option<String>> {
         self.get(CHAIN_NAME_KEY, Column::Metadata)
     }
+
+    /// Checks that the database accepts the writes by writing the time of the check.
+    pub fn check_writable(&self) -> DatabaseResult<()> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let _: Option<u64> = self.insert(HEALTH_CHECK_KEY, Column::Metadata, &now)?;
+        Ok(())
+    }
 }
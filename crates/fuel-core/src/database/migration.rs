@@ -0,0 +1,203 @@
+//! The migrations of the database schema. Every migration upgrades the database by one
+//! version and is applied in its own transaction together with the new version, so
+//! an interrupted upgrade is resumed from the last applied migration.
+
+use crate::database::{
+    metadata::{
+        DB_VERSION,
+        DB_VERSION_KEY,
+    },
+    Column,
+    Database,
+    Error as DatabaseError,
+    Result as DatabaseResult,
+};
+use fuel_core_storage::{
+    transactional::Transaction,
+    Result as StorageResult,
+};
+
+/// The number of the processed entries between the progress reports.
+const PROGRESS_INTERVAL: u64 = 100_000;
+
+pub type MigrationFn = fn(&mut Database, &mut MigrationProgress) -> StorageResult<()>;
+
+pub struct Migration {
+    /// The version of the database after the migration.
+    pub version: u32,
+    pub description: &'static str,
+    pub migrate: MigrationFn,
+}
+
+/// The migrations of the database ordered by the version. The version of the last
+/// migration is [`DB_VERSION`].
+pub const MIGRATIONS: &[Migration] = &[];
+
+/// Reports the progress of the long-running migration.
+pub struct MigrationProgress {
+    version: u32,
+    processed: u64,
+}
+
+impl MigrationProgress {
+    fn new(version: u32) -> Self {
+        Self {
+            version,
+            processed: 0,
+        }
+    }
+
+    /// Records `count` more processed entries.
+    pub fn advance(&mut self, count: u64) {
+        let before = self.processed / PROGRESS_INTERVAL;
+        self.processed = self.processed.saturating_add(count);
+        if self.processed / PROGRESS_INTERVAL > before {
+            tracing::info!(
+                "Migrating the database to version {}: processed {} entries",
+                self.version,
+                self.processed
+            );
+        }
+    }
+}
+
+impl Database {
+    /// Applies the pending migrations of the database. The new databases are created
+    /// with the latest version and don't require the migrations.
+    pub fn migrate(&self) -> DatabaseResult<()> {
+        self.migrate_with(MIGRATIONS, DB_VERSION)
+    }
+
+    fn migrate_with(&self, migrations: &[Migration], latest: u32) -> DatabaseResult<()> {
+        let mut version = match self.get::<u32>(DB_VERSION_KEY, Column::Metadata)? {
+            Some(version) => version,
+            None => return Ok(()),
+        };
+        if version > latest {
+            return Err(DatabaseError::InvalidDatabaseVersion {
+                found: version,
+                expected: latest,
+            })
+        }
+
+        let pending: Vec<_> = migrations
+            .iter()
+            .filter(|migration| migration.version > version)
+            .collect();
+        for (i, migration) in pending.iter().enumerate() {
+            if migration.version != version + 1 {
+                return Err(DatabaseError::InvalidDatabaseVersion {
+                    found: version,
+                    expected: latest,
+                })
+            }
+            tracing::info!(
+                "Migrating the database to version {} ({}/{}): {}",
+                migration.version,
+                i + 1,
+                pending.len(),
+                migration.description
+            );
+            self.apply(migration)
+                .map_err(|e| DatabaseError::Other(e.into()))?;
+            version = migration.version;
+        }
+
+        if version != latest {
+            return Err(DatabaseError::InvalidDatabaseVersion {
+                found: version,
+                expected: latest,
+            })
+        }
+        Ok(())
+    }
+
+    fn apply(&self, migration: &Migration) -> StorageResult<()> {
+        let mut progress = MigrationProgress::new(migration.version);
+        let mut transaction = self.transaction();
+        let database = transaction.as_mut();
+        (migration.migrate)(database, &mut progress)?;
+        let _: Option<u32> =
+            database.insert(DB_VERSION_KEY, Column::Metadata, &migration.version)?;
+        transaction.commit()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &[u8] = b"migrated";
+
+    fn record_migration(
+        database: &mut Database,
+        version: u32,
+        progress: &mut MigrationProgress,
+    ) -> StorageResult<()> {
+        progress.advance(1);
+        let mut migrated: Vec<u32> =
+            database.get(KEY, Column::Metadata)?.unwrap_or_default();
+        migrated.push(version);
+        let _: Option<Vec<u32>> = database.insert(KEY, Column::Metadata, &migrated)?;
+        Ok(())
+    }
+
+    fn migrations() -> Vec<Migration> {
+        vec![
+            Migration {
+                version: 1,
+                description: "first",
+                migrate: |database, progress| record_migration(database, 1, progress),
+            },
+            Migration {
+                version: 2,
+                description: "second",
+                migrate: |database, progress| record_migration(database, 2, progress),
+            },
+        ]
+    }
+
+    #[test]
+    fn migrations_are_consecutive_up_to_the_latest_version() {
+        for (i, migration) in MIGRATIONS.iter().enumerate() {
+            assert_eq!(migration.version, i as u32 + 1);
+        }
+        assert_eq!(MIGRATIONS.last().map_or(0, |m| m.version), DB_VERSION);
+    }
+
+    #[test]
+    fn old_database_is_migrated_in_order() {
+        let database = Database::default();
+        let _: Option<u32> = database
+            .insert(DB_VERSION_KEY, Column::Metadata, &0u32)
+            .unwrap();
+
+        database.migrate_with(&migrations(), 2).unwrap();
+
+        let migrated: Option<Vec<u32>> = database.get(KEY, Column::Metadata).unwrap();
+        assert_eq!(migrated, Some(vec![1, 2]));
+        let version: Option<u32> =
+            database.get(DB_VERSION_KEY, Column::Metadata).unwrap();
+        assert_eq!(version, Some(2));
+    }
+
+    #[test]
+    fn newer_database_is_refused() {
+        let database = Database::default();
+        let _: Option<u32> = database
+            .insert(DB_VERSION_KEY, Column::Metadata, &3u32)
+            .unwrap();
+
+        let result = database.migrate_with(&migrations(), 2);
+
+        assert!(matches!(
+            result,
+            Err(DatabaseError::InvalidDatabaseVersion {
+                found: 3,
+                expected: 2
+            })
+        ));
+        let migrated: Option<Vec<u32>> = database.get(KEY, Column::Metadata).unwrap();
+        assert_eq!(migrated, None);
+    }
+}
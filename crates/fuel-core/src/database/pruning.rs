@@ -0,0 +1,149 @@
+use crate::database::{
+    storage::ToDatabaseKey,
+    Column,
+    Database,
+    Result as DatabaseResult,
+};
+use fuel_core_storage::{
+    tables::FuelBlocks,
+    transactional::Transaction,
+    Result as StorageResult,
+    StorageAsRef,
+};
+use fuel_core_types::fuel_types::BlockHeight;
+
+pub(crate) const PRUNED_HEIGHT_KEY: &[u8] = b"pruned_height";
+
+impl Database {
+    /// Returns the height of the latest block with the pruned history.
+    pub fn pruned_height(&self) -> DatabaseResult<Option<BlockHeight>> {
+        self.get(PRUNED_HEIGHT_KEY, Column::Metadata)
+    }
+
+    /// Removes the rollback changes, the state diff, and the receipts of the transactions
+    /// of the block at the `height` together with their entries in the index by the
+    /// contracts, and returns the number of the reclaimed bytes. The state before the
+    /// block can't be restored afterwards.
+    pub fn prune_block_history(&self, height: &BlockHeight) -> StorageResult<usize> {
+        let mut transaction = self.transaction();
+        let database = transaction.as_mut();
+        let key = height.database_key();
+        let mut reclaimed = 0;
+        for column in [Column::RollbackChanges, Column::StateDiffs] {
+            if let Some(value) = database.take(key.as_ref(), column)? {
+                reclaimed += value.len();
+            }
+        }
+
+        let block = match database.get_block_id(height)? {
+            Some(block_id) => database.storage::<FuelBlocks>().get(&block_id)?,
+            None => None,
+        };
+        if let Some(block) = block {
+            reclaimed += database.remove_receipts_index(height, block.transactions())?;
+            for tx_id in block.transactions() {
+                if let Some(receipts) = database.take(tx_id.as_ref(), Column::Receipts)? {
+                    reclaimed += receipts.len();
+                }
+            }
+        }
+
+        let _: Option<BlockHeight> =
+            database.insert(PRUNED_HEIGHT_KEY, Column::Metadata, height)?;
+        transaction.commit()?;
+        Ok(reclaimed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::{
+        tables::Receipts,
+        StorageAsMut,
+    };
+    use fuel_core_types::{
+        blockchain::{
+            block::PartialFuelBlock,
+            header::{
+                ConsensusHeader,
+                PartialBlockHeader,
+            },
+            primitives::Empty,
+        },
+        fuel_tx::{
+            Receipt,
+            Transaction,
+            UniqueIdentifier,
+        },
+        fuel_types::ChainId,
+        services::block_importer::StateDiff,
+    };
+
+    fn insert_block(database: &mut Database, height: u32) -> Transaction {
+        let tx = Transaction::default_test_tx();
+        let header = PartialBlockHeader {
+            application: Default::default(),
+            consensus: ConsensusHeader::<Empty> {
+                height: height.into(),
+                ..Default::default()
+            },
+        };
+        let block = PartialFuelBlock::new(header, vec![tx.clone()]).generate(&[]);
+        database
+            .storage::<FuelBlocks>()
+            .insert(&block.id(), &block.compress(&ChainId::default()))
+            .unwrap();
+        tx
+    }
+
+    #[test]
+    fn pruning_removes_the_history_of_the_block() {
+        let mut database = Database::default();
+        let height = BlockHeight::from(1u32);
+        let tx = insert_block(&mut database, 1);
+        let tx_id = tx.id(&ChainId::default());
+        let receipt = Receipt::ret(Default::default(), 1, 0, 0);
+        database
+            .storage::<Receipts>()
+            .insert(&tx_id, &[receipt])
+            .unwrap();
+        database.index_receipts(&height, &[tx_id]).unwrap();
+        let _: Option<StateDiff> = database
+            .insert(
+                height.database_key(),
+                Column::StateDiffs,
+                &StateDiff::default(),
+            )
+            .unwrap();
+        assert_eq!(database.pruned_height().unwrap(), None);
+
+        let reclaimed = database.prune_block_history(&height).unwrap();
+
+        assert!(reclaimed > 0);
+        assert!(database
+            .storage::<Receipts>()
+            .get(&tx_id)
+            .unwrap()
+            .is_none());
+        assert!(database
+            .contract_receipts(Default::default(), None, None)
+            .next()
+            .is_none());
+        assert_eq!(database.get_state_diff(&height).unwrap(), None);
+        assert_eq!(database.pruned_height().unwrap(), Some(height));
+        // The block itself is kept
+        assert!(database.get_block_id(&height).unwrap().is_some());
+    }
+
+    #[test]
+    fn pruning_of_the_block_without_history_reclaims_nothing() {
+        let mut database = Database::default();
+        insert_block(&mut database, 1);
+
+        let reclaimed = database.prune_block_history(&1u32.into()).unwrap();
+
+        assert_eq!(reclaimed, 0);
+        assert_eq!(database.pruned_height().unwrap(), Some(1u32.into()));
+    }
+}
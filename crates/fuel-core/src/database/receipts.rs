@@ -1,11 +1,210 @@
 use crate::database::{
     storage::DatabaseColumn,
     Column,
+    Database,
+    Error as DatabaseError,
+    Result as DatabaseResult,
 };
-use fuel_core_storage::tables::Receipts;
+use fuel_core_storage::{
+    iter::IterDirection,
+    tables::Receipts,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        Bytes32,
+        Receipt,
+    },
+    fuel_types::{
+        BlockHeight,
+        ContractId,
+    },
+    services::graphql_api::{
+        ContractReceipt,
+        ReceiptPointer,
+    },
+};
+use std::mem::size_of;
 
 impl DatabaseColumn for Receipts {
     fn column() -> Column {
         Column::Receipts
     }
 }
+
+impl Database {
+    /// Indexes the receipts of the transactions at the `height` by the contracts that
+    /// emitted them. The `tx_ids` are ordered as the transactions of the block.
+    pub fn index_receipts(
+        &self,
+        height: &BlockHeight,
+        tx_ids: &[Bytes32],
+    ) -> DatabaseResult<()> {
+        for (key, entry) in self.contract_receipt_entries(height, tx_ids)? {
+            let _: Option<(Bytes32, Receipt)> =
+                self.insert(key, Column::ReceiptsByContract, &entry)?;
+        }
+        Ok(())
+    }
+
+    /// Removes the receipts of the transactions at the `height` from the index by the
+    /// contracts and returns the number of the reclaimed bytes. The receipts must
+    /// still be stored to find the entries of the index.
+    pub fn remove_receipts_index(
+        &self,
+        height: &BlockHeight,
+        tx_ids: &[Bytes32],
+    ) -> DatabaseResult<usize> {
+        let mut reclaimed = 0;
+        for (key, _) in self.contract_receipt_entries(height, tx_ids)? {
+            if let Some(value) = self.take(key.as_ref(), Column::ReceiptsByContract)? {
+                reclaimed += value.len();
+            }
+        }
+        Ok(reclaimed)
+    }
+
+    /// The entries of the index by the contracts for the receipts of the transactions
+    /// at the `height`.
+    fn contract_receipt_entries(
+        &self,
+        height: &BlockHeight,
+        tx_ids: &[Bytes32],
+    ) -> DatabaseResult<Vec<([u8; KEY_SIZE], (Bytes32, Receipt))>> {
+        let mut entries = vec![];
+        for (tx_idx, tx_id) in tx_ids.iter().enumerate() {
+            let receipts: Vec<Receipt> = self
+                .get(tx_id.as_ref(), Column::Receipts)?
+                .unwrap_or_default();
+            for (receipt_idx, receipt) in receipts.into_iter().enumerate() {
+                let contract = match receipt.id().or(receipt.contract_id()) {
+                    Some(contract) => *contract,
+                    None => continue,
+                };
+                let pointer = ReceiptPointer {
+                    block_height: *height,
+                    tx_idx: index(tx_idx)?,
+                    receipt_idx: index(receipt_idx)?,
+                };
+                entries
+                    .push((contract_receipt_key(&contract, &pointer), (*tx_id, receipt)));
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Iterates over the receipts emitted by the `contract` ordered by their position
+    /// in the chain, starting from the `start`.
+    pub fn contract_receipts(
+        &self,
+        contract: ContractId,
+        start: Option<ReceiptPointer>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = DatabaseResult<ContractReceipt>> + '_ {
+        let start = start.map(|pointer| contract_receipt_key(&contract, &pointer));
+        self.iter_all_filtered::<Vec<u8>, (Bytes32, Receipt), _, _>(
+            Column::ReceiptsByContract,
+            Some(contract),
+            start,
+            direction,
+        )
+        .map(|res| {
+            res.map(|(key, (tx_id, receipt))| ContractReceipt {
+                pointer: receipt_pointer(&key[ContractId::LEN..]),
+                tx_id,
+                receipt,
+            })
+        })
+    }
+}
+
+const BLOCK_HEIGHT_SIZE: usize = size_of::<BlockHeight>();
+const INDEX_SIZE: usize = size_of::<u16>();
+const KEY_SIZE: usize = ContractId::LEN + BLOCK_HEIGHT_SIZE + 2 * INDEX_SIZE;
+
+fn index(idx: usize) -> DatabaseResult<u16> {
+    u16::try_from(idx).map_err(|e| DatabaseError::Other(e.into()))
+}
+
+/// The key of the index is `contract id + block height + tx idx + receipt idx`,
+/// so the receipts of the contract are sorted by their position in the chain.
+fn contract_receipt_key(
+    contract: &ContractId,
+    pointer: &ReceiptPointer,
+) -> [u8; KEY_SIZE] {
+    let mut key = [0u8; KEY_SIZE];
+    let (contract_bytes, rest) = key.split_at_mut(ContractId::LEN);
+    contract_bytes.copy_from_slice(contract.as_ref());
+    let (height, rest) = rest.split_at_mut(BLOCK_HEIGHT_SIZE);
+    height.copy_from_slice(pointer.block_height.to_bytes().as_ref());
+    let (tx_idx, receipt_idx) = rest.split_at_mut(INDEX_SIZE);
+    tx_idx.copy_from_slice(&pointer.tx_idx.to_be_bytes());
+    receipt_idx.copy_from_slice(&pointer.receipt_idx.to_be_bytes());
+    key
+}
+
+fn receipt_pointer(bytes: &[u8]) -> ReceiptPointer {
+    let mut height = [0u8; BLOCK_HEIGHT_SIZE];
+    height.copy_from_slice(&bytes[..BLOCK_HEIGHT_SIZE]);
+    let mut tx_idx = [0u8; INDEX_SIZE];
+    tx_idx.copy_from_slice(&bytes[BLOCK_HEIGHT_SIZE..BLOCK_HEIGHT_SIZE + INDEX_SIZE]);
+    let mut receipt_idx = [0u8; INDEX_SIZE];
+    receipt_idx.copy_from_slice(&bytes[BLOCK_HEIGHT_SIZE + INDEX_SIZE..]);
+    ReceiptPointer {
+        block_height: u32::from_be_bytes(height).into(),
+        tx_idx: u16::from_be_bytes(tx_idx),
+        receipt_idx: u16::from_be_bytes(receipt_idx),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::StorageAsMut;
+
+    #[test]
+    fn receipts_are_indexed_by_contract() {
+        let mut database = Database::default();
+        let contract = ContractId::from([1; 32]);
+        let other = ContractId::from([2; 32]);
+        let tx_id = Bytes32::from([3; 32]);
+        let receipts = vec![
+            Receipt::log(contract, 1, 0, 0, 0, 0, 0),
+            Receipt::ret(Default::default(), 2, 0, 0),
+            Receipt::log(other, 3, 0, 0, 0, 0, 0),
+            Receipt::ret(contract, 4, 0, 0),
+        ];
+        database
+            .storage::<Receipts>()
+            .insert(&tx_id, &receipts)
+            .unwrap();
+
+        database
+            .index_receipts(&5u32.into(), &[Bytes32::zeroed(), tx_id])
+            .unwrap();
+
+        let indexed: Vec<_> = database
+            .contract_receipts(contract, None, None)
+            .map(|receipt| receipt.unwrap())
+            .collect();
+        assert_eq!(indexed.len(), 2);
+        assert_eq!(
+            indexed[0].pointer,
+            ReceiptPointer {
+                block_height: 5u32.into(),
+                tx_idx: 1,
+                receipt_idx: 0,
+            }
+        );
+        assert_eq!(indexed[0].tx_id, tx_id);
+        assert_eq!(indexed[0].receipt, receipts[0]);
+        assert_eq!(indexed[1].pointer.receipt_idx, 3);
+        assert_eq!(indexed[1].receipt, receipts[3]);
+
+        let start = indexed[1].pointer;
+        let from_start: Vec<_> = database
+            .contract_receipts(contract, Some(start), None)
+            .map(|receipt| receipt.unwrap().pointer)
+            .collect();
+        assert_eq!(from_start, vec![start]);
+    }
+}
@@ -0,0 +1,402 @@
+use crate::{
+    database::{
+        storage::ToDatabaseKey,
+        transaction::DatabaseTransaction,
+        Column,
+        Database,
+        Error as DatabaseError,
+        Result as DatabaseResult,
+    },
+    state::{
+        snapshot::SnapshotStore,
+        WriteOperation,
+    },
+};
+use fuel_core_storage::{
+    not_found,
+    Result as StorageResult,
+};
+use fuel_core_types::{
+    blockchain::SealedBlock,
+    fuel_types::BlockHeight,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::{
+    ops::Deref,
+    sync::Arc,
+};
+
+/// The previous values of all keys modified by the block.
+/// `None` means that the key didn't exist before the block.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct RollbackChanges(Vec<(u32, Vec<u8>, Option<Vec<u8>>)>);
+
+impl Database {
+    /// Stores the changes that revert the pending changes of the database transaction
+    /// under the `height`. Only the changes of the latest `max_depth` blocks are kept,
+    /// so the chain can't be rolled back deeper than that.
+    pub fn store_rollback_changes(
+        &self,
+        height: &BlockHeight,
+        max_depth: u32,
+    ) -> DatabaseResult<()> {
+        if max_depth == 0 {
+            return Ok(())
+        }
+
+        let changes = self
+            .data
+            .revert_operations()?
+            .into_iter()
+            .map(|(key, column, operation)| {
+                let previous = match operation {
+                    WriteOperation::Insert(value) => Some(value.deref().clone()),
+                    WriteOperation::Remove => None,
+                };
+                (column as u32, key, previous)
+            })
+            .collect();
+        let _: Option<RollbackChanges> = self.insert(
+            height.database_key(),
+            Column::RollbackChanges,
+            &RollbackChanges(changes),
+        )?;
+
+        if let Some(pruned) = height.checked_sub(max_depth) {
+            let _: Option<RollbackChanges> = self.remove(
+                BlockHeight::from(pruned).database_key().as_ref(),
+                Column::RollbackChanges,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Reverts the state of the database to the one before the latest block
+    /// and returns the reverted block.
+    pub fn rollback_last_block(&self) -> StorageResult<SealedBlock> {
+        let height = self.latest_height()?;
+        let block = self
+            .get_sealed_block_by_height(&height)?
+            .ok_or(not_found!("SealedBlock"))?;
+        let mut operations = self
+            .rollback_operations(&height)?
+            .ok_or(not_found!("RollbackChanges"))?;
+        operations.push((
+            height.database_key().to_vec(),
+            Column::RollbackChanges,
+            WriteOperation::Remove,
+        ));
+        self.data.batch_write(&mut operations.into_iter())?;
+
+        Ok(block)
+    }
+
+    /// Returns the view of the state at the `height`. The view is built on the snapshot
+    /// of the database, so the blocks committed after the call don't affect it. The
+    /// changes of the later blocks are reverted in the database transaction on top of
+    /// the snapshot that is never committed.
+    ///
+    /// The view is available only for the heights with the stored rollback changes:
+    /// the latest `max_depth` blocks, or all blocks in the archive mode. Every block
+    /// above the `height` is reverted one by one, so the `max_depth` also limits the
+    /// cost of the request.
+    pub fn state_at(
+        &self,
+        height: &BlockHeight,
+        max_depth: u32,
+    ) -> StorageResult<DatabaseTransaction> {
+        let snapshot = Database::new(Arc::new(SnapshotStore::new(self.data.clone())?));
+        let latest_height = snapshot.latest_height()?;
+        if *height > latest_height {
+            return Err(not_found!("BlockHeight"))
+        }
+        let depth = *latest_height - **height;
+        if depth > max_depth {
+            return Err(anyhow::anyhow!(
+                "The state at the height {} is {} blocks behind the latest block, \
+                but at most {} blocks are allowed",
+                **height,
+                depth,
+                max_depth
+            )
+            .into())
+        }
+
+        let view = snapshot.transaction();
+        let mut current = latest_height;
+        while current > *height {
+            let operations = view.rollback_operations(&current)?.ok_or_else(|| {
+                anyhow::anyhow!(
+                    "The state at the height {} isn't available, \
+                    it is only kept by the node in the archive mode",
+                    **height
+                )
+            })?;
+            view.data.batch_write(&mut operations.into_iter())?;
+            current = (*current - 1).into();
+        }
+        Ok(view)
+    }
+
+    /// The operations that revert the changes of the block at the `height`.
+    fn rollback_operations(
+        &self,
+        height: &BlockHeight,
+    ) -> DatabaseResult<Option<Vec<(Vec<u8>, Column, WriteOperation)>>> {
+        let changes: Option<RollbackChanges> =
+            self.get(height.database_key().as_ref(), Column::RollbackChanges)?;
+        changes
+            .map(|changes| {
+                changes
+                    .0
+                    .into_iter()
+                    .map(|(column, key, previous)| {
+                        let column = enum_iterator::all::<Column>()
+                            .find(|c| *c as u32 == column)
+                            .ok_or(DatabaseError::Codec)?;
+                        let operation = match previous {
+                            Some(value) => WriteOperation::Insert(Arc::new(value)),
+                            None => WriteOperation::Remove,
+                        };
+                        Ok((key, column, operation))
+                    })
+                    .collect()
+            })
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::{
+        tables::{
+            Coins,
+            FuelBlocks,
+            SealedBlockConsensus,
+        },
+        transactional::Transaction,
+        StorageAsMut,
+        StorageAsRef,
+    };
+    use fuel_core_types::{
+        blockchain::{
+            block::PartialFuelBlock,
+            consensus::Consensus,
+            header::{
+                ConsensusHeader,
+                PartialBlockHeader,
+            },
+            primitives::Empty,
+        },
+        entities::coins::coin::CompressedCoin,
+        fuel_tx::UtxoId,
+        fuel_types::ChainId,
+    };
+
+    fn insert_block(database: &mut Database, height: u32) {
+        let header = PartialBlockHeader {
+            application: Default::default(),
+            consensus: ConsensusHeader::<Empty> {
+                height: height.into(),
+                ..Default::default()
+            },
+        };
+        let block = PartialFuelBlock::new(header, vec![]).generate(&[]);
+        let id = block.id();
+        database
+            .storage::<FuelBlocks>()
+            .insert(&id, &block.compress(&ChainId::default()))
+            .unwrap();
+        database
+            .storage::<SealedBlockConsensus>()
+            .insert(&id, &Consensus::PoA(Default::default()))
+            .unwrap();
+    }
+
+    #[test]
+    fn rollback_reverts_the_changes_of_the_latest_block() {
+        let mut database = Database::default();
+        insert_block(&mut database, 0);
+        let utxo_id = UtxoId::new(Default::default(), 0);
+
+        let mut transaction = database.transaction();
+        insert_block(transaction.as_mut(), 1);
+        transaction
+            .as_mut()
+            .storage::<Coins>()
+            .insert(
+                &utxo_id,
+                &CompressedCoin {
+                    owner: Default::default(),
+                    amount: 100,
+                    asset_id: Default::default(),
+                    maturity: Default::default(),
+                    tx_pointer: Default::default(),
+                },
+            )
+            .unwrap();
+        transaction
+            .store_rollback_changes(&1u32.into(), 10)
+            .unwrap();
+        transaction.commit().unwrap();
+        assert_eq!(database.latest_height().unwrap(), 1u32.into());
+
+        let reverted = database.rollback_last_block().unwrap();
+
+        assert_eq!(*reverted.entity.header().height(), 1u32.into());
+        assert_eq!(database.latest_height().unwrap(), 0u32.into());
+        assert!(!database.storage::<Coins>().contains_key(&utxo_id).unwrap());
+        assert!(database.rollback_last_block().is_err());
+    }
+
+    #[test]
+    fn rollback_changes_are_pruned_after_max_depth() {
+        let mut database = Database::default();
+        insert_block(&mut database, 0);
+
+        for height in 1..=3u32 {
+            let mut transaction = database.transaction();
+            insert_block(transaction.as_mut(), height);
+            transaction
+                .store_rollback_changes(&height.into(), 2)
+                .unwrap();
+            transaction.commit().unwrap();
+        }
+
+        database.rollback_last_block().unwrap();
+        database.rollback_last_block().unwrap();
+        assert_eq!(database.latest_height().unwrap(), 1u32.into());
+        assert!(database.rollback_last_block().is_err());
+    }
+
+    #[test]
+    fn state_at_restores_the_state_of_the_block() {
+        let mut database = Database::default();
+        insert_block(&mut database, 0);
+        let utxo_id = UtxoId::new(Default::default(), 0);
+        let coin = |amount| CompressedCoin {
+            owner: Default::default(),
+            amount,
+            asset_id: Default::default(),
+            maturity: Default::default(),
+            tx_pointer: Default::default(),
+        };
+
+        for height in 1..=3u32 {
+            let mut transaction = database.transaction();
+            insert_block(transaction.as_mut(), height);
+            transaction
+                .as_mut()
+                .storage::<Coins>()
+                .insert(&utxo_id, &coin(height as u64))
+                .unwrap();
+            transaction
+                .store_rollback_changes(&height.into(), u32::MAX)
+                .unwrap();
+            transaction.commit().unwrap();
+        }
+
+        let view = database.state_at(&1u32.into(), u32::MAX).unwrap();
+
+        assert_eq!(view.latest_height().unwrap(), 1u32.into());
+        let historical = view.storage::<Coins>().get(&utxo_id).unwrap().unwrap();
+        assert_eq!(historical.amount, 1);
+        // The latest state is not affected
+        assert_eq!(database.latest_height().unwrap(), 3u32.into());
+        let latest = database.storage::<Coins>().get(&utxo_id).unwrap().unwrap();
+        assert_eq!(latest.amount, 3);
+    }
+
+    #[test]
+    fn state_at_is_unavailable_without_rollback_changes() {
+        let mut database = Database::default();
+        insert_block(&mut database, 0);
+
+        for height in 1..=3u32 {
+            let mut transaction = database.transaction();
+            insert_block(transaction.as_mut(), height);
+            transaction
+                .store_rollback_changes(&height.into(), 1)
+                .unwrap();
+            transaction.commit().unwrap();
+        }
+
+        assert!(database.state_at(&2u32.into(), u32::MAX).is_ok());
+        assert!(database.state_at(&1u32.into(), u32::MAX).is_err());
+        assert!(database.state_at(&4u32.into(), u32::MAX).is_err());
+    }
+
+    fn commit_coin_block(database: &mut Database, height: u32, utxo_id: &UtxoId) {
+        let mut transaction = database.transaction();
+        insert_block(transaction.as_mut(), height);
+        transaction
+            .as_mut()
+            .storage::<Coins>()
+            .insert(
+                utxo_id,
+                &CompressedCoin {
+                    owner: Default::default(),
+                    amount: height as u64,
+                    asset_id: Default::default(),
+                    maturity: Default::default(),
+                    tx_pointer: Default::default(),
+                },
+            )
+            .unwrap();
+        transaction
+            .store_rollback_changes(&height.into(), u32::MAX)
+            .unwrap();
+        transaction.commit().unwrap();
+    }
+
+    #[test]
+    fn state_at_is_limited_by_max_depth() {
+        let mut database = Database::default();
+        insert_block(&mut database, 0);
+        let utxo_id = UtxoId::new(Default::default(), 0);
+        for height in 1..=3u32 {
+            commit_coin_block(&mut database, height, &utxo_id);
+        }
+
+        assert!(database.state_at(&1u32.into(), 2).is_ok());
+        assert!(database.state_at(&1u32.into(), 1).is_err());
+        assert!(database.state_at(&3u32.into(), 0).is_ok());
+    }
+
+    #[test]
+    fn state_at_is_not_affected_by_concurrent_commits() {
+        let mut database = Database::default();
+        insert_block(&mut database, 0);
+        let utxo_id = UtxoId::new(Default::default(), 0);
+        for height in 1..=3u32 {
+            commit_coin_block(&mut database, height, &utxo_id);
+        }
+
+        std::thread::scope(|scope| {
+            let mut writer = database.clone();
+            let utxo_id = &utxo_id;
+            let committer = scope.spawn(move || {
+                for height in 4..=200u32 {
+                    commit_coin_block(&mut writer, height, utxo_id);
+                }
+            });
+
+            while !committer.is_finished() {
+                let view = database.state_at(&1u32.into(), u32::MAX).unwrap();
+                assert_eq!(view.latest_height().unwrap(), 1u32.into());
+                let coin = view.storage::<Coins>().get(utxo_id).unwrap().unwrap();
+                assert_eq!(coin.amount, 1);
+            }
+        });
+
+        let view = database.state_at(&1u32.into(), u32::MAX).unwrap();
+        commit_coin_block(&mut database, 201, &utxo_id);
+        let coin = view.storage::<Coins>().get(&utxo_id).unwrap().unwrap();
+        assert_eq!(coin.amount, 1);
+        assert_eq!(view.latest_height().unwrap(), 1u32.into());
+    }
+}
@@ -14,6 +14,7 @@ use fuel_core_storage::{
 };
 use fuel_core_types::{
     blockchain::{
+        block::CompressedBlock,
         consensus::{
             Consensus,
             Genesis,
@@ -112,6 +113,26 @@ impl Database {
         }
     }
 
+    /// Returns the block with the ids of the transactions and the consensus data.
+    pub fn get_sealed_compressed_block(
+        &self,
+        block_id: &BlockId,
+    ) -> StorageResult<Option<Sealed<CompressedBlock>>> {
+        let block = self.storage::<FuelBlocks>().get(block_id)?;
+        let consensus = self.storage::<SealedBlockConsensus>().get(block_id)?;
+
+        if let (Some(block), Some(consensus)) = (block, consensus) {
+            let sealed_block = Sealed {
+                entity: block.into_owned(),
+                consensus: consensus.into_owned(),
+            };
+
+            Ok(Some(sealed_block))
+        } else {
+            Ok(None)
+        }
+    }
+
     pub fn get_transactions_on_block(
         &self,
         block_id: &BlockId,
@@ -0,0 +1,277 @@
+use crate::{
+    database::{
+        storage::ToDatabaseKey,
+        Column,
+        Database,
+        Error as DatabaseError,
+        Result as DatabaseResult,
+    },
+    state::WriteOperation,
+};
+use fuel_core_types::{
+    entities::coins::coin::CompressedCoin,
+    fuel_asm::Word,
+    fuel_tx::{
+        TxId,
+        UtxoId,
+    },
+    fuel_types::{
+        AssetId,
+        BlockHeight,
+        Bytes32,
+        ContractId,
+    },
+    services::block_importer::{
+        BalanceDelta,
+        BalanceOwner,
+        ContractStorageWrite,
+        StateDiff,
+    },
+};
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+
+impl Database {
+    /// Computes the changes of the coins, the contract storage, and the balances made
+    /// by the pending changes of the database transaction.
+    pub fn state_diff(&self) -> DatabaseResult<StateDiff> {
+        let mut diff = StateDiff::default();
+        let mut balances = BTreeMap::<(BalanceOwner, AssetId), (Word, Word)>::new();
+        for (key, column, operation) in self.data.revert_operations()? {
+            match column {
+                Column::Coins => {
+                    let utxo_id = utxo_id_from_bytes(&key)?;
+                    let previous: Option<CompressedCoin> = decode(operation)?;
+                    let current: Option<CompressedCoin> = self.get(&key, column)?;
+                    match (previous, current) {
+                        (Some(coin), None) => {
+                            let (_, debit) = balances
+                                .entry((BalanceOwner::Address(coin.owner), coin.asset_id))
+                                .or_default();
+                            *debit = debit.saturating_add(coin.amount);
+                            diff.spent_coins.push(coin.uncompress(utxo_id));
+                        }
+                        (None, Some(coin)) => {
+                            let (credit, _) = balances
+                                .entry((BalanceOwner::Address(coin.owner), coin.asset_id))
+                                .or_default();
+                            *credit = credit.saturating_add(coin.amount);
+                            diff.created_coins.push(coin.uncompress(utxo_id));
+                        }
+                        _ => {}
+                    }
+                }
+                Column::ContractsState => {
+                    let (contract_id, slot) = double_key(&key)?;
+                    let previous: Option<Bytes32> = decode(operation)?;
+                    let current: Option<Bytes32> = self.get(&key, column)?;
+                    if previous != current {
+                        diff.contract_storage_writes.push(ContractStorageWrite {
+                            contract_id,
+                            key: slot,
+                            value: current,
+                        });
+                    }
+                }
+                Column::ContractsAssets => {
+                    let (contract_id, asset_id) = double_key(&key)?;
+                    let previous: Word = decode(operation)?.unwrap_or_default();
+                    let current: Word = self.get(&key, column)?.unwrap_or_default();
+                    let (credit, debit) = balances
+                        .entry((
+                            BalanceOwner::Contract(contract_id),
+                            AssetId::new(*asset_id),
+                        ))
+                        .or_default();
+                    *credit = current.saturating_sub(previous);
+                    *debit = previous.saturating_sub(current);
+                }
+                _ => {}
+            }
+        }
+
+        diff.created_coins.sort_by_key(|coin| coin.utxo_id);
+        diff.spent_coins.sort_by_key(|coin| coin.utxo_id);
+        diff.contract_storage_writes.sort();
+        diff.balance_deltas = balances
+            .into_iter()
+            .filter(|(_, (credit, debit))| *credit != 0 || *debit != 0)
+            .map(|((owner, asset_id), (credit, debit))| BalanceDelta {
+                owner,
+                asset_id,
+                credit,
+                debit,
+            })
+            .collect();
+        Ok(diff)
+    }
+
+    /// Computes the state diff of the pending changes and stores it under the `height`.
+    pub fn store_state_diff(&self, height: &BlockHeight) -> DatabaseResult<StateDiff> {
+        let diff = self.state_diff()?;
+        let _: Option<StateDiff> =
+            self.insert(height.database_key(), Column::StateDiffs, &diff)?;
+        Ok(diff)
+    }
+
+    /// Returns the state diff of the block at the `height`, if it was stored.
+    pub fn get_state_diff(
+        &self,
+        height: &BlockHeight,
+    ) -> DatabaseResult<Option<StateDiff>> {
+        self.get(height.database_key().as_ref(), Column::StateDiffs)
+    }
+}
+
+/// Decodes the previous value from the operation that reverts the change.
+fn decode<V: DeserializeOwned>(operation: WriteOperation) -> DatabaseResult<Option<V>> {
+    match operation {
+        WriteOperation::Insert(value) => postcard::from_bytes(&value)
+            .map(Some)
+            .map_err(|_| DatabaseError::Codec),
+        WriteOperation::Remove => Ok(None),
+    }
+}
+
+pub(super) fn utxo_id_from_bytes(key: &[u8]) -> DatabaseResult<UtxoId> {
+    match key {
+        [tx_id @ .., output_index] if tx_id.len() == TxId::LEN => {
+            let tx_id = TxId::try_from(tx_id).map_err(|_| DatabaseError::Codec)?;
+            Ok(UtxoId::new(tx_id, *output_index))
+        }
+        _ => Err(DatabaseError::Codec),
+    }
+}
+
+/// Splits the key of the contract's table into the contract id and the second key.
+fn double_key(key: &[u8]) -> DatabaseResult<(ContractId, Bytes32)> {
+    if key.len() != ContractId::LEN + Bytes32::LEN {
+        return Err(DatabaseError::Codec)
+    }
+    let (contract_id, second) = key.split_at(ContractId::LEN);
+    let contract_id =
+        ContractId::try_from(contract_id).map_err(|_| DatabaseError::Codec)?;
+    let second = Bytes32::try_from(second).map_err(|_| DatabaseError::Codec)?;
+    Ok((contract_id, second))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::{
+        tables::{
+            Coins,
+            ContractsAssets,
+            ContractsState,
+        },
+        transactional::Transaction,
+        ContractsAssetKey,
+        ContractsStateKey,
+        StorageAsMut,
+    };
+    use fuel_core_types::fuel_types::Address;
+
+    fn coin(owner: Address, amount: Word) -> CompressedCoin {
+        CompressedCoin {
+            owner,
+            amount,
+            asset_id: AssetId::default(),
+            maturity: Default::default(),
+            tx_pointer: Default::default(),
+        }
+    }
+
+    #[test]
+    fn state_diff_contains_coins_storage_writes_and_balances() {
+        let mut database = Database::default();
+        let sender = Address::from([1; 32]);
+        let recipient = Address::from([2; 32]);
+        let contract_id = ContractId::from([3; 32]);
+        let asset_id = AssetId::from([4; 32]);
+        let spent = UtxoId::new([5; 32].into(), 0);
+        let created = UtxoId::new([6; 32].into(), 1);
+        let slot = Bytes32::from([7; 32]);
+        database
+            .storage::<Coins>()
+            .insert(&spent, &coin(sender, 100))
+            .unwrap();
+        database
+            .storage::<ContractsAssets>()
+            .insert(&ContractsAssetKey::new(&contract_id, &asset_id), &50)
+            .unwrap();
+
+        let mut transaction = database.transaction();
+        let view = transaction.as_mut();
+        view.storage::<Coins>().remove(&spent).unwrap();
+        view.storage::<Coins>()
+            .insert(&created, &coin(recipient, 100))
+            .unwrap();
+        view.storage::<ContractsState>()
+            .insert(
+                &ContractsStateKey::new(&contract_id, &slot),
+                &[8; 32].into(),
+            )
+            .unwrap();
+        view.storage::<ContractsAssets>()
+            .insert(&ContractsAssetKey::new(&contract_id, &asset_id), &20)
+            .unwrap();
+
+        let diff = transaction.state_diff().unwrap();
+
+        assert_eq!(diff.spent_coins, vec![coin(sender, 100).uncompress(spent)]);
+        assert_eq!(
+            diff.created_coins,
+            vec![coin(recipient, 100).uncompress(created)]
+        );
+        assert_eq!(
+            diff.contract_storage_writes,
+            vec![ContractStorageWrite {
+                contract_id,
+                key: slot,
+                value: Some([8; 32].into()),
+            }]
+        );
+        assert_eq!(
+            diff.balance_deltas,
+            vec![
+                BalanceDelta {
+                    owner: BalanceOwner::Address(sender),
+                    asset_id: AssetId::default(),
+                    credit: 0,
+                    debit: 100,
+                },
+                BalanceDelta {
+                    owner: BalanceOwner::Address(recipient),
+                    asset_id: AssetId::default(),
+                    credit: 100,
+                    debit: 0,
+                },
+                BalanceDelta {
+                    owner: BalanceOwner::Contract(contract_id),
+                    asset_id,
+                    credit: 0,
+                    debit: 30,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn stored_state_diff_is_committed_with_the_block() {
+        let database = Database::default();
+        let height = BlockHeight::from(1u32);
+        let mut transaction = database.transaction();
+        transaction
+            .as_mut()
+            .storage::<Coins>()
+            .insert(&UtxoId::default(), &coin(Address::default(), 10))
+            .unwrap();
+
+        let diff = transaction.store_state_diff(&height).unwrap();
+        transaction.commit().unwrap();
+
+        assert_eq!(diff.created_coins.len(), 1);
+        assert_eq!(database.get_state_diff(&height).unwrap(), Some(diff));
+        assert_eq!(database.get_state_diff(&2u32.into()).unwrap(), None);
+    }
+}
@@ -0,0 +1,146 @@
+use crate::database::{
+    storage::ToDatabaseKey,
+    Column,
+    Database,
+    Result as DatabaseResult,
+};
+use fuel_core_storage::{
+    tables::FuelBlocks,
+    Result as StorageResult,
+    StorageAsRef,
+};
+use fuel_core_types::fuel_types::BlockHeight;
+
+pub(crate) const COLD_HEIGHT_KEY: &[u8] = b"cold_height";
+
+impl Database {
+    /// Returns the height of the latest block moved to the cold storage.
+    pub fn cold_height(&self) -> DatabaseResult<Option<BlockHeight>> {
+        self.get(COLD_HEIGHT_KEY, Column::Metadata)
+    }
+
+    /// Moves the block at the `height` with its consensus, transactions, and receipts
+    /// to the cold storage and returns the number of the moved bytes. The block is
+    /// still readable afterwards. Fails if the database has no cold storage.
+    pub fn move_block_to_cold_storage(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<usize> {
+        let mut entries = vec![];
+        if let Some(block_id) = self.get_block_id(height)? {
+            if let Some(block) = self.storage::<FuelBlocks>().get(&block_id)? {
+                for tx_id in block.transactions() {
+                    entries.push((tx_id.database_key().to_vec(), Column::Transactions));
+                    entries.push((tx_id.database_key().to_vec(), Column::Receipts));
+                }
+            }
+            entries.push((block_id.database_key().to_vec(), Column::FuelBlocks));
+            entries.push((block_id.database_key().to_vec(), Column::FuelBlockConsensus));
+        }
+
+        let moved = self.data.move_to_cold(&entries)?;
+        let _: Option<BlockHeight> =
+            self.insert(COLD_HEIGHT_KEY, Column::Metadata, height)?;
+        Ok(moved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{
+        in_memory::memory_store::MemoryStore,
+        tiered::TieredStore,
+        DataSource,
+        KeyValueStore,
+    };
+    use fuel_core_storage::{
+        tables::{
+            Receipts,
+            Transactions,
+        },
+        StorageAsMut,
+    };
+    use fuel_core_types::{
+        blockchain::{
+            block::PartialFuelBlock,
+            header::{
+                ConsensusHeader,
+                PartialBlockHeader,
+            },
+            primitives::Empty,
+        },
+        fuel_tx::{
+            Receipt,
+            Transaction,
+            UniqueIdentifier,
+        },
+        fuel_types::ChainId,
+    };
+    use std::sync::Arc;
+
+    #[test]
+    fn moved_block_is_read_from_the_cold_storage() {
+        let hot: DataSource = Arc::new(MemoryStore::default());
+        let cold: DataSource = Arc::new(MemoryStore::default());
+        let mut database =
+            Database::new(Arc::new(TieredStore::new(hot.clone(), cold.clone())));
+
+        let tx = Transaction::default_test_tx();
+        let tx_id = tx.id(&ChainId::default());
+        let header = PartialBlockHeader {
+            application: Default::default(),
+            consensus: ConsensusHeader::<Empty> {
+                height: 1u32.into(),
+                ..Default::default()
+            },
+        };
+        let block = PartialFuelBlock::new(header, vec![tx.clone()]).generate(&[]);
+        let block_id = block.id();
+        database
+            .storage::<FuelBlocks>()
+            .insert(&block_id, &block.compress(&ChainId::default()))
+            .unwrap();
+        database
+            .storage::<Transactions>()
+            .insert(&tx_id, &tx)
+            .unwrap();
+        database
+            .storage::<Receipts>()
+            .insert(&tx_id, &[Receipt::ret(Default::default(), 1, 0, 0)])
+            .unwrap();
+
+        let moved = database.move_block_to_cold_storage(&1u32.into()).unwrap();
+
+        assert!(moved > 0);
+        assert_eq!(database.cold_height().unwrap(), Some(1u32.into()));
+        for (key, column) in [
+            (block_id.database_key().to_vec(), Column::FuelBlocks),
+            (tx_id.database_key().to_vec(), Column::Transactions),
+            (tx_id.database_key().to_vec(), Column::Receipts),
+        ] {
+            assert!(!hot.exists(&key, column).unwrap());
+            assert!(cold.exists(&key, column).unwrap());
+        }
+        assert!(database
+            .storage::<FuelBlocks>()
+            .get(&block_id)
+            .unwrap()
+            .is_some());
+        assert_eq!(
+            database
+                .storage::<Transactions>()
+                .get(&tx_id)
+                .unwrap()
+                .map(|tx| tx.into_owned()),
+            Some(tx)
+        );
+    }
+
+    #[test]
+    fn database_without_cold_storage_refuses_to_move_blocks() {
+        let database = Database::in_memory();
+
+        assert!(database.move_block_to_cold_storage(&1u32.into()).is_err());
+    }
+}
@@ -1,13 +1,20 @@
-use crate::database::{
-    Column,
-    Database,
-    Error as DatabaseError,
+use crate::{
+    database::{
+        Column,
+        Database,
+        Error as DatabaseError,
+    },
+    executor::ExecutorCache,
 };
 use anyhow::anyhow;
 use fuel_core_storage::{
     iter::IterDirection,
     not_found,
-    tables::ContractsState,
+    tables::{
+        ContractsInfo,
+        ContractsRawCode,
+        ContractsState,
+    },
     ContractsAssetsStorage,
     ContractsStateKey,
     Error as StorageError,
@@ -15,6 +22,7 @@ use fuel_core_storage::{
     MerkleRoot,
     MerkleRootStorage,
     StorageAsMut,
+    StorageAsRef,
     StorageInspect,
     StorageMutate,
     StorageRead,
@@ -38,7 +46,10 @@ use fuel_core_types::{
     tai64::Tai64,
 };
 use primitive_types::U256;
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    sync::Arc,
+};
 
 /// Used to store metadata relevant during the execution of a transaction
 #[derive(Clone, Debug)]
@@ -47,6 +58,7 @@ pub struct VmDatabase {
     current_timestamp: Tai64,
     coinbase: Address,
     database: Database,
+    cache: Option<Arc<ExecutorCache>>,
 }
 
 trait IncreaseStorageKey {
@@ -69,6 +81,7 @@ impl Default for VmDatabase {
             current_timestamp: Tai64::now(),
             coinbase: Default::default(),
             database: Default::default(),
+            cache: None,
         }
     }
 }
@@ -84,9 +97,16 @@ impl VmDatabase {
             current_timestamp: header.time,
             coinbase,
             database,
+            cache: None,
         }
     }
 
+    /// Loads the contract code through the `cache`.
+    pub fn with_cache(mut self, cache: Arc<ExecutorCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
     pub fn database_mut(&mut self) -> &mut Database {
         &mut self.database
     }
@@ -198,6 +218,28 @@ impl InterpreterStorage for VmDatabase {
         Ok(self.coinbase)
     }
 
+    fn storage_contract(
+        &self,
+        id: &ContractId,
+    ) -> Result<Option<Cow<'_, Contract>>, Self::DataError> {
+        let cache = match self.cache.as_ref() {
+            Some(cache) => cache,
+            None => return self.storage::<ContractsRawCode>().get(id),
+        };
+        let root = match self.storage::<ContractsInfo>().get(id)? {
+            Some(info) => info.1,
+            None => return self.storage::<ContractsRawCode>().get(id),
+        };
+        if let Some(code) = cache.contract_code(&root) {
+            return Ok(Some(Cow::Owned(code)))
+        }
+        let code = self.storage::<ContractsRawCode>().get(id)?;
+        if let Some(code) = code.as_ref() {
+            cache.insert_contract_code(root, code.clone().into_owned());
+        }
+        Ok(code)
+    }
+
     fn deploy_contract_with_id(
         &mut self,
         salt: &Salt,
@@ -4,14 +4,14 @@ use crate::database::{
     vm_database::VmDatabase,
     Database,
 };
-use fuel_core_executor::{
-    refs::ContractRef,
-    Config,
-};
+use fuel_core_executor::refs::ContractRef;
 use fuel_core_storage::{
     tables::{
         Coins,
+        ContractsInfo,
         ContractsLatestUtxo,
+        ContractsRawCode,
+        ContractsState,
         FuelBlocks,
         Messages,
         Receipts,
@@ -78,6 +78,7 @@ use fuel_core_types::{
         UtxoId,
     },
     fuel_types::{
+        bytes::SerializableVec,
         BlockHeight,
         MessageId,
     },
@@ -110,6 +111,7 @@ use fuel_core_types::{
             ExecutionType,
             ExecutionTypes,
             Result as ExecutorResult,
+            StateOverrides,
             TransactionExecutionResult,
             TransactionExecutionStatus,
             TransactionValidityError,
@@ -133,13 +135,25 @@ use tracing::{
     warn,
 };
 
+mod cache;
+#[cfg(feature = "parallel-executor")]
+mod parallel;
 mod ports;
+mod replay;
+#[cfg(feature = "debug")]
+mod trace;
 
+pub use cache::ExecutorCache;
+pub use fuel_core_executor::Config;
 pub use ports::{
     MaybeCheckedTransaction,
     RelayerPort,
     TransactionsSource,
 };
+pub use replay::{
+    Divergence,
+    ReplayedBlock,
+};
 
 pub type ExecutionBlockWithSource<TxSource> = ExecutionTypes<Components<TxSource>, Block>;
 
@@ -179,9 +193,11 @@ where
     pub database: Database,
     pub relayer: R,
     pub config: Arc<Config>,
+    pub cache: Arc<ExecutorCache>,
 }
 
 /// Data that is generated after executing all transactions.
+#[derive(Default)]
 struct ExecutionData {
     coinbase: u64,
     used_gas: u64,
@@ -249,6 +265,7 @@ impl Executor<Database> {
         Self {
             relayer: database.clone(),
             database,
+            cache: Arc::new(ExecutorCache::new(&config)),
             config: Arc::new(config),
         }
     }
@@ -273,6 +290,7 @@ where
         &self,
         component: Components<Transaction>,
         utxo_validation: Option<bool>,
+        overrides: StateOverrides,
     ) -> ExecutorResult<Vec<Vec<Receipt>>> {
         // fallback to service config value if no utxo_validation override is provided
         let utxo_validation =
@@ -288,6 +306,10 @@ where
             gas_limit: component.gas_limit,
         };
 
+        // The overrides live only in the database transaction that is never committed.
+        let mut overridden_db = self.database.transaction();
+        apply_state_overrides(overridden_db.as_mut(), overrides)?;
+
         let (
             ExecutionResult {
                 block,
@@ -296,7 +318,11 @@ where
             },
             temporary_db,
         ) = self
-            .execute_without_commit(ExecutionTypes::DryRun(component), options)?
+            .execute_inner(
+                ExecutionTypes::DryRun(component),
+                overridden_db.as_ref(),
+                options,
+            )?
             .into();
 
         // If one of the transactions fails, return an error.
@@ -320,6 +346,49 @@ where
     }
 }
 
+/// Writes the `overrides` into the `database`.
+fn apply_state_overrides(
+    database: &mut Database,
+    overrides: StateOverrides,
+) -> ExecutorResult<()> {
+    for coin in overrides.coins {
+        let utxo_id = coin.utxo_id;
+        database
+            .storage::<Coins>()
+            .insert(&utxo_id, &coin.compress())?;
+    }
+    for (contract_id, key, value) in overrides.contract_state {
+        database
+            .storage::<ContractsState>()
+            .insert(&(&contract_id, &key).into(), &value)?;
+    }
+    for (contract_id, code) in overrides.contract_code {
+        database
+            .storage::<ContractsRawCode>()
+            .insert(&contract_id, code.as_slice())?;
+        // The cached code is looked up by the root, so the root must follow the code.
+        let salt = database
+            .storage::<ContractsInfo>()
+            .get(&contract_id)?
+            .map(|info| info.0)
+            .unwrap_or_default();
+        let root = fuel_core_types::fuel_tx::Contract::from(code).root();
+        database
+            .storage::<ContractsInfo>()
+            .insert(&contract_id, &(salt, root))?;
+        // The contract without the UTXO can't be used as an input of the transaction.
+        if !database
+            .storage::<ContractsLatestUtxo>()
+            .contains_key(&contract_id)?
+        {
+            database
+                .storage::<ContractsLatestUtxo>()
+                .insert(&contract_id, &Default::default())?;
+        }
+    }
+    Ok(())
+}
+
 mod private {
     use super::*;
 
@@ -561,56 +630,89 @@ where
         };
 
         while iter.peek().is_some() {
-            for transaction in iter {
-                let mut filter_tx = |tx: MaybeCheckedTransaction, idx| {
-                    let mut tx_db_transaction = block_db_transaction.transaction();
-                    let tx_id = tx.id(&self.config.consensus_parameters.chain_id);
-                    let result = self.execute_transaction(
-                        idx,
-                        tx,
-                        &tx_id,
+            let transactions: Vec<_> = iter.collect();
+            #[cfg(feature = "parallel-executor")]
+            let batches = parallel::independent_batches(
+                transactions,
+                &self.config.consensus_parameters.chain_id,
+                self.config.parallel_workers,
+            );
+            #[cfg(not(feature = "parallel-executor"))]
+            let batches = [transactions];
+
+            for batch in batches {
+                #[cfg(feature = "parallel-executor")]
+                if batch.len() > 1 {
+                    // If any transaction fails, the batch is re-executed sequentially
+                    // below, so the failure is handled the same way.
+                    if let Some(executed) = self.execute_in_parallel(
+                        &batch,
+                        tx_index,
                         &block.header,
-                        execution_data,
                         execution_kind,
-                        &mut tx_db_transaction,
+                        block_db_transaction,
                         options,
-                    );
-
-                    let tx = match result {
-                        Err(err) => {
-                            return match execution_kind {
-                                ExecutionKind::Production => {
-                                    // If, during block production, we get an invalid transaction,
-                                    // remove it from the block and continue block creation. An invalid
-                                    // transaction means that the caller didn't validate it first, so
-                                    // maybe something is wrong with validation rules in the `TxPool`
-                                    // (or in another place that should validate it). Or we forgot to
-                                    // clean up some dependent/conflict transactions. But it definitely
-                                    // means that something went wrong, and we must fix it.
-                                    execution_data
-                                        .skipped_transactions
-                                        .push((tx_id, err));
-                                    None
-                                }
-                                ExecutionKind::DryRun | ExecutionKind::Validation => {
-                                    Some(Err(err))
+                    ) {
+                        for executed in executed {
+                            let tx = executed.commit(execution_data)?;
+                            tx_index += 1;
+                            block.transactions.push(tx);
+                        }
+                        continue
+                    }
+                }
+
+                for transaction in batch {
+                    let mut filter_tx = |tx: MaybeCheckedTransaction, idx| {
+                        let mut tx_db_transaction = block_db_transaction.transaction();
+                        let tx_id = tx.id(&self.config.consensus_parameters.chain_id);
+                        let result = self.execute_transaction(
+                            idx,
+                            tx,
+                            &tx_id,
+                            &block.header,
+                            execution_data,
+                            execution_kind,
+                            &mut tx_db_transaction,
+                            options,
+                        );
+
+                        let tx = match result {
+                            Err(err) => {
+                                return match execution_kind {
+                                    ExecutionKind::Production => {
+                                        // If, during block production, we get an invalid transaction,
+                                        // remove it from the block and continue block creation. An invalid
+                                        // transaction means that the caller didn't validate it first, so
+                                        // maybe something is wrong with validation rules in the `TxPool`
+                                        // (or in another place that should validate it). Or we forgot to
+                                        // clean up some dependent/conflict transactions. But it definitely
+                                        // means that something went wrong, and we must fix it.
+                                        execution_data
+                                            .skipped_transactions
+                                            .push((tx_id, err));
+                                        None
+                                    }
+                                    ExecutionKind::DryRun | ExecutionKind::Validation => {
+                                        Some(Err(err))
+                                    }
                                 }
                             }
+                            Ok(tx) => tx,
+                        };
+
+                        if let Err(err) = tx_db_transaction.commit() {
+                            return Some(Err(err.into()))
                         }
-                        Ok(tx) => tx,
+                        Some(Ok(tx))
                     };
 
-                    if let Err(err) = tx_db_transaction.commit() {
-                        return Some(Err(err.into()))
+                    let filtered_tx = filter_tx(transaction, tx_index);
+                    if let Some(result) = filtered_tx {
+                        let tx = result?;
+                        tx_index += 1;
+                        block.transactions.push(tx);
                     }
-                    Some(Ok(tx))
-                };
-
-                let filtered_tx = filter_tx(transaction, tx_index);
-                if let Some(result) = filtered_tx {
-                    let tx = result?;
-                    tx_index += 1;
-                    block.transactions.push(tx);
                 }
             }
 
@@ -791,23 +893,43 @@ where
         options: ExecutionOptions,
     ) -> ExecutorResult<Transaction>
     where
-        Tx: ExecutableTransaction + PartialEq + Cacheable + Send + Sync + 'static,
+        Tx: ExecutableTransaction
+            + PartialEq
+            + Cacheable
+            + SerializableVec
+            + Send
+            + Sync
+            + 'static,
         <Tx as IntoChecked>::Metadata: Fee + CheckedMetadata + Clone + Send + Sync,
     {
         let tx_id = checked_tx.id();
         let min_fee = checked_tx.metadata().min_fee();
         let max_fee = checked_tx.metadata().max_fee();
 
-        checked_tx = checked_tx
-            .check_predicates(&CheckPredicateParams::from(
-                &self.config.consensus_parameters,
-            ))
-            .map_err(|_| {
-                ExecutorError::TransactionValidity(
-                    TransactionValidityError::InvalidPredicate(tx_id),
-                )
-            })?;
-        debug_assert!(checked_tx.checks().contains(Checks::Predicates));
+        // The transaction is encoded only if the predicates cache is enabled.
+        let encoding = self
+            .cache
+            .caches_predicates()
+            .then(|| checked_tx.transaction().clone().to_bytes());
+        let predicates_verified = encoding
+            .as_ref()
+            .map(|encoding| self.cache.predicates_verified(encoding))
+            .unwrap_or(false);
+        if !predicates_verified {
+            checked_tx = checked_tx
+                .check_predicates(&CheckPredicateParams::from(
+                    &self.config.consensus_parameters,
+                ))
+                .map_err(|_| {
+                    ExecutorError::TransactionValidity(
+                        TransactionValidityError::InvalidPredicate(tx_id),
+                    )
+                })?;
+            debug_assert!(checked_tx.checks().contains(Checks::Predicates));
+            if let Some(encoding) = encoding.as_ref() {
+                self.cache.insert_verified_predicates(encoding);
+            }
+        }
 
         if options.utxo_validation {
             // validate utxos exist and maturity is properly set
@@ -833,7 +955,8 @@ where
             sub_db_view.clone(),
             &header.consensus,
             self.config.coinbase_recipient,
-        );
+        )
+        .with_cache(self.cache.clone());
         let mut vm = Interpreter::with_storage(
             vm_db,
             InterpreterParams::from(&self.config.consensus_parameters),
@@ -1596,10 +1719,24 @@ where
     ) -> ExecutorResult<()> {
         let mut owners = vec![];
         for input in inputs {
-            if let Input::CoinSigned(CoinSigned { owner, .. })
-            | Input::CoinPredicate(CoinPredicate { owner, .. }) = input
-            {
-                owners.push(owner);
+            match input {
+                Input::CoinSigned(CoinSigned { owner, .. })
+                | Input::CoinPredicate(CoinPredicate { owner, .. }) => {
+                    owners.push(owner);
+                }
+                // The sender of the message is the address on the DA layer,
+                // so only the recipient is the owner of the transaction.
+                Input::MessageCoinSigned(MessageCoinSigned { recipient, .. })
+                | Input::MessageCoinPredicate(MessageCoinPredicate {
+                    recipient, ..
+                })
+                | Input::MessageDataSigned(MessageDataSigned { recipient, .. })
+                | Input::MessageDataPredicate(MessageDataPredicate {
+                    recipient, ..
+                }) => {
+                    owners.push(recipient);
+                }
+                Input::Contract(_) => {}
             }
         }
 
@@ -1698,7 +1835,10 @@ mod tests {
     use fuel_core_storage::tables::Messages;
     use fuel_core_types::{
         blockchain::header::ConsensusHeader,
-        entities::message::Message,
+        entities::{
+            coins::coin::Coin,
+            message::Message,
+        },
         fuel_asm::op,
         fuel_crypto::SecretKey,
         fuel_merkle::sparse,
@@ -3749,6 +3889,36 @@ mod tests {
             .expect("block validation failed unexpectedly");
     }
 
+    #[test]
+    fn spent_message_is_indexed_by_recipient() {
+        let mut rng = StdRng::seed_from_u64(2322);
+
+        let (tx, message) = make_tx_and_message(&mut rng, 0);
+        let tx_id = tx.id(&ChainId::default());
+
+        let block = PartialFuelBlock {
+            header: Default::default(),
+            transactions: vec![tx],
+        };
+
+        let executor = make_executor(&[&message]);
+        executor
+            .execute_and_commit(
+                ExecutionBlock::Production(block),
+                ExecutionOptions {
+                    utxo_validation: true,
+                },
+            )
+            .expect("block execution failed unexpectedly");
+
+        let owned: Vec<_> = executor
+            .database
+            .owned_transactions(message.recipient, None, None)
+            .map(|res| res.unwrap().1)
+            .collect();
+        assert_eq!(owned, vec![tx_id]);
+    }
+
     #[test]
     fn successful_execution_consume_all_messages() {
         let mut rng = StdRng::seed_from_u64(2322);
@@ -4213,4 +4383,180 @@ mod tests {
 
         assert_eq!(time.0, receipts[0].val().unwrap());
     }
+
+    #[test]
+    fn dry_run_spends_coin_from_state_overrides() {
+        let tx = TxBuilder::new(2322u64)
+            .gas_limit(0)
+            .coin_input(AssetId::BASE, 1000)
+            .change_output(AssetId::BASE)
+            .build()
+            .transaction()
+            .clone();
+        let input = tx.inputs()[0].clone();
+        let database = Database::default();
+        let executor = Executor::test(database.clone(), Default::default());
+        let components = || Components {
+            header_to_produce: Default::default(),
+            transactions_source: tx.clone().into(),
+            gas_limit: u64::MAX,
+        };
+
+        // The coin doesn't exist without the overrides
+        let result = executor.dry_run(components(), Some(true), Default::default());
+        assert!(result.is_err());
+
+        let overrides = StateOverrides {
+            coins: vec![Coin {
+                utxo_id: *input.utxo_id().unwrap(),
+                owner: *input.input_owner().unwrap(),
+                amount: 1000,
+                asset_id: AssetId::BASE,
+                maturity: Default::default(),
+                tx_pointer: Default::default(),
+            }],
+            ..Default::default()
+        };
+        let receipts = executor
+            .dry_run(components(), Some(true), overrides)
+            .expect("The coin from the overrides is spendable");
+
+        assert_eq!(receipts.len(), 1);
+        // The overrides are not persisted
+        assert!(!database
+            .storage::<Coins>()
+            .contains_key(input.utxo_id().unwrap())
+            .unwrap());
+    }
+
+    #[test]
+    fn dry_run_executes_contract_code_from_state_overrides() {
+        let database = Database::default();
+        let executor = Executor::test(database.clone(), Default::default());
+        let (create, script) = setup_executable_script();
+        let contract_id = script
+            .inputs()
+            .iter()
+            .find_map(|input| match input {
+                Input::Contract(contract) => Some(contract.contract_id),
+                _ => None,
+            })
+            .unwrap();
+        let mut block = Block::default();
+        *block.transactions_mut() = vec![create.into()];
+        executor
+            .execute_and_commit(
+                ExecutionBlock::Production(block.into()),
+                Default::default(),
+            )
+            .unwrap();
+        let original_code = database
+            .storage::<ContractsRawCode>()
+            .get(&contract_id)
+            .unwrap()
+            .unwrap()
+            .into_owned();
+
+        let overrides = StateOverrides {
+            contract_code: vec![(
+                contract_id,
+                vec![op::movi(0x10, 42), op::ret(0x10)]
+                    .into_iter()
+                    .collect(),
+            )],
+            ..Default::default()
+        };
+        let receipts: Vec<_> = executor
+            .dry_run(
+                Components {
+                    header_to_produce: Default::default(),
+                    transactions_source: script.into(),
+                    gas_limit: u64::MAX,
+                },
+                None,
+                overrides,
+            )
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .collect();
+
+        assert!(receipts.iter().any(|receipt| matches!(
+            receipt,
+            Receipt::Return { id, val: 42, .. } if id == &contract_id
+        )));
+        // The original code stays in the database
+        let code = database
+            .storage::<ContractsRawCode>()
+            .get(&contract_id)
+            .unwrap()
+            .unwrap()
+            .into_owned();
+        assert_eq!(code, original_code);
+    }
+
+    #[test]
+    fn dry_run_code_override_is_not_shadowed_by_cached_code() {
+        let executor = Executor::test(
+            Database::default(),
+            Config {
+                contract_code_cache_size: 1024 * 1024,
+                ..Default::default()
+            },
+        );
+        let (create, script) = setup_executable_script();
+        let contract_id = script
+            .inputs()
+            .iter()
+            .find_map(|input| match input {
+                Input::Contract(contract) => Some(contract.contract_id),
+                _ => None,
+            })
+            .unwrap();
+        let mut block = Block::default();
+        *block.transactions_mut() = vec![create.into()];
+        executor
+            .execute_and_commit(
+                ExecutionBlock::Production(block.into()),
+                Default::default(),
+            )
+            .unwrap();
+        let dry_run = |overrides: StateOverrides| -> Vec<Receipt> {
+            executor
+                .dry_run(
+                    Components {
+                        header_to_produce: Default::default(),
+                        transactions_source: script.clone().into(),
+                        gas_limit: u64::MAX,
+                    },
+                    None,
+                    overrides,
+                )
+                .unwrap()
+                .into_iter()
+                .flatten()
+                .collect()
+        };
+        // The first call caches the deployed code
+        let receipts = dry_run(Default::default());
+        assert!(receipts.iter().any(|receipt| matches!(
+            receipt,
+            Receipt::Return { id, val: 1, .. } if id == &contract_id
+        )));
+
+        let receipts = dry_run(StateOverrides {
+            contract_code: vec![(
+                contract_id,
+                vec![op::movi(0x10, 42), op::ret(0x10)]
+                    .into_iter()
+                    .collect(),
+            )],
+            ..Default::default()
+        });
+
+        assert!(receipts.iter().any(|receipt| matches!(
+            receipt,
+            Receipt::Return { id, val: 42, .. } if id == &contract_id
+        )));
+    }
 }
@@ -0,0 +1,229 @@
+//! The caches shared between the executions of the blocks.
+//!
+//! The contract code is cached by its root, so the contracts deployed with the same
+//! code share the entry, and the new code of the contract never hits the old entry.
+//! The size of the cached code is limited by the memory budget, and the least
+//! recently used code is evicted first.
+//!
+//! The transactions with the verified predicates are cached by the hash of their
+//! canonical encoding, so the transaction validated during the block production or
+//! the dry run doesn't run its predicates again in the block of the same node.
+
+use super::Config;
+use fuel_core_types::{
+    fuel_crypto::Hasher,
+    fuel_tx::Contract,
+    fuel_types::Bytes32,
+};
+use lru::LruCache;
+use parking_lot::Mutex;
+
+#[cfg(feature = "metrics")]
+use fuel_core_metrics::executor_metrics::EXECUTOR_METRICS;
+
+const CONTRACT_CODE: &str = "contract_code";
+const PREDICATES: &str = "predicates";
+
+#[derive(Debug)]
+pub struct ExecutorCache {
+    contract_code: Mutex<ContractCodeCache>,
+    predicates: Mutex<LruCache<Bytes32, ()>>,
+    predicates_capacity: usize,
+}
+
+#[derive(Debug)]
+struct ContractCodeCache {
+    entries: LruCache<Bytes32, Contract>,
+    /// The total size of the cached code in bytes.
+    size: usize,
+    budget: usize,
+}
+
+impl ExecutorCache {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            contract_code: Mutex::new(ContractCodeCache {
+                entries: LruCache::unbounded(),
+                size: 0,
+                budget: config.contract_code_cache_size,
+            }),
+            predicates: Mutex::new(LruCache::unbounded()),
+            predicates_capacity: config.predicate_cache_size,
+        }
+    }
+
+    /// Returns the cached code with the `root`.
+    pub fn contract_code(&self, root: &Bytes32) -> Option<Contract> {
+        let mut cache = self.contract_code.lock();
+        if cache.budget == 0 {
+            return None
+        }
+        let code = cache.entries.get(root).cloned();
+        observe(CONTRACT_CODE, code.is_some());
+        code
+    }
+
+    /// Caches the `code` with the `root`. The code larger than the whole budget
+    /// isn't cached.
+    pub fn insert_contract_code(&self, root: Bytes32, code: Contract) {
+        let mut cache = self.contract_code.lock();
+        let len = code.as_ref().len();
+        if cache.budget == 0 || len > cache.budget || cache.entries.contains(&root) {
+            return
+        }
+        cache.size = cache.size.saturating_add(len);
+        cache.entries.put(root, code);
+        while cache.size > cache.budget {
+            match cache.entries.pop_lru() {
+                Some((_, evicted)) => {
+                    cache.size = cache.size.saturating_sub(evicted.as_ref().len());
+                }
+                None => break,
+            }
+        }
+        #[cfg(feature = "metrics")]
+        EXECUTOR_METRICS
+            .contract_code_cache_bytes
+            .set(cache.size as i64);
+    }
+
+    /// Returns `true` if the predicates of the transaction with the `encoding`
+    /// were already verified.
+    pub fn predicates_verified(&self, encoding: &[u8]) -> bool {
+        if self.predicates_capacity == 0 {
+            return false
+        }
+        let verified = self
+            .predicates
+            .lock()
+            .get(&Hasher::hash(encoding))
+            .is_some();
+        observe(PREDICATES, verified);
+        verified
+    }
+
+    /// Remembers that the predicates of the transaction with the `encoding`
+    /// are valid.
+    pub fn insert_verified_predicates(&self, encoding: &[u8]) {
+        if self.predicates_capacity == 0 {
+            return
+        }
+        let mut cache = self.predicates.lock();
+        cache.put(Hasher::hash(encoding), ());
+        while cache.len() > self.predicates_capacity {
+            cache.pop_lru();
+        }
+    }
+
+    /// Returns `true` if the predicates cache is enabled.
+    pub fn caches_predicates(&self) -> bool {
+        self.predicates_capacity > 0
+    }
+}
+
+/// The caches are disabled by default.
+impl Default for ExecutorCache {
+    fn default() -> Self {
+        Self::new(&Default::default())
+    }
+}
+
+#[cfg(feature = "metrics")]
+fn observe(cache: &'static str, hit: bool) {
+    if hit {
+        EXECUTOR_METRICS.observe_hit(cache);
+    } else {
+        EXECUTOR_METRICS.observe_miss(cache);
+    }
+}
+
+#[cfg(not(feature = "metrics"))]
+fn observe(_: &'static str, _: bool) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cache(
+        contract_code_cache_size: usize,
+        predicate_cache_size: usize,
+    ) -> ExecutorCache {
+        ExecutorCache::new(&Config {
+            contract_code_cache_size,
+            predicate_cache_size,
+            ..Default::default()
+        })
+    }
+
+    fn code(byte: u8, len: usize) -> (Bytes32, Contract) {
+        let code = Contract::from(vec![byte; len]);
+        (code.root(), code)
+    }
+
+    #[test]
+    fn cached_code_is_returned_by_root() {
+        let cache = cache(100, 0);
+        let (root, contract) = code(1, 10);
+
+        assert_eq!(cache.contract_code(&root), None);
+        cache.insert_contract_code(root, contract.clone());
+
+        assert_eq!(cache.contract_code(&root), Some(contract));
+        assert_eq!(cache.contract_code(&code(2, 10).0), None);
+    }
+
+    #[test]
+    fn least_recently_used_code_is_evicted_over_budget() {
+        let cache = cache(25, 0);
+        let (first, first_code) = code(1, 10);
+        let (second, second_code) = code(2, 10);
+        let (third, third_code) = code(3, 10);
+        cache.insert_contract_code(first, first_code);
+        cache.insert_contract_code(second, second_code);
+        // The first code becomes the most recently used one.
+        assert!(cache.contract_code(&first).is_some());
+
+        cache.insert_contract_code(third, third_code);
+
+        assert!(cache.contract_code(&first).is_some());
+        assert!(cache.contract_code(&second).is_none());
+        assert!(cache.contract_code(&third).is_some());
+        assert_eq!(cache.contract_code.lock().size, 20);
+    }
+
+    #[test]
+    fn code_larger_than_budget_is_not_cached() {
+        let cache = cache(5, 0);
+        let (root, contract) = code(1, 10);
+
+        cache.insert_contract_code(root, contract);
+
+        assert!(cache.contract_code(&root).is_none());
+        assert_eq!(cache.contract_code.lock().size, 0);
+    }
+
+    #[test]
+    fn verified_predicates_are_limited_by_capacity() {
+        let cache = cache(0, 2);
+        cache.insert_verified_predicates(&[1]);
+        cache.insert_verified_predicates(&[2]);
+        cache.insert_verified_predicates(&[3]);
+
+        assert!(!cache.predicates_verified(&[1]));
+        assert!(cache.predicates_verified(&[2]));
+        assert!(cache.predicates_verified(&[3]));
+        assert!(!cache.predicates_verified(&[4]));
+    }
+
+    #[test]
+    fn disabled_caches_keep_nothing() {
+        let cache = cache(0, 0);
+        let (root, contract) = code(1, 10);
+
+        cache.insert_contract_code(root, contract);
+        cache.insert_verified_predicates(&[1]);
+
+        assert!(cache.contract_code(&root).is_none());
+        assert!(!cache.predicates_verified(&[1]));
+    }
+}
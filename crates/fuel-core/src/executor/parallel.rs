@@ -0,0 +1,366 @@
+//! The parallel execution of the independent transactions of the block.
+//!
+//! The transactions are split into consecutive batches where no two transactions
+//! touch the same coins, messages, or contracts. The transactions of the batch are
+//! executed in parallel, each in its own database transaction, and the results are
+//! merged in the order of the block, so the outcome is the same as of the sequential
+//! execution.
+
+use super::{
+    ExecutionData,
+    ExecutionOptions,
+    Executor,
+    MaybeCheckedTransaction,
+    RelayerPort,
+};
+use crate::database::transaction::DatabaseTransaction;
+use fuel_core_storage::transactional::Transaction as StorageTransactionTrait;
+use fuel_core_types::{
+    blockchain::header::PartialBlockHeader,
+    fuel_tx::{
+        field::{
+            Inputs,
+            Outputs,
+        },
+        input::contract::Contract,
+        Input,
+        Output,
+        Transaction,
+        TxId,
+        UtxoId,
+    },
+    fuel_types::{
+        ChainId,
+        ContractId,
+        Nonce,
+    },
+    fuel_vm::checked_transaction::CheckedTransaction,
+    services::executor::{
+        Error as ExecutorError,
+        ExecutionKind,
+        Result as ExecutorResult,
+    },
+};
+use std::collections::HashSet;
+
+/// The part of the state that the transaction reads or modifies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum StateKey {
+    /// The outputs of the transaction.
+    Transaction(TxId),
+    Utxo(UtxoId),
+    Contract(ContractId),
+    Message(Nonce),
+}
+
+fn state_keys(tx: &MaybeCheckedTransaction, chain_id: &ChainId) -> Vec<StateKey> {
+    let (inputs, outputs): (&[Input], &[Output]) = match tx {
+        MaybeCheckedTransaction::CheckedTransaction(CheckedTransaction::Script(tx)) => {
+            (tx.transaction().inputs(), tx.transaction().outputs())
+        }
+        MaybeCheckedTransaction::CheckedTransaction(CheckedTransaction::Create(tx)) => {
+            (tx.transaction().inputs(), tx.transaction().outputs())
+        }
+        MaybeCheckedTransaction::CheckedTransaction(CheckedTransaction::Mint(tx)) => {
+            (&[], tx.transaction().outputs())
+        }
+        MaybeCheckedTransaction::Transaction(Transaction::Script(tx)) => {
+            (tx.inputs(), tx.outputs())
+        }
+        MaybeCheckedTransaction::Transaction(Transaction::Create(tx)) => {
+            (tx.inputs(), tx.outputs())
+        }
+        MaybeCheckedTransaction::Transaction(Transaction::Mint(tx)) => {
+            (&[], tx.outputs())
+        }
+    };
+
+    let mut keys = vec![StateKey::Transaction(tx.id(chain_id))];
+    for input in inputs {
+        if let Input::Contract(Contract { contract_id, .. }) = input {
+            keys.push(StateKey::Contract(*contract_id));
+        } else if let Some(utxo_id) = input.utxo_id() {
+            keys.push(StateKey::Utxo(*utxo_id));
+            keys.push(StateKey::Transaction(*utxo_id.tx_id()));
+        }
+        if let Some(nonce) = input.nonce() {
+            keys.push(StateKey::Message(*nonce));
+        }
+    }
+    for output in outputs {
+        if let Output::ContractCreated { contract_id, .. } = output {
+            keys.push(StateKey::Contract(*contract_id));
+        }
+    }
+    keys
+}
+
+/// Splits the `transactions` into consecutive batches of at most `max_batch_size`
+/// transactions that don't touch the same state.
+pub(super) fn independent_batches(
+    transactions: Vec<MaybeCheckedTransaction>,
+    chain_id: &ChainId,
+    max_batch_size: usize,
+) -> Vec<Vec<MaybeCheckedTransaction>> {
+    let max_batch_size = max_batch_size.max(1);
+    let mut batches = vec![];
+    let mut batch = vec![];
+    let mut touched = HashSet::new();
+    for tx in transactions {
+        let keys = state_keys(&tx, chain_id);
+        if batch.len() >= max_batch_size || keys.iter().any(|key| touched.contains(key)) {
+            batches.push(core::mem::take(&mut batch));
+            touched.clear();
+        }
+        touched.extend(keys);
+        batch.push(tx);
+    }
+    if !batch.is_empty() {
+        batches.push(batch);
+    }
+    batches
+}
+
+/// The transaction executed on top of the block database transaction
+/// without committing the changes.
+pub(super) struct ExecutedTransaction {
+    tx: Transaction,
+    data: ExecutionData,
+    db_transaction: DatabaseTransaction,
+}
+
+impl ExecutedTransaction {
+    /// Commits the changes of the transaction and merges its execution data
+    /// into the data of the block.
+    pub(super) fn commit(
+        mut self,
+        execution_data: &mut ExecutionData,
+    ) -> ExecutorResult<Transaction> {
+        self.db_transaction.commit()?;
+        execution_data.coinbase = execution_data
+            .coinbase
+            .checked_add(self.data.coinbase)
+            .ok_or(ExecutorError::FeeOverflow)?;
+        execution_data.used_gas =
+            execution_data.used_gas.saturating_add(self.data.used_gas);
+        execution_data.message_ids.extend(self.data.message_ids);
+        execution_data.tx_status.extend(self.data.tx_status);
+        Ok(self.tx)
+    }
+}
+
+impl<R> Executor<R>
+where
+    R: RelayerPort + Clone,
+{
+    /// Executes the independent `transactions` in parallel, starting from the
+    /// `first_idx` index in the block. Returns `None` if any of the transactions
+    /// fails, so the caller can execute them sequentially and handle the failure
+    /// the same way as without the parallel execution.
+    pub(super) fn execute_in_parallel(
+        &self,
+        transactions: &[MaybeCheckedTransaction],
+        first_idx: u16,
+        header: &PartialBlockHeader,
+        execution_kind: ExecutionKind,
+        block_db_transaction: &DatabaseTransaction,
+        options: ExecutionOptions,
+    ) -> Option<Vec<ExecutedTransaction>> {
+        let chain_id = &self.config.consensus_parameters.chain_id;
+        let results: Vec<ExecutorResult<ExecutedTransaction>> =
+            std::thread::scope(|scope| {
+                let handles: Vec<_> = transactions
+                    .iter()
+                    .enumerate()
+                    .map(|(i, tx)| {
+                        scope.spawn(move || {
+                            let mut data = ExecutionData::default();
+                            let mut db_transaction = block_db_transaction.transaction();
+                            let tx_id = tx.id(chain_id);
+                            let tx = self.execute_transaction(
+                                first_idx + i as u16,
+                                tx.clone(),
+                                &tx_id,
+                                header,
+                                &mut data,
+                                execution_kind,
+                                &mut db_transaction,
+                                options,
+                            )?;
+                            Ok(ExecutedTransaction {
+                                tx,
+                                data,
+                                db_transaction,
+                            })
+                        })
+                    })
+                    .collect();
+                handles
+                    .into_iter()
+                    .map(|handle| {
+                        handle
+                            .join()
+                            .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                    })
+                    .collect()
+            });
+
+        results.into_iter().collect::<ExecutorResult<Vec<_>>>().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        database::Database,
+        executor::tests::setup_executable_script,
+    };
+    use fuel_core_executor::Config;
+    use fuel_core_storage::{
+        tables::Receipts,
+        StorageAsRef,
+    };
+    use fuel_core_types::{
+        blockchain::block::Block,
+        fuel_tx::{
+            AssetId,
+            UniqueIdentifier,
+        },
+        fuel_vm::util::test_helpers::TestBuilder as TxBuilder,
+        services::executor::{
+            ExecutionBlock,
+            ExecutionResult,
+        },
+    };
+
+    fn independent_tx(seed: u64) -> Transaction {
+        TxBuilder::new(seed)
+            .gas_limit(10)
+            .coin_input(AssetId::default(), 100)
+            .coin_output(AssetId::default(), 50)
+            .change_output(AssetId::default())
+            .build()
+            .transaction()
+            .clone()
+            .into()
+    }
+
+    fn test_transactions() -> Vec<Transaction> {
+        let mut transactions: Vec<Transaction> = (0..8).map(independent_tx).collect();
+        // The script calls the contract deployed by the create transaction
+        let (create, script) = setup_executable_script();
+        transactions.insert(3, create.into());
+        transactions.push(script.into());
+        transactions.extend((8..12).map(independent_tx));
+        transactions
+    }
+
+    fn produce(
+        parallel_workers: usize,
+        transactions: Vec<Transaction>,
+    ) -> (ExecutionResult, Database) {
+        let database = Database::default();
+        let executor = Executor::test(
+            database.clone(),
+            Config {
+                parallel_workers,
+                ..Default::default()
+            },
+        );
+        let mut block = Block::default();
+        *block.transactions_mut() = transactions;
+        let result = executor
+            .execute_and_commit(
+                ExecutionBlock::Production(block.into()),
+                Default::default(),
+            )
+            .unwrap();
+        (result, database)
+    }
+
+    #[test]
+    fn independent_batches_split_conflicting_transactions() {
+        let chain_id = ChainId::default();
+        let (create, script) = setup_executable_script();
+        let transactions = vec![
+            independent_tx(0),
+            create.into(),
+            independent_tx(1),
+            script.into(),
+            independent_tx(2),
+        ]
+        .into_iter()
+        .map(MaybeCheckedTransaction::Transaction)
+        .collect();
+
+        let batches = independent_batches(transactions, &chain_id, 10);
+
+        let sizes: Vec<_> = batches.iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![3, 2]);
+    }
+
+    #[test]
+    fn independent_batches_respect_max_batch_size() {
+        let chain_id = ChainId::default();
+        let transactions = (0..5)
+            .map(|seed| MaybeCheckedTransaction::Transaction(independent_tx(seed)))
+            .collect();
+
+        let batches = independent_batches(transactions, &chain_id, 2);
+
+        let sizes: Vec<_> = batches.iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![2, 2, 1]);
+    }
+
+    #[test]
+    fn parallel_execution_matches_sequential_execution() {
+        let transactions = test_transactions();
+
+        let (sequential, sequential_db) = produce(1, transactions.clone());
+        let (parallel, parallel_db) = produce(4, transactions);
+
+        assert!(sequential.skipped_transactions.is_empty());
+        assert!(parallel.skipped_transactions.is_empty());
+        assert_eq!(parallel.block.id(), sequential.block.id());
+        assert_eq!(
+            parallel.block.transactions(),
+            sequential.block.transactions()
+        );
+        assert_eq!(parallel.tx_status.len(), sequential.tx_status.len());
+        for tx in sequential.block.transactions() {
+            let id = tx.id(&ChainId::default());
+            let sequential_receipts =
+                sequential_db.storage::<Receipts>().get(&id).unwrap();
+            let parallel_receipts = parallel_db.storage::<Receipts>().get(&id).unwrap();
+            assert_eq!(parallel_receipts, sequential_receipts);
+        }
+    }
+
+    #[test]
+    fn block_produced_in_parallel_is_validated_sequentially() {
+        let (parallel, _) = produce(4, test_transactions());
+        let verifier = Executor::test(Default::default(), Default::default());
+
+        let result = verifier.execute_and_commit(
+            ExecutionBlock::Validation(parallel.block),
+            Default::default(),
+        );
+
+        assert!(result.is_ok(), "{result:?}");
+    }
+
+    #[test]
+    fn failed_transaction_is_skipped_as_in_sequential_execution() {
+        let mut transactions = test_transactions();
+        // The duplicate of the first transaction fails with the id collision
+        transactions.insert(5, independent_tx(0));
+
+        let (sequential, _) = produce(1, transactions.clone());
+        let (parallel, _) = produce(4, transactions);
+
+        assert_eq!(sequential.skipped_transactions.len(), 1);
+        assert_eq!(parallel.skipped_transactions.len(), 1);
+        assert_eq!(parallel.block.id(), sequential.block.id());
+    }
+}
@@ -12,6 +12,7 @@ use fuel_core_types::{
 };
 
 /// The wrapper around either `Transaction` or `CheckedTransaction`.
+#[derive(Clone)]
 pub enum MaybeCheckedTransaction {
     CheckedTransaction(CheckedTransaction),
     Transaction(fuel_tx::Transaction),
@@ -39,7 +40,7 @@ pub trait TransactionsSource {
     fn next(&self, gas_limit: u64) -> Vec<MaybeCheckedTransaction>;
 }
 
-pub trait RelayerPort {
+pub trait RelayerPort: Send + Sync {
     /// Get a message from the relayer if it has been
     /// synced and is <= the given da height.
     fn get_message(
@@ -49,10 +50,9 @@ pub trait RelayerPort {
     ) -> anyhow::Result<Option<Message>>;
 }
 
-#[cfg(test)]
-/// For some tests we don't care about the actual
-/// implementation of the RelayerPort
-/// and using a passthrough is fine.
+/// The database contains all messages downloaded by the relayer, so it can serve them
+/// to re-execute the committed blocks. For some tests we don't care about the actual
+/// implementation of the RelayerPort and using a passthrough is fine.
 impl RelayerPort for crate::database::Database {
     fn get_message(
         &self,
@@ -0,0 +1,306 @@
+//! The re-execution of the committed blocks.
+//!
+//! Every block is validated again on top of the historical state of the previous
+//! block, and the receipts of its transactions are compared with the stored ones.
+//! The historical state is available only for the blocks with the stored rollback
+//! changes, so the node should run in the archive mode to replay the old blocks.
+
+use super::{
+    ExecutionOptions,
+    Executor,
+    RelayerPort,
+};
+use crate::database::Database;
+use fuel_core_storage::{
+    not_found,
+    tables::Receipts,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    blockchain::block::Block,
+    fuel_tx::{
+        Receipt,
+        TxId,
+        UniqueIdentifier,
+    },
+    fuel_types::BlockHeight,
+    services::executor::{
+        Error as ExecutorError,
+        ExecutionResult,
+        ExecutionTypes,
+        Result as ExecutorResult,
+    },
+};
+use std::borrow::Cow;
+
+/// The difference between the re-executed block and the stored one.
+#[derive(Debug)]
+pub enum Divergence {
+    /// The re-executed block doesn't match the stored one, for example,
+    /// the roots of the header or the outputs of the transactions differ.
+    InvalidBlock(ExecutorError),
+    /// The receipts of the transaction differ from the stored ones.
+    Receipts {
+        tx_id: TxId,
+        expected: Vec<Receipt>,
+        actual: Vec<Receipt>,
+    },
+}
+
+/// The result of the re-execution of the block.
+#[derive(Debug)]
+pub struct ReplayedBlock {
+    pub height: BlockHeight,
+    pub divergences: Vec<Divergence>,
+}
+
+impl<R> Executor<R>
+where
+    R: RelayerPort + Clone,
+{
+    /// Re-executes the committed blocks from `from` to `to` inclusive and reports the
+    /// divergences from the stored results. Nothing is committed to the database.
+    pub fn replay(
+        &self,
+        from: BlockHeight,
+        to: BlockHeight,
+    ) -> ExecutorResult<Vec<ReplayedBlock>> {
+        (*from..=*to)
+            .map(|height| self.replay_block(height.into()))
+            .collect()
+    }
+
+    fn replay_block(&self, height: BlockHeight) -> ExecutorResult<ReplayedBlock> {
+        let block = self
+            .database
+            .get_sealed_block_by_height(&height)?
+            .ok_or(not_found!("SealedBlock"))?
+            .entity;
+        let previous_height = height
+            .checked_sub(1)
+            .ok_or_else(|| anyhow::anyhow!("The genesis block can't be replayed"))
+            .map_err(fuel_core_storage::Error::from)?;
+        // The replay is started by the operator for the explicit range of the blocks,
+        // so the depth of the historical state isn't limited.
+        let state = self.database.state_at(&previous_height.into(), u32::MAX)?;
+        let options = ExecutionOptions::from(self.config.as_ref());
+
+        let divergences = match self.execute_inner(
+            ExecutionTypes::Validation(block.clone()),
+            state.as_ref(),
+            options,
+        ) {
+            Ok(result) => {
+                let (_, db_transaction): (ExecutionResult, _) = result.into();
+                self.receipt_divergences(&block, db_transaction.as_ref())?
+            }
+            Err(error) => vec![Divergence::InvalidBlock(error)],
+        };
+
+        Ok(ReplayedBlock {
+            height,
+            divergences,
+        })
+    }
+
+    /// Compares the stored receipts of the transactions of the `block` with the ones
+    /// produced by the re-execution in the `replayed` database.
+    fn receipt_divergences(
+        &self,
+        block: &Block,
+        replayed: &Database,
+    ) -> ExecutorResult<Vec<Divergence>> {
+        let chain_id = &self.config.consensus_parameters.chain_id;
+        let mut divergences = vec![];
+        for tx in block.transactions() {
+            let tx_id = tx.id(chain_id);
+            let expected = self
+                .database
+                .storage::<Receipts>()
+                .get(&tx_id)?
+                .map(Cow::into_owned)
+                .unwrap_or_default();
+            let actual = replayed
+                .storage::<Receipts>()
+                .get(&tx_id)?
+                .map(Cow::into_owned)
+                .unwrap_or_default();
+            if expected != actual {
+                divergences.push(Divergence::Receipts {
+                    tx_id,
+                    expected,
+                    actual,
+                });
+            }
+        }
+        Ok(divergences)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::executor::{
+        tests::setup_executable_script,
+        OnceTransactionsSource,
+    };
+    use fuel_core_storage::{
+        tables::{
+            ContractsRawCode,
+            SealedBlockConsensus,
+        },
+        transactional::Transaction as _,
+        StorageAsMut,
+    };
+    use fuel_core_types::{
+        blockchain::{
+            consensus::Consensus,
+            header::{
+                ConsensusHeader,
+                PartialBlockHeader,
+            },
+        },
+        fuel_asm::{
+            op,
+            RegId,
+        },
+        fuel_tx::{
+            field::Inputs,
+            AssetId,
+            Input,
+            Transaction,
+        },
+        fuel_vm::util::test_helpers::TestBuilder as TxBuilder,
+        services::block_producer::Components,
+    };
+
+    fn transfer(seed: u64) -> Transaction {
+        TxBuilder::new(seed)
+            .gas_limit(10)
+            .coin_input(AssetId::default(), 100)
+            .coin_output(AssetId::default(), 50)
+            .change_output(AssetId::default())
+            .build()
+            .transaction()
+            .clone()
+            .into()
+    }
+
+    /// Produces the block and commits it the same way as the block importer.
+    fn produce(
+        executor: &Executor<Database>,
+        height: u32,
+        transactions: Vec<Transaction>,
+    ) {
+        let header = PartialBlockHeader {
+            consensus: ConsensusHeader {
+                height: height.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let (result, mut db_transaction) = executor
+            .execute_without_commit(
+                ExecutionTypes::Production(Components {
+                    header_to_produce: header,
+                    transactions_source: OnceTransactionsSource::new(transactions),
+                    gas_limit: u64::MAX,
+                }),
+                Default::default(),
+            )
+            .unwrap()
+            .into();
+        let database = db_transaction.as_mut();
+        database
+            .storage::<SealedBlockConsensus>()
+            .insert(&result.block.id(), &Consensus::PoA(Default::default()))
+            .unwrap();
+        if height > 0 {
+            database
+                .store_rollback_changes(&height.into(), u32::MAX)
+                .unwrap();
+        }
+        db_transaction.commit().unwrap();
+    }
+
+    /// Produces the genesis block, the block deploying the contract, and
+    /// the block calling it.
+    fn chain() -> (Executor<Database>, Transaction) {
+        let executor = Executor::test(Default::default(), Default::default());
+        let (create, script) = setup_executable_script();
+        produce(&executor, 0, vec![]);
+        produce(&executor, 1, vec![transfer(1), create.into()]);
+        produce(&executor, 2, vec![transfer(2), script.clone().into()]);
+        (executor, script.into())
+    }
+
+    #[test]
+    fn replay_of_committed_blocks_has_no_divergences() {
+        let (executor, _) = chain();
+
+        let replayed = executor.replay(1u32.into(), 2u32.into()).unwrap();
+
+        assert_eq!(replayed.len(), 2);
+        for block in replayed {
+            assert!(block.divergences.is_empty(), "{:?}", block.divergences);
+        }
+    }
+
+    #[test]
+    fn replay_reports_modified_receipts() {
+        let (executor, script) = chain();
+        let tx_id = script.id(&Default::default());
+        let mut database = executor.database.clone();
+        database.storage::<Receipts>().insert(&tx_id, &[]).unwrap();
+
+        let replayed = executor.replay(2u32.into(), 2u32.into()).unwrap();
+
+        assert!(matches!(
+            replayed[0].divergences.as_slice(),
+            [Divergence::Receipts { tx_id: id, expected, actual }]
+                if id == &tx_id && expected.is_empty() && !actual.is_empty()
+        ));
+    }
+
+    #[test]
+    fn replay_reports_divergent_state() {
+        let (executor, script) = chain();
+        let contract_id = script
+            .as_script()
+            .unwrap()
+            .inputs()
+            .iter()
+            .find_map(|input| match input {
+                Input::Contract(contract) => Some(contract.contract_id),
+                _ => None,
+            })
+            .unwrap();
+        // The contract doesn't transfer the coins anymore
+        let mut database = executor.database.clone();
+        database
+            .storage::<ContractsRawCode>()
+            .insert(
+                &contract_id,
+                vec![op::ret(RegId::ONE)]
+                    .into_iter()
+                    .collect::<Vec<u8>>()
+                    .as_slice(),
+            )
+            .unwrap();
+
+        let replayed = executor.replay(1u32.into(), 2u32.into()).unwrap();
+
+        assert!(replayed[0].divergences.is_empty());
+        assert!(matches!(
+            replayed[1].divergences.as_slice(),
+            [Divergence::InvalidBlock(_)]
+        ));
+    }
+
+    #[test]
+    fn genesis_block_can_not_be_replayed() {
+        let (executor, _) = chain();
+
+        assert!(executor.replay(0u32.into(), 1u32.into()).is_err());
+    }
+}
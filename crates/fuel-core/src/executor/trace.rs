@@ -0,0 +1,376 @@
+//! The tracing of the transaction execution.
+//!
+//! The transaction is executed twice: the usual dry run provides the receipts, and
+//! the second execution with the single stepping of the VM records every executed
+//! instruction. The second execution doesn't affect the result of the trace other
+//! than the opcodes and the registers, so the receipts are the same as of `dry_run`.
+
+use super::{
+    Executor,
+    RelayerPort,
+};
+use crate::database::vm_database::VmDatabase;
+use fuel_core_types::{
+    fuel_asm::{
+        Instruction,
+        RegId,
+        Word,
+    },
+    fuel_tx::{
+        Receipt,
+        Script,
+        Transaction,
+        UniqueIdentifier,
+    },
+    fuel_vm::{
+        checked_transaction::{
+            Checked,
+            CheckedTransaction,
+            IntoChecked,
+        },
+        interpreter::InterpreterParams,
+        Interpreter,
+    },
+    services::{
+        block_producer::Components,
+        executor::{
+            CallFrameTrace,
+            CallOutcome,
+            Error as ExecutorError,
+            Result as ExecutorResult,
+            TracedInstruction,
+            TransactionTrace,
+        },
+    },
+};
+use std::collections::BTreeMap;
+
+impl<R> Executor<R>
+where
+    R: RelayerPort + Clone,
+{
+    /// Executes the transaction without committing it to the database and collects
+    /// the trace of the execution. The trace includes every executed instruction
+    /// if `full_opcode_stream` is set.
+    pub fn trace(
+        &self,
+        component: Components<Transaction>,
+        utxo_validation: Option<bool>,
+        full_opcode_stream: bool,
+    ) -> ExecutorResult<TransactionTrace> {
+        let header = component.header_to_produce.clone();
+        let transaction = component.transactions_source.clone();
+        let tx_id = transaction.id(&self.config.consensus_parameters.chain_id);
+        let receipts: Vec<Receipt> = self
+            .dry_run(component, utxo_validation, Default::default())?
+            .into_iter()
+            .flatten()
+            .collect();
+
+        let checked_tx: CheckedTransaction = transaction
+            .into_checked_basic(*header.height(), &self.config.consensus_parameters)?
+            .into();
+        let steps = match checked_tx {
+            CheckedTransaction::Script(script) => {
+                // The changes of the tracing execution are dropped with the transaction.
+                let mut db_transaction = self.database.transaction();
+                let vm_db = VmDatabase::new(
+                    db_transaction.as_mut().clone(),
+                    &header.consensus,
+                    self.config.coinbase_recipient,
+                )
+                .with_cache(self.cache.clone());
+                self.step_through(vm_db, script)?
+            }
+            // The `Create` transaction doesn't execute any instructions.
+            CheckedTransaction::Create(_) => Steps::default(),
+            CheckedTransaction::Mint(_) => {
+                return Err(ExecutorError::NotSupportedTransaction(tx_id))
+            }
+        };
+
+        let mut opcode_counts = BTreeMap::<String, u64>::new();
+        for step in steps.instructions.iter() {
+            if let Some(opcode) = &step.opcode {
+                *opcode_counts.entry(opcode.clone()).or_default() += 1;
+            }
+        }
+        let panicked = receipts
+            .iter()
+            .any(|receipt| matches!(receipt, Receipt::Panic { .. }));
+
+        Ok(TransactionTrace {
+            frames: call_frames(&receipts),
+            receipts,
+            opcode_counts: opcode_counts.into_iter().collect(),
+            opcodes: full_opcode_stream.then_some(steps.instructions),
+            panic_registers: panicked.then_some(steps.final_registers),
+        })
+    }
+
+    /// Executes the `script` one instruction at a time.
+    fn step_through(
+        &self,
+        vm_db: VmDatabase,
+        script: Checked<Script>,
+    ) -> ExecutorResult<Steps> {
+        let transaction_id = script.id();
+        let vm_error = |error| ExecutorError::VmExecution {
+            error,
+            transaction_id,
+        };
+        let mut vm = Interpreter::with_storage(
+            vm_db,
+            InterpreterParams::from(&self.config.consensus_parameters),
+        );
+        vm.set_single_stepping(true);
+
+        let mut state = *vm.transact(script).map_err(vm_error)?.state();
+        let mut instructions = vec![];
+        while state.debug_ref().is_some() {
+            let registers = vm.registers();
+            let pc = registers[RegId::PC];
+            let opcode = vm
+                .memory()
+                .get(pc as usize..(pc as usize).saturating_add(Instruction::SIZE))
+                .and_then(|bytes| <[u8; Instruction::SIZE]>::try_from(bytes).ok())
+                .and_then(|bytes| Instruction::try_from(bytes).ok())
+                .map(|instruction| format!("{:?}", instruction.opcode()));
+            instructions.push(TracedInstruction {
+                pc,
+                is: registers[RegId::IS],
+                opcode,
+            });
+            state = vm.resume().map_err(vm_error)?;
+        }
+
+        Ok(Steps {
+            instructions,
+            final_registers: vm.registers().to_vec(),
+        })
+    }
+}
+
+#[derive(Default)]
+struct Steps {
+    instructions: Vec<TracedInstruction>,
+    final_registers: Vec<Word>,
+}
+
+/// Restores the contract calls from the `receipts`.
+fn call_frames(receipts: &[Receipt]) -> Vec<CallFrameTrace> {
+    let mut frames: Vec<CallFrameTrace> = vec![];
+    // The indexes of the frames of the not finished calls.
+    let mut stack: Vec<usize> = vec![];
+    for receipt in receipts {
+        match receipt {
+            Receipt::Call {
+                to,
+                amount,
+                asset_id,
+                gas,
+                ..
+            } => {
+                stack.push(frames.len());
+                frames.push(CallFrameTrace {
+                    depth: (stack.len() - 1) as u32,
+                    contract_id: *to,
+                    asset_id: *asset_id,
+                    amount: *amount,
+                    gas: *gas,
+                    outcome: CallOutcome::Unfinished,
+                });
+            }
+            Receipt::Return { .. } | Receipt::ReturnData { .. } => {
+                // The return from the script doesn't have a frame.
+                if let Some(index) = stack.pop() {
+                    frames[index].outcome = CallOutcome::Return;
+                }
+            }
+            // The panic and the revert abort all not finished calls.
+            Receipt::Panic { .. } => {
+                for index in stack.drain(..) {
+                    frames[index].outcome = CallOutcome::Panic;
+                }
+            }
+            Receipt::Revert { .. } => {
+                for index in stack.drain(..) {
+                    frames[index].outcome = CallOutcome::Revert;
+                }
+            }
+            _ => {}
+        }
+    }
+    frames
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        database::Database,
+        executor::tests::setup_executable_script,
+    };
+    use fuel_core_types::{
+        blockchain::block::Block,
+        fuel_asm::op,
+        fuel_tx::TransactionBuilder,
+        services::executor::ExecutionBlock,
+    };
+
+    fn executor() -> Executor<Database> {
+        Executor::test(Default::default(), Default::default())
+    }
+
+    fn components(transaction: Transaction) -> Components<Transaction> {
+        Components {
+            header_to_produce: Default::default(),
+            transactions_source: transaction,
+            gas_limit: u64::MAX,
+        }
+    }
+
+    fn script(ops: Vec<Instruction>) -> Transaction {
+        TransactionBuilder::script(ops.into_iter().collect(), vec![])
+            .gas_limit(1_000_000)
+            .add_random_fee_input()
+            .finalize_as_transaction()
+    }
+
+    #[test]
+    fn trace_counts_opcodes_of_the_script() {
+        let tx = script(vec![
+            op::movi(0x10, 1),
+            op::movi(0x11, 2),
+            op::add(0x12, 0x10, 0x11),
+            op::ret(0x12),
+        ]);
+
+        let trace = executor().trace(components(tx), None, true).unwrap();
+
+        let opcodes: Vec<_> = trace
+            .opcodes
+            .unwrap()
+            .into_iter()
+            .map(|step| step.opcode.unwrap())
+            .collect();
+        assert_eq!(opcodes, vec!["MOVI", "MOVI", "ADD", "RET"]);
+        assert_eq!(
+            trace.opcode_counts,
+            vec![
+                ("ADD".to_string(), 1),
+                ("MOVI".to_string(), 2),
+                ("RET".to_string(), 1)
+            ]
+        );
+        assert!(trace.frames.is_empty());
+        assert!(trace.panic_registers.is_none());
+        assert!(matches!(
+            trace.receipts.first(),
+            Some(Receipt::Return { val: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn trace_omits_opcode_stream_unless_requested() {
+        let tx = script(vec![op::ret(RegId::ONE)]);
+
+        let trace = executor().trace(components(tx), None, false).unwrap();
+
+        assert!(trace.opcodes.is_none());
+        assert_eq!(trace.opcode_counts, vec![("RET".to_string(), 1)]);
+    }
+
+    #[test]
+    fn trace_snapshots_registers_at_panic() {
+        let tx = script(vec![
+            op::movi(0x11, 42),
+            op::div(0x10, RegId::ONE, RegId::ZERO),
+            op::ret(RegId::ONE),
+        ]);
+
+        let trace = executor().trace(components(tx), None, false).unwrap();
+
+        let registers = trace.panic_registers.expect("The script panics");
+        assert_eq!(registers[0x11], 42);
+        assert!(trace
+            .receipts
+            .iter()
+            .any(|receipt| matches!(receipt, Receipt::Panic { .. })));
+        assert!(!trace
+            .opcode_counts
+            .iter()
+            .any(|(opcode, _)| opcode == "RET"));
+    }
+
+    #[test]
+    fn trace_contains_call_frames() {
+        let executor = executor();
+        let (create, script) = setup_executable_script();
+        let mut block = Block::default();
+        *block.transactions_mut() = vec![create.into()];
+        executor
+            .execute_and_commit(
+                ExecutionBlock::Production(block.into()),
+                Default::default(),
+            )
+            .unwrap();
+
+        let trace = executor
+            .trace(components(script.into()), None, false)
+            .unwrap();
+
+        assert_eq!(trace.frames.len(), 1);
+        let frame = &trace.frames[0];
+        assert_eq!(frame.depth, 0);
+        assert_eq!(frame.outcome, CallOutcome::Return);
+        assert!(trace
+            .opcode_counts
+            .iter()
+            .any(|(opcode, count)| opcode == "CALL" && *count == 1));
+    }
+
+    #[test]
+    fn call_frames_follow_nesting() {
+        let call = |to: u8| Receipt::Call {
+            id: Default::default(),
+            to: [to; 32].into(),
+            amount: 0,
+            asset_id: Default::default(),
+            gas: 100,
+            param1: 0,
+            param2: 0,
+            pc: 0,
+            is: 0,
+        };
+        let ret = Receipt::Return {
+            id: Default::default(),
+            val: 0,
+            pc: 0,
+            is: 0,
+        };
+        let revert = Receipt::Revert {
+            id: Default::default(),
+            ra: 0,
+            pc: 0,
+            is: 0,
+        };
+        let receipts = vec![call(1), call(2), ret.clone(), call(3), call(4), revert];
+
+        let frames = call_frames(&receipts);
+
+        let summary: Vec<_> = frames
+            .iter()
+            .map(|frame| (frame.contract_id.as_ref()[0], frame.depth, frame.outcome))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (1, 0, CallOutcome::Revert),
+                (2, 1, CallOutcome::Return),
+                (3, 1, CallOutcome::Revert),
+                (4, 2, CallOutcome::Revert),
+            ]
+        );
+    }
+}
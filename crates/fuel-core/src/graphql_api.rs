@@ -1,3 +1,4 @@
+use crate::graphql_api::auth::ApiTokens;
 use fuel_core_storage::{
     Error as StorageError,
     IsNotFound,
@@ -7,24 +8,86 @@ use fuel_core_types::{
     fuel_tx::ConsensusParameters,
     secrecy::Secret,
 };
-use std::net::SocketAddr;
+use std::{
+    net::SocketAddr,
+    path::PathBuf,
+    time::Duration,
+};
 
+pub mod auth;
 #[cfg(feature = "metrics")]
 pub(crate) mod metrics_extension;
 pub mod ports;
 pub mod service;
+pub(crate) mod timeout_extension;
+pub(crate) mod tls;
 
 #[derive(Clone, Debug)]
 pub struct Config {
     pub addr: SocketAddr,
     pub utxo_validation: bool,
     pub manual_blocks_enabled: bool,
+    pub peer_admin_enabled: bool,
+    pub production_admin_enabled: bool,
+    pub database_admin_enabled: bool,
+    pub database_checkpoint_dir: Option<PathBuf>,
     pub vm_backtrace: bool,
+    pub vm_tracing: bool,
     pub min_gas_price: u64,
     pub max_tx: usize,
     pub max_depth: usize,
+    pub block_gas_limit: u64,
     pub consensus_parameters: ConsensusParameters,
     pub consensus_key: Option<Secret<SecretKeyWrapper>>,
+    /// The limits of the queries accepted by the API.
+    pub limits: QueryLimits,
+    /// The tokens granting the access to the restricted endpoints.
+    pub api_tokens: ApiTokens,
+    /// Serves the API over HTTPS instead of HTTP if set.
+    pub tls: Option<TlsConfig>,
+    /// The origins allowed to make the cross-origin requests. All origins are
+    /// allowed if empty.
+    pub cors_allowed_origins: Vec<String>,
+}
+
+/// The certificate of the HTTPS server.
+#[derive(Clone, Debug)]
+pub struct TlsConfig {
+    /// The path of the PEM-encoded certificate chain.
+    pub cert_path: PathBuf,
+    /// The path of the PEM-encoded private key.
+    pub key_path: PathBuf,
+    /// The interval of checking the files for the rotated certificate. The new
+    /// certificate is used by the new connections without restarting the node.
+    pub reload_interval: Duration,
+}
+
+/// The limits protecting the API from the queries too expensive to execute.
+#[derive(Clone, Debug)]
+pub struct QueryLimits {
+    /// The maximum depth of the nested fields of the query.
+    pub max_depth: usize,
+    /// The maximum complexity of the query. Every field costs one, and the fields
+    /// of the connections cost as much as the size of the requested page.
+    pub max_complexity: usize,
+    /// The maximum time of the execution of the query or the mutation. The
+    /// subscriptions aren't limited.
+    pub request_timeout: Duration,
+    /// The maximum number of the blocks between the latest block and the height of
+    /// the historical state requested by the query. The changes of every later block
+    /// are reverted to build the state.
+    pub max_historical_depth: u32,
+}
+
+impl Default for QueryLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: 16,
+            max_complexity: 80_000,
+            request_timeout: Duration::from_secs(30),
+            max_historical_depth: 1000,
+        }
+    }
 }
 
 pub trait IntoApiResult<T> {
@@ -0,0 +1,157 @@
+//! The authentication of the API requests by the bearer tokens.
+//!
+//! Every token grants the role that scopes the endpoints available to its requests.
+//! The requests without the token are read-only, so the public data stays open while
+//! the transaction submission, the debugging, and the administration of the node
+//! can be restricted to the token holders. All requests are allowed if no token
+//! is configured.
+
+use async_graphql::{
+    Context,
+    Guard,
+};
+use axum::http::{
+    header::AUTHORIZATION,
+    HeaderMap,
+};
+use std::{
+    collections::HashMap,
+    fmt,
+};
+use strum_macros::{
+    Display,
+    EnumString,
+    EnumVariantNames,
+};
+
+/// The scope of the access to the API. Every role includes the scopes of the
+/// lower ones.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Display,
+    EnumString,
+    EnumVariantNames,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+)]
+#[strum(serialize_all = "kebab_case")]
+pub enum ApiRole {
+    /// Queries the chain and subscribes to its updates.
+    ReadOnly,
+    /// Submits and dry runs the transactions.
+    SubmitTx,
+    /// Debugs the transactions and administers the node.
+    Admin,
+}
+
+/// The tokens accepted by the API with the roles they grant.
+#[derive(Clone, Default)]
+pub struct ApiTokens(HashMap<String, ApiRole>);
+
+impl ApiTokens {
+    pub fn new(tokens: impl IntoIterator<Item = (String, ApiRole)>) -> Self {
+        Self(tokens.into_iter().collect())
+    }
+
+    /// Returns the role of the request with the `token`, or `None` if the token
+    /// is unknown.
+    pub fn role(&self, token: Option<&str>) -> Option<ApiRole> {
+        if self.0.is_empty() {
+            return Some(ApiRole::Admin)
+        }
+        match token {
+            Some(token) => self.0.get(token).copied(),
+            None => Some(ApiRole::ReadOnly),
+        }
+    }
+}
+
+impl fmt::Debug for ApiTokens {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The tokens are secrets, so only the granted roles are printed.
+        f.debug_list().entries(self.0.values()).finish()
+    }
+}
+
+/// Returns the bearer token of the `Authorization` header, if any.
+pub(crate) fn bearer_token(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")
+        .map(str::trim)
+}
+
+/// Allows the field only to the requests with the `role` or a higher one.
+pub(crate) struct RoleGuard {
+    role: ApiRole,
+}
+
+impl RoleGuard {
+    pub fn new(role: ApiRole) -> Self {
+        Self { role }
+    }
+}
+
+#[async_trait::async_trait]
+impl Guard for RoleGuard {
+    async fn check(&self, ctx: &Context<'_>) -> async_graphql::Result<()> {
+        // The role is attached to every request by the server, the requests
+        // without it are treated as anonymous.
+        let granted = ctx
+            .data_opt::<ApiRole>()
+            .copied()
+            .unwrap_or(ApiRole::ReadOnly);
+        if granted < self.role {
+            return Err(async_graphql::Error::new(format!(
+                "The `{}` role is required to use this endpoint",
+                self.role
+            )))
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requests_are_scoped_by_the_role_of_the_token() {
+        let tokens = ApiTokens::new([
+            ("reader".to_string(), ApiRole::ReadOnly),
+            ("admin".to_string(), ApiRole::Admin),
+        ]);
+
+        assert_eq!(tokens.role(None), Some(ApiRole::ReadOnly));
+        assert_eq!(tokens.role(Some("reader")), Some(ApiRole::ReadOnly));
+        assert_eq!(tokens.role(Some("admin")), Some(ApiRole::Admin));
+        assert_eq!(tokens.role(Some("unknown")), None);
+    }
+
+    #[test]
+    fn all_requests_are_allowed_without_tokens() {
+        let tokens = ApiTokens::default();
+
+        assert_eq!(tokens.role(None), Some(ApiRole::Admin));
+        assert_eq!(tokens.role(Some("unknown")), Some(ApiRole::Admin));
+    }
+
+    #[test]
+    fn bearer_token_is_parsed_from_the_authorization_header() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(bearer_token(&headers), None);
+
+        headers.insert(AUTHORIZATION, "Bearer secret".parse().unwrap());
+        assert_eq!(bearer_token(&headers), Some("secret"));
+
+        headers.insert(AUTHORIZATION, "Basic secret".parse().unwrap());
+        assert_eq!(bearer_token(&headers), None);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::database::Column;
 use async_trait::async_trait;
 use fuel_core_services::stream::{
     BoxFuture,
@@ -13,6 +14,7 @@ use fuel_core_storage::{
         ContractsAssets,
         ContractsInfo,
         ContractsRawCode,
+        ContractsState,
         FuelBlocks,
         Messages,
         Receipts,
@@ -24,11 +26,18 @@ use fuel_core_storage::{
     Result as StorageResult,
     StorageInspect,
 };
-use fuel_core_txpool::service::TxStatusMessage;
+use fuel_core_txpool::service::{
+    TxStatusFilter,
+    TxStatusMessage,
+    TxStatusUpdate,
+};
 use fuel_core_types::{
-    blockchain::primitives::{
-        BlockId,
-        DaBlockHeight,
+    blockchain::{
+        header::BlockHeader,
+        primitives::{
+            BlockId,
+            DaBlockHeight,
+        },
     },
     entities::message::{
         MerkleProof,
@@ -45,11 +54,34 @@ use fuel_core_types::{
         Address,
         AssetId,
         BlockHeight,
+        Bytes32,
+        ChainId,
         ContractId,
         Nonce,
     },
     services::{
-        graphql_api::ContractBalance,
+        block_importer::{
+            CoinEvent,
+            StateDiff,
+        },
+        block_producer::BlockDryRun,
+        executor::{
+            StateOverrides,
+            TransactionTrace,
+        },
+        graphql_api::{
+            AddressBalance,
+            AssetSupply,
+            ChainStats,
+            ContractBalance,
+            ContractReceipt,
+            ReceiptPointer,
+        },
+        p2p::{
+            PeerId,
+            PeerInfo,
+        },
+        sync::SyncStatus,
         txpool::{
             InsertionResult,
             TransactionStatus,
@@ -57,7 +89,10 @@ use fuel_core_types::{
     },
     tai64::Tai64,
 };
-use std::sync::Arc;
+use std::{
+    path::Path,
+    sync::Arc,
+};
 
 /// The database port expected by GraphQL API service.
 pub trait DatabasePort:
@@ -68,9 +103,19 @@ pub trait DatabasePort:
     + DatabaseMessages
     + DatabaseCoins
     + DatabaseContracts
+    + DatabaseAssets
     + DatabaseChain
     + DatabaseMessageProof
+    + DatabaseAdmin
 {
+    /// Returns the view of the database at the `height`, where the changes of the
+    /// later blocks are reverted. The `height` can be at most `max_depth` blocks
+    /// behind the latest block.
+    fn view_at(
+        &self,
+        height: &BlockHeight,
+        max_depth: u32,
+    ) -> StorageResult<Box<dyn DatabasePort>>;
 }
 
 /// Trait that specifies all the getters required for blocks.
@@ -87,6 +132,10 @@ pub trait DatabaseBlocks:
     ) -> BoxedIter<'_, StorageResult<(BlockHeight, BlockId)>>;
 
     fn ids_of_latest_block(&self) -> StorageResult<(BlockHeight, BlockId)>;
+
+    /// Returns the state diff of the block at the `height`,
+    /// if the node computed it during the import.
+    fn state_diff(&self, height: &BlockHeight) -> StorageResult<Option<StateDiff>>;
 }
 
 /// Trait that specifies all the getters required for transactions.
@@ -102,6 +151,15 @@ pub trait DatabaseTransactions:
         start: Option<TxPointer>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
+
+    /// Returns the receipts emitted by the `contract` starting from the `start`.
+    /// Only available if the node indexes the receipts.
+    fn contract_receipts(
+        &self,
+        contract: ContractId,
+        start: Option<ReceiptPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<ContractReceipt>>;
 }
 
 /// Trait that specifies all the getters required for messages.
@@ -138,6 +196,7 @@ pub trait DatabaseContracts:
     StorageInspect<ContractsRawCode, Error = StorageError>
     + StorageInspect<ContractsInfo, Error = StorageError>
     + StorageInspect<ContractsAssets, Error = StorageError>
+    + StorageInspect<ContractsState, Error = StorageError>
 {
     fn contract_balances(
         &self,
@@ -145,6 +204,32 @@ pub trait DatabaseContracts:
         start_asset: Option<AssetId>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<ContractBalance>>;
+
+    /// Returns the keys and the values of the storage slots of the contract
+    /// ordered by the keys.
+    fn contract_slots(
+        &self,
+        contract: ContractId,
+        start_key: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(Bytes32, Bytes32)>>;
+}
+
+/// Trait that specifies all the getters required for the assets.
+pub trait DatabaseAssets {
+    /// Returns the supply of the asset, or `None` if the asset is unknown.
+    /// Only available if the node indexes the assets.
+    fn asset_supply(&self, asset_id: &AssetId) -> StorageResult<Option<AssetSupply>>;
+
+    /// Returns the holders of the asset from the largest balance, starting from
+    /// the holder with the `start` balance and owner.
+    /// Only available if the node indexes the assets.
+    fn asset_holders(
+        &self,
+        asset_id: AssetId,
+        start: Option<(u64, Address)>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<AddressBalance>>;
 }
 
 /// Trait that specifies all the getters required for chain metadata.
@@ -154,21 +239,61 @@ pub trait DatabaseChain {
     fn base_chain_height(&self) -> StorageResult<DaBlockHeight>;
 }
 
+/// Trait that specifies the maintenance of the database.
+pub trait DatabaseAdmin {
+    /// Starts the compaction of the `column`, or of all columns if `None`, in the
+    /// background.
+    fn start_compaction(&self, column: Option<Column>) -> anyhow::Result<()>;
+
+    /// Creates the checkpoint of the database at the `path` on the file system of
+    /// the node. The `path` must not exist.
+    fn create_checkpoint(&self, path: &Path) -> anyhow::Result<()>;
+}
+
 #[async_trait]
 pub trait TxPoolPort: Send + Sync {
     fn transaction(&self, id: TxId) -> Option<Transaction>;
 
     fn submission_time(&self, id: TxId) -> Option<Tai64>;
 
+    /// Returns all pending transactions of the pool sorted by their ids.
+    fn pending_transactions(&self) -> Vec<(TxId, Transaction)>;
+
+    fn pending_number(&self) -> usize;
+
+    fn total_consumable_gas(&self) -> u64;
+
+    /// Returns the number of pending transactions per owner sorted by the owner.
+    fn pending_number_by_owner(&self) -> Vec<(Address, usize)>;
+
     async fn insert(
         &self,
         txs: Vec<Arc<Transaction>>,
     ) -> Vec<anyhow::Result<InsertionResult>>;
 
+    /// Inserts the transactions without broadcasting them to the network.
+    async fn insert_local(
+        &self,
+        txs: Vec<Arc<Transaction>>,
+    ) -> Vec<anyhow::Result<InsertionResult>>;
+
+    /// Inserts the transactions in their order. Fails for the whole batch if some
+    /// transaction depends on the transaction that follows it.
+    async fn insert_batch(
+        &self,
+        txs: Vec<Arc<Transaction>>,
+    ) -> anyhow::Result<Vec<anyhow::Result<InsertionResult>>>;
+
     fn tx_update_subscribe(
         &self,
         tx_id: TxId,
     ) -> BoxFuture<'_, BoxStream<TxStatusMessage>>;
+
+    /// Subscribes to the status updates of all transactions matching the `filter`.
+    fn tx_update_subscribe_filtered(
+        &self,
+        filter: TxStatusFilter,
+    ) -> BoxStream<TxStatusUpdate>;
 }
 
 #[async_trait]
@@ -178,10 +303,29 @@ pub trait DryRunExecution {
         transaction: Transaction,
         height: Option<BlockHeight>,
         utxo_validation: Option<bool>,
+        overrides: StateOverrides,
     ) -> anyhow::Result<Vec<Receipt>>;
+
+    /// Assembles the next block from the transactions of the pool without committing
+    /// or signing it.
+    async fn dry_run_block(&self, block_gas_limit: u64) -> anyhow::Result<BlockDryRun>;
+
+    /// Simulates the transaction like `dry_run_tx` and returns the trace of its execution.
+    async fn trace_tx(
+        &self,
+        transaction: Transaction,
+        utxo_validation: Option<bool>,
+        full_opcode_stream: bool,
+    ) -> anyhow::Result<TransactionTrace>;
 }
 
-pub trait BlockProducerPort: Send + Sync + DryRunExecution {}
+pub trait BlockProducerPort: Send + Sync + DryRunExecution {
+    /// The minimum gas price of the transactions for the next block.
+    fn latest_gas_price(&self) -> u64;
+
+    /// The estimated minimum gas price after `block_horizon` blocks.
+    fn estimate_gas_price(&self, block_horizon: u32) -> u64;
+}
 
 #[async_trait::async_trait]
 pub trait ConsensusModulePort: Send + Sync {
@@ -190,6 +334,63 @@ pub trait ConsensusModulePort: Send + Sync {
         start_time: Option<Tai64>,
         number_of_blocks: u32,
     ) -> anyhow::Result<()>;
+
+    /// Pauses the block production after the block in progress.
+    async fn pause_block_production(&self) -> anyhow::Result<()>;
+
+    /// Resumes the paused block production.
+    async fn resume_block_production(&self) -> anyhow::Result<()>;
+}
+
+#[async_trait::async_trait]
+pub trait P2pPort: Send + Sync {
+    /// Returns the information about all connected peers.
+    async fn all_peer_info(&self) -> anyhow::Result<Vec<PeerInfo>>;
+
+    /// Closes all connections with the peer.
+    fn disconnect_peer(&self, peer_id: PeerId) -> anyhow::Result<()>;
+
+    /// Bans the peer, so no connections with it are allowed.
+    fn ban_peer(&self, peer_id: PeerId) -> anyhow::Result<()>;
+}
+
+pub trait BlockImporterPort: Send + Sync {
+    /// Returns the stream of the headers of the imported blocks. The headers are
+    /// skipped if the stream is polled slower than the blocks are imported.
+    fn block_headers(&self) -> BoxStream<BlockHeader>;
+
+    /// Returns the stream of the changes of the coins made by the imported and the
+    /// reverted blocks. The `chain_id` is required to compute the ids of the coins
+    /// created by the reverted blocks. The events are skipped if the stream is polled
+    /// slower than the blocks are imported.
+    fn coin_events(&self, chain_id: ChainId) -> BoxStream<CoinEvent>;
+}
+
+pub trait SyncPort: Send + Sync {
+    /// Returns the latest sync status, or `None` if the sync is disabled.
+    fn sync_status(&self) -> Option<SyncStatus>;
+}
+
+pub trait ChainStatsPort: Send + Sync {
+    /// Returns the statistics of up to `window` latest blocks.
+    fn chain_stats(&self, window: u32) -> ChainStats;
+}
+
+/// The state of the node reported by the readiness endpoint.
+#[async_trait::async_trait]
+pub trait HealthPort: Send + Sync {
+    /// Returns the height and the time of the latest block.
+    fn latest_block(&self) -> StorageResult<(BlockHeight, Tai64)>;
+
+    /// Checks that the database accepts the writes.
+    fn check_database_writable(&self) -> anyhow::Result<()>;
+
+    /// Returns the number of the connected peers, or `None` if the P2P is disabled.
+    async fn connected_peers(&self) -> anyhow::Result<Option<usize>>;
+
+    /// Returns the number of the finalized DA blocks not synced by the relayer yet,
+    /// or `None` if the relayer is disabled or hasn't reached the DA node yet.
+    fn relayer_lag(&self) -> Option<u64>;
 }
 
 /// Trait that specifies queries supported by the database.
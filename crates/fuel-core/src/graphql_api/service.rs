@@ -2,12 +2,28 @@
 use crate::graphql_api::metrics_extension::MetricsExtension;
 use crate::{
     fuel_core_graphql_api::ports::{
+        BlockImporterPort,
         BlockProducerPort,
+        ChainStatsPort,
         ConsensusModulePort,
         DatabasePort,
+        HealthPort,
+        P2pPort,
+        SyncPort,
         TxPoolPort,
     },
-    graphql_api::Config,
+    graphql_api::{
+        auth::{
+            bearer_token,
+            ApiTokens,
+        },
+        timeout_extension::TimeoutExtension,
+        tls::{
+            self,
+            CertificateResolver,
+        },
+        Config,
+    },
     schema::{
         CoreSchema,
         CoreSchemaBuilder,
@@ -21,6 +37,7 @@ use async_graphql::{
     },
     Request,
     Response,
+    ServerError,
 };
 use axum::{
     extract::{
@@ -33,7 +50,9 @@ use axum::{
             ACCESS_CONTROL_ALLOW_METHODS,
             ACCESS_CONTROL_ALLOW_ORIGIN,
         },
+        HeaderMap,
         HeaderValue,
+        StatusCode,
     },
     response::{
         sse::Event,
@@ -53,7 +72,12 @@ use fuel_core_services::{
     RunnableTask,
     StateWatcher,
 };
-use futures::Stream;
+use fuel_core_types::tai64::Tai64;
+use futures::{
+    future::Either,
+    FutureExt,
+    Stream,
+};
 use serde_json::json;
 use std::{
     future::Future,
@@ -62,10 +86,16 @@ use std::{
         TcpListener,
     },
     pin::Pin,
+    sync::Arc,
     time::Duration,
 };
 use tokio_stream::StreamExt;
 use tower_http::{
+    cors::{
+        AllowOrigin,
+        Any,
+        CorsLayer,
+    },
     set_header::SetResponseHeaderLayer,
     trace::TraceLayer,
 };
@@ -79,6 +109,11 @@ pub type BlockProducer = Box<dyn BlockProducerPort>;
 //  use only `Database` to receive all information about transactions.
 pub type TxPool = Box<dyn TxPoolPort>;
 pub type ConsensusModule = Box<dyn ConsensusModulePort>;
+pub type P2pService = Box<dyn P2pPort>;
+pub type SyncService = Arc<dyn SyncPort>;
+pub type BlockImporter = Box<dyn BlockImporterPort>;
+pub type HealthCheck = Arc<dyn HealthPort>;
+pub type ChainStatsSource = Box<dyn ChainStatsPort>;
 
 #[derive(Clone)]
 pub struct SharedState {
@@ -92,11 +127,12 @@ pub struct GraphqlService {
 pub struct ServerParams {
     router: Router,
     listener: TcpListener,
+    tls: Option<Arc<CertificateResolver>>,
 }
 
 pub struct Task {
     // Ugly workaround because of https://github.com/hyperium/hyper/issues/2582
-    server: Pin<Box<dyn Future<Output = hyper::Result<()>> + Send + 'static>>,
+    server: Pin<Box<dyn Future<Output = anyhow::Result<()>> + Send + 'static>>,
 }
 
 #[async_trait::async_trait]
@@ -119,21 +155,29 @@ impl RunnableService for GraphqlService {
         params: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
         let mut state = state.clone();
-        let ServerParams { router, listener } = params;
-
-        let server = axum::Server::from_tcp(listener)
-            .unwrap()
-            .serve(router.into_make_service())
-            .with_graceful_shutdown(async move {
-                state
-                    .while_started()
-                    .await
-                    .expect("The service is destroyed");
-            });
-
-        Ok(Task {
-            server: Box::pin(server),
-        })
+        let ServerParams {
+            router,
+            listener,
+            tls,
+        } = params;
+        let shutdown = async move {
+            state
+                .while_started()
+                .await
+                .expect("The service is destroyed");
+        };
+
+        let server = match tls {
+            Some(resolver) => tls::serve(listener, router, resolver, shutdown).boxed(),
+            None => axum::Server::from_tcp(listener)
+                .unwrap()
+                .serve(router.into_make_service())
+                .with_graceful_shutdown(shutdown)
+                .map(|result| result.map_err(Into::into))
+                .boxed(),
+        };
+
+        Ok(Task { server })
     }
 }
 
@@ -162,17 +206,38 @@ pub fn new_service(
     txpool: TxPool,
     producer: BlockProducer,
     consensus_module: ConsensusModule,
+    p2p_service: P2pService,
+    sync_service: SyncService,
+    block_importer: BlockImporter,
+    health_check: HealthCheck,
+    chain_stats: ChainStatsSource,
     _log_threshold_ms: Duration,
 ) -> anyhow::Result<Service> {
     let network_addr = config.addr;
+    let limits = config.limits.clone();
+    let api_tokens = config.api_tokens.clone();
+    let tls = config
+        .tls
+        .clone()
+        .map(CertificateResolver::new)
+        .transpose()?
+        .map(Arc::new);
+    let cors = cors_layer(&config.cors_allowed_origins)?;
 
     let builder = schema
+        .limit_depth(limits.max_depth)
+        .limit_complexity(limits.max_complexity)
         .data(config)
         .data(database)
         .data(txpool)
         .data(producer)
-        .data(consensus_module);
+        .data(consensus_module)
+        .data(p2p_service)
+        .data(sync_service.clone())
+        .data(block_importer)
+        .data(chain_stats);
     let builder = builder.extension(async_graphql::extensions::Tracing);
+    let builder = builder.extension(TimeoutExtension::new(limits.request_timeout));
 
     #[cfg(feature = "metrics")]
     let builder = builder.extension(MetricsExtension::new(_log_threshold_ms));
@@ -188,30 +253,68 @@ pub fn new_service(
         )
         .route("/metrics", get(metrics))
         .route("/health", get(health))
+        .route("/health/live", get(liveness))
+        .route("/health/ready", get(readiness))
         .layer(Extension(schema))
-        .layer(TraceLayer::new_for_http())
-        .layer(SetResponseHeaderLayer::<_>::overriding(
-            ACCESS_CONTROL_ALLOW_ORIGIN,
-            HeaderValue::from_static("*"),
-        ))
-        .layer(SetResponseHeaderLayer::<_>::overriding(
-            ACCESS_CONTROL_ALLOW_METHODS,
-            HeaderValue::from_static("*"),
-        ))
-        .layer(SetResponseHeaderLayer::<_>::overriding(
-            ACCESS_CONTROL_ALLOW_HEADERS,
-            HeaderValue::from_static("*"),
-        ))
-        .layer(DefaultBodyLimit::disable());
+        .layer(Extension(sync_service))
+        .layer(Extension(health_check))
+        .layer(Extension(api_tokens))
+        .layer(TraceLayer::new_for_http());
+    let router = match cors {
+        Some(cors) => router.layer(cors),
+        None => router
+            .layer(SetResponseHeaderLayer::<_>::overriding(
+                ACCESS_CONTROL_ALLOW_ORIGIN,
+                HeaderValue::from_static("*"),
+            ))
+            .layer(SetResponseHeaderLayer::<_>::overriding(
+                ACCESS_CONTROL_ALLOW_METHODS,
+                HeaderValue::from_static("*"),
+            ))
+            .layer(SetResponseHeaderLayer::<_>::overriding(
+                ACCESS_CONTROL_ALLOW_HEADERS,
+                HeaderValue::from_static("*"),
+            )),
+    };
+    let router = router.layer(DefaultBodyLimit::disable());
 
     let listener = TcpListener::bind(network_addr)?;
     let bound_address = listener.local_addr()?;
 
-    tracing::info!("Binding GraphQL provider to {}", bound_address);
+    tracing::info!(
+        "Binding GraphQL provider to {} over {}",
+        bound_address,
+        if tls.is_some() { "HTTPS" } else { "HTTP" }
+    );
 
     Ok(Service::new_with_params(
         GraphqlService { bound_address },
-        ServerParams { router, listener },
+        ServerParams {
+            router,
+            listener,
+            tls,
+        },
+    ))
+}
+
+/// Returns the layer allowing the cross-origin requests only from the `origins`,
+/// or `None` if all origins are allowed.
+fn cors_layer(origins: &[String]) -> anyhow::Result<Option<CorsLayer>> {
+    if origins.is_empty() {
+        return Ok(None)
+    }
+    let origins = origins
+        .iter()
+        .map(|origin| {
+            HeaderValue::from_str(origin)
+                .map_err(|_| anyhow::anyhow!("Invalid CORS origin `{}`", origin))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    Ok(Some(
+        CorsLayer::new()
+            .allow_origin(AllowOrigin::list(origins))
+            .allow_methods(Any)
+            .allow_headers(Any),
     ))
 }
 
@@ -219,24 +322,100 @@ async fn graphql_playground() -> impl IntoResponse {
     Html(playground_source(GraphQLPlaygroundConfig::new("/graphql")))
 }
 
-async fn health() -> Json<serde_json::Value> {
+async fn health(sync: Extension<SyncService>) -> Json<serde_json::Value> {
+    match sync.sync_status() {
+        Some(status) => Json(json!({ "up": true, "synced": status.is_synced() })),
+        None => Json(json!({ "up": true })),
+    }
+}
+
+async fn liveness() -> Json<serde_json::Value> {
     Json(json!({ "up": true }))
 }
 
+/// Responds with `503 Service Unavailable` unless the database accepts the writes and
+/// the node is synced with the network. The lags of the latest block and the relayer
+/// are only reported, so the probes can apply their own thresholds.
+async fn readiness(
+    sync: Extension<SyncService>,
+    health: Extension<HealthCheck>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    let sync_status = sync.sync_status();
+    let synced = sync_status
+        .as_ref()
+        .map_or(true, |status| status.is_synced());
+    let database = health.check_database_writable();
+    let ready = synced && database.is_ok();
+
+    let sync = sync_status.map(|status| {
+        json!({
+            "synced": status.is_synced(),
+            "current_height": *status.current_height,
+            "highest_seen_height": *status.highest_seen_height,
+            "lag": status.blocks_remaining(),
+        })
+    });
+    let latest_block = health.latest_block().ok().map(|(height, time)| {
+        json!({
+            "height": *height,
+            "time": time.to_unix(),
+            "age_seconds": Tai64::now().0.saturating_sub(time.0),
+        })
+    });
+    let database = match database {
+        Ok(()) => json!({ "writable": true }),
+        Err(err) => json!({ "writable": false, "error": err.to_string() }),
+    };
+    let connected_peers = health.connected_peers().await.ok().flatten();
+    let relayer = health.relayer_lag().map(|lag| json!({ "lag": lag }));
+
+    let status = if ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (
+        status,
+        Json(json!({
+            "ready": ready,
+            "sync": sync,
+            "connected_peers": connected_peers,
+            "latest_block": latest_block,
+            "database": database,
+            "relayer": relayer,
+        })),
+    )
+}
+
+fn invalid_token() -> Response {
+    Response::from_errors(vec![ServerError::new("The API token is invalid", None)])
+}
+
 async fn graphql_handler(
     schema: Extension<CoreSchema>,
+    api_tokens: Extension<ApiTokens>,
+    headers: HeaderMap,
     req: Json<Request>,
 ) -> Json<Response> {
-    schema.execute(req.0).await.into()
+    match api_tokens.role(bearer_token(&headers)) {
+        Some(role) => schema.execute(req.0.data(role)).await.into(),
+        None => invalid_token().into(),
+    }
 }
 
 async fn graphql_subscription_handler(
     schema: Extension<CoreSchema>,
+    api_tokens: Extension<ApiTokens>,
+    headers: HeaderMap,
     req: Json<Request>,
 ) -> Sse<impl Stream<Item = anyhow::Result<Event, serde_json::Error>>> {
-    let stream = schema
-        .execute_stream(req.0)
-        .map(|r| Ok(Event::default().json_data(r).unwrap()));
+    let stream = match api_tokens.role(bearer_token(&headers)) {
+        Some(role) => Either::Left(schema.execute_stream(req.0.data(role))),
+        None => Either::Right(futures::stream::once(futures::future::ready(
+            invalid_token(),
+        ))),
+    };
+    let stream = stream.map(|r| Ok(Event::default().json_data(r).unwrap()));
     Sse::new(stream)
         .keep_alive(axum::response::sse::KeepAlive::new().text("keep-alive-text"))
 }
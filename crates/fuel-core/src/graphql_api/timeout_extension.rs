@@ -0,0 +1,58 @@
+use async_graphql::{
+    extensions::{
+        Extension,
+        ExtensionContext,
+        ExtensionFactory,
+        NextRequest,
+    },
+    Response,
+    ServerError,
+};
+use std::{
+    sync::Arc,
+    time::Duration,
+};
+
+/// Aborts the execution of the queries and the mutations that take longer than the
+/// timeout. The subscriptions are long-living, so they aren't limited.
+pub(crate) struct TimeoutExtension {
+    timeout: Duration,
+}
+
+impl TimeoutExtension {
+    pub fn new(timeout: Duration) -> Self {
+        TimeoutExtension { timeout }
+    }
+}
+
+impl ExtensionFactory for TimeoutExtension {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(TimeoutExtInner {
+            timeout: self.timeout,
+        })
+    }
+}
+
+pub(crate) struct TimeoutExtInner {
+    timeout: Duration,
+}
+
+#[async_trait::async_trait]
+impl Extension for TimeoutExtInner {
+    async fn request(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        next: NextRequest<'_>,
+    ) -> Response {
+        match tokio::time::timeout(self.timeout, next.run(ctx)).await {
+            Ok(response) => response,
+            Err(_) => Response::from_errors(vec![ServerError::new(
+                format!(
+                    "The request exceeded the timeout of {} seconds",
+                    self.timeout.as_secs_f64()
+                ),
+                None,
+            )]),
+        }
+    }
+}
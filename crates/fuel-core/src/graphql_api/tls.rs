@@ -0,0 +1,285 @@
+//! The termination of the TLS connections to the API server.
+//!
+//! The certificate is loaded from the PEM files and the files are checked for the
+//! rotation periodically, so the renewed certificate is used by the new connections
+//! without restarting the node. The established connections keep the certificate
+//! of their handshake.
+
+use crate::graphql_api::TlsConfig;
+use anyhow::Context;
+use axum::Router;
+use futures::{
+    Future,
+    Stream,
+    StreamExt,
+};
+use parking_lot::RwLock;
+use std::{
+    io,
+    sync::Arc,
+    time::Duration,
+};
+use tokio::net::{
+    TcpListener,
+    TcpStream,
+};
+use tokio_rustls::{
+    rustls::{
+        server::{
+            ClientHello,
+            ResolvesServerCert,
+        },
+        sign::{
+            any_supported_type,
+            CertifiedKey,
+        },
+        Certificate,
+        PrivateKey,
+        ServerConfig,
+    },
+    server::TlsStream,
+    TlsAcceptor,
+};
+
+/// The maximum number of the concurrent TLS handshakes.
+const MAX_PENDING_HANDSHAKES: usize = 128;
+/// The time for the client to finish the TLS handshake.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+/// The delay before accepting the connections again after the failure,
+/// e.g. because of the exhausted file descriptors.
+const ACCEPT_ERROR_DELAY: Duration = Duration::from_millis(100);
+
+/// Provides the certificate to the TLS handshakes and reloads it after the rotation.
+pub(crate) struct CertificateResolver {
+    config: TlsConfig,
+    loaded: RwLock<LoadedCertificate>,
+}
+
+struct LoadedCertificate {
+    cert_pem: Vec<u8>,
+    key_pem: Vec<u8>,
+    key: Arc<CertifiedKey>,
+}
+
+impl CertificateResolver {
+    pub fn new(config: TlsConfig) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            !config.reload_interval.is_zero(),
+            "The reload interval of the TLS certificate should be positive"
+        );
+        let (cert_pem, key_pem) = read_pem_files(&config)?;
+        let key = Arc::new(parse_certified_key(&cert_pem, &key_pem)?);
+        Ok(Self {
+            config,
+            loaded: RwLock::new(LoadedCertificate {
+                cert_pem,
+                key_pem,
+                key,
+            }),
+        })
+    }
+
+    fn certified_key(&self) -> Arc<CertifiedKey> {
+        self.loaded.read().key.clone()
+    }
+
+    /// Reloads the certificate if its files were changed. Returns whether the
+    /// certificate was reloaded.
+    pub fn reload(&self) -> anyhow::Result<bool> {
+        let (cert_pem, key_pem) = read_pem_files(&self.config)?;
+        {
+            let loaded = self.loaded.read();
+            if loaded.cert_pem == cert_pem && loaded.key_pem == key_pem {
+                return Ok(false)
+            }
+        }
+        let key = Arc::new(parse_certified_key(&cert_pem, &key_pem)?);
+        *self.loaded.write() = LoadedCertificate {
+            cert_pem,
+            key_pem,
+            key,
+        };
+        Ok(true)
+    }
+}
+
+impl ResolvesServerCert for CertificateResolver {
+    fn resolve(&self, _: ClientHello) -> Option<Arc<CertifiedKey>> {
+        Some(self.certified_key())
+    }
+}
+
+fn read_pem_files(config: &TlsConfig) -> anyhow::Result<(Vec<u8>, Vec<u8>)> {
+    let cert_pem = std::fs::read(&config.cert_path).with_context(|| {
+        format!(
+            "Failed to read the TLS certificate from {}",
+            config.cert_path.display()
+        )
+    })?;
+    let key_pem = std::fs::read(&config.key_path).with_context(|| {
+        format!(
+            "Failed to read the TLS private key from {}",
+            config.key_path.display()
+        )
+    })?;
+    Ok((cert_pem, key_pem))
+}
+
+fn parse_certified_key(cert_pem: &[u8], key_pem: &[u8]) -> anyhow::Result<CertifiedKey> {
+    let certs: Vec<_> = rustls_pemfile::certs(&mut &cert_pem[..])
+        .context("Failed to parse the TLS certificate")?
+        .into_iter()
+        .map(Certificate)
+        .collect();
+    anyhow::ensure!(!certs.is_empty(), "The TLS certificate file is empty");
+
+    let key = rustls_pemfile::read_all(&mut &key_pem[..])
+        .context("Failed to parse the TLS private key")?
+        .into_iter()
+        .find_map(|item| match item {
+            rustls_pemfile::Item::RSAKey(key)
+            | rustls_pemfile::Item::PKCS8Key(key)
+            | rustls_pemfile::Item::ECKey(key) => Some(PrivateKey(key)),
+            _ => None,
+        })
+        .context("The TLS private key file doesn't contain a private key")?;
+    let key = any_supported_type(&key)
+        .map_err(|_| anyhow::anyhow!("The type of the TLS private key is unsupported"))?;
+
+    Ok(CertifiedKey::new(certs, key))
+}
+
+/// Returns the stream of the connections that finished the TLS handshake. The
+/// failed handshakes are dropped without interrupting the server.
+fn incoming(
+    listener: TcpListener,
+    acceptor: TlsAcceptor,
+) -> impl Stream<Item = io::Result<TlsStream<TcpStream>>> + Send {
+    futures::stream::unfold(listener, |listener| async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, _)) => return Some((stream, listener)),
+                Err(err) => {
+                    tracing::warn!("Failed to accept the connection: {}", err);
+                    tokio::time::sleep(ACCEPT_ERROR_DELAY).await;
+                }
+            }
+        }
+    })
+    .map(move |stream| {
+        let handshake = acceptor.accept(stream);
+        async move { tokio::time::timeout(HANDSHAKE_TIMEOUT, handshake).await }
+    })
+    .buffer_unordered(MAX_PENDING_HANDSHAKES)
+    .filter_map(|handshake| async move {
+        match handshake {
+            Ok(Ok(stream)) => Some(Ok(stream)),
+            Ok(Err(err)) => {
+                tracing::debug!("The TLS handshake failed: {}", err);
+                None
+            }
+            Err(_) => {
+                tracing::debug!("The TLS handshake timed out");
+                None
+            }
+        }
+    })
+}
+
+/// Serves the `router` over HTTPS until the `shutdown` is signaled, reloading the
+/// rotated certificate in the meantime.
+pub(crate) async fn serve(
+    listener: std::net::TcpListener,
+    router: Router,
+    resolver: Arc<CertificateResolver>,
+    shutdown: impl Future<Output = ()>,
+) -> anyhow::Result<()> {
+    listener.set_nonblocking(true)?;
+    let listener = TcpListener::from_std(listener)?;
+
+    let mut config = ServerConfig::builder()
+        .with_safe_defaults()
+        .with_no_client_auth()
+        .with_cert_resolver(resolver.clone());
+    config.alpn_protocols = vec![b"http/1.1".to_vec()];
+    let acceptor = TlsAcceptor::from(Arc::new(config));
+
+    let server = axum::Server::builder(hyper::server::accept::from_stream(incoming(
+        listener, acceptor,
+    )))
+    .serve(router.into_make_service())
+    .with_graceful_shutdown(shutdown);
+    tokio::pin!(server);
+
+    let mut reload = tokio::time::interval(resolver.config.reload_interval);
+    loop {
+        tokio::select! {
+            result = &mut server => return Ok(result?),
+            _ = reload.tick() => match resolver.reload() {
+                Ok(true) => tracing::info!("The rotated TLS certificate is reloaded"),
+                Ok(false) => {}
+                // The previous certificate is used until the files are fixed.
+                Err(err) => tracing::error!("Failed to reload the TLS certificate: {:?}", err),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_certificate(config: &TlsConfig) -> Vec<u8> {
+        let cert =
+            rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+        let cert_pem = cert.serialize_pem().unwrap();
+        std::fs::write(&config.cert_path, &cert_pem).unwrap();
+        std::fs::write(&config.key_path, cert.serialize_private_key_pem()).unwrap();
+        rustls_pemfile::certs(&mut cert_pem.as_bytes())
+            .unwrap()
+            .remove(0)
+    }
+
+    fn config(dir: &tempfile::TempDir) -> TlsConfig {
+        TlsConfig {
+            cert_path: dir.path().join("cert.pem"),
+            key_path: dir.path().join("key.pem"),
+            reload_interval: Duration::from_secs(60),
+        }
+    }
+
+    #[test]
+    fn rotated_certificate_is_reloaded() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = config(&dir);
+        let first = write_certificate(&config);
+        let resolver = CertificateResolver::new(config.clone()).unwrap();
+        assert_eq!(resolver.certified_key().cert[0].0, first);
+        assert!(!resolver.reload().unwrap());
+
+        let second = write_certificate(&config);
+        assert!(resolver.reload().unwrap());
+
+        assert_eq!(resolver.certified_key().cert[0].0, second);
+    }
+
+    #[test]
+    fn invalid_certificate_is_not_reloaded() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = config(&dir);
+        let first = write_certificate(&config);
+        let resolver = CertificateResolver::new(config.clone()).unwrap();
+
+        std::fs::write(&config.key_path, "not a key").unwrap();
+
+        assert!(resolver.reload().is_err());
+        assert_eq!(resolver.certified_key().cert[0].0, first);
+    }
+
+    #[test]
+    fn missing_certificate_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+
+        assert!(CertificateResolver::new(config(&dir)).is_err());
+    }
+}
@@ -3,6 +3,8 @@
 
 #[doc(no_inline)]
 pub use fuel_core_chain_config as chain_config;
+#[doc(no_inline)]
+pub use fuel_core_importer as importer;
 #[cfg(feature = "p2p")]
 #[doc(no_inline)]
 pub use fuel_core_p2p as p2p;
@@ -1,7 +1,10 @@
 //! # Helpers for creating networks of nodes
 
 use crate::{
-    chain_config::ChainConfig,
+    chain_config::{
+        ChainConfig,
+        ConsensusConfig,
+    },
     database::Database,
     p2p::Multiaddr,
     service::{
@@ -39,6 +42,7 @@ use fuel_core_types::{
     },
     fuel_types::{
         Address,
+        BlockHeight,
         Bytes32,
         ChainId,
     },
@@ -80,6 +84,8 @@ pub struct ValidatorSetup {
     pub name: String,
     /// Public key of the producer to sync from.
     pub pub_key: Address,
+    /// Sync only the headers of the blocks.
+    pub light_client: bool,
 }
 
 #[derive(Clone)]
@@ -229,11 +235,9 @@ pub async fn make_nodes(
                         chain_config.clone(),
                     );
                     if let Some(BootstrapSetup { pub_key, .. }) = boot {
-                        match &mut node_config.chain_conf.consensus {
-                            crate::chain_config::ConsensusConfig::PoA { signing_key } => {
-                                *signing_key = pub_key;
-                            }
-                        }
+                        node_config.chain_conf.consensus = ConsensusConfig::PoA {
+                            signing_key: pub_key,
+                        };
                     }
                     Bootstrap::new(&node_config).await
                 }
@@ -260,11 +264,9 @@ pub async fn make_nodes(
 
         if let Some((ProducerSetup { secret, .. }, txs)) = s {
             let pub_key = secret.public_key();
-            match &mut node_config.chain_conf.consensus {
-                crate::chain_config::ConsensusConfig::PoA { signing_key } => {
-                    *signing_key = Input::owner(&pub_key);
-                }
-            }
+            node_config.chain_conf.consensus = ConsensusConfig::PoA {
+                signing_key: Input::owner(&pub_key),
+            };
 
             node_config.consensus_key = Some(Secret::new(secret.into()));
 
@@ -288,12 +290,16 @@ pub async fn make_nodes(
         node_config.block_production = Trigger::Never;
         node_config.p2p.as_mut().unwrap().bootstrap_nodes = boots.clone();
 
-        if let Some(ValidatorSetup { pub_key, .. }) = s {
-            match &mut node_config.chain_conf.consensus {
-                crate::chain_config::ConsensusConfig::PoA { signing_key } => {
-                    *signing_key = pub_key;
-                }
-            }
+        if let Some(ValidatorSetup {
+            pub_key,
+            light_client,
+            ..
+        }) = s
+        {
+            node_config.chain_conf.consensus = ConsensusConfig::PoA {
+                signing_key: pub_key,
+            };
+            node_config.light_client = light_client;
         }
         validators.push(make_node(node_config, Vec::with_capacity(0)).await)
     }
@@ -396,6 +402,26 @@ impl Node {
             });
     }
 
+    /// Wait for the node to commit the block at the `height` within 10 seconds.
+    pub async fn wait_for_height_10s(&self, height: BlockHeight) {
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while self
+                .db
+                .latest_height()
+                .map_or(true, |latest| latest < height)
+            {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+        })
+        .await
+        .unwrap_or_else(|_| {
+            panic!(
+                "Failed to reach the height {height} for {:?}",
+                self.config.name
+            )
+        });
+    }
+
     /// Insert the test transactions into the node's transaction pool.
     pub async fn insert_txs(&self) -> HashMap<Bytes32, Transaction> {
         let mut expected = HashMap::new();
@@ -476,6 +502,7 @@ impl ValidatorSetup {
         Self {
             pub_key,
             name: Default::default(),
+            light_client: false,
         }
     }
 
@@ -485,6 +512,13 @@ impl ValidatorSetup {
             ..self
         }
     }
+
+    pub fn with_light_client(self) -> Self {
+        Self {
+            light_client: true,
+            ..self
+        }
+    }
 }
 impl BootstrapSetup {
     pub fn new(pub_key: Address) -> Self {
@@ -9,6 +9,7 @@ use fuel_core_storage::{
         ContractsAssets,
         ContractsInfo,
         ContractsRawCode,
+        ContractsState,
     },
     Result as StorageResult,
     StorageAsRef,
@@ -16,6 +17,7 @@ use fuel_core_storage::{
 use fuel_core_types::{
     fuel_types::{
         AssetId,
+        Bytes32,
         ContractId,
     },
     fuel_vm::Salt,
@@ -41,6 +43,19 @@ pub trait ContractQueryData: Send + Sync {
         start_asset: Option<AssetId>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<ContractBalance>>;
+
+    fn contract_slots(
+        &self,
+        contract_id: ContractId,
+        start_key: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(Bytes32, Bytes32)>>;
+
+    fn contract_state(
+        &self,
+        contract_id: ContractId,
+        key: Bytes32,
+    ) -> StorageResult<Bytes32>;
 }
 
 impl<D: DatabasePort + ?Sized> ContractQueryData for D {
@@ -99,4 +114,27 @@ impl<D: DatabasePort + ?Sized> ContractQueryData for D {
     ) -> BoxedIter<StorageResult<ContractBalance>> {
         self.contract_balances(contract_id, start_asset, direction)
     }
+
+    fn contract_slots(
+        &self,
+        contract_id: ContractId,
+        start_key: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(Bytes32, Bytes32)>> {
+        self.contract_slots(contract_id, start_key, direction)
+    }
+
+    fn contract_state(
+        &self,
+        contract_id: ContractId,
+        key: Bytes32,
+    ) -> StorageResult<Bytes32> {
+        let value = self
+            .storage::<ContractsState>()
+            .get(&(&contract_id, &key).into())?
+            .ok_or(not_found!(ContractsState))?
+            .into_owned();
+
+        Ok(value)
+    }
 }
@@ -27,6 +27,7 @@ use fuel_core_storage::{
 use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
+        header::BlockHeader,
         primitives::BlockId,
     },
     entities::message::{
@@ -48,7 +49,13 @@ use fuel_core_types::{
     services::txpool::TransactionStatus,
 };
 use itertools::Itertools;
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    collections::{
+        hash_map::Entry,
+        HashMap,
+    },
+};
 
 #[cfg(test)]
 mod test;
@@ -141,6 +148,51 @@ pub fn message_proof<T: MessageProofData + ?Sized>(
     transaction_id: Bytes32,
     message_id: MessageId,
     commit_block_id: BlockId,
+) -> StorageResult<Option<MessageProof>> {
+    let proofs =
+        message_proofs(database, &[(transaction_id, message_id)], commit_block_id)?;
+    Ok(proofs.into_iter().next().flatten())
+}
+
+/// Generate the output proofs of the `messages` against the same commit block.
+/// The proofs are returned in the order of the `messages`, the proof is `None`
+/// if it can't be proven. The receipts of every message block are fetched once,
+/// so the messages from the same block share the work.
+pub fn message_proofs<T: MessageProofData + ?Sized>(
+    database: &T,
+    messages: &[(Bytes32, MessageId)],
+    commit_block_id: BlockId,
+) -> StorageResult<Vec<Option<MessageProof>>> {
+    // Get the commit fuel block header.
+    let commit_block_header = match database
+        .block(&commit_block_id)
+        .into_api_result::<CompressedBlock, StorageError>()?
+    {
+        Some(t) => t.into_inner().0,
+        None => return Ok(messages.iter().map(|_| None).collect()),
+    };
+
+    let mut block_message_ids = HashMap::new();
+    messages
+        .iter()
+        .map(|(transaction_id, message_id)| {
+            prove_message(
+                database,
+                *transaction_id,
+                *message_id,
+                &commit_block_header,
+                &mut block_message_ids,
+            )
+        })
+        .collect()
+}
+
+fn prove_message<T: MessageProofData + ?Sized>(
+    database: &T,
+    transaction_id: Bytes32,
+    message_id: MessageId,
+    commit_block_header: &BlockHeader,
+    block_message_ids: &mut HashMap<BlockId, Vec<MessageId>>,
 ) -> StorageResult<Option<MessageProof>> {
     // Check if the receipts for this transaction actually contain this message id or exit.
     let receipt = database
@@ -185,18 +237,12 @@ pub fn message_proof<T: MessageProofData + ?Sized>(
         None => return Ok(None),
     };
 
-    let message_proof =
-        match message_receipts_proof(database, message_id, &message_block_txs)? {
-            Some(proof) => proof,
-            None => return Ok(None),
-        };
-
-    // Get the commit fuel block header.
-    let commit_block_header = match database
-        .block(&commit_block_id)
-        .into_api_result::<CompressedBlock, StorageError>()?
-    {
-        Some(t) => t.into_inner().0,
+    let message_ids = match block_message_ids.entry(message_block_id) {
+        Entry::Occupied(entry) => entry.into_mut(),
+        Entry::Vacant(entry) => entry.insert(message_ids(database, &message_block_txs)?),
+    };
+    let message_proof = match message_receipts_proof(message_ids, message_id) {
+        Some(proof) => proof,
         None => return Ok(None),
     };
 
@@ -210,7 +256,7 @@ pub fn message_proof<T: MessageProofData + ?Sized>(
         message_proof,
         block_proof,
         message_block_header,
-        commit_block_header,
+        commit_block_header: commit_block_header.clone(),
         sender,
         recipient,
         nonce,
@@ -219,31 +265,38 @@ pub fn message_proof<T: MessageProofData + ?Sized>(
     }))
 }
 
-fn message_receipts_proof<T: MessageProofData + ?Sized>(
+/// Returns the ids of the output messages of the block in the order of the receipts.
+fn message_ids<T: MessageProofData + ?Sized>(
     database: &T,
-    message_id: MessageId,
     message_block_txs: &[Bytes32],
-) -> StorageResult<Option<MerkleProof>> {
+) -> StorageResult<Vec<MessageId>> {
     // Get the message receipts from the block.
     let leaves: Vec<Vec<Receipt>> = message_block_txs
         .iter()
         .map(|id| database.receipts(id))
         .filter_map(|result| result.into_api_result::<_, StorageError>().transpose())
         .try_collect()?;
-    let leaves = leaves.into_iter()
+    let message_ids = leaves.into_iter()
         // Flatten the receipts after filtering on output messages
         // and mapping to message ids.
         .flat_map(|receipts|
-            receipts.into_iter().filter_map(|r| r.message_id()));
+            receipts.into_iter().filter_map(|r| r.message_id()))
+        .collect();
+    Ok(message_ids)
+}
 
-    // Build the merkle proof from the above iterator.
+fn message_receipts_proof(
+    message_ids: &[MessageId],
+    message_id: MessageId,
+) -> Option<MerkleProof> {
+    // Build the merkle proof from the message ids of the block.
     let mut tree = MerkleTree::new();
 
     let mut proof_index = None;
 
-    for (index, id) in leaves.enumerate() {
+    for (index, id) in message_ids.iter().enumerate() {
         // Check if this is the message id being proved.
-        if message_id == id {
+        if message_id == *id {
             // Save the index of this message to use as the proof index.
             proof_index = Some(index as u64);
         }
@@ -253,17 +306,11 @@ fn message_receipts_proof<T: MessageProofData + ?Sized>(
     }
 
     // If we found the leaf proof index then return the proof.
-    match proof_index {
-        Some(proof_index) => {
-            // Generate the actual merkle proof.
-            match tree.prove(proof_index) {
-                Some((_, proof_set)) => Ok(Some(MerkleProof {
-                    proof_set,
-                    proof_index,
-                })),
-                None => Ok(None),
-            }
-        }
-        None => Ok(None),
-    }
+    let proof_index = proof_index?;
+    // Generate the actual merkle proof.
+    let (_, proof_set) = tree.prove(proof_index)?;
+    Some(MerkleProof {
+        proof_set,
+        proof_index,
+    })
 }
@@ -1,3 +1,10 @@
+use crate::{
+    fuel_core_graphql_api::{
+        service::Database,
+        Config as GraphQLConfig,
+    },
+    schema::scalars::U32,
+};
 use anyhow::anyhow;
 use async_graphql::{
     connection::{
@@ -7,6 +14,7 @@ use async_graphql::{
         Edge,
         EmptyFields,
     },
+    Context,
     MergedObject,
     MergedSubscription,
     OutputType,
@@ -18,7 +26,9 @@ use fuel_core_storage::{
     Result as StorageResult,
 };
 use itertools::Itertools;
+use std::ops::Deref;
 
+pub mod asset;
 pub mod balance;
 pub mod block;
 pub mod chain;
@@ -26,11 +36,15 @@ pub mod coins;
 pub mod contract;
 #[cfg(feature = "dap")]
 pub mod dap;
+pub mod gas_price;
 pub mod health;
 pub mod message;
 pub mod node_info;
 pub mod scalars;
+pub mod stats;
+pub mod sync;
 pub mod tx;
+pub mod txpool;
 
 #[cfg(feature = "dap")]
 #[derive(MergedObject, Default)]
@@ -46,6 +60,13 @@ pub struct Query(
     contract::ContractBalanceQuery,
     node_info::NodeQuery,
     message::MessageQuery,
+    txpool::TxPoolQuery,
+    sync::SyncQuery,
+    gas_price::LatestGasPriceQuery,
+    gas_price::EstimateGasPriceQuery,
+    gas_price::EstimateFeesQuery,
+    asset::AssetQuery,
+    stats::ChainStatsQuery,
 );
 
 #[cfg(not(feature = "dap"))]
@@ -61,18 +82,38 @@ pub struct Query(
     contract::ContractBalanceQuery,
     node_info::NodeQuery,
     message::MessageQuery,
+    txpool::TxPoolQuery,
+    sync::SyncQuery,
+    gas_price::LatestGasPriceQuery,
+    gas_price::EstimateGasPriceQuery,
+    gas_price::EstimateFeesQuery,
+    asset::AssetQuery,
+    stats::ChainStatsQuery,
 );
 
 #[cfg(feature = "dap")]
 #[derive(MergedObject, Default)]
-pub struct Mutation(dap::DapMutation, tx::TxMutation, block::BlockMutation);
+pub struct Mutation(
+    dap::DapMutation,
+    tx::TxMutation,
+    block::BlockMutation,
+    node_info::NodeMutation,
+);
 
 #[cfg(not(feature = "dap"))]
 #[derive(MergedObject, Default)]
-pub struct Mutation(tx::TxMutation, block::BlockMutation);
+pub struct Mutation(
+    tx::TxMutation,
+    block::BlockMutation,
+    node_info::NodeMutation,
+);
 
 #[derive(MergedSubscription, Default)]
-pub struct Subscription(tx::TxStatusSubscription);
+pub struct Subscription(
+    tx::TxStatusSubscription,
+    block::BlockSubscription,
+    coins::CoinSubscription,
+);
 
 pub type CoreSchema = Schema<Query, Mutation, Subscription>;
 pub type CoreSchemaBuilder = SchemaBuilder<Query, Mutation, Subscription>;
@@ -86,6 +127,51 @@ pub fn build_schema() -> CoreSchemaBuilder {
     )
 }
 
+/// The database at the block height requested by the query.
+pub(crate) enum DatabaseAtHeight<'a> {
+    Latest(&'a Database),
+    Historical(Database),
+}
+
+impl Deref for DatabaseAtHeight<'_> {
+    type Target = Database;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            DatabaseAtHeight::Latest(database) => database,
+            DatabaseAtHeight::Historical(database) => database,
+        }
+    }
+}
+
+/// Returns the state of the database at the `block_height`, or the latest state
+/// if the height is not specified.
+pub(crate) fn database_at_height<'a>(
+    ctx: &Context<'a>,
+    block_height: Option<U32>,
+) -> async_graphql::Result<DatabaseAtHeight<'a>> {
+    let database: &Database = ctx.data_unchecked();
+    let config = ctx.data_unchecked::<GraphQLConfig>();
+    match block_height {
+        Some(height) => Ok(DatabaseAtHeight::Historical(
+            database.view_at(&height.0.into(), config.limits.max_historical_depth)?,
+        )),
+        None => Ok(DatabaseAtHeight::Latest(database)),
+    }
+}
+
+/// Returns the complexity of the connection field. The page of the connection has
+/// up to `first` or `last` nodes, so the complexity of the selected fields is scaled
+/// by the size of the page.
+pub(crate) fn connection_complexity(
+    child_complexity: usize,
+    first: Option<i32>,
+    last: Option<i32>,
+) -> usize {
+    let page_size = first.or(last).unwrap_or_default().max(0) as usize;
+    child_complexity.saturating_mul(page_size).saturating_add(1)
+}
+
 async fn query_pagination<F, Entries, SchemaKey, SchemaValue>(
     after: Option<String>,
     before: Option<String>,
@@ -0,0 +1,126 @@
+use crate::{
+    fuel_core_graphql_api::{
+        ports::DatabaseAssets,
+        service::Database,
+    },
+    schema::scalars::{
+        Address,
+        AssetId,
+        HolderCursor,
+        U64,
+    },
+};
+use anyhow::anyhow;
+use async_graphql::{
+    connection::{
+        Connection,
+        EmptyFields,
+    },
+    Context,
+    Object,
+};
+use fuel_core_types::services::graphql_api;
+
+pub struct AssetSupply(graphql_api::AssetSupply);
+
+#[Object]
+impl AssetSupply {
+    /// The amount minted by the contracts.
+    async fn minted(&self) -> U64 {
+        self.0.minted.into()
+    }
+
+    /// The amount burned by the contracts.
+    async fn burned(&self) -> U64 {
+        self.0.burned.into()
+    }
+
+    /// The amount in circulation.
+    async fn total(&self) -> U64 {
+        self.0.total().into()
+    }
+
+    /// The number of the addresses holding the coins of the asset.
+    async fn holders(&self) -> U64 {
+        self.0.holders.into()
+    }
+}
+
+/// The address holding the coins of the asset.
+pub struct AssetHolder(graphql_api::AddressBalance);
+
+#[Object]
+impl AssetHolder {
+    async fn owner(&self) -> Address {
+        self.0.owner.into()
+    }
+
+    async fn amount(&self) -> U64 {
+        self.0.amount.into()
+    }
+}
+
+#[derive(Default)]
+pub struct AssetQuery;
+
+#[Object]
+impl AssetQuery {
+    /// Returns the supply of the asset. Only available if the node indexes the assets.
+    async fn asset_supply(
+        &self,
+        ctx: &Context<'_>,
+        asset_id: AssetId,
+    ) -> async_graphql::Result<Option<AssetSupply>> {
+        let query: &Database = ctx.data_unchecked();
+        Ok(query.asset_supply(&asset_id.0)?.map(AssetSupply))
+    }
+
+    /// Returns the addresses holding the coins of the asset from the largest balance.
+    /// Only available if the node indexes the assets.
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(child_complexity, first, last)"
+    )]
+    async fn asset_holders(
+        &self,
+        ctx: &Context<'_>,
+        asset_id: AssetId,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<HolderCursor, AssetHolder, EmptyFields, EmptyFields>,
+    > {
+        // Rocksdb doesn't support reverse iteration over a prefix
+        if matches!(last, Some(last) if last > 0) {
+            return Err(
+                anyhow!("reverse pagination isn't supported for this resource").into(),
+            )
+        }
+
+        let query: &Database = ctx.data_unchecked();
+        crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<HolderCursor>, direction| {
+                let start = start.map(|cursor| (cursor.amount, cursor.owner.0));
+                let holders =
+                    query
+                        .asset_holders(asset_id.0, start, direction)
+                        .map(|result| {
+                            result.map(|holder| {
+                                let cursor = HolderCursor {
+                                    amount: holder.amount,
+                                    owner: holder.owner.into(),
+                                };
+                                (cursor, AssetHolder(holder))
+                            })
+                        });
+                Ok(holders)
+            },
+        )
+        .await
+    }
+}
@@ -1,10 +1,14 @@
 use crate::{
     fuel_core_graphql_api::service::Database,
     query::BalanceQueryData,
-    schema::scalars::{
-        Address,
-        AssetId,
-        U64,
+    schema::{
+        database_at_height,
+        scalars::{
+            Address,
+            AssetId,
+            U32,
+            U64,
+        },
     },
 };
 use anyhow::anyhow;
@@ -52,8 +56,10 @@ impl BalanceQuery {
         ctx: &Context<'_>,
         #[graphql(desc = "address of the owner")] owner: Address,
         #[graphql(desc = "asset_id of the coin")] asset_id: AssetId,
+        #[graphql(desc = "The height of the block to query the balance at")]
+        block_height: Option<U32>,
     ) -> async_graphql::Result<Balance> {
-        let data: &Database = ctx.data_unchecked();
+        let data = database_at_height(ctx, block_height)?;
         let balance = data.balance(owner.0, asset_id.0)?.into();
         Ok(balance)
     }
@@ -62,6 +68,9 @@ impl BalanceQuery {
     //  It should be replaced with `UtxoId`.
     //  This API should be migrated to the indexer for better support and
     //  discontinued within fuel-core.
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(child_complexity, first, last)"
+    )]
     async fn balances(
         &self,
         ctx: &Context<'_>,
@@ -4,7 +4,13 @@ use super::scalars::{
 };
 use crate::{
     fuel_core_graphql_api::{
+        auth::{
+            ApiRole,
+            RoleGuard,
+        },
         service::{
+            BlockImporter,
+            BlockProducer,
             ConsensusModule,
             Database,
         },
@@ -17,9 +23,14 @@ use crate::{
         SimpleTransactionData,
     },
     schema::{
+        coins::Coin,
         scalars::{
+            Address,
+            AssetId,
             BlockId,
+            ContractId,
             Signature,
+            TransactionId,
             U32,
             U64,
         },
@@ -35,6 +46,7 @@ use async_graphql::{
     Context,
     Object,
     SimpleObject,
+    Subscription,
     Union,
 };
 use fuel_core_storage::{
@@ -50,8 +62,17 @@ use fuel_core_types::{
         block::CompressedBlock,
         header::BlockHeader,
     },
+    fuel_tx::UniqueIdentifier,
     fuel_types,
     fuel_types::BlockHeight,
+    services::{
+        block_importer,
+        block_producer::BlockDryRun,
+    },
+};
+use futures::{
+    Stream,
+    StreamExt,
 };
 
 pub struct Block(pub(crate) CompressedBlock);
@@ -117,6 +138,98 @@ impl Block {
             })
             .collect()
     }
+
+    /// The changes of the state made by the block.
+    /// Only available if the node computes the state diffs of the imported blocks.
+    async fn state_diff(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<StateDiff>> {
+        let query: &Database = ctx.data_unchecked();
+        Ok(query.state_diff(self.0.header().height())?.map(StateDiff))
+    }
+}
+
+pub struct StateDiff(block_importer::StateDiff);
+
+#[Object]
+impl StateDiff {
+    /// The coins created by the block.
+    async fn created_coins(&self) -> Vec<Coin> {
+        self.0
+            .created_coins
+            .iter()
+            .cloned()
+            .map(Into::into)
+            .collect()
+    }
+
+    /// The coins spent by the block.
+    async fn spent_coins(&self) -> Vec<Coin> {
+        self.0.spent_coins.iter().cloned().map(Into::into).collect()
+    }
+
+    /// The writes to the storage slots of the contracts.
+    async fn contract_storage_writes(&self) -> Vec<ContractStorageWrite> {
+        self.0
+            .contract_storage_writes
+            .iter()
+            .map(|write| ContractStorageWrite {
+                contract_id: write.contract_id.into(),
+                key: write.key.into(),
+                value: write.value.map(Into::into),
+            })
+            .collect()
+    }
+
+    /// The changes of the balances of the addresses and of the contracts.
+    async fn balance_deltas(&self) -> Vec<BalanceDelta> {
+        self.0
+            .balance_deltas
+            .iter()
+            .map(|delta| {
+                let (address, contract_id) = match delta.owner {
+                    block_importer::BalanceOwner::Address(address) => {
+                        (Some(address.into()), None)
+                    }
+                    block_importer::BalanceOwner::Contract(contract_id) => {
+                        (None, Some(contract_id.into()))
+                    }
+                };
+                BalanceDelta {
+                    address,
+                    contract_id,
+                    asset_id: delta.asset_id.into(),
+                    credit: delta.credit.into(),
+                    debit: delta.debit.into(),
+                }
+            })
+            .collect()
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct ContractStorageWrite {
+    /// The contract owning the storage.
+    contract_id: ContractId,
+    /// The key of the storage slot.
+    key: Bytes32,
+    /// The new value of the storage slot, `null` if the slot was cleared.
+    value: Option<Bytes32>,
+}
+
+#[derive(SimpleObject)]
+pub struct BalanceDelta {
+    /// The address owning the balance, `null` for the balance of the contract.
+    address: Option<Address>,
+    /// The contract owning the balance, `null` for the balance of the address.
+    contract_id: Option<ContractId>,
+    /// The asset of the balance.
+    asset_id: AssetId,
+    /// The amount added to the balance.
+    credit: U64,
+    /// The amount removed from the balance.
+    debit: U64,
 }
 
 #[Object]
@@ -173,6 +286,56 @@ impl Header {
     }
 }
 
+/// The next block assembled from the transactions of the pool without committing
+/// or signing it.
+pub struct DryRunBlock {
+    dry_run: BlockDryRun,
+    chain_id: fuel_types::ChainId,
+}
+
+#[Object]
+impl DryRunBlock {
+    /// The header of the block that would be produced.
+    async fn header(&self) -> Header {
+        self.dry_run.block.header().clone().into()
+    }
+
+    /// The transactions of the block that would be produced.
+    async fn transactions(&self) -> Vec<Transaction> {
+        self.dry_run
+            .block
+            .transactions()
+            .iter()
+            .map(|tx| Transaction::from_tx(tx.id(&self.chain_id), tx.clone()))
+            .collect()
+    }
+
+    /// The gas used by the transactions of the block.
+    async fn gas_used(&self) -> U64 {
+        self.dry_run.gas_used.into()
+    }
+
+    /// The transactions of the pool that would be left out of the block.
+    async fn excluded_transactions(&self) -> Vec<ExcludedTransaction> {
+        self.dry_run
+            .excluded_transactions
+            .iter()
+            .map(|(id, reason)| ExcludedTransaction {
+                id: (*id).into(),
+                reason: reason.clone(),
+            })
+            .collect()
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct ExcludedTransaction {
+    /// The id of the transaction.
+    id: TransactionId,
+    /// Why the transaction is not included into the block.
+    reason: String,
+}
+
 #[Object]
 impl PoAConsensus {
     /// Gets the signature of the block produced by `PoA` consensus.
@@ -213,6 +376,9 @@ impl BlockQuery {
         id.and_then(|id| data.block(&id)).into_api_result()
     }
 
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(child_complexity, first, last)"
+    )]
     async fn blocks(
         &self,
         ctx: &Context<'_>,
@@ -227,6 +393,23 @@ impl BlockQuery {
         })
         .await
     }
+
+    /// Assembles the next block from the transactions of the pool without committing
+    /// or signing it, and reports the transactions of the pool left out of it.
+    #[graphql(guard = "RoleGuard::new(ApiRole::SubmitTx)")]
+    async fn dry_run_block(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<DryRunBlock> {
+        let config = ensure_production_admin(ctx)?;
+        let block_producer = ctx.data_unchecked::<BlockProducer>();
+
+        let dry_run = block_producer.dry_run_block(config.block_gas_limit).await?;
+        Ok(DryRunBlock {
+            dry_run,
+            chain_id: config.consensus_parameters.chain_id,
+        })
+    }
 }
 
 #[derive(Default)]
@@ -246,6 +429,9 @@ impl HeaderQuery {
             .map(|b| b.0.header().clone().into()))
     }
 
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(child_complexity, first, last)"
+    )]
     async fn headers(
         &self,
         ctx: &Context<'_>,
@@ -287,6 +473,7 @@ impl BlockMutation {
     /// `start_timestamp`. If the block production in the [`crate::service::Config`] is
     /// `Trigger::Interval { block_time }`, produces blocks with `block_time ` intervals between
     /// them. The `start_timestamp` is the timestamp in seconds.
+    #[graphql(guard = "RoleGuard::new(ApiRole::Admin)")]
     async fn produce_blocks(
         &self,
         ctx: &Context<'_>,
@@ -314,6 +501,66 @@ impl BlockMutation {
             .map(Into::into)
             .map_err(Into::into)
     }
+
+    /// Pauses the block production after the block in progress. The transactions
+    /// are still accepted into the pool while the production is paused.
+    #[graphql(guard = "RoleGuard::new(ApiRole::Admin)")]
+    async fn pause_block_production(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<bool> {
+        admin_consensus_module(ctx)?
+            .pause_block_production()
+            .await?;
+        Ok(true)
+    }
+
+    /// Resumes the paused block production.
+    #[graphql(guard = "RoleGuard::new(ApiRole::Admin)")]
+    async fn resume_block_production(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<bool> {
+        admin_consensus_module(ctx)?
+            .resume_block_production()
+            .await?;
+        Ok(true)
+    }
+}
+
+#[derive(Default)]
+pub struct BlockSubscription;
+
+#[Subscription]
+impl BlockSubscription {
+    /// Returns a stream of the headers of the blocks imported after the subscription.
+    ///
+    /// The headers are skipped if the stream is polled slower than the blocks
+    /// are imported.
+    async fn new_blocks<'a>(
+        &self,
+        ctx: &Context<'a>,
+    ) -> impl Stream<Item = async_graphql::Result<Header>> + 'a {
+        let block_importer = ctx.data_unchecked::<BlockImporter>();
+        block_importer
+            .block_headers()
+            .map(|header| Ok(Header(header)))
+    }
+}
+
+fn admin_consensus_module<'a>(ctx: &Context<'a>) -> anyhow::Result<&'a ConsensusModule> {
+    ensure_production_admin(ctx)?;
+    Ok(ctx.data_unchecked::<ConsensusModule>())
+}
+
+fn ensure_production_admin<'a>(ctx: &Context<'a>) -> anyhow::Result<&'a GraphQLConfig> {
+    let config = ctx.data_unchecked::<GraphQLConfig>();
+    if !config.production_admin_enabled {
+        return Err(anyhow!(
+            "Production administration must be enabled to use this endpoint"
+        ))
+    }
+    Ok(config)
 }
 
 impl From<CompressedBlock> for Block {
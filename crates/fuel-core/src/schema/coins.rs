@@ -7,18 +7,24 @@ use crate::{
         Config as GraphQLConfig,
         IntoApiResult,
     },
-    graphql_api::service::Database,
+    graphql_api::service::{
+        BlockImporter,
+        Database,
+    },
     query::{
         asset_query::AssetSpendTarget,
         CoinQueryData,
     },
-    schema::scalars::{
-        Address,
-        AssetId,
-        Nonce,
-        UtxoId,
-        U32,
-        U64,
+    schema::{
+        database_at_height,
+        scalars::{
+            Address,
+            AssetId,
+            Nonce,
+            UtxoId,
+            U32,
+            U64,
+        },
     },
 };
 use anyhow::anyhow;
@@ -28,6 +34,8 @@ use async_graphql::{
         EmptyFields,
     },
     Context,
+    Enum,
+    Subscription,
 };
 use fuel_core_types::{
     entities::{
@@ -38,6 +46,14 @@ use fuel_core_types::{
         },
     },
     fuel_tx,
+    services::block_importer::{
+        CoinEvent as CoinEventModel,
+        CoinEventKind as CoinEventKindModel,
+    },
+};
+use futures::{
+    Stream,
+    StreamExt,
 };
 use itertools::Itertools;
 
@@ -114,6 +130,49 @@ pub enum CoinType {
     MessageCoin(MessageCoin),
 }
 
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum CoinEventKind {
+    /// The coin is created by the imported block.
+    Created,
+    /// The coin is spent by the imported block.
+    Spent,
+    /// The coin created by the reverted block doesn't exist anymore.
+    CreationReverted,
+    /// The coin spent by the reverted block is unspent again.
+    SpendReverted,
+}
+
+/// The change of the coin made by the imported or the reverted block.
+pub struct CoinEvent(pub(crate) CoinEventModel);
+
+#[async_graphql::Object]
+impl CoinEvent {
+    async fn kind(&self) -> CoinEventKind {
+        self.0.kind.into()
+    }
+
+    /// The height of the block that made the change.
+    async fn block_height(&self) -> U32 {
+        self.0.block_height.into()
+    }
+
+    async fn utxo_id(&self) -> UtxoId {
+        self.0.utxo_id.into()
+    }
+
+    async fn owner(&self) -> Address {
+        self.0.owner.into()
+    }
+
+    async fn asset_id(&self) -> AssetId {
+        self.0.asset_id.into()
+    }
+
+    async fn amount(&self) -> U64 {
+        self.0.amount.into()
+    }
+}
+
 #[derive(async_graphql::InputObject)]
 struct CoinFilterInput {
     /// Returns coins owned by the `owner`.
@@ -156,6 +215,9 @@ impl CoinQuery {
     }
 
     /// Gets all unspent coins of some `owner` maybe filtered with by `asset_id` per page.
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(child_complexity, first, last)"
+    )]
     async fn coins(
         &self,
         ctx: &Context<'_>,
@@ -164,13 +226,15 @@ impl CoinQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
+        #[graphql(desc = "The height of the block to query the coins at")]
+        block_height: Option<U32>,
     ) -> async_graphql::Result<Connection<UtxoId, Coin, EmptyFields, EmptyFields>> {
         // Rocksdb doesn't support reverse iteration over a prefix
         if matches!(last, Some(last) if last > 0) {
             return Err(anyhow!("reverse pagination isn't supported for this coins").into())
         }
 
-        let query: &Database = ctx.data_unchecked();
+        let query = database_at_height(ctx, block_height)?;
         crate::schema::query_pagination(after, before, first, last, |start, direction| {
             let owner: fuel_tx::Address = filter.owner.into();
             let coins = query
@@ -217,7 +281,7 @@ impl CoinQuery {
     ) -> async_graphql::Result<Vec<Vec<CoinType>>> {
         let config = ctx.data_unchecked::<GraphQLConfig>();
 
-        let owner: fuel_tx::Address = owner.0;
+        let owner: fuel_tx::Address = owner.into();
         let query_per_asset = query_per_asset
             .into_iter()
             .map(|e| {
@@ -265,6 +329,34 @@ impl CoinQuery {
     }
 }
 
+#[derive(Default)]
+pub struct CoinSubscription;
+
+#[Subscription]
+impl CoinSubscription {
+    /// Returns a stream of the changes of the coins owned by the `owner`, made by
+    /// the blocks imported or reverted after the subscription.
+    ///
+    /// The changes are skipped if the stream is polled slower than the blocks
+    /// are imported.
+    async fn coin_changes<'a>(
+        &self,
+        ctx: &Context<'a>,
+        #[graphql(desc = "The owner of the coins")] owner: Address,
+    ) -> impl Stream<Item = async_graphql::Result<CoinEvent>> + 'a {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        let block_importer = ctx.data_unchecked::<BlockImporter>();
+        let owner: fuel_tx::Address = owner.into();
+        block_importer
+            .coin_events(config.consensus_parameters.chain_id)
+            .filter_map(move |event| {
+                futures::future::ready(
+                    (event.owner == owner).then(|| Ok(CoinEvent(event))),
+                )
+            })
+    }
+}
+
 impl From<CoinModel> for Coin {
     fn from(value: CoinModel) -> Self {
         Coin(value)
@@ -277,6 +369,17 @@ impl From<MessageCoinModel> for MessageCoin {
     }
 }
 
+impl From<CoinEventKindModel> for CoinEventKind {
+    fn from(kind: CoinEventKindModel) -> Self {
+        match kind {
+            CoinEventKindModel::Created => CoinEventKind::Created,
+            CoinEventKindModel::Spent => CoinEventKind::Spent,
+            CoinEventKindModel::CreationReverted => CoinEventKind::CreationReverted,
+            CoinEventKindModel::SpendReverted => CoinEventKind::SpendReverted,
+        }
+    }
+}
+
 impl From<coins::CoinType> for CoinType {
     fn from(value: coins::CoinType) -> Self {
         match value {
@@ -4,12 +4,17 @@ use crate::{
         IntoApiResult,
     },
     query::ContractQueryData,
-    schema::scalars::{
-        AssetId,
-        ContractId,
-        HexString,
-        Salt,
-        U64,
+    schema::{
+        database_at_height,
+        scalars::{
+            AssetId,
+            Bytes32,
+            ContractId,
+            HexString,
+            Salt,
+            U32,
+            U64,
+        },
     },
 };
 use anyhow::anyhow;
@@ -67,10 +72,91 @@ impl ContractQuery {
         &self,
         ctx: &Context<'_>,
         #[graphql(desc = "ID of the Contract")] id: ContractId,
+        #[graphql(
+            desc = "The height of the block to check the existence of the contract at"
+        )]
+        block_height: Option<U32>,
     ) -> async_graphql::Result<Option<Contract>> {
-        let data: &Database = ctx.data_unchecked();
+        // The code of the contract never changes, so only the existence of the contract
+        // depends on the height.
+        let data = database_at_height(ctx, block_height)?;
         data.contract_id(id.0).into_api_result()
     }
+
+    /// Returns the value of the storage slot of the contract,
+    /// or `null` if the slot is empty.
+    async fn contract_state(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "ID of the Contract")] contract: ContractId,
+        #[graphql(desc = "The key of the storage slot")] key: Bytes32,
+        #[graphql(desc = "The height of the block to query the storage slot at")]
+        block_height: Option<U32>,
+    ) -> async_graphql::Result<Option<Bytes32>> {
+        let data = database_at_height(ctx, block_height)?;
+        data.contract_state(contract.0, key.0).into_api_result()
+    }
+
+    /// Returns the storage slots of the contract ordered by their keys. The keys of
+    /// the slots are the cursors of the connection.
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(child_complexity, first, last)"
+    )]
+    async fn contract_slots(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "ID of the Contract")] contract_id: ContractId,
+        #[graphql(desc = "The key to start the slots from, if `after` is unset")]
+        start_key: Option<Bytes32>,
+        #[graphql(desc = "The key to end the slots before")] end_key: Option<Bytes32>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<Connection<Bytes32, ContractSlot, EmptyFields, EmptyFields>>
+    {
+        let query: &Database = ctx.data_unchecked();
+
+        // Rocksdb doesn't support reverse iteration over a prefix
+        if matches!(last, Some(last) if last > 0) {
+            return Err(
+                anyhow!("reverse pagination isn't supported for this resource").into(),
+            )
+        }
+
+        crate::schema::query_pagination(after, before, first, last, |start, direction| {
+            let start = (*start).or(start_key).map(|key| key.0);
+            let slots = query
+                .contract_slots(contract_id.0, start, direction)
+                .take_while(move |slot| match (slot, end_key) {
+                    (Ok((key, _)), Some(end_key)) => key < &end_key.0,
+                    _ => true,
+                })
+                .map(|slot| {
+                    let (key, value) = slot?;
+                    Ok((key.into(), ContractSlot { key, value }))
+                });
+
+            Ok(slots)
+        })
+        .await
+    }
+}
+
+pub struct ContractSlot {
+    key: fuel_types::Bytes32,
+    value: fuel_types::Bytes32,
+}
+
+#[Object]
+impl ContractSlot {
+    async fn key(&self) -> Bytes32 {
+        self.key.into()
+    }
+
+    async fn value(&self) -> Bytes32 {
+        self.value.into()
+    }
 }
 
 pub struct ContractBalance(graphql_api::ContractBalance);
@@ -125,6 +211,9 @@ impl ContractBalanceQuery {
             })
     }
 
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(child_complexity, first, last)"
+    )]
     async fn contract_balances(
         &self,
         ctx: &Context<'_>,
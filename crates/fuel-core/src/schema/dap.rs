@@ -4,6 +4,10 @@ use crate::{
         vm_database::VmDatabase,
         Database,
     },
+    fuel_core_graphql_api::auth::{
+        ApiRole,
+        RoleGuard,
+    },
     schema::scalars::U64,
 };
 use async_graphql::{
@@ -206,6 +210,7 @@ pub fn init<Q, M, S>(
 
 #[Object]
 impl DapQuery {
+    #[graphql(guard = "RoleGuard::new(ApiRole::Admin)")]
     async fn register(
         &self,
         ctx: &Context<'_>,
@@ -220,6 +225,7 @@ impl DapQuery {
             .map(|val| val.into())
     }
 
+    #[graphql(guard = "RoleGuard::new(ApiRole::Admin)")]
     async fn memory(
         &self,
         ctx: &Context<'_>,
@@ -238,6 +244,7 @@ impl DapQuery {
 
 #[Object]
 impl DapMutation {
+    #[graphql(guard = "RoleGuard::new(ApiRole::Admin)")]
     async fn start_session(&self, ctx: &Context<'_>) -> async_graphql::Result<ID> {
         trace!("Initializing new interpreter");
 
@@ -254,6 +261,7 @@ impl DapMutation {
         Ok(id)
     }
 
+    #[graphql(guard = "RoleGuard::new(ApiRole::Admin)")]
     async fn end_session(&self, ctx: &Context<'_>, id: ID) -> bool {
         let existed = ctx.data_unchecked::<GraphStorage>().lock().await.kill(&id);
 
@@ -262,6 +270,7 @@ impl DapMutation {
         existed
     }
 
+    #[graphql(guard = "RoleGuard::new(ApiRole::Admin)")]
     async fn reset(&self, ctx: &Context<'_>, id: ID) -> async_graphql::Result<bool> {
         let db = ctx.data_unchecked::<Database>();
 
@@ -275,6 +284,7 @@ impl DapMutation {
         Ok(true)
     }
 
+    #[graphql(guard = "RoleGuard::new(ApiRole::Admin)")]
     async fn execute(
         &self,
         ctx: &Context<'_>,
@@ -296,6 +306,7 @@ impl DapMutation {
     }
 
     #[cfg(not(feature = "debug"))]
+    #[graphql(guard = "RoleGuard::new(ApiRole::Admin)")]
     async fn set_single_stepping(
         &self,
         _ctx: &Context<'_>,
@@ -308,6 +319,7 @@ impl DapMutation {
     }
 
     #[cfg(feature = "debug")]
+    #[graphql(guard = "RoleGuard::new(ApiRole::Admin)")]
     async fn set_single_stepping(
         &self,
         ctx: &Context<'_>,
@@ -327,6 +339,7 @@ impl DapMutation {
     }
 
     #[cfg(not(feature = "debug"))]
+    #[graphql(guard = "RoleGuard::new(ApiRole::Admin)")]
     async fn set_breakpoint(
         &self,
         _ctx: &Context<'_>,
@@ -339,6 +352,7 @@ impl DapMutation {
     }
 
     #[cfg(feature = "debug")]
+    #[graphql(guard = "RoleGuard::new(ApiRole::Admin)")]
     async fn set_breakpoint(
         &self,
         ctx: &Context<'_>,
@@ -357,6 +371,7 @@ impl DapMutation {
         Ok(true)
     }
 
+    #[graphql(guard = "RoleGuard::new(ApiRole::Admin)")]
     async fn start_tx(
         &self,
         ctx: &Context<'_>,
@@ -441,6 +456,7 @@ impl DapMutation {
     }
 
     #[cfg(not(feature = "debug"))]
+    #[graphql(guard = "RoleGuard::new(ApiRole::Admin)")]
     async fn continue_tx(
         &self,
         _ctx: &Context<'_>,
@@ -452,6 +468,7 @@ impl DapMutation {
     }
 
     #[cfg(feature = "debug")]
+    #[graphql(guard = "RoleGuard::new(ApiRole::Admin)")]
     async fn continue_tx(
         &self,
         ctx: &Context<'_>,
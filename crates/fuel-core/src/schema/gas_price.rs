@@ -0,0 +1,275 @@
+use super::scalars::{
+    HexString,
+    U32,
+    U64,
+};
+use crate::{
+    fuel_core_graphql_api::{
+        auth::{
+            ApiRole,
+            RoleGuard,
+        },
+        service::{
+            BlockProducer,
+            Database,
+        },
+    },
+    graphql_api::Config,
+    query::{
+        BlockQueryData,
+        SimpleTransactionData,
+    },
+};
+use async_graphql::{
+    Context,
+    Enum,
+    Object,
+};
+use fuel_core_storage::{
+    iter::IterDirection,
+    Result as StorageResult,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        Cacheable,
+        Chargeable,
+        Receipt,
+        Transaction as FuelTx,
+    },
+    fuel_types::bytes::Deserializable,
+    services::executor::StateOverrides,
+};
+use itertools::Itertools;
+
+/// The number of the latest blocks with the gas prices of the included transactions
+/// used for the fee estimation.
+const RECENT_BLOCKS: usize = 20;
+
+pub struct LatestGasPrice {
+    gas_price: U64,
+    block_height: U32,
+}
+
+#[Object]
+impl LatestGasPrice {
+    /// The minimum gas price of the transactions for the next block
+    async fn gas_price(&self) -> U64 {
+        self.gas_price
+    }
+
+    /// The height of the latest block
+    async fn block_height(&self) -> U32 {
+        self.block_height
+    }
+}
+
+#[derive(Default)]
+pub struct LatestGasPriceQuery;
+
+#[Object]
+impl LatestGasPriceQuery {
+    /// Returns the minimum gas price accepted by the node for the next block.
+    async fn latest_gas_price(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<LatestGasPrice> {
+        let query: &Database = ctx.data_unchecked();
+        let block_producer = ctx.data_unchecked::<BlockProducer>();
+
+        let block_height = query.latest_block_height()?;
+        Ok(LatestGasPrice {
+            gas_price: block_producer.latest_gas_price().into(),
+            block_height: block_height.into(),
+        })
+    }
+}
+
+pub struct EstimateGasPrice {
+    gas_price: U64,
+}
+
+#[Object]
+impl EstimateGasPrice {
+    /// The estimated minimum gas price of the transactions
+    async fn gas_price(&self) -> U64 {
+        self.gas_price
+    }
+}
+
+#[derive(Default)]
+pub struct EstimateGasPriceQuery;
+
+#[Object]
+impl EstimateGasPriceQuery {
+    /// Estimates the minimum gas price in the future, assuming the upcoming blocks are
+    /// as full as the recent ones.
+    async fn estimate_gas_price(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(
+            desc = "Number of blocks into the future to estimate the gas price for"
+        )]
+        block_horizon: Option<U32>,
+    ) -> EstimateGasPrice {
+        let block_producer = ctx.data_unchecked::<BlockProducer>();
+        let block_horizon = block_horizon.map(|horizon| horizon.0).unwrap_or(0);
+
+        EstimateGasPrice {
+            gas_price: block_producer.estimate_gas_price(block_horizon).into(),
+        }
+    }
+}
+
+#[derive(Enum, Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum FeeConfidence {
+    /// Enough to be accepted by the node now, but may wait for the emptier blocks.
+    Low,
+    /// As much as the typical transaction of the recent blocks.
+    #[default]
+    Medium,
+    /// Stays accepted if the blocks get full, and outbids most recent transactions.
+    High,
+}
+
+impl FeeConfidence {
+    /// The number of the blocks the minimum gas price is estimated for.
+    fn block_horizon(&self) -> u32 {
+        match self {
+            FeeConfidence::Low => 0,
+            FeeConfidence::Medium => 1,
+            FeeConfidence::High => 5,
+        }
+    }
+
+    /// The percentile of the gas prices of the recently included transactions.
+    fn percentile(&self) -> usize {
+        match self {
+            FeeConfidence::Low => 25,
+            FeeConfidence::Medium => 50,
+            FeeConfidence::High => 90,
+        }
+    }
+
+    /// The extra gas above the gas used by the dry run in percent, covering the
+    /// changes of the state before the inclusion.
+    fn gas_margin(&self) -> u64 {
+        match self {
+            FeeConfidence::Low => 0,
+            FeeConfidence::Medium => 10,
+            FeeConfidence::High => 25,
+        }
+    }
+}
+
+pub struct FeeEstimate {
+    confidence: FeeConfidence,
+    gas_price: U64,
+    gas_limit: U64,
+    gas_used: U64,
+}
+
+#[Object]
+impl FeeEstimate {
+    /// The confidence of the inclusion the estimate is made for
+    async fn confidence(&self) -> FeeConfidence {
+        self.confidence
+    }
+
+    /// The recommended gas price of the transaction
+    async fn gas_price(&self) -> U64 {
+        self.gas_price
+    }
+
+    /// The recommended gas limit of the transaction
+    async fn gas_limit(&self) -> U64 {
+        self.gas_limit
+    }
+
+    /// The gas used by the dry run of the transaction
+    async fn gas_used(&self) -> U64 {
+        self.gas_used
+    }
+}
+
+#[derive(Default)]
+pub struct EstimateFeesQuery;
+
+#[Object]
+impl EstimateFeesQuery {
+    /// Estimates the gas price and the gas limit of the transaction to be included
+    /// with the `confidence`. The gas limit is based on the dry run of the transaction,
+    /// so the transaction should have the gas limit enough for its execution.
+    /// The gas price follows the minimum gas price of the node and the gas prices of
+    /// the transactions included into the recent blocks.
+    #[graphql(guard = "RoleGuard::new(ApiRole::SubmitTx)")]
+    async fn estimate_fees(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+        confidence: Option<FeeConfidence>,
+        // If set to false, disable input utxo validation, overriding the configuration of the node.
+        utxo_validation: Option<bool>,
+    ) -> async_graphql::Result<FeeEstimate> {
+        let query: &Database = ctx.data_unchecked();
+        let block_producer = ctx.data_unchecked::<BlockProducer>();
+        let config = ctx.data_unchecked::<Config>();
+        let confidence = confidence.unwrap_or_default();
+
+        let mut tx = FuelTx::from_bytes(&tx.0)?;
+        tx.precompute(&config.consensus_parameters.chain_id)?;
+
+        let receipts = block_producer
+            .dry_run_tx(tx, None, utxo_validation, StateOverrides::default())
+            .await?;
+        let gas_used = receipts
+            .iter()
+            .map(|receipt| match receipt {
+                Receipt::ScriptResult { gas_used, .. } => *gas_used,
+                _ => 0,
+            })
+            .sum::<u64>();
+        let max_gas_per_tx = config.consensus_parameters.tx_params().max_gas_per_tx;
+        let gas_limit = (gas_used as u128 * (100 + confidence.gas_margin()) as u128 / 100)
+            .min(max_gas_per_tx as u128) as u64;
+
+        let min_gas_price = block_producer.estimate_gas_price(confidence.block_horizon());
+        let recent_gas_prices = recent_gas_prices(query.as_ref())?;
+        let gas_price = percentile(&recent_gas_prices, confidence.percentile())
+            .map(|recent| recent.max(min_gas_price))
+            .unwrap_or(min_gas_price);
+
+        Ok(FeeEstimate {
+            confidence,
+            gas_price: gas_price.into(),
+            gas_limit: gas_limit.into(),
+            gas_used: gas_used.into(),
+        })
+    }
+}
+
+/// Returns the sorted gas prices of the transactions included into the recent blocks.
+fn recent_gas_prices<D>(query: &D) -> StorageResult<Vec<u64>>
+where
+    D: BlockQueryData + SimpleTransactionData + ?Sized,
+{
+    let mut gas_prices = vec![];
+    for block in query
+        .compressed_blocks(None, IterDirection::Reverse)
+        .take(RECENT_BLOCKS)
+    {
+        for tx_id in block?.transactions() {
+            match query.transaction(tx_id)? {
+                FuelTx::Script(script) => gas_prices.push(script.price()),
+                FuelTx::Create(create) => gas_prices.push(create.price()),
+                FuelTx::Mint(_) => {}
+            }
+        }
+    }
+    Ok(gas_prices.into_iter().sorted().collect())
+}
+
+/// Returns the `percentile` of the `sorted` values, or `None` if there are none.
+fn percentile(sorted: &[u64], percentile: usize) -> Option<u64> {
+    let last = sorted.len().checked_sub(1)?;
+    sorted.get(last * percentile / 100).copied()
+}
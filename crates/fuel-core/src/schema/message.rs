@@ -65,6 +65,9 @@ pub struct MessageQuery {}
 
 #[Object]
 impl MessageQuery {
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(child_complexity, first, last)"
+    )]
     async fn messages(
         &self,
         ctx: &Context<'_>,
@@ -123,16 +126,7 @@ impl MessageQuery {
         commit_block_height: Option<U32>,
     ) -> async_graphql::Result<Option<MessageProof>> {
         let data: &Database = ctx.data_unchecked();
-        let block_id = match (commit_block_id, commit_block_height) {
-            (Some(commit_block_id), None) => commit_block_id.0.into(),
-            (None, Some(commit_block_height)) => {
-                let block_height = commit_block_height.0.into();
-                data.block_id(&block_height)?
-            }
-            _ => Err(anyhow::anyhow!(
-                "Either `commit_block_id` or `commit_block_height` must be provided exclusively"
-            ))?,
-        };
+        let block_id = commit_block(data, commit_block_id, commit_block_height)?;
 
         Ok(crate::query::message_proof(
             data.deref(),
@@ -142,7 +136,67 @@ impl MessageQuery {
         )?
         .map(MessageProof))
     }
+
+    /// Returns the proofs of the output `messages` against the same commit block in
+    /// the order of the `messages`. The proof is `null` if the message can't be proven.
+    async fn message_proofs(
+        &self,
+        ctx: &Context<'_>,
+        messages: Vec<MessageProofInput>,
+        commit_block_id: Option<BlockId>,
+        commit_block_height: Option<U32>,
+    ) -> async_graphql::Result<Vec<Option<MessageProof>>> {
+        if messages.len() > MAX_MESSAGE_PROOFS {
+            return Err(anyhow!(
+                "The number of the messages exceeds the limit of {}",
+                MAX_MESSAGE_PROOFS
+            )
+            .into())
+        }
+        let data: &Database = ctx.data_unchecked();
+        let block_id = commit_block(data, commit_block_id, commit_block_height)?;
+
+        let messages: Vec<_> = messages
+            .into_iter()
+            .map(|message| (message.transaction_id.into(), message.message_id.into()))
+            .collect();
+        let proofs = crate::query::message_proofs(data.deref(), &messages, block_id)?
+            .into_iter()
+            .map(|proof| proof.map(MessageProof))
+            .collect();
+        Ok(proofs)
+    }
+}
+
+/// The maximum number of the messages proven by one request.
+const MAX_MESSAGE_PROOFS: usize = 256;
+
+fn commit_block(
+    data: &Database,
+    commit_block_id: Option<BlockId>,
+    commit_block_height: Option<U32>,
+) -> async_graphql::Result<fuel_core_types::blockchain::primitives::BlockId> {
+    let block_id = match (commit_block_id, commit_block_height) {
+        (Some(commit_block_id), None) => commit_block_id.0.into(),
+        (None, Some(commit_block_height)) => {
+            let block_height = commit_block_height.0.into();
+            data.block_id(&block_height)?
+        }
+        _ => Err(anyhow::anyhow!(
+            "Either `commit_block_id` or `commit_block_height` must be provided exclusively"
+        ))?,
+    };
+    Ok(block_id)
 }
+
+#[derive(async_graphql::InputObject)]
+struct MessageProofInput {
+    /// The transaction that contains the output message.
+    transaction_id: TransactionId,
+    /// The id of the output message to prove.
+    message_id: MessageId,
+}
+
 pub struct MerkleProof(pub(crate) entities::message::MerkleProof);
 
 #[Object]
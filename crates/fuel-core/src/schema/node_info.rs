@@ -1,9 +1,40 @@
-use super::scalars::U64;
-use crate::fuel_core_graphql_api::Config as GraphQLConfig;
+use super::scalars::{
+    U32,
+    U64,
+};
+use crate::{
+    database::Column,
+    fuel_core_graphql_api::{
+        auth::{
+            ApiRole,
+            RoleGuard,
+        },
+        service::{
+            Database,
+            P2pService,
+        },
+        Config as GraphQLConfig,
+    },
+};
+use anyhow::{
+    anyhow,
+    Context as _,
+};
 use async_graphql::{
     Context,
     Object,
 };
+use fuel_core_types::services::p2p::{
+    PeerId,
+    PeerInfo as PeerInfoEntity,
+};
+use std::{
+    path::{
+        Path,
+        PathBuf,
+    },
+    str::FromStr,
+};
 
 pub struct NodeInfo {
     utxo_validation: bool,
@@ -39,6 +70,54 @@ impl NodeInfo {
     async fn node_version(&self) -> String {
         self.node_version.to_owned()
     }
+
+    async fn peers(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<PeerInfo>> {
+        let p2p = ctx.data_unchecked::<P2pService>();
+        let peers = p2p.all_peer_info().await?;
+        Ok(peers.into_iter().map(PeerInfo).collect())
+    }
+}
+
+pub struct PeerInfo(PeerInfoEntity);
+
+#[Object]
+impl PeerInfo {
+    /// The libp2p peer id
+    async fn id(&self) -> String {
+        self.0.id.to_string()
+    }
+
+    /// The advertised multi-addrs that can be used to connect to this peer
+    async fn addresses(&self) -> Vec<String> {
+        self.0.peer_addresses.clone()
+    }
+
+    /// The self-reported version of the client the peer is using
+    async fn client_version(&self) -> Option<String> {
+        self.0.client_version.clone()
+    }
+
+    /// The last reported height of the peer
+    async fn block_height(&self) -> Option<U32> {
+        self.0.block_height.map(Into::into)
+    }
+
+    /// The time in ms since the last heartbeat from this peer
+    async fn last_heartbeat_ms(&self) -> Option<U64> {
+        self.0
+            .last_heartbeat
+            .map(|duration| (duration.as_millis() as u64).into())
+    }
+
+    /// The time in ms since the connection with the peer was established
+    async fn connection_age_ms(&self) -> U64 {
+        (self.0.connection_age.as_millis() as u64).into()
+    }
+
+    /// The internal fuel p2p reputation of this peer
+    async fn app_score(&self) -> f64 {
+        self.0.app_score
+    }
 }
 
 #[derive(Default)]
@@ -61,3 +140,149 @@ impl NodeQuery {
         })
     }
 }
+
+#[derive(Default)]
+pub struct NodeMutation;
+
+#[Object]
+impl NodeMutation {
+    /// Closes all connections with the peer. The peer is free to reconnect.
+    #[graphql(guard = "RoleGuard::new(ApiRole::Admin)")]
+    async fn disconnect_peer(
+        &self,
+        ctx: &Context<'_>,
+        id: String,
+    ) -> async_graphql::Result<bool> {
+        let p2p = admin_p2p_service(ctx)?;
+        p2p.disconnect_peer(PeerId::from_str(&id).map_err(|e| anyhow!(e))?)?;
+        Ok(true)
+    }
+
+    /// Bans the peer, so no connections with it are allowed until the restart.
+    #[graphql(guard = "RoleGuard::new(ApiRole::Admin)")]
+    async fn ban_peer(
+        &self,
+        ctx: &Context<'_>,
+        id: String,
+    ) -> async_graphql::Result<bool> {
+        let p2p = admin_p2p_service(ctx)?;
+        p2p.ban_peer(PeerId::from_str(&id).map_err(|e| anyhow!(e))?)?;
+        Ok(true)
+    }
+
+    /// Starts the compaction of the database column, or of the whole database if
+    /// the column is not set. The compaction runs in the background.
+    #[graphql(guard = "RoleGuard::new(ApiRole::Admin)")]
+    async fn compact_database(
+        &self,
+        ctx: &Context<'_>,
+        column: Option<String>,
+    ) -> async_graphql::Result<bool> {
+        let database = admin_database(ctx)?;
+        let column = column
+            .map(|column| Column::from_str(&column))
+            .transpose()
+            .map_err(|e| anyhow!("Unknown column: {}", e))?;
+        database.start_compaction(column)?;
+        Ok(true)
+    }
+
+    /// Creates the checkpoint of the database at the `path` on the file system of the
+    /// node. The checkpoint is the consistent copy of the database that can be opened
+    /// separately, e.g. to back it up while the node is running. The files are hard
+    /// linked if the `path` is on the same file system as the database. The `path`
+    /// must be inside of the checkpoint directory of the node, the relative `path`
+    /// is relative to it.
+    #[graphql(guard = "RoleGuard::new(ApiRole::Admin)")]
+    async fn create_database_checkpoint(
+        &self,
+        ctx: &Context<'_>,
+        path: String,
+    ) -> async_graphql::Result<bool> {
+        let database = admin_database(ctx)?;
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        let dir = config.database_checkpoint_dir.as_ref().ok_or_else(|| {
+            anyhow!(
+                "The checkpoint directory must be configured to create the checkpoints"
+            )
+        })?;
+        database.create_checkpoint(&checkpoint_path(dir, Path::new(&path))?)?;
+        Ok(true)
+    }
+}
+
+fn admin_p2p_service<'a>(ctx: &Context<'a>) -> anyhow::Result<&'a P2pService> {
+    let config = ctx.data_unchecked::<GraphQLConfig>();
+    if !config.peer_admin_enabled {
+        return Err(anyhow!(
+            "Peer administration must be enabled to use this endpoint"
+        ))
+    }
+    Ok(ctx.data_unchecked::<P2pService>())
+}
+
+fn admin_database<'a>(ctx: &Context<'a>) -> anyhow::Result<&'a Database> {
+    let config = ctx.data_unchecked::<GraphQLConfig>();
+    if !config.database_admin_enabled {
+        return Err(anyhow!(
+            "Database administration must be enabled to use this endpoint"
+        ))
+    }
+    Ok(ctx.data_unchecked::<Database>())
+}
+
+/// Resolves the `path` of the new checkpoint, which must be inside of the `dir`.
+/// The symbolic links are resolved, so they can't point the checkpoint outside.
+fn checkpoint_path(dir: &Path, path: &Path) -> anyhow::Result<PathBuf> {
+    // The absolute `path` replaces the `dir`
+    let path = dir.join(path);
+    let name = path
+        .file_name()
+        .ok_or_else(|| anyhow!("The checkpoint path {} has no name", path.display()))?;
+    let parent = path
+        .parent()
+        .and_then(|parent| parent.canonicalize().ok())
+        .ok_or_else(|| {
+            anyhow!(
+                "The parent of the checkpoint path {} doesn't exist",
+                path.display()
+            )
+        })?;
+    let dir = dir.canonicalize().with_context(|| {
+        format!("The checkpoint directory {} doesn't exist", dir.display())
+    })?;
+    if !parent.starts_with(&dir) {
+        return Err(anyhow!(
+            "The checkpoint must be inside of the checkpoint directory {}",
+            dir.display()
+        ))
+    }
+    Ok(parent.join(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn checkpoint_path_is_constrained_to_the_dir() {
+        let tmp_dir = TempDir::new().unwrap();
+        let dir = tmp_dir.path().join("checkpoints");
+        std::fs::create_dir(&dir).unwrap();
+        let dir = dir.canonicalize().unwrap();
+
+        assert_eq!(
+            checkpoint_path(&dir, Path::new("backup")).unwrap(),
+            dir.join("backup")
+        );
+        assert_eq!(
+            checkpoint_path(&dir, &dir.join("backup")).unwrap(),
+            dir.join("backup")
+        );
+        assert!(checkpoint_path(&dir, Path::new("../backup")).is_err());
+        assert!(checkpoint_path(&dir, Path::new("..")).is_err());
+        assert!(checkpoint_path(&dir, &tmp_dir.path().join("backup")).is_err());
+        assert!(checkpoint_path(&dir, Path::new("missing/backup")).is_err());
+    }
+}
@@ -9,6 +9,7 @@ use async_graphql::{
 use fuel_core_types::{
     fuel_types,
     fuel_types::BlockHeight,
+    services::graphql_api::ReceiptPointer,
     tai64::Tai64,
 };
 use std::{
@@ -155,6 +156,59 @@ impl CursorType for SortedTxCursor {
     }
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ReceiptCursor(pub ReceiptPointer);
+
+impl CursorType for ReceiptCursor {
+    type Error = String;
+
+    fn decode_cursor(s: &str) -> Result<Self, Self::Error> {
+        let parts: Vec<_> = s.split('#').collect();
+        match parts.as_slice() {
+            [block_height, tx_idx, receipt_idx] => Ok(Self(ReceiptPointer {
+                block_height: u32::from_str(block_height)
+                    .map_err(|_| "Failed to decode block_height")?
+                    .into(),
+                tx_idx: u16::from_str(tx_idx).map_err(|_| "Failed to decode tx_idx")?,
+                receipt_idx: u16::from_str(receipt_idx)
+                    .map_err(|_| "Failed to decode receipt_idx")?,
+            })),
+            _ => Err("Incorrect format provided".to_string()),
+        }
+    }
+
+    fn encode_cursor(&self) -> String {
+        format!(
+            "{}#{}#{}",
+            self.0.block_height, self.0.tx_idx, self.0.receipt_idx
+        )
+    }
+}
+
+/// The position of the holder in the holders of the asset sorted by the balances.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct HolderCursor {
+    pub amount: u64,
+    pub owner: Address,
+}
+
+impl CursorType for HolderCursor {
+    type Error = String;
+
+    fn decode_cursor(s: &str) -> Result<Self, Self::Error> {
+        let (amount, owner) = s.split_once('#').ok_or("Incorrect format provided")?;
+
+        Ok(Self {
+            amount: u64::from_str(amount).map_err(|_| "Failed to decode amount")?,
+            owner: Address::decode_cursor(owner)?,
+        })
+    }
+
+    fn encode_cursor(&self) -> String {
+        format!("{}#{}", self.amount, self.owner)
+    }
+}
+
 #[derive(Clone, Debug, derive_more::Into, derive_more::From, PartialEq, Eq)]
 pub struct HexString(pub(crate) Vec<u8>);
 
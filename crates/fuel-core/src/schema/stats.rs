@@ -0,0 +1,104 @@
+use crate::{
+    fuel_core_graphql_api::service::{
+        ChainStatsSource,
+        TxPool,
+    },
+    schema::scalars::{
+        U32,
+        U64,
+    },
+    service::chain_stats::MAX_STATS_WINDOW,
+};
+use anyhow::anyhow;
+use async_graphql::{
+    Context,
+    Object,
+};
+use fuel_core_types::services::graphql_api;
+
+/// The number of the latest blocks the statistics are computed over by default.
+const DEFAULT_STATS_WINDOW: u32 = 100;
+
+pub struct ChainStats {
+    stats: graphql_api::ChainStats,
+    pool_depth: u64,
+}
+
+#[Object]
+impl ChainStats {
+    /// The number of the latest blocks the statistics are computed over.
+    async fn blocks(&self) -> U32 {
+        self.stats.blocks.into()
+    }
+
+    /// The number of the transactions in the blocks, except the mint transactions.
+    async fn transactions(&self) -> U64 {
+        self.stats.transactions.into()
+    }
+
+    /// The number of the transactions included per second.
+    async fn transactions_per_second(&self) -> f64 {
+        self.stats.transactions_per_second
+    }
+
+    /// The average interval between the blocks in seconds.
+    async fn average_block_interval(&self) -> f64 {
+        self.stats.average_block_interval
+    }
+
+    /// The median interval between the blocks in seconds.
+    async fn p50_block_interval(&self) -> U64 {
+        self.stats.p50_block_interval.into()
+    }
+
+    /// The 90th percentile of the intervals between the blocks in seconds.
+    async fn p90_block_interval(&self) -> U64 {
+        self.stats.p90_block_interval.into()
+    }
+
+    /// The 99th percentile of the intervals between the blocks in seconds.
+    async fn p99_block_interval(&self) -> U64 {
+        self.stats.p99_block_interval.into()
+    }
+
+    /// The average gas used by the scripts of the block.
+    async fn average_gas_used(&self) -> U64 {
+        self.stats.average_gas_used.into()
+    }
+
+    /// The number of the pending transactions in the pool.
+    async fn pool_depth(&self) -> U64 {
+        self.pool_depth.into()
+    }
+}
+
+#[derive(Default)]
+pub struct ChainStatsQuery;
+
+#[Object]
+impl ChainStatsQuery {
+    /// Returns the rolling statistics of the latest blocks imported by the node.
+    async fn chain_stats(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The number of the latest blocks, 100 by default")]
+        window: Option<U32>,
+    ) -> async_graphql::Result<ChainStats> {
+        let window = window
+            .map(|window| window.0)
+            .unwrap_or(DEFAULT_STATS_WINDOW);
+        if window == 0 || window > MAX_STATS_WINDOW {
+            return Err(anyhow!(
+                "The window should be between 1 and {MAX_STATS_WINDOW} blocks"
+            )
+            .into())
+        }
+
+        let chain_stats = ctx.data_unchecked::<ChainStatsSource>();
+        let txpool = ctx.data_unchecked::<TxPool>();
+        Ok(ChainStats {
+            stats: chain_stats.chain_stats(window),
+            pool_depth: txpool.pending_number() as u64,
+        })
+    }
+}
@@ -0,0 +1,65 @@
+use super::scalars::{
+    U32,
+    U64,
+};
+use crate::fuel_core_graphql_api::service::{
+    P2pService,
+    SyncService,
+};
+use async_graphql::{
+    Context,
+    Object,
+};
+use fuel_core_types::services::sync::SyncStatus as SyncStatusEntity;
+
+pub struct SyncStatus(SyncStatusEntity);
+
+#[Object]
+impl SyncStatus {
+    /// The height of the latest block committed by the node
+    async fn current_height(&self) -> U32 {
+        self.0.current_height.into()
+    }
+
+    /// The highest block height reported by the peers
+    async fn highest_seen_height(&self) -> U32 {
+        self.0.highest_seen_height.into()
+    }
+
+    /// The number of connected peers that reported a height above the current height
+    async fn peers_synced_against(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<U32> {
+        let p2p = ctx.data_unchecked::<P2pService>();
+        let peers = p2p.all_peer_info().await?;
+        let count = peers
+            .iter()
+            .filter(|peer| {
+                peer.block_height
+                    .map_or(false, |height| height > self.0.current_height)
+            })
+            .count();
+        Ok((count as u32).into())
+    }
+
+    /// The estimated time in seconds to reach the highest seen height.
+    /// `null` until the import rate is known.
+    async fn estimated_seconds_remaining(&self) -> Option<U64> {
+        self.0
+            .estimated_time_remaining
+            .map(|duration| duration.as_secs().into())
+    }
+}
+
+#[derive(Default)]
+pub struct SyncQuery;
+
+#[Object]
+impl SyncQuery {
+    /// Returns the progress of the block sync, or `null` if the sync is disabled.
+    async fn sync_status(&self, ctx: &Context<'_>) -> Option<SyncStatus> {
+        let sync = ctx.data_unchecked::<SyncService>();
+        sync.sync_status().map(SyncStatus)
+    }
+}
@@ -1,5 +1,10 @@
 use crate::{
     fuel_core_graphql_api::{
+        auth::{
+            ApiRole,
+            RoleGuard,
+        },
+        ports::DatabaseTransactions,
         service::{
             BlockProducer,
             Database,
@@ -16,10 +21,16 @@ use crate::{
     },
     schema::scalars::{
         Address,
+        AssetId,
+        Bytes32,
+        ContractId,
         HexString,
+        ReceiptCursor,
         SortedTxCursor,
         TransactionId,
         TxPointer,
+        UtxoId,
+        U64,
     },
 };
 use anyhow::anyhow;
@@ -37,8 +48,13 @@ use fuel_core_storage::{
     Error as StorageError,
     Result as StorageResult,
 };
-use fuel_core_txpool::service::TxStatusMessage;
+use fuel_core_txpool::service::{
+    TxStatusFilter,
+    TxStatusMessage,
+    TxStatusUpdate,
+};
 use fuel_core_types::{
+    entities::coins::coin::Coin,
     fuel_tx::{
         Cacheable,
         Transaction as FuelTx,
@@ -47,7 +63,11 @@ use fuel_core_types::{
     fuel_types,
     fuel_types::bytes::Deserializable,
     fuel_vm::checked_transaction::EstimatePredicates,
-    services::txpool,
+    services::{
+        executor::StateOverrides,
+        graphql_api::ReceiptPointer,
+        txpool,
+    },
 };
 use futures::{
     Stream,
@@ -61,12 +81,24 @@ use std::{
 use tokio_stream::StreamExt;
 use types::Transaction;
 
-use self::types::TransactionStatus;
+use self::{
+    receipt::{
+        ContractReceipt,
+        ReceiptFilterInput,
+        ReceiptType,
+    },
+    types::{
+        BatchSubmitResult,
+        TransactionStatus,
+        TransactionStatusUpdate,
+    },
+};
 use fuel_core_types::fuel_vm::checked_transaction::CheckPredicateParams;
 
 pub mod input;
 pub mod output;
 pub mod receipt;
+pub mod trace;
 pub mod types;
 
 #[derive(Default)]
@@ -93,6 +125,9 @@ impl TxQuery {
         }
     }
 
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(child_complexity, first, last)"
+    )]
     async fn transactions(
         &self,
         ctx: &Context<'_>,
@@ -155,6 +190,11 @@ impl TxQuery {
         .await
     }
 
+    /// Returns the transactions spending the coins or the messages of the `owner`,
+    /// or sending the coins to it, ordered by their position in the chain.
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(child_complexity, first, last)"
+    )]
     async fn transactions_by_owner(
         &self,
         ctx: &Context<'_>,
@@ -198,6 +238,77 @@ impl TxQuery {
         .await
     }
 
+    /// Returns the receipts emitted by the contract ordered by their position in
+    /// the chain. Only available if the node indexes the receipts.
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(child_complexity, first, last)"
+    )]
+    async fn receipts(
+        &self,
+        ctx: &Context<'_>,
+        filter: ReceiptFilterInput,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<ReceiptCursor, ContractReceipt, EmptyFields, EmptyFields>,
+    > {
+        // Rocksdb doesn't support reverse iteration over a prefix
+        if matches!(last, Some(last) if last > 0) {
+            return Err(
+                anyhow!("reverse pagination isn't supported for this resource").into(),
+            )
+        }
+
+        let query: &Database = ctx.data_unchecked();
+        let lowest = ReceiptPointer {
+            block_height: filter
+                .start_height
+                .map(|height| height.0)
+                .unwrap_or(0)
+                .into(),
+            tx_idx: 0,
+            receipt_idx: 0,
+        };
+        let end_height = filter.end_height.map(|height| height.0);
+        let receipt_type = filter.receipt_type;
+
+        crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<ReceiptCursor>, direction| {
+                let start = match start {
+                    Some(cursor) => cursor.0.max(lowest),
+                    None => lowest,
+                };
+                let receipts = query
+                    .contract_receipts(filter.contract.0, Some(start), direction)
+                    .take_while(move |result| match (result, end_height) {
+                        (Ok(receipt), Some(end_height)) => {
+                            *receipt.pointer.block_height <= end_height
+                        }
+                        _ => true,
+                    })
+                    .filter(move |result| match (result, receipt_type) {
+                        (Ok(receipt), Some(receipt_type)) => {
+                            ReceiptType::from(&receipt.receipt) == receipt_type
+                        }
+                        _ => true,
+                    })
+                    .map(|result| {
+                        result.map(|receipt| {
+                            (ReceiptCursor(receipt.pointer), ContractReceipt(receipt))
+                        })
+                    });
+                Ok(receipts)
+            },
+        )
+        .await
+    }
+
     /// Estimate the predicate gas for the provided transaction
     async fn estimate_predicates(
         &self,
@@ -227,12 +338,86 @@ impl TxQuery {
     }
 }
 
+/// The ephemeral changes of the state visible only to the dry run.
+#[derive(async_graphql::InputObject)]
+pub struct StateOverridesInput {
+    /// The coins added to the state, for example, to fund the spender.
+    coins: Option<Vec<CoinOverrideInput>>,
+    /// The new values of the contract storage slots.
+    contract_state: Option<Vec<ContractStateOverrideInput>>,
+    /// The bytecode that replaces the code of the contract.
+    contract_code: Option<Vec<ContractCodeOverrideInput>>,
+}
+
+#[derive(async_graphql::InputObject)]
+pub struct CoinOverrideInput {
+    /// The UTXO id of the coin, referenced by the input of the transaction.
+    utxo_id: UtxoId,
+    /// The owner of the coin.
+    owner: Address,
+    /// The amount of the coin.
+    amount: U64,
+    /// The asset of the coin.
+    asset_id: AssetId,
+}
+
+#[derive(async_graphql::InputObject)]
+pub struct ContractStateOverrideInput {
+    /// The contract owning the storage slot.
+    contract_id: ContractId,
+    /// The key of the storage slot.
+    key: Bytes32,
+    /// The new value of the storage slot.
+    value: Bytes32,
+}
+
+#[derive(async_graphql::InputObject)]
+pub struct ContractCodeOverrideInput {
+    /// The contract whose code is replaced.
+    contract_id: ContractId,
+    /// The new bytecode of the contract.
+    bytecode: HexString,
+}
+
+impl From<StateOverridesInput> for StateOverrides {
+    fn from(input: StateOverridesInput) -> Self {
+        StateOverrides {
+            coins: input
+                .coins
+                .unwrap_or_default()
+                .into_iter()
+                .map(|coin| Coin {
+                    utxo_id: coin.utxo_id.into(),
+                    owner: coin.owner.into(),
+                    amount: coin.amount.0,
+                    asset_id: coin.asset_id.into(),
+                    maturity: Default::default(),
+                    tx_pointer: Default::default(),
+                })
+                .collect(),
+            contract_state: input
+                .contract_state
+                .unwrap_or_default()
+                .into_iter()
+                .map(|slot| (slot.contract_id.into(), slot.key.into(), slot.value.into()))
+                .collect(),
+            contract_code: input
+                .contract_code
+                .unwrap_or_default()
+                .into_iter()
+                .map(|code| (code.contract_id.into(), code.bytecode.0))
+                .collect(),
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct TxMutation;
 
 #[Object]
 impl TxMutation {
     /// Execute a dry-run of the transaction using a fork of current state, no changes are committed.
+    #[graphql(guard = "RoleGuard::new(ApiRole::SubmitTx)")]
     async fn dry_run(
         &self,
         ctx: &Context<'_>,
@@ -241,6 +426,9 @@ impl TxMutation {
         // This allows for non-existent inputs to be used without signature validation
         // for read-only calls.
         utxo_validation: Option<bool>,
+        // The changes of the state applied only for the dry run, e.g. the coins to fund the
+        // spender or the substituted bytecode of the contract.
+        state_overrides: Option<StateOverridesInput>,
     ) -> async_graphql::Result<Vec<receipt::Receipt>> {
         let block_producer = ctx.data_unchecked::<BlockProducer>();
         let config = ctx.data_unchecked::<Config>();
@@ -248,13 +436,47 @@ impl TxMutation {
         let mut tx = FuelTx::from_bytes(&tx.0)?;
         tx.precompute(&config.consensus_parameters.chain_id)?;
 
-        let receipts = block_producer.dry_run_tx(tx, None, utxo_validation).await?;
+        let overrides = state_overrides.map(Into::into).unwrap_or_default();
+        let receipts = block_producer
+            .dry_run_tx(tx, None, utxo_validation, overrides)
+            .await?;
         Ok(receipts.iter().map(Into::into).collect())
     }
 
+    /// Execute a dry-run of the transaction and return the trace of its execution:
+    /// the contract calls, the executed opcodes, and the registers at the panic.
+    /// Requires the node to be started with the VM tracing enabled.
+    #[graphql(guard = "RoleGuard::new(ApiRole::SubmitTx)")]
+    async fn trace_transaction(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+        // If set to false, disable input utxo validation, overriding the configuration of the node.
+        utxo_validation: Option<bool>,
+        #[graphql(
+            desc = "Return every executed instruction instead of only the opcode counts"
+        )]
+        full_opcode_stream: Option<bool>,
+    ) -> async_graphql::Result<trace::TransactionTrace> {
+        let block_producer = ctx.data_unchecked::<BlockProducer>();
+        let config = ctx.data_unchecked::<Config>();
+        if !config.vm_tracing {
+            return Err(anyhow!("VM tracing must be enabled to use this endpoint").into())
+        }
+
+        let mut tx = FuelTx::from_bytes(&tx.0)?;
+        tx.precompute(&config.consensus_parameters.chain_id)?;
+
+        let trace = block_producer
+            .trace_tx(tx, utxo_validation, full_opcode_stream.unwrap_or(false))
+            .await?;
+        Ok(trace::TransactionTrace(trace))
+    }
+
     /// Submits transaction to the `TxPool`.
     ///
     /// Returns submitted transaction if the transaction is included in the `TxPool` without problems.
+    #[graphql(guard = "RoleGuard::new(ApiRole::SubmitTx)")]
     async fn submit(
         &self,
         ctx: &Context<'_>,
@@ -274,6 +496,65 @@ impl TxMutation {
         let tx = Transaction(tx, id);
         Ok(tx)
     }
+
+    /// Submits transaction to the `TxPool` without broadcasting it to the network,
+    /// so only this node can include it into the block.
+    ///
+    /// Returns submitted transaction if the transaction is included in the `TxPool` without problems.
+    #[graphql(guard = "RoleGuard::new(ApiRole::SubmitTx)")]
+    async fn submit_local(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+    ) -> async_graphql::Result<Transaction> {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let config = ctx.data_unchecked::<Config>();
+        let tx = FuelTx::from_bytes(&tx.0)?;
+
+        let _: Vec<_> = txpool
+            .insert_local(vec![Arc::new(tx.clone())])
+            .await
+            .into_iter()
+            .try_collect()?;
+        let id = tx.id(&config.consensus_parameters.chain_id);
+
+        let tx = Transaction(tx, id);
+        Ok(tx)
+    }
+
+    /// Submits the batch of transactions to the `TxPool` in their order, so later
+    /// transactions may depend on the earlier ones.
+    ///
+    /// The whole batch is rejected if some transaction depends on the transaction that
+    /// follows it. Otherwise, returns the result of the insertion of each transaction.
+    #[graphql(guard = "RoleGuard::new(ApiRole::SubmitTx)")]
+    async fn submit_batch(
+        &self,
+        ctx: &Context<'_>,
+        txs: Vec<HexString>,
+    ) -> async_graphql::Result<Vec<BatchSubmitResult>> {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let config = ctx.data_unchecked::<Config>();
+        let txs: Vec<_> = txs
+            .iter()
+            .map(|tx| FuelTx::from_bytes(&tx.0).map(Arc::new))
+            .try_collect()?;
+        let ids: Vec<_> = txs
+            .iter()
+            .map(|tx| tx.id(&config.consensus_parameters.chain_id))
+            .collect();
+
+        let results = txpool.insert_batch(txs).await?;
+        let results = ids
+            .into_iter()
+            .zip(results)
+            .map(|(id, result)| BatchSubmitResult {
+                id,
+                error: result.err().map(|err| err.to_string()),
+            })
+            .collect();
+        Ok(results)
+    }
 }
 
 #[derive(Default)]
@@ -321,7 +602,38 @@ impl TxStatusSubscription {
         .map_err(async_graphql::Error::from)
     }
 
+    /// Returns a stream of status updates for all transactions with the given ids.
+    /// Unlike `statusChange`, the stream yields only the updates that happened
+    /// after the subscription and doesn't end after the final status of a transaction.
+    ///
+    /// Updates are skipped if the stream is polled slower than they arrive.
+    async fn status_changes<'a>(
+        &self,
+        ctx: &Context<'a>,
+        #[graphql(desc = "The IDs of the transactions")] ids: Vec<TransactionId>,
+    ) -> impl Stream<Item = async_graphql::Result<TransactionStatusUpdate>> + 'a {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let ids = ids.into_iter().map(Into::into).collect();
+        txpool
+            .tx_update_subscribe_filtered(TxStatusFilter::Ids(ids))
+            .map(status_update)
+    }
+
+    /// Returns a stream of status updates for all transactions spending the coins
+    /// or the messages of the `owner`. The stream behaves the same as `statusChanges`.
+    async fn status_changes_by_owner<'a>(
+        &self,
+        ctx: &Context<'a>,
+        #[graphql(desc = "The owner of the inputs of the transactions")] owner: Address,
+    ) -> impl Stream<Item = async_graphql::Result<TransactionStatusUpdate>> + 'a {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        txpool
+            .tx_update_subscribe_filtered(TxStatusFilter::Owner(owner.into()))
+            .map(status_update)
+    }
+
     /// Submits transaction to the `TxPool` and await either confirmation or failure.
+    #[graphql(guard = "RoleGuard::new(ApiRole::SubmitTx)")]
     async fn submit_and_await<'a>(
         &self,
         ctx: &Context<'a>,
@@ -357,3 +669,17 @@ impl TxStatusSubscription {
             .take(1))
     }
 }
+
+fn status_update(
+    update: TxStatusUpdate,
+) -> async_graphql::Result<TransactionStatusUpdate> {
+    match update.message {
+        TxStatusMessage::Status(status) => Ok(TransactionStatusUpdate {
+            id: update.tx_id,
+            status: status.into(),
+        }),
+        TxStatusMessage::FailedStatus => {
+            Err(anyhow::anyhow!("Failed to get transaction status").into())
+        }
+    }
+}
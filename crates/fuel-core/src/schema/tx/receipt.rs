@@ -7,17 +7,21 @@ use crate::schema::{
         ContractId,
         HexString,
         Nonce,
+        TransactionId,
+        U32,
         U64,
     },
 };
 use async_graphql::{
     Enum,
+    InputObject,
     Object,
 };
 use derive_more::Display;
 use fuel_core_types::{
     fuel_asm::Word,
     fuel_tx,
+    services::graphql_api,
 };
 
 #[derive(Copy, Clone, Debug, Display, Enum, Eq, PartialEq, strum_macros::EnumIter)]
@@ -264,3 +268,35 @@ pub fn all_receipts() -> Vec<fuel_tx::Receipt> {
     }
     receipts
 }
+
+/// The receipt emitted by the contract with the transaction that produced it.
+pub struct ContractReceipt(pub(crate) graphql_api::ContractReceipt);
+
+#[Object]
+impl ContractReceipt {
+    /// The height of the block with the transaction.
+    async fn block_height(&self) -> U32 {
+        self.0.pointer.block_height.into()
+    }
+
+    /// The transaction that produced the receipt.
+    async fn transaction_id(&self) -> TransactionId {
+        self.0.tx_id.into()
+    }
+
+    async fn receipt(&self) -> Receipt {
+        Receipt(self.0.receipt.clone())
+    }
+}
+
+#[derive(InputObject)]
+pub struct ReceiptFilterInput {
+    /// Filter the receipts emitted by the contract.
+    pub(crate) contract: ContractId,
+    /// Filter the receipts of the type.
+    pub(crate) receipt_type: Option<ReceiptType>,
+    /// The lowest height of the blocks, inclusive.
+    pub(crate) start_height: Option<U32>,
+    /// The highest height of the blocks, inclusive.
+    pub(crate) end_height: Option<U32>,
+}
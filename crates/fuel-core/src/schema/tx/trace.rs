@@ -0,0 +1,142 @@
+use crate::schema::{
+    scalars::{
+        AssetId,
+        ContractId,
+        U32,
+        U64,
+    },
+    tx::receipt::Receipt,
+};
+use async_graphql::{
+    Enum,
+    Object,
+    SimpleObject,
+};
+use fuel_core_types::services::executor;
+
+pub struct TransactionTrace(pub executor::TransactionTrace);
+
+#[Object]
+impl TransactionTrace {
+    /// The receipts produced by the transaction.
+    async fn receipts(&self) -> Vec<Receipt> {
+        self.0.receipts.iter().map(Into::into).collect()
+    }
+
+    /// The contract calls made by the transaction in the order of the calls.
+    async fn frames(&self) -> Vec<CallFrameTrace> {
+        self.0.frames.iter().cloned().map(CallFrameTrace).collect()
+    }
+
+    /// The number of executions of each opcode, sorted by the opcode name.
+    async fn opcode_counts(&self) -> Vec<OpcodeCount> {
+        self.0
+            .opcode_counts
+            .iter()
+            .map(|(opcode, count)| OpcodeCount {
+                opcode: opcode.clone(),
+                count: (*count).into(),
+            })
+            .collect()
+    }
+
+    /// All executed instructions in the order of the execution.
+    /// Only returned if the full opcode stream was requested.
+    async fn opcodes(&self) -> Option<Vec<TracedInstruction>> {
+        self.0
+            .opcodes
+            .as_ref()
+            .map(|opcodes| opcodes.iter().cloned().map(TracedInstruction).collect())
+    }
+
+    /// The values of the registers at the moment of the panic.
+    /// Only returned if the transaction panicked.
+    async fn panic_registers(&self) -> Option<Vec<U64>> {
+        self.0
+            .panic_registers
+            .as_ref()
+            .map(|registers| registers.iter().copied().map(Into::into).collect())
+    }
+}
+
+pub struct CallFrameTrace(executor::CallFrameTrace);
+
+#[Object]
+impl CallFrameTrace {
+    /// The nesting level of the call, where the calls from the script are at zero.
+    async fn depth(&self) -> U32 {
+        self.0.depth.into()
+    }
+
+    /// The called contract.
+    async fn contract_id(&self) -> ContractId {
+        self.0.contract_id.into()
+    }
+
+    /// The asset forwarded to the contract.
+    async fn asset_id(&self) -> AssetId {
+        self.0.asset_id.into()
+    }
+
+    /// The amount of the forwarded asset.
+    async fn amount(&self) -> U64 {
+        self.0.amount.into()
+    }
+
+    /// The gas forwarded to the contract.
+    async fn gas(&self) -> U64 {
+        self.0.gas.into()
+    }
+
+    /// How the call ended.
+    async fn outcome(&self) -> CallOutcome {
+        self.0.outcome.into()
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum CallOutcome {
+    Return,
+    Panic,
+    Revert,
+    Unfinished,
+}
+
+impl From<executor::CallOutcome> for CallOutcome {
+    fn from(outcome: executor::CallOutcome) -> Self {
+        match outcome {
+            executor::CallOutcome::Return => CallOutcome::Return,
+            executor::CallOutcome::Panic => CallOutcome::Panic,
+            executor::CallOutcome::Revert => CallOutcome::Revert,
+            executor::CallOutcome::Unfinished => CallOutcome::Unfinished,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct OpcodeCount {
+    /// The name of the opcode.
+    opcode: String,
+    /// How many times the opcode was executed.
+    count: U64,
+}
+
+pub struct TracedInstruction(executor::TracedInstruction);
+
+#[Object]
+impl TracedInstruction {
+    /// The program counter of the instruction.
+    async fn pc(&self) -> U64 {
+        self.0.pc.into()
+    }
+
+    /// The start of the executed code, the script or the called contract.
+    async fn is(&self) -> U64 {
+        self.0.is.into()
+    }
+
+    /// The name of the opcode, or `null` if the instruction can't be decoded.
+    async fn opcode(&self) -> Option<String> {
+        self.0.opcode.clone()
+    }
+}
@@ -198,6 +198,40 @@ impl SqueezedOutStatus {
     }
 }
 
+pub struct BatchSubmitResult {
+    pub id: fuel_tx::TxId,
+    pub error: Option<String>,
+}
+
+#[Object]
+impl BatchSubmitResult {
+    async fn id(&self) -> TransactionId {
+        TransactionId(self.id)
+    }
+
+    /// The reason why the transaction was not inserted into the `TxPool`.
+    /// It is `null` if the transaction was inserted.
+    async fn error(&self) -> Option<String> {
+        self.error.clone()
+    }
+}
+
+pub struct TransactionStatusUpdate {
+    pub id: fuel_tx::TxId,
+    pub status: TransactionStatus,
+}
+
+#[Object]
+impl TransactionStatusUpdate {
+    async fn id(&self) -> TransactionId {
+        TransactionId(self.id)
+    }
+
+    async fn status(&self) -> &TransactionStatus {
+        &self.status
+    }
+}
+
 impl From<TxStatus> for TransactionStatus {
     fn from(s: TxStatus) -> Self {
         match s {
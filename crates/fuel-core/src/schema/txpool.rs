@@ -0,0 +1,126 @@
+use crate::{
+    fuel_core_graphql_api::service::TxPool,
+    schema::{
+        scalars::{
+            Address,
+            TransactionId,
+            U64,
+        },
+        tx::types::Transaction,
+    },
+};
+use async_graphql::{
+    connection::{
+        Connection,
+        EmptyFields,
+    },
+    Context,
+    Object,
+};
+use fuel_core_storage::iter::IterDirection;
+
+pub struct TxPoolInfo {
+    pending: U64,
+    total_gas: U64,
+    owners: Vec<TxPoolOwnerInfo>,
+}
+
+#[Object]
+impl TxPoolInfo {
+    /// The number of pending transactions in the pool.
+    async fn pending(&self) -> U64 {
+        self.pending
+    }
+
+    /// The amount of gas in all pending transactions combined.
+    async fn total_gas(&self) -> U64 {
+        self.total_gas
+    }
+
+    /// The number of pending transactions per owner of their inputs.
+    async fn owners(&self) -> &[TxPoolOwnerInfo] {
+        &self.owners
+    }
+}
+
+pub struct TxPoolOwnerInfo {
+    owner: Address,
+    pending: U64,
+}
+
+#[Object]
+impl TxPoolOwnerInfo {
+    async fn owner(&self) -> Address {
+        self.owner
+    }
+
+    /// The number of pending transactions spending the inputs of the owner.
+    async fn pending(&self) -> U64 {
+        self.pending
+    }
+}
+
+#[derive(Default)]
+pub struct TxPoolQuery;
+
+#[Object]
+impl TxPoolQuery {
+    /// Returns the pending transactions of the `TxPool` sorted by their ids.
+    #[graphql(
+        complexity = "crate::schema::connection_complexity(child_complexity, first, last)"
+    )]
+    async fn tx_pool_transactions(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<TransactionId, Transaction, EmptyFields, EmptyFields>,
+    > {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<TransactionId>, direction| {
+                let start = start.map(|id| id.0);
+                let mut txs = txpool.pending_transactions();
+                if direction == IterDirection::Reverse {
+                    txs.reverse();
+                }
+                let txs = txs
+                    .into_iter()
+                    .skip_while(move |(id, _)| match (start, direction) {
+                        (Some(start), IterDirection::Forward) => id < &start,
+                        (Some(start), IterDirection::Reverse) => id > &start,
+                        (None, _) => false,
+                    })
+                    .map(|(id, tx)| Ok((id.into(), Transaction(tx, id))));
+                Ok(txs)
+            },
+        )
+        .await
+    }
+
+    /// Returns the summary of the current content of the `TxPool`.
+    async fn tx_pool_info(&self, ctx: &Context<'_>) -> TxPoolInfo {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let owners = txpool
+            .pending_number_by_owner()
+            .into_iter()
+            .map(|(owner, pending)| TxPoolOwnerInfo {
+                owner: owner.into(),
+                pending: (pending as u64).into(),
+            })
+            .collect();
+
+        TxPoolInfo {
+            pending: (txpool.pending_number() as u64).into(),
+            total_gas: txpool.total_consumable_gas().into(),
+            owners,
+        }
+    }
+}
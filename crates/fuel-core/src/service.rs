@@ -13,8 +13,12 @@ use std::net::SocketAddr;
 use tracing::warn;
 
 pub use config::{
+    CheckpointConfig,
+    CompactionConfig,
     Config,
     DbType,
+    PruningConfig,
+    TieringConfig,
     VMConfig,
 };
 pub use fuel_core_services::Service as ServiceTrait;
@@ -24,11 +28,17 @@ pub use fuel_core_consensus_module::RelayerVerifierConfig;
 use self::adapters::BlockImporterAdapter;
 
 pub mod adapters;
+pub mod chain_stats;
+pub mod compactor;
 pub mod config;
+pub mod gas_price;
 pub mod genesis;
 pub mod metrics;
+pub mod pruner;
 mod query;
+pub mod state_diff_sink;
 pub mod sub_services;
+pub mod tiering;
 
 #[derive(Clone)]
 pub struct SharedState {
@@ -37,6 +47,9 @@ pub struct SharedState {
     /// The P2P network shared state.
     #[cfg(feature = "p2p")]
     pub network: Option<fuel_core_p2p::service::SharedState>,
+    /// The sync shared state.
+    #[cfg(feature = "p2p")]
+    pub sync: Option<fuel_core_sync::service::SharedState>,
     #[cfg(feature = "relayer")]
     /// The Relayer shared state.
     pub relayer: Option<fuel_core_relayer::SharedState<Database>>,
@@ -81,7 +94,15 @@ impl FuelService {
     }
 
     /// Creates and starts fuel node instance from service config
-    pub async fn new_node(config: Config) -> anyhow::Result<Self> {
+    pub async fn new_node(mut config: Config) -> anyhow::Result<Self> {
+        if config.tiering.is_some()
+            && (config.database_type == DbType::InMemory
+                || config.database_path.as_os_str().is_empty())
+        {
+            warn!("The cold storage requires the RocksDB path, the tiering is disabled");
+            config.tiering = None;
+        }
+
         // initialize database
         let database = match config.database_type {
             #[cfg(feature = "rocksdb")]
@@ -92,8 +113,19 @@ impl FuelService {
                         "No RocksDB path configured, initializing database with a tmp directory"
                     );
                     Database::default()
+                } else if let Some(tiering) = &config.tiering {
+                    Database::open_tiered(
+                        &config.database_path,
+                        &tiering.path,
+                        config.max_database_cache_size,
+                        &config.rocksdb,
+                    )?
                 } else {
-                    Database::open(&config.database_path, config.max_database_cache_size)?
+                    Database::open(
+                        &config.database_path,
+                        config.max_database_cache_size,
+                        &config.rocksdb,
+                    )?
                 }
             }
             DbType::InMemory => Database::in_memory(),
@@ -147,6 +179,10 @@ impl FuelService {
             warn!("The `utxo_validation` of `BlockProducer` was inconsistent");
             config.block_producer.utxo_validation = config.utxo_validation;
         }
+        if config.state_diff_sink.is_some() && !config.block_importer.state_diff {
+            warn!("The `state_diff` of `BlockImporter` was inconsistent with the sink");
+            config.block_importer.state_diff = true;
+        }
     }
 }
 
@@ -1,11 +1,20 @@
 use crate::{
     database::Database,
+    executor::ExecutorCache,
     service::sub_services::BlockProducerService,
 };
 use fuel_core_consensus_module::block_verifier::Verifier;
+use fuel_core_producer::TxSelection;
 use fuel_core_txpool::service::SharedState as TxPoolSharedState;
-use fuel_core_types::fuel_types::BlockHeight;
-use std::sync::Arc;
+use fuel_core_types::{
+    fuel_tx::TxId,
+    fuel_types::BlockHeight,
+};
+use parking_lot::Mutex;
+use std::{
+    collections::HashSet,
+    sync::Arc,
+};
 
 pub mod block_importer;
 pub mod consensus_module;
@@ -14,6 +23,7 @@ pub mod graphql_api;
 #[cfg(feature = "p2p")]
 pub mod p2p;
 pub mod producer;
+pub mod pruner;
 #[cfg(feature = "p2p")]
 pub mod sync;
 pub mod txpool;
@@ -38,24 +48,38 @@ impl TxPoolAdapter {
 pub struct TransactionsSource {
     txpool: TxPoolSharedState<P2PAdapter, Database>,
     _block_height: BlockHeight,
+    selection: TxSelection,
+    /// The transactions already returned by the dry run source. `None` if the
+    /// source removes the returned transactions from the pool.
+    dry_run_selected: Option<Arc<Mutex<HashSet<TxId>>>>,
 }
 
 impl TransactionsSource {
     pub fn new(
         txpool: TxPoolSharedState<P2PAdapter, Database>,
         block_height: BlockHeight,
+        selection: TxSelection,
     ) -> Self {
         Self {
             txpool,
             _block_height: block_height,
+            selection,
+            dry_run_selected: None,
         }
     }
+
+    /// Leaves the returned transactions in the pool.
+    pub fn dry_run(mut self) -> Self {
+        self.dry_run_selected = Some(Default::default());
+        self
+    }
 }
 
 #[derive(Clone)]
 pub struct ExecutorAdapter {
     pub relayer: MaybeRelayerAdapter,
     pub config: Arc<fuel_core_executor::Config>,
+    pub cache: Arc<ExecutorCache>,
 }
 
 #[derive(Clone)]
@@ -72,6 +96,14 @@ pub struct MaybeRelayerAdapter {
     pub da_deploy_height: fuel_core_types::blockchain::primitives::DaBlockHeight,
 }
 
+#[derive(Clone)]
+pub struct HealthAdapter {
+    pub database: Database,
+    pub p2p: P2PAdapter,
+    #[cfg(feature = "relayer")]
+    pub relayer: Option<fuel_core_relayer::SharedState<Database>>,
+}
+
 #[derive(Clone)]
 pub struct BlockProducerAdapter {
     pub block_producer: Arc<BlockProducerService>,
@@ -81,6 +113,7 @@ pub struct BlockProducerAdapter {
 pub struct BlockImporterAdapter {
     pub block_importer:
         Arc<fuel_core_importer::Importer<Database, ExecutorAdapter, VerifierAdapter>>,
+    database: Database,
 }
 
 #[cfg(feature = "p2p")]
@@ -106,3 +139,27 @@ impl P2PAdapter {
         Default::default()
     }
 }
+
+#[cfg(feature = "p2p")]
+#[derive(Clone)]
+pub struct SyncAdapter {
+    shared_state: Option<fuel_core_sync::service::SharedState>,
+}
+
+#[cfg(not(feature = "p2p"))]
+#[derive(Default, Clone)]
+pub struct SyncAdapter;
+
+#[cfg(feature = "p2p")]
+impl SyncAdapter {
+    pub fn new(shared_state: Option<fuel_core_sync::service::SharedState>) -> Self {
+        Self { shared_state }
+    }
+}
+
+#[cfg(not(feature = "p2p"))]
+impl SyncAdapter {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
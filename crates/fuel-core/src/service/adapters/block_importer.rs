@@ -18,26 +18,41 @@ use fuel_core_importer::{
 };
 use fuel_core_poa::ports::RelayerPort;
 use fuel_core_storage::{
-    tables::SealedBlockConsensus,
-    transactional::StorageTransaction,
+    tables::{
+        FuelBlocks,
+        SealedBlockConsensus,
+    },
+    transactional::{
+        StorageTransaction,
+        Transaction as StorageTransactionTrait,
+    },
     Result as StorageResult,
     StorageAsMut,
 };
 use fuel_core_types::{
     blockchain::{
-        block::Block,
+        block::{
+            Block,
+            CompressedBlock,
+        },
         consensus::Consensus,
+        header::BlockHeader,
         primitives::{
             BlockId,
             DaBlockHeight,
         },
         SealedBlock,
+        SealedBlockHeader,
     },
+    fuel_tx::TxId,
     fuel_types::BlockHeight,
-    services::executor::{
-        ExecutionTypes,
-        Result as ExecutorResult,
-        UncommittedResult as UncommittedExecutionResult,
+    services::{
+        block_importer::StateDiff,
+        executor::{
+            ExecutionTypes,
+            Result as ExecutorResult,
+            UncommittedResult as UncommittedExecutionResult,
+        },
     },
 };
 use std::sync::Arc;
@@ -52,7 +67,13 @@ impl BlockImporterAdapter {
         verifier: VerifierAdapter,
     ) -> Self {
         Self {
-            block_importer: Arc::new(Importer::new(config, database, executor, verifier)),
+            block_importer: Arc::new(Importer::new(
+                config,
+                database.clone(),
+                executor,
+                verifier,
+            )),
+            database,
         }
     }
 
@@ -67,6 +88,31 @@ impl BlockImporterAdapter {
         .await??;
         Ok(())
     }
+
+    pub async fn rollback_to(&self, common_ancestor: BlockHeight) -> anyhow::Result<()> {
+        tokio::task::spawn_blocking({
+            let importer = self.block_importer.clone();
+            move || importer.rollback_to(common_ancestor)
+        })
+        .await??;
+        Ok(())
+    }
+
+    pub async fn commit_header(
+        &self,
+        sealed_header: SealedBlockHeader,
+    ) -> anyhow::Result<()> {
+        tokio::task::spawn_blocking({
+            let importer = self.block_importer.clone();
+            move || importer.verify_and_commit_header(sealed_header)
+        })
+        .await??;
+        Ok(())
+    }
+
+    pub fn block_id(&self, height: &BlockHeight) -> anyhow::Result<Option<BlockId>> {
+        Ok(self.database.get_block_id(height)?)
+    }
 }
 
 impl BlockVerifier for VerifierAdapter {
@@ -77,6 +123,14 @@ impl BlockVerifier for VerifierAdapter {
     ) -> anyhow::Result<()> {
         self.block_verifier.verify_block_fields(consensus, block)
     }
+
+    fn verify_header_fields(
+        &self,
+        consensus: &Consensus,
+        header: &BlockHeader,
+    ) -> anyhow::Result<()> {
+        self.block_verifier.verify_header_fields(consensus, header)
+    }
 }
 
 #[async_trait::async_trait]
@@ -113,6 +167,26 @@ impl ImporterDatabase for Database {
     fn latest_block_height(&self) -> StorageResult<BlockHeight> {
         self.latest_height()
     }
+
+    fn rollback_last_block(&self) -> StorageResult<SealedBlock> {
+        Database::rollback_last_block(self)
+    }
+
+    fn store_sealed_header(
+        &self,
+        header: &SealedBlockHeader,
+    ) -> StorageResult<Option<Consensus>> {
+        let block = CompressedBlock::from_header(header.entity.clone());
+        let block_id = block.id();
+        let mut transaction = self.transaction();
+        let database = transaction.as_mut();
+        database.storage::<FuelBlocks>().insert(&block_id, &block)?;
+        let previous = database
+            .storage::<SealedBlockConsensus>()
+            .insert(&block_id, &header.consensus)?;
+        transaction.commit()?;
+        Ok(previous)
+    }
 }
 
 impl ExecutorDatabase for Database {
@@ -125,6 +199,30 @@ impl ExecutorDatabase for Database {
             .insert(block_id, consensus)
             .map_err(Into::into)
     }
+
+    fn store_rollback_changes(
+        &mut self,
+        height: &BlockHeight,
+        max_depth: u32,
+    ) -> StorageResult<()> {
+        Database::store_rollback_changes(self, height, max_depth).map_err(Into::into)
+    }
+
+    fn store_state_diff(&mut self, height: &BlockHeight) -> StorageResult<StateDiff> {
+        Database::store_state_diff(self, height).map_err(Into::into)
+    }
+
+    fn index_receipts(
+        &mut self,
+        height: &BlockHeight,
+        tx_ids: &[TxId],
+    ) -> StorageResult<()> {
+        Database::index_receipts(self, height, tx_ids).map_err(Into::into)
+    }
+
+    fn index_assets(&mut self, tx_ids: &[TxId]) -> StorageResult<()> {
+        Database::index_assets(self, tx_ids).map_err(Into::into)
+    }
 }
 
 impl Executor for ExecutorAdapter {
@@ -60,6 +60,22 @@ impl ConsensusModulePort for PoAAdapter {
             .manually_produce_block(start_time, number_of_blocks)
             .await
     }
+
+    async fn pause_block_production(&self) -> anyhow::Result<()> {
+        self.shared_state
+            .as_ref()
+            .ok_or(anyhow!("The block production is disabled"))?
+            .pause_block_production()
+            .await
+    }
+
+    async fn resume_block_production(&self) -> anyhow::Result<()> {
+        self.shared_state
+            .as_ref()
+            .ok_or(anyhow!("The block production is disabled"))?
+            .resume_block_production()
+            .await
+    }
 }
 
 impl TransactionPool for TxPoolAdapter {
@@ -71,6 +87,10 @@ impl TransactionPool for TxPoolAdapter {
         self.service.total_consumable_gas()
     }
 
+    fn total_bytes_size(&self) -> usize {
+        self.service.total_bytes_size()
+    }
+
     fn remove_txs(&self, ids: Vec<TxId>) -> Vec<ArcPoolTx> {
         self.service.remove_txs(ids)
     }
@@ -12,10 +12,15 @@ use crate::{
     },
 };
 use fuel_core_executor::refs::ContractStorageTrait;
+use fuel_core_producer::tx_selector::{
+    order_transactions,
+    TxCandidate,
+};
 use fuel_core_storage::{
     transactional::StorageTransaction,
     Error as StorageError,
 };
+use fuel_core_txpool::TxInfo;
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
     entities::message::Message,
@@ -26,6 +31,8 @@ use fuel_core_types::{
         block_producer::Components,
         executor::{
             Result as ExecutorResult,
+            StateOverrides,
+            TransactionTrace,
             UncommittedResult,
         },
     },
@@ -33,9 +40,29 @@ use fuel_core_types::{
 
 impl crate::executor::TransactionsSource for TransactionsSource {
     fn next(&self, gas_limit: u64) -> Vec<MaybeCheckedTransaction> {
-        self.txpool
-            .select_transactions(gas_limit)
-            .into_iter()
+        let order = |txs: Vec<TxInfo>| {
+            let candidates = txs
+                .into_iter()
+                .filter(|tx| match &self.dry_run_selected {
+                    Some(selected) => !selected.lock().contains(&tx.tx().id()),
+                    None => true,
+                })
+                .map(|tx| TxCandidate {
+                    tx: tx.tx().clone(),
+                    submitted_time: tx.submitted_time(),
+                })
+                .collect();
+            order_transactions(&self.selection, candidates)
+        };
+        let txs = match &self.dry_run_selected {
+            Some(selected) => {
+                let txs = self.txpool.peek_transactions_ordered_by(gas_limit, order);
+                selected.lock().extend(txs.iter().map(|tx| tx.id()));
+                txs
+            }
+            None => self.txpool.select_transactions_ordered_by(gas_limit, order),
+        };
+        txs.into_iter()
             .map(|tx| MaybeCheckedTransaction::CheckedTransaction(tx.as_ref().into()))
             .collect()
     }
@@ -50,6 +77,7 @@ impl ExecutorAdapter {
             database: self.relayer.database.clone(),
             relayer: self.relayer.clone(),
             config: self.config.clone(),
+            cache: self.cache.clone(),
         };
         executor.execute_without_commit(block, self.config.as_ref().into())
     }
@@ -58,13 +86,38 @@ impl ExecutorAdapter {
         &self,
         block: Components<fuel_tx::Transaction>,
         utxo_validation: Option<bool>,
+        overrides: StateOverrides,
     ) -> ExecutorResult<Vec<Vec<Receipt>>> {
         let executor = Executor {
             database: self.relayer.database.clone(),
             relayer: self.relayer.clone(),
             config: self.config.clone(),
+            cache: self.cache.clone(),
         };
-        executor.dry_run(block, utxo_validation)
+        executor.dry_run(block, utxo_validation, overrides)
+    }
+
+    pub(crate) fn _trace(
+        &self,
+        block: Components<fuel_tx::Transaction>,
+        utxo_validation: Option<bool>,
+        full_opcode_stream: bool,
+    ) -> ExecutorResult<TransactionTrace> {
+        #[cfg(feature = "debug")]
+        {
+            let executor = Executor {
+                database: self.relayer.database.clone(),
+                relayer: self.relayer.clone(),
+                config: self.config.clone(),
+                cache: self.cache.clone(),
+            };
+            executor.trace(block, utxo_validation, full_opcode_stream)
+        }
+        #[cfg(not(feature = "debug"))]
+        {
+            let _ = (block, utxo_validation, full_opcode_stream);
+            Err(fuel_core_types::services::executor::Error::TracingNotSupported)
+        }
     }
 }
 
@@ -1,10 +1,15 @@
 use crate::{
     database::{
         transactions::OwnedTransactionIndexCursor,
+        Column,
         Database,
     },
     fuel_core_graphql_api::ports::{
+        BlockImporterPort,
         BlockProducerPort,
+        ChainStatsPort,
+        DatabaseAdmin,
+        DatabaseAssets,
         DatabaseBlocks,
         DatabaseChain,
         DatabaseCoins,
@@ -14,9 +19,21 @@ use crate::{
         DatabasePort,
         DatabaseTransactions,
         DryRunExecution,
+        HealthPort,
+        P2pPort,
+        SyncPort,
         TxPoolPort,
     },
-    service::adapters::TxPoolAdapter,
+    service::{
+        adapters::{
+            BlockImporterAdapter,
+            HealthAdapter,
+            P2PAdapter,
+            SyncAdapter,
+            TxPoolAdapter,
+        },
+        chain_stats::RecentBlocks,
+    },
 };
 use async_trait::async_trait;
 use fuel_core_services::stream::{
@@ -30,20 +47,29 @@ use fuel_core_storage::{
         IterDirection,
     },
     not_found,
+    tables::Receipts,
     Error as StorageError,
     Result as StorageResult,
+    StorageAsRef,
 };
 use fuel_core_txpool::{
-    service::TxStatusMessage,
+    service::{
+        TxStatusFilter,
+        TxStatusMessage,
+        TxStatusUpdate,
+    },
     types::{
         ContractId,
         TxId,
     },
 };
 use fuel_core_types::{
-    blockchain::primitives::{
-        BlockId,
-        DaBlockHeight,
+    blockchain::{
+        header::BlockHeader,
+        primitives::{
+            BlockId,
+            DaBlockHeight,
+        },
     },
     entities::message::{
         MerkleProof,
@@ -55,14 +81,38 @@ use fuel_core_types::{
         Receipt as TxReceipt,
         Transaction,
         TxPointer,
+        UniqueIdentifier,
         UtxoId,
     },
     fuel_types::{
         BlockHeight,
+        Bytes32,
+        ChainId,
         Nonce,
+        Word,
     },
     services::{
-        graphql_api::ContractBalance,
+        block_importer::{
+            CoinEvent,
+            StateDiff,
+        },
+        block_producer::BlockDryRun,
+        executor::{
+            StateOverrides,
+            TransactionTrace,
+        },
+        graphql_api::{
+            AddressBalance,
+            AssetSupply,
+            ChainStats,
+            ContractBalance,
+            ContractReceipt,
+            ReceiptPointer,
+        },
+        p2p::{
+            PeerId,
+            PeerInfo,
+        },
         txpool::{
             InsertionResult,
             TransactionStatus,
@@ -71,7 +121,9 @@ use fuel_core_types::{
     tai64::Tai64,
 };
 use std::{
+    collections::HashSet,
     ops::Deref,
+    path::Path,
     sync::Arc,
 };
 
@@ -97,6 +149,10 @@ impl DatabaseBlocks for Database {
             .transpose()
             .ok_or(not_found!("BlockId"))??)
     }
+
+    fn state_diff(&self, height: &BlockHeight) -> StorageResult<Option<StateDiff>> {
+        Ok(self.get_state_diff(height)?)
+    }
 }
 
 impl DatabaseTransactions for Database {
@@ -121,6 +177,17 @@ impl DatabaseTransactions for Database {
             .map(|result| result.map_err(StorageError::from))
             .into_boxed()
     }
+
+    fn contract_receipts(
+        &self,
+        contract: ContractId,
+        start: Option<ReceiptPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<ContractReceipt>> {
+        Database::contract_receipts(self, contract, start, Some(direction))
+            .map(|result| result.map_err(StorageError::from))
+            .into_boxed()
+    }
 }
 
 impl DatabaseMessages for Database {
@@ -178,6 +245,34 @@ impl DatabaseContracts for Database {
             })
             .into_boxed()
     }
+
+    fn contract_slots(
+        &self,
+        contract: ContractId,
+        start_key: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(Bytes32, Bytes32)>> {
+        self.contract_slots(contract, start_key, Some(direction))
+            .map(|result| result.map_err(StorageError::from))
+            .into_boxed()
+    }
+}
+
+impl DatabaseAssets for Database {
+    fn asset_supply(&self, asset_id: &AssetId) -> StorageResult<Option<AssetSupply>> {
+        Database::asset_supply(self, asset_id).map_err(Into::into)
+    }
+
+    fn asset_holders(
+        &self,
+        asset_id: AssetId,
+        start: Option<(u64, Address)>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<AddressBalance>> {
+        Database::asset_holders(self, asset_id, start, Some(direction))
+            .map(|result| result.map_err(StorageError::from))
+            .into_boxed()
+    }
 }
 
 impl DatabaseChain for Database {
@@ -202,7 +297,42 @@ impl DatabaseChain for Database {
     }
 }
 
-impl DatabasePort for Database {}
+impl DatabaseAdmin for Database {
+    fn start_compaction(&self, column: Option<Column>) -> anyhow::Result<()> {
+        let database = self.clone();
+        std::thread::Builder::new()
+            .name("database-compaction".to_string())
+            .spawn(move || {
+                tracing::info!("Starting the compaction of the database");
+                match database.compact(column) {
+                    Ok(()) => {
+                        tracing::info!("The compaction of the database is finished")
+                    }
+                    Err(err) => {
+                        tracing::error!("The compaction of the database failed: {}", err)
+                    }
+                }
+            })?;
+        Ok(())
+    }
+
+    fn create_checkpoint(&self, path: &Path) -> anyhow::Result<()> {
+        self.checkpoint(path)?;
+        tracing::info!("The checkpoint of the database is created at {:?}", path);
+        Ok(())
+    }
+}
+
+impl DatabasePort for Database {
+    fn view_at(
+        &self,
+        height: &BlockHeight,
+        max_depth: u32,
+    ) -> StorageResult<Box<dyn DatabasePort>> {
+        let view = self.state_at(height, max_depth)?;
+        Ok(Box::new(view.as_ref().clone()))
+    }
+}
 
 #[async_trait]
 impl TxPoolPort for TxPoolAdapter {
@@ -218,6 +348,35 @@ impl TxPoolPort for TxPoolAdapter {
             .map(|info| Tai64::from_unix(info.submitted_time().as_secs() as i64))
     }
 
+    fn pending_transactions(&self) -> Vec<(TxId, Transaction)> {
+        let mut txs = self
+            .service
+            .pending_txs()
+            .into_iter()
+            .map(|info| (info.tx().id(), info.tx().deref().into()))
+            .collect::<Vec<_>>();
+        txs.sort_by_key(|(id, _)| *id);
+        txs
+    }
+
+    fn pending_number(&self) -> usize {
+        self.service.pending_number()
+    }
+
+    fn total_consumable_gas(&self) -> u64 {
+        self.service.total_consumable_gas()
+    }
+
+    fn pending_number_by_owner(&self) -> Vec<(Address, usize)> {
+        let mut owners = self
+            .service
+            .pending_number_by_owner()
+            .into_iter()
+            .collect::<Vec<_>>();
+        owners.sort_by_key(|(owner, _)| *owner);
+        owners
+    }
+
     async fn insert(
         &self,
         txs: Vec<Arc<Transaction>>,
@@ -225,9 +384,30 @@ impl TxPoolPort for TxPoolAdapter {
         self.service.insert(txs).await
     }
 
+    async fn insert_local(
+        &self,
+        txs: Vec<Arc<Transaction>>,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        self.service.insert_local(txs).await
+    }
+
+    async fn insert_batch(
+        &self,
+        txs: Vec<Arc<Transaction>>,
+    ) -> anyhow::Result<Vec<anyhow::Result<InsertionResult>>> {
+        self.service.insert_batch(txs).await
+    }
+
     fn tx_update_subscribe(&self, id: TxId) -> BoxFuture<BoxStream<TxStatusMessage>> {
         Box::pin(self.service.tx_update_subscribe(id))
     }
+
+    fn tx_update_subscribe_filtered(
+        &self,
+        filter: TxStatusFilter,
+    ) -> BoxStream<TxStatusUpdate> {
+        self.service.tx_update_subscribe_filtered(filter)
+    }
 }
 
 impl DatabaseMessageProof for Database {
@@ -247,13 +427,252 @@ impl DryRunExecution for BlockProducerAdapter {
         transaction: Transaction,
         height: Option<BlockHeight>,
         utxo_validation: Option<bool>,
+        overrides: StateOverrides,
     ) -> anyhow::Result<Vec<TxReceipt>> {
         self.block_producer
-            .dry_run(transaction, height, utxo_validation)
+            .dry_run(transaction, height, utxo_validation, overrides)
+            .await
+    }
+
+    async fn dry_run_block(&self, block_gas_limit: u64) -> anyhow::Result<BlockDryRun> {
+        let (result, database_transaction) = self
+            .block_producer
+            .dry_run_block(block_gas_limit)
+            .await?
+            .into();
+        let database: &Database = database_transaction.as_ref();
+        let chain_id = self
+            .block_producer
+            .executor
+            .config
+            .consensus_parameters
+            .chain_id;
+
+        let mut gas_used: Word = 0;
+        for tx in result.block.transactions() {
+            let receipts = database.storage::<Receipts>().get(&tx.id(&chain_id))?;
+            for receipt in receipts.iter().flat_map(|receipts| receipts.iter()) {
+                if let TxReceipt::ScriptResult { gas_used: used, .. } = receipt {
+                    gas_used = gas_used.saturating_add(*used);
+                }
+            }
+        }
+
+        let included: HashSet<TxId> = result
+            .block
+            .transactions()
+            .iter()
+            .map(|tx| tx.id(&chain_id))
+            .collect();
+        let mut excluded_transactions: Vec<_> = result
+            .skipped_transactions
+            .into_iter()
+            .map(|(id, err)| (id, err.to_string()))
+            .collect();
+        let mut pending_txs = self.block_producer.txpool.service.pending_txs();
+        pending_txs.sort_by_key(|info| info.tx().id());
+        for info in pending_txs {
+            let id = info.tx().id();
+            if included.contains(&id)
+                || excluded_transactions
+                    .iter()
+                    .any(|(skipped, _)| *skipped == id)
+            {
+                continue
+            }
+            excluded_transactions.push((
+                id,
+                "Not selected: the block gas limit is reached or the transaction \
+                 depends on an excluded one"
+                    .to_string(),
+            ));
+        }
+
+        Ok(BlockDryRun {
+            block: result.block,
+            gas_used,
+            excluded_transactions,
+        })
+    }
+
+    async fn trace_tx(
+        &self,
+        transaction: Transaction,
+        utxo_validation: Option<bool>,
+        full_opcode_stream: bool,
+    ) -> anyhow::Result<TransactionTrace> {
+        self.block_producer
+            .trace_tx(transaction, utxo_validation, full_opcode_stream)
             .await
     }
 }
 
-impl BlockProducerPort for BlockProducerAdapter {}
+impl BlockProducerPort for BlockProducerAdapter {
+    fn latest_gas_price(&self) -> u64 {
+        self.block_producer.gas_price.latest_gas_price()
+    }
+
+    fn estimate_gas_price(&self, block_horizon: u32) -> u64 {
+        self.block_producer
+            .gas_price
+            .estimate_gas_price(block_horizon)
+    }
+}
 
 use super::BlockProducerAdapter;
+
+#[cfg(feature = "p2p")]
+#[async_trait::async_trait]
+impl P2pPort for P2PAdapter {
+    async fn all_peer_info(&self) -> anyhow::Result<Vec<PeerInfo>> {
+        if let Some(service) = &self.service {
+            service.get_all_peer_info().await
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    fn disconnect_peer(&self, peer_id: PeerId) -> anyhow::Result<()> {
+        self.service
+            .as_ref()
+            .ok_or(anyhow::anyhow!("The P2P service is disabled"))?
+            .disconnect_peer(peer_id)
+    }
+
+    fn ban_peer(&self, peer_id: PeerId) -> anyhow::Result<()> {
+        self.service
+            .as_ref()
+            .ok_or(anyhow::anyhow!("The P2P service is disabled"))?
+            .ban_peer(peer_id)
+    }
+}
+
+#[cfg(not(feature = "p2p"))]
+#[async_trait::async_trait]
+impl P2pPort for P2PAdapter {
+    async fn all_peer_info(&self) -> anyhow::Result<Vec<PeerInfo>> {
+        Ok(vec![])
+    }
+
+    fn disconnect_peer(&self, _peer_id: PeerId) -> anyhow::Result<()> {
+        Err(anyhow::anyhow!("The P2P service is disabled"))
+    }
+
+    fn ban_peer(&self, _peer_id: PeerId) -> anyhow::Result<()> {
+        Err(anyhow::anyhow!("The P2P service is disabled"))
+    }
+}
+
+#[cfg(feature = "p2p")]
+impl SyncPort for SyncAdapter {
+    fn sync_status(&self) -> Option<SyncStatus> {
+        self.shared_state.as_ref().map(|shared| shared.status())
+    }
+}
+
+#[cfg(not(feature = "p2p"))]
+impl SyncPort for SyncAdapter {
+    fn sync_status(&self) -> Option<SyncStatus> {
+        None
+    }
+}
+
+#[async_trait::async_trait]
+impl HealthPort for HealthAdapter {
+    fn latest_block(&self) -> StorageResult<(BlockHeight, Tai64)> {
+        let block = self
+            .database
+            .get_current_block()?
+            .ok_or(not_found!("FuelBlocks"))?;
+        Ok((*block.header().height(), block.header().time()))
+    }
+
+    fn check_database_writable(&self) -> anyhow::Result<()> {
+        Ok(self.database.check_writable()?)
+    }
+
+    async fn connected_peers(&self) -> anyhow::Result<Option<usize>> {
+        #[cfg(feature = "p2p")]
+        if let Some(service) = &self.p2p.service {
+            return Ok(Some(service.get_all_peer_info().await?.len()))
+        }
+        Ok(None)
+    }
+
+    fn relayer_lag(&self) -> Option<u64> {
+        #[cfg(feature = "relayer")]
+        if let Some(relayer) = &self.relayer {
+            return relayer.da_lag()
+        }
+        None
+    }
+}
+
+impl ChainStatsPort for RecentBlocks {
+    fn chain_stats(&self, window: u32) -> ChainStats {
+        self.stats(window)
+    }
+}
+
+impl BlockImporterPort for BlockImporterAdapter {
+    fn block_headers(&self) -> BoxStream<BlockHeader> {
+        use futures::StreamExt;
+        let blocks =
+            tokio_stream::wrappers::BroadcastStream::new(self.block_importer.subscribe())
+                .filter_map(|r| {
+                    futures::future::ready(
+                        r.ok().map(|r| r.sealed_block.entity.header().clone()),
+                    )
+                });
+        // The light client imports only the headers of the blocks.
+        let headers = tokio_stream::wrappers::BroadcastStream::new(
+            self.block_importer.subscribe_headers(),
+        )
+        .filter_map(|r| futures::future::ready(r.ok().map(|h| h.entity.clone())));
+        fuel_core_services::stream::IntoBoxStream::into_boxed(futures::stream::select(
+            blocks, headers,
+        ))
+    }
+
+    fn coin_events(&self, chain_id: ChainId) -> BoxStream<CoinEvent> {
+        use futures::StreamExt;
+        let imported =
+            tokio_stream::wrappers::BroadcastStream::new(self.block_importer.subscribe())
+                .filter_map(|r| futures::future::ready(r.ok()))
+                .flat_map(|result| {
+                    let tx_ids: Vec<_> =
+                        result.tx_status.iter().map(|status| status.id).collect();
+                    futures::stream::iter(CoinEvent::imported(
+                        &result.sealed_block.entity,
+                        &tx_ids,
+                    ))
+                });
+        let reverted = tokio_stream::wrappers::BroadcastStream::new(
+            self.block_importer.subscribe_reorgs(),
+        )
+        .filter_map(|r| futures::future::ready(r.ok()))
+        .flat_map(move |reorg| {
+            let events: Vec<_> = reorg
+                .reverted_blocks
+                .iter()
+                .flat_map(|block| {
+                    let tx_ids: Vec<_> = block
+                        .entity
+                        .transactions()
+                        .iter()
+                        .map(|tx| tx.id(&chain_id))
+                        .collect();
+                    CoinEvent::reverted(&block.entity, &tx_ids)
+                })
+                .collect();
+            futures::stream::iter(events)
+        });
+        // The reorg is broadcast before the blocks of the competing chain are imported,
+        // so the reverted events are polled first to keep the order of the changes.
+        fuel_core_services::stream::IntoBoxStream::into_boxed(
+            futures::stream::select_with_strategy(reverted, imported, |_: &mut ()| {
+                futures::stream::PollNext::Left
+            }),
+        )
+    }
+}
@@ -11,7 +11,10 @@ use crate::{
         sub_services::BlockProducerService,
     },
 };
-use fuel_core_producer::ports::TxPool;
+use fuel_core_producer::{
+    ports::TxPool,
+    TxSelection,
+};
 use fuel_core_storage::{
     not_found,
     tables::FuelBlocks,
@@ -35,6 +38,8 @@ use fuel_core_types::{
         executor::{
             ExecutionTypes,
             Result as ExecutorResult,
+            StateOverrides,
+            TransactionTrace,
             UncommittedResult,
         },
     },
@@ -56,8 +61,20 @@ impl BlockProducerAdapter {
 impl TxPool for TxPoolAdapter {
     type TxSource = TransactionsSource;
 
-    fn get_source(&self, block_height: BlockHeight) -> Self::TxSource {
-        TransactionsSource::new(self.service.clone(), block_height)
+    fn get_source(
+        &self,
+        block_height: BlockHeight,
+        selection: TxSelection,
+    ) -> Self::TxSource {
+        TransactionsSource::new(self.service.clone(), block_height, selection)
+    }
+
+    fn get_dry_run_source(
+        &self,
+        block_height: BlockHeight,
+        selection: TxSelection,
+    ) -> Self::TxSource {
+        TransactionsSource::new(self.service.clone(), block_height, selection).dry_run()
     }
 }
 
@@ -77,8 +94,18 @@ impl fuel_core_producer::ports::Executor for ExecutorAdapter {
         &self,
         block: Components<fuel_tx::Transaction>,
         utxo_validation: Option<bool>,
+        overrides: StateOverrides,
     ) -> ExecutorResult<Vec<Vec<Receipt>>> {
-        self._dry_run(block, utxo_validation)
+        self._dry_run(block, utxo_validation, overrides)
+    }
+
+    fn trace(
+        &self,
+        block: Components<fuel_tx::Transaction>,
+        utxo_validation: Option<bool>,
+        full_opcode_stream: bool,
+    ) -> ExecutorResult<TransactionTrace> {
+        self._trace(block, utxo_validation, full_opcode_stream)
     }
 }
 
@@ -0,0 +1,17 @@
+use crate::service::{
+    adapters::MaybeRelayerAdapter,
+    pruner::FinalizedDaHeight,
+};
+use fuel_core_types::blockchain::primitives::DaBlockHeight;
+
+impl FinalizedDaHeight for MaybeRelayerAdapter {
+    fn finalized_da_height(&self) -> anyhow::Result<Option<DaBlockHeight>> {
+        #[cfg(feature = "relayer")]
+        {
+            if let Some(sync) = self.relayer_synced.as_ref() {
+                return Ok(Some(sync.get_finalized_da_height()?))
+            }
+        }
+        Ok(None)
+    }
+}
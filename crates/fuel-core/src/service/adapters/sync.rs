@@ -22,6 +22,7 @@ use fuel_core_types::{
     fuel_types::BlockHeight,
     services::p2p::SourcePeer,
 };
+use std::ops::Range;
 
 #[async_trait::async_trait]
 impl PeerToPeerPort for P2PAdapter {
@@ -39,17 +40,17 @@ impl PeerToPeerPort for P2PAdapter {
         }
     }
 
-    async fn get_sealed_block_header(
+    async fn get_sealed_block_headers(
         &self,
-        height: BlockHeight,
-    ) -> anyhow::Result<Option<SourcePeer<SealedBlockHeader>>> {
+        block_height_range: Range<u32>,
+    ) -> anyhow::Result<Option<SourcePeer<Vec<SealedBlockHeader>>>> {
         if let Some(service) = &self.service {
             Ok(service
-                .get_sealed_block_header(height)
+                .get_sealed_block_headers(block_height_range)
                 .await?
-                .map(|(peer_id, header)| SourcePeer {
+                .map(|(peer_id, headers)| SourcePeer {
                     peer_id: peer_id.into(),
-                    data: header,
+                    data: headers,
                 }))
         } else {
             Ok(None)
@@ -78,18 +79,37 @@ impl PeerToPeerPort for P2PAdapter {
 impl BlockImporterPort for BlockImporterAdapter {
     fn committed_height_stream(&self) -> BoxStream<BlockHeight> {
         use futures::StreamExt;
-        fuel_core_services::stream::IntoBoxStream::into_boxed(
+        let blocks =
             tokio_stream::wrappers::BroadcastStream::new(self.block_importer.subscribe())
                 .filter_map(|r| {
                     futures::future::ready(
                         r.ok().map(|r| *r.sealed_block.entity.header().height()),
                     )
-                }),
+                });
+        // The light client commits only the headers of the blocks.
+        let headers = tokio_stream::wrappers::BroadcastStream::new(
+            self.block_importer.subscribe_headers(),
         )
+        .filter_map(|r| futures::future::ready(r.ok().map(|h| *h.entity.height())));
+        fuel_core_services::stream::IntoBoxStream::into_boxed(futures::stream::select(
+            blocks, headers,
+        ))
     }
     async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()> {
         self.execute_and_commit(block).await
     }
+
+    async fn commit_header(&self, header: SealedBlockHeader) -> anyhow::Result<()> {
+        self.commit_header(header).await
+    }
+
+    fn committed_block_id(&self, height: BlockHeight) -> anyhow::Result<Option<BlockId>> {
+        self.block_id(&height)
+    }
+
+    async fn rollback_to(&self, height: BlockHeight) -> anyhow::Result<()> {
+        self.rollback_to(height).await
+    }
 }
 
 #[async_trait::async_trait]
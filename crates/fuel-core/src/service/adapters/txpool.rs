@@ -5,6 +5,7 @@ use crate::{
         P2PAdapter,
     },
 };
+use fuel_core_producer::GasPriceTracker;
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::{
     not_found,
@@ -17,14 +18,20 @@ use fuel_core_storage::{
     Result as StorageResult,
     StorageAsRef,
 };
-use fuel_core_txpool::ports::BlockImporter;
+use fuel_core_txpool::{
+    admission::TxAdmissionPolicy,
+    ports::BlockImporter,
+    Error as TxPoolError,
+};
 use fuel_core_types::{
     entities::{
         coins::coin::CompressedCoin,
         message::Message,
     },
     fuel_tx::{
+        ConsensusParameters,
         Transaction,
+        TxId,
         UtxoId,
     },
     fuel_types::{
@@ -33,12 +40,18 @@ use fuel_core_types::{
         Nonce,
     },
     services::{
-        block_importer::ImportResult,
+        block_importer::{
+            ImportResult,
+            Reorg,
+        },
         p2p::{
+            peer_reputation::TransactionGossipReport,
             GossipsubMessageAcceptance,
             GossipsubMessageInfo,
+            PeerId,
             TransactionGossipData,
         },
+        txpool::PoolTransaction,
     },
 };
 use std::sync::Arc;
@@ -54,6 +67,23 @@ impl BlockImporter for BlockImporterAdapter {
                 .filter_map(|result| result.ok()),
         )
     }
+
+    fn consensus_parameters_events(&self) -> BoxStream<ConsensusParameters> {
+        // The consensus parameters are defined by the chain config and can't be
+        // changed by the imported blocks yet.
+        Box::pin(fuel_core_services::stream::pending())
+    }
+
+    fn reorg_events(&self) -> BoxStream<Arc<Reorg>> {
+        use tokio_stream::{
+            wrappers::BroadcastStream,
+            StreamExt,
+        };
+        Box::pin(
+            BroadcastStream::new(self.block_importer.subscribe_reorgs())
+                .filter_map(|reorg| reorg.ok()),
+        )
+    }
 }
 
 #[cfg(feature = "p2p")]
@@ -68,6 +98,17 @@ impl fuel_core_txpool::ports::PeerToPeer for P2PAdapter {
         }
     }
 
+    fn broadcast_transactions(
+        &self,
+        transactions: Vec<Arc<Transaction>>,
+    ) -> anyhow::Result<()> {
+        if let Some(service) = &self.service {
+            service.broadcast_transactions(transactions)
+        } else {
+            Ok(())
+        }
+    }
+
     fn gossiped_transaction_events(&self) -> BoxStream<Self::GossipedTransaction> {
         use tokio_stream::{
             wrappers::BroadcastStream,
@@ -94,6 +135,45 @@ impl fuel_core_txpool::ports::PeerToPeer for P2PAdapter {
             Ok(())
         }
     }
+
+    fn report_peer(
+        &self,
+        peer_id: PeerId,
+        report: TransactionGossipReport,
+    ) -> anyhow::Result<()> {
+        if let Some(service) = &self.service {
+            service.report_peer(peer_id, report, "TxPool")
+        } else {
+            Ok(())
+        }
+    }
+
+    fn request_transactions(
+        &self,
+        peer_id: PeerId,
+        tx_ids: Vec<TxId>,
+    ) -> anyhow::Result<()> {
+        if let Some(service) = &self.service {
+            service.request_transactions(peer_id, tx_ids)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn requested_transaction_events(&self) -> BoxStream<Vec<Transaction>> {
+        use tokio_stream::{
+            wrappers::BroadcastStream,
+            StreamExt,
+        };
+        if let Some(service) = &self.service {
+            Box::pin(
+                BroadcastStream::new(service.subscribe_requested_transactions())
+                    .filter_map(|result| result.ok()),
+            )
+        } else {
+            fuel_core_services::stream::IntoBoxStream::into_boxed(tokio_stream::pending())
+        }
+    }
 }
 
 #[cfg(not(feature = "p2p"))]
@@ -107,6 +187,13 @@ impl fuel_core_txpool::ports::PeerToPeer for P2PAdapter {
         Ok(())
     }
 
+    fn broadcast_transactions(
+        &self,
+        _transactions: Vec<Arc<Transaction>>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     fn gossiped_transaction_events(&self) -> BoxStream<Self::GossipedTransaction> {
         Box::pin(fuel_core_services::stream::pending())
     }
@@ -118,6 +205,26 @@ impl fuel_core_txpool::ports::PeerToPeer for P2PAdapter {
     ) -> anyhow::Result<()> {
         Ok(())
     }
+
+    fn report_peer(
+        &self,
+        _peer_id: PeerId,
+        _report: TransactionGossipReport,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn request_transactions(
+        &self,
+        _peer_id: PeerId,
+        _tx_ids: Vec<TxId>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn requested_transaction_events(&self) -> BoxStream<Vec<Transaction>> {
+        Box::pin(fuel_core_services::stream::pending())
+    }
 }
 
 impl fuel_core_txpool::ports::TxPoolDb for Database {
@@ -155,3 +262,21 @@ impl fuel_core_txpool::ports::TxPoolDb for Database {
             .ok_or(not_found!("TransactionId"))??)
     }
 }
+
+/// Rejects the transactions paying less than the minimum gas price adjusted by the
+/// block producer to the fullness of the recent blocks.
+#[derive(Debug, Clone)]
+pub struct DynamicGasPricePolicy(pub GasPriceTracker);
+
+impl TxAdmissionPolicy for DynamicGasPricePolicy {
+    fn check(&self, tx: &PoolTransaction) -> Result<(), TxPoolError> {
+        let minimum = self.0.latest_gas_price();
+        if tx.price() < minimum {
+            return Err(TxPoolError::NotInsertedGasPriceTooLow {
+                provided: tx.price(),
+                minimum,
+            })
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,320 @@
+//! Collects the statistics of the recent blocks for the GraphQL API.
+//!
+//! The collector keeps the time, the number of the transactions, and the gas used
+//! of the latest imported blocks in memory, so the rolling statistics are computed
+//! without reading the blocks from the database. The history is restored from the
+//! database on start, so the statistics are available right after the restart.
+
+use crate::database::Database;
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    StateWatcher,
+};
+use fuel_core_storage::{
+    tables::Receipts,
+    Result as StorageResult,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    blockchain::block::Block,
+    fuel_tx::{
+        Receipt,
+        Transaction,
+        UniqueIdentifier,
+    },
+    fuel_types::{
+        BlockHeight,
+        ChainId,
+    },
+    services::{
+        block_importer::ImportResult,
+        graphql_api::ChainStats,
+    },
+    tai64::Tai64,
+};
+use parking_lot::RwLock;
+use std::{
+    collections::VecDeque,
+    sync::Arc,
+};
+use tokio::sync::broadcast::{
+    self,
+    error::RecvError,
+};
+
+/// The maximum number of the latest blocks the statistics are computed over.
+pub const MAX_STATS_WINDOW: u32 = 1_000;
+
+/// The statistics of the single block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct BlockSample {
+    height: BlockHeight,
+    time: Tai64,
+    /// The number of the transactions except the mint transaction.
+    transactions: u64,
+    /// The gas used by the scripts of the block.
+    gas_used: u64,
+}
+
+/// The samples of the latest blocks shared with the GraphQL API.
+#[derive(Clone, Default)]
+pub struct RecentBlocks {
+    samples: Arc<RwLock<VecDeque<BlockSample>>>,
+}
+
+impl RecentBlocks {
+    fn record(&self, sample: BlockSample) {
+        let mut samples = self.samples.write();
+        // The blocks at the same or the greater height were reverted by the reorg,
+        // or are already restored from the database.
+        while matches!(samples.back(), Some(last) if last.height >= sample.height) {
+            samples.pop_back();
+        }
+        samples.push_back(sample);
+        while samples.len() > MAX_STATS_WINDOW as usize {
+            samples.pop_front();
+        }
+    }
+
+    /// Computes the statistics of up to `window` latest blocks.
+    pub fn stats(&self, window: u32) -> ChainStats {
+        let samples = self.samples.read();
+        let window = (window as usize).min(samples.len());
+        let samples: Vec<_> = samples.iter().skip(samples.len() - window).collect();
+
+        let mut intervals: Vec<u64> = samples
+            .windows(2)
+            .map(|pair| pair[1].time.0.saturating_sub(pair[0].time.0))
+            .collect();
+        intervals.sort_unstable();
+        let elapsed: u64 = intervals.iter().sum();
+
+        let transactions: u64 = samples.iter().map(|sample| sample.transactions).sum();
+        let gas_used: u64 = samples.iter().map(|sample| sample.gas_used).sum();
+        // The transactions of the first block were included before the window.
+        let transactions_in_window = samples
+            .first()
+            .map(|first| transactions - first.transactions)
+            .unwrap_or_default();
+
+        ChainStats {
+            blocks: samples.len() as u32,
+            transactions,
+            transactions_per_second: if elapsed > 0 {
+                transactions_in_window as f64 / elapsed as f64
+            } else {
+                0.0
+            },
+            average_block_interval: if intervals.is_empty() {
+                0.0
+            } else {
+                elapsed as f64 / intervals.len() as f64
+            },
+            p50_block_interval: percentile(&intervals, 50),
+            p90_block_interval: percentile(&intervals, 90),
+            p99_block_interval: percentile(&intervals, 99),
+            average_gas_used: gas_used
+                .checked_div(samples.len() as u64)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Returns the `percent` percentile of the sorted `values` by the nearest rank.
+fn percentile(values: &[u64], percent: usize) -> u64 {
+    if values.is_empty() {
+        return 0
+    }
+    let rank = (values.len() * percent + 99) / 100;
+    values[rank.saturating_sub(1)]
+}
+
+pub struct ChainStatsCollector {
+    database: Database,
+    chain_id: ChainId,
+    recent_blocks: RecentBlocks,
+    blocks: broadcast::Receiver<Arc<ImportResult>>,
+}
+
+impl ChainStatsCollector {
+    pub fn new(
+        database: Database,
+        chain_id: ChainId,
+        blocks: broadcast::Receiver<Arc<ImportResult>>,
+    ) -> Self {
+        Self {
+            database,
+            chain_id,
+            recent_blocks: Default::default(),
+            blocks,
+        }
+    }
+}
+
+pub struct Task {
+    database: Database,
+    chain_id: ChainId,
+    recent_blocks: RecentBlocks,
+    blocks: broadcast::Receiver<Arc<ImportResult>>,
+}
+
+impl Task {
+    fn sample(&self, block: &Block) -> StorageResult<BlockSample> {
+        let mut transactions = 0;
+        let mut gas_used = 0u64;
+        for tx in block.transactions() {
+            if !tx.is_mint() {
+                transactions += 1;
+            }
+            if let Transaction::Script(script) = tx {
+                let receipts = self
+                    .database
+                    .storage::<Receipts>()
+                    .get(&script.id(&self.chain_id))?;
+                let used = receipts
+                    .iter()
+                    .flat_map(|receipts| receipts.iter())
+                    .find_map(|receipt| match receipt {
+                        Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+                        _ => None,
+                    })
+                    .unwrap_or_default();
+                gas_used = gas_used.saturating_add(used);
+            }
+        }
+
+        Ok(BlockSample {
+            height: *block.header().height(),
+            time: block.header().time(),
+            transactions,
+            gas_used,
+        })
+    }
+
+    /// Restores the samples of the latest blocks from the database.
+    fn restore(&self) -> anyhow::Result<()> {
+        let latest = *self.database.latest_height()?;
+        let first = latest.saturating_sub(MAX_STATS_WINDOW - 1);
+        for height in first..=latest {
+            if let Some(block) =
+                self.database.get_sealed_block_by_height(&height.into())?
+            {
+                self.recent_blocks.record(self.sample(&block.entity)?);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableService for ChainStatsCollector {
+    const NAME: &'static str = "ChainStatsCollector";
+
+    type SharedData = RecentBlocks;
+    type Task = Task;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        self.recent_blocks.clone()
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        let task = Task {
+            database: self.database,
+            chain_id: self.chain_id,
+            recent_blocks: self.recent_blocks,
+            blocks: self.blocks,
+        };
+        task.restore()?;
+        Ok(task)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        tokio::select! {
+            biased;
+            _ = watcher.while_started() => Ok(false),
+            result = self.blocks.recv() => match result {
+                Ok(result) => {
+                    let sample = self.sample(&result.sealed_block.entity)?;
+                    self.recent_blocks.record(sample);
+                    Ok(true)
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::warn!("The statistics of {} blocks were skipped", skipped);
+                    Ok(true)
+                }
+                Err(RecvError::Closed) => Ok(false),
+            },
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(height: u32, time: u64, transactions: u64, gas_used: u64) -> BlockSample {
+        BlockSample {
+            height: height.into(),
+            time: Tai64(time),
+            transactions,
+            gas_used,
+        }
+    }
+
+    #[test]
+    fn stats_are_computed_over_the_window() {
+        let recent_blocks = RecentBlocks::default();
+        recent_blocks.record(sample(1, 100, 50, 0));
+        recent_blocks.record(sample(2, 101, 2, 10));
+        recent_blocks.record(sample(3, 103, 4, 20));
+        recent_blocks.record(sample(4, 104, 6, 30));
+        recent_blocks.record(sample(5, 110, 8, 40));
+
+        let stats = recent_blocks.stats(4);
+
+        assert_eq!(stats.blocks, 4);
+        assert_eq!(stats.transactions, 20);
+        // 18 transactions were included within 9 seconds after the first block.
+        assert_eq!(stats.transactions_per_second, 2.0);
+        assert_eq!(stats.average_block_interval, 3.0);
+        assert_eq!(stats.p50_block_interval, 2);
+        assert_eq!(stats.p90_block_interval, 6);
+        assert_eq!(stats.p99_block_interval, 6);
+        assert_eq!(stats.average_gas_used, 25);
+    }
+
+    #[test]
+    fn reverted_blocks_are_replaced() {
+        let recent_blocks = RecentBlocks::default();
+        recent_blocks.record(sample(1, 100, 1, 0));
+        recent_blocks.record(sample(2, 101, 1, 0));
+        recent_blocks.record(sample(3, 102, 1, 0));
+
+        recent_blocks.record(sample(2, 105, 3, 0));
+
+        let stats = recent_blocks.stats(MAX_STATS_WINDOW);
+        assert_eq!(stats.blocks, 2);
+        assert_eq!(stats.transactions, 4);
+        assert_eq!(stats.average_block_interval, 5.0);
+    }
+
+    #[test]
+    fn stats_of_empty_history_are_zero() {
+        let stats = RecentBlocks::default().stats(10);
+
+        assert_eq!(stats, ChainStats::default());
+    }
+}
@@ -0,0 +1,138 @@
+//! Compacts the database every day at the configured time.
+//!
+//! The long-running nodes accumulate the tombstones of the removed entries, which
+//! slow down the reads until the files with them are compacted. The full compaction
+//! is heavy on IO, so it is scheduled for the off-peak hours.
+
+use crate::{
+    database::Database,
+    service::config::CompactionConfig,
+};
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    StateWatcher,
+};
+use std::time::{
+    Duration,
+    SystemTime,
+};
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+pub struct Compactor {
+    database: Database,
+    config: CompactionConfig,
+}
+
+impl Compactor {
+    pub fn new(database: Database, config: CompactionConfig) -> Self {
+        Self { database, config }
+    }
+}
+
+pub struct Task {
+    database: Database,
+    config: CompactionConfig,
+}
+
+impl Task {
+    async fn compact(&self) -> anyhow::Result<()> {
+        let database = self.database.clone();
+        let columns = self.config.columns.clone();
+        tokio::task::spawn_blocking(move || {
+            if columns.is_empty() {
+                database.compact(None)
+            } else {
+                columns
+                    .into_iter()
+                    .try_for_each(|column| database.compact(Some(column)))
+            }
+        })
+        .await??;
+        Ok(())
+    }
+}
+
+/// Returns the duration from the `now` until the next `time_of_day` in UTC.
+fn until_next(now: SystemTime, time_of_day: Duration) -> Duration {
+    let since_midnight = now
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|since_epoch| Duration::from_secs(since_epoch.as_secs() % DAY.as_secs()))
+        .unwrap_or_default();
+    let time_of_day = Duration::from_secs(time_of_day.as_secs() % DAY.as_secs());
+    if time_of_day > since_midnight {
+        time_of_day - since_midnight
+    } else {
+        DAY - since_midnight + time_of_day
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Compactor {
+    const NAME: &'static str = "Compactor";
+
+    type SharedData = ();
+    type Task = Task;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        Ok(Task {
+            database: self.database,
+            config: self.config,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let delay = until_next(SystemTime::now(), self.config.time_of_day);
+        tokio::select! {
+            biased;
+            _ = watcher.while_started() => Ok(false),
+            _ = tokio::time::sleep(delay) => {
+                tracing::info!("Starting the scheduled compaction of the database");
+                match self.compact().await {
+                    Ok(()) => tracing::info!("The scheduled compaction is finished"),
+                    // The compaction is retried the next day.
+                    Err(err) => tracing::error!("The scheduled compaction failed: {}", err),
+                }
+                Ok(true)
+            }
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_compaction_is_scheduled_at_the_time_of_day() {
+        let midnight = SystemTime::UNIX_EPOCH + DAY * 100;
+        let three_am = Duration::from_secs(3 * 60 * 60);
+
+        assert_eq!(until_next(midnight, three_am), three_am);
+        assert_eq!(
+            until_next(midnight + Duration::from_secs(60 * 60), three_am),
+            Duration::from_secs(2 * 60 * 60)
+        );
+        // The time of day has already passed today.
+        assert_eq!(until_next(midnight + three_am, three_am), DAY);
+        assert_eq!(
+            until_next(midnight + Duration::from_secs(4 * 60 * 60), three_am),
+            DAY - Duration::from_secs(60 * 60)
+        );
+    }
+}
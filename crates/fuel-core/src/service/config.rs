@@ -1,10 +1,21 @@
+use crate::{
+    database::Column,
+    graphql_api::{
+        auth::ApiTokens,
+        QueryLimits,
+        TlsConfig,
+    },
+    state::rocks_db_config::RocksDbConfig,
+};
 use clap::ValueEnum;
 use fuel_core_chain_config::{
     default_consensus_dev_key,
     ChainConfig,
+    Checkpoint,
 };
 use fuel_core_types::{
     blockchain::primitives::SecretKeyWrapper,
+    fuel_types::Bytes32,
     secrecy::Secret,
 };
 use std::{
@@ -27,7 +38,11 @@ use fuel_core_p2p::config::{
     NotInitialized,
 };
 
-pub use fuel_core_poa::Trigger;
+pub use fuel_core_poa::{
+    RemoteSigner,
+    SignMode,
+    Trigger,
+};
 
 #[derive(Clone, Debug)]
 pub struct Config {
@@ -35,11 +50,27 @@ pub struct Config {
     pub max_database_cache_size: usize,
     pub database_path: PathBuf,
     pub database_type: DbType,
+    /// The tuning of the RocksDB.
+    pub rocksdb: RocksDbConfig,
     pub chain_conf: ChainConfig,
+    /// The checkpoint to initialize the empty database from instead of the genesis.
+    pub checkpoint: Option<CheckpointConfig>,
     // default to false until downstream consumers stabilize
     pub utxo_validation: bool,
     pub manual_blocks_enabled: bool,
+    /// Allows GraphQL endpoints to disconnect and ban peers.
+    pub peer_admin_enabled: bool,
+    /// Allows GraphQL endpoints to pause and resume the block production.
+    pub production_admin_enabled: bool,
+    /// Allows GraphQL endpoints to trigger the compaction of the database.
+    pub database_admin_enabled: bool,
+    /// The directory where GraphQL endpoints may create the checkpoints of the
+    /// database. The checkpoints can't be created if unset.
+    pub database_checkpoint_dir: Option<PathBuf>,
     pub block_production: Trigger,
+    /// Sync and verify only the headers of the blocks without executing them.
+    /// The node doesn't produce blocks and doesn't track the state of the chain.
+    pub light_client: bool,
     pub vm: VMConfig,
     pub txpool: fuel_core_txpool::Config,
     pub block_producer: fuel_core_producer::Config,
@@ -52,6 +83,9 @@ pub struct Config {
     #[cfg(feature = "p2p")]
     pub sync: fuel_core_sync::Config,
     pub consensus_key: Option<Secret<SecretKeyWrapper>>,
+    /// Signs the produced blocks instead of the `consensus_key`, so the key
+    /// doesn't need to be kept on the node.
+    pub remote_signer: Option<RemoteSigner>,
     pub name: String,
     pub verifier: fuel_core_consensus_module::RelayerVerifierConfig,
     /// The number of reserved peers to connect to before starting to sync.
@@ -60,6 +94,28 @@ pub struct Config {
     pub time_until_synced: Duration,
     /// Time to wait after submitting a query before debug info will be logged about query.
     pub query_log_threshold_time: Duration,
+    /// The limits of the depth, the complexity, and the execution time of the queries.
+    pub query_limits: QueryLimits,
+    /// The tokens granting the access to the GraphQL endpoints submitting the
+    /// transactions, debugging, and administering the node. All endpoints are
+    /// public if empty.
+    pub api_tokens: ApiTokens,
+    /// Serves the GraphQL API over HTTPS with the certificate. The API is served
+    /// over HTTP if unset.
+    pub tls: Option<TlsConfig>,
+    /// The origins allowed to make the cross-origin requests to the GraphQL API.
+    /// All origins are allowed if empty.
+    pub cors_allowed_origins: Vec<String>,
+    /// The file where the state diffs of the imported blocks are appended as JSON lines.
+    pub state_diff_sink: Option<PathBuf>,
+    /// Prunes the history of the old blocks. The history of all blocks is kept if unset.
+    pub pruning: Option<PruningConfig>,
+    /// Moves the old blocks and receipts to the cold storage. All blocks are kept
+    /// in the main database if unset.
+    pub tiering: Option<TieringConfig>,
+    /// Compacts the database every day. The database is compacted only by the
+    /// RocksDB itself if unset.
+    pub compaction: Option<CompactionConfig>,
 }
 
 impl Config {
@@ -76,9 +132,16 @@ impl Config {
             database_type: DbType::RocksDb,
             #[cfg(not(feature = "rocksdb"))]
             database_type: DbType::InMemory,
+            rocksdb: Default::default(),
             chain_conf: chain_conf.clone(),
+            checkpoint: None,
             manual_blocks_enabled: false,
+            peer_admin_enabled: false,
+            production_admin_enabled: false,
+            database_admin_enabled: false,
+            database_checkpoint_dir: None,
             block_production: Trigger::Instant,
+            light_client: false,
             vm: Default::default(),
             utxo_validation,
             txpool: fuel_core_txpool::Config {
@@ -98,15 +161,61 @@ impl Config {
             #[cfg(feature = "p2p")]
             sync: fuel_core_sync::Config::default(),
             consensus_key: Some(Secret::new(default_consensus_dev_key().into())),
+            remote_signer: None,
             name: String::default(),
             verifier: Default::default(),
             min_connected_reserved_peers: 0,
             time_until_synced: Duration::ZERO,
             query_log_threshold_time: Duration::from_secs(2),
+            query_limits: Default::default(),
+            api_tokens: Default::default(),
+            tls: None,
+            cors_allowed_origins: vec![],
+            state_diff_sink: None,
+            pruning: None,
+            tiering: None,
+            compaction: None,
         }
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct CheckpointConfig {
+    /// The snapshot of the chain at the checkpoint height.
+    pub checkpoint: Checkpoint,
+    /// The trusted root of the checkpoint state. The checkpoint is rejected
+    /// if the root of its state doesn't match. The block headers don't commit
+    /// to the state, so the root supplied by the operator is the only validation
+    /// of the state, instead of the state root of the block header.
+    pub state_root: Bytes32,
+}
+
+#[derive(Clone, Debug)]
+pub struct PruningConfig {
+    /// The number of the latest blocks with the kept history.
+    pub retention: u32,
+    /// The maximum number of blocks pruned at once.
+    pub batch_size: u32,
+}
+
+#[derive(Clone, Debug)]
+pub struct TieringConfig {
+    /// The path of the cold storage, e.g. the cheap disk or the mounted object storage.
+    pub path: PathBuf,
+    /// The number of the latest blocks kept in the main database.
+    pub threshold: u32,
+    /// The maximum number of blocks moved at once.
+    pub batch_size: u32,
+}
+
+#[derive(Clone, Debug)]
+pub struct CompactionConfig {
+    /// The time since the midnight in UTC to start the compaction at.
+    pub time_of_day: Duration,
+    /// The compacted columns. All columns are compacted if empty.
+    pub columns: Vec<Column>,
+}
+
 impl TryFrom<&Config> for fuel_core_poa::Config {
     type Error = anyhow::Error;
 
@@ -116,19 +225,27 @@ impl TryFrom<&Config> for fuel_core_poa::Config {
             !config.manual_blocks_enabled
                 || matches!(
                     config.block_production,
-                    Trigger::Never | Trigger::Instant | Trigger::Interval { .. }
+                    Trigger::Never
+                        | Trigger::Instant
+                        | Trigger::Interval { .. }
+                        | Trigger::Hybrid { .. }
                 ),
-            "Cannot use manual block production unless trigger mode is never, instant or interval."
+            "Cannot use manual block production unless trigger mode is never, instant, interval or hybrid."
         );
 
         Ok(fuel_core_poa::Config {
             trigger: config.block_production,
             block_gas_limit: config.chain_conf.block_gas_limit,
-            signing_key: config.consensus_key.clone(),
+            signer: match (&config.remote_signer, &config.consensus_key) {
+                (Some(remote_signer), _) => SignMode::Remote(remote_signer.clone()),
+                (None, Some(key)) => SignMode::Key(key.clone()),
+                (None, None) => SignMode::Unavailable,
+            },
             metrics: false,
             consensus_params: config.chain_conf.consensus_parameters.clone(),
             min_connected_reserved_peers: config.min_connected_reserved_peers,
             time_until_synced: config.time_until_synced,
+            consensus: config.chain_conf.consensus.clone(),
         })
     }
 }
@@ -136,6 +253,8 @@ impl TryFrom<&Config> for fuel_core_poa::Config {
 #[derive(Clone, Debug, Default)]
 pub struct VMConfig {
     pub backtrace: bool,
+    /// Allows GraphQL endpoints to trace the execution of the transactions.
+    pub tracing: bool,
 }
 
 #[derive(
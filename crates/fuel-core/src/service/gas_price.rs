@@ -0,0 +1,137 @@
+//! Adjusts the dynamic gas price to the fullness of the committed blocks. The blocks are
+//! taken from the importer, so the gas price is the same on the block producer and on the
+//! nodes that only sync the blocks.
+
+use fuel_core_producer::GasPriceTracker;
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    StateWatcher,
+};
+use fuel_core_types::{
+    fuel_asm::Word,
+    services::block_importer::ImportResult,
+};
+use std::sync::Arc;
+use tokio::sync::broadcast::{
+    self,
+    error::RecvError,
+};
+
+pub struct GasPriceUpdater {
+    gas_price: GasPriceTracker,
+    block_gas_limit: Word,
+    blocks: broadcast::Receiver<Arc<ImportResult>>,
+}
+
+impl GasPriceUpdater {
+    pub fn new(
+        gas_price: GasPriceTracker,
+        block_gas_limit: Word,
+        blocks: broadcast::Receiver<Arc<ImportResult>>,
+    ) -> Self {
+        Self {
+            gas_price,
+            block_gas_limit,
+            blocks,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableService for GasPriceUpdater {
+    const NAME: &'static str = "GasPriceUpdater";
+
+    type SharedData = ();
+    type Task = GasPriceUpdater;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for GasPriceUpdater {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        tokio::select! {
+            biased;
+            _ = watcher.while_started() => Ok(false),
+            result = self.blocks.recv() => match result {
+                Ok(result) => {
+                    self.gas_price.record_committed_block(
+                        &result.sealed_block.entity,
+                        self.block_gas_limit,
+                    );
+                    Ok(true)
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::warn!("The gas price skipped {} committed blocks", skipped);
+                    Ok(true)
+                }
+                Err(RecvError::Closed) => Ok(false),
+            },
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_producer::GasPriceConfig;
+    use fuel_core_services::{
+        Service,
+        ServiceRunner,
+    };
+    use fuel_core_types::{
+        blockchain::{
+            block::Block,
+            SealedBlock,
+        },
+        fuel_tx::{
+            Transaction,
+            TransactionBuilder,
+        },
+    };
+
+    #[tokio::test]
+    async fn committed_blocks_update_the_gas_price() {
+        let gas_price = GasPriceTracker::new(800, Some(GasPriceConfig::default()));
+        let (sender, receiver) = broadcast::channel(1);
+        let service =
+            ServiceRunner::new(GasPriceUpdater::new(gas_price.clone(), 1000, receiver));
+        service.start_and_await().await.unwrap();
+
+        let full_tx: Transaction = TransactionBuilder::script(vec![], vec![])
+            .gas_limit(1000)
+            .finalize_as_transaction();
+        let block = Block::new(Default::default(), vec![full_tx], &[]);
+        let result = ImportResult::new_from_local(
+            SealedBlock {
+                entity: block,
+                consensus: Default::default(),
+            },
+            vec![],
+        );
+        sender.send(Arc::new(result)).unwrap();
+
+        tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            while gas_price.latest_gas_price() == 800 {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("The gas price should grow after the full block");
+        service.stop_and_await().await.unwrap();
+    }
+}
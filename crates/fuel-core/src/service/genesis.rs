@@ -1,9 +1,16 @@
 use crate::{
     database::Database,
-    service::config::Config,
+    service::config::{
+        CheckpointConfig,
+        Config,
+    },
+};
+use anyhow::{
+    anyhow,
+    ensure,
 };
-use anyhow::anyhow;
 use fuel_core_chain_config::{
+    Checkpoint,
     ContractConfig,
     GenesisCommitment,
     StateConfig,
@@ -18,6 +25,7 @@ use fuel_core_storage::{
         ContractsRawCode,
         FuelBlocks,
         Messages,
+        SealedBlockConsensus,
     },
     transactional::Transactional,
     MerkleRoot,
@@ -51,6 +59,7 @@ use fuel_core_types::{
     },
     fuel_types::{
         bytes::WORD_SIZE,
+        BlockHeight,
         Bytes32,
         ContractId,
     },
@@ -68,7 +77,10 @@ pub fn maybe_initialize_state(
 ) -> anyhow::Result<()> {
     // check if chain is initialized
     if database.ids_of_latest_block()?.is_none() {
-        import_genesis_block(config, database)?;
+        match &config.checkpoint {
+            Some(checkpoint) => import_checkpoint(config, checkpoint, database)?,
+            None => import_genesis_block(config, database)?,
+        }
     }
 
     Ok(())
@@ -86,6 +98,9 @@ fn import_genesis_block(
 
     let chain_config_hash = config.chain_conf.root()?.into();
     let coins_root = init_coin_state(database, &config.chain_conf.initial_state)?.into();
+    if config.block_importer.index_assets {
+        database.index_genesis_assets()?;
+    }
     let contracts_root =
         init_contracts(database, &config.chain_conf.initial_state)?.into();
     let messages_root = init_da_messages(database, &config.chain_conf.initial_state)?;
@@ -149,6 +164,109 @@ fn import_genesis_block(
     Ok(())
 }
 
+/// Initializes the database from the checkpoint instead of the genesis.
+/// The blocks before the checkpoint block are not available, except the genesis block.
+fn import_checkpoint(
+    config: &Config,
+    checkpoint_config: &CheckpointConfig,
+    original_database: &Database,
+) -> anyhow::Result<()> {
+    let CheckpointConfig {
+        checkpoint,
+        state_root,
+    } = checkpoint_config;
+    let Checkpoint {
+        genesis,
+        block_ids,
+        block,
+        state,
+    } = checkpoint;
+    let height = checkpoint.height();
+
+    // The state of the checkpoint is trusted only if it matches the trusted root.
+    // The block header doesn't commit to the state, so the root supplied
+    // by the operator substitutes the validation against the header.
+    ensure!(
+        Bytes32::from(state.root()?) == *state_root,
+        "The state of the checkpoint doesn't match the state root {state_root}"
+    );
+    ensure!(
+        state.height == Some(height),
+        "The state of the checkpoint doesn't belong to the checkpoint block {height}"
+    );
+
+    // The genesis block should belong to the network of the chain config.
+    match &genesis.consensus {
+        Consensus::Genesis(consensus) => ensure!(
+            consensus.chain_config_hash == Bytes32::from(config.chain_conf.root()?),
+            "The genesis block of the checkpoint doesn't match the chain config"
+        ),
+        _ => {
+            return Err(anyhow!(
+                "The first block of the checkpoint isn't the genesis"
+            ))
+        }
+    }
+    let genesis_height = *genesis.entity.header().height();
+    ensure!(
+        *genesis_height as usize + block_ids.len() + 1 == *height as usize,
+        "The checkpoint doesn't contain the ids of all blocks before the checkpoint block"
+    );
+
+    // The checkpoint block should be signed by the block producer of the network.
+    match &block.consensus {
+        Consensus::PoA(consensus) => ensure!(
+            fuel_core_poa::verifier::verify_consensus(
+                &config.chain_conf.consensus,
+                block.entity.header(),
+                consensus,
+            ),
+            "The checkpoint block has an invalid signature"
+        ),
+        _ => return Err(anyhow!("The checkpoint block should be produced by PoA")),
+    }
+
+    // start a db transaction for bulk-writing
+    let mut database_transaction = Transactional::transaction(original_database);
+    let database = database_transaction.as_mut();
+
+    let initial_state = Some(state.clone());
+    init_coin_state(database, &initial_state)?;
+    if config.block_importer.index_assets {
+        database.index_genesis_assets()?;
+    }
+    init_contracts(database, &initial_state)?;
+    init_da_messages(database, &initial_state)?;
+
+    let genesis_id = genesis.entity.id();
+    database
+        .storage::<FuelBlocks>()
+        .insert(&genesis_id, &genesis.entity)?;
+    database
+        .storage::<SealedBlockConsensus>()
+        .insert(&genesis_id, &genesis.consensus)?;
+
+    // The checkpoint block commits to the ids of all blocks before it.
+    let prev_height = BlockHeight::from(*height - 1);
+    let prev_root = database.append_block_merkle_leaves(&prev_height, block_ids)?;
+    ensure!(
+        block.entity.header().prev_root() == &Bytes32::from(prev_root),
+        "The block ids of the checkpoint don't match the root of the checkpoint block"
+    );
+
+    let block_id = block.entity.id();
+    database
+        .storage::<FuelBlocks>()
+        .insert(&block_id, &block.entity)?;
+    database
+        .storage::<SealedBlockConsensus>()
+        .insert(&block_id, &block.consensus)?;
+
+    database_transaction.commit()?;
+    tracing::info!("Initialized the database from the checkpoint at height {height}");
+    Ok(())
+}
+
 fn init_coin_state(
     db: &mut Database,
     state: &Option<StateConfig>,
@@ -419,6 +537,29 @@ mod tests {
         )
     }
 
+    #[tokio::test]
+    async fn checkpoint_with_unexpected_state_root_is_rejected() {
+        let checkpoint = Checkpoint {
+            genesis: Default::default(),
+            block_ids: vec![],
+            block: Default::default(),
+            state: Default::default(),
+        };
+        let service_config = Config {
+            checkpoint: Some(CheckpointConfig {
+                checkpoint,
+                state_root: Bytes32::zeroed(),
+            }),
+            ..Config::local_node()
+        };
+
+        let db = Database::default();
+        let result = FuelService::from_database(db.clone(), service_config).await;
+
+        assert!(result.is_err());
+        assert!(db.ids_of_latest_block().unwrap().is_none());
+    }
+
     #[tokio::test]
     async fn config_initializes_block_height() {
         let test_height = BlockHeight::from(99u32);
@@ -0,0 +1,342 @@
+//! Prunes the history of the blocks outside of the retention window in the background.
+//!
+//! The history of the block is the rollback changes, the state diff, and the receipts
+//! of its transactions. The blocks within the `max_reorg_depth` can still be reverted
+//! by the competing chain, so they are never pruned, even if the retention window is
+//! smaller. The blocks including the messages from the DA blocks that are not finalized
+//! yet may be reverted together with the DA layer, so they are never pruned either.
+//! The pruning is done in batches after every imported block, so the service catches
+//! up with the old history without blocking the shutdown.
+
+use crate::{
+    database::Database,
+    service::config::PruningConfig,
+};
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    StateWatcher,
+};
+use fuel_core_storage::{
+    not_found,
+    tables::FuelBlocks,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
+    fuel_types::BlockHeight,
+    services::block_importer::ImportResult,
+};
+use std::sync::Arc;
+use tokio::sync::broadcast::{
+    self,
+    error::RecvError,
+};
+
+#[cfg(feature = "metrics")]
+use fuel_core_metrics::pruning_metrics::PRUNING_METRICS;
+
+/// The source of the latest DA height finalized by the DA layer.
+pub trait FinalizedDaHeight: Send + Sync {
+    /// Returns `None` if the node doesn't follow the DA layer, so all DA heights
+    /// of the blocks are final.
+    fn finalized_da_height(&self) -> anyhow::Result<Option<DaBlockHeight>>;
+}
+
+pub struct Pruner<F> {
+    database: Database,
+    config: PruningConfig,
+    max_reorg_depth: u32,
+    finality: F,
+    blocks: broadcast::Receiver<Arc<ImportResult>>,
+}
+
+impl<F> Pruner<F> {
+    pub fn new(
+        database: Database,
+        config: PruningConfig,
+        max_reorg_depth: u32,
+        finality: F,
+        blocks: broadcast::Receiver<Arc<ImportResult>>,
+    ) -> Self {
+        Self {
+            database,
+            config,
+            max_reorg_depth,
+            finality,
+            blocks,
+        }
+    }
+}
+
+pub struct Task<F> {
+    database: Database,
+    config: PruningConfig,
+    max_reorg_depth: u32,
+    finality: F,
+    blocks: broadcast::Receiver<Arc<ImportResult>>,
+    /// The history of some blocks may still be pruned.
+    pending: bool,
+}
+
+impl<F> Task<F>
+where
+    F: FinalizedDaHeight,
+{
+    /// The height of the latest block with the prunable history, if any.
+    fn prunable_height(&self) -> anyhow::Result<Option<BlockHeight>> {
+        let latest = self.database.latest_height()?;
+        let window = self.config.retention.max(self.max_reorg_depth);
+        let prunable: BlockHeight = match latest.checked_sub(window) {
+            Some(prunable) => prunable.into(),
+            None => return Ok(None),
+        };
+        match self.finality.finalized_da_height()? {
+            Some(finalized) => self.latest_finalized_height(prunable, finalized),
+            None => Ok(Some(prunable)),
+        }
+    }
+
+    /// The height of the latest block not above the `height` that includes only
+    /// the messages from the `finalized` DA blocks. The DA heights of the blocks
+    /// never decrease, so the search is binary.
+    fn latest_finalized_height(
+        &self,
+        height: BlockHeight,
+        finalized: DaBlockHeight,
+    ) -> anyhow::Result<Option<BlockHeight>> {
+        let genesis = self.database.ids_of_genesis_block()?.0;
+        if self.da_height(&genesis)? > finalized {
+            return Ok(None)
+        }
+        let (mut low, mut high) = (*genesis, *height);
+        while low < high {
+            let middle = low + (high - low + 1) / 2;
+            if self.da_height(&middle.into())? <= finalized {
+                low = middle;
+            } else {
+                high = middle - 1;
+            }
+        }
+        Ok(Some(low.into()))
+    }
+
+    fn da_height(&self, height: &BlockHeight) -> anyhow::Result<DaBlockHeight> {
+        let block_id = self
+            .database
+            .get_block_id(height)?
+            .ok_or(not_found!("BlockId"))?;
+        let block = self
+            .database
+            .storage::<FuelBlocks>()
+            .get(&block_id)?
+            .ok_or(not_found!(FuelBlocks))?;
+        Ok(block.header().da_height)
+    }
+
+    /// Prunes the history of the next batch of blocks and returns `true`
+    /// if more blocks can be pruned.
+    fn prune_batch(&self) -> anyhow::Result<bool> {
+        let prunable = match self.prunable_height()? {
+            Some(prunable) => prunable,
+            None => return Ok(false),
+        };
+        let next = match self.database.pruned_height()? {
+            Some(pruned) => (*pruned + 1).into(),
+            None => self.database.ids_of_genesis_block()?.0,
+        };
+        if next > prunable {
+            return Ok(false)
+        }
+
+        let batch_end = next.saturating_add(self.config.batch_size.saturating_sub(1));
+        let last = prunable.min(batch_end.into());
+        let mut reclaimed = 0;
+        for height in *next..=*last {
+            reclaimed += self.database.prune_block_history(&height.into())?;
+        }
+        tracing::debug!(
+            "Pruned the history of the blocks {}..={}, reclaimed {} bytes",
+            next,
+            last,
+            reclaimed
+        );
+
+        #[cfg(feature = "metrics")]
+        {
+            PRUNING_METRICS
+                .pruned_blocks
+                .inc_by((*last - *next + 1) as u64);
+            PRUNING_METRICS.reclaimed_bytes.inc_by(reclaimed as u64);
+            PRUNING_METRICS.pruned_height.set(*last as i64);
+        }
+        Ok(last < prunable)
+    }
+}
+
+#[async_trait::async_trait]
+impl<F> RunnableService for Pruner<F>
+where
+    F: FinalizedDaHeight + 'static,
+{
+    const NAME: &'static str = "Pruner";
+
+    type SharedData = ();
+    type Task = Task<F>;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        Ok(Task {
+            database: self.database,
+            config: self.config,
+            max_reorg_depth: self.max_reorg_depth,
+            finality: self.finality,
+            blocks: self.blocks,
+            // Catches up with the history left by the previous run.
+            pending: true,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl<F> RunnableTask for Task<F>
+where
+    F: FinalizedDaHeight + 'static,
+{
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        if self.pending {
+            // The failed batch is retried after the next block.
+            self.pending = false;
+            self.pending = self.prune_batch()?;
+            tokio::task::yield_now().await;
+            return Ok(true)
+        }
+
+        tokio::select! {
+            biased;
+            _ = watcher.while_started() => Ok(false),
+            result = self.blocks.recv() => match result {
+                Ok(_) | Err(RecvError::Lagged(_)) => {
+                    self.pending = true;
+                    Ok(true)
+                }
+                Err(RecvError::Closed) => Ok(false),
+            },
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::StorageAsMut;
+    use fuel_core_types::{
+        blockchain::{
+            block::PartialFuelBlock,
+            header::{
+                ApplicationHeader,
+                ConsensusHeader,
+                PartialBlockHeader,
+            },
+            primitives::Empty,
+        },
+        fuel_types::ChainId,
+    };
+
+    struct Finalized(Option<u64>);
+
+    impl FinalizedDaHeight for Finalized {
+        fn finalized_da_height(&self) -> anyhow::Result<Option<DaBlockHeight>> {
+            Ok(self.0.map(Into::into))
+        }
+    }
+
+    /// The DA height of every block is equal to its height.
+    fn task(
+        latest: u32,
+        retention: u32,
+        max_reorg_depth: u32,
+        finalized_da_height: Option<u64>,
+    ) -> Task<Finalized> {
+        let mut database = Database::default();
+        for height in 0..=latest {
+            let header = PartialBlockHeader {
+                application: ApplicationHeader {
+                    da_height: (height as u64).into(),
+                    generated: Empty,
+                },
+                consensus: ConsensusHeader::<Empty> {
+                    height: height.into(),
+                    ..Default::default()
+                },
+            };
+            let block = PartialFuelBlock::new(header, vec![]).generate(&[]);
+            database
+                .storage::<FuelBlocks>()
+                .insert(&block.id(), &block.compress(&ChainId::default()))
+                .unwrap();
+        }
+        let (_, blocks) = broadcast::channel(1);
+        Task {
+            database,
+            config: PruningConfig {
+                retention,
+                batch_size: 3,
+            },
+            max_reorg_depth,
+            finality: Finalized(finalized_da_height),
+            blocks,
+            pending: true,
+        }
+    }
+
+    #[test]
+    fn pruning_keeps_the_retention_window() {
+        let task = task(10, 4, 2, None);
+
+        assert!(task.prune_batch().unwrap());
+        assert_eq!(task.database.pruned_height().unwrap(), Some(2u32.into()));
+        assert!(task.prune_batch().unwrap());
+        assert_eq!(task.database.pruned_height().unwrap(), Some(5u32.into()));
+        assert!(!task.prune_batch().unwrap());
+        assert_eq!(task.database.pruned_height().unwrap(), Some(6u32.into()));
+        assert!(!task.prune_batch().unwrap());
+        assert_eq!(task.database.pruned_height().unwrap(), Some(6u32.into()));
+    }
+
+    #[test]
+    fn pruning_never_reaches_the_blocks_within_the_reorg_depth() {
+        let task = task(10, 1, 5, None);
+
+        while task.prune_batch().unwrap() {}
+
+        assert_eq!(task.database.pruned_height().unwrap(), Some(5u32.into()));
+    }
+
+    #[test]
+    fn nothing_is_pruned_within_the_retention_window() {
+        let task = task(3, 10, 0, None);
+
+        assert!(!task.prune_batch().unwrap());
+        assert_eq!(task.database.pruned_height().unwrap(), None);
+    }
+
+    #[test]
+    fn pruning_never_reaches_the_blocks_above_the_finalized_da_height() {
+        let task = task(10, 1, 1, Some(4));
+
+        while task.prune_batch().unwrap() {}
+
+        assert_eq!(task.database.pruned_height().unwrap(), Some(4u32.into()));
+    }
+}
@@ -0,0 +1,125 @@
+//! Appends the state diffs of the imported blocks to the file, one JSON object per line,
+//! so the indexers can consume them without re-deriving the changes from the receipts.
+
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    StateWatcher,
+};
+use fuel_core_types::{
+    blockchain::primitives::BlockId,
+    fuel_types::BlockHeight,
+    services::block_importer::{
+        ImportResult,
+        StateDiff,
+    },
+};
+use serde::Serialize;
+use std::{
+    fs::{
+        File,
+        OpenOptions,
+    },
+    io::Write,
+    path::PathBuf,
+    sync::Arc,
+};
+use tokio::sync::broadcast::{
+    self,
+    error::RecvError,
+};
+
+/// The line of the sink.
+#[derive(Serialize)]
+struct StateDiffRecord<'a> {
+    height: BlockHeight,
+    block_id: BlockId,
+    state_diff: &'a StateDiff,
+}
+
+pub struct StateDiffSink {
+    path: PathBuf,
+    blocks: broadcast::Receiver<Arc<ImportResult>>,
+}
+
+impl StateDiffSink {
+    pub fn new(path: PathBuf, blocks: broadcast::Receiver<Arc<ImportResult>>) -> Self {
+        Self { path, blocks }
+    }
+}
+
+pub struct Task {
+    file: File,
+    blocks: broadcast::Receiver<Arc<ImportResult>>,
+}
+
+impl Task {
+    fn write(&mut self, result: &ImportResult) -> anyhow::Result<()> {
+        let state_diff = match result.state_diff.as_ref() {
+            Some(state_diff) => state_diff,
+            None => return Ok(()),
+        };
+        let block = &result.sealed_block.entity;
+        let record = StateDiffRecord {
+            height: *block.header().height(),
+            block_id: block.id(),
+            state_diff,
+        };
+        let mut line = serde_json::to_vec(&record)?;
+        line.push(b'\n');
+        self.file.write_all(&line)?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableService for StateDiffSink {
+    const NAME: &'static str = "StateDiffSink";
+
+    type SharedData = ();
+    type Task = Task;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        Ok(Task {
+            file,
+            blocks: self.blocks,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        tokio::select! {
+            biased;
+            _ = watcher.while_started() => Ok(false),
+            result = self.blocks.recv() => match result {
+                Ok(result) => {
+                    self.write(&result)?;
+                    Ok(true)
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::warn!("The state diffs of {} blocks were not written", skipped);
+                    Ok(true)
+                }
+                Err(RecvError::Closed) => Ok(false),
+            },
+        }
+    }
+
+    async fn shutdown(mut self) -> anyhow::Result<()> {
+        self.file.flush()?;
+        Ok(())
+    }
+}
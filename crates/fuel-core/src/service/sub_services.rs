@@ -3,24 +3,36 @@ use super::adapters::P2PAdapter;
 
 use crate::{
     database::Database,
+    executor::ExecutorCache,
     fuel_core_graphql_api::Config as GraphQLConfig,
     schema::build_schema,
     service::{
         adapters::{
+            txpool::DynamicGasPricePolicy,
             BlockImporterAdapter,
             BlockProducerAdapter,
             ExecutorAdapter,
+            HealthAdapter,
             MaybeRelayerAdapter,
             PoAAdapter,
+            SyncAdapter,
             TxPoolAdapter,
             VerifierAdapter,
         },
+        chain_stats::ChainStatsCollector,
+        compactor::Compactor,
+        gas_price::GasPriceUpdater,
+        pruner::Pruner,
+        state_diff_sink::StateDiffSink,
+        tiering::ColdStorageMover,
         Config,
         SharedState,
         SubServices,
     },
 };
 use fuel_core_poa::Trigger;
+use fuel_core_producer::GasPriceTracker;
+use fuel_core_services::ServiceRunner;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
@@ -63,14 +75,19 @@ pub fn init_sub_services(
         da_deploy_height: config.relayer.da_deploy_height,
     };
 
+    let executor_config = fuel_core_executor::Config {
+        consensus_parameters: config.chain_conf.consensus_parameters.clone(),
+        coinbase_recipient: config.block_producer.coinbase_recipient,
+        backtrace: config.vm.backtrace,
+        utxo_validation_default: config.utxo_validation,
+        parallel_workers: config.block_executor.parallel_workers,
+        contract_code_cache_size: config.block_executor.contract_code_cache_size,
+        predicate_cache_size: config.block_executor.predicate_cache_size,
+    };
     let executor = ExecutorAdapter {
         relayer: relayer_adapter.clone(),
-        config: Arc::new(fuel_core_executor::Config {
-            consensus_parameters: config.chain_conf.consensus_parameters.clone(),
-            coinbase_recipient: config.block_producer.coinbase_recipient,
-            backtrace: config.vm.backtrace,
-            utxo_validation_default: config.utxo_validation,
-        }),
+        cache: Arc::new(ExecutorCache::new(&executor_config)),
+        config: Arc::new(executor_config),
     };
 
     let verifier =
@@ -85,10 +102,11 @@ pub fn init_sub_services(
 
     #[cfg(feature = "p2p")]
     let mut network = {
-        if let Some(config) = config.p2p.clone() {
+        if let Some(p2p_config) = config.p2p.clone() {
             let p2p_db = database.clone();
             let genesis = p2p_db.get_genesis()?;
-            let p2p_config = config.init(genesis)?;
+            let mut p2p_config = p2p_config.init(genesis)?;
+            p2p_config.chain_id = config.chain_conf.consensus_parameters.chain_id;
 
             Some(fuel_core_p2p::service::new_service(
                 p2p_config,
@@ -108,8 +126,19 @@ pub fn init_sub_services(
 
     let p2p_adapter = p2p_adapter;
 
+    let gas_price = GasPriceTracker::new(
+        config.txpool.min_gas_price,
+        config.block_producer.dynamic_gas_price.clone(),
+    );
+    let mut txpool_config = config.txpool.clone();
+    if gas_price.is_dynamic() {
+        txpool_config
+            .admission_policies
+            .push(Arc::new(DynamicGasPricePolicy(gas_price.clone())));
+    }
+
     let txpool = fuel_core_txpool::new_service(
-        config.txpool.clone(),
+        txpool_config,
         database.clone(),
         importer_adapter.clone(),
         p2p_adapter.clone(),
@@ -121,14 +150,17 @@ pub fn init_sub_services(
         db: database.clone(),
         txpool: tx_pool_adapter.clone(),
         executor: Arc::new(executor),
-        relayer: Box::new(relayer_adapter),
+        relayer: Box::new(relayer_adapter.clone()),
+        gas_price: gas_price.clone(),
         lock: Mutex::new(()),
     };
     let producer_adapter = BlockProducerAdapter::new(block_producer);
 
     let poa_config: fuel_core_poa::Config = config.try_into()?;
-    let production_enabled =
-        !matches!(poa_config.trigger, Trigger::Never) || config.manual_blocks_enabled;
+    // The light client doesn't have the state to produce blocks.
+    let production_enabled = !config.light_client
+        && (!matches!(poa_config.trigger, Trigger::Never)
+            || config.manual_blocks_enabled);
 
     let poa = (production_enabled).then(|| {
         fuel_core_poa::new_service(
@@ -145,11 +177,18 @@ pub fn init_sub_services(
     #[cfg(feature = "p2p")]
     let sync = fuel_core_sync::service::new_service(
         *last_block.header().height(),
-        p2p_adapter,
+        p2p_adapter.clone(),
         importer_adapter.clone(),
         verifier,
-        config.sync,
+        fuel_core_sync::Config {
+            headers_only: config.light_client,
+            ..config.sync
+        },
     )?;
+    #[cfg(feature = "p2p")]
+    let sync_adapter = SyncAdapter::new(network.as_ref().map(|_| sync.shared.clone()));
+    #[cfg(not(feature = "p2p"))]
+    let sync_adapter = SyncAdapter::new();
 
     // TODO: Figure out on how to move it into `fuel-core-graphql-api`.
     let schema = {
@@ -167,23 +206,51 @@ pub fn init_sub_services(
         }
     };
 
+    let health_adapter = HealthAdapter {
+        database: database.clone(),
+        p2p: p2p_adapter.clone(),
+        #[cfg(feature = "relayer")]
+        relayer: relayer_service.as_ref().map(|r| r.shared.clone()),
+    };
+
+    let chain_stats = ServiceRunner::new(ChainStatsCollector::new(
+        database.clone(),
+        config.chain_conf.consensus_parameters.chain_id,
+        importer_adapter.block_importer.subscribe(),
+    ));
+
     let graph_ql = crate::fuel_core_graphql_api::service::new_service(
         GraphQLConfig {
             addr: config.addr,
             utxo_validation: config.utxo_validation,
             manual_blocks_enabled: config.manual_blocks_enabled,
+            peer_admin_enabled: config.peer_admin_enabled,
+            production_admin_enabled: config.production_admin_enabled,
+            database_admin_enabled: config.database_admin_enabled,
+            database_checkpoint_dir: config.database_checkpoint_dir.clone(),
             vm_backtrace: config.vm.backtrace,
+            vm_tracing: config.vm.tracing,
             min_gas_price: config.txpool.min_gas_price,
             max_tx: config.txpool.max_tx,
             max_depth: config.txpool.max_depth,
+            block_gas_limit: config.chain_conf.block_gas_limit,
             consensus_parameters: config.chain_conf.consensus_parameters.clone(),
             consensus_key: config.consensus_key.clone(),
+            limits: config.query_limits.clone(),
+            api_tokens: config.api_tokens.clone(),
+            tls: config.tls.clone(),
+            cors_allowed_origins: config.cors_allowed_origins.clone(),
         },
         schema,
         Box::new(database.clone()),
         Box::new(tx_pool_adapter),
         Box::new(producer_adapter),
         Box::new(poa_adapter),
+        Box::new(p2p_adapter),
+        Arc::new(sync_adapter),
+        Box::new(importer_adapter.clone()),
+        Arc::new(health_adapter),
+        Box::new(chain_stats.shared.clone()),
         config.query_log_threshold_time,
     )?;
 
@@ -191,6 +258,8 @@ pub fn init_sub_services(
         txpool: txpool.shared.clone(),
         #[cfg(feature = "p2p")]
         network: network.as_ref().map(|n| n.shared.clone()),
+        #[cfg(feature = "p2p")]
+        sync: network.as_ref().map(|_| sync.shared.clone()),
         #[cfg(feature = "relayer")]
         relayer: relayer_service.as_ref().map(|r| r.shared.clone()),
         graph_ql: graph_ql.shared.clone(),
@@ -205,6 +274,7 @@ pub fn init_sub_services(
         // GraphQL should be shutdown first, so let's start it first.
         Box::new(graph_ql),
         Box::new(txpool),
+        Box::new(chain_stats),
     ];
 
     if let Some(poa) = poa {
@@ -224,5 +294,45 @@ pub fn init_sub_services(
         }
     }
 
+    if gas_price.is_dynamic() {
+        services.push(Box::new(ServiceRunner::new(GasPriceUpdater::new(
+            gas_price,
+            config.chain_conf.block_gas_limit,
+            shared.block_importer.block_importer.subscribe(),
+        ))));
+    }
+
+    if let Some(path) = config.state_diff_sink.clone() {
+        services.push(Box::new(ServiceRunner::new(StateDiffSink::new(
+            path,
+            shared.block_importer.block_importer.subscribe(),
+        ))));
+    }
+
+    if let Some(pruning) = config.pruning.clone() {
+        services.push(Box::new(ServiceRunner::new(Pruner::new(
+            database.clone(),
+            pruning,
+            config.block_importer.max_reorg_depth,
+            relayer_adapter,
+            shared.block_importer.block_importer.subscribe(),
+        ))));
+    }
+
+    if let Some(tiering) = config.tiering.clone() {
+        services.push(Box::new(ServiceRunner::new(ColdStorageMover::new(
+            database.clone(),
+            tiering,
+            shared.block_importer.block_importer.subscribe(),
+        ))));
+    }
+
+    if let Some(compaction) = config.compaction.clone() {
+        services.push(Box::new(ServiceRunner::new(Compactor::new(
+            database.clone(),
+            compaction,
+        ))));
+    }
+
     Ok((services, shared))
 }
@@ -0,0 +1,223 @@
+//! Moves the blocks older than the threshold to the cold storage in the background.
+//!
+//! The blocks are moved with their consensus, transactions, and receipts, and stay
+//! readable through the database, see [`TieredStore`](crate::state::tiered::TieredStore).
+//! The moving is done in batches after every imported block, so the service catches
+//! up with the old blocks without blocking the shutdown.
+
+use crate::{
+    database::Database,
+    service::config::TieringConfig,
+};
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    StateWatcher,
+};
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    services::block_importer::ImportResult,
+};
+use std::sync::Arc;
+use tokio::sync::broadcast::{
+    self,
+    error::RecvError,
+};
+
+pub struct ColdStorageMover {
+    database: Database,
+    config: TieringConfig,
+    blocks: broadcast::Receiver<Arc<ImportResult>>,
+}
+
+impl ColdStorageMover {
+    pub fn new(
+        database: Database,
+        config: TieringConfig,
+        blocks: broadcast::Receiver<Arc<ImportResult>>,
+    ) -> Self {
+        Self {
+            database,
+            config,
+            blocks,
+        }
+    }
+}
+
+pub struct Task {
+    database: Database,
+    config: TieringConfig,
+    blocks: broadcast::Receiver<Arc<ImportResult>>,
+    /// Some blocks may still be moved.
+    pending: bool,
+}
+
+impl Task {
+    /// The height of the latest block that can be moved to the cold storage, if any.
+    fn movable_height(&self) -> anyhow::Result<Option<BlockHeight>> {
+        let latest = self.database.latest_height()?;
+        Ok(latest.checked_sub(self.config.threshold).map(Into::into))
+    }
+
+    /// Moves the next batch of blocks and returns `true` if more blocks can be moved.
+    fn move_batch(&self) -> anyhow::Result<bool> {
+        let movable = match self.movable_height()? {
+            Some(movable) => movable,
+            None => return Ok(false),
+        };
+        let next = match self.database.cold_height()? {
+            Some(moved) => (*moved + 1).into(),
+            None => self.database.ids_of_genesis_block()?.0,
+        };
+        if next > movable {
+            return Ok(false)
+        }
+
+        let batch_end = next.saturating_add(self.config.batch_size.saturating_sub(1));
+        let last = movable.min(batch_end.into());
+        let mut moved = 0;
+        for height in *next..=*last {
+            moved += self.database.move_block_to_cold_storage(&height.into())?;
+        }
+        tracing::debug!(
+            "Moved the blocks {}..={} to the cold storage, {} bytes",
+            next,
+            last,
+            moved
+        );
+        Ok(last < movable)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableService for ColdStorageMover {
+    const NAME: &'static str = "ColdStorageMover";
+
+    type SharedData = ();
+    type Task = Task;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        Ok(Task {
+            database: self.database,
+            config: self.config,
+            blocks: self.blocks,
+            // Catches up with the blocks left by the previous run.
+            pending: true,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        if self.pending {
+            // The failed batch is retried after the next block.
+            self.pending = false;
+            self.pending = self.move_batch()?;
+            tokio::task::yield_now().await;
+            return Ok(true)
+        }
+
+        tokio::select! {
+            biased;
+            _ = watcher.while_started() => Ok(false),
+            result = self.blocks.recv() => match result {
+                Ok(_) | Err(RecvError::Lagged(_)) => {
+                    self.pending = true;
+                    Ok(true)
+                }
+                Err(RecvError::Closed) => Ok(false),
+            },
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{
+        in_memory::memory_store::MemoryStore,
+        tiered::TieredStore,
+    };
+    use fuel_core_storage::{
+        tables::FuelBlocks,
+        StorageAsMut,
+    };
+    use fuel_core_types::{
+        blockchain::{
+            block::PartialFuelBlock,
+            header::{
+                ConsensusHeader,
+                PartialBlockHeader,
+            },
+            primitives::Empty,
+        },
+        fuel_types::ChainId,
+    };
+    use std::path::PathBuf;
+
+    fn task(latest: u32, threshold: u32) -> Task {
+        let mut database = Database::new(Arc::new(TieredStore::new(
+            Arc::new(MemoryStore::default()),
+            Arc::new(MemoryStore::default()),
+        )));
+        for height in 0..=latest {
+            let header = PartialBlockHeader {
+                application: Default::default(),
+                consensus: ConsensusHeader::<Empty> {
+                    height: height.into(),
+                    ..Default::default()
+                },
+            };
+            let block = PartialFuelBlock::new(header, vec![]).generate(&[]);
+            database
+                .storage::<FuelBlocks>()
+                .insert(&block.id(), &block.compress(&ChainId::default()))
+                .unwrap();
+        }
+        let (_, blocks) = broadcast::channel(1);
+        Task {
+            database,
+            config: TieringConfig {
+                path: PathBuf::new(),
+                threshold,
+                batch_size: 3,
+            },
+            blocks,
+            pending: true,
+        }
+    }
+
+    #[test]
+    fn blocks_older_than_the_threshold_are_moved() {
+        let task = task(10, 4);
+
+        assert!(task.move_batch().unwrap());
+        assert_eq!(task.database.cold_height().unwrap(), Some(2u32.into()));
+        assert!(task.move_batch().unwrap());
+        assert_eq!(task.database.cold_height().unwrap(), Some(5u32.into()));
+        assert!(!task.move_batch().unwrap());
+        assert_eq!(task.database.cold_height().unwrap(), Some(6u32.into()));
+        assert!(!task.move_batch().unwrap());
+        assert_eq!(task.database.cold_height().unwrap(), Some(6u32.into()));
+    }
+
+    #[test]
+    fn nothing_is_moved_within_the_threshold() {
+        let task = task(3, 10);
+
+        assert!(!task.move_batch().unwrap());
+        assert_eq!(task.database.cold_height().unwrap(), None);
+    }
+}
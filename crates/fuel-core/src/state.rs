@@ -1,5 +1,6 @@
 use crate::database::{
     Column,
+    Error as DatabaseError,
     Result as DatabaseResult,
 };
 use fuel_core_storage::iter::{
@@ -8,10 +9,11 @@ use fuel_core_storage::iter::{
 };
 use std::{
     fmt::Debug,
+    path::Path,
     sync::Arc,
 };
 
-pub type DataSource = Arc<dyn TransactableStorage>;
+pub type DataSource = Arc<dyn KvStore>;
 pub type Value = Arc<Vec<u8>>;
 pub type KVItem = DatabaseResult<(Vec<u8>, Value)>;
 
@@ -86,8 +88,62 @@ pub enum WriteOperation {
     Remove,
 }
 
-pub trait TransactableStorage: BatchOperations + Debug + Send + Sync {}
+/// The key-value store behind the [`Database`](crate::database::Database).
+/// The database works on top of any implementation, so the backend is selected
+/// at runtime, see [`DbType`](crate::service::DbType).
+pub trait KvStore: BatchOperations + Debug + Send + Sync {
+    /// Returns the operations that revert the pending changes of the storage
+    /// on top of its data source. The storage without pending changes returns nothing.
+    fn revert_operations(
+        &self,
+    ) -> DatabaseResult<Vec<(Vec<u8>, Column, WriteOperation)>> {
+        Ok(vec![])
+    }
+
+    /// Returns the consistent view of the store at the current moment.
+    fn snapshot(&self) -> DatabaseResult<Box<dyn KvSnapshot + '_>>;
+
+    /// Compacts the `column` or all columns if unset, so the space of the removed and
+    /// overwritten entries is reclaimed. The stores without the files do nothing.
+    fn compact(&self, _column: Option<Column>) -> DatabaseResult<()> {
+        Ok(())
+    }
+
+    /// Creates the checkpoint of the store at the `path`, the consistent copy that can
+    /// be opened as a separate store. The files are hard linked when the `path` is on
+    /// the same file system. Only the [`RocksDb`](rocks_db::RocksDb) supports it.
+    fn checkpoint(&self, _path: &Path) -> DatabaseResult<()> {
+        Err(DatabaseError::Other(anyhow::anyhow!(
+            "The store doesn't support the checkpoints"
+        )))
+    }
+
+    /// Moves the entries to the cold storage and returns the number of the moved bytes.
+    /// Only the [`TieredStore`](tiered::TieredStore) has the cold storage.
+    fn move_to_cold(&self, _entries: &[(Vec<u8>, Column)]) -> DatabaseResult<usize> {
+        Err(DatabaseError::Other(anyhow::anyhow!(
+            "The store doesn't have the cold storage"
+        )))
+    }
+}
+
+/// The read-only view of the [`KvStore`] at the moment of its creation.
+/// The writes to the store after the creation are not visible through the snapshot.
+pub trait KvSnapshot: Send + Sync {
+    fn get(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>>;
+
+    fn iter_all(
+        &self,
+        column: Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem>;
+}
 
 pub mod in_memory;
 #[cfg(feature = "rocksdb")]
 pub mod rocks_db;
+pub mod rocks_db_config;
+pub mod snapshot;
+pub mod tiered;
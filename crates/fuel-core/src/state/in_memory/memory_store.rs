@@ -9,7 +9,7 @@ use crate::{
         IterDirection,
         KVItem,
         KeyValueStore,
-        TransactableStorage,
+        KvStore,
         Value,
     },
 };
@@ -212,13 +212,60 @@ impl KeyValueStore for MemoryStore {
 
 impl BatchOperations for MemoryStore {}
 
-impl TransactableStorage for MemoryStore {}
+impl KvStore for MemoryStore {
+    fn snapshot(&self) -> DatabaseResult<Box<dyn crate::state::KvSnapshot + '_>> {
+        // The values are shared between the store and the snapshot.
+        let snapshot = MemoryStore::default();
+        for (column, copy) in self.inner.iter().zip(snapshot.inner.iter()) {
+            *copy.lock().expect("poisoned") = column.lock().expect("poisoned").clone();
+        }
+        Ok(Box::new(snapshot))
+    }
+}
+
+impl crate::state::KvSnapshot for MemoryStore {
+    fn get(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        KeyValueStore::get(self, key, column)
+    }
+
+    fn iter_all(
+        &self,
+        column: Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        self.iter_all(column, prefix, start, direction).into_boxed()
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::sync::Arc;
 
+    #[test]
+    fn snapshot_does_not_see_later_writes() {
+        let db = MemoryStore::default();
+        db.put(&[1], Column::Metadata, Arc::new(vec![1])).unwrap();
+
+        let snapshot = db.snapshot().unwrap();
+        db.put(&[1], Column::Metadata, Arc::new(vec![2])).unwrap();
+        db.put(&[2], Column::Metadata, Arc::new(vec![2])).unwrap();
+
+        assert_eq!(
+            snapshot.get(&[1], Column::Metadata).unwrap(),
+            Some(Arc::new(vec![1]))
+        );
+        assert_eq!(
+            snapshot
+                .iter_all(Column::Metadata, None, None, IterDirection::Forward)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            vec![(vec![1], Arc::new(vec![1]))]
+        );
+    }
+
     #[test]
     fn can_use_unit_value() {
         let key = vec![0x00];
@@ -1,6 +1,7 @@
 use crate::{
     database::{
         Column,
+        Error as DatabaseError,
         Result as DatabaseResult,
     },
     state::{
@@ -10,7 +11,7 @@ use crate::{
         IterDirection,
         KVItem,
         KeyValueStore,
-        TransactableStorage,
+        KvStore,
         Value,
         WriteOperation,
     },
@@ -284,7 +285,37 @@ impl KeyValueStore for MemoryTransactionView {
 
 impl BatchOperations for MemoryTransactionView {}
 
-impl TransactableStorage for MemoryTransactionView {}
+impl KvStore for MemoryTransactionView {
+    fn revert_operations(
+        &self,
+    ) -> DatabaseResult<Vec<(Vec<u8>, Column, WriteOperation)>> {
+        let mut operations = vec![];
+        for (column_map, column) in
+            self.changes.iter().zip(enum_iterator::all::<Column>())
+        {
+            let keys: Vec<_> = column_map
+                .lock()
+                .expect("poisoned lock")
+                .keys()
+                .cloned()
+                .collect();
+            for key in keys {
+                let operation = match self.data_source.get(&key, column)? {
+                    Some(previous) => WriteOperation::Insert(previous),
+                    None => WriteOperation::Remove,
+                };
+                operations.push((key, column, operation));
+            }
+        }
+        Ok(operations)
+    }
+
+    fn snapshot(&self) -> DatabaseResult<Box<dyn crate::state::KvSnapshot + '_>> {
+        Err(DatabaseError::Other(anyhow::anyhow!(
+            "The snapshot of the uncommitted changes is not supported"
+        )))
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -708,4 +739,39 @@ mod tests {
             expected
         );
     }
+
+    #[test]
+    fn revert_operations_restore_the_data_source() {
+        let store = Arc::new(MemoryStore::default());
+        let existing = vec![0xA];
+        let removed = vec![0xB];
+        let inserted = vec![0xC];
+        store
+            .put(&existing, Column::Metadata, Arc::new(vec![1]))
+            .unwrap();
+        store
+            .put(&removed, Column::Metadata, Arc::new(vec![2]))
+            .unwrap();
+
+        let view = MemoryTransactionView::new(store.clone());
+        view.put(&existing, Column::Metadata, Arc::new(vec![3]))
+            .unwrap();
+        view.delete(&removed, Column::Metadata).unwrap();
+        view.put(&inserted, Column::Metadata, Arc::new(vec![4]))
+            .unwrap();
+        let revert = view.revert_operations().unwrap();
+        view.commit().unwrap();
+
+        store.batch_write(&mut revert.into_iter()).unwrap();
+
+        assert_eq!(
+            store.get(&existing, Column::Metadata).unwrap().unwrap(),
+            Arc::new(vec![1])
+        );
+        assert_eq!(
+            store.get(&removed, Column::Metadata).unwrap().unwrap(),
+            Arc::new(vec![2])
+        );
+        assert!(!store.exists(&inserted, Column::Metadata).unwrap());
+    }
 }
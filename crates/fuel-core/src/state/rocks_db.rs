@@ -6,11 +6,16 @@ use crate::{
         Result as DatabaseResult,
     },
     state::{
+        rocks_db_config::{
+            Compression,
+            RocksDbConfig,
+        },
         BatchOperations,
         IterDirection,
         KVItem,
         KeyValueStore,
-        TransactableStorage,
+        KvSnapshot,
+        KvStore,
         Value,
         WriteOperation,
     },
@@ -22,16 +27,27 @@ use fuel_core_storage::iter::{
     IntoBoxedIter,
 };
 use rocksdb::{
+    backup::{
+        BackupEngine,
+        BackupEngineOptions,
+        RestoreOptions,
+    },
+    checkpoint::Checkpoint,
+    BlockBasedOptions,
+    BottommostLevelCompaction,
     BoundColumnFamily,
     Cache,
     ColumnFamilyDescriptor,
+    CompactOptions,
     DBCompressionType,
     DBWithThreadMode,
+    Env,
     IteratorMode,
     MultiThreaded,
     Options,
     ReadOptions,
     SliceTransform,
+    SnapshotWithThreadMode,
     WriteBatch,
 };
 use std::{
@@ -39,11 +55,52 @@ use std::{
     path::Path,
     sync::Arc,
 };
+#[cfg(feature = "metrics")]
+use std::{
+    sync::Mutex,
+    time::{
+        Duration,
+        Instant,
+    },
+};
 
 type DB = DBWithThreadMode<MultiThreaded>;
+type Snapshot<'a> = SnapshotWithThreadMode<'a, DB>;
+
+impl From<Compression> for DBCompressionType {
+    fn from(compression: Compression) -> Self {
+        match compression {
+            Compression::None => DBCompressionType::None,
+            Compression::Snappy => DBCompressionType::Snappy,
+            Compression::Lz4 => DBCompressionType::Lz4,
+            Compression::Zstd => DBCompressionType::Zstd,
+        }
+    }
+}
+
+/// The minimal interval between the updates of the metrics of the database state.
+#[cfg(feature = "metrics")]
+const METRICS_REPORT_INTERVAL: Duration = Duration::from_secs(10);
+
 #[derive(Debug)]
 pub struct RocksDb {
     db: DB,
+    #[cfg(feature = "metrics")]
+    metrics_reported_at: Mutex<Option<Instant>>,
+    /// Whether the size, the compactions, and the block cache are reported.
+    #[cfg(feature = "metrics")]
+    report_state_metrics: bool,
+}
+
+/// The description of the backup of the database.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackupInfo {
+    pub id: u32,
+    /// The unix timestamp of the backup in seconds.
+    pub timestamp: i64,
+    /// The size of the backup in bytes, including the files shared with other backups.
+    pub size: u64,
+    pub num_files: u32,
 }
 
 impl RocksDb {
@@ -55,6 +112,7 @@ impl RocksDb {
             path,
             enum_iterator::all::<Column>().collect::<Vec<_>>(),
             capacity,
+            &RocksDbConfig::default(),
         )
     }
 
@@ -62,16 +120,29 @@ impl RocksDb {
         path: P,
         columns: Vec<Column>,
         capacity: Option<usize>,
+        config: &RocksDbConfig,
     ) -> DatabaseResult<RocksDb> {
+        // The block cache is shared by all columns, so its size is the total one.
+        let block_cache = Cache::new_lru_cache(config.block_cache_size);
         let cf_descriptors: Vec<_> = columns
             .clone()
             .into_iter()
-            .map(|i| ColumnFamilyDescriptor::new(RocksDb::col_name(i), Self::cf_opts(i)))
+            .map(|i| {
+                ColumnFamilyDescriptor::new(
+                    RocksDb::col_name(i),
+                    Self::cf_opts(i, config, &block_cache),
+                )
+            })
             .collect();
 
         let mut opts = Options::default();
         opts.create_if_missing(true);
-        opts.set_compression_type(DBCompressionType::Lz4);
+        // The columns added by the newer versions are created in the existing databases.
+        opts.create_missing_column_families(true);
+        opts.set_compression_type(config.compression.into());
+        opts.set_max_background_jobs(config.max_background_jobs);
+        #[cfg(feature = "metrics")]
+        opts.enable_statistics();
         if let Some(capacity) = capacity {
             let cache = Cache::new_lru_cache(capacity);
             opts.set_row_cache(&cache);
@@ -83,7 +154,7 @@ impl RocksDb {
                 match DB::open_cf(&opts, &path, &[] as &[&str]) {
                     Ok(db) => {
                         for i in columns {
-                            let opts = Self::cf_opts(i);
+                            let opts = Self::cf_opts(i, config, &block_cache);
                             db.create_cf(RocksDb::col_name(i), &opts)
                                 .map_err(|e| DatabaseError::Other(e.into()))?;
                         }
@@ -95,10 +166,159 @@ impl RocksDb {
             ok => ok,
         }
         .map_err(|e| DatabaseError::Other(e.into()))?;
-        let rocks_db = RocksDb { db };
+        let rocks_db = RocksDb::new(db);
         Ok(rocks_db)
     }
 
+    fn new(db: DB) -> Self {
+        Self {
+            db,
+            #[cfg(feature = "metrics")]
+            metrics_reported_at: Mutex::new(None),
+            #[cfg(feature = "metrics")]
+            report_state_metrics: true,
+        }
+    }
+
+    /// Disables the metrics of the size, the compactions, and the block cache, so
+    /// the database doesn't overwrite the metrics of the main database.
+    #[cfg(feature = "metrics")]
+    pub fn without_state_metrics(self) -> Self {
+        Self {
+            report_state_metrics: false,
+            ..self
+        }
+    }
+
+    /// Creates a new backup of the database in the `backup_dir` and verifies it.
+    /// The files shared with the previous backups in the directory are not copied
+    /// again, so every backup after the first one is incremental. The database of
+    /// the running node is backed up from its checkpoint, see [`KvStore::checkpoint`].
+    pub fn backup<P: AsRef<Path>>(&self, backup_dir: P) -> DatabaseResult<BackupInfo> {
+        let mut engine = Self::backup_engine(backup_dir)?;
+        engine
+            .create_new_backup_flush(&self.db, true)
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        let info = Self::latest_backup(&engine)?;
+        engine
+            .verify_backup(info.id)
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        Ok(info)
+    }
+
+    /// Verifies the latest backup in the `backup_dir` and restores the database
+    /// from it at the `path`. The database at the `path` must not be opened.
+    pub fn restore<P: AsRef<Path>, B: AsRef<Path>>(
+        path: P,
+        backup_dir: B,
+    ) -> DatabaseResult<BackupInfo> {
+        let mut engine = Self::backup_engine(backup_dir)?;
+        let info = Self::latest_backup(&engine)?;
+        engine.verify_backup(info.id).map_err(|e| {
+            DatabaseError::Other(anyhow::anyhow!(
+                "The backup {} is corrupted: {}",
+                info.id,
+                e
+            ))
+        })?;
+        engine
+            .restore_from_latest_backup(
+                path.as_ref(),
+                path.as_ref(),
+                &RestoreOptions::default(),
+            )
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        Ok(info)
+    }
+
+    fn backup_engine<P: AsRef<Path>>(backup_dir: P) -> DatabaseResult<BackupEngine> {
+        let opts = BackupEngineOptions::new(backup_dir.as_ref())
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        let env = Env::new().map_err(|e| DatabaseError::Other(e.into()))?;
+        BackupEngine::open(&opts, &env).map_err(|e| DatabaseError::Other(e.into()))
+    }
+
+    fn latest_backup(engine: &BackupEngine) -> DatabaseResult<BackupInfo> {
+        engine
+            .get_backup_info()
+            .into_iter()
+            .max_by_key(|info| info.backup_id)
+            .map(|info| BackupInfo {
+                id: info.backup_id,
+                timestamp: info.timestamp,
+                size: info.size,
+                num_files: info.num_files,
+            })
+            .ok_or_else(|| DatabaseError::Other(anyhow::anyhow!("There are no backups")))
+    }
+
+    /// Updates the metrics of the size, the compactions, and the block cache of
+    /// the database, unless they were updated recently.
+    #[cfg(feature = "metrics")]
+    fn report_metrics(&self) {
+        if !self.report_state_metrics {
+            return
+        }
+        {
+            let mut reported_at = match self.metrics_reported_at.try_lock() {
+                Ok(reported_at) => reported_at,
+                // Another thread is reporting the metrics right now.
+                Err(_) => return,
+            };
+            if matches!(*reported_at, Some(at) if at.elapsed() < METRICS_REPORT_INTERVAL)
+            {
+                return
+            }
+            *reported_at = Some(Instant::now());
+        }
+
+        let mut block_cache_usage = None;
+        for column in enum_iterator::all::<Column>() {
+            let cf = match self.db.cf_handle(&RocksDb::col_name(column)) {
+                Some(cf) => cf,
+                None => continue,
+            };
+            // The block cache is shared by all columns.
+            if block_cache_usage.is_none() {
+                block_cache_usage = self
+                    .db
+                    .property_int_value_cf(&cf, "rocksdb.block-cache-usage")
+                    .ok()
+                    .flatten();
+            }
+            if let Ok(Some(size)) = self
+                .db
+                .property_int_value_cf(&cf, "rocksdb.total-sst-files-size")
+            {
+                DATABASE_METRICS.set_column_size(column.into(), size);
+            }
+            if let Ok(Some(pending)) = self
+                .db
+                .property_int_value_cf(&cf, "rocksdb.estimate-pending-compaction-bytes")
+            {
+                DATABASE_METRICS.set_pending_compaction(column.into(), pending);
+            }
+        }
+        if let Ok(Some(running)) = self
+            .db
+            .property_int_value("rocksdb.num-running-compactions")
+        {
+            DATABASE_METRICS.running_compactions.set(running as i64);
+        }
+        if let Some(usage) = block_cache_usage {
+            DATABASE_METRICS.block_cache_usage_bytes.set(usage as i64);
+        }
+        if let Ok(Some(statistics)) = self.db.property_value("rocksdb.options-statistics")
+        {
+            if let Some(hits) = ticker(&statistics, "rocksdb.block.cache.hit") {
+                DATABASE_METRICS.block_cache_hits.set(hits as i64);
+            }
+            if let Some(misses) = ticker(&statistics, "rocksdb.block.cache.miss") {
+                DATABASE_METRICS.block_cache_misses.set(misses as i64);
+            }
+        }
+    }
+
     fn cf(&self, column: Column) -> Arc<BoundColumnFamily> {
         self.db
             .cf_handle(&RocksDb::col_name(column))
@@ -109,10 +329,18 @@ impl RocksDb {
         format!("column-{}", column.as_usize())
     }
 
-    fn cf_opts(column: Column) -> Options {
+    fn cf_opts(column: Column, config: &RocksDbConfig, block_cache: &Cache) -> Options {
         let mut opts = Options::default();
         opts.create_if_missing(true);
-        opts.set_compression_type(DBCompressionType::Lz4);
+        opts.set_compression_type(config.compression_of(column).into());
+        opts.set_write_buffer_size(config.write_buffer_size);
+
+        let mut block_opts = BlockBasedOptions::default();
+        block_opts.set_block_cache(block_cache);
+        if let Some(bits_per_key) = config.bloom_filter_bits_per_key {
+            block_opts.set_bloom_filter(bits_per_key, false);
+        }
+        opts.set_block_based_table_factory(&block_opts);
 
         // All double-keys should be configured here
         match column {
@@ -120,7 +348,9 @@ impl RocksDb {
             | Column::TransactionsByOwnerBlockIdx
             | Column::OwnedMessageIds
             | Column::ContractsAssets
-            | Column::ContractsState => {
+            | Column::ContractsState
+            | Column::AssetHolders
+            | Column::AssetHoldersByBalance => {
                 // prefix is address length
                 opts.set_prefix_extractor(SliceTransform::create_fixed_prefix(32))
             }
@@ -130,12 +360,81 @@ impl RocksDb {
         opts
     }
 
+    /// Iterates over the `column` at the `snapshot` or over the latest state.
+    fn iter_all_at(
+        &self,
+        column: Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+        snapshot: Option<&Snapshot>,
+    ) -> BoxedIter<KVItem> {
+        match (prefix, start) {
+            (None, None) => {
+                let iter_mode =
+                    // if no start or prefix just start iterating over entire keyspace
+                    match direction {
+                        IterDirection::Forward => IteratorMode::Start,
+                        // end always iterates in reverse
+                        IterDirection::Reverse => IteratorMode::End,
+                    };
+                self._iter_all(column, ReadOptions::default(), iter_mode, snapshot)
+                    .into_boxed()
+            }
+            (Some(prefix), None) => {
+                // start iterating in a certain direction within the keyspace
+                let iter_mode =
+                    IteratorMode::From(prefix, convert_to_rocksdb_direction(direction));
+                let mut opts = ReadOptions::default();
+                opts.set_prefix_same_as_start(true);
+
+                self._iter_all(column, opts, iter_mode, snapshot)
+                    .into_boxed()
+            }
+            (None, Some(start)) => {
+                // start iterating in a certain direction from the start key
+                let iter_mode =
+                    IteratorMode::From(start, convert_to_rocksdb_direction(direction));
+                self._iter_all(column, ReadOptions::default(), iter_mode, snapshot)
+                    .into_boxed()
+            }
+            (Some(prefix), Some(start)) => {
+                // TODO: Maybe we want to allow the `start` to be without a `prefix` in the future.
+                // If the `start` doesn't have the same `prefix`, return nothing.
+                if !start.starts_with(prefix) {
+                    return iter::empty().into_boxed()
+                }
+
+                // start iterating in a certain direction from the start key
+                // and end iterating when we've gone outside the prefix
+                let prefix = prefix.to_vec();
+                let iter_mode =
+                    IteratorMode::From(start, convert_to_rocksdb_direction(direction));
+                self._iter_all(column, ReadOptions::default(), iter_mode, snapshot)
+                    .take_while(move |item| {
+                        if let Ok((key, _)) = item {
+                            key.starts_with(prefix.as_slice())
+                        } else {
+                            true
+                        }
+                    })
+                    .into_boxed()
+            }
+        }
+    }
+
     fn _iter_all(
         &self,
         column: Column,
-        opts: ReadOptions,
+        mut opts: ReadOptions,
         iter_mode: IteratorMode,
+        snapshot: Option<&Snapshot>,
     ) -> impl Iterator<Item = KVItem> + '_ {
+        if let Some(snapshot) = snapshot {
+            opts.set_snapshot(snapshot);
+        }
+        #[cfg(feature = "metrics")]
+        DATABASE_METRICS.observe_iterator(column.into());
         self.db
             .iterator_cf_opt(&self.cf(column), opts, iter_mode)
             .map(|item| {
@@ -159,7 +458,10 @@ impl RocksDb {
 impl KeyValueStore for RocksDb {
     fn get(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
         #[cfg(feature = "metrics")]
-        DATABASE_METRICS.read_meter.inc();
+        {
+            DATABASE_METRICS.read_meter.inc();
+            DATABASE_METRICS.observe_read(column.into());
+        }
         let value = self
             .db
             .get_cf(&self.cf(column), key)
@@ -182,6 +484,7 @@ impl KeyValueStore for RocksDb {
         #[cfg(feature = "metrics")]
         {
             DATABASE_METRICS.write_meter.inc();
+            DATABASE_METRICS.observe_write(column.into());
             DATABASE_METRICS.bytes_written.observe(value.len() as f64);
         }
         // FIXME: This is a race condition. We should use a transaction.
@@ -194,6 +497,8 @@ impl KeyValueStore for RocksDb {
     }
 
     fn delete(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        #[cfg(feature = "metrics")]
+        DATABASE_METRICS.observe_delete(column.into());
         // FIXME: This is a race condition. We should use a transaction.
         let prev = self.get(key, column)?;
         // FIXME: This is a race condition. We should use a transaction.
@@ -219,62 +524,15 @@ impl KeyValueStore for RocksDb {
         start: Option<&[u8]>,
         direction: IterDirection,
     ) -> BoxedIter<KVItem> {
-        match (prefix, start) {
-            (None, None) => {
-                let iter_mode =
-                    // if no start or prefix just start iterating over entire keyspace
-                    match direction {
-                        IterDirection::Forward => IteratorMode::Start,
-                        // end always iterates in reverse
-                        IterDirection::Reverse => IteratorMode::End,
-                    };
-                self._iter_all(column, ReadOptions::default(), iter_mode)
-                    .into_boxed()
-            }
-            (Some(prefix), None) => {
-                // start iterating in a certain direction within the keyspace
-                let iter_mode =
-                    IteratorMode::From(prefix, convert_to_rocksdb_direction(direction));
-                let mut opts = ReadOptions::default();
-                opts.set_prefix_same_as_start(true);
-
-                self._iter_all(column, opts, iter_mode).into_boxed()
-            }
-            (None, Some(start)) => {
-                // start iterating in a certain direction from the start key
-                let iter_mode =
-                    IteratorMode::From(start, convert_to_rocksdb_direction(direction));
-                self._iter_all(column, ReadOptions::default(), iter_mode)
-                    .into_boxed()
-            }
-            (Some(prefix), Some(start)) => {
-                // TODO: Maybe we want to allow the `start` to be without a `prefix` in the future.
-                // If the `start` doesn't have the same `prefix`, return nothing.
-                if !start.starts_with(prefix) {
-                    return iter::empty().into_boxed()
-                }
-
-                // start iterating in a certain direction from the start key
-                // and end iterating when we've gone outside the prefix
-                let prefix = prefix.to_vec();
-                let iter_mode =
-                    IteratorMode::From(start, convert_to_rocksdb_direction(direction));
-                self._iter_all(column, ReadOptions::default(), iter_mode)
-                    .take_while(move |item| {
-                        if let Ok((key, _)) = item {
-                            key.starts_with(prefix.as_slice())
-                        } else {
-                            true
-                        }
-                    })
-                    .into_boxed()
-            }
-        }
+        self.iter_all_at(column, prefix, start, direction, None)
     }
 
     fn size_of_value(&self, key: &[u8], column: Column) -> DatabaseResult<Option<usize>> {
         #[cfg(feature = "metrics")]
-        DATABASE_METRICS.read_meter.inc();
+        {
+            DATABASE_METRICS.read_meter.inc();
+            DATABASE_METRICS.observe_read(column.into());
+        }
 
         Ok(self
             .db
@@ -290,7 +548,10 @@ impl KeyValueStore for RocksDb {
         mut buf: &mut [u8],
     ) -> DatabaseResult<Option<usize>> {
         #[cfg(feature = "metrics")]
-        DATABASE_METRICS.read_meter.inc();
+        {
+            DATABASE_METRICS.read_meter.inc();
+            DATABASE_METRICS.observe_read(column.into());
+        }
 
         let r = self
             .db
@@ -317,6 +578,7 @@ impl KeyValueStore for RocksDb {
         #[cfg(feature = "metrics")]
         {
             DATABASE_METRICS.write_meter.inc();
+            DATABASE_METRICS.observe_write(column.into());
             DATABASE_METRICS.bytes_written.observe(buf.len() as f64);
         }
 
@@ -330,7 +592,10 @@ impl KeyValueStore for RocksDb {
 
     fn read_alloc(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
         #[cfg(feature = "metrics")]
-        DATABASE_METRICS.read_meter.inc();
+        {
+            DATABASE_METRICS.read_meter.inc();
+            DATABASE_METRICS.observe_read(column.into());
+        }
 
         let r = self
             .db
@@ -362,6 +627,8 @@ impl KeyValueStore for RocksDb {
     }
 
     fn take(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        #[cfg(feature = "metrics")]
+        DATABASE_METRICS.observe_delete(column.into());
         // FIXME: This is a race condition. We should use a transaction.
         let prev = self.read_alloc(key, column)?;
         // FIXME: This is a race condition. We should use a transaction.
@@ -382,9 +649,13 @@ impl BatchOperations for RocksDb {
         for (key, column, op) in entries {
             match op {
                 WriteOperation::Insert(value) => {
+                    #[cfg(feature = "metrics")]
+                    DATABASE_METRICS.observe_write(column.into());
                     batch.put_cf(&self.cf(column), key, value.as_ref());
                 }
                 WriteOperation::Remove => {
+                    #[cfg(feature = "metrics")]
+                    DATABASE_METRICS.observe_delete(column.into());
                     batch.delete_cf(&self.cf(column), key);
                 }
             }
@@ -398,11 +669,92 @@ impl BatchOperations for RocksDb {
         }
         self.db
             .write(batch)
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        #[cfg(feature = "metrics")]
+        self.report_metrics();
+        Ok(())
+    }
+}
+
+impl KvStore for RocksDb {
+    fn snapshot(&self) -> DatabaseResult<Box<dyn KvSnapshot + '_>> {
+        Ok(Box::new(RocksDbSnapshot {
+            db: self,
+            snapshot: self.db.snapshot(),
+        }))
+    }
+
+    fn compact(&self, column: Option<Column>) -> DatabaseResult<()> {
+        let columns = match column {
+            Some(column) => vec![column],
+            None => enum_iterator::all::<Column>().collect(),
+        };
+        let mut opts = CompactOptions::default();
+        // The tombstones are dropped only when the files of the last level are rewritten.
+        opts.set_bottommost_level_compaction(BottommostLevelCompaction::Force);
+        for column in columns {
+            // The cold storage has only some of the columns.
+            if let Some(cf) = self.db.cf_handle(&RocksDb::col_name(column)) {
+                tracing::info!("Compacting the column {:?}", column);
+                self.db
+                    .compact_range_cf_opt(&cf, None::<&[u8]>, None::<&[u8]>, &opts);
+            }
+        }
+        Ok(())
+    }
+
+    fn checkpoint(&self, path: &Path) -> DatabaseResult<()> {
+        // The checkpoint is consistent without stopping the writes of the node.
+        Checkpoint::new(&self.db)
+            .and_then(|checkpoint| checkpoint.create_checkpoint(path))
             .map_err(|e| DatabaseError::Other(e.into()))
     }
 }
 
-impl TransactableStorage for RocksDb {}
+/// The snapshot of the [`RocksDb`] backed by the native RocksDB snapshot,
+/// so the old versions of the values are kept until it is dropped.
+pub struct RocksDbSnapshot<'a> {
+    db: &'a RocksDb,
+    snapshot: Snapshot<'a>,
+}
+
+impl KvSnapshot for RocksDbSnapshot<'_> {
+    fn get(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        #[cfg(feature = "metrics")]
+        {
+            DATABASE_METRICS.read_meter.inc();
+            DATABASE_METRICS.observe_read(column.into());
+        }
+        self.snapshot
+            .get_cf(&self.db.cf(column), key)
+            .map(|value| value.map(Arc::new))
+            .map_err(|e| DatabaseError::Other(e.into()))
+    }
+
+    fn iter_all(
+        &self,
+        column: Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        self.db
+            .iter_all_at(column, prefix, start, direction, Some(&self.snapshot))
+    }
+}
+
+/// Returns the value of the `name` ticker from the dump of the RocksDB statistics.
+#[cfg(feature = "metrics")]
+fn ticker(statistics: &str, name: &str) -> Option<u64> {
+    statistics.lines().find_map(|line| {
+        line.strip_prefix(name)?
+            .trim()
+            .strip_prefix("COUNT :")?
+            .trim()
+            .parse()
+            .ok()
+    })
+}
 
 #[cfg(test)]
 mod tests {
@@ -428,6 +780,61 @@ mod tests {
         assert_eq!(db.get(&key, Column::Metadata).unwrap().unwrap(), expected)
     }
 
+    #[test]
+    fn snapshot_does_not_see_later_writes() {
+        let (db, _tmp) = create_db();
+        db.put(&[1], Column::Metadata, Arc::new(vec![1])).unwrap();
+
+        let snapshot = db.snapshot().unwrap();
+        db.put(&[1], Column::Metadata, Arc::new(vec![2])).unwrap();
+        db.put(&[2], Column::Metadata, Arc::new(vec![2])).unwrap();
+
+        assert_eq!(
+            snapshot.get(&[1], Column::Metadata).unwrap(),
+            Some(Arc::new(vec![1]))
+        );
+        assert_eq!(
+            snapshot
+                .iter_all(Column::Metadata, None, None, IterDirection::Forward)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            vec![(vec![1], Arc::new(vec![1]))]
+        );
+        assert_eq!(
+            db.get(&[1], Column::Metadata).unwrap(),
+            Some(Arc::new(vec![2]))
+        );
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn ticker_is_parsed_from_statistics() {
+        let statistics = "rocksdb.block.cache.miss COUNT : 12\n\
+            rocksdb.block.cache.hit COUNT : 34\n\
+            rocksdb.db.get.micros P50 : 1.000000 P95 : 2.000000 COUNT : 5 SUM : 7\n";
+
+        assert_eq!(ticker(statistics, "rocksdb.block.cache.hit"), Some(34));
+        assert_eq!(ticker(statistics, "rocksdb.block.cache.miss"), Some(12));
+        assert_eq!(ticker(statistics, "rocksdb.db.get.micros"), None);
+        assert_eq!(ticker(statistics, "rocksdb.block.cache.add"), None);
+    }
+
+    #[test]
+    fn compaction_keeps_the_entries() {
+        let (db, _tmp) = create_db();
+        db.put(&[1], Column::Metadata, Arc::new(vec![1])).unwrap();
+        db.put(&[2], Column::Metadata, Arc::new(vec![2])).unwrap();
+        db.delete(&[1], Column::Metadata).unwrap();
+
+        db.compact(None).unwrap();
+
+        assert_eq!(db.get(&[1], Column::Metadata).unwrap(), None);
+        assert_eq!(
+            db.get(&[2], Column::Metadata).unwrap(),
+            Some(Arc::new(vec![2]))
+        );
+    }
+
     #[test]
     fn put_returns_previous_value() {
         let key = vec![0xA, 0xB, 0xC];
@@ -575,4 +982,87 @@ mod tests {
 
         assert!(!db.exists(&key, Column::Metadata).unwrap());
     }
+
+    /// Backs up the checkpoint of the opened `db`, as the node does.
+    fn backup_checkpoint(db: &RocksDb, backup_dir: &Path) -> BackupInfo {
+        let checkpoint_dir = TempDir::new().unwrap();
+        let checkpoint_path = checkpoint_dir.path().join("checkpoint");
+        db.checkpoint(&checkpoint_path).unwrap();
+        RocksDb::default_open(&checkpoint_path, None)
+            .unwrap()
+            .backup(backup_dir)
+            .unwrap()
+    }
+
+    #[test]
+    fn checkpoint_does_not_see_later_writes() {
+        let (db, _tmp) = create_db();
+        let checkpoint_dir = TempDir::new().unwrap();
+        let checkpoint_path = checkpoint_dir.path().join("checkpoint");
+        db.put(&[0xA], Column::Metadata, Arc::new(vec![1])).unwrap();
+
+        db.checkpoint(&checkpoint_path).unwrap();
+        db.put(&[0xA], Column::Metadata, Arc::new(vec![2])).unwrap();
+
+        let checkpoint = RocksDb::default_open(&checkpoint_path, None).unwrap();
+        assert_eq!(
+            checkpoint.get(&[0xA], Column::Metadata).unwrap(),
+            Some(Arc::new(vec![1]))
+        );
+        // The existing directory is not overwritten
+        assert!(db.checkpoint(&checkpoint_path).is_err());
+    }
+
+    #[test]
+    fn backup_of_opened_database_is_restored() {
+        let (db, _tmp) = create_db();
+        let backup_dir = TempDir::new().unwrap();
+        let restored_dir = TempDir::new().unwrap();
+        let first_key = vec![0xA];
+        let second_key = vec![0xB];
+        let value = Arc::new(vec![1, 2, 3]);
+
+        db.put(&first_key, Column::Metadata, value.clone()).unwrap();
+        let first = backup_checkpoint(&db, backup_dir.path());
+        db.put(&second_key, Column::Metadata, value.clone())
+            .unwrap();
+        let second = backup_checkpoint(&db, backup_dir.path());
+        assert_eq!(second.id, first.id + 1);
+
+        let restored = RocksDb::restore(restored_dir.path(), backup_dir.path()).unwrap();
+        assert_eq!(restored, second);
+
+        let restored_db = RocksDb::default_open(restored_dir.path(), None).unwrap();
+        assert_eq!(
+            restored_db.get(&first_key, Column::Metadata).unwrap(),
+            Some(value.clone())
+        );
+        assert_eq!(
+            restored_db.get(&second_key, Column::Metadata).unwrap(),
+            Some(value)
+        );
+    }
+
+    #[test]
+    fn corrupted_backup_is_not_restored() {
+        let (db, _tmp) = create_db();
+        let backup_dir = TempDir::new().unwrap();
+        let restored_dir = TempDir::new().unwrap();
+        db.put(&[0xA], Column::Metadata, Arc::new(vec![1, 2, 3]))
+            .unwrap();
+        let backup = backup_checkpoint(&db, backup_dir.path());
+
+        let private_dir = backup_dir
+            .path()
+            .join("private")
+            .join(backup.id.to_string());
+        let largest_file = std::fs::read_dir(private_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .max_by_key(|path| std::fs::metadata(path).unwrap().len())
+            .unwrap();
+        std::fs::write(largest_file, b"").unwrap();
+
+        assert!(RocksDb::restore(restored_dir.path(), backup_dir.path()).is_err());
+    }
 }
@@ -0,0 +1,123 @@
+//! The tuning of the RocksDB. The presets cover the typical nodes, and every setting
+//! can be overridden on top of the preset.
+
+use crate::database::Column;
+use clap::ValueEnum;
+use strum_macros::{
+    Display,
+    EnumString,
+    EnumVariantNames,
+};
+
+const MIB: usize = 1024 * 1024;
+
+#[derive(
+    Copy, Clone, Debug, Display, Eq, PartialEq, EnumString, EnumVariantNames, ValueEnum,
+)]
+#[strum(serialize_all = "kebab_case")]
+pub enum RocksDbProfile {
+    /// The settings of the RocksDB used before the tuning was configurable.
+    Default,
+    /// The small caches and buffers for the nodes with the little memory.
+    LowMemory,
+    /// The large caches and buffers and more background jobs for the archive nodes.
+    HighThroughput,
+}
+
+#[derive(
+    Copy, Clone, Debug, Display, Eq, PartialEq, EnumString, EnumVariantNames, ValueEnum,
+)]
+#[strum(serialize_all = "kebab_case")]
+pub enum Compression {
+    None,
+    Snappy,
+    Lz4,
+    Zstd,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RocksDbConfig {
+    /// The size of the block cache shared by all columns in bytes.
+    pub block_cache_size: usize,
+    /// The size of the memtable of each column in bytes.
+    pub write_buffer_size: usize,
+    /// The maximum number of the concurrent flushes and compactions.
+    pub max_background_jobs: i32,
+    /// The compression of the columns without the override.
+    pub compression: Compression,
+    /// The compression of the specific columns.
+    pub column_compression: Vec<(Column, Compression)>,
+    /// The bits per key of the bloom filters. The bloom filters are disabled if unset.
+    pub bloom_filter_bits_per_key: Option<f64>,
+}
+
+impl RocksDbConfig {
+    /// Returns the compression of the `column`.
+    pub fn compression_of(&self, column: Column) -> Compression {
+        self.column_compression
+            .iter()
+            .rev()
+            .find(|(overridden, _)| *overridden == column)
+            .map(|(_, compression)| *compression)
+            .unwrap_or(self.compression)
+    }
+}
+
+impl RocksDbProfile {
+    pub fn config(self) -> RocksDbConfig {
+        match self {
+            RocksDbProfile::Default => RocksDbConfig {
+                block_cache_size: 8 * MIB,
+                write_buffer_size: 64 * MIB,
+                max_background_jobs: 2,
+                compression: Compression::Lz4,
+                column_compression: vec![],
+                bloom_filter_bits_per_key: None,
+            },
+            RocksDbProfile::LowMemory => RocksDbConfig {
+                block_cache_size: 4 * MIB,
+                write_buffer_size: 8 * MIB,
+                max_background_jobs: 1,
+                compression: Compression::Zstd,
+                column_compression: vec![],
+                bloom_filter_bits_per_key: None,
+            },
+            RocksDbProfile::HighThroughput => RocksDbConfig {
+                block_cache_size: 1024 * MIB,
+                write_buffer_size: 256 * MIB,
+                max_background_jobs: 8,
+                compression: Compression::Lz4,
+                // The old blocks and their transactions are rarely read.
+                column_compression: vec![
+                    (Column::Transactions, Compression::Zstd),
+                    (Column::Receipts, Compression::Zstd),
+                    (Column::FuelBlocks, Compression::Zstd),
+                ],
+                bloom_filter_bits_per_key: Some(10.0),
+            },
+        }
+    }
+}
+
+impl Default for RocksDbConfig {
+    fn default() -> Self {
+        RocksDbProfile::Default.config()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn column_compression_overrides_the_default_one() {
+        let mut config = RocksDbProfile::HighThroughput.config();
+        config
+            .column_compression
+            .push((Column::Receipts, Compression::None));
+
+        assert_eq!(config.compression_of(Column::Coins), Compression::Lz4);
+        assert_eq!(config.compression_of(Column::FuelBlocks), Compression::Zstd);
+        assert_eq!(config.compression_of(Column::Receipts), Compression::None);
+    }
+}
@@ -0,0 +1,225 @@
+//! The read-only [`KvStore`] on top of the [`KvSnapshot`] of another store. The
+//! [`Database`](crate::database::Database) built on it sees the state at the moment
+//! of the creation of the snapshot, and the later commits to the store are not
+//! visible through it.
+
+use crate::{
+    database::{
+        Column,
+        Error as DatabaseError,
+        Result as DatabaseResult,
+    },
+    state::{
+        BatchOperations,
+        DataSource,
+        IterDirection,
+        KVItem,
+        KeyValueStore,
+        KvSnapshot,
+        KvStore,
+        Value,
+        WriteOperation,
+    },
+};
+use fuel_core_storage::iter::BoxedIter;
+use std::fmt::{
+    self,
+    Debug,
+    Formatter,
+};
+
+type BoxedSnapshot<'a> = Box<dyn KvSnapshot + 'a>;
+
+self_cell::self_cell!(
+    /// The snapshot together with the store it borrows from.
+    struct OwnedSnapshot {
+        owner: DataSource,
+
+        #[covariant]
+        dependent: BoxedSnapshot,
+    }
+);
+
+/// The store serving the reads from the snapshot. The writes are rejected, so it is
+/// used behind the database transaction that is never committed.
+pub struct SnapshotStore {
+    snapshot: OwnedSnapshot,
+}
+
+impl SnapshotStore {
+    /// Takes the snapshot of the `source` at the current moment.
+    pub fn new(source: DataSource) -> DatabaseResult<Self> {
+        let snapshot = OwnedSnapshot::try_new(source, |source| source.snapshot())?;
+        Ok(Self { snapshot })
+    }
+
+    fn view(&self) -> &dyn KvSnapshot {
+        self.snapshot.borrow_dependent().as_ref()
+    }
+}
+
+impl Debug for SnapshotStore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SnapshotStore")
+            .field("source", self.snapshot.borrow_owner())
+            .finish()
+    }
+}
+
+fn read_only() -> DatabaseError {
+    DatabaseError::Other(anyhow::anyhow!("The snapshot of the store is read-only"))
+}
+
+impl KeyValueStore for SnapshotStore {
+    fn put(&self, _: &[u8], _: Column, _: Value) -> DatabaseResult<Option<Value>> {
+        Err(read_only())
+    }
+
+    fn write(&self, _: &[u8], _: Column, _: &[u8]) -> DatabaseResult<usize> {
+        Err(read_only())
+    }
+
+    fn replace(
+        &self,
+        _: &[u8],
+        _: Column,
+        _: &[u8],
+    ) -> DatabaseResult<(usize, Option<Value>)> {
+        Err(read_only())
+    }
+
+    fn take(&self, _: &[u8], _: Column) -> DatabaseResult<Option<Value>> {
+        Err(read_only())
+    }
+
+    fn delete(&self, _: &[u8], _: Column) -> DatabaseResult<Option<Value>> {
+        Err(read_only())
+    }
+
+    fn exists(&self, key: &[u8], column: Column) -> DatabaseResult<bool> {
+        Ok(self.view().get(key, column)?.is_some())
+    }
+
+    fn size_of_value(&self, key: &[u8], column: Column) -> DatabaseResult<Option<usize>> {
+        Ok(self.view().get(key, column)?.map(|value| value.len()))
+    }
+
+    fn get(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        self.view().get(key, column)
+    }
+
+    fn read(
+        &self,
+        key: &[u8],
+        column: Column,
+        mut buf: &mut [u8],
+    ) -> DatabaseResult<Option<usize>> {
+        self.view()
+            .get(key, column)?
+            .map(|value| {
+                let read = value.len();
+                std::io::Write::write_all(&mut buf, value.as_ref())
+                    .map_err(|e| DatabaseError::Other(anyhow::anyhow!(e)))?;
+                DatabaseResult::Ok(read)
+            })
+            .transpose()
+    }
+
+    fn read_alloc(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        self.view().get(key, column)
+    }
+
+    fn iter_all(
+        &self,
+        column: Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        self.view().iter_all(column, prefix, start, direction)
+    }
+}
+
+impl BatchOperations for SnapshotStore {
+    fn batch_write(
+        &self,
+        _: &mut dyn Iterator<Item = (Vec<u8>, Column, WriteOperation)>,
+    ) -> DatabaseResult<()> {
+        Err(read_only())
+    }
+}
+
+impl KvStore for SnapshotStore {
+    fn snapshot(&self) -> DatabaseResult<Box<dyn KvSnapshot + '_>> {
+        Ok(Box::new(SnapshotStoreView(self)))
+    }
+}
+
+/// The snapshot of the [`SnapshotStore`] is the same view of the store.
+struct SnapshotStoreView<'a>(&'a SnapshotStore);
+
+impl KvSnapshot for SnapshotStoreView<'_> {
+    fn get(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        self.0.view().get(key, column)
+    }
+
+    fn iter_all(
+        &self,
+        column: Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        self.0.view().iter_all(column, prefix, start, direction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::in_memory::memory_store::MemoryStore;
+    use std::sync::Arc;
+
+    #[test]
+    fn snapshot_store_does_not_see_later_writes() {
+        let source = Arc::new(MemoryStore::default());
+        source
+            .put(&[1], Column::Metadata, Arc::new(vec![1]))
+            .unwrap();
+
+        let store = SnapshotStore::new(source.clone()).unwrap();
+        source
+            .put(&[1], Column::Metadata, Arc::new(vec![2]))
+            .unwrap();
+        source
+            .put(&[2], Column::Metadata, Arc::new(vec![2]))
+            .unwrap();
+
+        assert_eq!(
+            store.get(&[1], Column::Metadata).unwrap(),
+            Some(Arc::new(vec![1]))
+        );
+        assert!(!store.exists(&[2], Column::Metadata).unwrap());
+        assert_eq!(
+            store
+                .iter_all(Column::Metadata, None, None, IterDirection::Forward)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn snapshot_store_rejects_writes() {
+        let store = SnapshotStore::new(Arc::new(MemoryStore::default())).unwrap();
+
+        assert!(store
+            .put(&[1], Column::Metadata, Arc::new(vec![1]))
+            .is_err());
+        assert!(store
+            .batch_write(
+                &mut vec![(vec![1], Column::Metadata, WriteOperation::Remove)]
+                    .into_iter()
+            )
+            .is_err());
+    }
+}
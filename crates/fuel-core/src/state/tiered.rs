@@ -0,0 +1,361 @@
+//! The store that keeps the historical blocks and receipts in the cold storage.
+//!
+//! The cold storage is the separate store, usually on the cheap disk. All writes go
+//! to the hot store, and the entries of the old blocks are moved to the cold storage
+//! later, see [`Database::move_block_to_cold_storage`]. The reads of the cold columns
+//! fall through to the cold storage if the entry is not in the hot store, so the
+//! historical queries don't depend on where the block is.
+//!
+//! [`Database::move_block_to_cold_storage`]: crate::database::Database::move_block_to_cold_storage
+
+use crate::{
+    database::{
+        Column,
+        Error as DatabaseError,
+        Result as DatabaseResult,
+    },
+    state::{
+        BatchOperations,
+        DataSource,
+        IterDirection,
+        KVItem,
+        KeyValueStore,
+        KvSnapshot,
+        KvStore,
+        Value,
+        WriteOperation,
+    },
+};
+use fuel_core_storage::iter::{
+    BoxedIter,
+    IntoBoxedIter,
+};
+use itertools::Itertools;
+
+/// The columns moved to the cold storage.
+pub const COLD_COLUMNS: [Column; 4] = [
+    Column::FuelBlocks,
+    Column::FuelBlockConsensus,
+    Column::Transactions,
+    Column::Receipts,
+];
+
+fn is_cold(column: Column) -> bool {
+    COLD_COLUMNS.contains(&column)
+}
+
+#[derive(Debug)]
+pub struct TieredStore {
+    hot: DataSource,
+    cold: DataSource,
+}
+
+impl TieredStore {
+    pub fn new(hot: DataSource, cold: DataSource) -> Self {
+        Self { hot, cold }
+    }
+}
+
+impl KeyValueStore for TieredStore {
+    fn put(
+        &self,
+        key: &[u8],
+        column: Column,
+        value: Value,
+    ) -> DatabaseResult<Option<Value>> {
+        match self.hot.put(key, column, value)? {
+            Some(prev) => Ok(Some(prev)),
+            None if is_cold(column) => self.cold.get(key, column),
+            None => Ok(None),
+        }
+    }
+
+    fn write(&self, key: &[u8], column: Column, buf: &[u8]) -> DatabaseResult<usize> {
+        self.hot.write(key, column, buf)
+    }
+
+    fn replace(
+        &self,
+        key: &[u8],
+        column: Column,
+        buf: &[u8],
+    ) -> DatabaseResult<(usize, Option<Value>)> {
+        match self.hot.replace(key, column, buf)? {
+            (written, None) if is_cold(column) => {
+                Ok((written, self.cold.get(key, column)?))
+            }
+            result => Ok(result),
+        }
+    }
+
+    fn take(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        self.delete(key, column)
+    }
+
+    fn delete(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        let prev = self.hot.delete(key, column)?;
+        if is_cold(column) {
+            let cold_prev = self.cold.delete(key, column)?;
+            return Ok(prev.or(cold_prev))
+        }
+        Ok(prev)
+    }
+
+    fn exists(&self, key: &[u8], column: Column) -> DatabaseResult<bool> {
+        Ok(self.hot.exists(key, column)?
+            || (is_cold(column) && self.cold.exists(key, column)?))
+    }
+
+    fn size_of_value(&self, key: &[u8], column: Column) -> DatabaseResult<Option<usize>> {
+        match self.hot.size_of_value(key, column)? {
+            None if is_cold(column) => self.cold.size_of_value(key, column),
+            size => Ok(size),
+        }
+    }
+
+    fn get(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        match self.hot.get(key, column)? {
+            None if is_cold(column) => self.cold.get(key, column),
+            value => Ok(value),
+        }
+    }
+
+    fn read(
+        &self,
+        key: &[u8],
+        column: Column,
+        buf: &mut [u8],
+    ) -> DatabaseResult<Option<usize>> {
+        match self.hot.read(key, column, buf)? {
+            None if is_cold(column) => self.cold.read(key, column, buf),
+            read => Ok(read),
+        }
+    }
+
+    fn read_alloc(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        match self.hot.read_alloc(key, column)? {
+            None if is_cold(column) => self.cold.read_alloc(key, column),
+            value => Ok(value),
+        }
+    }
+
+    fn iter_all(
+        &self,
+        column: Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        let hot = self.hot.iter_all(column, prefix, start, direction);
+        if !is_cold(column) {
+            return hot
+        }
+        let cold = self.cold.iter_all(column, prefix, start, direction);
+        merge(hot, cold, direction)
+    }
+}
+
+impl BatchOperations for TieredStore {
+    fn batch_write(
+        &self,
+        entries: &mut dyn Iterator<Item = (Vec<u8>, Column, WriteOperation)>,
+    ) -> DatabaseResult<()> {
+        let entries: Vec<_> = entries.collect();
+        // The removed entries may be already moved to the cold storage. The inserted
+        // entries shadow the old values in the cold storage, so they are kept there
+        // until the entry is moved again.
+        let cold_removes: Vec<_> = entries
+            .iter()
+            .filter(|(_, column, op)| {
+                is_cold(*column) && matches!(op, WriteOperation::Remove)
+            })
+            .map(|(key, column, _)| (key.clone(), *column, WriteOperation::Remove))
+            .collect();
+        self.hot.batch_write(&mut entries.into_iter())?;
+        if !cold_removes.is_empty() {
+            self.cold.batch_write(&mut cold_removes.into_iter())?;
+        }
+        Ok(())
+    }
+}
+
+impl KvStore for TieredStore {
+    fn snapshot(&self) -> DatabaseResult<Box<dyn KvSnapshot + '_>> {
+        // The hot snapshot is taken first, so the entry moved to the cold storage
+        // in between is visible through one of the snapshots.
+        let hot = self.hot.snapshot()?;
+        let cold = self.cold.snapshot()?;
+        Ok(Box::new(TieredSnapshot { hot, cold }))
+    }
+
+    fn compact(&self, column: Option<Column>) -> DatabaseResult<()> {
+        self.hot.compact(column)?;
+        match column {
+            Some(column) if !is_cold(column) => Ok(()),
+            _ => self.cold.compact(column),
+        }
+    }
+
+    fn move_to_cold(&self, entries: &[(Vec<u8>, Column)]) -> DatabaseResult<usize> {
+        let mut moved = 0;
+        let mut inserts = vec![];
+        for (key, column) in entries {
+            if !is_cold(*column) {
+                return Err(DatabaseError::Other(anyhow::anyhow!(
+                    "The column {:?} is not stored in the cold storage",
+                    column
+                )))
+            }
+            if let Some(value) = self.hot.get(key, *column)? {
+                moved += value.len();
+                inserts.push((key.clone(), *column, WriteOperation::Insert(value)));
+            }
+        }
+        let removes: Vec<_> = inserts
+            .iter()
+            .map(|(key, column, _)| (key.clone(), *column, WriteOperation::Remove))
+            .collect();
+
+        // The entries are removed from the hot store only after they are written
+        // to the cold storage, so they are readable at any moment.
+        self.cold.batch_write(&mut inserts.into_iter())?;
+        self.hot.batch_write(&mut removes.into_iter())?;
+        Ok(moved)
+    }
+}
+
+struct TieredSnapshot<'a> {
+    hot: Box<dyn KvSnapshot + 'a>,
+    cold: Box<dyn KvSnapshot + 'a>,
+}
+
+impl KvSnapshot for TieredSnapshot<'_> {
+    fn get(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        match self.hot.get(key, column)? {
+            None if is_cold(column) => self.cold.get(key, column),
+            value => Ok(value),
+        }
+    }
+
+    fn iter_all(
+        &self,
+        column: Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        let hot = self.hot.iter_all(column, prefix, start, direction);
+        if !is_cold(column) {
+            return hot
+        }
+        let cold = self.cold.iter_all(column, prefix, start, direction);
+        merge(hot, cold, direction)
+    }
+}
+
+/// Merges the iterators over the same column of the hot and the cold stores. The entry
+/// of the hot store wins if the key is in both stores.
+fn merge<'a>(
+    hot: BoxedIter<'a, KVItem>,
+    cold: BoxedIter<'a, KVItem>,
+    direction: IterDirection,
+) -> BoxedIter<'a, KVItem> {
+    hot.merge_by(cold, move |hot, cold| match (hot, cold) {
+        (Ok((hot, _)), Ok((cold, _))) => match direction {
+            IterDirection::Forward => hot <= cold,
+            IterDirection::Reverse => hot >= cold,
+        },
+        (Err(_), _) => true,
+        (_, Err(_)) => false,
+    })
+    .dedup_by(|first, second| {
+        matches!((first, second), (Ok((first, _)), Ok((second, _))) if first == second)
+    })
+    .into_boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::in_memory::memory_store::MemoryStore;
+    use std::sync::Arc;
+
+    fn store() -> (TieredStore, DataSource, DataSource) {
+        let hot: DataSource = Arc::new(MemoryStore::default());
+        let cold: DataSource = Arc::new(MemoryStore::default());
+        (TieredStore::new(hot.clone(), cold.clone()), hot, cold)
+    }
+
+    #[test]
+    fn moved_entries_are_read_through_the_cold_storage() {
+        let (store, hot, cold) = store();
+        store
+            .put(&[1], Column::Receipts, Arc::new(vec![1]))
+            .unwrap();
+        store
+            .put(&[2], Column::Receipts, Arc::new(vec![2]))
+            .unwrap();
+        store
+            .put(&[3], Column::Receipts, Arc::new(vec![3]))
+            .unwrap();
+
+        let moved = store
+            .move_to_cold(&[(vec![1], Column::Receipts), (vec![3], Column::Receipts)])
+            .unwrap();
+
+        assert_eq!(moved, 2);
+        assert!(!hot.exists(&[1], Column::Receipts).unwrap());
+        assert!(cold.exists(&[1], Column::Receipts).unwrap());
+        assert_eq!(
+            store.get(&[1], Column::Receipts).unwrap(),
+            Some(Arc::new(vec![1]))
+        );
+        for direction in [IterDirection::Forward, IterDirection::Reverse] {
+            let mut expected = vec![
+                (vec![1], Arc::new(vec![1])),
+                (vec![2], Arc::new(vec![2])),
+                (vec![3], Arc::new(vec![3])),
+            ];
+            if direction == IterDirection::Reverse {
+                expected.reverse();
+            }
+            assert_eq!(
+                store
+                    .iter_all(Column::Receipts, None, None, direction)
+                    .collect::<Result<Vec<_>, _>>()
+                    .unwrap(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn removed_entries_are_removed_from_both_stores() {
+        let (store, _, cold) = store();
+        store
+            .put(&[1], Column::FuelBlocks, Arc::new(vec![1]))
+            .unwrap();
+        store
+            .move_to_cold(&[(vec![1], Column::FuelBlocks)])
+            .unwrap();
+
+        store
+            .batch_write(
+                &mut vec![(vec![1], Column::FuelBlocks, WriteOperation::Remove)]
+                    .into_iter(),
+            )
+            .unwrap();
+
+        assert!(!store.exists(&[1], Column::FuelBlocks).unwrap());
+        assert!(!cold.exists(&[1], Column::FuelBlocks).unwrap());
+    }
+
+    #[test]
+    fn only_cold_columns_are_moved() {
+        let (store, _, _) = store();
+        store.put(&[1], Column::Coins, Arc::new(vec![1])).unwrap();
+
+        assert!(store.move_to_cold(&[(vec![1], Column::Coins)]).is_err());
+        assert!(store.exists(&[1], Column::Coins).unwrap());
+    }
+}
@@ -1,12 +1,21 @@
 use lazy_static::lazy_static;
 use prometheus_client::{
+    encoding::EncodeLabelSet,
     metrics::{
         counter::Counter,
+        family::Family,
+        gauge::Gauge,
         histogram::Histogram,
     },
     registry::Registry,
 };
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct ColumnLabel {
+    // the column of the database, e.g. `coins`
+    column: &'static str,
+}
+
 pub struct DatabaseMetrics {
     pub registry: Registry,
     // For descriptions of each Counter, see the `new` function where each Counter/Histogram is initialized
@@ -14,6 +23,16 @@ pub struct DatabaseMetrics {
     pub read_meter: Counter,
     pub bytes_written: Histogram,
     pub bytes_read: Histogram,
+    pub running_compactions: Gauge,
+    pub block_cache_usage_bytes: Gauge,
+    pub block_cache_hits: Gauge,
+    pub block_cache_misses: Gauge,
+    column_reads: Family<ColumnLabel, Counter>,
+    column_writes: Family<ColumnLabel, Counter>,
+    column_deletes: Family<ColumnLabel, Counter>,
+    column_iterators: Family<ColumnLabel, Counter>,
+    column_size_bytes: Family<ColumnLabel, Gauge>,
+    column_pending_compaction_bytes: Family<ColumnLabel, Gauge>,
 }
 
 impl DatabaseMetrics {
@@ -35,8 +54,54 @@ impl DatabaseMetrics {
             read_meter,
             bytes_read: bytes_read_histogram,
             bytes_written: bytes_written_histogram,
+            running_compactions: Gauge::default(),
+            block_cache_usage_bytes: Gauge::default(),
+            block_cache_hits: Gauge::default(),
+            block_cache_misses: Gauge::default(),
+            column_reads: Family::default(),
+            column_writes: Family::default(),
+            column_deletes: Family::default(),
+            column_iterators: Family::default(),
+            column_size_bytes: Family::default(),
+            column_pending_compaction_bytes: Family::default(),
         }
     }
+
+    pub fn observe_read(&self, column: &'static str) {
+        self.column_reads
+            .get_or_create(&ColumnLabel { column })
+            .inc();
+    }
+
+    pub fn observe_write(&self, column: &'static str) {
+        self.column_writes
+            .get_or_create(&ColumnLabel { column })
+            .inc();
+    }
+
+    pub fn observe_delete(&self, column: &'static str) {
+        self.column_deletes
+            .get_or_create(&ColumnLabel { column })
+            .inc();
+    }
+
+    pub fn observe_iterator(&self, column: &'static str) {
+        self.column_iterators
+            .get_or_create(&ColumnLabel { column })
+            .inc();
+    }
+
+    pub fn set_column_size(&self, column: &'static str, bytes: u64) {
+        self.column_size_bytes
+            .get_or_create(&ColumnLabel { column })
+            .set(bytes as i64);
+    }
+
+    pub fn set_pending_compaction(&self, column: &'static str, bytes: u64) {
+        self.column_pending_compaction_bytes
+            .get_or_create(&ColumnLabel { column })
+            .set(bytes as i64);
+    }
 }
 
 pub fn init(mut metrics: DatabaseMetrics) -> DatabaseMetrics {
@@ -60,6 +125,56 @@ pub fn init(mut metrics: DatabaseMetrics) -> DatabaseMetrics {
         "Histogram containing values of amount of bytes written per operation",
         metrics.bytes_written.clone(),
     );
+    metrics.registry.register(
+        "Database_Running_Compactions",
+        "A Gauge keeping track of the number of the running compactions",
+        metrics.running_compactions.clone(),
+    );
+    metrics.registry.register(
+        "Database_Block_Cache_Usage_Bytes",
+        "A Gauge keeping track of the memory used by the entries of the block cache",
+        metrics.block_cache_usage_bytes.clone(),
+    );
+    metrics.registry.register(
+        "Database_Block_Cache_Hits",
+        "The total number of the hits of the block cache since the start",
+        metrics.block_cache_hits.clone(),
+    );
+    metrics.registry.register(
+        "Database_Block_Cache_Misses",
+        "The total number of the misses of the block cache since the start",
+        metrics.block_cache_misses.clone(),
+    );
+    metrics.registry.register(
+        "Database_Column_Reads",
+        "Number of database read operations, by the column",
+        metrics.column_reads.clone(),
+    );
+    metrics.registry.register(
+        "Database_Column_Writes",
+        "Number of database write operations, by the column",
+        metrics.column_writes.clone(),
+    );
+    metrics.registry.register(
+        "Database_Column_Deletes",
+        "Number of database delete operations, by the column",
+        metrics.column_deletes.clone(),
+    );
+    metrics.registry.register(
+        "Database_Column_Iterators",
+        "Number of the iterators created over the column",
+        metrics.column_iterators.clone(),
+    );
+    metrics.registry.register(
+        "Database_Column_Size_Bytes",
+        "A Gauge keeping track of the size of the files of the column on the disk",
+        metrics.column_size_bytes.clone(),
+    );
+    metrics.registry.register(
+        "Database_Column_Pending_Compaction_Bytes",
+        "A Gauge keeping track of the estimated bytes to be rewritten by the compaction",
+        metrics.column_pending_compaction_bytes.clone(),
+    );
 
     metrics
 }
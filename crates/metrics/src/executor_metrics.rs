@@ -0,0 +1,67 @@
+use lazy_static::lazy_static;
+use prometheus_client::{
+    encoding::EncodeLabelSet,
+    metrics::{
+        counter::Counter,
+        family::Family,
+        gauge::Gauge,
+    },
+    registry::Registry,
+};
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct CacheLabel {
+    // the cache of the executor: `contract_code` or `predicates`
+    cache: &'static str,
+}
+
+pub struct ExecutorMetrics {
+    pub registry: Registry,
+    pub contract_code_cache_bytes: Gauge,
+    cache_hits: Family<CacheLabel, Counter>,
+    cache_misses: Family<CacheLabel, Counter>,
+}
+
+impl ExecutorMetrics {
+    fn new() -> Self {
+        let mut registry = Registry::default();
+        let contract_code_cache_bytes = Gauge::default();
+        let cache_hits = Family::<CacheLabel, Counter>::default();
+        let cache_misses = Family::<CacheLabel, Counter>::default();
+
+        registry.register(
+            "Executor_Contract_Code_Cache_Bytes",
+            "A Gauge keeping track of the size of the contract code in the executor cache",
+            contract_code_cache_bytes.clone(),
+        );
+        registry.register(
+            "Executor_Cache_Hits",
+            "A Counter keeping track of the hits of the executor caches",
+            cache_hits.clone(),
+        );
+        registry.register(
+            "Executor_Cache_Misses",
+            "A Counter keeping track of the misses of the executor caches",
+            cache_misses.clone(),
+        );
+
+        Self {
+            registry,
+            contract_code_cache_bytes,
+            cache_hits,
+            cache_misses,
+        }
+    }
+
+    pub fn observe_hit(&self, cache: &'static str) {
+        self.cache_hits.get_or_create(&CacheLabel { cache }).inc();
+    }
+
+    pub fn observe_miss(&self, cache: &'static str) {
+        self.cache_misses.get_or_create(&CacheLabel { cache }).inc();
+    }
+}
+
+lazy_static! {
+    pub static ref EXECUTOR_METRICS: ExecutorMetrics = ExecutorMetrics::new();
+}
@@ -2,9 +2,11 @@
 #![deny(warnings)]
 
 pub mod core_metrics;
+pub mod executor_metrics;
 pub mod future_tracker;
 pub mod graphql_metrics;
 pub mod p2p_metrics;
+pub mod pruning_metrics;
 pub mod response;
 pub mod services;
 pub mod txpool_metrics;
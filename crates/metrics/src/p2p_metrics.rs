@@ -1,15 +1,27 @@
 use lazy_static::lazy_static;
 use libp2p_prom_client::{
-    metrics::counter::Counter,
+    metrics::{
+        counter::Counter,
+        family::Family,
+        gauge::Gauge,
+    },
     registry::Registry,
 };
 use once_cell::race::OnceBox;
 
+/// The labels of the p2p metrics, e.g. `[("protocol", "gossipsub")]`.
+pub type P2PLabels = Vec<(String, String)>;
+
 pub struct P2PMetrics {
     pub gossip_sub_registry: OnceBox<Registry>,
     // For descriptions of each Counter, see the `new` function where each Counter/Histogram is initialized
     pub peer_metrics: Registry,
     pub unique_peers: Counter,
+    pub bytes_received: Family<P2PLabels, Counter>,
+    pub bytes_sent: Family<P2PLabels, Counter>,
+    pub connections: Family<P2PLabels, Gauge>,
+    pub dial_failures: Family<P2PLabels, Counter>,
+    pub gossip_mesh_peers: Family<P2PLabels, Gauge>,
 }
 
 impl P2PMetrics {
@@ -17,11 +29,21 @@ impl P2PMetrics {
         let peer_metrics = Registry::default();
 
         let unique_peers = Counter::default();
+        let bytes_received = Family::default();
+        let bytes_sent = Family::default();
+        let connections = Family::default();
+        let dial_failures = Family::default();
+        let gossip_mesh_peers = Family::default();
 
         let mut metrics = P2PMetrics {
             gossip_sub_registry: OnceBox::new(),
             peer_metrics,
             unique_peers,
+            bytes_received,
+            bytes_sent,
+            connections,
+            dial_failures,
+            gossip_mesh_peers,
         };
 
         metrics.peer_metrics.register(
@@ -29,11 +51,41 @@ impl P2PMetrics {
             "A Counter which keeps track of each unique peer the p2p service has connected to",
             Box::new(metrics.unique_peers.clone()),
         );
+        metrics.peer_metrics.register(
+            "P2P_Bytes_Received",
+            "The number of the payload bytes received from the peers, by the protocol",
+            Box::new(metrics.bytes_received.clone()),
+        );
+        metrics.peer_metrics.register(
+            "P2P_Bytes_Sent",
+            "The number of the payload bytes sent to the peers, by the protocol",
+            Box::new(metrics.bytes_sent.clone()),
+        );
+        metrics.peer_metrics.register(
+            "P2P_Connections",
+            "The number of the active connections, by the direction",
+            Box::new(metrics.connections.clone()),
+        );
+        metrics.peer_metrics.register(
+            "P2P_Dial_Failures",
+            "The number of the failed outbound connections, by the reason",
+            Box::new(metrics.dial_failures.clone()),
+        );
+        metrics.peer_metrics.register(
+            "Gossip_Mesh_Peers",
+            "The number of the peers in the gossipsub mesh, by the topic",
+            Box::new(metrics.gossip_mesh_peers.clone()),
+        );
 
         metrics
     }
 }
 
+/// Returns the label set with a single label.
+pub fn p2p_label(name: &str, value: &str) -> P2PLabels {
+    vec![(name.to_string(), value.to_string())]
+}
+
 lazy_static! {
     pub static ref P2P_METRICS: P2PMetrics = P2PMetrics::new();
 }
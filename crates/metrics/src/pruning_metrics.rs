@@ -0,0 +1,51 @@
+use lazy_static::lazy_static;
+use prometheus_client::{
+    metrics::{
+        counter::Counter,
+        gauge::Gauge,
+    },
+    registry::Registry,
+};
+
+pub struct PruningMetrics {
+    pub registry: Registry,
+    pub pruned_blocks: Counter,
+    pub reclaimed_bytes: Counter,
+    pub pruned_height: Gauge,
+}
+
+impl PruningMetrics {
+    fn new() -> Self {
+        let mut registry = Registry::default();
+        let pruned_blocks = Counter::default();
+        let reclaimed_bytes = Counter::default();
+        let pruned_height = Gauge::default();
+
+        registry.register(
+            "Pruning_Blocks",
+            "A Counter keeping track of the blocks with the pruned history",
+            pruned_blocks.clone(),
+        );
+        registry.register(
+            "Pruning_Reclaimed_Bytes",
+            "A Counter keeping track of the bytes of the history removed by the pruning",
+            reclaimed_bytes.clone(),
+        );
+        registry.register(
+            "Pruning_Height",
+            "A Gauge keeping track of the height of the latest block with the pruned history",
+            pruned_height.clone(),
+        );
+
+        Self {
+            registry,
+            pruned_blocks,
+            reclaimed_bytes,
+            pruned_height,
+        }
+    }
+}
+
+lazy_static! {
+    pub static ref PRUNING_METRICS: PruningMetrics = PruningMetrics::new();
+}
@@ -1,6 +1,9 @@
 use crate::{
+    core_metrics::DATABASE_METRICS,
+    executor_metrics::EXECUTOR_METRICS,
     graphql_metrics::GRAPHQL_METRICS,
     p2p_metrics::P2P_METRICS,
+    pruning_metrics::PRUNING_METRICS,
     services::SERVICES_METRICS,
     txpool_metrics::TXPOOL_METRICS,
 };
@@ -48,6 +51,18 @@ pub fn encode_metrics_response() -> impl IntoResponse {
         return error_body()
     }
 
+    if encode(&mut encoded, &EXECUTOR_METRICS.registry).is_err() {
+        return error_body()
+    }
+
+    if encode(&mut encoded, &PRUNING_METRICS.registry).is_err() {
+        return error_body()
+    }
+
+    if encode(&mut encoded, &DATABASE_METRICS.registry).is_err() {
+        return error_body()
+    }
+
     Response::builder()
         .status(200)
         .body(Body::from(encoded))
@@ -1,15 +1,55 @@
 use lazy_static::lazy_static;
 use prometheus_client::{
-    metrics::histogram::Histogram,
+    encoding::EncodeLabelSet,
+    metrics::{
+        counter::Counter,
+        family::Family,
+        gauge::Gauge,
+        histogram::Histogram,
+    },
     registry::Registry,
 };
 use std::default::Default;
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct RejectionLabel {
+    // the reason why the transaction was not inserted
+    reason: &'static str,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct SourceLabel {
+    // where the transaction came from: `local` or `gossip`
+    source: &'static str,
+}
+
 pub struct TxPoolMetrics {
     // Attaches each Metric to the Registry
     pub registry: Registry,
     pub gas_price_histogram: Histogram,
     pub tx_size_histogram: Histogram,
+    pub expired_tx_counter: Counter,
+    pub pool_size_gauge: Gauge,
+    pub pool_bytes_gauge: Gauge,
+    pub inserted_tx_counter: Counter,
+    pub evicted_tx_counter: Counter,
+    pub time_in_pool_histogram: Histogram,
+    rejected_tx_counter: Family<RejectionLabel, Counter>,
+    received_tx_counter: Family<SourceLabel, Counter>,
+}
+
+impl TxPoolMetrics {
+    pub fn observe_rejection(&self, reason: &'static str) {
+        self.rejected_tx_counter
+            .get_or_create(&RejectionLabel { reason })
+            .inc();
+    }
+
+    pub fn observe_received(&self, source: &'static str, count: u64) {
+        self.received_tx_counter
+            .get_or_create(&SourceLabel { source })
+            .inc_by(count);
+    }
 }
 
 impl Default for TxPoolMetrics {
@@ -24,10 +64,22 @@ impl Default for TxPoolMetrics {
 
         let tx_size_histogram = Histogram::new(tx_sizes.into_iter());
 
+        let expired_tx_counter = Counter::default();
+
+        let time_in_pool_histogram = Histogram::new(TIME_IN_POOL_BUCKETS.iter().cloned());
+
         let mut metrics = TxPoolMetrics {
             registry,
             gas_price_histogram,
             tx_size_histogram,
+            expired_tx_counter,
+            pool_size_gauge: Gauge::default(),
+            pool_bytes_gauge: Gauge::default(),
+            inserted_tx_counter: Counter::default(),
+            evicted_tx_counter: Counter::default(),
+            time_in_pool_histogram,
+            rejected_tx_counter: Family::default(),
+            received_tx_counter: Family::default(),
         };
 
         metrics.registry.register(
@@ -42,10 +94,60 @@ impl Default for TxPoolMetrics {
             metrics.tx_size_histogram.clone(),
         );
 
+        metrics.registry.register(
+            "Tx_Expired_Counter",
+            "A Counter keeping track of txs removed from the mempool after their TTL",
+            metrics.expired_tx_counter.clone(),
+        );
+
+        metrics.registry.register(
+            "Tx_Pool_Size",
+            "A Gauge keeping track of the number of txs in the mempool",
+            metrics.pool_size_gauge.clone(),
+        );
+
+        metrics.registry.register(
+            "Tx_Pool_Bytes",
+            "A Gauge keeping track of the total size of txs in the mempool",
+            metrics.pool_bytes_gauge.clone(),
+        );
+
+        metrics.registry.register(
+            "Tx_Inserted_Counter",
+            "A Counter keeping track of txs inserted into the mempool",
+            metrics.inserted_tx_counter.clone(),
+        );
+
+        metrics.registry.register(
+            "Tx_Rejected_Counter",
+            "A Counter keeping track of txs rejected by the mempool by the reason",
+            metrics.rejected_tx_counter.clone(),
+        );
+
+        metrics.registry.register(
+            "Tx_Evicted_Counter",
+            "A Counter keeping track of txs pushed out of the mempool by more priced txs",
+            metrics.evicted_tx_counter.clone(),
+        );
+
+        metrics.registry.register(
+            "Tx_Received_Counter",
+            "A Counter keeping track of txs submitted locally or received via gossip",
+            metrics.received_tx_counter.clone(),
+        );
+
+        metrics.registry.register(
+            "Tx_Time_In_Pool_Histogram",
+            "A Histogram keeping track of the seconds txs spent in the mempool",
+            metrics.time_in_pool_histogram.clone(),
+        );
+
         metrics
     }
 }
 
 lazy_static! {
     pub static ref TXPOOL_METRICS: TxPoolMetrics = TxPoolMetrics::default();
+    static ref TIME_IN_POOL_BUCKETS: Vec<f64> =
+        vec![1.0, 5.0, 10.0, 30.0, 60.0, 120.0, 300.0, 600.0, 1800.0, 3600.0];
 }
@@ -1,8 +1,8 @@
+use crate::signer::SignMode;
+use fuel_core_chain_config::ConsensusConfig;
 use fuel_core_types::{
-    blockchain::primitives::SecretKeyWrapper,
     fuel_asm::Word,
     fuel_tx::ConsensusParameters,
-    secrecy::Secret,
 };
 use tokio::time::Duration;
 
@@ -10,11 +10,13 @@ use tokio::time::Duration;
 pub struct Config {
     pub trigger: Trigger,
     pub block_gas_limit: Word,
-    pub signing_key: Option<Secret<SecretKeyWrapper>>,
+    pub signer: SignMode,
     pub metrics: bool,
     pub consensus_params: ConsensusParameters,
     pub min_connected_reserved_peers: usize,
     pub time_until_synced: Duration,
+    /// The authorities allowed to produce blocks and the assignment of their slots.
+    pub consensus: ConsensusConfig,
 }
 
 impl Default for Config {
@@ -22,11 +24,12 @@ impl Default for Config {
         Config {
             trigger: Trigger::default(),
             block_gas_limit: 0,
-            signing_key: None,
+            signer: SignMode::Unavailable,
             metrics: false,
             consensus_params: ConsensusParameters::default(),
             min_connected_reserved_peers: 0,
             time_until_synced: Duration::ZERO,
+            consensus: ConsensusConfig::default_poa(),
         }
     }
 }
@@ -42,4 +45,13 @@ pub enum Trigger {
     Never,
     /// A new block is produced periodically. Used to simulate consensus block delay.
     Interval { block_time: Duration },
+    /// A new block is produced periodically, or instantly when the pending
+    /// transactions reach one of the thresholds.
+    Hybrid {
+        block_time: Duration,
+        /// The gas of the pending transactions that triggers the block production.
+        max_gas: Word,
+        /// The size of the pending transactions that triggers the block production.
+        max_bytes: usize,
+    },
 }
@@ -11,6 +11,7 @@ mod service_test;
 pub mod config;
 pub mod ports;
 pub mod service;
+pub mod signer;
 pub mod verifier;
 
 pub use config::{
@@ -21,3 +22,7 @@ pub use service::{
     new_service,
     Service,
 };
+pub use signer::{
+    RemoteSigner,
+    SignMode,
+};
@@ -32,6 +32,9 @@ pub trait TransactionPool: Send + Sync {
 
     fn total_consumable_gas(&self) -> u64;
 
+    /// Returns the size in bytes of the pending transactions.
+    fn total_bytes_size(&self) -> usize;
+
     fn remove_txs(&self, tx_ids: Vec<TxId>) -> Vec<ArcPoolTx>;
 
     fn transaction_status_events(&self) -> BoxStream<TxId>;
@@ -9,6 +9,7 @@ use crate::{
         P2pPort,
         TransactionPool,
     },
+    signer::SignMode,
     sync::{
         SyncState,
         SyncTask,
@@ -20,6 +21,7 @@ use anyhow::{
     anyhow,
     Context,
 };
+use fuel_core_chain_config::ConsensusConfig;
 use fuel_core_services::{
     stream::BoxStream,
     RunnableService,
@@ -31,23 +33,12 @@ use fuel_core_services::{
 use fuel_core_storage::transactional::StorageTransaction;
 use fuel_core_types::{
     blockchain::{
-        block::Block,
-        consensus::{
-            poa::PoAConsensus,
-            Consensus,
-        },
         header::BlockHeader,
-        primitives::SecretKeyWrapper,
         SealedBlock,
     },
     fuel_asm::Word,
-    fuel_crypto::Signature,
     fuel_tx::TxId,
     fuel_types::BlockHeight,
-    secrecy::{
-        ExposeSecret,
-        Secret,
-    },
     services::{
         block_importer::ImportResult,
         executor::{
@@ -58,10 +49,7 @@ use fuel_core_types::{
     },
     tai64::Tai64,
 };
-use std::{
-    ops::Deref,
-    time::Duration,
-};
+use std::time::Duration;
 use tokio::{
     sync::{
         mpsc,
@@ -97,6 +85,26 @@ impl SharedState {
             .await?;
         receiver.await?
     }
+
+    /// Pauses the block production. The block in progress is finished first, and
+    /// the transaction pool keeps accepting transactions while the production is paused.
+    pub async fn pause_block_production(&self) -> anyhow::Result<()> {
+        self.set_paused(true).await
+    }
+
+    /// Resumes the paused block production.
+    pub async fn resume_block_production(&self) -> anyhow::Result<()> {
+        self.set_paused(false).await
+    }
+
+    async fn set_paused(&self, paused: bool) -> anyhow::Result<()> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(Request::SetPaused((paused, sender)))
+            .await?;
+        receiver.await?
+    }
 }
 
 struct ManualProduction {
@@ -109,6 +117,8 @@ enum Request {
     /// Manually produces the next blocks with `Tai64` block timestamp.
     /// The block timestamp should be higher than previous one.
     ManualBlocks((ManualProduction, oneshot::Sender<anyhow::Result<()>>)),
+    /// Pauses or resumes the block production.
+    SetPaused((bool, oneshot::Sender<anyhow::Result<()>>)),
 }
 
 impl core::fmt::Debug for Request {
@@ -124,7 +134,7 @@ pub(crate) enum RequestType {
 
 pub struct MainTask<T, B, I> {
     block_gas_limit: Word,
-    signing_key: Option<Secret<SecretKeyWrapper>>,
+    signer: SignMode,
     block_producer: B,
     block_importer: I,
     txpool: T,
@@ -135,6 +145,9 @@ pub struct MainTask<T, B, I> {
     last_timestamp: Tai64,
     last_block_created: Instant,
     trigger: Trigger,
+    consensus: ConsensusConfig,
+    /// The block production is paused by the operator.
+    paused: bool,
     /// Deadline clock, used by the triggers
     timer: DeadlineClock,
     sync_task_handle: ServiceRunner<SyncTask>,
@@ -163,10 +176,11 @@ where
 
         let Config {
             block_gas_limit,
-            signing_key,
+            signer,
             min_connected_reserved_peers,
             time_until_synced,
             trigger,
+            consensus,
             ..
         } = config;
 
@@ -182,7 +196,7 @@ where
 
         Self {
             block_gas_limit,
-            signing_key,
+            signer,
             txpool,
             block_producer,
             block_importer,
@@ -193,6 +207,8 @@ where
             last_timestamp,
             last_block_created,
             trigger,
+            consensus,
+            paused: false,
             timer: DeadlineClock::new(),
             sync_task_handle,
         }
@@ -207,6 +223,35 @@ where
         (last_height, last_timestamp, last_block_created)
     }
 
+    /// Returns `true` if the node is assigned to produce the block at the `height`
+    /// with the `block_time`. A single PoA authority owns all slots.
+    fn is_slot_producer(&self, height: &BlockHeight, block_time: Tai64) -> bool {
+        match &self.consensus {
+            ConsensusConfig::PoA { .. } => true,
+            ConsensusConfig::RoundRobin { .. } => {
+                let delay = block_time.0.saturating_sub(self.last_timestamp.0);
+                let producer = self.consensus.slot_producer(height, delay);
+                producer.is_some() && producer == self.signer.address()
+            }
+        }
+    }
+
+    /// Wakes up the node when the slot passes to the next authority, because
+    /// the node may be the fallback for the authority that skipped its slot.
+    async fn wait_for_next_slot(&self, block_time: Tai64) {
+        if let ConsensusConfig::RoundRobin { skip_timeout, .. } = &self.consensus {
+            let delay = block_time.0.saturating_sub(self.last_timestamp.0);
+            let timeout = match delay.checked_rem(*skip_timeout) {
+                Some(passed) => skip_timeout - passed,
+                // Without the skip timeout the slot only changes with the next block.
+                None => 1,
+            };
+            self.timer
+                .set_timeout(Duration::from_secs(timeout), OnConflict::Min)
+                .await;
+        }
+    }
+
     fn next_height(&self) -> BlockHeight {
         self.last_height + 1u32.into()
     }
@@ -218,7 +263,7 @@ where
                     let duration = self.last_block_created.elapsed();
                     increase_time(self.last_timestamp, duration)
                 }
-                Trigger::Interval { block_time } => {
+                Trigger::Interval { block_time } | Trigger::Hybrid { block_time, .. } => {
                     increase_time(self.last_timestamp, block_time)
                 }
             },
@@ -252,18 +297,26 @@ where
     }
 
     pub(crate) async fn produce_next_block(&mut self) -> anyhow::Result<()> {
-        self.produce_block(
-            self.next_height(),
-            self.next_time(RequestType::Trigger)?,
-            RequestType::Trigger,
-        )
-        .await
+        if self.paused {
+            return Ok(())
+        }
+        let height = self.next_height();
+        let block_time = self.next_time(RequestType::Trigger)?;
+        if !self.is_slot_producer(&height, block_time) {
+            self.wait_for_next_slot(block_time).await;
+            return Ok(())
+        }
+        self.produce_block(height, block_time, RequestType::Trigger)
+            .await
     }
 
     async fn produce_manual_blocks(
         &mut self,
         block_production: ManualProduction,
     ) -> anyhow::Result<()> {
+        if self.paused {
+            return Err(anyhow!("The block production is paused"))
+        }
         let mut block_time = if let Some(time) = block_production.start_time {
             time
         } else {
@@ -285,7 +338,7 @@ where
     ) -> anyhow::Result<()> {
         let last_block_created = Instant::now();
         // verify signing key is set
-        if self.signing_key.is_none() {
+        if !self.signer.is_available() {
             return Err(anyhow!("unable to produce blocks without a consensus key"))
         }
 
@@ -314,7 +367,7 @@ where
         self.txpool.remove_txs(tx_ids_to_remove);
 
         // Sign the block and seal it
-        let seal = seal_block(&self.signing_key, &block)?;
+        let seal = self.signer.seal_block(&block).await?;
         let block = SealedBlock {
             entity: block,
             consensus: seal,
@@ -342,7 +395,8 @@ where
                     .set_deadline(last_block_created + block_time, OnConflict::Min)
                     .await;
             }
-            (Trigger::Interval { block_time }, RequestType::Manual) => {
+            (Trigger::Interval { block_time }, RequestType::Manual)
+            | (Trigger::Hybrid { block_time, .. }, _) => {
                 self.timer
                     .set_deadline(last_block_created + block_time, OnConflict::Overwrite)
                     .await;
@@ -362,17 +416,55 @@ where
                 }
                 Ok(())
             }
+            Trigger::Hybrid {
+                max_gas, max_bytes, ..
+            } => {
+                // produce the block ahead of the interval if the pool is full enough
+                if self.txpool.total_consumable_gas() >= max_gas
+                    || self.txpool.total_bytes_size() >= max_bytes
+                {
+                    self.produce_next_block().await?;
+                }
+                Ok(())
+            }
             Trigger::Never | Trigger::Interval { .. } => Ok(()),
         }
     }
 
+    async fn set_paused(&mut self, paused: bool) -> anyhow::Result<()> {
+        if self.paused == paused {
+            return Ok(())
+        }
+        self.paused = paused;
+
+        if paused {
+            tracing::info!("Block production is paused");
+            return Ok(())
+        }
+        tracing::info!("Block production is resumed");
+        // The triggers were ignored during the pause, so restart them.
+        match self.trigger {
+            Trigger::Never => Ok(()),
+            Trigger::Instant => self.on_txpool_event().await,
+            Trigger::Interval { block_time } | Trigger::Hybrid { block_time, .. } => {
+                self.timer
+                    .set_timeout(block_time, OnConflict::Overwrite)
+                    .await;
+                Ok(())
+            }
+        }
+    }
+
     async fn on_timer(&mut self, _at: Instant) -> anyhow::Result<()> {
         match self.trigger {
-            Trigger::Instant | Trigger::Never => {
+            Trigger::Never => {
                 unreachable!("Timer is never set in this mode");
             }
+            // In the Instant mode the timer expires only when the slot of
+            // the round-robin consensus passes to the next authority.
+            Trigger::Instant => self.on_txpool_event().await,
             // In the Interval mode the timer expires only when a new block should be created.
-            Trigger::Interval { .. } => {
+            Trigger::Interval { .. } | Trigger::Hybrid { .. } => {
                 self.produce_next_block().await?;
                 Ok(())
             }
@@ -404,7 +496,7 @@ where
 
         match self.trigger {
             Trigger::Never | Trigger::Instant => {}
-            Trigger::Interval { block_time } => {
+            Trigger::Interval { block_time } | Trigger::Hybrid { block_time, .. } => {
                 self.timer
                     .set_timeout(block_time, OnConflict::Overwrite)
                     .await;
@@ -444,6 +536,17 @@ where
             }
         }
 
+        // With the round-robin consensus, other authorities produce blocks too.
+        if let SyncState::Synced(block_header) = &*self.sync_task_handle.shared.borrow() {
+            if block_header.height() > &self.last_height {
+                let (last_height, last_timestamp, last_block_created) =
+                    Self::extract_block_info(block_header);
+                self.last_height = last_height;
+                self.last_timestamp = last_timestamp;
+                self.last_block_created = last_block_created;
+            }
+        }
+
         tokio::select! {
             biased;
             _ = watcher.while_started() => {
@@ -456,6 +559,10 @@ where
                             let result = self.produce_manual_blocks(block).await;
                             let _ = response.send(result);
                         }
+                        Request::SetPaused((paused, response)) => {
+                            let result = self.set_paused(paused).await;
+                            let _ = response.send(result);
+                        }
                     }
                     should_continue = true;
                 } else {
@@ -515,25 +622,6 @@ where
     ))
 }
 
-fn seal_block(
-    signing_key: &Option<Secret<SecretKeyWrapper>>,
-    block: &Block,
-) -> anyhow::Result<Consensus> {
-    if let Some(key) = signing_key {
-        let block_hash = block.id();
-        let message = block_hash.into_message();
-
-        // The length of the secret is checked
-        let signing_key = key.expose_secret().deref();
-
-        let poa_signature = Signature::sign(signing_key, &message);
-        let seal = Consensus::PoA(PoAConsensus::new(poa_signature));
-        Ok(seal)
-    } else {
-        Err(anyhow!("no PoA signing key configured"))
-    }
-}
-
 fn increase_time(time: Tai64, duration: Duration) -> anyhow::Result<Tai64> {
     let timestamp = time.0;
     let timestamp = timestamp
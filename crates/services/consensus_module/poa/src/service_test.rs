@@ -9,6 +9,7 @@ use crate::{
     service::MainTask,
     Config,
     Service,
+    SignMode,
     Trigger,
 };
 use fuel_core_services::{
@@ -228,6 +229,15 @@ impl MockTransactionPool {
                 .map(|tx| *tx.gas_limit())
                 .sum()
         });
+        let bytes = txs.clone();
+        txpool.expect_total_bytes_size().returning(move || {
+            bytes
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|tx| tx.metered_bytes_size())
+                .sum()
+        });
         let removed = txs.clone();
         txpool
             .expect_remove_txs()
@@ -325,7 +335,7 @@ async fn remove_skipped_transactions() {
     let config = Config {
         trigger: Trigger::Instant,
         block_gas_limit: 1000000,
-        signing_key: Some(Secret::new(secret_key.into())),
+        signer: SignMode::Key(Secret::new(secret_key.into())),
         metrics: false,
         ..Default::default()
     };
@@ -373,7 +383,7 @@ async fn does_not_produce_when_txpool_empty_in_instant_mode() {
     let config = Config {
         trigger: Trigger::Instant,
         block_gas_limit: 1000000,
-        signing_key: Some(Secret::new(secret_key.into())),
+        signer: SignMode::Key(Secret::new(secret_key.into())),
         metrics: false,
         ..Default::default()
     };
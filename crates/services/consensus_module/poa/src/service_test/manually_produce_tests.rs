@@ -44,7 +44,7 @@ async fn can_manually_produce_block(
     ctx_builder.with_config(Config {
         trigger,
         block_gas_limit: 100_000,
-        signing_key: Some(test_signing_key()),
+        signer: SignMode::Key(test_signing_key()),
         metrics: false,
         consensus_params: consensus_params.clone(),
         ..Default::default()
@@ -1,4 +1,7 @@
 use super::*;
+use fuel_core_chain_config::ConsensusConfig;
+use fuel_core_types::secrecy::ExposeSecret;
+use test_case::test_case;
 
 #[tokio::test(start_paused = true)] // Run with time paused, start/stop must still work
 async fn clean_startup_shutdown_each_trigger() -> anyhow::Result<()> {
@@ -13,7 +16,7 @@ async fn clean_startup_shutdown_each_trigger() -> anyhow::Result<()> {
         ctx_builder.with_config(Config {
             trigger,
             block_gas_limit: 100_000,
-            signing_key: Some(test_signing_key()),
+            signer: SignMode::Key(test_signing_key()),
             metrics: false,
             ..Default::default()
         });
@@ -34,7 +37,7 @@ async fn never_trigger_never_produces_blocks() {
     ctx_builder.with_config(Config {
         trigger: Trigger::Never,
         block_gas_limit: 100_000,
-        signing_key: Some(test_signing_key()),
+        signer: SignMode::Key(test_signing_key()),
         metrics: false,
         consensus_params: consensus_params.clone(),
         ..Default::default()
@@ -121,7 +124,7 @@ async fn instant_trigger_produces_block_instantly() {
     let mut ctx = DefaultContext::new(Config {
         trigger: Trigger::Instant,
         block_gas_limit: 100_000,
-        signing_key: Some(test_signing_key()),
+        signer: SignMode::Key(test_signing_key()),
         metrics: false,
         ..Default::default()
     });
@@ -134,6 +137,65 @@ async fn instant_trigger_produces_block_instantly() {
     assert_eq!(ctx.test_ctx.stop().await, State::Stopped);
 }
 
+#[tokio::test(start_paused = true)]
+async fn paused_instant_trigger_produces_block_after_resume() {
+    let mut ctx = DefaultContext::new(Config {
+        trigger: Trigger::Instant,
+        block_gas_limit: 100_000,
+        signer: SignMode::Key(test_signing_key()),
+        metrics: false,
+        ..Default::default()
+    });
+    let shared = ctx.test_ctx.service.shared.clone();
+    shared.pause_block_production().await.unwrap();
+
+    // Make sure no blocks are produced while paused
+    ctx.status_sender.send_replace(Some(TxId::zeroed()));
+    time::sleep(Duration::new(1, 0)).await;
+    assert!(matches!(
+        ctx.block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+    assert!(shared.manually_produce_block(None, 1).await.is_err());
+
+    // The pending transactions are included after resume
+    shared.resume_block_production().await.unwrap();
+    assert!(ctx.block_import.try_recv().is_ok());
+
+    // Stop
+    assert_eq!(ctx.test_ctx.stop().await, State::Stopped);
+}
+
+#[test_case(true ; "Own slot")]
+#[test_case(false ; "Slot of another authority")]
+#[tokio::test(start_paused = true)]
+async fn round_robin_produces_blocks_only_in_own_slot(own_slot: bool) {
+    let own_key = Input::owner(&test_signing_key().expose_secret().public_key());
+    // The next block has the height `2`, so the slot belongs to the first authority.
+    let mut signing_keys = vec![own_key, Address::zeroed()];
+    if !own_slot {
+        signing_keys.reverse();
+    }
+    let mut ctx = DefaultContext::new(Config {
+        trigger: Trigger::Instant,
+        block_gas_limit: 100_000,
+        signer: SignMode::Key(test_signing_key()),
+        metrics: false,
+        consensus: ConsensusConfig::RoundRobin {
+            signing_keys,
+            skip_timeout: 0,
+        },
+        ..Default::default()
+    });
+    ctx.status_sender.send_replace(Some(TxId::zeroed()));
+
+    time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(ctx.block_import.try_recv().is_ok(), own_slot);
+
+    // Stop
+    assert_eq!(ctx.test_ctx.stop().await, State::Stopped);
+}
+
 #[tokio::test(start_paused = true)]
 async fn interval_trigger_produces_blocks_periodically() -> anyhow::Result<()> {
     let mut ctx = DefaultContext::new(Config {
@@ -141,7 +203,7 @@ async fn interval_trigger_produces_blocks_periodically() -> anyhow::Result<()> {
             block_time: Duration::new(2, 0),
         },
         block_gas_limit: 100_000,
-        signing_key: Some(test_signing_key()),
+        signer: SignMode::Key(test_signing_key()),
         metrics: false,
         ..Default::default()
     });
@@ -200,6 +262,39 @@ async fn interval_trigger_produces_blocks_periodically() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test_case(0, usize::MAX => true ; "Gas threshold reached")]
+#[test_case(u64::MAX, 0 => true ; "Bytes threshold reached")]
+#[test_case(u64::MAX, usize::MAX => false ; "Thresholds not reached")]
+#[tokio::test(start_paused = true)]
+async fn hybrid_trigger_produces_block_instantly_if_txpool_is_full(
+    max_gas: u64,
+    max_bytes: usize,
+) -> bool {
+    let mut ctx = DefaultContext::new(Config {
+        trigger: Trigger::Hybrid {
+            block_time: Duration::new(10, 0),
+            max_gas,
+            max_bytes,
+        },
+        block_gas_limit: 100_000,
+        signer: SignMode::Key(test_signing_key()),
+        metrics: false,
+        ..Default::default()
+    });
+    ctx.status_sender.send_replace(Some(TxId::zeroed()));
+
+    time::sleep(Duration::new(1, 0)).await;
+    let produced = ctx.block_import.try_recv().is_ok();
+
+    // The block is produced after the block time in any case
+    time::sleep(Duration::new(10, 0)).await;
+    assert!(ctx.block_import.try_recv().is_ok());
+
+    // Stop
+    assert_eq!(ctx.test_ctx.stop().await, State::Stopped);
+    produced
+}
+
 #[tokio::test(start_paused = true)]
 async fn interval_trigger_doesnt_react_to_full_txpool() -> anyhow::Result<()> {
     let mut ctx = DefaultContext::new(Config {
@@ -207,7 +302,7 @@ async fn interval_trigger_doesnt_react_to_full_txpool() -> anyhow::Result<()> {
             block_time: Duration::new(2, 0),
         },
         block_gas_limit: 100_000,
-        signing_key: Some(test_signing_key()),
+        signer: SignMode::Key(test_signing_key()),
         metrics: false,
         ..Default::default()
     });
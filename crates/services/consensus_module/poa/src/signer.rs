@@ -0,0 +1,150 @@
+use anyhow::{
+    anyhow,
+    ensure,
+    Context,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::{
+            poa::PoAConsensus,
+            Consensus,
+        },
+        primitives::{
+            BlockId,
+            SecretKeyWrapper,
+        },
+    },
+    fuel_crypto::Signature,
+    fuel_tx::Input,
+    fuel_types::{
+        Address,
+        Bytes64,
+    },
+    secrecy::{
+        ExposeSecret,
+        Secret,
+    },
+};
+use std::{
+    ops::Deref,
+    str::FromStr,
+    time::Duration,
+};
+
+/// The time to wait for the response of the remote signer.
+const REMOTE_SIGNER_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How the node signs the produced blocks.
+#[derive(Clone, Debug, Default)]
+pub enum SignMode {
+    /// The node can't sign blocks.
+    #[default]
+    Unavailable,
+    /// Blocks are signed with the in-process key.
+    Key(Secret<SecretKeyWrapper>),
+    /// Blocks are signed by the remote signer, so the key never leaves
+    /// the signing sidecar or HSM.
+    Remote(RemoteSigner),
+}
+
+impl SignMode {
+    /// Returns `true` if the node can sign blocks.
+    pub fn is_available(&self) -> bool {
+        !matches!(self, SignMode::Unavailable)
+    }
+
+    /// Returns the address of the block producer.
+    pub fn address(&self) -> Option<Address> {
+        match self {
+            SignMode::Unavailable => None,
+            SignMode::Key(key) => Some(Input::owner(&key.expose_secret().public_key())),
+            SignMode::Remote(signer) => Some(signer.address),
+        }
+    }
+
+    /// Signs the block and returns its seal.
+    pub async fn seal_block(&self, block: &Block) -> anyhow::Result<Consensus> {
+        let block_id = block.id();
+        let signature = match self {
+            SignMode::Unavailable => return Err(anyhow!("no PoA signing key configured")),
+            SignMode::Key(key) => {
+                let message = block_id.into_message();
+
+                // The length of the secret is checked
+                let signing_key = key.expose_secret().deref();
+
+                Signature::sign(signing_key, &message)
+            }
+            SignMode::Remote(signer) => signer.sign(block_id).await?,
+        };
+        Ok(Consensus::PoA(PoAConsensus::new(signature)))
+    }
+}
+
+/// The client of the remote signer. The id of the block is sent to the JSON-RPC
+/// `sign` method, which should return the hex-encoded signature of the id.
+#[derive(Clone, Debug)]
+pub struct RemoteSigner {
+    url: String,
+    address: Address,
+    client: reqwest::Client,
+}
+
+impl RemoteSigner {
+    /// Creates the client of the signer at the `url`, holding the key of the `address`.
+    pub fn new(url: impl Into<String>, address: Address) -> Self {
+        Self {
+            url: url.into(),
+            address,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Returns the address of the key held by the signer.
+    pub fn address(&self) -> Address {
+        self.address
+    }
+
+    async fn sign(&self, block_id: BlockId) -> anyhow::Result<Signature> {
+        let request = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "sign",
+            "params": [format!("{block_id:#x}")],
+        });
+        let response = self
+            .client
+            .post(&self.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(serde_json::to_vec(&request)?)
+            .timeout(REMOTE_SIGNER_TIMEOUT)
+            .send()
+            .await
+            .context("Failed to reach the remote signer")?
+            .error_for_status()?
+            .bytes()
+            .await?;
+        let response: serde_json::Value = serde_json::from_slice(&response)?;
+
+        if let Some(error) = response.get("error") {
+            return Err(anyhow!(
+                "The remote signer failed to sign the block: {error}"
+            ))
+        }
+        let signature = response
+            .get("result")
+            .and_then(|result| result.as_str())
+            .ok_or(anyhow!("The remote signer didn't return the signature"))?;
+        let signature = Bytes64::from_str(signature)
+            .map_err(|e| anyhow!("The remote signer returned invalid signature: {e}"))?;
+        let signature = Signature::from_bytes(*signature);
+
+        let public_key = signature.recover(&block_id.into_message())?;
+        ensure!(
+            Input::owner(&public_key) == self.address,
+            "The remote signer signed the block with an unexpected key"
+        );
+        Ok(signature)
+    }
+}
@@ -8,6 +8,7 @@ use fuel_core_types::{
         header::BlockHeader,
     },
     fuel_tx::Input,
+    fuel_types::Address,
 };
 
 #[cfg(test)]
@@ -25,24 +26,72 @@ pub fn verify_consensus(
     header: &BlockHeader,
     consensus: &PoAConsensus,
 ) -> bool {
+    block_producer(header, consensus)
+        .map_or(false, |producer| consensus_config.is_authority(&producer))
+}
+
+/// Verifies that the block is signed by the authority assigned to its slot.
+/// It is required only by the round-robin consensus, because with a single
+/// authority [`verify_consensus`] already checks the producer.
+pub fn verify_slot_producer<D: Database>(
+    consensus_config: &ConsensusConfig,
+    database: &D,
+    header: &BlockHeader,
+    consensus: &PoAConsensus,
+) -> anyhow::Result<()> {
     match consensus_config {
-        ConsensusConfig::PoA { signing_key } => {
-            let id = header.id();
-            let m = id.as_message();
-            consensus
-                .signature
-                .recover(m)
-                .map_or(false, |k| Input::owner(&k) == *signing_key)
+        ConsensusConfig::PoA { .. } => Ok(()),
+        ConsensusConfig::RoundRobin { .. } => {
+            let height = *header.height();
+            ensure!(
+                height != 0u32.into(),
+                "The PoA block can't have the zero height"
+            );
+            let prev_header = database.block_header(&(height - 1u32.into()))?;
+            let delay = header.time().0.saturating_sub(prev_header.time().0);
+            let expected = consensus_config.slot_producer(&height, delay);
+            ensure!(
+                expected.is_some() && expected == block_producer(header, consensus),
+                "The block is not signed by the producer of the slot"
+            );
+            Ok(())
         }
     }
 }
 
+fn block_producer(header: &BlockHeader, consensus: &PoAConsensus) -> Option<Address> {
+    let id = header.id();
+    consensus
+        .signature
+        .recover(id.as_message())
+        .ok()
+        .map(|k| Input::owner(&k))
+}
+
 pub fn verify_block_fields<D: Database>(
     config: &Config,
     database: &D,
     block: &Block,
 ) -> anyhow::Result<()> {
-    let height = *block.header().height();
+    verify_header_fields(config, database, block.header())?;
+
+    // TODO: We can check the root of the transactions and the root of the messages here.
+    //  But we do the same in the executor right now during validation mode. I will not check
+    //  it for now. But after merge of the https://github.com/FuelLabs/fuel-core/pull/889 it
+    //  is should be easy to do with the `validate_transactions` method. And maybe we want
+    //  to remove this check from the executor and replace it with check that transaction
+    //  id is not modified during the execution.
+
+    Ok(())
+}
+
+/// Verifies the fields of the header that don't depend on the transactions of the block.
+pub fn verify_header_fields<D: Database>(
+    config: &Config,
+    database: &D,
+    header: &BlockHeader,
+) -> anyhow::Result<()> {
+    let height = *header.height();
     ensure!(
         height != 0u32.into(),
         "The PoA block can't have the zero height"
@@ -50,7 +99,6 @@ pub fn verify_block_fields<D: Database>(
 
     let prev_height = height - 1u32.into();
     let prev_root = database.block_header_merkle_root(&prev_height)?;
-    let header = block.header();
     ensure!(
         header.prev_root() == &prev_root,
         "Previous root of the next block should match the previous block root"
@@ -76,12 +124,5 @@ pub fn verify_block_fields<D: Database>(
         "The application hash mismatch."
     );
 
-    // TODO: We can check the root of the transactions and the root of the messages here.
-    //  But we do the same in the executor right now during validation mode. I will not check
-    //  it for now. But after merge of the https://github.com/FuelLabs/fuel-core/pull/889 it
-    //  is should be easy to do with the `validate_transactions` method. And maybe we want
-    //  to remove this check from the executor and replace it with check that transaction
-    //  id is not modified during the execution.
-
     Ok(())
 }
@@ -7,9 +7,17 @@ use fuel_core_types::{
         GeneratedApplicationFields,
         GeneratedConsensusFields,
     },
+    fuel_crypto::{
+        SecretKey,
+        Signature,
+    },
     fuel_types::Bytes32,
     tai64::Tai64,
 };
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
 use test_case::test_case;
 
 struct Input {
@@ -111,3 +119,35 @@ fn test_verify_genesis_block_fields(input: Input) -> anyhow::Result<()> {
     b.header_mut().application = ah;
     verify_block_fields(&c, &d, &b)
 }
+
+#[test_case(0, 0 => true ; "Producer of the slot")]
+#[test_case(1, 0 => false ; "Producer of another slot")]
+#[test_case(1, 10 => true ; "Next producer after the skip timeout")]
+#[test_case(0, 10 => false ; "Skipped producer after the skip timeout")]
+#[test_case(0, 20 => true ; "Slot returns after skipping all producers")]
+fn test_verify_slot_producer(signer: usize, delay: u64) -> bool {
+    let mut rng = StdRng::seed_from_u64(2);
+    let secrets: Vec<_> = (0..2).map(|_| SecretKey::random(&mut rng)).collect();
+    let config = ConsensusConfig::RoundRobin {
+        signing_keys: secrets
+            .iter()
+            .map(|secret| fuel_core_types::fuel_tx::Input::owner(&secret.public_key()))
+            .collect(),
+        skip_timeout: 10,
+    };
+    let mut d = MockDatabase::default();
+    d.expect_block_header().returning(|_| {
+        let mut h = BlockHeader::default();
+        h.consensus.time = Tai64(100);
+        Ok(h)
+    });
+
+    let mut header = BlockHeader::default();
+    header.consensus.height = 2u32.into();
+    header.consensus.time = Tai64(100 + delay);
+    let signature = Signature::sign(&secrets[signer], &header.id().into_message());
+    let consensus = PoAConsensus::new(signature);
+
+    verify_consensus(&config, &header, &consensus)
+        && verify_slot_producer(&config, &d, &header, &consensus).is_ok()
+}
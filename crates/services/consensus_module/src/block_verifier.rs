@@ -57,23 +57,54 @@ where
         &self,
         consensus: &Consensus,
         block: &Block,
+    ) -> anyhow::Result<()> {
+        match consensus {
+            Consensus::Genesis(_) => verify_genesis_block_fields(
+                self.expected_genesis_height(),
+                block.header(),
+            ),
+            Consensus::PoA(consensus) => {
+                fuel_core_poa::verifier::verify_block_fields(
+                    &self.config.poa,
+                    &self.database,
+                    block,
+                )?;
+                fuel_core_poa::verifier::verify_slot_producer(
+                    &self.config.chain_config.consensus,
+                    &self.database,
+                    block.header(),
+                    consensus,
+                )
+            }
+        }
+    }
+
+    /// Verifies the fields of the header based on used consensus. Unlike
+    /// [`Verifier::verify_block_fields`], it doesn't require the transactions of the block.
+    ///
+    /// Return an error if the verification failed, otherwise `Ok(())`.
+    pub fn verify_header_fields(
+        &self,
+        consensus: &Consensus,
+        header: &BlockHeader,
     ) -> anyhow::Result<()> {
         match consensus {
             Consensus::Genesis(_) => {
-                let expected_genesis_height = self
-                    .config
-                    .chain_config
-                    .initial_state
-                    .as_ref()
-                    .map(|config| config.height.unwrap_or_else(|| 0u32.into()))
-                    .unwrap_or_else(|| 0u32.into());
-                verify_genesis_block_fields(expected_genesis_height, block.header())
+                verify_genesis_block_fields(self.expected_genesis_height(), header)
+            }
+            Consensus::PoA(consensus) => {
+                fuel_core_poa::verifier::verify_header_fields(
+                    &self.config.poa,
+                    &self.database,
+                    header,
+                )?;
+                fuel_core_poa::verifier::verify_slot_producer(
+                    &self.config.chain_config.consensus,
+                    &self.database,
+                    header,
+                    consensus,
+                )
             }
-            Consensus::PoA(_) => fuel_core_poa::verifier::verify_block_fields(
-                &self.config.poa,
-                &self.database,
-                block,
-            ),
         }
     }
 
@@ -93,6 +124,15 @@ where
         }
     }
 
+    fn expected_genesis_height(&self) -> BlockHeight {
+        self.config
+            .chain_config
+            .initial_state
+            .as_ref()
+            .map(|config| config.height.unwrap_or_else(|| 0u32.into()))
+            .unwrap_or_else(|| 0u32.into())
+    }
+
     /// Wait for the relayer to be in sync with the given DA height
     /// if the `da_height` is within the range of the current
     /// relayer sync'd height - `max_da_lag`.
@@ -13,4 +13,14 @@ pub struct Config {
     pub backtrace: bool,
     /// Default mode for utxo_validation
     pub utxo_validation_default: bool,
+    /// The number of threads executing the independent transactions of the block
+    /// in parallel. `0` and `1` mean the sequential execution. Takes effect only
+    /// with the `parallel-executor` feature of `fuel-core`.
+    pub parallel_workers: usize,
+    /// The memory budget in bytes of the contract code cached between the executions.
+    /// `0` disables the cache.
+    pub contract_code_cache_size: usize,
+    /// The number of transactions with the verified predicates cached between
+    /// the executions. `0` disables the cache.
+    pub predicate_cache_size: usize,
 }
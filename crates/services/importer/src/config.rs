@@ -2,6 +2,16 @@
 pub struct Config {
     pub max_block_notify_buffer: usize,
     pub metrics: bool,
+    pub max_reorg_depth: u32,
+    /// Keeps the changes of all blocks, so the state at any height can be restored.
+    pub archive: bool,
+    /// Computes the state diff of every imported block, stores it, and attaches it
+    /// to the import result.
+    pub state_diff: bool,
+    /// Indexes the receipts of every imported block by the contracts that emitted them.
+    pub index_receipts: bool,
+    /// Indexes the supply and the holders of the assets changed by every imported block.
+    pub index_assets: bool,
 }
 
 impl Default for Config {
@@ -9,6 +19,11 @@ impl Default for Config {
         Self {
             max_block_notify_buffer: 1 << 10,
             metrics: false,
+            max_reorg_depth: 10,
+            archive: false,
+            state_diff: false,
+            index_receipts: false,
+            index_assets: false,
         }
     }
 }
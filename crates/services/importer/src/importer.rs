@@ -20,11 +20,13 @@ use fuel_core_types::{
         },
         primitives::BlockId,
         SealedBlock,
+        SealedBlockHeader,
     },
     fuel_types::BlockHeight,
     services::{
         block_importer::{
             ImportResult,
+            Reorg,
             UncommittedResult,
         },
         executor,
@@ -72,6 +74,11 @@ pub enum Error {
     ExecuteGenesis,
     #[error("The database already contains the data at the height {0}.")]
     NotUnique(BlockHeight),
+    #[error(
+        "It is not possible to roll back the chain from the height {0} to {1}. \
+        The maximum reorg depth is {2}."
+    )]
+    ReorgTooDeep(BlockHeight, BlockHeight, u32),
     #[error(transparent)]
     StorageError(#[from] StorageError),
 }
@@ -88,17 +95,33 @@ pub struct Importer<D, E, V> {
     executor: E,
     verifier: V,
     broadcast: broadcast::Sender<Arc<ImportResult>>,
+    reorgs: broadcast::Sender<Arc<Reorg>>,
+    headers: broadcast::Sender<Arc<SealedBlockHeader>>,
+    max_reorg_depth: u32,
+    archive: bool,
+    state_diff: bool,
+    index_receipts: bool,
+    index_assets: bool,
     guard: tokio::sync::Semaphore,
 }
 
 impl<D, E, V> Importer<D, E, V> {
     pub fn new(config: Config, database: D, executor: E, verifier: V) -> Self {
         let (broadcast, _) = broadcast::channel(config.max_block_notify_buffer);
+        let (reorgs, _) = broadcast::channel(config.max_block_notify_buffer);
+        let (headers, _) = broadcast::channel(config.max_block_notify_buffer);
         Self {
             database,
             executor,
             verifier,
             broadcast,
+            reorgs,
+            headers,
+            max_reorg_depth: config.max_reorg_depth,
+            archive: config.archive,
+            state_diff: config.state_diff,
+            index_receipts: config.index_receipts,
+            index_assets: config.index_assets,
             guard: tokio::sync::Semaphore::new(1),
         }
     }
@@ -107,6 +130,16 @@ impl<D, E, V> Importer<D, E, V> {
         self.broadcast.subscribe()
     }
 
+    /// Subscribes to the reorganizations of the chain.
+    pub fn subscribe_reorgs(&self) -> broadcast::Receiver<Arc<Reorg>> {
+        self.reorgs.subscribe()
+    }
+
+    /// Subscribes to the headers committed without the transactions.
+    pub fn subscribe_headers(&self) -> broadcast::Receiver<Arc<SealedBlockHeader>> {
+        self.headers.subscribe()
+    }
+
     pub(crate) fn lock(&self) -> Result<tokio::sync::SemaphorePermit, Error> {
         let guard = self.guard.try_acquire();
         match guard {
@@ -166,7 +199,7 @@ where
     where
         ExecutorDatabase: ports::ExecutorDatabase,
     {
-        let (result, mut db_tx) = result.into();
+        let (mut result, mut db_tx) = result.into();
         let block = &result.sealed_block.entity;
         let consensus = &result.sealed_block.consensus;
         let block_id = block.id();
@@ -223,12 +256,87 @@ where
             .seal_block(&block_id, &result.sealed_block.consensus)?
             .should_be_unique(&expected_next_height)?;
 
+        // The diff is stored before the rollback changes, so it is reverted with the block.
+        let state_diff = if self.state_diff {
+            Some(db_after_execution.store_state_diff(&expected_next_height)?)
+        } else {
+            None
+        };
+
+        // The indexes are updated before the rollback changes, so they are reverted
+        // with the block.
+        if self.index_receipts || self.index_assets {
+            let tx_ids: Vec<_> =
+                result.tx_status.iter().map(|status| status.id).collect();
+            if self.index_receipts {
+                db_after_execution.index_receipts(&expected_next_height, &tx_ids)?;
+            }
+            if self.index_assets {
+                db_after_execution.index_assets(&tx_ids)?;
+            }
+        }
+
+        // The genesis block is never reverted.
+        if !matches!(consensus, Consensus::Genesis(_)) {
+            // The archive keeps the changes of all blocks to restore the historical state.
+            let max_depth = if self.archive {
+                u32::MAX
+            } else {
+                self.max_reorg_depth
+            };
+            db_after_execution
+                .store_rollback_changes(&expected_next_height, max_depth)?;
+        }
+
         db_tx.commit()?;
 
         tracing::info!("Committed block");
+        result.state_diff = state_diff;
         let _ = self.broadcast.send(Arc::new(result));
         Ok(())
     }
+
+    /// Reverts the blocks above the `common_ancestor`, so the blocks of the competing
+    /// chain can be imported on top of it. The reverted blocks are broadcast to the
+    /// reorg subscribers.
+    ///
+    /// Returns an error if more than `max_reorg_depth` blocks should be reverted.
+    ///
+    /// # Concurrency
+    ///
+    /// Only one commit or rollback may be in progress at the time.
+    pub fn rollback_to(&self, common_ancestor: BlockHeight) -> Result<(), Error> {
+        let _guard = self.lock()?;
+        let latest_height = self.database.latest_block_height()?;
+        let depth = latest_height.saturating_sub(*common_ancestor);
+        if depth == 0 {
+            return Ok(())
+        }
+        if depth > self.max_reorg_depth {
+            return Err(Error::ReorgTooDeep(
+                latest_height,
+                common_ancestor,
+                self.max_reorg_depth,
+            ))
+        }
+
+        let mut reverted_blocks = Vec::with_capacity(depth as usize);
+        for _ in 0..depth {
+            reverted_blocks.push(self.database.rollback_last_block()?);
+        }
+
+        tracing::warn!(
+            "Reverted {} blocks from the height {} to the common ancestor {}",
+            depth,
+            latest_height,
+            common_ancestor
+        );
+        let _ = self.reorgs.send(Arc::new(Reorg {
+            common_ancestor,
+            reverted_blocks,
+        }));
+        Ok(())
+    }
 }
 
 impl<IDatabase, E, V> Importer<IDatabase, E, V>
@@ -319,6 +427,74 @@ where
     }
 }
 
+impl<IDatabase, E, V> Importer<IDatabase, E, V>
+where
+    IDatabase: ImporterDatabase,
+    V: BlockVerifier,
+{
+    /// The method validates the header fields and commits the `SealedBlockHeader`
+    /// without the transactions of the block. It is used by the nodes that track
+    /// only the headers of the chain and don't execute the blocks.
+    ///
+    /// The validity of the seal information is not the concern of this function.
+    ///
+    /// After the commit into the database notifies about a new imported header.
+    ///
+    /// # Concurrency
+    ///
+    /// Only one commit may be in progress at the time. All other calls will fail.
+    /// Returns an error if called while another call is in progress.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            block_id = %sealed_header.entity.id(),
+            height = **sealed_header.entity.height(),
+        ),
+        err
+    )]
+    pub fn verify_and_commit_header(
+        &self,
+        sealed_header: SealedBlockHeader,
+    ) -> Result<(), Error> {
+        let _guard = self.lock()?;
+        let header = &sealed_header.entity;
+        let consensus = &sealed_header.consensus;
+
+        // The genesis block is always created by the node itself.
+        if let Consensus::Genesis(_) = consensus {
+            return Err(Error::ExecuteGenesis)
+        }
+
+        let result_of_verification =
+            self.verifier.verify_header_fields(consensus, header);
+        if let Err(err) = result_of_verification {
+            return Err(Error::FailedVerification(err))
+        }
+
+        let actual_next_height = *header.height();
+        let expected_next_height: BlockHeight = self
+            .database
+            .latest_block_height()?
+            .checked_add(1u32)
+            .ok_or(Error::Overflow)?
+            .into();
+        if expected_next_height != actual_next_height {
+            return Err(Error::IncorrectBlockHeight(
+                expected_next_height,
+                actual_next_height,
+            ))
+        }
+
+        self.database
+            .store_sealed_header(&sealed_header)?
+            .should_be_unique(&expected_next_height)?;
+
+        tracing::info!("Committed header");
+        let _ = self.headers.send(Arc::new(sealed_header));
+        Ok(())
+    }
+}
+
 trait ShouldBeUnique {
     fn should_be_unique(&self, height: &BlockHeight) -> Result<(), Error>;
 }
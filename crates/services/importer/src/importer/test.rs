@@ -6,6 +6,7 @@ use crate::{
         MockBlockVerifier,
         MockExecutor,
     },
+    Config,
     Importer,
 };
 use anyhow::anyhow;
@@ -24,12 +25,14 @@ use fuel_core_types::{
         consensus::Consensus,
         primitives::BlockId,
         SealedBlock,
+        SealedBlockHeader,
     },
     fuel_tx::TxId,
     fuel_types::BlockHeight,
     services::{
         block_importer::{
             ImportResult,
+            StateDiff,
             UncommittedResult,
         },
         executor::{
@@ -40,6 +43,10 @@ use fuel_core_types::{
         Uncommitted,
     },
 };
+use std::sync::{
+    Arc,
+    Mutex,
+};
 use test_case::test_case;
 use tokio::sync::{
     broadcast::error::TryRecvError,
@@ -51,6 +58,13 @@ mockall::mock! {
 
     impl ImporterDatabase for Database {
         fn latest_block_height(&self) -> StorageResult<BlockHeight>;
+
+        fn rollback_last_block(&self) -> StorageResult<SealedBlock>;
+
+        fn store_sealed_header(
+            &self,
+            header: &SealedBlockHeader,
+        ) -> StorageResult<Option<Consensus>>;
     }
 
     impl ExecutorDatabase for Database {
@@ -59,6 +73,22 @@ mockall::mock! {
             block_id: &BlockId,
             consensus: &Consensus,
         ) -> StorageResult<Option<Consensus>>;
+
+        fn store_rollback_changes(
+            &mut self,
+            height: &BlockHeight,
+            max_depth: u32,
+        ) -> StorageResult<()>;
+
+        fn store_state_diff(&mut self, height: &BlockHeight) -> StorageResult<StateDiff>;
+
+        fn index_receipts(
+            &mut self,
+            height: &BlockHeight,
+            tx_ids: &[TxId],
+        ) -> StorageResult<()>;
+
+        fn index_assets(&mut self, tx_ids: &[TxId]) -> StorageResult<()>;
     }
 
     impl TransactionTrait<MockDatabase> for Database {
@@ -131,6 +161,7 @@ where
         db.expect_latest_block_height()
             .returning(move || height().map(Into::into));
         db.expect_seal_block().returning(move |_, _| seal());
+        db.expect_store_rollback_changes().returning(|_, _| Ok(()));
         db.expect_commit().times(commits).returning(|| Ok(()));
 
         db
@@ -531,3 +562,137 @@ fn verify_and_execute_allowed_when_locked() {
     let _guard = importer.lock();
     assert!(importer.verify_and_execute_block(poa_block(13)).is_ok());
 }
+
+fn chain_db(latest_height: u32) -> MockDatabase {
+    let height = Arc::new(Mutex::new(latest_height));
+    let mut db = MockDatabase::default();
+    db.expect_latest_block_height().returning({
+        let height = height.clone();
+        move || Ok((*height.lock().unwrap()).into())
+    });
+    db.expect_rollback_last_block().returning(move || {
+        let mut height = height.lock().unwrap();
+        let reverted = poa_block(*height);
+        *height -= 1;
+        Ok(reverted)
+    });
+    db
+}
+
+#[test]
+fn rollback_to_reverts_blocks_above_common_ancestor() {
+    let importer = Importer::new(Default::default(), chain_db(10), (), ());
+    let mut reorgs = importer.subscribe_reorgs();
+
+    importer.rollback_to(7u32.into()).unwrap();
+
+    let reorg = reorgs.try_recv().unwrap();
+    assert_eq!(reorg.common_ancestor, 7u32.into());
+    assert_eq!(
+        reorg.reverted_blocks,
+        vec![poa_block(10), poa_block(9), poa_block(8)]
+    );
+}
+
+#[test]
+fn rollback_to_latest_height_does_nothing() {
+    let importer = Importer::new(Default::default(), chain_db(10), (), ());
+    let mut reorgs = importer.subscribe_reorgs();
+
+    importer.rollback_to(10u32.into()).unwrap();
+
+    assert_eq!(reorgs.try_recv(), Err(TryRecvError::Empty));
+}
+
+#[test]
+fn rollback_deeper_than_max_reorg_depth_fails() {
+    let config = Config {
+        max_reorg_depth: 2,
+        ..Default::default()
+    };
+    let importer = Importer::new(config, chain_db(10), (), ());
+
+    assert_eq!(
+        importer.rollback_to(7u32.into()),
+        Err(Error::ReorgTooDeep(10u32.into(), 7u32.into(), 2))
+    );
+}
+
+fn sealed_header(block: SealedBlock) -> SealedBlockHeader {
+    SealedBlockHeader {
+        entity: block.entity.header().clone(),
+        consensus: block.consensus,
+    }
+}
+
+fn header_db<S>(latest_height: u32, store: S) -> MockDatabase
+where
+    S: Fn() -> StorageResult<Option<Consensus>> + Send + 'static,
+{
+    let mut db = MockDatabase::default();
+    db.expect_latest_block_height()
+        .returning(move || Ok(latest_height.into()));
+    db.expect_store_sealed_header().returning(move |_| store());
+    db
+}
+
+fn header_verifier<R>(result: R) -> MockBlockVerifier
+where
+    R: Fn() -> anyhow::Result<()> + Send + 'static,
+{
+    let mut verifier = MockBlockVerifier::default();
+    verifier
+        .expect_verify_header_fields()
+        .return_once(move |_, _| result());
+
+    verifier
+}
+
+#[test_case(
+    poa_block(11),
+    header_db(10, ok(None)),
+    header_verifier(ok(()))
+    => Ok(()) ; "commits the next header"
+)]
+#[test_case(
+    poa_block(12),
+    header_db(10, ok(None)),
+    header_verifier(ok(()))
+    => Err(Error::IncorrectBlockHeight(11u32.into(), 12u32.into())) ; "fails on the gap"
+)]
+#[test_case(
+    poa_block(11),
+    header_db(10, ok(Some(Consensus::PoA(Default::default())))),
+    header_verifier(ok(()))
+    => Err(Error::NotUnique(11u32.into())) ; "fails if the header already exists"
+)]
+#[test_case(
+    poa_block(11),
+    header_db(10, ok(None)),
+    header_verifier(verification_failure)
+    => Err(verification_failure_error()) ; "fails if the verification fails"
+)]
+#[test_case(
+    genesis(11),
+    header_db(10, ok(None)),
+    MockBlockVerifier::default()
+    => Err(Error::ExecuteGenesis) ; "fails for the genesis header"
+)]
+fn verify_and_commit_header(
+    block: SealedBlock,
+    db: MockDatabase,
+    verifier: MockBlockVerifier,
+) -> Result<(), Error> {
+    let importer = Importer::new(Default::default(), db, (), verifier);
+    let mut headers = importer.subscribe_headers();
+    let header = sealed_header(block);
+
+    let result = importer.verify_and_commit_header(header.clone());
+
+    if result.is_ok() {
+        assert_eq!(headers.try_recv().unwrap().as_ref(), &header);
+    } else {
+        assert_eq!(headers.try_recv(), Err(TryRecvError::Empty));
+    }
+    result
+}
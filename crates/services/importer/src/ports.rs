@@ -6,12 +6,19 @@ use fuel_core_types::{
     blockchain::{
         block::Block,
         consensus::Consensus,
+        header::BlockHeader,
         primitives::BlockId,
+        SealedBlock,
+        SealedBlockHeader,
     },
+    fuel_tx::TxId,
     fuel_types::BlockHeight,
-    services::executor::{
-        Result as ExecutorResult,
-        UncommittedResult,
+    services::{
+        block_importer::StateDiff,
+        executor::{
+            Result as ExecutorResult,
+            UncommittedResult,
+        },
     },
 };
 
@@ -33,6 +40,16 @@ pub trait Executor: Send + Sync {
 pub trait ImporterDatabase {
     /// Returns the latest block height.
     fn latest_block_height(&self) -> StorageResult<BlockHeight>;
+
+    /// Reverts the state changes of the latest block and returns the reverted block.
+    fn rollback_last_block(&self) -> StorageResult<SealedBlock>;
+
+    /// Stores the header with its `Consensus` data without the transactions of the block.
+    /// Return the previous value at the height of the header, if any.
+    fn store_sealed_header(
+        &self,
+        header: &SealedBlockHeader,
+    ) -> StorageResult<Option<Consensus>>;
 }
 
 /// The port for returned database from the executor.
@@ -44,6 +61,30 @@ pub trait ExecutorDatabase: ImporterDatabase {
         block_id: &BlockId,
         consensus: &Consensus,
     ) -> StorageResult<Option<Consensus>>;
+
+    /// Stores the changes required to roll back the block at the `height`.
+    /// Only the changes of the latest `max_depth` blocks are kept.
+    fn store_rollback_changes(
+        &mut self,
+        height: &BlockHeight,
+        max_depth: u32,
+    ) -> StorageResult<()>;
+
+    /// Computes the changes of the state made by the block at the `height`
+    /// and stores them under the `height`.
+    fn store_state_diff(&mut self, height: &BlockHeight) -> StorageResult<StateDiff>;
+
+    /// Indexes the receipts of the transactions at the `height` by the contracts
+    /// that emitted them. The `tx_ids` are ordered as the transactions of the block.
+    fn index_receipts(
+        &mut self,
+        height: &BlockHeight,
+        tx_ids: &[TxId],
+    ) -> StorageResult<()>;
+
+    /// Updates the supply and the holders of the assets by the transactions of the
+    /// block. The `tx_ids` are ordered as the transactions of the block.
+    fn index_assets(&mut self, tx_ids: &[TxId]) -> StorageResult<()>;
 }
 
 #[cfg_attr(test, mockall::automock)]
@@ -59,4 +100,15 @@ pub trait BlockVerifier {
         consensus: &Consensus,
         block: &Block,
     ) -> anyhow::Result<()>;
+
+    /// Verifies the consistency of the header fields for the header's height.
+    /// Unlike `verify_block_fields`, it doesn't verify the fields derived from
+    /// the transactions of the block.
+    ///
+    /// Return an error if the verification failed, otherwise `Ok(())`.
+    fn verify_header_fields(
+        &self,
+        consensus: &Consensus,
+        header: &BlockHeader,
+    ) -> anyhow::Result<()>;
 }
@@ -21,12 +21,21 @@ use crate::{
 };
 use fuel_core_types::fuel_types::BlockHeight;
 use libp2p::{
+    dcutr::behaviour::{
+        Behaviour as Dcutr,
+        Event as DcutrEvent,
+    },
     gossipsub::{
         error::PublishError,
         Gossipsub,
         GossipsubEvent,
         MessageAcceptance,
         MessageId,
+        TopicHash,
+    },
+    relay::v2::client::{
+        Client as RelayClient,
+        Event as RelayClientEvent,
     },
     request_response::{
         ProtocolSupport,
@@ -52,6 +61,8 @@ pub enum FuelBehaviourEvent {
     PeerReport(PeerReportEvent),
     Gossipsub(GossipsubEvent),
     RequestResponse(RequestResponseEvent<RequestMessage, NetworkResponse>),
+    Relay(RelayClientEvent),
+    Dcutr(DcutrEvent),
 }
 
 /// Handles all p2p protocols needed for Fuel.
@@ -69,10 +80,16 @@ pub struct FuelBehaviour<Codec: NetworkCodec> {
 
     /// RequestResponse protocol
     request_response: RequestResponse<Codec>,
+
+    /// Reserves the circuits on the relay nodes and dials the peers through the relays
+    relay: RelayClient,
+
+    /// Upgrades the relayed connections to the direct ones with the hole punching
+    dcutr: Dcutr,
 }
 
 impl<Codec: NetworkCodec> FuelBehaviour<Codec> {
-    pub(crate) fn new(p2p_config: &Config, codec: Codec) -> Self {
+    pub(crate) fn new(p2p_config: &Config, codec: Codec, relay: RelayClient) -> Self {
         let local_public_key = p2p_config.keypair.public();
         let local_peer_id = PeerId::from_public_key(&local_public_key);
 
@@ -118,6 +135,8 @@ impl<Codec: NetworkCodec> FuelBehaviour<Codec> {
             gossipsub,
             peer_report,
             request_response,
+            relay,
+            dcutr: Dcutr::new(),
         }
     }
 
@@ -139,6 +158,27 @@ impl<Codec: NetworkCodec> FuelBehaviour<Codec> {
         self.gossipsub.publish(topic, encoded_data)
     }
 
+    /// Returns `true` if all known peers subscribed to the `topic`
+    /// are also subscribed to the `capability_topic`.
+    pub fn topic_peers_subscribed_to(
+        &self,
+        topic: &TopicHash,
+        capability_topic: &TopicHash,
+    ) -> bool {
+        self.gossipsub
+            .all_peers()
+            .filter(|(_, topics)| topics.contains(&topic))
+            .all(|(_, topics)| topics.contains(&capability_topic))
+    }
+
+    /// Returns the number of the mesh peers of each subscribed topic.
+    pub fn mesh_peers_count(&self) -> Vec<(TopicHash, usize)> {
+        self.gossipsub
+            .topics()
+            .map(|topic| (topic.clone(), self.gossipsub.mesh_peers(topic).count()))
+            .collect()
+    }
+
     pub fn send_request_msg(
         &mut self,
         message_request: RequestMessage,
@@ -218,3 +258,15 @@ impl From<RequestResponseEvent<RequestMessage, NetworkResponse>> for FuelBehavio
         FuelBehaviourEvent::RequestResponse(event)
     }
 }
+
+impl From<RelayClientEvent> for FuelBehaviourEvent {
+    fn from(event: RelayClientEvent) -> Self {
+        FuelBehaviourEvent::Relay(event)
+    }
+}
+
+impl From<DcutrEvent> for FuelBehaviourEvent {
+    fn from(event: DcutrEvent) -> Self {
+        FuelBehaviourEvent::Dcutr(event)
+    }
+}
@@ -19,6 +19,10 @@ use crate::{
     },
 };
 use async_trait::async_trait;
+use fuel_core_metrics::p2p_metrics::{
+    p2p_label,
+    P2P_METRICS,
+};
 use futures::{
     AsyncRead,
     AsyncWriteExt,
@@ -39,18 +43,47 @@ use serde::{
 };
 use std::io;
 
+const REQUEST_RESPONSE_METRICS_LABEL: &str = "request_response";
+
 #[derive(Debug, Clone)]
 pub struct PostcardCodec {
     /// Used for `max_size` parameter when reading Response Message
     /// Necessary in order to avoid DoS attacks
     /// Currently the size mostly depends on the max size of the Block
     max_response_size: usize,
+    /// Whether or not the sizes of the requests and responses are collected
+    metrics: bool,
 }
 
 impl PostcardCodec {
     pub fn new(max_block_size: usize) -> Self {
         Self {
             max_response_size: max_block_size,
+            metrics: false,
+        }
+    }
+
+    /// Enables the collection of the request-response bandwidth metrics
+    pub fn with_metrics(mut self, metrics: bool) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    fn record_received(&self, bytes: usize) {
+        if self.metrics {
+            P2P_METRICS
+                .bytes_received
+                .get_or_create(&p2p_label("protocol", REQUEST_RESPONSE_METRICS_LABEL))
+                .inc_by(bytes as u64);
+        }
+    }
+
+    fn record_sent(&self, bytes: usize) {
+        if self.metrics {
+            P2P_METRICS
+                .bytes_sent
+                .get_or_create(&p2p_label("protocol", REQUEST_RESPONSE_METRICS_LABEL))
+                .inc_by(bytes as u64);
         }
     }
 
@@ -92,6 +125,7 @@ impl RequestResponseCodec for PostcardCodec {
         T: AsyncRead + Unpin + Send,
     {
         let encoded_data = read_length_prefixed(socket, MAX_REQUEST_SIZE).await?;
+        self.record_received(encoded_data.len());
 
         self.deserialize(&encoded_data)
     }
@@ -105,6 +139,7 @@ impl RequestResponseCodec for PostcardCodec {
         T: futures::AsyncRead + Unpin + Send,
     {
         let encoded_data = read_length_prefixed(socket, self.max_response_size).await?;
+        self.record_received(encoded_data.len());
 
         self.deserialize(&encoded_data)
     }
@@ -120,6 +155,7 @@ impl RequestResponseCodec for PostcardCodec {
     {
         match postcard::to_stdvec(&req) {
             Ok(encoded_data) => {
+                self.record_sent(encoded_data.len());
                 write_length_prefixed(socket, encoded_data).await?;
                 socket.close().await?;
 
@@ -140,6 +176,7 @@ impl RequestResponseCodec for PostcardCodec {
     {
         match postcard::to_stdvec(&res) {
             Ok(encoded_data) => {
+                self.record_sent(encoded_data.len());
                 write_length_prefixed(socket, encoded_data).await?;
                 socket.close().await?;
 
@@ -159,6 +196,7 @@ impl GossipsubCodec for PostcardCodec {
             GossipsubBroadcastRequest::ConsensusVote(vote) => postcard::to_stdvec(&*vote),
             GossipsubBroadcastRequest::NewBlock(block) => postcard::to_stdvec(&*block),
             GossipsubBroadcastRequest::NewTx(tx) => postcard::to_stdvec(&*tx),
+            GossipsubBroadcastRequest::NewTxId(tx_id) => postcard::to_stdvec(&tx_id),
         };
 
         encoded_data.map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))
@@ -173,6 +211,9 @@ impl GossipsubCodec for PostcardCodec {
             GossipTopicTag::NewTx => {
                 GossipsubMessage::NewTx(self.deserialize(encoded_data)?)
             }
+            GossipTopicTag::NewTxId => {
+                GossipsubMessage::NewTxId(self.deserialize(encoded_data)?)
+            }
             GossipTopicTag::NewBlock => {
                 GossipsubMessage::NewBlock(self.deserialize(encoded_data)?)
             }
@@ -213,6 +254,15 @@ impl RequestResponseConverter for PostcardCodec {
 
                 Ok(ResponseMessage::SealedHeader(response))
             }
+            NetworkResponse::Headers(headers_bytes) => {
+                let response = if let Some(headers_bytes) = headers_bytes {
+                    Some(self.deserialize(headers_bytes)?)
+                } else {
+                    None
+                };
+
+                Ok(ResponseMessage::SealedHeaders(response))
+            }
             NetworkResponse::Transactions(tx_bytes) => {
                 let response = if let Some(tx_bytes) = tx_bytes {
                     Some(self.deserialize(tx_bytes)?)
@@ -222,6 +272,25 @@ impl RequestResponseConverter for PostcardCodec {
 
                 Ok(ResponseMessage::Transactions(response))
             }
+            NetworkResponse::Transaction(tx_bytes) => {
+                let response = if let Some(tx_bytes) = tx_bytes {
+                    Some(self.deserialize(tx_bytes)?)
+                } else {
+                    None
+                };
+
+                Ok(ResponseMessage::Transaction(response))
+            }
+            NetworkResponse::Peers(peers_bytes) => {
+                let response = if let Some(peers_bytes) = peers_bytes {
+                    Some(self.deserialize(peers_bytes)?)
+                } else {
+                    None
+                };
+
+                Ok(ResponseMessage::Peers(response))
+            }
+            NetworkResponse::Throttled => Ok(ResponseMessage::Throttled),
         }
     }
 
@@ -248,6 +317,15 @@ impl RequestResponseConverter for PostcardCodec {
 
                 Ok(NetworkResponse::Header(response))
             }
+            OutboundResponse::SealedHeaders(sealed_headers) => {
+                let response = if let Some(sealed_headers) = sealed_headers {
+                    Some(self.serialize(sealed_headers.as_ref())?)
+                } else {
+                    None
+                };
+
+                Ok(NetworkResponse::Headers(response))
+            }
             OutboundResponse::Transactions(transactions) => {
                 let response = if let Some(transactions) = transactions {
                     Some(self.serialize(transactions.as_ref())?)
@@ -257,6 +335,24 @@ impl RequestResponseConverter for PostcardCodec {
 
                 Ok(NetworkResponse::Transactions(response))
             }
+            OutboundResponse::Transaction(transaction) => {
+                let response = if let Some(transaction) = transaction {
+                    Some(self.serialize(transaction.as_ref())?)
+                } else {
+                    None
+                };
+
+                Ok(NetworkResponse::Transaction(response))
+            }
+            OutboundResponse::Peers(peers) => {
+                let response = if let Some(peers) = peers {
+                    Some(self.serialize(peers)?)
+                } else {
+                    None
+                };
+
+                Ok(NetworkResponse::Peers(response))
+            }
         }
     }
 }
@@ -3,10 +3,14 @@ use crate::{
     heartbeat::HeartbeatConfig,
     peer_manager::ConnectionState,
 };
-use fuel_core_types::blockchain::consensus::Genesis;
+use fuel_core_types::{
+    blockchain::consensus::Genesis,
+    fuel_types::ChainId,
+};
 
 use libp2p::{
     core::{
+        either::EitherOutput,
         muxing::StreamMuxerBox,
         transport::Boxed,
     },
@@ -16,7 +20,10 @@ use libp2p::{
         Keypair,
     },
     mplex,
-    noise::{self,},
+    noise::{
+        self,
+    },
+    relay::v2::client::transport::ClientTransport as RelayClientTransport,
     tcp::{
         tokio::Transport as TokioTcpTransport,
         Config as TcpConfig,
@@ -47,11 +54,18 @@ use self::{
         FuelUpgrade,
     },
     guarded_node::GuardedNode,
+    quic::build_quic_transport,
 };
 mod connection_tracker;
 mod fuel_authenticated;
 mod fuel_upgrade;
 mod guarded_node;
+mod quic;
+
+pub(crate) use quic::{
+    is_quic_address,
+    quic_address,
+};
 
 const REQ_RES_TIMEOUT: Duration = Duration::from_secs(20);
 
@@ -65,6 +79,18 @@ pub const MAX_RESPONSE_SIZE: usize = 18 * 1024 * 1024;
 /// inbound and outbound connections established through the transport.
 const TRANSPORT_TIMEOUT: Duration = Duration::from_secs(20);
 
+/// Defines how the transactions are propagated through the network.
+/// All nodes of the network should use the same mode.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TxPropagation {
+    /// The transactions are gossiped with their bodies.
+    #[default]
+    Full,
+    /// Only the ids of the transactions are gossiped.
+    /// Peers request the bodies of the unknown transactions from the announcer.
+    Announce,
+}
+
 #[derive(Clone, Debug)]
 pub struct Config<State = Initialized> {
     /// The keypair used for for handshake during communication with other p2p nodes.
@@ -76,6 +102,9 @@ pub struct Config<State = Initialized> {
     /// Checksum is a hash(sha256) of [`Genesis`](fuel_core_types::blockchain::consensus::Genesis) - chain id.
     pub checksum: Checksum,
 
+    /// The id of the chain, used to calculate the ids of the announced transactions.
+    pub chain_id: ChainId,
+
     /// IP address for Swarm to listen on
     pub address: IpAddr,
 
@@ -85,12 +114,36 @@ pub struct Config<State = Initialized> {
     /// The TCP port that Swarm listens on
     pub tcp_port: u16,
 
+    /// The UDP port that Swarm listens on with the QUIC transport
+    pub quic_port: Option<u16>,
+
+    /// Additional addresses that Swarm listens on. The transport is selected per address:
+    /// `/ip4/<ip>/tcp/<port>` for TCP and `/ip4/<ip>/udp/<port>/quic` for QUIC.
+    /// QUIC is disabled if neither `quic_port` nor a QUIC listen address is set.
+    pub listen_addresses: Vec<Multiaddr>,
+
+    /// The relay nodes that reserve the circuits for this node, so the peers can reach
+    /// the node behind the NAT through them. The relayed connections are upgraded to
+    /// the direct ones with the hole punching when it is possible.
+    /// The addresses should contain the `PeerId` of the relay.
+    pub relay_nodes: Vec<Multiaddr>,
+
+    /// Map the TCP and QUIC ports on the UPnP IGD or NAT-PMP gateway of the local network
+    /// and advertise the external addresses to the peers. Disabled by default
+    pub port_mapping: bool,
+
     /// Max Size of a Block in bytes
     pub max_block_size: usize,
 
     // `DiscoveryBehaviour` related fields
     pub bootstrap_nodes: Vec<Multiaddr>,
+    /// Domain names whose `TXT` and `SRV` records are resolved into the bootstrap nodes
+    pub bootstrap_dns_seeds: Vec<String>,
+    /// How often the DNS seeds are resolved again. If `None`, only at startup
+    pub dns_seeds_refresh_interval: Option<Duration>,
     pub enable_mdns: bool,
+    /// Request a sample of the known peers from each new peer and share ours on request
+    pub peer_exchange: bool,
     pub allow_private_addresses: bool,
     pub random_walk: Option<Duration>,
     pub connection_idle_timeout: Option<Duration>,
@@ -118,6 +171,10 @@ pub struct Config<State = Initialized> {
 
     // `Gossipsub` config
     pub gossipsub_config: GossipsubConfig,
+    /// Compress the gossiped messages if all subscribed peers support the compression
+    pub gossip_compression: bool,
+    /// How the transactions are propagated through the network
+    pub tx_propagation: TxPropagation,
 
     pub heartbeat_config: HeartbeatConfig,
 
@@ -126,6 +183,10 @@ pub struct Config<State = Initialized> {
     pub set_request_timeout: Duration,
     /// Sets the keep-alive timeout of idle connections.
     pub set_connection_keep_alive: Duration,
+    /// Max number of the inbound requests of a single peer processed concurrently
+    pub max_concurrent_requests_per_peer: usize,
+    /// Max number of the inbound requests of a single peer per second
+    pub max_requests_per_second_per_peer: usize,
 
     /// Enables prometheus metrics for this fuel-service
     pub metrics: bool,
@@ -136,6 +197,13 @@ pub struct Config<State = Initialized> {
     pub state: State,
 }
 
+impl<State> Config<State> {
+    /// Returns `true` if Swarm listens on at least one QUIC address.
+    pub fn quic_enabled(&self) -> bool {
+        self.quic_port.is_some() || self.listen_addresses.iter().any(is_quic_address)
+    }
+}
+
 /// The initialized state can be achieved only by the `init` function because `()` is private.
 #[derive(Clone, Debug)]
 pub struct Initialized(());
@@ -152,12 +220,20 @@ impl Config<NotInitialized> {
             keypair: self.keypair,
             network_name: self.network_name,
             checksum: genesis.root()?.into(),
+            chain_id: self.chain_id,
             address: self.address,
             public_address: self.public_address,
             tcp_port: self.tcp_port,
+            quic_port: self.quic_port,
+            listen_addresses: self.listen_addresses,
+            relay_nodes: self.relay_nodes,
+            port_mapping: self.port_mapping,
             max_block_size: self.max_block_size,
             bootstrap_nodes: self.bootstrap_nodes,
+            bootstrap_dns_seeds: self.bootstrap_dns_seeds,
+            dns_seeds_refresh_interval: self.dns_seeds_refresh_interval,
             enable_mdns: self.enable_mdns,
+            peer_exchange: self.peer_exchange,
             max_peers_connected: self.max_peers_connected,
             max_connections_per_peer: self.max_connections_per_peer,
             allow_private_addresses: self.allow_private_addresses,
@@ -168,9 +244,13 @@ impl Config<NotInitialized> {
             identify_interval: self.identify_interval,
             info_interval: self.info_interval,
             gossipsub_config: self.gossipsub_config,
+            gossip_compression: self.gossip_compression,
+            tx_propagation: self.tx_propagation,
             heartbeat_config: self.heartbeat_config,
             set_request_timeout: self.set_request_timeout,
             set_connection_keep_alive: self.set_connection_keep_alive,
+            max_concurrent_requests_per_peer: self.max_concurrent_requests_per_peer,
+            max_requests_per_second_per_peer: self.max_requests_per_second_per_peer,
             metrics: self.metrics,
             state: Initialized(()),
         })
@@ -195,12 +275,20 @@ impl Config<NotInitialized> {
             keypair,
             network_name: network_name.into(),
             checksum: Default::default(),
+            chain_id: Default::default(),
             address: IpAddr::V4(Ipv4Addr::from([0, 0, 0, 0])),
             public_address: None,
             tcp_port: 0,
+            quic_port: None,
+            listen_addresses: vec![],
+            relay_nodes: vec![],
+            port_mapping: false,
             max_block_size: MAX_RESPONSE_SIZE,
             bootstrap_nodes: vec![],
+            bootstrap_dns_seeds: vec![],
+            dns_seeds_refresh_interval: None,
             enable_mdns: false,
+            peer_exchange: false,
             max_peers_connected: 50,
             max_connections_per_peer: 3,
             allow_private_addresses: true,
@@ -209,9 +297,13 @@ impl Config<NotInitialized> {
             reserved_nodes: vec![],
             reserved_nodes_only_mode: false,
             gossipsub_config: default_gossipsub_config(),
+            gossip_compression: true,
+            tx_propagation: TxPropagation::Full,
             heartbeat_config: HeartbeatConfig::default(),
             set_request_timeout: REQ_RES_TIMEOUT,
             set_connection_keep_alive: REQ_RES_TIMEOUT,
+            max_concurrent_requests_per_peer: 20,
+            max_requests_per_second_per_peer: 100,
             info_interval: Some(Duration::from_secs(3)),
             identify_interval: Some(Duration::from_secs(5)),
             metrics: false,
@@ -233,8 +325,11 @@ impl Config<Initialized> {
 /// TCP/IP, Websocket
 /// Noise as encryption layer
 /// mplex or yamux for multiplexing
+/// QUIC, if it is enabled by `quic_port` or one of `listen_addresses`
+/// Relayed connections through the `relay_transport`, upgraded like the TCP ones
 pub(crate) fn build_transport(
     p2p_config: &Config,
+    relay_transport: RelayClientTransport,
 ) -> (
     Boxed<(PeerId, StreamMuxerBox)>,
     Arc<RwLock<ConnectionState>>,
@@ -248,7 +343,9 @@ pub(crate) fn build_transport(
         let ws_tcp =
             libp2p::websocket::WsConfig::new(generate_tcp_transport()).or_transport(tcp);
 
-        libp2p::dns::TokioDnsConfig::system(ws_tcp).unwrap()
+        let dns_ws_tcp = libp2p::dns::TokioDnsConfig::system(ws_tcp).unwrap();
+
+        relay_transport.or_transport(dns_ws_tcp)
     }
     .upgrade(libp2p::core::upgrade::Version::V1);
 
@@ -271,31 +368,62 @@ pub(crate) fn build_transport(
     let fuel_upgrade = FuelUpgrade::new(p2p_config.checksum);
     let connection_state = ConnectionState::new();
 
-    let transport = if p2p_config.reserved_nodes_only_mode {
+    let (transport, quic_transport) = if p2p_config.reserved_nodes_only_mode {
         let guarded_node = GuardedNode::new(&p2p_config.reserved_nodes);
 
+        let quic_transport = p2p_config.quic_enabled().then(|| {
+            build_quic_transport(
+                &p2p_config.keypair,
+                fuel_upgrade.clone(),
+                guarded_node.clone(),
+            )
+        });
+
         let fuel_authenticated =
             FuelAuthenticated::new(noise_authenticated, guarded_node);
 
-        transport
+        let transport = transport
             .authenticate(fuel_authenticated)
             .apply(fuel_upgrade)
             .multiplex(multiplex_config)
             .timeout(TRANSPORT_TIMEOUT)
-            .boxed()
+            .boxed();
+
+        (transport, quic_transport)
     } else {
         let connection_tracker =
             ConnectionTracker::new(&p2p_config.reserved_nodes, connection_state.clone());
 
+        let quic_transport = p2p_config.quic_enabled().then(|| {
+            build_quic_transport(
+                &p2p_config.keypair,
+                fuel_upgrade.clone(),
+                connection_tracker.clone(),
+            )
+        });
+
         let fuel_authenticated =
             FuelAuthenticated::new(noise_authenticated, connection_tracker);
 
-        transport
+        let transport = transport
             .authenticate(fuel_authenticated)
             .apply(fuel_upgrade)
             .multiplex(multiplex_config)
             .timeout(TRANSPORT_TIMEOUT)
-            .boxed()
+            .boxed();
+
+        (transport, quic_transport)
+    };
+
+    let transport = match quic_transport {
+        Some(quic_transport) => quic_transport
+            .or_transport(transport)
+            .map(|output, _| match output {
+                EitherOutput::First(output) => output,
+                EitherOutput::Second(output) => output,
+            })
+            .boxed(),
+        None => transport,
     };
 
     (transport, connection_state)
@@ -0,0 +1,161 @@
+use super::{
+    fuel_authenticated::Approver,
+    fuel_upgrade::FuelUpgrade,
+    TRANSPORT_TIMEOUT,
+};
+use futures::{
+    future,
+    AsyncRead,
+    AsyncWrite,
+    AsyncWriteExt,
+};
+use libp2p::{
+    core::{
+        muxing::{
+            StreamMuxerBox,
+            StreamMuxerExt,
+        },
+        transport::Boxed,
+        ConnectedPoint,
+        StreamMuxer,
+        UpgradeInfo,
+    },
+    multiaddr::Protocol,
+    quic::{
+        tokio::Transport as QuicTransport,
+        Config as QuicConfig,
+    },
+    InboundUpgrade,
+    Multiaddr,
+    OutboundUpgrade,
+    PeerId,
+    Transport,
+};
+use std::{
+    io,
+    task::Poll,
+};
+
+/// QUIC transport. The encryption and the multiplexing are provided by the QUIC itself.
+/// It reuses the listening UDP socket for the outbound connections, which allows
+/// the connections through the NAT in more cases than TCP.
+/// The relayed connections are upgraded to the direct QUIC ones by the `dcutr` protocol
+/// of the `FuelBehaviour`.
+pub(crate) fn build_quic_transport<A>(
+    keypair: &libp2p::identity::Keypair,
+    fuel_upgrade: FuelUpgrade,
+    approver: A,
+) -> Boxed<(PeerId, StreamMuxerBox)>
+where
+    A: Approver + Clone + Send + Sync + 'static,
+{
+    QuicTransport::new(QuicConfig::new(keypair))
+        .and_then(move |(peer_id, connection), endpoint| async move {
+            if !approver.allow_peer(&peer_id) {
+                return Err(io::Error::new(
+                    io::ErrorKind::PermissionDenied,
+                    format!("The connection with {peer_id} is not allowed"),
+                ))
+            }
+            let connection =
+                exchange_checksum(connection, &endpoint, fuel_upgrade).await?;
+
+            Ok((peer_id, StreamMuxerBox::new(connection)))
+        })
+        .timeout(TRANSPORT_TIMEOUT)
+        .boxed()
+}
+
+/// Applies the `FuelUpgrade` to the first substream of the connection,
+/// since the QUIC connections are not upgraded like the TCP ones.
+async fn exchange_checksum<M>(
+    mut connection: M,
+    endpoint: &ConnectedPoint,
+    fuel_upgrade: FuelUpgrade,
+) -> io::Result<M>
+where
+    M: StreamMuxer + Unpin,
+    M::Substream: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    M::Error: std::error::Error + Send + Sync + 'static,
+{
+    let substream = future::poll_fn(|cx| {
+        // Drives the connection while the substream is opened
+        if let Poll::Ready(Err(e)) = connection.poll_unpin(cx) {
+            return Poll::Ready(Err(e))
+        }
+        if endpoint.is_dialer() {
+            connection.poll_outbound_unpin(cx)
+        } else {
+            connection.poll_inbound_unpin(cx)
+        }
+    })
+    .await
+    .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+
+    let info = fuel_upgrade
+        .protocol_info()
+        .next()
+        .expect("`FuelUpgrade` has a protocol");
+    let mut substream = if endpoint.is_dialer() {
+        fuel_upgrade.upgrade_outbound(substream, info).await
+    } else {
+        fuel_upgrade.upgrade_inbound(substream, info).await
+    }
+    .map_err(|e| io::Error::new(io::ErrorKind::PermissionDenied, e))?;
+    substream.close().await?;
+
+    Ok(connection)
+}
+
+/// Returns the QUIC address with the same host as the `address`.
+pub(crate) fn quic_address(address: &Multiaddr, quic_port: u16) -> Option<Multiaddr> {
+    let host = address.iter().next()?;
+    match host {
+        Protocol::Ip4(_)
+        | Protocol::Ip6(_)
+        | Protocol::Dns(_)
+        | Protocol::Dns4(_)
+        | Protocol::Dns6(_) => Some(
+            Multiaddr::empty()
+                .with(host)
+                .with(Protocol::Udp(quic_port))
+                .with(Protocol::Quic),
+        ),
+        _ => None,
+    }
+}
+
+/// Returns `true` if the `address` is served by the QUIC transport.
+pub(crate) fn is_quic_address(address: &Multiaddr) -> bool {
+    address.iter().any(|protocol| protocol == Protocol::Quic)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quic_address_keeps_the_host() {
+        let tcp_address: Multiaddr = "/ip4/1.2.3.4/tcp/30333".parse().unwrap();
+        let dns_address: Multiaddr = "/dns4/node.example.com/tcp/30333".parse().unwrap();
+
+        assert_eq!(
+            quic_address(&tcp_address, 30334),
+            Some("/ip4/1.2.3.4/udp/30334/quic".parse().unwrap())
+        );
+        assert_eq!(
+            quic_address(&dns_address, 30334),
+            Some("/dns4/node.example.com/udp/30334/quic".parse().unwrap())
+        );
+        assert_eq!(quic_address(&Multiaddr::empty(), 30334), None);
+    }
+
+    #[test]
+    fn quic_address_is_detected_by_the_protocol() {
+        let tcp_address: Multiaddr = "/ip4/0.0.0.0/tcp/30333".parse().unwrap();
+        let quic_address: Multiaddr = "/ip4/0.0.0.0/udp/30334/quic".parse().unwrap();
+
+        assert!(!is_quic_address(&tcp_address));
+        assert!(is_quic_address(&quic_address));
+    }
+}
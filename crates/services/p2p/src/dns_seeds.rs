@@ -0,0 +1,118 @@
+use fuel_core_services::stream::BoxStream;
+use libp2p::{
+    multiaddr::Protocol,
+    Multiaddr,
+};
+use std::time::Duration;
+use trust_dns_resolver::TokioAsyncResolver;
+
+/// The prefix of the `TXT` record that contains the multiaddr of the bootstrap node.
+const DNSADDR_PREFIX: &str = "dnsaddr=";
+
+/// Returns the stream of the addresses resolved from the DNS `seeds`.
+/// The seeds are resolved at startup and after each `refresh_interval`, if any.
+pub(crate) fn dns_seeds_stream(
+    seeds: Vec<String>,
+    refresh_interval: Option<Duration>,
+) -> BoxStream<Vec<Multiaddr>> {
+    if seeds.is_empty() {
+        return Box::pin(fuel_core_services::stream::pending())
+    }
+
+    let resolver = match TokioAsyncResolver::tokio_from_system_conf() {
+        Ok(resolver) => resolver,
+        Err(e) => {
+            tracing::error!("Unable to create the DNS resolver for the seeds: {e}");
+            return Box::pin(fuel_core_services::stream::pending())
+        }
+    };
+
+    Box::pin(fuel_core_services::stream::unfold(
+        (resolver, seeds, refresh_interval, true),
+        |(resolver, seeds, refresh_interval, first)| async move {
+            if !first {
+                tokio::time::sleep(refresh_interval?).await;
+            }
+            let addresses = resolve(&resolver, &seeds).await;
+            Some((addresses, (resolver, seeds, refresh_interval, false)))
+        },
+    ))
+}
+
+/// Resolves the `TXT` and `SRV` records of the `seeds` into the multiaddrs.
+async fn resolve(resolver: &TokioAsyncResolver, seeds: &[String]) -> Vec<Multiaddr> {
+    let mut addresses = vec![];
+    for seed in seeds {
+        match resolver.txt_lookup(seed.as_str()).await {
+            Ok(records) => addresses.extend(records.iter().filter_map(|txt| {
+                let record = txt
+                    .txt_data()
+                    .iter()
+                    .map(|data| String::from_utf8_lossy(data))
+                    .collect::<String>();
+                parse_txt_record(&record)
+            })),
+            Err(e) => tracing::debug!("No TXT records for the DNS seed `{seed}`: {e}"),
+        }
+        match resolver.srv_lookup(seed.as_str()).await {
+            Ok(records) => addresses.extend(
+                records
+                    .iter()
+                    .map(|srv| srv_address(&srv.target().to_utf8(), srv.port())),
+            ),
+            Err(e) => tracing::debug!("No SRV records for the DNS seed `{seed}`: {e}"),
+        }
+    }
+    tracing::info!(
+        "Resolved {} bootstrap addresses from the DNS seeds",
+        addresses.len()
+    );
+    addresses
+}
+
+/// Parses the `TXT` record in the `dnsaddr=<multiaddr>` or the `<multiaddr>` format.
+fn parse_txt_record(record: &str) -> Option<Multiaddr> {
+    let address = record.strip_prefix(DNSADDR_PREFIX).unwrap_or(record);
+    match address.parse() {
+        Ok(address) => Some(address),
+        Err(e) => {
+            tracing::debug!("Skipping the TXT record `{record}`: {e}");
+            None
+        }
+    }
+}
+
+/// Builds the multiaddr from the target and the port of the `SRV` record.
+fn srv_address(target: &str, port: u16) -> Multiaddr {
+    let host = target.trim_end_matches('.');
+    Multiaddr::empty()
+        .with(Protocol::Dns(host.into()))
+        .with(Protocol::Tcp(port))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn txt_record_is_parsed_with_and_without_prefix() {
+        let address: Multiaddr = "/ip4/127.0.0.1/tcp/4001".parse().unwrap();
+
+        assert_eq!(
+            parse_txt_record("dnsaddr=/ip4/127.0.0.1/tcp/4001"),
+            Some(address.clone())
+        );
+        assert_eq!(parse_txt_record("/ip4/127.0.0.1/tcp/4001"), Some(address));
+        assert_eq!(parse_txt_record("v=spf1 -all"), None);
+    }
+
+    #[test]
+    fn srv_record_is_converted_into_dns_address() {
+        let address = srv_address("bootnode.example.com.", 30333);
+
+        assert_eq!(
+            address,
+            "/dns/bootnode.example.com/tcp/30333".parse().unwrap()
+        );
+    }
+}
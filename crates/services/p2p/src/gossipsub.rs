@@ -1,3 +1,4 @@
+pub(crate) mod compression;
 pub mod config;
 pub mod messages;
 pub mod topics;
@@ -0,0 +1,87 @@
+use flate2::{
+    read::DeflateDecoder,
+    write::DeflateEncoder,
+    Compression,
+};
+use std::io::{
+    Read,
+    Write,
+};
+
+/// Prefix of the compressed gossip payloads.
+/// The uncompressed payloads are sent without any prefix to stay compatible
+/// with the peers that don't support the compression.
+const COMPRESSED_PREFIX: [u8; 4] = [0xff, b'f', b'z', 0x01];
+
+/// Payloads smaller than this are broadcast uncompressed, since they don't benefit from it.
+const MIN_COMPRESSION_SIZE: usize = 256;
+
+/// Compresses the encoded gossip payload.
+/// Returns the original payload if it is too small or the compression doesn't reduce its size.
+pub(crate) fn compress(encoded_data: Vec<u8>) -> Vec<u8> {
+    if encoded_data.len() < MIN_COMPRESSION_SIZE {
+        return encoded_data
+    }
+
+    let mut encoder =
+        DeflateEncoder::new(COMPRESSED_PREFIX.to_vec(), Compression::default());
+    let compressed = encoder
+        .write_all(&encoded_data)
+        .and_then(|_| encoder.finish());
+
+    match compressed {
+        Ok(compressed) if compressed.len() < encoded_data.len() => compressed,
+        _ => encoded_data,
+    }
+}
+
+/// Decompresses the gossip payload if it has the prefix of the compressed one.
+/// Returns `None` if the payload is not compressed, is malformed,
+/// or its decompressed size exceeds `max_size`(protection against the compression bombs).
+pub(crate) fn decompress(data: &[u8], max_size: usize) -> Option<Vec<u8>> {
+    let compressed = data.strip_prefix(&COMPRESSED_PREFIX)?;
+
+    let mut decompressed = vec![];
+    DeflateDecoder::new(compressed)
+        .take(max_size as u64 + 1)
+        .read_to_end(&mut decompressed)
+        .ok()?;
+
+    if decompressed.len() > max_size {
+        return None
+    }
+
+    Some(decompressed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compressed_payload_is_decompressed() {
+        let data = vec![7u8; 4 * MIN_COMPRESSION_SIZE];
+
+        let compressed = compress(data.clone());
+
+        assert!(compressed.len() < data.len());
+        assert_eq!(decompress(&compressed, data.len()), Some(data));
+    }
+
+    #[test]
+    fn small_payloads_are_not_compressed() {
+        let data = vec![1, 2, 3];
+
+        assert_eq!(compress(data.clone()), data);
+        assert_eq!(decompress(&data, data.len()), None);
+    }
+
+    #[test]
+    fn decompression_is_limited_by_max_size() {
+        let data = vec![7u8; 4 * MIN_COMPRESSION_SIZE];
+
+        let compressed = compress(data.clone());
+
+        assert_eq!(decompress(&compressed, data.len() - 1), None);
+    }
+}
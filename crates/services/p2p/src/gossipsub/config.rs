@@ -28,8 +28,10 @@ use std::time::Duration;
 use super::topics::{
     GossipTopic,
     CON_VOTE_GOSSIP_TOPIC,
+    GOSSIP_COMPRESSION_TOPIC,
     NEW_BLOCK_GOSSIP_TOPIC,
     NEW_TX_GOSSIP_TOPIC,
+    NEW_TX_ID_GOSSIP_TOPIC,
 };
 
 // The number of slots in each epoch.
@@ -53,6 +55,9 @@ const MESH_SIZE: usize = 8;
 // The weight applied to the score for delivering new transactions.
 const NEW_TX_GOSSIP_WEIGHT: f64 = 0.05;
 
+// The weight applied to the score for delivering announcements of new transactions.
+const NEW_TX_ID_GOSSIP_WEIGHT: f64 = 0.05;
+
 // The weight applied to the score for delivering new blocks.
 const NEW_BLOCK_GOSSIP_WEIGHT: f64 = 0.05;
 
@@ -221,6 +226,7 @@ fn initialize_gossipsub(gossipsub: &mut Gossipsub, p2p_config: &Config) {
 
     let topics = vec![
         (NEW_TX_GOSSIP_TOPIC, NEW_TX_GOSSIP_WEIGHT),
+        (NEW_TX_ID_GOSSIP_TOPIC, NEW_TX_ID_GOSSIP_WEIGHT),
         (NEW_BLOCK_GOSSIP_TOPIC, NEW_BLOCK_GOSSIP_WEIGHT),
         (CON_VOTE_GOSSIP_TOPIC, CON_VOTE_GOSSIP_WEIGHT),
     ];
@@ -237,4 +243,13 @@ fn initialize_gossipsub(gossipsub: &mut Gossipsub, p2p_config: &Config) {
             .subscribe(&t)
             .expect("Subscription to Topic: {topic} successful");
     }
+
+    // advertise the support of the compressed gossip messages
+    let compression_topic: GossipTopic = Topic::new(format!(
+        "{}/{}",
+        GOSSIP_COMPRESSION_TOPIC, p2p_config.network_name
+    ));
+    gossipsub
+        .subscribe(&compression_topic)
+        .expect("Subscription to the compression topic successful");
 }
@@ -1,6 +1,9 @@
 use std::sync::Arc;
 
-use fuel_core_types::fuel_tx::Transaction;
+use fuel_core_types::fuel_tx::{
+    Transaction,
+    TxId,
+};
 
 use fuel_core_types::blockchain::{
     block::Block,
@@ -16,6 +19,7 @@ use serde::{
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum GossipTopicTag {
     NewTx,
+    NewTxId,
     NewBlock,
     ConsensusVote,
 }
@@ -26,6 +30,8 @@ pub enum GossipTopicTag {
 #[derive(Debug, Clone)]
 pub enum GossipsubBroadcastRequest {
     NewTx(Arc<Transaction>),
+    /// Announces the transaction without its body
+    NewTxId(TxId),
     NewBlock(Arc<Block>),
     ConsensusVote(Arc<ConsensusVote>),
 }
@@ -33,6 +39,7 @@ pub enum GossipsubBroadcastRequest {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum GossipsubMessage {
     NewTx(Transaction),
+    NewTxId(TxId),
     NewBlock(Block),
     ConsensusVote(ConsensusVote),
 }
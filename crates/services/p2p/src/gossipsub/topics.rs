@@ -11,8 +11,12 @@ use super::messages::{
 
 pub type GossipTopic = Sha256Topic;
 pub const NEW_TX_GOSSIP_TOPIC: &str = "new_tx";
+pub const NEW_TX_ID_GOSSIP_TOPIC: &str = "new_tx_id";
 pub const NEW_BLOCK_GOSSIP_TOPIC: &str = "new_block";
 pub const CON_VOTE_GOSSIP_TOPIC: &str = "consensus_vote";
+/// Nothing is published to this topic. The subscription to it advertises to the peers
+/// that the node is able to decode the compressed gossip messages.
+pub const GOSSIP_COMPRESSION_TOPIC: &str = "gossip_compression";
 
 /// Holds used Gossipsub Topics
 /// Each field contains TopicHash and GossipTopic itself
@@ -20,25 +24,38 @@ pub const CON_VOTE_GOSSIP_TOPIC: &str = "consensus_vote";
 #[derive(Debug)]
 pub struct GossipsubTopics {
     new_tx_topic: (TopicHash, GossipTopic),
+    new_tx_id_topic: (TopicHash, GossipTopic),
     new_block_topic: (TopicHash, GossipTopic),
     consensus_vote_topic: (TopicHash, GossipTopic),
+    compression_topic: TopicHash,
 }
 
 impl GossipsubTopics {
     pub fn new(network_name: &str) -> Self {
         let new_tx_topic = Topic::new(format!("{NEW_TX_GOSSIP_TOPIC}/{network_name}"));
+        let new_tx_id_topic =
+            Topic::new(format!("{NEW_TX_ID_GOSSIP_TOPIC}/{network_name}"));
         let new_block_topic =
             Topic::new(format!("{NEW_BLOCK_GOSSIP_TOPIC}/{network_name}"));
         let consensus_vote_topic =
             Topic::new(format!("{CON_VOTE_GOSSIP_TOPIC}/{network_name}"));
+        let compression_topic: GossipTopic =
+            Topic::new(format!("{GOSSIP_COMPRESSION_TOPIC}/{network_name}"));
 
         Self {
             new_tx_topic: (new_tx_topic.hash(), new_tx_topic),
+            new_tx_id_topic: (new_tx_id_topic.hash(), new_tx_id_topic),
             new_block_topic: (new_block_topic.hash(), new_block_topic),
             consensus_vote_topic: (consensus_vote_topic.hash(), consensus_vote_topic),
+            compression_topic: compression_topic.hash(),
         }
     }
 
+    /// Returns the `TopicHash` of the topic advertising the support of the compression
+    pub fn compression_topic(&self) -> &TopicHash {
+        &self.compression_topic
+    }
+
     /// Given a TopicHash it will return a matching GossipTopicTag
     pub fn get_gossipsub_tag(
         &self,
@@ -46,12 +63,15 @@ impl GossipsubTopics {
     ) -> Option<GossipTopicTag> {
         let GossipsubTopics {
             new_tx_topic,
+            new_tx_id_topic,
             new_block_topic,
             consensus_vote_topic,
+            ..
         } = &self;
 
         match incoming_topic {
             hash if hash == &new_tx_topic.0 => Some(GossipTopicTag::NewTx),
+            hash if hash == &new_tx_id_topic.0 => Some(GossipTopicTag::NewTxId),
             hash if hash == &new_block_topic.0 => Some(GossipTopicTag::NewBlock),
             hash if hash == &consensus_vote_topic.0 => {
                 Some(GossipTopicTag::ConsensusVote)
@@ -72,6 +92,7 @@ impl GossipsubTopics {
             }
             GossipsubBroadcastRequest::NewBlock(_) => self.new_block_topic.1.clone(),
             GossipsubBroadcastRequest::NewTx(_) => self.new_tx_topic.1.clone(),
+            GossipsubBroadcastRequest::NewTxId(_) => self.new_tx_id_topic.1.clone(),
         }
     }
 }
@@ -98,6 +119,8 @@ mod tests {
             Topic::new(format!("{NEW_BLOCK_GOSSIP_TOPIC}/{network_name}"));
         let consensus_vote_topic: GossipTopic =
             Topic::new(format!("{CON_VOTE_GOSSIP_TOPIC}/{network_name}"));
+        let compression_topic: GossipTopic =
+            Topic::new(format!("{GOSSIP_COMPRESSION_TOPIC}/{network_name}"));
 
         let gossipsub_topics = GossipsubTopics::new(network_name);
 
@@ -108,6 +131,10 @@ mod tests {
             gossipsub_topics.consensus_vote_topic.0,
             consensus_vote_topic.hash()
         );
+        assert_eq!(
+            gossipsub_topics.compression_topic(),
+            &compression_topic.hash()
+        );
 
         // Test given a TopicHash that `get_gossipsub_tag()` returns matching `GossipTopicTag`
         assert_eq!(
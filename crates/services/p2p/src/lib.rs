@@ -2,17 +2,21 @@ mod behavior;
 pub mod codecs;
 pub mod config;
 mod discovery;
+mod dns_seeds;
 mod gossipsub;
 mod heartbeat;
 mod p2p_service;
 mod peer_manager;
 mod peer_report;
+mod port_mapping;
 pub mod ports;
 mod request_response;
 pub mod service;
+mod tx_announcement;
 
 pub use gossipsub::config as gossipsub_config;
 pub use heartbeat::HeartbeatConfig;
+pub use peer_manager::PeerReputations;
 
 pub use libp2p::{
     multiaddr::Protocol,
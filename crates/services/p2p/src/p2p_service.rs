@@ -6,9 +6,11 @@ use crate::{
     codecs::NetworkCodec,
     config::{
         build_transport,
+        quic_address,
         Config,
     },
     gossipsub::{
+        compression,
         messages::{
             GossipsubBroadcastRequest,
             GossipsubMessage as FuelGossipsubMessage,
@@ -20,23 +22,38 @@ use crate::{
         Punisher,
     },
     peer_report::PeerReportEvent,
-    request_response::messages::{
-        NetworkResponse,
-        OutboundResponse,
-        RequestError,
-        RequestMessage,
-        ResponseChannelItem,
-        ResponseError,
-        ResponseMessage,
+    request_response::{
+        messages::{
+            NetworkResponse,
+            OutboundResponse,
+            RequestError,
+            RequestMessage,
+            ResponseChannelItem,
+            ResponseError,
+            ResponseMessage,
+            MAX_EXCHANGED_PEERS,
+        },
+        rate_limiter::InboundRequestLimiter,
     },
 };
-use fuel_core_metrics::p2p_metrics::P2P_METRICS;
+use fuel_core_metrics::p2p_metrics::{
+    p2p_label,
+    P2PLabels,
+    P2P_METRICS,
+};
 use fuel_core_types::{
     fuel_types::BlockHeight,
-    services::p2p::peer_reputation::AppScore,
+    services::p2p::peer_reputation::{
+        AppScore,
+        PeerProtocolReport,
+        PeerReport,
+        DEFAULT_APP_SCORE,
+    },
 };
 use futures::prelude::*;
 use libp2p::{
+    core::ConnectedPoint,
+    dcutr::behaviour::Event as DcutrEvent,
     gossipsub::{
         error::PublishError,
         GossipsubEvent,
@@ -45,7 +62,12 @@ use libp2p::{
         TopicHash,
     },
     multiaddr::Protocol,
+    relay::v2::client::{
+        Client as RelayClient,
+        Event as RelayClientEvent,
+    },
     request_response::{
+        OutboundFailure,
         RequestId,
         RequestResponseEvent,
         RequestResponseMessage,
@@ -54,6 +76,7 @@ use libp2p::{
     swarm::{
         AddressScore,
         ConnectionLimits,
+        DialError,
         SwarmBuilder,
         SwarmEvent,
     },
@@ -64,13 +87,18 @@ use libp2p::{
 use rand::seq::IteratorRandom;
 use std::{
     collections::HashMap,
-    time::Duration,
+    time::{
+        Duration,
+        Instant,
+    },
 };
 use tracing::{
     debug,
     warn,
 };
 
+const GOSSIPSUB_METRICS_LABEL: &str = "gossipsub";
+
 impl<Codec: NetworkCodec> Punisher for Swarm<FuelBehaviour<Codec>> {
     fn ban_peer(&mut self, peer_id: PeerId) {
         self.ban_peer_id(peer_id)
@@ -89,6 +117,15 @@ pub struct FuelP2PService<Codec: NetworkCodec> {
     /// The TCP port that Swarm listens on
     tcp_port: u16,
 
+    /// The UDP port that Swarm listens on with the QUIC transport
+    quic_port: Option<u16>,
+
+    /// Additional addresses that Swarm listens on with the TCP or QUIC transport
+    listen_addresses: Vec<Multiaddr>,
+
+    /// The relay nodes that Swarm listens on through the relayed circuits
+    relay_nodes: Vec<Multiaddr>,
+
     /// Swarm handler for FuelBehaviour
     swarm: Swarm<FuelBehaviour<Codec>>,
 
@@ -100,7 +137,11 @@ pub struct FuelP2PService<Codec: NetworkCodec> {
     /// Holds the ResponseChannel(s) for the inbound requests from the p2p Network
     /// Once the Response is prepared by the NetworkOrchestrator
     /// It will send it to the specified Peer via its unique ResponseChannel    
-    inbound_requests_table: HashMap<RequestId, ResponseChannel<NetworkResponse>>,
+    inbound_requests_table:
+        HashMap<RequestId, (PeerId, ResponseChannel<NetworkResponse>)>,
+
+    /// Limits the inbound requests of each peer
+    inbound_request_limiter: InboundRequestLimiter,
 
     /// NetworkCodec used as <GossipsubCodec> for encoding and decoding of Gossipsub messages    
     network_codec: Codec,
@@ -111,6 +152,9 @@ pub struct FuelP2PService<Codec: NetworkCodec> {
     /// Whether or not metrics collection is enabled
     metrics: bool,
 
+    /// Whether or not the known peers are exchanged with the connected peers
+    peer_exchange: bool,
+
     /// Holds peers' information, and manages existing connections
     peer_manager: PeerManager,
 }
@@ -118,11 +162,19 @@ pub struct FuelP2PService<Codec: NetworkCodec> {
 #[derive(Debug)]
 struct GossipsubData {
     topics: GossipsubTopics,
+    /// Whether or not the published messages are compressed
+    compression: bool,
+    /// The max size of the decompressed message
+    max_decompressed_size: usize,
 }
 
 impl GossipsubData {
-    pub fn with_topics(topics: GossipsubTopics) -> Self {
-        Self { topics }
+    pub fn new(config: &Config) -> Self {
+        Self {
+            topics: GossipsubTopics::new(&config.network_name),
+            compression: config.gossip_compression,
+            max_decompressed_size: config.max_block_size,
+        }
     }
 }
 
@@ -157,13 +209,14 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
     pub fn new(config: Config, codec: Codec) -> Self {
         let local_peer_id = PeerId::from(config.keypair.public());
 
-        let gossipsub_data =
-            GossipsubData::with_topics(GossipsubTopics::new(&config.network_name));
+        let gossipsub_data = GossipsubData::new(&config);
         let network_metadata = NetworkMetadata { gossipsub_data };
 
         // configure and build P2P Service
-        let (transport, connection_state) = build_transport(&config);
-        let behaviour = FuelBehaviour::new(&config, codec.clone());
+        let (relay_transport, relay_client) =
+            RelayClient::new_transport_and_behaviour(local_peer_id);
+        let (transport, connection_state) = build_transport(&config, relay_transport);
+        let behaviour = FuelBehaviour::new(&config, codec.clone(), relay_client);
 
         let total_connections = {
             // Reserved nodes do not count against the configured peer input/output limits.
@@ -201,6 +254,13 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
         let metrics = config.metrics;
 
         if let Some(public_address) = config.public_address {
+            // Advertise both transports to the peers
+            if let Some(quic_address) = config
+                .quic_port
+                .and_then(|quic_port| quic_address(&public_address, quic_port))
+            {
+                let _ = swarm.add_external_address(quic_address, AddressScore::Infinite);
+            }
             let _ = swarm.add_external_address(public_address, AddressScore::Infinite);
         }
 
@@ -214,12 +274,20 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
             local_peer_id,
             local_address: config.address,
             tcp_port: config.tcp_port,
+            quic_port: config.quic_port,
+            listen_addresses: config.listen_addresses,
+            relay_nodes: config.relay_nodes,
             swarm,
             network_codec: codec,
             outbound_requests_table: HashMap::default(),
             inbound_requests_table: HashMap::default(),
+            inbound_request_limiter: InboundRequestLimiter::new(
+                config.max_concurrent_requests_per_peer,
+                config.max_requests_per_second_per_peer,
+            ),
             network_metadata,
             metrics,
+            peer_exchange: config.peer_exchange,
             peer_manager: PeerManager::new(
                 reserved_peers,
                 connection_state,
@@ -244,6 +312,36 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
         // start listening at the given address
         self.swarm.listen_on(listen_multiaddr)?;
 
+        if let Some(quic_port) = self.quic_port {
+            let quic_multiaddr = {
+                let mut m = Multiaddr::from(self.local_address);
+                m.push(Protocol::Udp(quic_port));
+                m.push(Protocol::Quic);
+                m
+            };
+
+            tracing::info!("The p2p service listens for QUIC on the `{quic_multiaddr}`");
+
+            self.swarm.listen_on(quic_multiaddr)?;
+        }
+
+        for address in self.listen_addresses.iter() {
+            tracing::info!("The p2p service listens on the `{address}`");
+
+            self.swarm.listen_on(address.clone())?;
+        }
+
+        for relay in self.relay_nodes.iter() {
+            // The reservation on the relay is requested once the connection to it is established
+            let circuit_address = relay.clone().with(Protocol::P2pCircuit);
+
+            tracing::info!(
+                "The p2p service listens through the relay `{circuit_address}`"
+            );
+
+            self.swarm.listen_on(circuit_address)?;
+        }
+
         // Wait for listener addresses.
         tokio::time::timeout(Duration::from_secs(5), self.await_listeners_address())
             .await
@@ -283,17 +381,34 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
         &mut self,
         message: GossipsubBroadcastRequest,
     ) -> Result<MessageId, PublishError> {
-        let topic = self
-            .network_metadata
-            .gossipsub_data
-            .topics
-            .get_gossipsub_topic(&message);
+        let gossipsub_data = &self.network_metadata.gossipsub_data;
+        let topic = gossipsub_data.topics.get_gossipsub_topic(&message);
+
+        // The message is relayed as is, so it is compressed only if every peer
+        // subscribed to the topic is able to decode it
+        let compress = gossipsub_data.compression
+            && self.swarm.behaviour().topic_peers_subscribed_to(
+                &topic.hash(),
+                gossipsub_data.topics.compression_topic(),
+            );
 
         match self.network_codec.encode(message) {
-            Ok(encoded_data) => self
-                .swarm
-                .behaviour_mut()
-                .publish_message(topic, encoded_data),
+            Ok(encoded_data) => {
+                let encoded_data = if compress {
+                    compression::compress(encoded_data)
+                } else {
+                    encoded_data
+                };
+                if self.metrics {
+                    P2P_METRICS
+                        .bytes_sent
+                        .get_or_create(&p2p_label("protocol", GOSSIPSUB_METRICS_LABEL))
+                        .inc_by(encoded_data.len() as u64);
+                }
+                self.swarm
+                    .behaviour_mut()
+                    .publish_message(topic, encoded_data)
+            }
             Err(e) => Err(PublishError::TransformFailed(e)),
         }
     }
@@ -332,6 +447,67 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
         Ok(request_id)
     }
 
+    /// Asks the peer for a sample of its known peers
+    fn request_peers(&mut self, peer_id: PeerId) {
+        let request_id = self
+            .swarm
+            .behaviour_mut()
+            .send_request_msg(RequestMessage::Peers, &peer_id);
+
+        self.outbound_requests_table
+            .insert(request_id, ResponseChannelItem::PeerExchange);
+    }
+
+    /// Returns the addresses of a random sample of the connected peers with
+    /// a non-negative reputation, excluding the `requester`.
+    fn known_peers_sample(&self, requester: &PeerId) -> Vec<Multiaddr> {
+        let mut range = rand::thread_rng();
+        self.peer_manager
+            .get_all_peers()
+            .filter(|(peer_id, info)| {
+                *peer_id != requester && info.score >= DEFAULT_APP_SCORE
+            })
+            .filter_map(|(peer_id, info)| {
+                let address = info.peer_addresses.iter().next()?.clone();
+                match address.iter().last() {
+                    Some(Protocol::P2p(_)) => Some(address),
+                    _ => Some(address.with(Protocol::P2p((*peer_id).into()))),
+                }
+            })
+            .choose_multiple(&mut range, MAX_EXCHANGED_PEERS)
+    }
+
+    /// Adds the peers received from the peer exchange to the discovery,
+    /// and dials them while there are free slots.
+    fn handle_exchanged_peers(&mut self, addresses: Vec<Multiaddr>) {
+        for address in addresses.into_iter().take(MAX_EXCHANGED_PEERS) {
+            let peer_id = match address.iter().last() {
+                Some(Protocol::P2p(multihash)) => {
+                    match PeerId::from_multihash(multihash) {
+                        Ok(peer_id) => peer_id,
+                        Err(_) => continue,
+                    }
+                }
+                _ => continue,
+            };
+            if peer_id == self.local_peer_id
+                || self.swarm.is_connected(&peer_id)
+                || self.peer_manager.reputations().is_banned(&peer_id)
+            {
+                continue
+            }
+
+            self.swarm
+                .behaviour_mut()
+                .add_addresses_to_discovery(&peer_id, vec![address.clone()]);
+            if self.peer_manager.has_available_slot() {
+                if let Err(e) = self.swarm.dial(address.clone()) {
+                    debug!(target: "fuel-p2p", "Unable to dial the exchanged address {address}: {e}");
+                }
+            }
+        }
+    }
+
     /// Sends ResponseMessage to a peer that requested the data
     pub fn send_response_msg(
         &mut self,
@@ -342,7 +518,8 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
             self.network_codec.convert_to_network_response(&message),
             self.inbound_requests_table.remove(&request_id),
         ) {
-            (Ok(message), Some(channel)) => {
+            (Ok(message), Some((peer_id, channel))) => {
+                self.inbound_request_limiter.release(&peer_id);
                 if self
                     .swarm
                     .behaviour_mut()
@@ -414,6 +591,57 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
         );
     }
 
+    /// Adds the bootstrap addresses resolved from the DNS seeds. The addresses with
+    /// the peer id are added to the discovery, and all of them are dialed.
+    pub fn add_bootstrap_addresses(&mut self, addresses: Vec<Multiaddr>) {
+        for address in addresses {
+            if let Some(Protocol::P2p(multihash)) = address.iter().last() {
+                if let Ok(peer_id) = PeerId::from_multihash(multihash) {
+                    if peer_id == self.local_peer_id {
+                        continue
+                    }
+                    self.swarm
+                        .behaviour_mut()
+                        .add_addresses_to_discovery(&peer_id, vec![address.clone()]);
+                }
+            }
+            if let Err(e) = self.swarm.dial(address.clone()) {
+                debug!(target: "fuel-p2p", "Unable to dial the bootstrap address {address}: {e}");
+            }
+        }
+    }
+
+    /// Advertises the external addresses of the node to the peers,
+    /// e.g. the addresses mapped on the gateway of the local network.
+    pub fn add_external_addresses(&mut self, addresses: Vec<Multiaddr>) {
+        for address in addresses {
+            let _ = self
+                .swarm
+                .add_external_address(address, AddressScore::Infinite);
+        }
+    }
+
+    pub fn remove_external_addresses(&mut self, addresses: Vec<Multiaddr>) {
+        for address in addresses {
+            self.swarm.remove_external_address(&address);
+        }
+    }
+
+    /// Bans the peer and closes all connections with it
+    pub fn ban_peer(&mut self, peer_id: PeerId) {
+        self.peer_manager.ban_peer(peer_id, &mut self.swarm);
+    }
+
+    /// Closes all connections with the peer. Returns `false` if the peer is not connected.
+    pub fn disconnect_peer(&mut self, peer_id: PeerId) -> bool {
+        self.swarm.disconnect_peer_id(peer_id).is_ok()
+    }
+
+    /// Penalizes the peer for the misbehaviour detected by the p2p service itself
+    fn report_protocol_violation(&mut self, peer_id: PeerId, report: PeerProtocolReport) {
+        self.report_peer(peer_id, report.get_score_from_report(), "p2p");
+    }
+
     #[tracing::instrument(skip_all,
         level = "debug",
         fields(
@@ -445,6 +673,33 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
                 );
                 None
             }
+            SwarmEvent::ConnectionEstablished { endpoint, .. } => {
+                if self.metrics {
+                    P2P_METRICS
+                        .connections
+                        .get_or_create(&connection_direction_label(&endpoint))
+                        .inc();
+                }
+                None
+            }
+            SwarmEvent::ConnectionClosed { endpoint, .. } => {
+                if self.metrics {
+                    P2P_METRICS
+                        .connections
+                        .get_or_create(&connection_direction_label(&endpoint))
+                        .dec();
+                }
+                None
+            }
+            SwarmEvent::OutgoingConnectionError { error, .. } => {
+                if self.metrics {
+                    P2P_METRICS
+                        .dial_failures
+                        .get_or_create(&p2p_label("reason", dial_error_reason(&error)))
+                        .inc();
+                }
+                None
+            }
             _ => None,
         }
     }
@@ -463,13 +718,33 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
                 message,
                 message_id,
             }) => {
-                if let Some(correct_topic) = self
-                    .network_metadata
-                    .gossipsub_data
-                    .topics
-                    .get_gossipsub_tag(&message.topic)
+                if self.metrics {
+                    P2P_METRICS
+                        .bytes_received
+                        .get_or_create(&p2p_label("protocol", GOSSIPSUB_METRICS_LABEL))
+                        .inc_by(message.data.len() as u64);
+                }
+
+                let gossipsub_data = &self.network_metadata.gossipsub_data;
+                if let Some(correct_topic) =
+                    gossipsub_data.topics.get_gossipsub_tag(&message.topic)
                 {
-                    match self.network_codec.decode(&message.data, correct_topic) {
+                    // Peers without the compression send the uncompressed messages,
+                    // so the raw payload is decoded if it is not a compressed one
+                    let decoded = match compression::decompress(
+                        &message.data,
+                        gossipsub_data.max_decompressed_size,
+                    ) {
+                        Some(decompressed) => self
+                            .network_codec
+                            .decode(&decompressed, correct_topic)
+                            .or_else(|_| {
+                                self.network_codec.decode(&message.data, correct_topic)
+                            }),
+                        None => self.network_codec.decode(&message.data, correct_topic),
+                    };
+
+                    match decoded {
                         Ok(decoded_message) => {
                             return Some(FuelP2PEvent::GossipsubMessage {
                                 peer_id: propagation_source,
@@ -486,6 +761,10 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
                                 propagation_source,
                                 MessageAcceptance::Reject,
                             );
+                            self.report_protocol_violation(
+                                propagation_source,
+                                PeerProtocolReport::MalformedGossip,
+                            );
                         }
                     }
                 } else {
@@ -515,7 +794,18 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
                             .add_addresses_to_discovery(&peer_id, addresses);
                     }
                     PeerReportEvent::PerformDecay => {
-                        self.peer_manager.batch_update_score_with_decay()
+                        self.peer_manager.batch_update_score_with_decay();
+
+                        if self.metrics {
+                            for (topic, peers) in
+                                self.swarm.behaviour().mesh_peers_count()
+                            {
+                                P2P_METRICS
+                                    .gossip_mesh_peers
+                                    .get_or_create(&p2p_label("topic", topic.as_str()))
+                                    .set(peers as u64);
+                            }
+                        }
                     }
                     PeerReportEvent::CheckReservedNodesHealth => {
                         let disconnected_peers: Vec<_> = self
@@ -554,6 +844,9 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
                         ) {
                             let _ = self.swarm.disconnect_peer_id(peer_id);
                         } else if initial_connection {
+                            if self.peer_exchange {
+                                self.request_peers(peer_id);
+                            }
                             return Some(FuelP2PEvent::PeerConnected(peer_id))
                         }
                     }
@@ -572,7 +865,38 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
                         channel,
                         request_id,
                     } => {
-                        self.inbound_requests_table.insert(request_id, channel);
+                        if !self
+                            .inbound_request_limiter
+                            .try_acquire(&peer, Instant::now())
+                        {
+                            debug!(
+                                "Throttled the request {:?} of {:?}",
+                                request_id, peer
+                            );
+                            let _ = self
+                                .swarm
+                                .behaviour_mut()
+                                .send_response_msg(channel, NetworkResponse::Throttled);
+                            self.report_protocol_violation(
+                                peer,
+                                PeerProtocolReport::RequestLimitExceeded,
+                            );
+                            return None
+                        }
+
+                        self.inbound_requests_table
+                            .insert(request_id, (peer, channel));
+
+                        if let RequestMessage::Peers = request {
+                            let peers = self
+                                .peer_exchange
+                                .then(|| self.known_peers_sample(&peer));
+                            let _ = self.send_response_msg(
+                                request_id,
+                                OutboundResponse::Peers(peers),
+                            );
+                            return None
+                        }
 
                         return Some(FuelP2PEvent::RequestMessage {
                             request_id,
@@ -609,6 +933,17 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
                                     );
                                 }
                             }
+                            (
+                                Some(ResponseChannelItem::Transaction(channel)),
+                                Ok(ResponseMessage::Transaction(transaction)),
+                            ) => {
+                                if channel.send(transaction).is_err() {
+                                    debug!(
+                                        "Failed to send through the channel for {:?}",
+                                        request_id
+                                    );
+                                }
+                            }
                             (
                                 Some(ResponseChannelItem::SealedHeader(channel)),
                                 Ok(ResponseMessage::SealedHeader(header)),
@@ -620,9 +955,37 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
                                     );
                                 }
                             }
+                            (
+                                Some(ResponseChannelItem::SealedHeaders(channel)),
+                                Ok(ResponseMessage::SealedHeaders(headers)),
+                            ) => {
+                                if channel.send(headers.map(|h| (peer, h))).is_err() {
+                                    debug!(
+                                        "Failed to send through the channel for {:?}",
+                                        request_id
+                                    );
+                                }
+                            }
 
+                            (
+                                Some(ResponseChannelItem::PeerExchange),
+                                Ok(ResponseMessage::Peers(peers)),
+                            ) => {
+                                self.handle_exchanged_peers(peers.unwrap_or_default());
+                            }
+                            (Some(_), Ok(ResponseMessage::Throttled)) => {
+                                // Dropping the channel notifies the requester about the failure
+                                debug!(
+                                    "The request {:?} was throttled by {:?}",
+                                    request_id, peer
+                                );
+                            }
                             (Some(_), Err(e)) => {
                                 debug!("Failed to convert IntermediateResponse into a ResponseMessage {:?} with {:?}", response, e);
+                                self.report_protocol_violation(
+                                    peer,
+                                    PeerProtocolReport::InvalidResponse,
+                                );
                             }
                             (None, Ok(_)) => {
                                 debug!("Send channel not found for {:?}", request_id);
@@ -637,6 +1000,10 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
                     request_id,
                 } => {
                     debug!("RequestResponse inbound error for peer: {:?} with id: {:?} and error: {:?}", peer, request_id, error);
+
+                    if self.inbound_requests_table.remove(&request_id).is_some() {
+                        self.inbound_request_limiter.release(&peer);
+                    }
                 }
                 RequestResponseEvent::OutboundFailure {
                     peer,
@@ -646,6 +1013,42 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
                     debug!("RequestResponse outbound error for peer: {:?} with id: {:?} and error: {:?}", peer, request_id, error);
 
                     let _ = self.outbound_requests_table.remove(&request_id);
+
+                    if matches!(error, OutboundFailure::Timeout) {
+                        self.report_protocol_violation(
+                            peer,
+                            PeerProtocolReport::RequestTimeout,
+                        );
+                    }
+                }
+                _ => {}
+            },
+
+            FuelBehaviourEvent::Relay(relay_event) => match relay_event {
+                RelayClientEvent::ReservationReqAccepted { relay_peer_id, .. } => {
+                    debug!(target: "fuel-p2p", "The relay {:?} reserved the circuit", relay_peer_id);
+                }
+                RelayClientEvent::ReservationReqFailed {
+                    relay_peer_id,
+                    error,
+                    ..
+                } => {
+                    warn!(target: "fuel-p2p", "The relay {:?} failed to reserve the circuit with error: {:?}", relay_peer_id, error);
+                }
+                event => {
+                    debug!(target: "fuel-p2p", "Relay event: {:?}", event);
+                }
+            },
+
+            FuelBehaviourEvent::Dcutr(dcutr_event) => match dcutr_event {
+                DcutrEvent::DirectConnectionUpgradeSucceeded { remote_peer_id } => {
+                    debug!(target: "fuel-p2p", "The relayed connection with {:?} is upgraded to the direct one", remote_peer_id);
+                }
+                DcutrEvent::DirectConnectionUpgradeFailed {
+                    remote_peer_id,
+                    error,
+                } => {
+                    debug!(target: "fuel-p2p", "The hole punching with {:?} failed with error: {:?}", remote_peer_id, error);
                 }
                 _ => {}
             },
@@ -657,6 +1060,30 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
     }
 }
 
+fn connection_direction_label(endpoint: &ConnectedPoint) -> P2PLabels {
+    let direction = if endpoint.is_dialer() {
+        "outbound"
+    } else {
+        "inbound"
+    };
+    p2p_label("direction", direction)
+}
+
+fn dial_error_reason(error: &DialError) -> &'static str {
+    match error {
+        DialError::Banned { .. } => "banned",
+        DialError::ConnectionLimit { .. } => "connection_limit",
+        DialError::LocalPeerId { .. } => "local_peer_id",
+        DialError::NoAddresses { .. } => "no_addresses",
+        DialError::DialPeerConditionFalse { .. } => "dial_condition_false",
+        DialError::Aborted { .. } => "aborted",
+        DialError::InvalidPeerId { .. } => "invalid_peer_id",
+        DialError::WrongPeerId { .. } => "wrong_peer_id",
+        DialError::ConnectionIo { .. } => "connection_io",
+        DialError::Transport { .. } => "transport",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::FuelP2PService;
@@ -673,6 +1100,7 @@ mod tests {
                 CON_VOTE_GOSSIP_TOPIC,
                 NEW_BLOCK_GOSSIP_TOPIC,
                 NEW_TX_GOSSIP_TOPIC,
+                NEW_TX_ID_GOSSIP_TOPIC,
             },
         },
         p2p_service::FuelP2PEvent,
@@ -700,6 +1128,7 @@ mod tests {
         fuel_tx::{
             Transaction,
             TransactionBuilder,
+            TxId,
         },
         services::p2p::GossipsubMessageAcceptance,
     };
@@ -713,6 +1142,7 @@ mod tests {
             Topic,
         },
         identity::Keypair,
+        multiaddr::Protocol,
         swarm::SwarmEvent,
         Multiaddr,
         PeerId,
@@ -1145,6 +1575,61 @@ mod tests {
         }
     }
 
+    // Simulates 2 p2p nodes with enabled QUIC transport
+    // Node B is bootstrapped only with the QUIC address of Node A
+    #[tokio::test]
+    #[instrument]
+    async fn nodes_connected_via_quic() {
+        let mut p2p_config = Config::default_initialized("nodes_connected_via_quic");
+        p2p_config.quic_port = Some(0);
+
+        nodes_connect_via_quic(p2p_config).await;
+    }
+
+    // The QUIC transport is enabled by the QUIC listen address
+    #[tokio::test]
+    #[instrument]
+    async fn nodes_connected_via_quic_listen_address() {
+        let mut p2p_config =
+            Config::default_initialized("nodes_connected_via_quic_listen_address");
+        p2p_config.listen_addresses = vec!["/ip4/127.0.0.1/udp/0/quic".parse().unwrap()];
+
+        nodes_connect_via_quic(p2p_config).await;
+    }
+
+    async fn nodes_connect_via_quic(mut p2p_config: Config) {
+        use libp2p::multiaddr::Protocol;
+
+        // Node A
+        let mut node_a = build_service_from_config(p2p_config.clone()).await;
+
+        let is_quic = |address: &Multiaddr| address.iter().any(|p| p == Protocol::Quic);
+        while !node_a.multiaddrs().iter().any(is_quic) {
+            node_a.next_event().await;
+        }
+
+        // Node B
+        p2p_config.bootstrap_nodes =
+            node_a.multiaddrs().into_iter().filter(is_quic).collect();
+        let mut node_b = build_service_from_config(p2p_config).await;
+
+        loop {
+            tokio::select! {
+                node_a_event = node_a.next_event() => {
+                    tracing::info!("Node A Event: {:?}", node_a_event);
+                },
+                node_b_event = node_b.next_event() => {
+                    if let Some(FuelP2PEvent::PeerConnected(peer_id)) = node_b_event {
+                        assert_eq!(peer_id, node_a.local_peer_id);
+                        break
+                    }
+
+                    tracing::info!("Node B Event: {:?}", node_b_event);
+                }
+            };
+        }
+    }
+
     // Simulates 2 p2p nodes that connect to each other and consequently exchange Peer Info
     // On sucessful connection, node B updates its latest BlockHeight
     // and shares it with Peer A via Heartbeat protocol
@@ -1211,6 +1696,16 @@ mod tests {
         .await;
     }
 
+    #[tokio::test]
+    #[instrument]
+    async fn gossipsub_broadcast_tx_id_with_accept() {
+        gossipsub_broadcast(
+            GossipsubBroadcastRequest::NewTxId(TxId::default()),
+            GossipsubMessageAcceptance::Accept,
+        )
+        .await;
+    }
+
     #[tokio::test]
     #[instrument]
     async fn gossipsub_broadcast_vote_with_accept() {
@@ -1405,6 +1900,7 @@ mod tests {
                 GossipsubBroadcastRequest::ConsensusVote(_) => CON_VOTE_GOSSIP_TOPIC,
                 GossipsubBroadcastRequest::NewBlock(_) => NEW_BLOCK_GOSSIP_TOPIC,
                 GossipsubBroadcastRequest::NewTx(_) => NEW_TX_GOSSIP_TOPIC,
+                GossipsubBroadcastRequest::NewTxId(_) => NEW_TX_ID_GOSSIP_TOPIC,
             };
 
             Topic::new(format!("{}/{}", topic, p2p_config.network_name))
@@ -1463,6 +1959,12 @@ mod tests {
                                     panic!("Wrong GossipsubMessage")
                                 }
                             }
+                            GossipsubMessage::NewTxId(tx_id) => {
+                                if tx_id != &TxId::default() {
+                                    tracing::error!("Wrong p2p message {:?}", message);
+                                    panic!("Wrong GossipsubMessage")
+                                }
+                            }
                             GossipsubMessage::NewBlock(block) => {
                                 if block.header().height() != Block::<Transaction>::default().header().height() {
                                     tracing::error!("Wrong p2p message {:?}", message);
@@ -1576,6 +2078,22 @@ mod tests {
                                             }
                                         });
                                     }
+                                    RequestMessage::SealedHeaders { .. } => {
+                                        let (tx_orchestrator, rx_orchestrator) = oneshot::channel();
+                                        assert!(node_a.send_request_msg(None, request_msg, ResponseChannelItem::SealedHeaders(tx_orchestrator)).is_ok());
+                                        let tx_test_end = tx_test_end.clone();
+
+                                        tokio::spawn(async move {
+                                            let response_message = rx_orchestrator.await;
+
+                                            if let Ok(Some((_, headers))) = response_message {
+                                                let _ = tx_test_end.send(headers.len() == 5).await;
+                                            } else {
+                                                tracing::error!("Orchestrator failed to receive a message: {:?}", response_message);
+                                                let _ = tx_test_end.send(false).await;
+                                            }
+                                        });
+                                    }
                                     RequestMessage::Transactions(_) => {
                                         let (tx_orchestrator, rx_orchestrator) = oneshot::channel();
                                         assert!(node_a.send_request_msg(None, request_msg, ResponseChannelItem::Transactions(tx_orchestrator)).is_ok());
@@ -1592,6 +2110,23 @@ mod tests {
                                             }
                                         });
                                     }
+                                    RequestMessage::Transaction(_) => {
+                                        let (tx_orchestrator, rx_orchestrator) = oneshot::channel();
+                                        assert!(node_a.send_request_msg(None, request_msg, ResponseChannelItem::Transaction(tx_orchestrator)).is_ok());
+                                        let tx_test_end = tx_test_end.clone();
+
+                                        tokio::spawn(async move {
+                                            let response_message = rx_orchestrator.await;
+
+                                            if let Ok(Some(transaction)) = response_message {
+                                                let _ = tx_test_end.send(transaction == Transaction::default_test_tx()).await;
+                                            } else {
+                                                tracing::error!("Orchestrator failed to receive a message: {:?}", response_message);
+                                                let _ = tx_test_end.send(false).await;
+                                            }
+                                        });
+                                    }
+                                    RequestMessage::Peers => unreachable!("The peer exchange is handled by the service"),
                                 }
                             }
                         }
@@ -1623,10 +2158,23 @@ mod tests {
 
                                 let _ = node_b.send_response_msg(request_id, OutboundResponse::SealedHeader(Some(Arc::new(sealed_header))));
                             }
+                            RequestMessage::SealedHeaders { .. } => {
+                                let headers = (0..5).map(|_| SealedBlockHeader {
+                                    entity: Default::default(),
+                                    consensus: Consensus::PoA(PoAConsensus::new(Default::default())),
+                                }).collect();
+
+                                let _ = node_b.send_response_msg(request_id, OutboundResponse::SealedHeaders(Some(Arc::new(headers))));
+                            }
                             RequestMessage::Transactions(_) => {
                                 let transactions = (0..5).map(|_| Transaction::default_test_tx()).collect();
                                 let _ = node_b.send_response_msg(request_id, OutboundResponse::Transactions(Some(Arc::new(transactions))));
                             }
+                            RequestMessage::Transaction(_) => {
+                                let transaction = Transaction::default_test_tx();
+                                let _ = node_b.send_response_msg(request_id, OutboundResponse::Transaction(Some(Arc::new(transaction))));
+                            }
+                            RequestMessage::Peers => unreachable!("The peer exchange is handled by the service"),
                         }
 
                     }
@@ -1644,6 +2192,12 @@ mod tests {
             .await
     }
 
+    #[tokio::test]
+    #[instrument]
+    async fn request_response_works_with_transaction() {
+        request_response_works_with(RequestMessage::Transaction(TxId::default())).await
+    }
+
     #[tokio::test]
     #[instrument]
     async fn request_response_works_with_block() {
@@ -1656,6 +2210,36 @@ mod tests {
         request_response_works_with(RequestMessage::SealedHeader(0.into())).await
     }
 
+    #[tokio::test]
+    #[instrument]
+    async fn request_response_works_with_sealed_headers() {
+        request_response_works_with(RequestMessage::SealedHeaders {
+            start: 0.into(),
+            end: 5.into(),
+        })
+        .await
+    }
+
+    #[tokio::test]
+    #[instrument]
+    async fn known_peers_sample_excludes_requester() {
+        let p2p_config = Config::default_initialized("known_peers_sample");
+        let mut node = build_service_from_config(p2p_config).await;
+
+        let requester = PeerId::random();
+        let peer = PeerId::random();
+        let address: Multiaddr = "/ip4/1.2.3.4/tcp/30333".parse().unwrap();
+        node.peer_manager
+            .handle_peer_connected(&requester, vec![address.clone()], true);
+        node.peer_manager
+            .handle_peer_connected(&peer, vec![address.clone()], true);
+
+        assert_eq!(
+            node.known_peers_sample(&requester),
+            vec![address.with(Protocol::P2p(peer.into()))]
+        );
+    }
+
     #[tokio::test]
     #[instrument]
     async fn req_res_outbound_timeout_works() {
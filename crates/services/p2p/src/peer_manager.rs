@@ -35,6 +35,9 @@ use crate::gossipsub_config::GRAYLIST_THRESHOLD;
 /// At this point we better just ban the peer
 const MIN_GOSSIPSUB_SCORE_BEFORE_BAN: AppScore = GRAYLIST_THRESHOLD;
 
+/// The score of the disconnected peer closer to the default is forgotten
+const MIN_SIGNIFICANT_SCORE: AppScore = 1.0;
+
 // Info about a single Peer that we're connected to
 #[derive(Debug, Clone)]
 pub struct PeerInfo {
@@ -42,12 +45,14 @@ pub struct PeerInfo {
     pub client_version: Option<String>,
     pub heartbeat_data: HeartbeatData,
     pub score: AppScore,
+    pub connected_at: Instant,
 }
 
 impl Default for PeerInfo {
     fn default() -> Self {
         Self {
             score: DEFAULT_APP_SCORE,
+            connected_at: Instant::now(),
             client_version: Default::default(),
             heartbeat_data: Default::default(),
             peer_addresses: Default::default(),
@@ -71,6 +76,7 @@ pub struct PeerManager {
     connection_state: Arc<RwLock<ConnectionState>>,
     max_non_reserved_peers: usize,
     reserved_peers_updates: tokio::sync::broadcast::Sender<usize>,
+    reputations: PeerReputations,
 }
 
 impl PeerManager {
@@ -90,6 +96,7 @@ impl PeerManager {
             connection_state,
             max_non_reserved_peers,
             reserved_peers_updates,
+            reputations: PeerReputations::default(),
         }
     }
 
@@ -97,6 +104,10 @@ impl PeerManager {
         self.reserved_peers_updates.clone()
     }
 
+    pub fn reputations(&self) -> PeerReputations {
+        self.reputations.clone()
+    }
+
     pub fn handle_gossip_score_update<T: Punisher>(
         &self,
         peer_id: PeerId,
@@ -106,6 +117,7 @@ impl PeerManager {
         if gossip_score < self.score_config.min_gossip_score_allowed
             && !self.reserved_peers.contains(&peer_id)
         {
+            self.reputations.ban(peer_id);
             punisher.ban_peer(peer_id);
         }
     }
@@ -156,6 +168,8 @@ impl PeerManager {
         for peer_info in self.non_reserved_connected_peers.values_mut() {
             peer_info.score *= DECAY_APP_SCORE;
         }
+        self.reputations
+            .decay(|peer_id| self.non_reserved_connected_peers.contains_key(peer_id));
     }
 
     pub fn update_app_score<T: Punisher>(
@@ -169,10 +183,12 @@ impl PeerManager {
             // score should not go over `max_score`
             let new_score = self.score_config.max_app_score.min(peer.score + score);
             peer.score = new_score;
+            self.reputations.set_score(peer_id, new_score);
 
             info!(target: "fuel-p2p", "{reporting_service} updated {peer_id} with new score {score}");
 
             if new_score < self.score_config.min_app_score_allowed {
+                self.reputations.ban(peer_id);
                 punisher.ban_peer(peer_id);
             }
         } else {
@@ -180,16 +196,34 @@ impl PeerManager {
         }
     }
 
+    /// Bans the peer regardless of its score, e.g. on the request of the node operator
+    pub fn ban_peer<T: Punisher>(&self, peer_id: PeerId, punisher: &mut T) {
+        info!(target: "fuel-p2p", "Banning {peer_id} on request");
+        self.reputations.ban(peer_id);
+        punisher.ban_peer(peer_id);
+    }
+
     pub fn total_peers_connected(&self) -> usize {
         self.reserved_connected_peers.len() + self.non_reserved_connected_peers.len()
     }
 
+    /// Returns `true` if more non-reserved peers can be connected
+    pub fn has_available_slot(&self) -> bool {
+        self.non_reserved_connected_peers.len() < self.max_non_reserved_peers
+    }
+
     pub fn get_peers_ids(&self) -> impl Iterator<Item = &PeerId> {
         self.non_reserved_connected_peers
             .keys()
             .chain(self.reserved_connected_peers.keys())
     }
 
+    pub fn get_all_peers(&self) -> impl Iterator<Item = (&PeerId, &PeerInfo)> {
+        self.non_reserved_connected_peers
+            .iter()
+            .chain(self.reserved_connected_peers.iter())
+    }
+
     pub fn get_peer_info(&self, peer_id: &PeerId) -> Option<&PeerInfo> {
         if self.reserved_peers.contains(peer_id) {
             return self.reserved_connected_peers.get(peer_id)
@@ -271,8 +305,13 @@ impl PeerManager {
                 }
             }
 
+            // the peer reconnecting doesn't reset its score
+            let peer_info = PeerInfo {
+                score: self.reputations.score(peer_id).unwrap_or(DEFAULT_APP_SCORE),
+                ..Default::default()
+            };
             self.non_reserved_connected_peers
-                .insert(*peer_id, PeerInfo::default());
+                .insert(*peer_id, peer_info);
         } else {
             self.reserved_connected_peers
                 .insert(*peer_id, PeerInfo::default());
@@ -311,6 +350,61 @@ impl PeerManager {
     }
 }
 
+/// The scores of the peers shared with other services.
+///
+/// Unlike the `PeerInfo`, the score outlives the connection, so the peer can't reset
+/// it by reconnecting. The scores of the disconnected peers are forgotten once they
+/// decay to the default.
+#[derive(Debug, Default, Clone)]
+pub struct PeerReputations(Arc<RwLock<Reputations>>);
+
+#[derive(Debug, Default)]
+struct Reputations {
+    scores: HashMap<PeerId, AppScore>,
+    banned: HashSet<PeerId>,
+}
+
+impl PeerReputations {
+    /// Returns the score of the peer, if it was reported.
+    pub fn score(&self, peer_id: &PeerId) -> Option<AppScore> {
+        self.0
+            .read()
+            .ok()
+            .and_then(|reputations| reputations.scores.get(peer_id).copied())
+    }
+
+    /// Returns `true` if the peer was banned due to the low score.
+    pub fn is_banned(&self, peer_id: &PeerId) -> bool {
+        self.0
+            .read()
+            .map_or(false, |reputations| reputations.banned.contains(peer_id))
+    }
+
+    fn set_score(&self, peer_id: PeerId, score: AppScore) {
+        if let Ok(mut reputations) = self.0.write() {
+            reputations.scores.insert(peer_id, score);
+        }
+    }
+
+    fn ban(&self, peer_id: PeerId) {
+        if let Ok(mut reputations) = self.0.write() {
+            reputations.banned.insert(peer_id);
+        }
+    }
+
+    /// Decays the scores of all peers and forgets the insignificant scores
+    /// of the peers that are not `connected`.
+    fn decay(&self, connected: impl Fn(&PeerId) -> bool) {
+        if let Ok(mut reputations) = self.0.write() {
+            reputations.scores.retain(|peer_id, score| {
+                *score *= DECAY_APP_SCORE;
+                connected(peer_id)
+                    || (*score - DEFAULT_APP_SCORE).abs() >= MIN_SIGNIFICANT_SCORE
+            });
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy)]
 pub struct ConnectionState {
     peers_allowed: bool,
@@ -445,6 +539,68 @@ mod tests {
         )
     }
 
+    struct FakePunisher(Vec<PeerId>);
+
+    impl Punisher for FakePunisher {
+        fn ban_peer(&mut self, peer_id: PeerId) {
+            self.0.push(peer_id);
+        }
+    }
+
+    #[test]
+    fn score_is_kept_after_reconnect() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let peer_id = PeerId::random();
+        let mut punisher = FakePunisher(vec![]);
+        peer_manager.handle_initial_connection(&peer_id, vec![]);
+
+        peer_manager.update_app_score(peer_id, -20.0, "test", &mut punisher);
+        peer_manager.handle_peer_disconnect(peer_id);
+        peer_manager.handle_initial_connection(&peer_id, vec![]);
+
+        let score = peer_manager.get_peer_info(&peer_id).unwrap().score;
+        assert_eq!(score, -20.0);
+        assert_eq!(peer_manager.reputations().score(&peer_id), Some(-20.0));
+        assert!(punisher.0.is_empty());
+    }
+
+    #[test]
+    fn peer_below_threshold_is_banned() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let peer_id = PeerId::random();
+        let mut punisher = FakePunisher(vec![]);
+        peer_manager.handle_initial_connection(&peer_id, vec![]);
+
+        peer_manager.update_app_score(
+            peer_id,
+            MIN_APP_SCORE - 1.0,
+            "test",
+            &mut punisher,
+        );
+
+        assert_eq!(punisher.0, vec![peer_id]);
+        assert!(peer_manager.reputations().is_banned(&peer_id));
+    }
+
+    #[test]
+    fn insignificant_scores_of_disconnected_peers_are_forgotten() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let connected = PeerId::random();
+        let disconnected = PeerId::random();
+        let mut punisher = FakePunisher(vec![]);
+        for peer_id in [connected, disconnected] {
+            peer_manager.handle_initial_connection(&peer_id, vec![]);
+            peer_manager.update_app_score(peer_id, 1.0, "test", &mut punisher);
+        }
+        peer_manager.handle_peer_disconnect(disconnected);
+
+        peer_manager.batch_update_score_with_decay();
+
+        let reputations = peer_manager.reputations();
+        assert_eq!(reputations.score(&connected), Some(DECAY_APP_SCORE));
+        assert_eq!(reputations.score(&disconnected), None);
+    }
+
     #[test]
     fn only_allowed_number_of_non_reserved_peers_is_connected() {
         let max_non_reserved_peers = 5;
@@ -0,0 +1,288 @@
+use fuel_core_services::stream::BoxStream;
+use igd_next::{
+    aio::{
+        tokio::{
+            search_gateway,
+            Tokio,
+        },
+        Gateway as UpnpGateway,
+    },
+    PortMappingProtocol,
+    SearchOptions,
+};
+use libp2p::{
+    multiaddr::Protocol,
+    Multiaddr,
+};
+use std::{
+    net::{
+        IpAddr,
+        Ipv4Addr,
+        Ipv6Addr,
+        SocketAddr,
+        UdpSocket,
+    },
+    time::Duration,
+};
+
+mod nat_pmp;
+
+/// The lease duration of the port mappings.
+const LEASE_DURATION: Duration = Duration::from_secs(60 * 60);
+/// The port mappings are renewed before the lease expires.
+const RENEWAL_INTERVAL: Duration = Duration::from_secs(30 * 60);
+/// The description of the port mappings on the gateway.
+const MAPPING_DESCRIPTION: &str = "fuel-core p2p";
+
+/// The gateway of the local network that maps the ports.
+#[derive(Clone, Debug)]
+enum Gateway {
+    Upnp(UpnpGateway<Tokio>),
+    NatPmp(SocketAddr),
+}
+
+#[derive(Clone, Copy, Debug)]
+struct MappedPort {
+    protocol: PortMappingProtocol,
+    internal_port: u16,
+    external_port: u16,
+}
+
+/// The ports mapped on the gateway of the local network.
+#[derive(Clone, Debug)]
+pub(crate) struct PortMapping {
+    gateway: Gateway,
+    local_address: IpAddr,
+    ports: Vec<MappedPort>,
+    /// The external addresses of the mapped ports
+    pub addresses: Vec<Multiaddr>,
+}
+
+impl PortMapping {
+    /// Removes the mappings of the ports from the gateway.
+    pub async fn remove(&self) -> anyhow::Result<()> {
+        for port in self.ports.iter() {
+            match &self.gateway {
+                Gateway::Upnp(gateway) => {
+                    gateway
+                        .remove_port(port.protocol, port.external_port)
+                        .await?
+                }
+                Gateway::NatPmp(gateway) => {
+                    nat_pmp::map_port(
+                        self.local_address,
+                        *gateway,
+                        port.protocol,
+                        port.internal_port,
+                        Duration::ZERO,
+                    )
+                    .await?;
+                }
+            }
+        }
+        tracing::info!(
+            "Removed the mappings of the p2p ports: {:?}",
+            self.addresses
+        );
+        Ok(())
+    }
+}
+
+/// Returns the stream of the port mappings on the gateway of the local network.
+/// The UPnP IGD gateway is preferred, the NAT-PMP gateway is used if it is not found.
+/// The ports are mapped at startup and renewed after each `RENEWAL_INTERVAL`.
+/// If the gateway is not found, it is searched again after the same interval.
+pub(crate) fn port_mapping_stream(
+    enabled: bool,
+    local_address: IpAddr,
+    tcp_port: u16,
+    quic_port: Option<u16>,
+) -> BoxStream<PortMapping> {
+    if !enabled {
+        return Box::pin(fuel_core_services::stream::pending())
+    }
+
+    Box::pin(fuel_core_services::stream::unfold(
+        true,
+        move |mut first| async move {
+            loop {
+                if !first {
+                    tokio::time::sleep(RENEWAL_INTERVAL).await;
+                }
+                first = false;
+
+                match map_ports_upnp(local_address, tcp_port, quic_port).await {
+                    Ok(mapping) => return Some((mapping, false)),
+                    Err(e) => {
+                        tracing::debug!("Unable to map the p2p ports with UPnP: {e}")
+                    }
+                }
+
+                match map_ports_nat_pmp(local_address, tcp_port, quic_port).await {
+                    Ok(mapping) => return Some((mapping, false)),
+                    Err(e) => {
+                        tracing::debug!("Unable to map the p2p ports with NAT-PMP: {e}")
+                    }
+                }
+            }
+        },
+    ))
+}
+
+/// Maps the ports on the UPnP IGD gateway.
+async fn map_ports_upnp(
+    local_address: IpAddr,
+    tcp_port: u16,
+    quic_port: Option<u16>,
+) -> anyhow::Result<PortMapping> {
+    let gateway = search_gateway(SearchOptions::default()).await?;
+    let local_ip = if local_address.is_unspecified() {
+        local_ip_towards(gateway.addr)?
+    } else {
+        local_address
+    };
+    let external_ip = gateway.get_external_ip().await?;
+    let lease_duration = LEASE_DURATION.as_secs() as u32;
+
+    let mut ports = vec![];
+    for (protocol, port) in mapped_protocols(tcp_port, quic_port) {
+        gateway
+            .add_port(
+                protocol,
+                port,
+                SocketAddr::new(local_ip, port),
+                lease_duration,
+                MAPPING_DESCRIPTION,
+            )
+            .await?;
+        ports.push(MappedPort {
+            protocol,
+            internal_port: port,
+            external_port: port,
+        });
+    }
+
+    let addresses = external_addresses(external_ip, &ports);
+    tracing::info!("Mapped the p2p ports with UPnP: {addresses:?}");
+    Ok(PortMapping {
+        gateway: Gateway::Upnp(gateway),
+        local_address: local_ip,
+        ports,
+        addresses,
+    })
+}
+
+/// Maps the ports on the NAT-PMP gateway. The gateway may choose
+/// the external ports different from the local ones.
+async fn map_ports_nat_pmp(
+    local_address: IpAddr,
+    tcp_port: u16,
+    quic_port: Option<u16>,
+) -> anyhow::Result<PortMapping> {
+    let gateway = nat_pmp::gateway_address()?;
+    let external_ip = nat_pmp::external_address(local_address, gateway).await?;
+
+    let mut ports = vec![];
+    for (protocol, port) in mapped_protocols(tcp_port, quic_port) {
+        let external_port =
+            nat_pmp::map_port(local_address, gateway, protocol, port, LEASE_DURATION)
+                .await?;
+        ports.push(MappedPort {
+            protocol,
+            internal_port: port,
+            external_port,
+        });
+    }
+
+    let addresses = external_addresses(IpAddr::V4(external_ip), &ports);
+    tracing::info!("Mapped the p2p ports with NAT-PMP: {addresses:?}");
+    Ok(PortMapping {
+        gateway: Gateway::NatPmp(gateway),
+        local_address,
+        ports,
+        addresses,
+    })
+}
+
+/// The TCP port is always mapped, the UDP port only if QUIC is enabled.
+fn mapped_protocols(
+    tcp_port: u16,
+    quic_port: Option<u16>,
+) -> impl Iterator<Item = (PortMappingProtocol, u16)> {
+    std::iter::once((PortMappingProtocol::TCP, tcp_port))
+        .chain(quic_port.map(|port| (PortMappingProtocol::UDP, port)))
+}
+
+/// Returns the IP of the local interface used to reach the `gateway`.
+/// No packets are sent, connecting the UDP socket only selects the route.
+fn local_ip_towards(gateway: SocketAddr) -> std::io::Result<IpAddr> {
+    let unspecified = match gateway {
+        SocketAddr::V4(_) => IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+        SocketAddr::V6(_) => IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+    };
+    let socket = UdpSocket::bind(SocketAddr::new(unspecified, 0))?;
+    socket.connect(gateway)?;
+    Ok(socket.local_addr()?.ip())
+}
+
+/// Returns the addresses of the mapped ports on the `ip`.
+/// The UDP port is served by the QUIC transport.
+fn external_addresses(ip: IpAddr, ports: &[MappedPort]) -> Vec<Multiaddr> {
+    ports
+        .iter()
+        .map(|port| match port.protocol {
+            PortMappingProtocol::TCP => {
+                Multiaddr::from(ip).with(Protocol::Tcp(port.external_port))
+            }
+            PortMappingProtocol::UDP => Multiaddr::from(ip)
+                .with(Protocol::Udp(port.external_port))
+                .with(Protocol::Quic),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn external_addresses_are_built_from_the_gateway_ip() {
+        let ip = IpAddr::V4(Ipv4Addr::new(1, 2, 3, 4));
+        let ports = [
+            MappedPort {
+                protocol: PortMappingProtocol::TCP,
+                internal_port: 30333,
+                external_port: 30333,
+            },
+            // The NAT-PMP gateway may choose another external port
+            MappedPort {
+                protocol: PortMappingProtocol::UDP,
+                internal_port: 30334,
+                external_port: 40334,
+            },
+        ];
+
+        assert_eq!(
+            external_addresses(ip, &ports),
+            vec![
+                "/ip4/1.2.3.4/tcp/30333".parse::<Multiaddr>().unwrap(),
+                "/ip4/1.2.3.4/udp/40334/quic".parse().unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn udp_port_is_mapped_only_with_quic() {
+        assert_eq!(
+            mapped_protocols(30333, None).collect::<Vec<_>>(),
+            vec![(PortMappingProtocol::TCP, 30333)]
+        );
+        assert_eq!(
+            mapped_protocols(30333, Some(30334)).collect::<Vec<_>>(),
+            vec![
+                (PortMappingProtocol::TCP, 30333),
+                (PortMappingProtocol::UDP, 30334)
+            ]
+        );
+    }
+}
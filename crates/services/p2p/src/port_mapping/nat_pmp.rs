@@ -0,0 +1,250 @@
+//! The client of the NAT Port Mapping Protocol([RFC 6886](https://www.rfc-editor.org/rfc/rfc6886)).
+//! It is used when the UPnP IGD gateway is not found in the local network.
+
+use anyhow::anyhow;
+use igd_next::PortMappingProtocol;
+use std::{
+    net::{
+        IpAddr,
+        Ipv4Addr,
+        SocketAddr,
+    },
+    time::Duration,
+};
+use tokio::net::UdpSocket;
+
+/// The port that the gateway listens on for the requests.
+const NAT_PMP_PORT: u16 = 5351;
+/// The timeout of the first request, it is doubled for each retry.
+const INITIAL_TIMEOUT: Duration = Duration::from_millis(250);
+/// The number of the attempts to send the request.
+const MAX_ATTEMPTS: u32 = 4;
+
+const VERSION: u8 = 0;
+const OPCODE_EXTERNAL_ADDRESS: u8 = 0;
+const RESPONSE_OPCODE_OFFSET: u8 = 128;
+const RESULT_SUCCESS: u16 = 0;
+
+fn mapping_opcode(protocol: PortMappingProtocol) -> u8 {
+    match protocol {
+        PortMappingProtocol::UDP => 1,
+        PortMappingProtocol::TCP => 2,
+    }
+}
+
+/// Returns the address of the gateway of the local network.
+/// The gateway is the router of the default route.
+pub(crate) fn gateway_address() -> anyhow::Result<SocketAddr> {
+    let gateway = default_gateway()?;
+    Ok(SocketAddr::new(IpAddr::V4(gateway), NAT_PMP_PORT))
+}
+
+#[cfg(target_os = "linux")]
+fn default_gateway() -> anyhow::Result<Ipv4Addr> {
+    let routes = std::fs::read_to_string("/proc/net/route")?;
+    parse_default_gateway(&routes)
+        .ok_or_else(|| anyhow!("The default route is not found"))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn default_gateway() -> anyhow::Result<Ipv4Addr> {
+    Err(anyhow!(
+        "The default gateway for NAT-PMP is discovered only on Linux"
+    ))
+}
+
+/// Returns the gateway of the default route from the content of the `/proc/net/route`.
+/// The addresses are written as the hex of the network-order bytes read as a native integer.
+#[cfg(target_os = "linux")]
+fn parse_default_gateway(routes: &str) -> Option<Ipv4Addr> {
+    routes.lines().skip(1).find_map(|line| {
+        let mut fields = line.split_whitespace().skip(1);
+        let destination = fields.next()?;
+        let gateway = fields.next()?;
+        if destination != "00000000" {
+            return None
+        }
+        let gateway = u32::from_str_radix(gateway, 16).ok()?;
+        Some(Ipv4Addr::from(gateway.to_ne_bytes()))
+    })
+}
+
+/// Returns the external IP address of the `gateway`.
+pub(crate) async fn external_address(
+    local_address: IpAddr,
+    gateway: SocketAddr,
+) -> anyhow::Result<Ipv4Addr> {
+    let response = request(
+        local_address,
+        gateway,
+        &[VERSION, OPCODE_EXTERNAL_ADDRESS],
+        OPCODE_EXTERNAL_ADDRESS,
+    )
+    .await?;
+    parse_external_address(&response)
+}
+
+/// Maps the `internal_port` on the `gateway` for the `lifetime` and returns
+/// the external port chosen by the gateway. The mapping is removed if the
+/// `lifetime` is zero.
+pub(crate) async fn map_port(
+    local_address: IpAddr,
+    gateway: SocketAddr,
+    protocol: PortMappingProtocol,
+    internal_port: u16,
+    lifetime: Duration,
+) -> anyhow::Result<u16> {
+    let lifetime = u32::try_from(lifetime.as_secs()).unwrap_or(u32::MAX);
+    // The external port of the removed mapping must be zero
+    let external_port = if lifetime == 0 { 0 } else { internal_port };
+    let response = request(
+        local_address,
+        gateway,
+        &mapping_request(protocol, internal_port, external_port, lifetime),
+        mapping_opcode(protocol),
+    )
+    .await?;
+    parse_mapping(&response)
+}
+
+/// Sends the `request` to the `gateway` and waits for the successful response.
+/// The request is retried with the doubled timeout, because it is sent over UDP.
+async fn request(
+    local_address: IpAddr,
+    gateway: SocketAddr,
+    request: &[u8],
+    opcode: u8,
+) -> anyhow::Result<Vec<u8>> {
+    // The gateway maps the ports to the source address of the request
+    let bind_address = match local_address {
+        IpAddr::V4(ip) if !ip.is_unspecified() => IpAddr::V4(ip),
+        _ => IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+    };
+    let socket = UdpSocket::bind(SocketAddr::new(bind_address, 0)).await?;
+    socket.connect(gateway).await?;
+
+    let mut timeout = INITIAL_TIMEOUT;
+    let mut buffer = [0u8; 16];
+    for _ in 0..MAX_ATTEMPTS {
+        socket.send(request).await?;
+        if let Ok(received) =
+            tokio::time::timeout(timeout, socket.recv(&mut buffer)).await
+        {
+            let response = &buffer[..received?];
+            check_response(response, opcode)?;
+            return Ok(response.to_vec())
+        }
+        timeout *= 2;
+    }
+
+    Err(anyhow!("The NAT-PMP gateway {gateway} didn't respond"))
+}
+
+fn mapping_request(
+    protocol: PortMappingProtocol,
+    internal_port: u16,
+    external_port: u16,
+    lifetime: u32,
+) -> [u8; 12] {
+    let mut request = [0u8; 12];
+    request[0] = VERSION;
+    request[1] = mapping_opcode(protocol);
+    // The bytes 2..4 are reserved
+    request[4..6].copy_from_slice(&internal_port.to_be_bytes());
+    request[6..8].copy_from_slice(&external_port.to_be_bytes());
+    request[8..12].copy_from_slice(&lifetime.to_be_bytes());
+    request
+}
+
+fn check_response(response: &[u8], opcode: u8) -> anyhow::Result<()> {
+    if response.len() < 4 {
+        return Err(anyhow!("The NAT-PMP response is too short"))
+    }
+    if response[0] != VERSION || response[1] != RESPONSE_OPCODE_OFFSET + opcode {
+        return Err(anyhow!("Unexpected NAT-PMP response {response:?}"))
+    }
+    let result = u16::from_be_bytes([response[2], response[3]]);
+    if result != RESULT_SUCCESS {
+        return Err(anyhow!(
+            "The NAT-PMP request failed with the result {result}"
+        ))
+    }
+    Ok(())
+}
+
+fn parse_external_address(response: &[u8]) -> anyhow::Result<Ipv4Addr> {
+    let ip: [u8; 4] = response
+        .get(8..12)
+        .and_then(|ip| ip.try_into().ok())
+        .ok_or_else(|| anyhow!("The NAT-PMP external address response is too short"))?;
+    Ok(Ipv4Addr::from(ip))
+}
+
+fn parse_mapping(response: &[u8]) -> anyhow::Result<u16> {
+    let port: [u8; 2] = response
+        .get(10..12)
+        .and_then(|port| port.try_into().ok())
+        .ok_or_else(|| anyhow!("The NAT-PMP mapping response is too short"))?;
+    Ok(u16::from_be_bytes(port))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mapping_request_follows_the_rfc_layout() {
+        let request = mapping_request(PortMappingProtocol::TCP, 30333, 30333, 3600);
+
+        assert_eq!(
+            request,
+            [0, 2, 0, 0, 0x76, 0x7D, 0x76, 0x7D, 0, 0, 0x0E, 0x10]
+        );
+    }
+
+    #[test]
+    fn responses_are_parsed() {
+        let external_address = [0, 128, 0, 0, 0, 0, 0, 1, 1, 2, 3, 4];
+        let mapping = [
+            0, 129, 0, 0, 0, 0, 0, 1, 0x76, 0x7D, 0x76, 0x7E, 0, 0, 0x0E, 0x10,
+        ];
+
+        check_response(&external_address, OPCODE_EXTERNAL_ADDRESS).unwrap();
+        assert_eq!(
+            parse_external_address(&external_address).unwrap(),
+            Ipv4Addr::new(1, 2, 3, 4)
+        );
+        check_response(&mapping, mapping_opcode(PortMappingProtocol::UDP)).unwrap();
+        assert_eq!(parse_mapping(&mapping).unwrap(), 30334);
+    }
+
+    #[test]
+    fn failed_responses_are_rejected() {
+        // The result code 2 means that the gateway is not authorized to map the ports
+        let refused = [0, 130, 0, 2, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0];
+        let wrong_opcode = [0, 129, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0];
+
+        assert!(
+            check_response(&refused, mapping_opcode(PortMappingProtocol::TCP)).is_err()
+        );
+        assert!(
+            check_response(&wrong_opcode, mapping_opcode(PortMappingProtocol::TCP))
+                .is_err()
+        );
+    }
+
+    #[cfg(all(target_os = "linux", target_endian = "little"))]
+    #[test]
+    fn default_gateway_is_parsed_from_the_routes() {
+        let routes = "\
+Iface\tDestination\tGateway \tFlags\tRefCnt\tUse\tMetric\tMask\t\tMTU\tWindow\tIRTT
+eth0\t0000A8C0\t00000000\t0001\t0\t0\t0\t00FFFFFF\t0\t0\t0
+eth0\t00000000\t0100A8C0\t0003\t0\t0\t0\t00000000\t0\t0\t0
+";
+
+        assert_eq!(
+            parse_default_gateway(routes),
+            Some(Ipv4Addr::new(192, 168, 0, 1))
+        );
+    }
+}
@@ -1 +1,2 @@
 pub mod messages;
+pub(crate) mod rate_limiter;
@@ -6,10 +6,16 @@ use fuel_core_types::{
         SealedBlock,
         SealedBlockHeader,
     },
-    fuel_tx::Transaction,
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     fuel_types::BlockHeight,
 };
-use libp2p::PeerId;
+use libp2p::{
+    Multiaddr,
+    PeerId,
+};
 use serde::{
     Deserialize,
     Serialize,
@@ -20,11 +26,20 @@ use serde_with::{
 };
 use tokio::sync::oneshot;
 
-pub(crate) const REQUEST_RESPONSE_PROTOCOL_ID: &[u8] = b"/fuel/req_res/0.0.1";
+/// The version is bumped on any change of the encoding of the messages, so the peers
+/// with the incompatible versions don't negotiate the protocol.
+/// `0.0.2` added the `SealedHeaders` range request and response.
+pub(crate) const REQUEST_RESPONSE_PROTOCOL_ID: &[u8] = b"/fuel/req_res/0.0.2";
 
 /// Max Size in Bytes of the Request Message
 pub(crate) const MAX_REQUEST_SIZE: usize = core::mem::size_of::<RequestMessage>();
 
+/// Max number of the headers sent in a single response
+pub(crate) const MAX_HEADERS_PER_REQUEST: u32 = 100;
+
+/// Max number of the peers' addresses shared in a single peer exchange response
+pub(crate) const MAX_EXCHANGED_PEERS: usize = 16;
+
 pub type ChannelItem<T> = oneshot::Sender<Option<T>>;
 
 // Peer receives a `RequestMessage`.
@@ -42,7 +57,16 @@ pub type ChannelItem<T> = oneshot::Sender<Option<T>>;
 pub enum RequestMessage {
     Block(BlockHeight),
     SealedHeader(BlockHeight),
+    /// Requests the headers of the `[start, end)` heights
+    SealedHeaders {
+        start: BlockHeight,
+        end: BlockHeight,
+    },
     Transactions(#[serde_as(as = "FromInto<[u8; 32]>")] BlockId),
+    /// Requests the body of the announced transaction
+    Transaction(#[serde_as(as = "FromInto<[u8; 32]>")] TxId),
+    /// Requests a sample of the peers known by the responder
+    Peers,
 }
 
 /// Final Response Message that p2p service sends to the Orchestrator
@@ -50,7 +74,12 @@ pub enum RequestMessage {
 pub enum ResponseMessage {
     SealedBlock(Option<SealedBlock>),
     SealedHeader(Option<SealedBlockHeader>),
+    SealedHeaders(Option<Vec<SealedBlockHeader>>),
     Transactions(Option<Vec<Transaction>>),
+    Transaction(Option<Transaction>),
+    Peers(Option<Vec<Multiaddr>>),
+    /// The peer refused to process the request due to the rate limit
+    Throttled,
 }
 
 /// Holds oneshot channels for specific responses
@@ -58,7 +87,11 @@ pub enum ResponseMessage {
 pub enum ResponseChannelItem {
     Block(ChannelItem<SealedBlock>),
     SealedHeader(ChannelItem<(PeerId, SealedBlockHeader)>),
+    SealedHeaders(ChannelItem<(PeerId, Vec<SealedBlockHeader>)>),
     Transactions(ChannelItem<Vec<Transaction>>),
+    Transaction(ChannelItem<Transaction>),
+    /// The peer exchange response is handled by the p2p service itself
+    PeerExchange,
 }
 
 /// Response that is sent over the wire
@@ -67,7 +100,12 @@ pub enum ResponseChannelItem {
 pub enum NetworkResponse {
     Block(Option<Vec<u8>>),
     Header(Option<Vec<u8>>),
+    Headers(Option<Vec<u8>>),
     Transactions(Option<Vec<u8>>),
+    Transaction(Option<Vec<u8>>),
+    Peers(Option<Vec<u8>>),
+    /// The request exceeded the rate limit of the responding peer
+    Throttled,
 }
 
 /// Initial state of the `ResponseMessage` prior to having its inner value serialized
@@ -76,7 +114,10 @@ pub enum NetworkResponse {
 pub enum OutboundResponse {
     Block(Option<Arc<SealedBlock>>),
     SealedHeader(Option<Arc<SealedBlockHeader>>),
+    SealedHeaders(Option<Arc<Vec<SealedBlockHeader>>>),
     Transactions(Option<Arc<Vec<Transaction>>>),
+    Transaction(Option<Arc<Transaction>>),
+    Peers(Option<Vec<Multiaddr>>),
 }
 
 #[derive(Debug)]
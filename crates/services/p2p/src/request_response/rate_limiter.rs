@@ -0,0 +1,111 @@
+use libp2p::PeerId;
+use std::{
+    collections::HashMap,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The window of the requests-per-second limit.
+const WINDOW: Duration = Duration::from_secs(1);
+
+#[derive(Debug)]
+struct PeerRequests {
+    /// The start of the current window
+    window_start: Instant,
+    /// The number of the requests received within the current window
+    count: usize,
+    /// The number of the requests waiting for the response
+    in_flight: usize,
+}
+
+/// Limits the number of the concurrent inbound requests of each peer
+/// and the number of its requests per second.
+#[derive(Debug)]
+pub(crate) struct InboundRequestLimiter {
+    max_concurrent: usize,
+    max_per_second: usize,
+    peers: HashMap<PeerId, PeerRequests>,
+}
+
+impl InboundRequestLimiter {
+    pub fn new(max_concurrent: usize, max_per_second: usize) -> Self {
+        Self {
+            max_concurrent,
+            max_per_second,
+            peers: HashMap::new(),
+        }
+    }
+
+    /// Registers the request received from the `peer_id`.
+    /// Returns `false` if the peer exceeded any of the limits, in this case the request
+    /// should be throttled. Otherwise, the request should be released after the response.
+    pub fn try_acquire(&mut self, peer_id: &PeerId, now: Instant) -> bool {
+        self.peers.retain(|_, requests| {
+            requests.in_flight > 0 || now.duration_since(requests.window_start) < WINDOW
+        });
+
+        let requests = self.peers.entry(*peer_id).or_insert(PeerRequests {
+            window_start: now,
+            count: 0,
+            in_flight: 0,
+        });
+        if now.duration_since(requests.window_start) >= WINDOW {
+            requests.window_start = now;
+            requests.count = 0;
+        }
+        requests.count = requests.count.saturating_add(1);
+
+        if requests.count > self.max_per_second
+            || requests.in_flight >= self.max_concurrent
+        {
+            return false
+        }
+
+        requests.in_flight += 1;
+        true
+    }
+
+    /// Releases the request of the `peer_id` once it is responded or failed.
+    pub fn release(&mut self, peer_id: &PeerId) {
+        if let Some(requests) = self.peers.get_mut(peer_id) {
+            requests.in_flight = requests.in_flight.saturating_sub(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn concurrent_requests_are_limited() {
+        let mut limiter = InboundRequestLimiter::new(2, 10);
+        let peer = PeerId::random();
+        let now = Instant::now();
+
+        assert!(limiter.try_acquire(&peer, now));
+        assert!(limiter.try_acquire(&peer, now));
+        assert!(!limiter.try_acquire(&peer, now));
+
+        limiter.release(&peer);
+
+        assert!(limiter.try_acquire(&peer, now));
+        assert!(limiter.try_acquire(&PeerId::random(), now));
+    }
+
+    #[test]
+    fn requests_per_second_are_limited() {
+        let mut limiter = InboundRequestLimiter::new(10, 2);
+        let peer = PeerId::random();
+        let now = Instant::now();
+
+        for _ in 0..2 {
+            assert!(limiter.try_acquire(&peer, now));
+            limiter.release(&peer);
+        }
+        assert!(!limiter.try_acquire(&peer, now));
+        assert!(limiter.try_acquire(&peer, now + WINDOW));
+    }
+}
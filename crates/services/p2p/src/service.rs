@@ -3,7 +3,11 @@ use crate::{
         postcard::PostcardCodec,
         NetworkCodec,
     },
-    config::Config,
+    config::{
+        Config,
+        TxPropagation,
+    },
+    dns_seeds::dns_seeds_stream,
     gossipsub::messages::{
         GossipsubBroadcastRequest,
         GossipsubMessage,
@@ -12,6 +16,14 @@ use crate::{
         FuelP2PEvent,
         FuelP2PService,
     },
+    peer_manager::{
+        PeerInfo,
+        PeerReputations,
+    },
+    port_mapping::{
+        port_mapping_stream,
+        PortMapping,
+    },
     ports::{
         BlockHeightImporter,
         P2pDb,
@@ -20,11 +32,19 @@ use crate::{
         OutboundResponse,
         RequestMessage,
         ResponseChannelItem,
+        MAX_HEADERS_PER_REQUEST,
+    },
+    tx_announcement::{
+        AnnouncedTransactions,
+        MAX_ANNOUNCED_TRANSACTIONS,
     },
 };
 use anyhow::anyhow;
 use fuel_core_services::{
-    stream::BoxStream,
+    stream::{
+        BoxFuture,
+        BoxStream,
+    },
     RunnableService,
     RunnableTask,
     ServiceRunner,
@@ -38,8 +58,14 @@ use fuel_core_types::{
         SealedBlock,
         SealedBlockHeader,
     },
-    fuel_tx::Transaction,
-    fuel_types::BlockHeight,
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
+    fuel_types::{
+        BlockHeight,
+        ChainId,
+    },
     services::p2p::{
         peer_reputation::{
             AppScore,
@@ -50,16 +76,25 @@ use fuel_core_types::{
         GossipsubMessageAcceptance,
         GossipsubMessageInfo,
         PeerId as FuelPeerId,
+        PeerInfo as FuelPeerInfo,
         TransactionGossipData,
     },
 };
-use futures::StreamExt;
+use futures::{
+    stream::FuturesUnordered,
+    StreamExt,
+};
 use libp2p::{
-    gossipsub::MessageAcceptance,
+    gossipsub::{
+        MessageAcceptance,
+        MessageId,
+    },
+    Multiaddr,
     PeerId,
 };
 use std::{
     fmt::Debug,
+    ops::Range,
     sync::Arc,
 };
 use tokio::sync::{
@@ -74,10 +109,14 @@ pub type Service<D> = ServiceRunner<Task<D>>;
 enum TaskRequest {
     // Broadcast requests to p2p network
     BroadcastTransaction(Arc<Transaction>),
+    BroadcastTransactions(Vec<Arc<Transaction>>),
     BroadcastBlock(Arc<Block>),
     BroadcastVote(Arc<ConsensusVote>),
     // Request to get one-off data from p2p network
     GetPeerIds(oneshot::Sender<Vec<PeerId>>),
+    GetAllPeerInfo {
+        channel: oneshot::Sender<Vec<FuelPeerInfo>>,
+    },
     GetBlock {
         height: BlockHeight,
         channel: oneshot::Sender<Option<SealedBlock>>,
@@ -86,11 +125,19 @@ enum TaskRequest {
         height: BlockHeight,
         channel: oneshot::Sender<Option<(PeerId, SealedBlockHeader)>>,
     },
+    GetSealedHeaders {
+        range: Range<u32>,
+        channel: oneshot::Sender<Option<(PeerId, Vec<SealedBlockHeader>)>>,
+    },
     GetTransactions {
         block_id: BlockId,
         from_peer: PeerId,
         channel: oneshot::Sender<Option<Vec<Transaction>>>,
     },
+    RequestTransactions {
+        tx_ids: Vec<TxId>,
+        from_peer: PeerId,
+    },
     // Responds back to the p2p network
     RespondWithGossipsubMessageReport((GossipsubMessageInfo, GossipsubMessageAcceptance)),
     RespondWithPeerReport {
@@ -98,6 +145,9 @@ enum TaskRequest {
         score: AppScore,
         reporting_service: &'static str,
     },
+    // Requests of the node operator
+    DisconnectPeer(PeerId),
+    BanPeer(PeerId),
 }
 
 impl Debug for TaskRequest {
@@ -106,12 +156,37 @@ impl Debug for TaskRequest {
     }
 }
 
+/// The body of the announced transaction requested from the announcer.
+struct FetchedTransaction {
+    tx_id: TxId,
+    peer_id: PeerId,
+    message_id: MessageId,
+    transaction: Option<Transaction>,
+}
+
 /// Orchestrates various p2p-related events between the inner `P2pService`
 /// and the top level `NetworkService`.
 pub struct Task<D> {
     p2p_service: FuelP2PService<PostcardCodec>,
     db: Arc<D>,
+    tx_propagation: TxPropagation,
+    chain_id: ChainId,
+    /// The recent transactions whose bodies are served to the peers on request
+    announced_transactions: AnnouncedTransactions,
+    /// The gossiped transactions waiting for the verdict of the `TxPool`,
+    /// by the id of the gossipsub message
+    unvalidated_transactions: AnnouncedTransactions<Vec<u8>>,
+    /// The requests of the bodies of the announced transactions
+    pending_transactions: FuturesUnordered<BoxFuture<'static, FetchedTransaction>>,
+    /// The requests of the transactions missing in the `TxPool`
+    requested_transactions: FuturesUnordered<BoxFuture<'static, Vec<Transaction>>>,
     next_block_height: BoxStream<BlockHeight>,
+    /// The bootstrap addresses resolved from the DNS seeds
+    dns_seeds: BoxStream<Vec<Multiaddr>>,
+    /// The port mappings on the gateway of the local network
+    port_mapping: BoxStream<PortMapping>,
+    /// The latest port mapping, removed from the gateway on shutdown
+    mapped_ports: Option<PortMapping>,
     /// Receive internal Task Requests
     request_receiver: mpsc::Receiver<TaskRequest>,
     shared: SharedState,
@@ -125,30 +200,199 @@ impl<D> Task<D> {
     ) -> Self {
         let (request_sender, request_receiver) = mpsc::channel(100);
         let (tx_broadcast, _) = broadcast::channel(100);
+        let (requested_tx_broadcast, _) = broadcast::channel(100);
         let (block_height_broadcast, _) = broadcast::channel(100);
 
         let next_block_height = block_importer.next_block_height();
+        let dns_seeds = dns_seeds_stream(
+            config.bootstrap_dns_seeds.clone(),
+            config.dns_seeds_refresh_interval,
+        );
+        let port_mapping = port_mapping_stream(
+            config.port_mapping,
+            config.address,
+            config.tcp_port,
+            config.quic_port,
+        );
+        let tx_propagation = config.tx_propagation;
+        let chain_id = config.chain_id;
         let max_block_size = config.max_block_size;
-        let p2p_service = FuelP2PService::new(config, PostcardCodec::new(max_block_size));
+        let codec = PostcardCodec::new(max_block_size).with_metrics(config.metrics);
+        let p2p_service = FuelP2PService::new(config, codec);
 
         let reserved_peers_broadcast =
             p2p_service.peer_manager().reserved_peers_updates();
+        let reputations = p2p_service.peer_manager().reputations();
 
         Self {
             p2p_service,
             db,
+            tx_propagation,
+            chain_id,
+            announced_transactions: AnnouncedTransactions::new(
+                MAX_ANNOUNCED_TRANSACTIONS,
+            ),
+            unvalidated_transactions: AnnouncedTransactions::new(
+                MAX_ANNOUNCED_TRANSACTIONS,
+            ),
+            pending_transactions: FuturesUnordered::new(),
+            requested_transactions: FuturesUnordered::new(),
             request_receiver,
             next_block_height,
+            dns_seeds,
+            port_mapping,
+            mapped_ports: None,
             shared: SharedState {
                 request_sender,
                 tx_broadcast,
+                requested_tx_broadcast,
                 reserved_peers_broadcast,
                 block_height_broadcast,
+                reputations,
             },
         }
     }
 }
 
+impl<D> Task<D> {
+    fn broadcast_transaction(&mut self, transaction: Arc<Transaction>) {
+        // The body is kept in both modes, so the peers can request
+        // the parents of the gossiped transactions.
+        let tx_id = transaction.id(&self.chain_id);
+        self.announced_transactions
+            .insert(tx_id, transaction.clone());
+        let broadcast = match self.tx_propagation {
+            TxPropagation::Full => GossipsubBroadcastRequest::NewTx(transaction),
+            TxPropagation::Announce => GossipsubBroadcastRequest::NewTxId(tx_id),
+        };
+
+        let result = self.p2p_service.publish_message(broadcast);
+        if let Err(e) = result {
+            tracing::error!("Got an error during transaction broadcasting {}", e);
+        }
+    }
+
+    /// Requests the body of the announced transaction from the announcer if it is unknown.
+    /// The validity of the announcement is reported once the body is validated by the `TxPool`.
+    fn handle_tx_announcement(
+        &mut self,
+        tx_id: TxId,
+        peer_id: PeerId,
+        message_id: MessageId,
+    ) {
+        if self.announced_transactions.contains(&tx_id) {
+            self.p2p_service.report_message_validation_result(
+                &message_id,
+                peer_id,
+                MessageAcceptance::Ignore,
+            );
+            return
+        }
+
+        let (sender, receiver) = oneshot::channel();
+        let request_msg = RequestMessage::Transaction(tx_id);
+        let channel_item = ResponseChannelItem::Transaction(sender);
+        let _ =
+            self.p2p_service
+                .send_request_msg(Some(peer_id), request_msg, channel_item);
+
+        self.pending_transactions.push(Box::pin(async move {
+            FetchedTransaction {
+                tx_id,
+                peer_id,
+                message_id,
+                transaction: receiver.await.ok().flatten(),
+            }
+        }));
+    }
+
+    /// Requests the `tx_ids` from the peer. The bodies are checked against the
+    /// requested ids, and the unknown ones are skipped.
+    fn request_transactions(&mut self, tx_ids: Vec<TxId>, from_peer: PeerId) {
+        let mut responses = Vec::with_capacity(tx_ids.len());
+        for tx_id in tx_ids {
+            let (sender, receiver) = oneshot::channel();
+            let request_msg = RequestMessage::Transaction(tx_id);
+            let channel_item = ResponseChannelItem::Transaction(sender);
+            if self
+                .p2p_service
+                .send_request_msg(Some(from_peer), request_msg, channel_item)
+                .is_ok()
+            {
+                responses.push((tx_id, receiver));
+            }
+        }
+
+        let chain_id = self.chain_id;
+        self.requested_transactions.push(Box::pin(async move {
+            let mut transactions = vec![];
+            for (tx_id, receiver) in responses {
+                match receiver.await.ok().flatten() {
+                    Some(transaction) if transaction.id(&chain_id) == tx_id => {
+                        transactions.push(transaction);
+                    }
+                    Some(_) => {
+                        tracing::debug!("The peer {from_peer} responded with the wrong body of the transaction {tx_id}");
+                    }
+                    None => {}
+                }
+            }
+            transactions
+        }));
+    }
+
+    /// Serves the body of the gossiped transaction to the peers
+    /// only if the `TxPool` accepted it.
+    fn handle_transaction_validity(
+        &mut self,
+        message: &GossipsubMessageInfo,
+        acceptance: &GossipsubMessageAcceptance,
+    ) {
+        if let Some(transaction) =
+            self.unvalidated_transactions.remove(&message.message_id)
+        {
+            if matches!(acceptance, GossipsubMessageAcceptance::Accept) {
+                let tx_id = transaction.id(&self.chain_id);
+                self.announced_transactions.insert(tx_id, transaction);
+            }
+        }
+    }
+
+    fn handle_fetched_transaction(&mut self, fetched: FetchedTransaction) {
+        let FetchedTransaction {
+            tx_id,
+            peer_id,
+            message_id,
+            transaction,
+        } = fetched;
+
+        match transaction {
+            Some(transaction) if transaction.id(&self.chain_id) == tx_id => {
+                self.unvalidated_transactions
+                    .insert(message_id.0.clone(), Arc::new(transaction.clone()));
+                let next_transaction =
+                    GossipData::new(transaction, peer_id, message_id.0);
+                let _ = self.shared.tx_broadcast.send(next_transaction);
+            }
+            Some(_) => {
+                tracing::debug!("The peer {peer_id} responded with the wrong body of the transaction {tx_id}");
+                self.p2p_service.report_message_validation_result(
+                    &message_id,
+                    peer_id,
+                    MessageAcceptance::Reject,
+                );
+            }
+            None => {
+                self.p2p_service.report_message_validation_result(
+                    &message_id,
+                    peer_id,
+                    MessageAcceptance::Ignore,
+                );
+            }
+        }
+    }
+}
+
 #[async_trait::async_trait]
 impl<D> RunnableService for Task<D>
 where
@@ -192,10 +436,11 @@ where
                 should_continue = true;
                 match next_service_request {
                     Some(TaskRequest::BroadcastTransaction(transaction)) => {
-                        let broadcast = GossipsubBroadcastRequest::NewTx(transaction);
-                        let result = self.p2p_service.publish_message(broadcast);
-                        if let Err(e) = result {
-                            tracing::error!("Got an error during transaction broadcasting {}", e);
+                        self.broadcast_transaction(transaction);
+                    }
+                    Some(TaskRequest::BroadcastTransactions(transactions)) => {
+                        for transaction in transactions {
+                            self.broadcast_transaction(transaction);
                         }
                     }
                     Some(TaskRequest::BroadcastBlock(block)) => {
@@ -216,6 +461,13 @@ where
                         let peer_ids = self.p2p_service.get_peers_ids().copied().collect();
                         let _ = channel.send(peer_ids);
                     }
+                    Some(TaskRequest::GetAllPeerInfo { channel }) => {
+                        let peers = self.p2p_service.peer_manager()
+                            .get_all_peers()
+                            .map(|(peer_id, peer_info)| to_peer_info(peer_id, peer_info))
+                            .collect();
+                        let _ = channel.send(peers);
+                    }
                     Some(TaskRequest::GetBlock { height, channel }) => {
                         let request_msg = RequestMessage::Block(height);
                         let channel_item = ResponseChannelItem::Block(channel);
@@ -228,17 +480,40 @@ where
                         let peer = self.p2p_service.peer_manager().get_peer_id_with_height(&height);
                         let _ = self.p2p_service.send_request_msg(peer, request_msg, channel_item);
                     }
+                    Some(TaskRequest::GetSealedHeaders { range, channel }) => {
+                        let request_msg = RequestMessage::SealedHeaders {
+                            start: range.start.into(),
+                            end: range.end.into(),
+                        };
+                        let channel_item = ResponseChannelItem::SealedHeaders(channel);
+                        // The peer should have all headers of the range
+                        let last_height: BlockHeight = range.end.saturating_sub(1).into();
+                        let peer = self.p2p_service.peer_manager().get_peer_id_with_height(&last_height);
+                        let _ = self.p2p_service.send_request_msg(peer, request_msg, channel_item);
+                    }
                     Some(TaskRequest::GetTransactions { block_id, from_peer, channel }) => {
                         let request_msg = RequestMessage::Transactions(block_id);
                         let channel_item = ResponseChannelItem::Transactions(channel);
                         let _ = self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel_item);
                     }
+                    Some(TaskRequest::RequestTransactions { tx_ids, from_peer }) => {
+                        self.request_transactions(tx_ids, from_peer);
+                    }
                     Some(TaskRequest::RespondWithGossipsubMessageReport((message, acceptance))) => {
+                        self.handle_transaction_validity(&message, &acceptance);
                         report_message(&mut self.p2p_service, message, acceptance);
                     }
                     Some(TaskRequest::RespondWithPeerReport { peer_id, score, reporting_service }) => {
                         self.p2p_service.report_peer(peer_id, score, reporting_service)
                     }
+                    Some(TaskRequest::DisconnectPeer(peer_id)) => {
+                        if !self.p2p_service.disconnect_peer(peer_id) {
+                            tracing::warn!("Unable to disconnect {peer_id}, it is not connected");
+                        }
+                    }
+                    Some(TaskRequest::BanPeer(peer_id)) => {
+                        self.p2p_service.ban_peer(peer_id);
+                    }
                     None => {
                         unreachable!("The `Task` is holder of the `Sender`, so it should not be possible");
                     }
@@ -261,9 +536,14 @@ where
 
                         match message {
                             GossipsubMessage::NewTx(transaction) => {
+                                // The body is served to the peers only after the `TxPool` accepts it
+                                self.unvalidated_transactions.insert(message_id.clone(), Arc::new(transaction.clone()));
                                 let next_transaction = GossipData::new(transaction, peer_id, message_id);
                                 let _ = self.shared.tx_broadcast.send(next_transaction);
                             },
+                            GossipsubMessage::NewTxId(tx_id) => {
+                                self.handle_tx_announcement(tx_id, peer_id, MessageId(message_id));
+                            },
                             GossipsubMessage::NewBlock(block) => {
                                 // todo: add logic to gossip newly received blocks
                                 let _new_block = GossipData::new(block, peer_id, message_id);
@@ -294,11 +574,60 @@ where
 
                                 let _ = self.p2p_service.send_response_msg(request_id, OutboundResponse::SealedHeader(response));
                             }
+                            RequestMessage::SealedHeaders { start, end } => {
+                                let end = (*end).min((*start).saturating_add(MAX_HEADERS_PER_REQUEST));
+                                let mut headers = vec![];
+                                for height in *start..end {
+                                    match self.db.get_sealed_header(&height.into())? {
+                                        Some(header) => headers.push(header),
+                                        None => break,
+                                    }
+                                }
+                                let response = (!headers.is_empty()).then(|| Arc::new(headers));
+
+                                let _ = self.p2p_service.send_response_msg(request_id, OutboundResponse::SealedHeaders(response));
+                            }
+                            RequestMessage::Transaction(tx_id) => {
+                                let response = self.announced_transactions.get(&tx_id);
+
+                                let _ = self.p2p_service.send_response_msg(request_id, OutboundResponse::Transaction(response));
+                            }
+                            // Served by the `FuelP2PService` itself
+                            RequestMessage::Peers => {}
                         }
                     },
                     _ => (),
                 }
             },
+            Some(fetched) = self.pending_transactions.next() => {
+                self.handle_fetched_transaction(fetched);
+                should_continue = true;
+            }
+            Some(transactions) = self.requested_transactions.next() => {
+                if !transactions.is_empty() {
+                    let _ = self.shared.requested_tx_broadcast.send(transactions);
+                }
+                should_continue = true;
+            }
+            Some(addresses) = self.dns_seeds.next() => {
+                self.p2p_service.add_bootstrap_addresses(addresses);
+                should_continue = true;
+            }
+            Some(mapping) = self.port_mapping.next() => {
+                // The external IP or ports may change between the renewals,
+                // so the outdated addresses are not advertised anymore.
+                if let Some(previous) = self.mapped_ports.take() {
+                    let outdated = previous
+                        .addresses
+                        .into_iter()
+                        .filter(|address| !mapping.addresses.contains(address))
+                        .collect();
+                    self.p2p_service.remove_external_addresses(outdated);
+                }
+                self.p2p_service.add_external_addresses(mapping.addresses.clone());
+                self.mapped_ports = Some(mapping);
+                should_continue = true;
+            }
             latest_block_height = self.next_block_height.next() => {
                 if let Some(latest_block_height) = latest_block_height {
                     let _ = self.p2p_service.update_block_height(latest_block_height);
@@ -313,8 +642,15 @@ where
     }
 
     async fn shutdown(self) -> anyhow::Result<()> {
-        // Nothing to shut down because we don't have any temporary state that should be dumped,
-        // and we don't spawn any sub-tasks that we need to finish or await.
+        // The ports are not reachable after the shutdown, so they are unmapped
+        // instead of waiting for the expiration of the lease.
+        if let Some(mapping) = &self.mapped_ports {
+            if let Err(e) = mapping.remove().await {
+                tracing::warn!("Unable to remove the mappings of the p2p ports: {e}");
+            }
+        }
+
+        // We don't spawn any sub-tasks that we need to finish or await.
 
         // `FuelP2PService` doesn't support graceful shutdown(with informing of connected peers).
         // https://github.com/libp2p/specs/blob/master/ROADMAP.md#%EF%B8%8F-polite-peering
@@ -328,12 +664,16 @@ where
 pub struct SharedState {
     /// Sender of p2p transaction used for subscribing.
     tx_broadcast: broadcast::Sender<TransactionGossipData>,
+    /// Sender of the transactions received in response to `request_transactions`.
+    requested_tx_broadcast: broadcast::Sender<Vec<Transaction>>,
     /// Sender of reserved peers connection updates.
     reserved_peers_broadcast: broadcast::Sender<usize>,
     /// Used for communicating with the `Task`.
     request_sender: mpsc::Sender<TaskRequest>,
     /// Sender of p2p blopck height data
     block_height_broadcast: broadcast::Sender<BlockHeightHeartbeatData>,
+    /// The scores of the peers maintained by the `PeerManager`.
+    reputations: PeerReputations,
 }
 
 impl SharedState {
@@ -385,6 +725,27 @@ impl SharedState {
             .map_err(|e| anyhow!("{}", e))
     }
 
+    /// Requests the headers of the `range` from a single peer.
+    /// The response may contain fewer headers than requested.
+    pub async fn get_sealed_block_headers(
+        &self,
+        range: Range<u32>,
+    ) -> anyhow::Result<Option<(Vec<u8>, Vec<SealedBlockHeader>)>> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(TaskRequest::GetSealedHeaders {
+                range,
+                channel: sender,
+            })
+            .await?;
+
+        receiver
+            .await
+            .map(|o| o.map(|(peer_id, headers)| (peer_id.to_bytes(), headers)))
+            .map_err(|e| anyhow!("{}", e))
+    }
+
     pub async fn get_transactions_from_peer(
         &self,
         peer_id: Vec<u8>,
@@ -404,6 +765,19 @@ impl SharedState {
         receiver.await.map_err(|e| anyhow!("{}", e))
     }
 
+    /// Requests the transactions from the peer. The received transactions
+    /// are sent to the subscribers of `subscribe_requested_transactions`.
+    pub fn request_transactions(
+        &self,
+        peer_id: FuelPeerId,
+        tx_ids: Vec<TxId>,
+    ) -> anyhow::Result<()> {
+        let from_peer = PeerId::from_bytes(&Vec::from(peer_id))?;
+        self.request_sender
+            .try_send(TaskRequest::RequestTransactions { tx_ids, from_peer })?;
+        Ok(())
+    }
+
     pub fn broadcast_vote(&self, vote: Arc<ConsensusVote>) -> anyhow::Result<()> {
         self.request_sender
             .try_send(TaskRequest::BroadcastVote(vote))?;
@@ -427,6 +801,17 @@ impl SharedState {
         Ok(())
     }
 
+    /// Broadcasts the `transactions` using one request to the task,
+    /// so a large batch doesn't overflow the requests channel.
+    pub fn broadcast_transactions(
+        &self,
+        transactions: Vec<Arc<Transaction>>,
+    ) -> anyhow::Result<()> {
+        self.request_sender
+            .try_send(TaskRequest::BroadcastTransactions(transactions))?;
+        Ok(())
+    }
+
     pub async fn get_peer_ids(&self) -> anyhow::Result<Vec<PeerId>> {
         let (sender, receiver) = oneshot::channel();
 
@@ -437,10 +822,43 @@ impl SharedState {
         receiver.await.map_err(|e| anyhow!("{}", e))
     }
 
+    /// Returns the information about all connected peers.
+    pub async fn get_all_peer_info(&self) -> anyhow::Result<Vec<FuelPeerInfo>> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(TaskRequest::GetAllPeerInfo { channel: sender })
+            .await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))
+    }
+
+    /// Closes all connections with the peer. The peer is free to reconnect.
+    pub fn disconnect_peer(&self, peer_id: FuelPeerId) -> anyhow::Result<()> {
+        let peer_id = PeerId::from_bytes(&Vec::from(peer_id))?;
+        self.request_sender
+            .try_send(TaskRequest::DisconnectPeer(peer_id))?;
+        Ok(())
+    }
+
+    /// Bans the peer, so no connections with it are allowed until the restart.
+    pub fn ban_peer(&self, peer_id: FuelPeerId) -> anyhow::Result<()> {
+        let peer_id = PeerId::from_bytes(&Vec::from(peer_id))?;
+        self.request_sender
+            .try_send(TaskRequest::BanPeer(peer_id))?;
+        Ok(())
+    }
+
     pub fn subscribe_tx(&self) -> broadcast::Receiver<TransactionGossipData> {
         self.tx_broadcast.subscribe()
     }
 
+    pub fn subscribe_requested_transactions(
+        &self,
+    ) -> broadcast::Receiver<Vec<Transaction>> {
+        self.requested_tx_broadcast.subscribe()
+    }
+
     pub fn subscribe_block_height(
         &self,
     ) -> broadcast::Receiver<BlockHeightHeartbeatData> {
@@ -451,6 +869,23 @@ impl SharedState {
         self.reserved_peers_broadcast.subscribe()
     }
 
+    /// Returns the score of the peer, if it was reported.
+    pub fn get_peer_score(&self, peer_id: FuelPeerId) -> Option<AppScore> {
+        let peer_id = PeerId::from_bytes(&Vec::from(peer_id)).ok()?;
+        self.reputations.score(&peer_id)
+    }
+
+    /// Returns `true` if the peer was banned due to the low score.
+    pub fn is_peer_banned(&self, peer_id: FuelPeerId) -> bool {
+        PeerId::from_bytes(&Vec::from(peer_id))
+            .map_or(false, |peer_id| self.reputations.is_banned(&peer_id))
+    }
+
+    /// Returns the handle to the scores of the peers.
+    pub fn peer_reputations(&self) -> PeerReputations {
+        self.reputations.clone()
+    }
+
     pub fn report_peer<T: PeerReport>(
         &self,
         peer_id: FuelPeerId,
@@ -490,6 +925,22 @@ where
     ))
 }
 
+fn to_peer_info(peer_id: &PeerId, peer_info: &PeerInfo) -> FuelPeerInfo {
+    FuelPeerInfo {
+        id: FuelPeerId::from(peer_id.to_bytes()),
+        peer_addresses: peer_info
+            .peer_addresses
+            .iter()
+            .map(|address| address.to_string())
+            .collect(),
+        client_version: peer_info.client_version.clone(),
+        block_height: peer_info.heartbeat_data.block_height,
+        last_heartbeat: peer_info.heartbeat_data.seconds_since_last_heartbeat(),
+        connection_age: peer_info.connected_at.elapsed(),
+        app_score: peer_info.score,
+    }
+}
+
 pub(crate) fn to_message_acceptance(
     acceptance: &GossipsubMessageAcceptance,
 ) -> MessageAcceptance {
@@ -0,0 +1,99 @@
+use fuel_core_types::fuel_tx::{
+    Transaction,
+    TxId,
+};
+use std::{
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    hash::Hash,
+    sync::Arc,
+};
+
+/// The max number of the announced transactions whose bodies are kept
+/// to answer the requests of the peers.
+pub(crate) const MAX_ANNOUNCED_TRANSACTIONS: usize = 4096;
+
+/// The bodies of the transactions announced by the node or accepted by the `TxPool`,
+/// by the key of the transaction. When the capacity is reached, the oldest transaction
+/// is evicted.
+#[derive(Debug)]
+pub(crate) struct AnnouncedTransactions<K = TxId> {
+    capacity: usize,
+    transactions: HashMap<K, Arc<Transaction>>,
+    order: VecDeque<K>,
+}
+
+impl<K> AnnouncedTransactions<K>
+where
+    K: Hash + Eq + Clone,
+{
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            transactions: HashMap::with_capacity(capacity),
+            order: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.transactions.contains_key(key)
+    }
+
+    pub fn get(&self, key: &K) -> Option<Arc<Transaction>> {
+        self.transactions.get(key).cloned()
+    }
+
+    pub fn insert(&mut self, key: K, transaction: Arc<Transaction>) {
+        if self.transactions.insert(key.clone(), transaction).is_some() {
+            return
+        }
+
+        self.order.push_back(key);
+        if self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.transactions.remove(&oldest);
+            }
+        }
+    }
+
+    /// Removes the transaction. Its key is left in the eviction order,
+    /// so the capacity still bounds the memory.
+    pub fn remove(&mut self, key: &K) -> Option<Arc<Transaction>> {
+        self.transactions.remove(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn oldest_transaction_is_evicted() {
+        let mut announced = AnnouncedTransactions::new(2);
+        let tx = Arc::new(Transaction::default_test_tx());
+        let ids: Vec<TxId> = (0..3u8).map(|i| [i; 32].into()).collect();
+
+        for id in &ids {
+            announced.insert(*id, tx.clone());
+        }
+
+        assert!(!announced.contains(&ids[0]));
+        assert!(announced.contains(&ids[1]));
+        assert_eq!(announced.get(&ids[2]), Some(tx));
+    }
+
+    #[test]
+    fn removed_transaction_is_not_served() {
+        let mut unvalidated = AnnouncedTransactions::new(2);
+        let tx = Arc::new(Transaction::default_test_tx());
+        let message_id = vec![1, 2, 3];
+
+        unvalidated.insert(message_id.clone(), tx.clone());
+
+        assert_eq!(unvalidated.remove(&message_id), Some(tx));
+        assert!(!unvalidated.contains(&message_id));
+        assert_eq!(unvalidated.remove(&message_id), None);
+    }
+}
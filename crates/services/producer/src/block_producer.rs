@@ -1,4 +1,5 @@
 use crate::{
+    gas_price::GasPriceTracker,
     ports,
     Config,
 };
@@ -28,7 +29,11 @@ use fuel_core_types::{
     },
     services::{
         block_producer::Components,
-        executor::UncommittedResult,
+        executor::{
+            StateOverrides,
+            TransactionTrace,
+            UncommittedResult,
+        },
     },
     tai64::Tai64,
 };
@@ -61,6 +66,7 @@ pub struct Producer<Database, TxPool, Executor> {
     pub txpool: TxPool,
     pub executor: Arc<Executor>,
     pub relayer: Box<dyn ports::Relayer>,
+    pub gas_price: GasPriceTracker,
     // use a tokio lock since we want callers to yield until the previous block
     // execution has completed (which may take a while).
     pub lock: Mutex<()>,
@@ -91,7 +97,9 @@ where
         // prevent simultaneous block production calls, the guard will drop at the end of this fn.
         let _production_guard = self.lock.lock().await;
 
-        let source = self.txpool.get_source(height);
+        let source = self
+            .txpool
+            .get_source(height, self.config.tx_selection.clone());
 
         let header = self.new_header(height, block_time).await?;
 
@@ -113,22 +121,95 @@ where
         Ok(result)
     }
 
+    /// Assembles the next block from the transactions of the pool without committing it
+    /// and without removing the transactions from the pool. Like `dry_run`, it uses the
+    /// DA height of the last block and doesn't acquire the production lock.
+    pub async fn dry_run_block(
+        &self,
+        max_gas: Word,
+    ) -> anyhow::Result<UncommittedResult<StorageTransaction<ExecutorDB>>> {
+        let height = self.db.current_block_height()? + 1.into();
+        let source = self
+            .txpool
+            .get_dry_run_source(height, self.config.tx_selection.clone());
+        let header = self._new_header(height, Tai64::now())?;
+
+        let component = Components {
+            header_to_produce: header,
+            transactions_source: source,
+            gas_limit: max_gas,
+        };
+
+        let context_string =
+            format!("Failed to dry run block {height:?} due to execution failure");
+        let result = self
+            .executor
+            .execute_without_commit(component)
+            .context(context_string)?;
+
+        debug!("Dry run block with result: {:?}", result.result());
+        Ok(result)
+    }
+
     // TODO: Support custom `block_time` for `dry_run`.
     /// Simulate a transaction without altering any state. Does not aquire the production lock
     /// since it is basically a "read only" operation and shouldn't get in the way of normal
-    /// production.
+    /// production. The `overrides` are applied to the state only for the simulation.
     pub async fn dry_run(
         &self,
         transaction: Transaction,
         height: Option<BlockHeight>,
         utxo_validation: Option<bool>,
+        overrides: StateOverrides,
     ) -> anyhow::Result<Vec<Receipt>> {
+        let is_script = transaction.is_script();
+        let component = self.dry_run_components(transaction, height)?;
+
+        let executor = self.executor.clone();
+        // use the blocking threadpool for dry_run to avoid clogging up the main async runtime
+        let res: Vec<_> =
+            tokio_rayon::spawn_fifo(move || -> anyhow::Result<Vec<Receipt>> {
+                Ok(executor
+                    .dry_run(component, utxo_validation, overrides)?
+                    .into_iter()
+                    .flatten()
+                    .collect())
+            })
+            .await?;
+        if is_script && res.is_empty() {
+            return Err(anyhow!("Expected at least one set of receipts"))
+        }
+        Ok(res)
+    }
+
+    /// Simulates the transaction like `dry_run` and returns the trace of its execution.
+    /// The tracing executes the transaction twice, so it is more expensive than `dry_run`.
+    pub async fn trace_tx(
+        &self,
+        transaction: Transaction,
+        utxo_validation: Option<bool>,
+        full_opcode_stream: bool,
+    ) -> anyhow::Result<TransactionTrace> {
+        let component = self.dry_run_components(transaction, None)?;
+
+        let executor = self.executor.clone();
+        let trace = tokio_rayon::spawn_fifo(move || {
+            executor.trace(component, utxo_validation, full_opcode_stream)
+        })
+        .await?;
+        Ok(trace)
+    }
+
+    fn dry_run_components(
+        &self,
+        transaction: Transaction,
+        height: Option<BlockHeight>,
+    ) -> anyhow::Result<Components<Transaction>> {
         let height = match height {
             None => self.db.current_block_height()?,
             Some(height) => height,
         } + 1.into();
 
-        let is_script = transaction.is_script();
         // The dry run execution should use the state of the blockchain based on the
         // last available block, not on the upcoming one. It means that we need to
         // use the same configuration as the last block -> the same DA height.
@@ -140,27 +221,11 @@ where
             Transaction::Create(create) => *create.gas_limit(),
             Transaction::Mint(_) => 0,
         };
-        let component = Components {
+        Ok(Components {
             header_to_produce: header,
             transactions_source: transaction,
             gas_limit,
-        };
-
-        let executor = self.executor.clone();
-        // use the blocking threadpool for dry_run to avoid clogging up the main async runtime
-        let res: Vec<_> =
-            tokio_rayon::spawn_fifo(move || -> anyhow::Result<Vec<Receipt>> {
-                Ok(executor
-                    .dry_run(component, utxo_validation)?
-                    .into_iter()
-                    .flatten()
-                    .collect())
-            })
-            .await?;
-        if is_script && res.is_empty() {
-            return Err(anyhow!("Expected at least one set of receipts"))
-        }
-        Ok(res)
+        })
     }
 }
 
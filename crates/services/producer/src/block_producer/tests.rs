@@ -8,6 +8,7 @@ use crate::{
         MockTxPool,
     },
     Config,
+    GasPriceTracker,
     Producer,
 };
 use fuel_core_producer as _;
@@ -188,6 +189,19 @@ async fn production_fails_on_execution_error() {
     );
 }
 
+#[tokio::test]
+async fn can_dry_run_next_block() {
+    let ctx = TestContext::default();
+    let producer = ctx.producer();
+
+    let result = producer
+        .dry_run_block(1_000_000_000)
+        .await
+        .expect("dry run should succeed");
+
+    assert_eq!(*result.result().block.header().height(), 1u32.into());
+}
+
 struct TestContext<Executor> {
     config: Config,
     db: MockDb,
@@ -243,6 +257,7 @@ impl<Executor> TestContext<Executor> {
             txpool: self.txpool,
             executor: self.executor,
             relayer: Box::new(self.relayer),
+            gas_price: GasPriceTracker::new(0, None),
             lock: Default::default(),
         }
     }
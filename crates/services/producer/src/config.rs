@@ -1,3 +1,7 @@
+use crate::{
+    gas_price::GasPriceConfig,
+    tx_selector::TxSelection,
+};
 use fuel_core_types::fuel_types::Address;
 
 #[derive(Clone, Debug, Default)]
@@ -5,4 +9,8 @@ pub struct Config {
     pub utxo_validation: bool,
     pub coinbase_recipient: Address,
     pub metrics: bool,
+    /// The order in which the transactions from the pool are included into the block.
+    pub tx_selection: TxSelection,
+    /// Adjusts the minimum gas price to the fullness of the produced blocks if set.
+    pub dynamic_gas_price: Option<GasPriceConfig>,
 }
@@ -0,0 +1,248 @@
+//! The minimum gas price adjusted to the fullness of the recent blocks, similar to
+//! the base fee of EIP-1559.
+
+use fuel_core_types::{
+    blockchain::block::Block,
+    fuel_asm::Word,
+    fuel_tx::{
+        field::GasLimit,
+        Transaction,
+    },
+};
+use std::{
+    collections::VecDeque,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+/// The estimation doesn't look further than this number of blocks.
+pub const MAX_BLOCK_HORIZON: u32 = 1_000;
+
+/// The configuration of the dynamic gas price.
+#[derive(Clone, Debug)]
+pub struct GasPriceConfig {
+    /// The fullness of the block in percent that keeps the gas price the same.
+    /// The fuller blocks increase the gas price, the emptier ones decrease it.
+    pub target_block_fullness: Word,
+    /// The gas price changes by at most `1 / max_change_denominator` per block.
+    pub max_change_denominator: Word,
+    /// The number of the recent blocks used to estimate the future gas price.
+    pub history_size: usize,
+}
+
+impl Default for GasPriceConfig {
+    fn default() -> Self {
+        Self {
+            target_block_fullness: 50,
+            max_change_denominator: 8,
+            history_size: 20,
+        }
+    }
+}
+
+/// Tracks the fullness of the imported blocks and the resulting minimum gas price.
+#[derive(Clone, Debug)]
+pub struct GasPriceTracker {
+    min_gas_price: Word,
+    config: Option<GasPriceConfig>,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug)]
+struct State {
+    gas_price: Word,
+    /// The used gas and the gas limit of the recent blocks.
+    recent_blocks: VecDeque<(Word, Word)>,
+}
+
+impl GasPriceTracker {
+    /// Creates the tracker starting from the `min_gas_price`. Without the `config`
+    /// the gas price is static.
+    pub fn new(min_gas_price: Word, config: Option<GasPriceConfig>) -> Self {
+        Self {
+            min_gas_price,
+            config,
+            state: Arc::new(Mutex::new(State {
+                gas_price: min_gas_price,
+                recent_blocks: VecDeque::new(),
+            })),
+        }
+    }
+
+    /// Returns `true` if the gas price follows the fullness of the blocks.
+    pub fn is_dynamic(&self) -> bool {
+        self.config.is_some()
+    }
+
+    /// The minimum gas price of the transactions for the next block.
+    pub fn latest_gas_price(&self) -> Word {
+        self.state.lock().expect("poisoned").gas_price
+    }
+
+    /// Estimates the minimum gas price after `block_horizon` blocks, assuming the
+    /// blocks are as full as the recent ones on average.
+    pub fn estimate_gas_price(&self, block_horizon: u32) -> Word {
+        let state = self.state.lock().expect("poisoned");
+        let config = match &self.config {
+            Some(config) => config,
+            None => return state.gas_price,
+        };
+        let (used, limit) = state.recent_blocks.iter().fold(
+            (0 as Word, 0 as Word),
+            |(used, limit), (block_used, block_limit)| {
+                (
+                    used.saturating_add(*block_used),
+                    limit.saturating_add(*block_limit),
+                )
+            },
+        );
+
+        let mut gas_price = state.gas_price;
+        for _ in 0..block_horizon.min(MAX_BLOCK_HORIZON) {
+            let next = self.next_gas_price(config, gas_price, used, limit);
+            if next == gas_price {
+                break
+            }
+            gas_price = next;
+        }
+        gas_price
+    }
+
+    /// Adjusts the gas price to the gas reserved by the transactions of the committed
+    /// `block` out of the block gas `limit`.
+    pub fn record_committed_block(&self, block: &Block, limit: Word) {
+        self.record_block(gas_used(block), limit)
+    }
+
+    /// Adjusts the gas price to the gas `used` by the block out of its `limit`.
+    pub fn record_block(&self, used: Word, limit: Word) {
+        let config = match &self.config {
+            Some(config) => config,
+            None => return,
+        };
+        let mut state = self.state.lock().expect("poisoned");
+        state.gas_price = self.next_gas_price(config, state.gas_price, used, limit);
+        state.recent_blocks.push_back((used, limit));
+        while state.recent_blocks.len() > config.history_size {
+            state.recent_blocks.pop_front();
+        }
+    }
+
+    fn next_gas_price(
+        &self,
+        config: &GasPriceConfig,
+        gas_price: Word,
+        used: Word,
+        limit: Word,
+    ) -> Word {
+        let target = limit as u128 * config.target_block_fullness as u128 / 100;
+        if target == 0 || config.max_change_denominator == 0 {
+            return gas_price
+        }
+        let used = used as u128;
+        let change = |difference: u128| -> Word {
+            let change = gas_price as u128 * difference
+                / target
+                / config.max_change_denominator as u128;
+            Word::try_from(change).unwrap_or(Word::MAX)
+        };
+
+        if used > target {
+            // The price should grow even if it is zero.
+            gas_price.saturating_add(change(used - target).max(1))
+        } else {
+            gas_price
+                .saturating_sub(change(target - used))
+                .max(self.min_gas_price)
+        }
+    }
+}
+
+/// The gas reserved by the transactions of the `block`. It is what the block
+/// producer fills up to the block gas limit.
+fn gas_used(block: &Block) -> Word {
+    block
+        .transactions()
+        .iter()
+        .map(|tx| match tx {
+            Transaction::Script(script) => *script.gas_limit(),
+            Transaction::Create(create) => *create.gas_limit(),
+            Transaction::Mint(_) => 0,
+        })
+        .fold(0, Word::saturating_add)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tracker(min_gas_price: Word) -> GasPriceTracker {
+        GasPriceTracker::new(min_gas_price, Some(GasPriceConfig::default()))
+    }
+
+    #[test]
+    fn full_blocks_increase_gas_price() {
+        let tracker = tracker(800);
+
+        tracker.record_block(1000, 1000);
+
+        // The block is twice as full as the target, so the price grows by 1/8
+        assert_eq!(tracker.latest_gas_price(), 900);
+    }
+
+    #[test]
+    fn empty_blocks_decrease_gas_price_down_to_minimum() {
+        let tracker = tracker(800);
+        tracker.record_block(1000, 1000);
+        tracker.record_block(1000, 1000);
+        assert_eq!(tracker.latest_gas_price(), 1012);
+
+        tracker.record_block(0, 1000);
+        assert_eq!(tracker.latest_gas_price(), 886);
+        tracker.record_block(0, 1000);
+        tracker.record_block(0, 1000);
+        assert_eq!(tracker.latest_gas_price(), 800);
+    }
+
+    #[test]
+    fn gas_price_grows_from_zero() {
+        let tracker = tracker(0);
+
+        tracker.record_block(600, 1000);
+
+        assert_eq!(tracker.latest_gas_price(), 1);
+    }
+
+    #[test]
+    fn target_fullness_keeps_gas_price() {
+        let tracker = tracker(800);
+
+        tracker.record_block(500, 1000);
+
+        assert_eq!(tracker.latest_gas_price(), 800);
+        assert_eq!(tracker.estimate_gas_price(10), 800);
+    }
+
+    #[test]
+    fn estimation_follows_recent_fullness() {
+        let tracker = tracker(800);
+        tracker.record_block(1000, 1000);
+        tracker.record_block(1000, 1000);
+
+        assert_eq!(tracker.estimate_gas_price(0), 1012);
+        assert_eq!(tracker.estimate_gas_price(1), 1138);
+        assert!(tracker.estimate_gas_price(5) > tracker.estimate_gas_price(1));
+    }
+
+    #[test]
+    fn static_gas_price_ignores_blocks() {
+        let tracker = GasPriceTracker::new(800, None);
+
+        tracker.record_block(1000, 1000);
+
+        assert_eq!(tracker.latest_gas_price(), 800);
+        assert_eq!(tracker.estimate_gas_price(10), 800);
+    }
+}
@@ -4,10 +4,20 @@
 
 pub mod block_producer;
 pub mod config;
+pub mod gas_price;
 pub mod ports;
+pub mod tx_selector;
 
 pub use block_producer::Producer;
 pub use config::Config;
+pub use gas_price::{
+    GasPriceConfig,
+    GasPriceTracker,
+};
+pub use tx_selector::{
+    TxSelection,
+    TxSelector,
+};
 
 #[cfg(any(test, feature = "test-helpers"))]
 pub mod mocks;
@@ -1,8 +1,11 @@
-use crate::ports::{
-    BlockProducerDatabase,
-    Executor,
-    Relayer,
-    TxPool,
+use crate::{
+    ports::{
+        BlockProducerDatabase,
+        Executor,
+        Relayer,
+        TxPool,
+    },
+    tx_selector::TxSelection,
 };
 use fuel_core_storage::{
     not_found,
@@ -34,6 +37,8 @@ use fuel_core_types::{
             Error as ExecutorError,
             ExecutionResult,
             Result as ExecutorResult,
+            StateOverrides,
+            TransactionTrace,
             UncommittedResult,
         },
         txpool::ArcPoolTx,
@@ -74,7 +79,11 @@ pub struct MockTxPool(pub Vec<ArcPoolTx>);
 impl TxPool for MockTxPool {
     type TxSource = Vec<ArcPoolTx>;
 
-    fn get_source(&self, _: BlockHeight) -> Self::TxSource {
+    fn get_source(&self, _: BlockHeight, _: TxSelection) -> Self::TxSource {
+        self.0.clone()
+    }
+
+    fn get_dry_run_source(&self, _: BlockHeight, _: TxSelection) -> Self::TxSource {
         self.0.clone()
     }
 }
@@ -162,9 +171,19 @@ impl Executor for MockExecutor {
         &self,
         _block: Components<fuel_tx::Transaction>,
         _utxo_validation: Option<bool>,
+        _overrides: StateOverrides,
     ) -> ExecutorResult<Vec<Vec<Receipt>>> {
         Ok(Default::default())
     }
+
+    fn trace(
+        &self,
+        _block: Components<fuel_tx::Transaction>,
+        _utxo_validation: Option<bool>,
+        _full_opcode_stream: bool,
+    ) -> ExecutorResult<TransactionTrace> {
+        Ok(Default::default())
+    }
 }
 
 pub struct FailingMockExecutor(pub Mutex<Option<ExecutorError>>);
@@ -199,6 +218,7 @@ impl Executor for FailingMockExecutor {
         &self,
         _block: Components<fuel_tx::Transaction>,
         _utxo_validation: Option<bool>,
+        _overrides: StateOverrides,
     ) -> ExecutorResult<Vec<Vec<Receipt>>> {
         let mut err = self.0.lock().unwrap();
         if let Some(err) = err.take() {
@@ -207,6 +227,20 @@ impl Executor for FailingMockExecutor {
             Ok(Default::default())
         }
     }
+
+    fn trace(
+        &self,
+        _block: Components<fuel_tx::Transaction>,
+        _utxo_validation: Option<bool>,
+        _full_opcode_stream: bool,
+    ) -> ExecutorResult<TransactionTrace> {
+        let mut err = self.0.lock().unwrap();
+        if let Some(err) = err.take() {
+            Err(err)
+        } else {
+            Ok(Default::default())
+        }
+    }
 }
 
 #[derive(Clone, Default, Debug)]
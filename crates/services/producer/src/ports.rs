@@ -1,3 +1,4 @@
+use crate::tx_selector::TxSelection;
 use async_trait::async_trait;
 use fuel_core_storage::{
     transactional::StorageTransaction,
@@ -18,6 +19,8 @@ use fuel_core_types::{
         block_producer::Components,
         executor::{
             Result as ExecutorResult,
+            StateOverrides,
+            TransactionTrace,
             UncommittedResult,
         },
     },
@@ -45,6 +48,15 @@ pub trait TxPool: Send + Sync {
         &self,
         // could be used by the txpool to filter txs based on maturity
         block_height: BlockHeight,
+        // the order of the transactions in the block
+        selection: TxSelection,
+    ) -> Self::TxSource;
+
+    /// Returns the source of includable transactions that leaves them in the pool.
+    fn get_dry_run_source(
+        &self,
+        block_height: BlockHeight,
+        selection: TxSelection,
     ) -> Self::TxSource;
 }
 
@@ -73,10 +85,21 @@ pub trait Executor: Send + Sync {
 
     /// Executes the block without committing it to the database. During execution collects the
     /// receipts to return them. The `utxo_validation` field can be used to disable the validation
-    /// of utxos during execution.
+    /// of utxos during execution. The `overrides` are applied to the state before the execution
+    /// and are never persisted.
     fn dry_run(
         &self,
         block: Components<Transaction>,
         utxo_validation: Option<bool>,
+        overrides: StateOverrides,
     ) -> ExecutorResult<Vec<Vec<Receipt>>>;
+
+    /// Executes the transaction like `dry_run` and collects the trace of its execution.
+    /// The trace includes every executed instruction if `full_opcode_stream` is set.
+    fn trace(
+        &self,
+        block: Components<Transaction>,
+        utxo_validation: Option<bool>,
+        full_opcode_stream: bool,
+    ) -> ExecutorResult<TransactionTrace>;
 }
@@ -0,0 +1,385 @@
+//! The strategies defining the order in which the transactions from the
+//! transaction pool are packed into the block.
+
+use fuel_core_types::{
+    fuel_tx::{
+        input::contract::Contract,
+        Input,
+        Output,
+        TxId,
+    },
+    fuel_types::{
+        Address,
+        ContractId,
+    },
+    services::txpool::ArcPoolTx,
+};
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+        VecDeque,
+    },
+    fmt,
+    sync::Arc,
+    time::Duration,
+};
+
+/// The includable transaction from the transaction pool.
+#[derive(Debug, Clone)]
+pub struct TxCandidate {
+    /// The transaction itself.
+    pub tx: ArcPoolTx,
+    /// The time since the `UNIX_EPOCH` when the transaction was submitted to the pool.
+    pub submitted_time: Duration,
+}
+
+/// Defines the priority of the transactions during block building.
+///
+/// The implementation doesn't need to care about the dependencies between
+/// transactions. The parents are always included before their children, and the
+/// transactions depending on the dropped ones are dropped too.
+pub trait TxSelector: Send + Sync {
+    /// Returns the `candidates` in the order of the inclusion, the most preferred first.
+    /// The candidates omitted from the result are not included into the block.
+    ///
+    /// The `candidates` come sorted by the gas price, highest first.
+    fn order(&self, candidates: Vec<TxCandidate>) -> Vec<TxCandidate>;
+}
+
+/// Prefers the transactions with the highest gas price.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HighestGasPrice;
+
+impl TxSelector for HighestGasPrice {
+    fn order(&self, candidates: Vec<TxCandidate>) -> Vec<TxCandidate> {
+        candidates
+    }
+}
+
+/// Prefers the transactions that were submitted to the pool earlier.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OldestFirst;
+
+impl TxSelector for OldestFirst {
+    fn order(&self, mut candidates: Vec<TxCandidate>) -> Vec<TxCandidate> {
+        candidates.sort_by_key(|candidate| candidate.submitted_time);
+        candidates
+    }
+}
+
+/// Takes the transactions of the owners in turns, so a single owner can't fill
+/// the whole block while others are waiting. The transactions of one owner keep
+/// the gas price order, and the owners are served in the order of their best
+/// transaction.
+///
+/// The owner of the transaction is the owner of its first coin or message input.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OwnerRoundRobin;
+
+impl TxSelector for OwnerRoundRobin {
+    fn order(&self, candidates: Vec<TxCandidate>) -> Vec<TxCandidate> {
+        let total = candidates.len();
+        let mut owners: Vec<Option<Address>> = Vec::new();
+        let mut by_owner: HashMap<Option<Address>, VecDeque<TxCandidate>> =
+            HashMap::new();
+        for candidate in candidates {
+            let owner = candidate
+                .tx
+                .inputs()
+                .iter()
+                .find_map(Input::input_owner)
+                .copied();
+            let queue = by_owner.entry(owner).or_insert_with(|| {
+                owners.push(owner);
+                VecDeque::new()
+            });
+            queue.push_back(candidate);
+        }
+
+        let mut ordered = Vec::with_capacity(total);
+        while ordered.len() < total {
+            for owner in owners.iter() {
+                if let Some(candidate) =
+                    by_owner.get_mut(owner).and_then(VecDeque::pop_front)
+                {
+                    ordered.push(candidate);
+                }
+            }
+        }
+        ordered
+    }
+}
+
+/// The transaction selection strategy used by the block producer.
+#[derive(Clone, Default)]
+pub enum TxSelection {
+    /// See [`HighestGasPrice`].
+    #[default]
+    HighestGasPrice,
+    /// See [`OldestFirst`].
+    OldestFirst,
+    /// See [`OwnerRoundRobin`].
+    OwnerRoundRobin,
+    /// The strategy provided by the user of the block producer.
+    Custom(Arc<dyn TxSelector>),
+}
+
+impl fmt::Debug for TxSelection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TxSelection::HighestGasPrice => f.write_str("HighestGasPrice"),
+            TxSelection::OldestFirst => f.write_str("OldestFirst"),
+            TxSelection::OwnerRoundRobin => f.write_str("OwnerRoundRobin"),
+            TxSelection::Custom(_) => f.write_str("Custom"),
+        }
+    }
+}
+
+impl TxSelector for TxSelection {
+    fn order(&self, candidates: Vec<TxCandidate>) -> Vec<TxCandidate> {
+        match self {
+            TxSelection::HighestGasPrice => HighestGasPrice.order(candidates),
+            TxSelection::OldestFirst => OldestFirst.order(candidates),
+            TxSelection::OwnerRoundRobin => OwnerRoundRobin.order(candidates),
+            TxSelection::Custom(selector) => selector.order(candidates),
+        }
+    }
+}
+
+/// Orders the `candidates` with the `selector` and returns the transactions
+/// to include, where each transaction goes after the transactions it depends on.
+pub fn order_transactions(
+    selector: &dyn TxSelector,
+    candidates: Vec<TxCandidate>,
+) -> Vec<ArcPoolTx> {
+    let pool_txs: HashSet<TxId> = candidates
+        .iter()
+        .map(|candidate| candidate.tx.id())
+        .collect();
+    let contract_creators: HashMap<ContractId, TxId> = candidates
+        .iter()
+        .flat_map(|candidate| {
+            let tx_id = candidate.tx.id();
+            candidate
+                .tx
+                .outputs()
+                .iter()
+                .filter_map(move |output| match output {
+                    Output::ContractCreated { contract_id, .. } => {
+                        Some((*contract_id, tx_id))
+                    }
+                    _ => None,
+                })
+        })
+        .collect();
+
+    let ordered = selector.order(candidates);
+    let mut ordering = Ordering {
+        selected: ordered
+            .iter()
+            .map(|candidate| (candidate.tx.id(), candidate.tx.clone()))
+            .collect(),
+        pool_txs,
+        contract_creators,
+        visited: HashMap::new(),
+        sorted: Vec::with_capacity(ordered.len()),
+    };
+    for candidate in ordered {
+        ordering.push_with_ancestors(&candidate.tx);
+    }
+    ordering.sorted
+}
+
+struct Ordering {
+    selected: HashMap<TxId, ArcPoolTx>,
+    pool_txs: HashSet<TxId>,
+    contract_creators: HashMap<ContractId, TxId>,
+    /// Whether the visited transaction is included.
+    visited: HashMap<TxId, bool>,
+    sorted: Vec<ArcPoolTx>,
+}
+
+impl Ordering {
+    /// Pushes the not yet visited ancestors of the `tx` to the `sorted`, followed by
+    /// the `tx` itself. The `tx` is skipped if any of its ancestors was not selected.
+    fn push_with_ancestors(&mut self, tx: &ArcPoolTx) -> bool {
+        let tx_id = tx.id();
+        if let Some(included) = self.visited.get(&tx_id) {
+            return *included
+        }
+
+        let mut included = true;
+        for parent in self.parents(tx) {
+            included &= match self.selected.get(&parent).cloned() {
+                Some(parent) => self.push_with_ancestors(&parent),
+                None => false,
+            };
+        }
+        self.visited.insert(tx_id, included);
+        if included {
+            self.sorted.push(tx.clone());
+        }
+        included
+    }
+
+    /// The transactions from the pool that the `tx` depends on.
+    fn parents(&self, tx: &ArcPoolTx) -> Vec<TxId> {
+        tx.inputs()
+            .iter()
+            .filter_map(|input| match input {
+                Input::Contract(Contract { contract_id, .. }) => {
+                    self.contract_creators.get(contract_id).copied()
+                }
+                _ => input
+                    .utxo_id()
+                    .map(|utxo_id| *utxo_id.tx_id())
+                    .filter(|tx_id| self.pool_txs.contains(tx_id)),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::{
+        fuel_asm::{
+            op,
+            RegId,
+        },
+        fuel_crypto::SecretKey,
+        fuel_tx::{
+            TransactionBuilder,
+            UtxoId,
+        },
+        fuel_vm::checked_transaction::builder::TransactionBuilderExt,
+    };
+    use rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    };
+
+    fn candidate(
+        owner: &SecretKey,
+        utxo_id: UtxoId,
+        gas_price: u64,
+        submitted_secs: u64,
+    ) -> TxCandidate {
+        let tx = TransactionBuilder::script(
+            vec![op::ret(RegId::ONE)].into_iter().collect(),
+            vec![],
+        )
+        .gas_price(gas_price)
+        .gas_limit(1000)
+        .add_unsigned_coin_input(
+            *owner,
+            utxo_id,
+            1_000_000,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::coin(Default::default(), 1_000, Default::default()))
+        .finalize_checked_basic(Default::default())
+        .into();
+        TxCandidate {
+            tx: Arc::new(tx),
+            submitted_time: Duration::from_secs(submitted_secs),
+        }
+    }
+
+    fn ids(txs: &[ArcPoolTx]) -> Vec<TxId> {
+        txs.iter().map(|tx| tx.id()).collect()
+    }
+
+    fn candidate_ids(candidates: &[TxCandidate]) -> Vec<TxId> {
+        candidates
+            .iter()
+            .map(|candidate| candidate.tx.id())
+            .collect()
+    }
+
+    #[test]
+    fn oldest_first_sorts_by_submitted_time() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let owner = SecretKey::random(&mut rng);
+        let candidates = vec![
+            candidate(&owner, rng.gen(), 3, 20),
+            candidate(&owner, rng.gen(), 2, 10),
+            candidate(&owner, rng.gen(), 1, 30),
+        ];
+        let expected = vec![
+            candidates[1].tx.id(),
+            candidates[0].tx.id(),
+            candidates[2].tx.id(),
+        ];
+
+        let ordered = TxSelection::OldestFirst.order(candidates);
+
+        assert_eq!(candidate_ids(&ordered), expected);
+    }
+
+    #[test]
+    fn owner_round_robin_takes_owners_in_turns() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let alice = SecretKey::random(&mut rng);
+        let bob = SecretKey::random(&mut rng);
+        let carol = SecretKey::random(&mut rng);
+        let candidates = vec![
+            candidate(&alice, rng.gen(), 10, 0),
+            candidate(&alice, rng.gen(), 9, 0),
+            candidate(&alice, rng.gen(), 8, 0),
+            candidate(&bob, rng.gen(), 7, 0),
+            candidate(&carol, rng.gen(), 6, 0),
+        ];
+        let expected = vec![
+            candidates[0].tx.id(),
+            candidates[3].tx.id(),
+            candidates[4].tx.id(),
+            candidates[1].tx.id(),
+            candidates[2].tx.id(),
+        ];
+
+        let ordered = TxSelection::OwnerRoundRobin.order(candidates);
+
+        assert_eq!(candidate_ids(&ordered), expected);
+    }
+
+    #[test]
+    fn order_transactions_puts_parents_before_children() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let owner = SecretKey::random(&mut rng);
+        let parent = candidate(&owner, rng.gen(), 1, 20);
+        let child = candidate(&owner, UtxoId::new(parent.tx.id(), 0), 1, 10);
+        let expected = vec![parent.tx.id(), child.tx.id()];
+
+        let ordered = order_transactions(&TxSelection::OldestFirst, vec![parent, child]);
+
+        assert_eq!(ids(&ordered), expected);
+    }
+
+    struct DropFirst;
+
+    impl TxSelector for DropFirst {
+        fn order(&self, candidates: Vec<TxCandidate>) -> Vec<TxCandidate> {
+            candidates.into_iter().skip(1).collect()
+        }
+    }
+
+    #[test]
+    fn order_transactions_drops_children_of_dropped_parents() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let owner = SecretKey::random(&mut rng);
+        let parent = candidate(&owner, rng.gen(), 3, 0);
+        let child = candidate(&owner, UtxoId::new(parent.tx.id(), 0), 2, 0);
+        let unrelated = candidate(&owner, rng.gen(), 1, 0);
+        let expected = vec![unrelated.tx.id()];
+        let selection = TxSelection::Custom(Arc::new(DropFirst));
+
+        let ordered = order_transactions(&selection, vec![parent, child, unrelated]);
+
+        assert_eq!(ids(&ordered), expected);
+    }
+}
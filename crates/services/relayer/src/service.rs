@@ -62,6 +62,8 @@ mod test;
 
 type Synced = watch::Receiver<Option<DaBlockHeight>>;
 type NotifySynced = watch::Sender<Option<DaBlockHeight>>;
+type RemoteFinalized = watch::Receiver<Option<DaBlockHeight>>;
+type NotifyRemoteFinalized = watch::Sender<Option<DaBlockHeight>>;
 
 /// The alias of runnable relayer service.
 pub type Service<D> = CustomizableService<Provider<Http>, D>;
@@ -72,6 +74,8 @@ type CustomizableService<P, D> = ServiceRunner<NotInitializedTask<P, D>>;
 pub struct SharedState<D> {
     /// Receives signals when the relayer reaches consistency with the DA layer.
     synced: Synced,
+    /// Receives the latest finalized height of the DA layer seen by the relayer.
+    remote_finalized: RemoteFinalized,
     database: D,
 }
 
@@ -79,6 +83,8 @@ pub struct SharedState<D> {
 pub struct NotInitializedTask<P, D> {
     /// Sends signals when the relayer reaches consistency with the DA layer.
     synced: NotifySynced,
+    /// Sends the latest finalized height of the DA layer.
+    remote_finalized: NotifyRemoteFinalized,
     /// The node that communicates with Ethereum.
     eth_node: P,
     /// The fuel database.
@@ -91,6 +97,8 @@ pub struct NotInitializedTask<P, D> {
 pub struct Task<P, D> {
     /// Sends signals when the relayer reaches consistency with the DA layer.
     synced: NotifySynced,
+    /// Sends the latest finalized height of the DA layer.
+    remote_finalized: NotifyRemoteFinalized,
     /// The node that communicates with Ethereum.
     eth_node: P,
     /// The fuel database.
@@ -106,8 +114,10 @@ impl<P, D> NotInitializedTask<P, D> {
     /// Create a new relayer task.
     fn new(eth_node: P, database: D, config: Config) -> Self {
         let (synced, _) = watch::channel(None);
+        let (remote_finalized, _) = watch::channel(None);
         Self {
             synced,
+            remote_finalized,
             eth_node,
             database,
             config,
@@ -164,6 +174,8 @@ where
     }
 
     fn update_synced(&self, state: &state::EthState) {
+        self.remote_finalized
+            .send_replace(Some(state.remote_finalized().into()));
         update_synced(&self.synced, state)
     }
 }
@@ -182,9 +194,11 @@ where
 
     fn shared_data(&self) -> Self::SharedData {
         let synced = self.synced.subscribe();
+        let remote_finalized = self.remote_finalized.subscribe();
 
         SharedState {
             synced,
+            remote_finalized,
             database: self.database.clone(),
         }
     }
@@ -197,12 +211,14 @@ where
         let shutdown = watcher.clone();
         let NotInitializedTask {
             synced,
+            remote_finalized,
             eth_node,
             database,
             config,
         } = self;
         let mut task = Task {
             synced,
+            remote_finalized,
             eth_node,
             database,
             config,
@@ -306,6 +322,17 @@ impl<D> SharedState<D> {
     {
         Ok(self.database.get_finalized_da_height()?)
     }
+
+    /// Returns the number of the finalized blocks of the DA layer not synced by the
+    /// relayer yet, or `None` if the relayer hasn't reached the DA node yet.
+    pub fn da_lag(&self) -> Option<u64>
+    where
+        D: RelayerDb + 'static,
+    {
+        let remote = (*self.remote_finalized.borrow())?;
+        let local = self.database.get_finalized_da_height().ok()?;
+        Some((*remote).saturating_sub(*local))
+    }
 }
 
 #[async_trait]
@@ -3,7 +3,10 @@
 //! importing blocks from the network into the local blockchain.
 
 use std::{
-    ops::RangeInclusive,
+    ops::{
+        Range,
+        RangeInclusive,
+    },
     sync::Arc,
 };
 
@@ -23,6 +26,8 @@ use fuel_core_types::{
     services::p2p::SourcePeer,
 };
 use futures::{
+    channel::mpsc,
+    future::Either,
     stream::{
         self,
         StreamExt,
@@ -47,7 +52,10 @@ use crate::{
 };
 
 #[cfg(test)]
-pub(crate) use tests::empty_header;
+pub(crate) use tests::{
+    empty_header,
+    empty_headers,
+};
 
 #[cfg(test)]
 mod tests;
@@ -62,6 +70,17 @@ pub struct Config {
     pub max_get_header_requests: usize,
     /// The maximum number of get transaction requests to make in a single batch.
     pub max_get_txns_requests: usize,
+    /// The number of headers requested from a single peer in one request.
+    pub header_batch_size: u32,
+    /// The number of downloaded blocks buffered ahead of the execution.
+    /// The download continues while the blocks are executed.
+    pub block_stream_buffer_size: usize,
+    /// The maximum number of local blocks that can be rolled back
+    /// to switch to the competing chain of the peers.
+    pub max_reorg_depth: u32,
+    /// Import only the headers of the blocks without the transactions
+    /// and execution. It is used by the light client.
+    pub headers_only: bool,
 }
 
 impl Default for Config {
@@ -69,6 +88,10 @@ impl Default for Config {
         Self {
             max_get_header_requests: 10,
             max_get_txns_requests: 10,
+            header_batch_size: 10,
+            block_stream_buffer_size: 10,
+            max_reorg_depth: 10,
+            headers_only: false,
         }
     }
 }
@@ -127,7 +150,11 @@ where
         // If there is a range to process, launch the stream.
         if let Some(range) = self.state.apply(|s| s.process_range()) {
             // Launch the stream to import the range.
-            let (count, result) = self.launch_stream(range.clone(), shutdown).await;
+            let (count, result) = if self.params.headers_only {
+                self.launch_header_stream(range.clone(), shutdown).await
+            } else {
+                self.launch_stream(range.clone(), shutdown).await
+            };
 
             // Get the size of the range.
             let range_len = range.size_hint().0 as u32;
@@ -136,13 +163,86 @@ where
             if (count as u32) < range_len {
                 let range = (*range.start() + count as u32)..=*range.end();
                 tracing::error!("Failed to import range of blocks: {:?}", range);
-                self.state.apply(|s| s.failed_to_process(range));
+                self.state.apply(|s| s.failed_to_process(range.clone()));
+
+                // None of the blocks could be imported on top of the local chain,
+                // it may have forked from the chain of the peers.
+                if count == 0
+                    && self
+                        .rollback_fork(*range.start(), *range.end())
+                        .await
+                        .trace_err("Failed to roll back the fork")
+                        .unwrap_or(false)
+                {
+                    self.notify.notify_one();
+                    return Ok(())
+                }
             }
             result?;
         }
         Ok(())
     }
 
+    #[tracing::instrument(skip(self))]
+    /// Checks whether the local chain forked from the chain of the peers
+    /// below the `next` height. If the peers agree with the local chain at
+    /// a height within the `max_reorg_depth`, the local blocks above it are
+    /// rolled back, so the competing chain up to the `observed` height can be imported.
+    ///
+    /// Returns true if the local blocks were rolled back.
+    async fn rollback_fork(&self, next: u32, observed: u32) -> anyhow::Result<bool> {
+        let depth = self.params.max_reorg_depth;
+        let committed = match next.checked_sub(1) {
+            Some(committed) if depth > 0 => committed,
+            _ => return Ok(false),
+        };
+        let lowest = committed.saturating_sub(depth);
+        let headers = match self.p2p.get_sealed_block_headers(lowest..next).await? {
+            Some(headers) => headers.data,
+            None => return Ok(false),
+        };
+
+        // The highest block the peers agree on is the common ancestor.
+        let mut common_ancestor = None;
+        for header in headers.iter().rev() {
+            let height = *header.entity.height();
+            if self.executor.committed_block_id(height)? == Some(header.entity.id()) {
+                common_ancestor = Some(height);
+                break
+            }
+        }
+        let common_ancestor = match common_ancestor {
+            Some(height) if *height < committed => height,
+            // The peers agree with the latest local block, it is not a fork.
+            Some(_) => return Ok(false),
+            None => {
+                tracing::warn!(
+                    "The local chain forked from the peers more than {} blocks ago",
+                    depth
+                );
+                return Ok(false)
+            }
+        };
+
+        // Only a valid competing chain can replace the local blocks.
+        for header in headers
+            .iter()
+            .filter(|header| *header.entity.height() > common_ancestor)
+        {
+            if !self.consensus.check_sealed_header(header)? {
+                tracing::warn!("Header {:?} of the fork failed consensus check", header);
+                return Ok(false)
+            }
+        }
+
+        self.executor.rollback_to(common_ancestor).await?;
+        self.state.apply(|s| {
+            s.rollback(*common_ancestor);
+            s.observe(observed);
+        });
+        Ok(true)
+    }
+
     #[tracing::instrument(skip(self, shutdown))]
     /// Launches a stream to import and execute a range of blocks.
     ///
@@ -150,6 +250,10 @@ where
     /// an error occurs.
     /// If an error occurs, the preceding blocks still be processed
     /// and the error will be returned.
+    ///
+    /// The download and the execution are pipelined: up to
+    /// `block_stream_buffer_size` downloaded blocks wait for the execution
+    /// while the next blocks are downloaded.
     async fn launch_stream(
         &self,
         range: RangeInclusive<u32>,
@@ -163,8 +267,11 @@ where
             consensus,
             ..
         } = &self;
-        // Request up to `max_get_header_requests` headers from the network.
-        get_header_range_buffered(range.clone(), params, p2p.clone())
+        let (block_sender, block_receiver) =
+            mpsc::channel(params.block_stream_buffer_size);
+
+        // Request up to `max_get_header_requests` header batches from the network.
+        let download = get_header_range_buffered(range.clone(), params, p2p.clone())
         .map({
             let p2p = p2p.clone();
             let consensus_port = consensus.clone();
@@ -216,6 +323,11 @@ where
                 tracing::info!("In progress import stream shutting down");
             }
         })
+        // Pass the downloaded blocks to the execution.
+        .map(Ok::<_, mpsc::SendError>)
+        .forward(block_sender);
+
+        let execution = block_receiver
         .then({
             let state = state.clone();
             let executor = executor.clone();
@@ -241,6 +353,102 @@ where
         // Count the number of successfully executed blocks and
         // find any errors.
         // Fold the stream into a count and any errors.
+        .fold((0usize, Ok(())), |(count, err), result| async move {
+            match result {
+                Ok(_) => (count + 1, err),
+                Err(e) => (count, Err(e)),
+            }
+        });
+
+        futures::pin_mut!(download);
+        futures::pin_mut!(execution);
+        match futures::future::select(download, execution)
+            .in_current_span()
+            .await
+        {
+            // All blocks are downloaded, execute the buffered ones.
+            Either::Left((_, execution)) => execution.in_current_span().await,
+            // The execution stopped on an error, the download is cancelled.
+            Either::Right((result, _)) => result,
+        }
+    }
+}
+
+impl<P, E, C> Import<P, E, C>
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+    E: BlockImporterPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+{
+    #[tracing::instrument(skip(self, shutdown))]
+    /// Launches a stream to import a range of headers without
+    /// the transactions of the blocks.
+    ///
+    /// This stream will commit all headers up to the given range or
+    /// an error occurs.
+    /// If an error occurs, the preceding headers still be committed
+    /// and the error will be returned.
+    async fn launch_header_stream(
+        &self,
+        range: RangeInclusive<u32>,
+        shutdown: &StateWatcher,
+    ) -> (usize, anyhow::Result<()>) {
+        let Self {
+            state,
+            params,
+            p2p,
+            executor,
+            consensus,
+            ..
+        } = &self;
+
+        // Request up to `max_get_header_requests` header batches from the network.
+        get_header_range_buffered(range, params, p2p.clone())
+        .then({
+            let state = state.clone();
+            let executor = executor.clone();
+            let consensus_port = consensus.clone();
+            move |result| {
+                let state = state.clone();
+                let executor = executor.clone();
+                let consensus_port = consensus_port.clone();
+                async move {
+                    // Short circuit on error.
+                    let header = match result {
+                        Ok(h) => h.data,
+                        Err(e) => return Err(e),
+                    };
+
+                    // Check the consensus is valid on this header.
+                    if !consensus_port
+                        .check_sealed_header(&header)
+                        .trace_err("Failed to check consensus on header")?
+                    {
+                        tracing::warn!("Header {:?} failed consensus check", header);
+                        return Ok(None)
+                    }
+
+                    commit_header(executor.as_ref(), &state, header)
+                        .await
+                        .map(Some)
+                }
+            }
+            .instrument(tracing::debug_span!("consensus_and_commit_header"))
+            .in_current_span()
+        })
+        // Continue the stream unless an error or none occurs.
+        .into_scan_none_or_err()
+        .scan_none_or_err()
+        // Continue the stream until the shutdown signal is received.
+        .take_until({
+            let mut s = shutdown.clone();
+            async move {
+                let _ = s.while_started().await;
+                tracing::info!("In progress header import stream shutting down");
+            }
+        })
+        // Count the number of successfully committed headers and
+        // find any errors.
         .fold((0usize, Ok(())), |(count, err), result| async move {
             match result {
                 Ok(_) => (count + 1, err),
@@ -270,53 +478,91 @@ async fn wait_for_notify_or_shutdown(
     matches!(r, futures::future::Either::Left(_))
 }
 
-/// Returns a stream of headers processing concurrently up to `max_get_header_requests`.
+/// Returns a stream of headers processing concurrently up to `max_get_header_requests`
+/// batches of `header_batch_size` headers.
 /// The headers are returned in order.
 fn get_header_range_buffered(
     range: RangeInclusive<u32>,
     params: &Config,
     p2p: Arc<impl PeerToPeerPort + Send + Sync + 'static>,
 ) -> impl Stream<Item = anyhow::Result<SourcePeer<SealedBlockHeader>>> {
-    get_header_range(range, p2p)
+    get_header_range(range, params.header_batch_size, p2p)
         .buffered(params.max_get_header_requests)
+        // Split the batches into the headers.
+        .flat_map(stream::iter)
         // Continue the stream unless an error or none occurs.
         .into_scan_none_or_err()
         .scan_none_or_err()
 }
 
 #[tracing::instrument(skip(p2p))]
-/// Returns a stream of network requests for headers.
+/// Returns a stream of network requests for the batches of headers.
+/// Each request results in one item per height of the batch,
+/// `None` if the header is missing or invalid.
 fn get_header_range(
     range: RangeInclusive<u32>,
+    batch_size: u32,
     p2p: Arc<impl PeerToPeerPort + 'static>,
 ) -> impl Stream<
-    Item = impl Future<Output = anyhow::Result<Option<SourcePeer<SealedBlockHeader>>>>,
+    Item = impl Future<Output = Vec<anyhow::Result<Option<SourcePeer<SealedBlockHeader>>>>>,
 > {
-    stream::iter(range).map(move |height| {
+    stream::iter(batches(range, batch_size)).map(move |batch| {
         let p2p = p2p.clone();
-        let height: BlockHeight = height.into();
+        let (start, end) = (batch.start, batch.end);
         async move {
-            tracing::debug!("getting header height: {}", *height);
-            Ok(p2p
-                .get_sealed_block_header(height)
+            tracing::debug!("getting headers of heights: {:?}", batch);
+            let headers = match p2p
+                .get_sealed_block_headers(batch.clone())
                 .await
-                .trace_err("Failed to get header")?
-                .and_then(|header| {
-                    // Check the header is the expected height.
-                    validate_header_height(height, &header.data)
-                        .then_some(header)
-                        .trace_none_error("Failed to validate header height")
+                .trace_err("Failed to get headers")
+            {
+                Ok(headers) => headers,
+                Err(e) => return vec![Err(e)],
+            };
+            let mut headers = headers
+                .map(|SourcePeer { peer_id, data }| {
+                    data.into_iter()
+                        .map(|header| SourcePeer {
+                            peer_id: peer_id.clone(),
+                            data: header,
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default()
+                .into_iter();
+
+            batch
+                .map(|height| {
+                    let height: BlockHeight = height.into();
+                    Ok(headers
+                        .next()
+                        .and_then(|header| {
+                            // Check the header is the expected height.
+                            validate_header_height(height, &header.data)
+                                .then_some(header)
+                                .trace_none_error("Failed to validate header height")
+                        })
+                        .trace_none_warn("Failed to find header"))
                 })
-                .trace_none_warn("Failed to find header"))
+                .collect()
         }
-        .instrument(tracing::debug_span!(
-            "get_sealed_block_header",
-            height = *height
-        ))
+        .instrument(tracing::debug_span!("get_sealed_block_headers", start, end))
         .in_current_span()
     })
 }
 
+/// Splits the `range` into the consecutive ranges of up to `batch_size` heights.
+fn batches(
+    range: RangeInclusive<u32>,
+    batch_size: u32,
+) -> impl Iterator<Item = Range<u32>> {
+    let batch_size = batch_size.max(1);
+    let end = range.end().saturating_add(1);
+    range
+        .step_by(batch_size as usize)
+        .map(move |start| start..start.saturating_add(batch_size).min(end))
+}
+
 /// Returns true if the header is the expected height.
 fn validate_header_height(
     expected_height: BlockHeight,
@@ -391,6 +637,35 @@ where
     r
 }
 
+#[tracing::instrument(
+    skip_all,
+    fields(
+        height = **header.entity.height(),
+        id = %header.entity.consensus.generated.application_hash
+    ),
+    err
+)]
+async fn commit_header<E>(
+    executor: &E,
+    state: &SharedMutex<State>,
+    header: SealedBlockHeader,
+) -> anyhow::Result<()>
+where
+    E: BlockImporterPort + Send + Sync + 'static,
+{
+    // Commit the header without the transactions.
+    let height = *header.entity.height();
+    let r = executor.commit_header(header).await;
+
+    // If the header committed successfully, mark it as committed.
+    if r.is_ok() {
+        state.apply(|s| s.commit(*height));
+    } else {
+        tracing::error!("Commit of the header {} failed: {:?}", *height, r);
+    }
+    r
+}
+
 /// Extra stream utilities.
 trait StreamUtil: Sized {
     /// Turn a stream of `Result<Option<T>>` into a stream of `Result<T>`.
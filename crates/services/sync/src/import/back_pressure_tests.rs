@@ -1,4 +1,7 @@
-use std::time::Duration;
+use std::{
+    ops::Range,
+    time::Duration,
+};
 
 use fuel_core_services::stream::BoxStream;
 use fuel_core_types::{
@@ -17,7 +20,7 @@ use crate::ports::{
 };
 
 use super::{
-    tests::empty_header,
+    tests::empty_headers,
     *,
 };
 use test_case::test_case;
@@ -35,6 +38,10 @@ struct Input {
     Config{
         max_get_header_requests: 1,
         max_get_txns_requests: 1,
+        header_batch_size: 1,
+        block_stream_buffer_size: 1,
+        max_reorg_depth: 0,
+        headers_only: false,
     }
     => Count::default() ; "Empty sanity test"
 )]
@@ -47,6 +54,10 @@ struct Input {
     Config{
         max_get_header_requests: 1,
         max_get_txns_requests: 1,
+        header_batch_size: 1,
+        block_stream_buffer_size: 1,
+        max_reorg_depth: 0,
+        headers_only: false,
     }
     => is less_or_equal_than Count{ headers: 1, consensus: 1, transactions: 1, executes: 1, blocks: 1 }
     ; "Single with slow headers"
@@ -60,8 +71,12 @@ struct Input {
     Config{
         max_get_header_requests: 10,
         max_get_txns_requests: 10,
+        header_batch_size: 1,
+        block_stream_buffer_size: 10,
+        max_reorg_depth: 0,
+        headers_only: false,
     }
-    => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
+    => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 32 }
     ; "100 headers with max 10 with slow headers"
 )]
 #[test_case(
@@ -73,8 +88,12 @@ struct Input {
     Config{
         max_get_header_requests: 10,
         max_get_txns_requests: 10,
+        header_batch_size: 1,
+        block_stream_buffer_size: 10,
+        max_reorg_depth: 0,
+        headers_only: false,
     }
-    => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
+    => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 32 }
     ; "100 headers with max 10 with slow transactions"
 )]
 #[test_case(
@@ -86,10 +105,31 @@ struct Input {
     Config{
         max_get_header_requests: 10,
         max_get_txns_requests: 10,
+        header_batch_size: 1,
+        block_stream_buffer_size: 10,
+        max_reorg_depth: 0,
+        headers_only: false,
     }
-    => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
+    => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 32 }
     ; "50 headers with max 10 with slow executes"
 )]
+#[test_case(
+    Input {
+        executes: Duration::from_millis(10),
+        ..Default::default()
+    },
+    State::new(None, 50),
+    Config{
+        max_get_header_requests: 10,
+        max_get_txns_requests: 10,
+        header_batch_size: 10,
+        block_stream_buffer_size: 10,
+        max_reorg_depth: 0,
+        headers_only: false,
+    }
+    => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 122 }
+    ; "50 headers in batches of 10 with slow executes"
+)]
 #[tokio::test(flavor = "multi_thread")]
 async fn test_back_pressure(input: Input, state: State, params: Config) -> Count {
     let counts = SharedCounts::new(Default::default());
@@ -149,17 +189,18 @@ impl PeerToPeerPort for PressurePeerToPeerPort {
     fn height_stream(&self) -> BoxStream<BlockHeight> {
         self.0.height_stream()
     }
-    async fn get_sealed_block_header(
+    async fn get_sealed_block_headers(
         &self,
-        height: BlockHeight,
-    ) -> anyhow::Result<Option<SourcePeer<SealedBlockHeader>>> {
+        block_height_range: Range<u32>,
+    ) -> anyhow::Result<Option<SourcePeer<Vec<SealedBlockHeader>>>> {
+        let blocks = block_height_range.len();
         self.2.apply(|c| c.inc_headers());
         tokio::time::sleep(self.1[0]).await;
         self.2.apply(|c| {
             c.dec_headers();
-            c.inc_blocks();
+            c.add_blocks(blocks);
         });
-        self.0.get_sealed_block_header(height).await
+        self.0.get_sealed_block_headers(block_height_range).await
     }
     async fn get_transactions(
         &self,
@@ -187,6 +228,18 @@ impl BlockImporterPort for PressureBlockImporterPort {
         });
         self.0.execute_and_commit(block).await
     }
+
+    async fn commit_header(&self, header: SealedBlockHeader) -> anyhow::Result<()> {
+        self.0.commit_header(header).await
+    }
+
+    fn committed_block_id(&self, height: BlockHeight) -> anyhow::Result<Option<BlockId>> {
+        self.0.committed_block_id(height)
+    }
+
+    async fn rollback_to(&self, height: BlockHeight) -> anyhow::Result<()> {
+        self.0.rollback_to(height).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -206,8 +259,8 @@ impl ConsensusPort for PressureConsensusPort {
 impl PressurePeerToPeerPort {
     fn new(counts: SharedCounts, delays: [Duration; 2]) -> Self {
         let mut mock = MockPeerToPeerPort::default();
-        mock.expect_get_sealed_block_header()
-            .returning(|h| Ok(Some(empty_header(h))));
+        mock.expect_get_sealed_block_headers()
+            .returning(|r| Ok(Some(empty_headers(r))));
         mock.expect_get_transactions()
             .returning(|_| Ok(Some(vec![])));
         Self(mock, delays, counts)
@@ -260,8 +313,8 @@ impl Counts {
     fn dec_executes(&mut self) {
         self.now.executes -= 1;
     }
-    fn inc_blocks(&mut self) {
-        self.now.blocks += 1;
+    fn add_blocks(&mut self, blocks: usize) {
+        self.now.blocks += blocks;
         self.max.blocks = self.max.blocks.max(self.now.blocks);
     }
     fn dec_blocks(&mut self) {
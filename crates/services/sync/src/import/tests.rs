@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use fuel_core_types::blockchain::{
     consensus::Consensus,
     header::BlockHeader,
@@ -66,7 +68,7 @@ use super::*;
     State::new(3, 5),
     {
         let mut p2p = MockPeerToPeerPort::default();
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_get_sealed_block_headers()
             .times(1)
             .returning(|_| Ok(None));
         Mocks{
@@ -81,9 +83,9 @@ use super::*;
     State::new(3, 5),
     {
         let mut p2p = MockPeerToPeerPort::default();
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_get_sealed_block_headers()
             .times(2)
-            .returning(|h| Ok((*h != 5).then(|| empty_header(h))));
+            .returning(single_header(|h| Ok((*h != 5).then(|| empty_header(h)))));
         p2p.expect_get_transactions()
             .times(1)
             .returning(|_| Ok(Some(vec![])));
@@ -99,9 +101,9 @@ use super::*;
     State::new(3, 5),
     {
         let mut p2p = MockPeerToPeerPort::default();
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_get_sealed_block_headers()
             .times(1)
-            .returning(|h| Ok((*h != 4).then(|| empty_header(h))));
+            .returning(single_header(|h| Ok((*h != 4).then(|| empty_header(h)))));
         Mocks{
             p2p,
             consensus_port: DefaultMocks::times([0]),
@@ -114,9 +116,9 @@ use super::*;
     State::new(3, 5),
     {
         let mut p2p = MockPeerToPeerPort::default();
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_get_sealed_block_headers()
             .times(2)
-            .returning(|h| Ok(Some(empty_header(h))));
+            .returning(|r| Ok(Some(empty_headers(r))));
         p2p.expect_get_transactions()
             .times(1)
             .returning(|_| Ok(None));
@@ -132,9 +134,9 @@ use super::*;
     State::new(3, 5),
     {
         let mut p2p = MockPeerToPeerPort::default();
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_get_sealed_block_headers()
             .times(2)
-            .returning(|h| Ok(Some(empty_header(h))));
+            .returning(|r| Ok(Some(empty_headers(r))));
         let mut count = 0;
         p2p.expect_get_transactions()
             .times(1)
@@ -158,9 +160,9 @@ use super::*;
     State::new(3, 5),
     {
         let mut p2p = MockPeerToPeerPort::default();
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_get_sealed_block_headers()
             .times(2)
-            .returning(|h| Ok(Some(empty_header(h))));
+            .returning(|r| Ok(Some(empty_headers(r))));
         let mut count = 0;
         p2p.expect_get_transactions()
             .times(2)
@@ -184,7 +186,7 @@ use super::*;
     State::new(3, 5),
     {
         let mut p2p = MockPeerToPeerPort::default();
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_get_sealed_block_headers()
             .times(1)
             .returning(|_| Err(anyhow::anyhow!("Some network error")));
         Mocks{
@@ -199,13 +201,13 @@ use super::*;
     State::new(3, 5),
     {
         let mut p2p = MockPeerToPeerPort::default();
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_get_sealed_block_headers()
             .times(1)
-            .returning(|h| if *h == 4 {
+            .returning(single_header(|h| if *h == 4 {
                 Err(anyhow::anyhow!("Some network error"))
             } else {
                 Ok(Some(empty_header(h)))
-            });
+            }));
         Mocks{
             p2p,
             consensus_port: DefaultMocks::times([0]),
@@ -218,13 +220,13 @@ use super::*;
     State::new(3, 5),
     {
         let mut p2p = MockPeerToPeerPort::default();
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_get_sealed_block_headers()
             .times(2)
-            .returning(|h| if *h == 5 {
+            .returning(single_header(|h| if *h == 5 {
                 Err(anyhow::anyhow!("Some network error"))
             } else {
                 Ok(Some(empty_header(h)))
-            });
+            }));
         p2p.expect_get_transactions()
             .times(1)
             .returning(|_| Ok(Some(vec![])));
@@ -240,9 +242,9 @@ use super::*;
     State::new(3, 5),
     {
         let mut p2p = MockPeerToPeerPort::default();
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_get_sealed_block_headers()
             .times(2)
-            .returning(|h| Ok(Some(empty_header(h))));
+            .returning(|r| Ok(Some(empty_headers(r))));
         p2p.expect_get_transactions()
             .times(1)
             .returning(|_| Err(anyhow::anyhow!("Some network error")));
@@ -258,9 +260,9 @@ use super::*;
     State::new(3, 5),
     {
         let mut p2p = MockPeerToPeerPort::default();
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_get_sealed_block_headers()
             .times(2)
-            .returning(|h| Ok(Some(empty_header(h))));
+            .returning(|r| Ok(Some(empty_headers(r))));
         let mut count = 0;
         p2p.expect_get_transactions()
             .times(1)
@@ -284,9 +286,9 @@ use super::*;
     State::new(3, 5),
     {
         let mut p2p = MockPeerToPeerPort::default();
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_get_sealed_block_headers()
             .times(2)
-            .returning(|h| Ok(Some(empty_header(h))));
+            .returning(|r| Ok(Some(empty_headers(r))));
         let mut count = 0;
         p2p.expect_get_transactions()
             .times(2)
@@ -371,8 +373,8 @@ use super::*;
             .times(1)
             .returning(|_| Err(anyhow::anyhow!("Some execution error")));
         Mocks{
-            consensus_port: DefaultMocks::times([1]),
-            p2p: DefaultMocks::times([2, 1]),
+            consensus_port: DefaultMocks::times([2]),
+            p2p: DefaultMocks::times([2, 2]),
             executor,
         }
     }
@@ -393,8 +395,8 @@ use super::*;
                 }
             });
         Mocks{
-            consensus_port: DefaultMocks::times([1]),
-            p2p: DefaultMocks::times([2, 1]),
+            consensus_port: DefaultMocks::times([2]),
+            p2p: DefaultMocks::times([2, 2]),
             executor,
         }
     }
@@ -425,7 +427,167 @@ use super::*;
 #[tokio::test]
 async fn test_import(state: State, mocks: Mocks) -> (State, bool) {
     let state = SharedMutex::new(state);
-    test_import_inner(state, mocks, None).await
+    test_import_inner(state, mocks, None, test_params()).await
+}
+
+#[test_case(
+    State::new(3, 8),
+    Config {
+        header_batch_size: 3,
+        ..test_params()
+    },
+    {
+        let mut p2p = MockPeerToPeerPort::default();
+        p2p.expect_get_sealed_block_headers()
+            .times(2)
+            .returning(|r| Ok(Some(empty_headers(r))));
+        p2p.expect_get_transactions()
+            .times(5)
+            .returning(|_| Ok(Some(vec![])));
+        Mocks{
+            p2p,
+            consensus_port: DefaultMocks::times([5]),
+            executor: DefaultMocks::times([5])
+        }
+    }
+    => (State::new(8, None), true) ; "executes 4 to 8 in batches of 3"
+)]
+#[test_case(
+    State::new(3, 5),
+    Config {
+        header_batch_size: 2,
+        ..test_params()
+    },
+    {
+        let mut p2p = MockPeerToPeerPort::default();
+        p2p.expect_get_sealed_block_headers()
+            .times(1)
+            .returning(|r| Ok(Some(empty_headers(r.start..r.start + 1))));
+        p2p.expect_get_transactions()
+            .times(1)
+            .returning(|_| Ok(Some(vec![])));
+        Mocks{
+            p2p,
+            consensus_port: DefaultMocks::times([1]),
+            executor: DefaultMocks::times([1])
+        }
+    }
+    => (State::new(4, None), true) ; "batch missing header 5"
+)]
+#[tokio::test]
+async fn test_import_batches(
+    state: State,
+    params: Config,
+    mocks: Mocks,
+) -> (State, bool) {
+    let state = SharedMutex::new(state);
+    test_import_inner(state, mocks, None, params).await
+}
+
+#[test_case(
+    {
+        let mut executor = MockBlockImporterPort::default();
+        executor
+            .expect_commit_header()
+            .times(2)
+            .returning(|_| Ok(()));
+        executor
+    }
+    => (State::new(5, None), true) ; "commits headers 4 to 5"
+)]
+#[test_case(
+    {
+        let mut executor = MockBlockImporterPort::default();
+        executor
+            .expect_commit_header()
+            .times(1)
+            .returning(|_| Err(anyhow::anyhow!("Some commit error")));
+        executor
+    }
+    => (State::new(3, None), false) ; "commit error on header 4"
+)]
+#[tokio::test]
+async fn test_import_headers_only(executor: MockBlockImporterPort) -> (State, bool) {
+    let state = SharedMutex::new(State::new(3, 5));
+    let mut consensus_port = MockConsensusPort::default();
+    consensus_port
+        .expect_check_sealed_header()
+        .returning(|_| Ok(true));
+    // Neither the transactions nor the DA height are required for the headers.
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_block_headers()
+        .returning(|r| Ok(Some(empty_headers(r))));
+    p2p.expect_get_transactions().times(0);
+    let mocks = Mocks {
+        consensus_port,
+        p2p,
+        executor,
+    };
+    let params = Config {
+        headers_only: true,
+        ..test_params()
+    };
+    test_import_inner(state, mocks, None, params).await
+}
+
+#[test_case(
+    {
+        let mut executor = MockBlockImporterPort::default();
+        executor
+            .expect_committed_block_id()
+            .times(2)
+            .returning(|h| Ok((*h <= 2).then(|| empty_header(h).data.entity.id())));
+        executor
+            .expect_rollback_to()
+            .times(1)
+            .withf(|h| **h == 2)
+            .returning(|_| Ok(()));
+        executor
+    }
+    => (State::new(2, 5), true) ; "rolls back the local block 3 of the fork"
+)]
+#[test_case(
+    {
+        let mut executor = MockBlockImporterPort::default();
+        executor
+            .expect_committed_block_id()
+            .times(3)
+            .returning(|_| Ok(None));
+        executor.expect_rollback_to().times(0);
+        executor
+    }
+    => (State::new(3, None), false) ; "fork deeper than the max reorg depth"
+)]
+#[tokio::test]
+async fn test_import_fork(mut executor: MockBlockImporterPort) -> (State, bool) {
+    let state = SharedMutex::new(State::new(3, 5));
+    // The peers' block 4 can't be executed on top of the local block 3.
+    executor
+        .expect_execute_and_commit()
+        .times(1)
+        .returning(|_| Err(anyhow::anyhow!("Wrong previous block")));
+    let mut consensus_port = MockConsensusPort::default();
+    consensus_port
+        .expect_check_sealed_header()
+        .returning(|_| Ok(true));
+    consensus_port
+        .expect_await_da_height()
+        .returning(|_| Ok(()));
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_block_headers()
+        .returning(|r| Ok(Some(empty_headers(r))));
+    p2p.expect_get_transactions()
+        .returning(|_| Ok(Some(vec![])));
+    let mocks = Mocks {
+        consensus_port,
+        p2p,
+        executor,
+    };
+    let params = Config {
+        max_reorg_depth: 2,
+        ..test_params()
+    };
+    test_import_inner(state, mocks, None, params).await
 }
 
 #[test_case(
@@ -433,12 +595,12 @@ async fn test_import(state: State, mocks: Mocks) -> (State, bool) {
         let s = SharedMutex::new(State::new(3, 5));
         let state = s.clone();
         let mut p2p = MockPeerToPeerPort::default();
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_get_sealed_block_headers()
             .times(3)
-            .returning(move |h| {
+            .returning(single_header(move |h| {
                 state.apply(|s| s.observe(6));
                 Ok(Some(empty_header(h)))
-            });
+            }));
         p2p.expect_get_transactions()
             .times(3)
             .returning(move|_| Ok(Some(vec![])));
@@ -455,13 +617,25 @@ async fn test_import(state: State, mocks: Mocks) -> (State, bool) {
 async fn test_import_loop(
     (state, count, mocks): (SharedMutex<State>, Count, Mocks),
 ) -> (State, bool) {
-    test_import_inner(state, mocks, Some(count)).await
+    test_import_inner(state, mocks, Some(count), test_params()).await
+}
+
+fn test_params() -> Config {
+    Config {
+        max_get_header_requests: 10,
+        max_get_txns_requests: 10,
+        header_batch_size: 1,
+        block_stream_buffer_size: 10,
+        max_reorg_depth: 0,
+        headers_only: false,
+    }
 }
 
 async fn test_import_inner(
     state: SharedMutex<State>,
     mocks: Mocks,
     count: Option<Count>,
+    params: Config,
 ) -> (State, bool) {
     let notify = Arc::new(Notify::new());
     let Mocks {
@@ -469,10 +643,6 @@ async fn test_import_inner(
         p2p,
         executor,
     } = mocks;
-    let params = Config {
-        max_get_header_requests: 10,
-        max_get_txns_requests: 10,
-    };
     let p2p = Arc::new(p2p);
 
     let executor = Arc::new(executor);
@@ -577,9 +747,9 @@ impl DefaultMocks for MockPeerToPeerPort {
         let mut p2p = MockPeerToPeerPort::default();
         let mut t = t.into_iter().cycle();
 
-        p2p.expect_get_sealed_block_header()
+        p2p.expect_get_sealed_block_headers()
             .times(t.next().unwrap())
-            .returning(|h| Ok(Some(empty_header(h))));
+            .returning(|r| Ok(Some(empty_headers(r))));
         p2p.expect_get_transactions()
             .times(t.next().unwrap())
             .returning(|_| Ok(Some(vec![])));
@@ -600,6 +770,32 @@ impl DefaultMocks for MockBlockImporterPort {
     }
 }
 
+/// Adapts the mock of a single header to the requests of
+/// the batches of one header.
+fn single_header(
+    mut f: impl FnMut(BlockHeight) -> anyhow::Result<Option<SourcePeer<SealedBlockHeader>>>
+        + Send
+        + 'static,
+) -> impl FnMut(Range<u32>) -> anyhow::Result<Option<SourcePeer<Vec<SealedBlockHeader>>>>
+       + Send
+       + 'static {
+    move |range| {
+        Ok(
+            f(range.start.into())?.map(|SourcePeer { peer_id, data }| SourcePeer {
+                peer_id,
+                data: vec![data],
+            }),
+        )
+    }
+}
+
+pub(crate) fn empty_headers(range: Range<u32>) -> SourcePeer<Vec<SealedBlockHeader>> {
+    SourcePeer {
+        peer_id: vec![].into(),
+        data: range.map(|h| empty_header(h.into()).data).collect(),
+    }
+}
+
 pub(crate) fn empty_header(h: BlockHeight) -> SourcePeer<SealedBlockHeader> {
     let mut header = BlockHeader::default();
     header.consensus.height = h;
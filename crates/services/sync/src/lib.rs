@@ -8,6 +8,7 @@ pub mod import;
 pub mod ports;
 pub mod service;
 mod state;
+mod status;
 pub mod sync;
 mod tracing_helpers;
 
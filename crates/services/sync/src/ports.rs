@@ -1,5 +1,7 @@
 //! Ports this services requires to function.
 
+use std::ops::Range;
+
 use fuel_core_services::stream::BoxStream;
 use fuel_core_types::{
     blockchain::{
@@ -22,14 +24,15 @@ pub trait PeerToPeerPort {
     /// Stream of newly observed block heights.
     fn height_stream(&self) -> BoxStream<BlockHeight>;
 
-    /// Request sealed block header from the network
-    /// at the given height.
+    /// Request sealed block headers from the network
+    /// for the given range of heights.
     ///
-    /// Returns the source peer this header was received from.
-    async fn get_sealed_block_header(
+    /// Returns the source peer these headers were received from.
+    /// The peer may return fewer headers than requested.
+    async fn get_sealed_block_headers(
         &self,
-        height: BlockHeight,
-    ) -> anyhow::Result<Option<SourcePeer<SealedBlockHeader>>>;
+        block_height_range: Range<u32>,
+    ) -> anyhow::Result<Option<SourcePeer<Vec<SealedBlockHeader>>>>;
 
     /// Request transactions from the network for the given block
     /// and source peer.
@@ -59,4 +62,14 @@ pub trait BlockImporterPort {
     /// Execute the given sealed block
     /// and commit it to the database.
     async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()>;
+
+    /// Verify the given sealed block header
+    /// and commit it to the database without the transactions.
+    async fn commit_header(&self, header: SealedBlockHeader) -> anyhow::Result<()>;
+
+    /// Returns the id of the committed block at the given height, if any.
+    fn committed_block_id(&self, height: BlockHeight) -> anyhow::Result<Option<BlockId>>;
+
+    /// Reverts the committed blocks above the given height.
+    async fn rollback_to(&self, height: BlockHeight) -> anyhow::Result<()>;
 }
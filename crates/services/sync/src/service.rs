@@ -13,6 +13,7 @@ use crate::{
         PeerToPeerPort,
     },
     state::State,
+    status::StatusTracker,
     sync::SyncHeights,
 };
 
@@ -28,9 +29,15 @@ use fuel_core_services::{
     SharedMutex,
     StateWatcher,
 };
-use fuel_core_types::fuel_types::BlockHeight;
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    services::sync::SyncStatus,
+};
 use futures::StreamExt;
-use tokio::sync::Notify;
+use tokio::sync::{
+    broadcast,
+    Notify,
+};
 
 #[cfg(test)]
 mod tests;
@@ -51,10 +58,12 @@ where
     let height_stream = p2p.height_stream();
     let committed_height_stream = executor.committed_height_stream();
     let state = State::new(Some(current_fuel_block_height.into()), None);
+    let shared = SharedState::new(current_fuel_block_height);
     Ok(ServiceRunner::new(SyncTask::new(
         height_stream,
         committed_height_stream,
         state,
+        shared,
         params,
         p2p,
         executor,
@@ -62,6 +71,40 @@ where
     )?))
 }
 
+/// The capacity of the sync status broadcast channel.
+const STATUS_CHANNEL_CAPACITY: usize = 100;
+
+/// The sync status shared with other services.
+#[derive(Clone)]
+pub struct SharedState {
+    pub(crate) status: SharedMutex<SyncStatus>,
+    pub(crate) status_sender: broadcast::Sender<SyncStatus>,
+}
+
+impl SharedState {
+    pub(crate) fn new(current_height: BlockHeight) -> Self {
+        let (status_sender, _) = broadcast::channel(STATUS_CHANNEL_CAPACITY);
+        Self {
+            status: SharedMutex::new(SyncStatus {
+                current_height,
+                highest_seen_height: current_height,
+                estimated_time_remaining: Some(Default::default()),
+            }),
+            status_sender,
+        }
+    }
+
+    /// Returns the latest sync status.
+    pub fn status(&self) -> SyncStatus {
+        self.status.apply(|status| *status)
+    }
+
+    /// Subscribes to the changes of the sync status.
+    pub fn subscribe(&self) -> broadcast::Receiver<SyncStatus> {
+        self.status_sender.subscribe()
+    }
+}
+
 /// Task for syncing heights.
 /// Contains import task as a child task.
 pub struct SyncTask<P, E, C>
@@ -71,6 +114,7 @@ where
     C: ConsensusPort + Send + Sync + 'static,
 {
     sync_heights: SyncHeights,
+    shared: SharedState,
     import_task_handle: ServiceRunner<ImportTask<P, E, C>>,
 }
 
@@ -86,6 +130,7 @@ where
         height_stream: BoxStream<BlockHeight>,
         committed_height_stream: BoxStream<BlockHeight>,
         state: State,
+        shared: SharedState,
         params: Config,
         p2p: P,
         executor: E,
@@ -100,12 +145,14 @@ where
             height_stream,
             committed_height_stream,
             state.clone(),
+            StatusTracker::new(shared.clone()),
             notify.clone(),
         );
         let import = Import::new(state, notify, params, p2p, executor, consensus);
         let import_task_handle = ServiceRunner::new(ImportTask(import));
         Ok(Self {
             sync_heights,
+            shared,
             import_task_handle,
         })
     }
@@ -139,12 +186,14 @@ where
 {
     const NAME: &'static str = "fuel-core-sync";
 
-    type SharedData = ();
+    type SharedData = SharedState;
 
     type Task = SyncTask<P, E, C>;
     type TaskParams = ();
 
-    fn shared_data(&self) -> Self::SharedData {}
+    fn shared_data(&self) -> Self::SharedData {
+        self.shared.clone()
+    }
 
     async fn into_task(
         mut self,
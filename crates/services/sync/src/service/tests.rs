@@ -8,7 +8,7 @@ use futures::{
 };
 
 use crate::{
-    import::empty_header,
+    import::empty_headers,
     ports::{
         MockBlockImporterPort,
         MockConsensusPort,
@@ -33,8 +33,8 @@ async fn test_new_service() {
         })
         .into_boxed()
     });
-    p2p.expect_get_sealed_block_header()
-        .returning(|h| Ok(Some(empty_header(h))));
+    p2p.expect_get_sealed_block_headers()
+        .returning(|r| Ok(Some(empty_headers(r))));
     p2p.expect_get_transactions()
         .returning(|_| Ok(Some(vec![])));
     let mut importer = MockBlockImporterPort::default();
@@ -54,6 +54,10 @@ async fn test_new_service() {
     let params = Config {
         max_get_header_requests: 10,
         max_get_txns_requests: 10,
+        header_batch_size: 10,
+        block_stream_buffer_size: 10,
+        max_reorg_depth: 0,
+        headers_only: false,
     };
     let s = new_service(4u32.into(), p2p, importer, consensus, params).unwrap();
 
@@ -188,6 +188,26 @@ impl State {
         self.apply_status(status);
     }
 
+    #[tracing::instrument]
+    /// Record that the committed blocks above the height have been rolled back.
+    /// The rolled back heights have to be processed again.
+    pub fn rollback(&mut self, height: u32) {
+        let next = height.saturating_add(1);
+        let status = match &self.status {
+            // Nothing is committed, so there is nothing to roll back.
+            Status::Uninitialized => None,
+            // The rolled back heights are processed again.
+            Status::Committed(committed) => {
+                (height < *committed).then(|| Status::Processing(next..=*committed))
+            }
+            // The processing range is extended down to the rolled back heights.
+            Status::Processing(range) => {
+                (next < *range.start()).then(|| Status::Processing(next..=*range.end()))
+            }
+        };
+        self.apply_status(status);
+    }
+
     fn apply_status(&mut self, status: Option<Status>) {
         match status {
             Some(s) => {
@@ -113,3 +113,15 @@ fn test_failed(mut state: State, range: RangeInclusive<u32>) -> Status {
     state.failed_to_process(range);
     state.status
 }
+
+#[test_case(State::new(None, None), 0 => Status::Uninitialized)]
+#[test_case(State::new(10, None), 10 => Status::Committed(10))]
+#[test_case(State::new(10, None), 11 => Status::Committed(10))]
+#[test_case(State::new(10, None), 7 => Status::Processing(8..=10))]
+#[test_case(State::new(10, 20), 10 => Status::Processing(11..=20))]
+#[test_case(State::new(10, 20), 7 => Status::Processing(8..=20))]
+#[test_case(State::new(None, 20), 0 => Status::Processing(0..=20))]
+fn test_rollback(mut state: State, height: u32) -> Status {
+    state.rollback(height);
+    state.status
+}
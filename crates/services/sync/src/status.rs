@@ -0,0 +1,94 @@
+//! # Sync status
+//! Tracks the progress of the sync towards the highest height seen in the network.
+
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    services::sync::SyncStatus,
+};
+
+use crate::service::SharedState;
+
+#[cfg(test)]
+mod tests;
+
+/// The weight of the latest import rate sample in the moving average.
+const RATE_SMOOTHING: f64 = 0.2;
+
+/// Updates the [`SyncStatus`] from the committed and observed heights
+/// and broadcasts it to the subscribers when it changes.
+pub(crate) struct StatusTracker {
+    shared: SharedState,
+    /// The latest committed height and the time it was committed.
+    last_commit: Option<(BlockHeight, Instant)>,
+    /// The exponential moving average of the committed blocks per second.
+    blocks_per_second: Option<f64>,
+}
+
+impl StatusTracker {
+    pub(crate) fn new(shared: SharedState) -> Self {
+        Self {
+            shared,
+            last_commit: None,
+            blocks_per_second: None,
+        }
+    }
+
+    /// Records the new committed height at the time `now`.
+    pub(crate) fn commit(&mut self, height: BlockHeight, now: Instant) {
+        if let Some((last_height, last_time)) = self.last_commit {
+            let blocks = (*height).saturating_sub(*last_height);
+            let elapsed = now.saturating_duration_since(last_time).as_secs_f64();
+            if blocks > 0 && elapsed > 0.0 {
+                let sample = blocks as f64 / elapsed;
+                self.blocks_per_second = Some(match self.blocks_per_second {
+                    Some(rate) => rate + RATE_SMOOTHING * (sample - rate),
+                    None => sample,
+                });
+            }
+        }
+        self.last_commit = Some((height, now));
+        self.update(|status| {
+            status.current_height = height;
+            status.highest_seen_height = status.highest_seen_height.max(height);
+        });
+    }
+
+    /// Records the new height observed in the network.
+    pub(crate) fn observe(&mut self, height: BlockHeight) {
+        self.update(|status| {
+            status.highest_seen_height = status.highest_seen_height.max(height);
+        });
+    }
+
+    fn update(&self, f: impl FnOnce(&mut SyncStatus)) {
+        let blocks_per_second = self.blocks_per_second;
+        let status = self.shared.status.apply(|status| {
+            let previous = *status;
+            f(status);
+            status.estimated_time_remaining =
+                estimate_time_remaining(status, blocks_per_second);
+            (*status != previous).then_some(*status)
+        });
+        if let Some(status) = status {
+            // It is fine if nobody is subscribed.
+            let _ = self.shared.status_sender.send(status);
+        }
+    }
+}
+
+fn estimate_time_remaining(
+    status: &SyncStatus,
+    blocks_per_second: Option<f64>,
+) -> Option<Duration> {
+    match status.blocks_remaining() {
+        0 => Some(Duration::ZERO),
+        remaining => blocks_per_second
+            .filter(|rate| *rate > 0.0)
+            .map(|rate| Duration::from_secs_f64(remaining as f64 / rate)),
+    }
+}
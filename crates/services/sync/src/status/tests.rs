@@ -0,0 +1,82 @@
+use super::*;
+
+fn tracker(current_height: u32) -> (StatusTracker, SharedState) {
+    let shared = SharedState::new(current_height.into());
+    (StatusTracker::new(shared.clone()), shared)
+}
+
+#[test]
+fn new_node_is_synced() {
+    let (_, shared) = tracker(10);
+
+    let status = shared.status();
+    assert!(status.is_synced());
+    assert_eq!(status.estimated_time_remaining, Some(Duration::ZERO));
+}
+
+#[test]
+fn estimate_is_unknown_until_the_rate_is_known() {
+    let (mut tracker, shared) = tracker(0);
+    let start = Instant::now();
+
+    tracker.observe(100u32.into());
+    assert_eq!(shared.status().highest_seen_height, 100u32.into());
+    assert_eq!(shared.status().estimated_time_remaining, None);
+
+    tracker.commit(10u32.into(), start);
+    assert_eq!(shared.status().estimated_time_remaining, None);
+
+    tracker.commit(20u32.into(), start + Duration::from_secs(1));
+    assert_eq!(
+        shared.status().estimated_time_remaining,
+        Some(Duration::from_secs(8))
+    );
+}
+
+#[test]
+fn rate_is_smoothed_over_commits() {
+    let (mut tracker, shared) = tracker(0);
+    let start = Instant::now();
+
+    tracker.observe(1000u32.into());
+    tracker.commit(0u32.into(), start);
+    tracker.commit(10u32.into(), start + Duration::from_secs(1));
+    // The second sample is 60 blocks per second.
+    tracker.commit(70u32.into(), start + Duration::from_secs(2));
+
+    let rate = 10.0 + RATE_SMOOTHING * (60.0 - 10.0);
+    assert_eq!(
+        shared.status().estimated_time_remaining,
+        Some(Duration::from_secs_f64(930.0 / rate))
+    );
+}
+
+#[test]
+fn committing_the_highest_seen_height_finishes_sync() {
+    let (mut tracker, shared) = tracker(0);
+    let start = Instant::now();
+
+    tracker.observe(5u32.into());
+    assert!(!shared.status().is_synced());
+
+    tracker.commit(5u32.into(), start);
+    let status = shared.status();
+    assert!(status.is_synced());
+    assert_eq!(status.current_height, 5u32.into());
+    assert_eq!(status.estimated_time_remaining, Some(Duration::ZERO));
+}
+
+#[test]
+fn only_changes_are_broadcast() {
+    let (mut tracker, shared) = tracker(0);
+    let mut receiver = shared.subscribe();
+
+    tracker.observe(5u32.into());
+    tracker.observe(5u32.into());
+    tracker.observe(3u32.into());
+
+    let status = receiver.try_recv().unwrap();
+    assert_eq!(status.highest_seen_height, 5u32.into());
+    assert_eq!(status.estimated_time_remaining, None);
+    assert!(receiver.try_recv().is_err());
+}
@@ -1,7 +1,10 @@
 //! # Sync task
 //! Updates the state from the height stream.
 
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::Instant,
+};
 
 use fuel_core_services::{
     stream::{
@@ -14,7 +17,10 @@ use fuel_core_types::fuel_types::BlockHeight;
 use futures::stream::StreamExt;
 use tokio::sync::Notify;
 
-use crate::state::State;
+use crate::{
+    state::State,
+    status::StatusTracker,
+};
 
 #[cfg(test)]
 mod tests;
@@ -27,6 +33,7 @@ pub(crate) enum IncomingHeight {
 pub(crate) struct SyncHeights {
     height_stream: BoxStream<IncomingHeight>,
     state: SharedMutex<State>,
+    status: StatusTracker,
     notify: Arc<Notify>,
 }
 
@@ -35,6 +42,7 @@ impl SyncHeights {
         height_stream: BoxStream<BlockHeight>,
         committed_height_stream: BoxStream<BlockHeight>,
         state: SharedMutex<State>,
+        status: StatusTracker,
         notify: Arc<Notify>,
     ) -> Self {
         let height_stream = futures::stream::select(
@@ -45,6 +53,7 @@ impl SyncHeights {
         Self {
             height_stream,
             state,
+            status,
             notify,
         }
     }
@@ -57,10 +66,14 @@ impl SyncHeights {
         let state_change = match height {
             IncomingHeight::Committed(height) => {
                 self.state.apply(|s| s.commit(*height));
+                self.status.commit(height, Instant::now());
                 // A new committed height doesn't represent new work for the import stream.
                 false
             }
-            IncomingHeight::Observed(height) => self.state.apply(|s| s.observe(*height)),
+            IncomingHeight::Observed(height) => {
+                self.status.observe(height);
+                self.state.apply(|s| s.observe(*height))
+            }
         };
         if state_change {
             self.notify.notify_one();
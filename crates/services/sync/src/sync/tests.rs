@@ -4,6 +4,7 @@ use fuel_core_services::stream::IntoBoxStream;
 use futures::stream;
 
 use super::*;
+use crate::service::SharedState;
 
 #[tokio::test]
 async fn test_sync() {
@@ -12,15 +13,19 @@ async fn test_sync() {
             .map(IncomingHeight::Observed)
             .into_boxed();
     let state = SharedMutex::new(State::new(None, None));
+    let shared = SharedState::new(0u32.into());
+    let status = StatusTracker::new(shared.clone());
     let notify = Arc::new(Notify::new());
 
     let mut s = SyncHeights {
         height_stream,
         state,
+        status,
         notify,
     };
 
     while s.sync().await.is_some() {}
 
     assert_eq!(s.state.apply(|s| s.proposed_height().copied()), Some(5u32));
+    assert_eq!(shared.status().highest_seen_height, 5u32.into());
 }
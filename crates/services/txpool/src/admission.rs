@@ -0,0 +1,143 @@
+//! The admission policies consulted by the `TxPool` before the insertion of the transaction.
+
+use crate::{
+    types::{
+        ContractId,
+        PoolTransaction,
+    },
+    Config,
+    Error,
+};
+use fuel_core_types::fuel_tx::{
+    input::contract::Contract,
+    Address,
+    Input,
+};
+use std::{
+    collections::HashSet,
+    fmt::Debug,
+    hash::Hash,
+    sync::Arc,
+};
+
+/// The custom rule deciding whether the transaction may be inserted into the `TxPool`.
+///
+/// The policies are consulted after the transaction passed the stateless checks and
+/// before it is validated against the state of the pool.
+pub trait TxAdmissionPolicy: Debug + Send + Sync {
+    /// Returns an error if the transaction is not allowed to be inserted into the pool.
+    fn check(&self, tx: &PoolTransaction) -> Result<(), Error>;
+}
+
+/// The list of the allowed and the denied items.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessList<T> {
+    /// If it is not empty, only these items are allowed.
+    pub allowed: HashSet<T>,
+    /// These items are never allowed.
+    pub denied: HashSet<T>,
+}
+
+impl<T> Default for AccessList<T> {
+    fn default() -> Self {
+        Self {
+            allowed: HashSet::new(),
+            denied: HashSet::new(),
+        }
+    }
+}
+
+impl<T> AccessList<T>
+where
+    T: Eq + Hash,
+{
+    pub fn new(
+        allowed: impl IntoIterator<Item = T>,
+        denied: impl IntoIterator<Item = T>,
+    ) -> Self {
+        Self {
+            allowed: allowed.into_iter().collect(),
+            denied: denied.into_iter().collect(),
+        }
+    }
+
+    /// Returns `true` if the list doesn't restrict any items.
+    pub fn is_empty(&self) -> bool {
+        self.allowed.is_empty() && self.denied.is_empty()
+    }
+
+    pub fn is_allowed(&self, item: &T) -> bool {
+        !self.denied.contains(item)
+            && (self.allowed.is_empty() || self.allowed.contains(item))
+    }
+}
+
+/// Restricts the contracts that the transactions may use as inputs.
+#[derive(Debug, Clone)]
+pub struct ContractAccessPolicy(pub AccessList<ContractId>);
+
+impl TxAdmissionPolicy for ContractAccessPolicy {
+    fn check(&self, tx: &PoolTransaction) -> Result<(), Error> {
+        for input in tx.inputs() {
+            if let Input::Contract(Contract { contract_id, .. }) = input {
+                if !self.0.is_allowed(contract_id) {
+                    return Err(Error::NotInsertedContractNotAllowed(*contract_id))
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Restricts the owners of the coins and the recipients of the messages spent by the
+/// transactions.
+#[derive(Debug, Clone)]
+pub struct OwnerAccessPolicy(pub AccessList<Address>);
+
+impl TxAdmissionPolicy for OwnerAccessPolicy {
+    fn check(&self, tx: &PoolTransaction) -> Result<(), Error> {
+        for owner in tx.inputs().iter().filter_map(Input::input_owner) {
+            if !self.0.is_allowed(owner) {
+                return Err(Error::NotInsertedOwnerNotAllowed(*owner))
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the built-in policies enabled by the `config` followed by the custom ones.
+pub(crate) fn policies(config: &Config) -> Vec<Arc<dyn TxAdmissionPolicy>> {
+    let mut policies: Vec<Arc<dyn TxAdmissionPolicy>> = vec![];
+    if !config.contract_access.is_empty() {
+        policies.push(Arc::new(ContractAccessPolicy(
+            config.contract_access.clone(),
+        )));
+    }
+    if !config.owner_access.is_empty() {
+        policies.push(Arc::new(OwnerAccessPolicy(config.owner_access.clone())));
+    }
+    policies.extend(config.admission_policies.iter().cloned());
+    policies
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn access_list_denies_items_outside_of_allowed() {
+        let list = AccessList::new([1, 2], [2]);
+
+        assert!(list.is_allowed(&1));
+        assert!(!list.is_allowed(&2));
+        assert!(!list.is_allowed(&3));
+    }
+
+    #[test]
+    fn empty_access_list_allows_everything() {
+        let list = AccessList::<u8>::default();
+
+        assert!(list.is_empty());
+        assert!(list.is_allowed(&1));
+    }
+}
@@ -1,10 +1,24 @@
+use crate::{
+    admission::{
+        AccessList,
+        TxAdmissionPolicy,
+    },
+    types::ContractId,
+};
 use fuel_core_chain_config::ChainConfig;
-use std::time::Duration;
+use fuel_core_types::fuel_tx::Address;
+use std::{
+    path::PathBuf,
+    sync::Arc,
+    time::Duration,
+};
 
 #[derive(Debug, Clone)]
 pub struct Config {
     /// Maximum number of transactions inside the pool
     pub max_tx: usize,
+    /// Maximum number of transactions inside the pool spending inputs of the same owner
+    pub max_tx_per_owner: usize,
     /// max depth of connected UTXO excluding contracts
     pub max_depth: usize,
     /// The minimum allowed gas price
@@ -19,11 +33,26 @@ pub struct Config {
     pub transaction_ttl: Duration,
     /// The number of allowed active transaction status subscriptions.
     pub number_of_active_subscription: usize,
+    /// The minimum gas price increase, in percent, required for a transaction to
+    /// replace the pooled transaction spending the same inputs.
+    pub replacement_gas_price_bump_percent: u64,
+    /// The file where pending transactions are saved on shutdown. The transactions
+    /// are restored from it on startup. Transactions are not persisted if it is `None`.
+    pub journal_path: Option<PathBuf>,
+    /// The maximum number of transactions accepted via gossip from a single peer per second.
+    pub max_gossiped_tx_per_peer: usize,
+    /// The contracts that transactions are allowed to use as inputs.
+    pub contract_access: AccessList<ContractId>,
+    /// The owners whose coins and messages transactions are allowed to spend.
+    pub owner_access: AccessList<Address>,
+    /// The custom admission policies consulted after the built-in ones.
+    pub admission_policies: Vec<Arc<dyn TxAdmissionPolicy>>,
 }
 
 impl Default for Config {
     fn default() -> Self {
         let max_tx = 4064;
+        let max_tx_per_owner = max_tx;
         let max_depth = 10;
         let min_gas_price = 0;
         let utxo_validation = true;
@@ -31,8 +60,14 @@ impl Default for Config {
         // 5 minute TTL
         let transaction_ttl = Duration::from_secs(60 * 5);
         let number_of_active_subscription = max_tx;
+        let replacement_gas_price_bump_percent = 0;
+        let journal_path = None;
+        let max_gossiped_tx_per_peer = 100;
+        let contract_access = AccessList::default();
+        let owner_access = AccessList::default();
         Self::new(
             max_tx,
+            max_tx_per_owner,
             max_depth,
             ChainConfig::default(),
             min_gas_price,
@@ -40,6 +75,11 @@ impl Default for Config {
             metrics,
             transaction_ttl,
             number_of_active_subscription,
+            replacement_gas_price_bump_percent,
+            journal_path,
+            max_gossiped_tx_per_peer,
+            contract_access,
+            owner_access,
         )
     }
 }
@@ -48,6 +88,7 @@ impl Config {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         max_tx: usize,
+        max_tx_per_owner: usize,
         max_depth: usize,
         chain_config: ChainConfig,
         min_gas_price: u64,
@@ -55,11 +96,17 @@ impl Config {
         metrics: bool,
         transaction_ttl: Duration,
         number_of_active_subscription: usize,
+        replacement_gas_price_bump_percent: u64,
+        journal_path: Option<PathBuf>,
+        max_gossiped_tx_per_peer: usize,
+        contract_access: AccessList<ContractId>,
+        owner_access: AccessList<Address>,
     ) -> Self {
         // # Dev-note: If you add a new field, be sure that this field is propagated correctly
         //  in all places where `new` is used.
         Self {
             max_tx,
+            max_tx_per_owner,
             max_depth,
             min_gas_price,
             utxo_validation,
@@ -67,6 +114,12 @@ impl Config {
             metrics,
             transaction_ttl,
             number_of_active_subscription,
+            replacement_gas_price_bump_percent,
+            journal_path,
+            max_gossiped_tx_per_peer,
+            contract_access,
+            owner_access,
+            admission_policies: vec![],
         }
     }
 }
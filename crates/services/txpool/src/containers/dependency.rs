@@ -51,6 +51,8 @@ pub struct Dependency {
     max_depth: usize,
     /// utxo-validation feature flag
     utxo_validation: bool,
+    /// the minimum gas price increase in percent to replace the colliding transaction
+    replacement_gas_price_bump_percent: u64,
 }
 
 #[derive(Debug, Clone)]
@@ -96,16 +98,73 @@ pub struct MessageState {
 }
 
 impl Dependency {
-    pub fn new(max_depth: usize, utxo_validation: bool) -> Self {
+    pub fn new(
+        max_depth: usize,
+        utxo_validation: bool,
+        replacement_gas_price_bump_percent: u64,
+    ) -> Self {
         Self {
             coins: HashMap::new(),
             contracts: HashMap::new(),
             messages: HashMap::new(),
             max_depth,
             utxo_validation,
+            replacement_gas_price_bump_percent,
         }
     }
 
+    /// Returns the minimum gas price of the transaction that replaces the colliding
+    /// transaction with the `gas_price`.
+    fn min_replacement_gas_price(&self, gas_price: GasPrice) -> GasPrice {
+        let percent = self.replacement_gas_price_bump_percent as u128;
+        let bump = (gas_price as u128 * percent + 99) / 100;
+        gas_price.saturating_add(bump.try_into().unwrap_or(GasPrice::MAX))
+    }
+
+    /// find the Transactions inside txpool which outputs are spent directly by the `tx`.
+    /// The `tx` itself is not required to be inside txpool.
+    pub(crate) fn find_parents(
+        &self,
+        tx: &ArcPoolTx,
+        txs: &HashMap<TxId, TxInfo>,
+    ) -> Vec<ArcPoolTx> {
+        let mut parents: Vec<ArcPoolTx> = Vec::new();
+        for input in tx.inputs() {
+            let parent = match input {
+                Input::CoinSigned(CoinSigned { utxo_id, .. })
+                | Input::CoinPredicate(CoinPredicate { utxo_id, .. }) => {
+                    Some(*utxo_id.tx_id())
+                }
+                Input::Contract(Contract { contract_id, .. }) => self
+                    .contracts
+                    .get(contract_id)
+                    .and_then(|state| state.origin.as_ref())
+                    .map(|origin| *origin.tx_id()),
+                _ => None,
+            };
+            if let Some(parent) = parent.and_then(|parent| txs.get(&parent)) {
+                if !parents.iter().any(|known| known.id() == parent.tx().id()) {
+                    parents.push(parent.tx().clone());
+                }
+            }
+        }
+        parents
+    }
+
+    /// find all Transactions inside txpool that the `tx` depends on.
+    /// Unlike `find_dependent`, the `tx` itself is not required to be inside txpool.
+    pub(crate) fn find_ancestors(
+        &self,
+        tx: &ArcPoolTx,
+        txs: &HashMap<TxId, TxInfo>,
+    ) -> HashMap<TxId, ArcPoolTx> {
+        let mut seen = HashMap::new();
+        for parent in self.find_parents(tx, txs) {
+            self.find_dependent(parent, &mut seen, txs);
+        }
+        seen
+    }
+
     /// find all dependent Transactions that are inside txpool.
     /// Does not check db. They can be sorted by gasPrice to get order of dependency
     pub(crate) fn find_dependent(
@@ -376,7 +435,9 @@ impl Dependency {
                                 .get(spend_by)
                                 .expect("Tx should be always present in txpool");
                             // compare if tx has better price
-                            if txpool_tx.price() > tx.price() {
+                            if tx.price()
+                                < self.min_replacement_gas_price(txpool_tx.price())
+                            {
                                 return Err(Error::NotInsertedCollision(
                                     *spend_by, *utxo_id,
                                 )
@@ -456,7 +517,10 @@ impl Dependency {
 
                     if let Some(state) = self.messages.get(nonce) {
                         // some other is already attempting to spend this message, compare gas price
-                        if state.gas_price >= tx.price() {
+                        if state.gas_price >= tx.price()
+                            || tx.price()
+                                < self.min_replacement_gas_price(state.gas_price)
+                        {
                             return Err(Error::NotInsertedCollisionMessageId(
                                 state.spent_by,
                                 *nonce,
@@ -527,7 +591,7 @@ impl Dependency {
                         )
                     }
                     // check who is priced more
-                    if contract.gas_price > tx.price() {
+                    if tx.price() < self.min_replacement_gas_price(contract.gas_price) {
                         // new tx is priced less then current tx
                         return Err(
                             Error::NotInsertedCollisionContractId(*contract_id).into()
@@ -0,0 +1,61 @@
+use crate::{
+    containers::sort::{
+        Sort,
+        SortableKey,
+    },
+    types::*,
+    TxInfo,
+};
+use std::cmp;
+
+/// transactions with the priority override sorted by min/max priority
+pub type PrioritySort = Sort<PrioritySortKey>;
+
+#[derive(Clone, Debug)]
+pub struct PrioritySortKey {
+    priority: u64,
+    tx_id: TxId,
+}
+
+impl SortableKey for PrioritySortKey {
+    type Value = u64;
+
+    fn new(info: &TxInfo) -> Self {
+        Self {
+            priority: info.priority().unwrap_or_default(),
+            tx_id: info.tx().id(),
+        }
+    }
+
+    fn value(&self) -> &Self::Value {
+        &self.priority
+    }
+
+    fn tx_id(&self) -> &TxId {
+        &self.tx_id
+    }
+}
+
+impl PartialEq for PrioritySortKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.tx_id == other.tx_id
+    }
+}
+
+impl Eq for PrioritySortKey {}
+
+impl PartialOrd for PrioritySortKey {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PrioritySortKey {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        let cmp = self.priority.cmp(&other.priority);
+        if cmp == cmp::Ordering::Equal {
+            return self.tx_id.cmp(&other.tx_id)
+        }
+        cmp
+    }
+}
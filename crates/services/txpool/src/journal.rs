@@ -0,0 +1,141 @@
+//! The journal of pending transactions. It allows restoring the content of
+//! the `TxPool` after the restart of the node.
+//!
+//! The journal is a sequence of transactions, each is prefixed by the length of its
+//! canonical encoding as a little-endian `u32`.
+
+use anyhow::{
+    anyhow,
+    Context,
+};
+use fuel_core_types::{
+    fuel_tx::Transaction,
+    fuel_types::bytes::{
+        Deserializable,
+        SerializableVec,
+    },
+};
+use std::{
+    fs,
+    io::ErrorKind,
+    path::Path,
+};
+
+const LENGTH_SIZE: usize = core::mem::size_of::<u32>();
+
+/// Saves the `txs` to the journal at the `path`, replacing the previous journal.
+///
+/// The journal is written to a temporary file first and renamed afterwards,
+/// so the interrupted save doesn't corrupt the previous journal.
+pub fn save<'a>(
+    path: &Path,
+    txs: impl IntoIterator<Item = &'a Transaction>,
+) -> anyhow::Result<usize> {
+    let mut journal = vec![];
+    let mut count = 0;
+    for tx in txs {
+        let bytes = tx.clone().to_bytes();
+        let len = u32::try_from(bytes.len())
+            .map_err(|_| anyhow!("the transaction is too large for the journal"))?;
+        journal.extend_from_slice(&len.to_le_bytes());
+        journal.extend_from_slice(&bytes);
+        count += 1;
+    }
+
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, journal).with_context(|| {
+        format!("failed to write the txpool journal {}", tmp_path.display())
+    })?;
+    fs::rename(&tmp_path, path).with_context(|| {
+        format!("failed to replace the txpool journal {}", path.display())
+    })?;
+    Ok(count)
+}
+
+/// Loads the transactions from the journal at the `path` in the order they were saved.
+/// Returns an empty list if the journal doesn't exist.
+pub fn load(path: &Path) -> anyhow::Result<Vec<Transaction>> {
+    let journal = match fs::read(path) {
+        Ok(journal) => journal,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => {
+            return Err(e).with_context(|| {
+                format!("failed to read the txpool journal {}", path.display())
+            })
+        }
+    };
+
+    let mut txs = vec![];
+    let mut rest = journal.as_slice();
+    while !rest.is_empty() {
+        if rest.len() < LENGTH_SIZE {
+            return Err(anyhow!("the txpool journal is truncated"))
+        }
+        let (len, tail) = rest.split_at(LENGTH_SIZE);
+        let len = u32::from_le_bytes(len.try_into().expect("the size is checked above"))
+            as usize;
+        if tail.len() < len {
+            return Err(anyhow!("the txpool journal is truncated"))
+        }
+        let (bytes, tail) = tail.split_at(len);
+        let tx = Transaction::from_bytes(bytes)
+            .map_err(|e| anyhow!("invalid transaction in the txpool journal: {e:?}"))?;
+        txs.push(tx);
+        rest = tail;
+    }
+    Ok(txs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_tx::TransactionBuilder;
+
+    fn script(gas_price: u64) -> Transaction {
+        TransactionBuilder::script(vec![], vec![])
+            .gas_price(gas_price)
+            .gas_limit(1000)
+            .finalize_as_transaction()
+    }
+
+    fn tmp_journal(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!(
+            "fuel-core-txpool-journal-{name}-{}",
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn saved_transactions_are_loaded_in_order() {
+        let path = tmp_journal("roundtrip");
+        let txs = vec![script(1), script(2), script(3)];
+
+        let saved = save(&path, &txs).unwrap();
+        let loaded = load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(saved, 3);
+        assert_eq!(loaded, txs);
+    }
+
+    #[test]
+    fn missing_journal_is_empty() {
+        let path = tmp_journal("missing");
+
+        assert!(load(&path).unwrap().is_empty());
+    }
+
+    #[test]
+    fn truncated_journal_is_an_error() {
+        let path = tmp_journal("truncated");
+        save(&path, &[script(1)]).unwrap();
+        let mut journal = fs::read(&path).unwrap();
+        journal.pop();
+        fs::write(&path, journal).unwrap();
+
+        let result = load(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert!(result.is_err());
+    }
+}
@@ -13,8 +13,11 @@ use std::{
     time::Duration,
 };
 
+pub mod admission;
 pub mod config;
 mod containers;
+mod journal;
+mod metrics;
 pub mod ports;
 pub mod service;
 mod transaction_selector;
@@ -46,11 +49,17 @@ pub struct TxInfo {
     tx: ArcPoolTx,
     submitted_time: Duration,
     creation_instant: tokio::time::Instant,
+    priority: Option<u64>,
 }
 
 #[allow(missing_docs)]
 impl TxInfo {
     pub fn new(tx: ArcPoolTx) -> Self {
+        Self::new_with_priority(tx, None)
+    }
+
+    /// The `priority` overrides the gas price ordering of the block building.
+    pub fn new_with_priority(tx: ArcPoolTx, priority: Option<u64>) -> Self {
         let since_epoch = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .expect("Now is bellow of the `UNIX_EPOCH`");
@@ -59,6 +68,7 @@ impl TxInfo {
             tx,
             submitted_time: since_epoch,
             creation_instant: tokio::time::Instant::now(),
+            priority,
         }
     }
 
@@ -73,6 +83,10 @@ impl TxInfo {
     pub fn created(&self) -> tokio::time::Instant {
         self.creation_instant
     }
+
+    pub fn priority(&self) -> Option<u64> {
+        self.priority
+    }
 }
 
 impl Deref for TxInfo {
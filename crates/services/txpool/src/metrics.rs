@@ -0,0 +1,103 @@
+//! Helpers recording the state of the `TxPool` into the node's metrics registry.
+
+use crate::{
+    Error,
+    TxInfo,
+};
+use fuel_core_metrics::txpool_metrics::TXPOOL_METRICS;
+
+/// The origin of the transaction submitted into the pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TxSource {
+    /// Submitted via the GraphQL API of the node.
+    Local,
+    /// Received from the peers.
+    Gossip,
+}
+
+impl TxSource {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TxSource::Local => "local",
+            TxSource::Gossip => "gossip",
+        }
+    }
+}
+
+/// Records the current number of transactions in the pool and their total size.
+pub(crate) fn observe_pool_size(txs: usize, bytes: usize) {
+    TXPOOL_METRICS.pool_size_gauge.set(txs as i64);
+    TXPOOL_METRICS.pool_bytes_gauge.set(bytes as i64);
+}
+
+/// Records the time the transaction spent in the pool before the removal.
+pub(crate) fn observe_removal(info: &TxInfo) {
+    TXPOOL_METRICS
+        .time_in_pool_histogram
+        .observe(info.created().elapsed().as_secs_f64());
+}
+
+/// Records the results of the insertion of the transactions received from the `source`.
+pub(crate) fn observe_insertion<T>(source: TxSource, results: &[anyhow::Result<T>]) {
+    TXPOOL_METRICS.observe_received(source.as_str(), results.len() as u64);
+    for err in results.iter().filter_map(|result| result.as_ref().err()) {
+        TXPOOL_METRICS.observe_rejection(rejection_reason(err));
+    }
+}
+
+/// Records the transaction received from the `source` and rejected before the insertion.
+pub(crate) fn observe_rejected(source: TxSource, err: &anyhow::Error) {
+    TXPOOL_METRICS.observe_received(source.as_str(), 1);
+    TXPOOL_METRICS.observe_rejection(rejection_reason(err));
+}
+
+/// Groups the insertion errors into the reasons with the low cardinality.
+pub(crate) fn rejection_reason(err: &anyhow::Error) -> &'static str {
+    let err = match err.downcast_ref::<Error>() {
+        Some(err) => err,
+        None => return "invalid",
+    };
+    match err {
+        Error::NotInsertedTxKnown(_) => "known",
+        Error::NotInsertedLimitHit => "pool_limit",
+        Error::NotInsertedOwnerLimitHit(_) => "owner_limit",
+        Error::NotInsertedMaxGasLimit { .. } => "max_gas",
+        Error::NotInsertedGasPriceTooLow { .. }
+        | Error::NotInsertedContractPricedLower(_) => "underpriced",
+        Error::NotInsertedCollision(_, _)
+        | Error::NotInsertedCollisionContractId(_)
+        | Error::NotInsertedCollisionMessageId(_, _)
+        | Error::NotInsertedContractIdAlreadyTaken(_) => "collision",
+        Error::NotInsertedOutputNotExisting(_)
+        | Error::NotInsertedInputContractNotExisting(_)
+        | Error::NotInsertedInputUtxoIdNotExisting(_)
+        | Error::NotInsertedInputUtxoIdSpent(_)
+        | Error::NotInsertedInputMessageSpent(_)
+        | Error::NotInsertedInputMessageUnknown(_)
+        | Error::NotInsertedMaxDepth
+        | Error::NotInsertedBatchOutOfOrder(_) => "missing_inputs",
+        Error::NotInsertedContractNotAllowed(_)
+        | Error::NotInsertedOwnerNotAllowed(_)
+        | Error::NotInsertedRejectedByPolicy(_) => "policy",
+        Error::NotInsertedTxPoolStopped => "stopped",
+        _ => "invalid",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejection_reason_groups_errors() {
+        assert_eq!(
+            rejection_reason(&Error::NotInsertedLimitHit.into()),
+            "pool_limit"
+        );
+        assert_eq!(
+            rejection_reason(&Error::NotInsertedMaxDepth.into()),
+            "missing_inputs"
+        );
+        assert_eq!(rejection_reason(&anyhow::anyhow!("malformed")), "invalid");
+    }
+}
@@ -6,7 +6,9 @@ use fuel_core_types::{
         message::Message,
     },
     fuel_tx::{
+        ConsensusParameters,
         Transaction,
+        TxId,
         UtxoId,
     },
     fuel_types::{
@@ -16,11 +18,16 @@ use fuel_core_types::{
         Nonce,
     },
     services::{
-        block_importer::ImportResult,
+        block_importer::{
+            ImportResult,
+            Reorg,
+        },
         p2p::{
+            peer_reputation::TransactionGossipReport,
             GossipsubMessageAcceptance,
             GossipsubMessageInfo,
             NetworkData,
+            PeerId,
         },
         txpool::TransactionStatus,
     },
@@ -33,6 +40,12 @@ pub trait PeerToPeer: Send + Sync {
     // Gossip broadcast a transaction inserted via API.
     fn broadcast_transaction(&self, transaction: Arc<Transaction>) -> anyhow::Result<()>;
 
+    /// Gossip broadcast a batch of transactions inserted together via API.
+    fn broadcast_transactions(
+        &self,
+        transactions: Vec<Arc<Transaction>>,
+    ) -> anyhow::Result<()>;
+
     /// Creates a stream of next transactions gossiped from the network.
     fn gossiped_transaction_events(&self) -> BoxStream<Self::GossipedTransaction>;
 
@@ -42,11 +55,39 @@ pub trait PeerToPeer: Send + Sync {
         message_info: GossipsubMessageInfo,
         validity: GossipsubMessageAcceptance,
     ) -> anyhow::Result<()>;
+
+    /// Reports the result of the validation of the transaction gossiped by the `peer_id`
+    /// to update the reputation of the peer.
+    fn report_peer(
+        &self,
+        peer_id: PeerId,
+        report: TransactionGossipReport,
+    ) -> anyhow::Result<()>;
+
+    /// Requests the transactions with `tx_ids` from the `peer_id`. The received
+    /// transactions are delivered via [`PeerToPeer::requested_transaction_events`].
+    fn request_transactions(
+        &self,
+        peer_id: PeerId,
+        tx_ids: Vec<TxId>,
+    ) -> anyhow::Result<()>;
+
+    /// Creates a stream of transactions received in response to
+    /// [`PeerToPeer::request_transactions`].
+    fn requested_transaction_events(&self) -> BoxStream<Vec<Transaction>>;
 }
 
 pub trait BlockImporter: Send + Sync {
     /// Wait until the next block is available
     fn block_events(&self) -> BoxStream<Arc<ImportResult>>;
+
+    /// Creates a stream of the consensus parameters that come into force after
+    /// the import of the block. The pending transactions are revalidated against them.
+    fn consensus_parameters_events(&self) -> BoxStream<ConsensusParameters>;
+
+    /// Creates a stream of the chain reorganizations. The transactions of the reverted
+    /// blocks are returned to the pool.
+    fn reorg_events(&self) -> BoxStream<Arc<Reorg>>;
 }
 
 pub trait TxPoolDb: Send + Sync {
@@ -1,4 +1,9 @@
 use crate::{
+    journal,
+    metrics::{
+        self,
+        TxSource,
+    },
     ports::{
         BlockImporter,
         PeerToPeer,
@@ -6,8 +11,11 @@ use crate::{
     },
     transaction_selector::select_transactions,
     txpool::{
+        check_batch_order,
         check_single_tx,
         check_transactions,
+        inputs_and_outputs,
+        owners,
     },
     Config,
     Error as TxPoolError,
@@ -28,14 +36,20 @@ use fuel_core_types::{
         Transaction,
         TxId,
         UniqueIdentifier,
+        UtxoId,
     },
     fuel_types::{
+        Address,
         BlockHeight,
         Bytes32,
     },
     services::{
-        block_importer::ImportResult,
+        block_importer::{
+            ImportResult,
+            Reorg,
+        },
         p2p::{
+            peer_reputation::TransactionGossipReport,
             GossipData,
             GossipsubMessageAcceptance,
             GossipsubMessageInfo,
@@ -52,7 +66,20 @@ use fuel_core_types::{
 };
 
 use parking_lot::Mutex as ParkingMutex;
-use std::sync::Arc;
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    sync::{
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
+        Arc,
+    },
+    time::Duration,
+};
 use tokio::{
     sync::broadcast,
     time::MissedTickBehavior,
@@ -60,11 +87,20 @@ use tokio::{
 use tokio_stream::StreamExt;
 use update_sender::UpdateSender;
 
-use self::update_sender::{
-    MpscChannel,
-    TxStatusStream,
+use self::{
+    orphans::{
+        OrphanTransactions,
+        MAX_ORPHAN_TRANSACTIONS,
+    },
+    rate_limiter::PeerRateLimiter,
+    update_sender::{
+        MpscChannel,
+        TxStatusStream,
+    },
 };
 
+mod orphans;
+mod rate_limiter;
 mod update_sender;
 
 pub type Service<P2P, DB> = ServiceRunner<Task<P2P, DB>>;
@@ -73,45 +109,95 @@ pub type Service<P2P, DB> = ServiceRunner<Task<P2P, DB>>;
 pub struct TxStatusChange {
     new_tx_notification_sender: broadcast::Sender<TxId>,
     update_sender: UpdateSender,
+    /// Broadcasts the status updates of all transactions to the filtered subscriptions.
+    status_update_sender: broadcast::Sender<TxStatusUpdate>,
 }
 
 impl TxStatusChange {
     pub fn new(capacity: usize) -> Self {
         let (new_tx_notification_sender, _) = broadcast::channel(capacity);
         let update_sender = UpdateSender::new(capacity);
+        let (status_update_sender, _) = broadcast::channel(capacity);
         Self {
             new_tx_notification_sender,
             update_sender,
+            status_update_sender,
         }
     }
 
     pub fn send_complete(
         &self,
         id: Bytes32,
+        owners: HashSet<Address>,
         block_height: &BlockHeight,
         message: impl Into<TxStatusMessage>,
     ) {
         tracing::info!("Transaction {id} successfully included in block {block_height}");
-        self.update_sender.send(TxUpdate::new(id, message.into()));
+        self.send(id, owners, message.into());
     }
 
-    pub fn send_submitted(&self, id: Bytes32, time: Tai64) {
+    pub fn send_submitted(&self, id: Bytes32, owners: HashSet<Address>, time: Tai64) {
         tracing::info!("Transaction {id} successfully submitted to the tx pool");
         let _ = self.new_tx_notification_sender.send(id);
-        self.update_sender.send(TxUpdate::new(
+        self.send(
             id,
+            owners,
             TxStatusMessage::Status(TransactionStatus::Submitted { time }),
-        ));
+        );
     }
 
-    pub fn send_squeezed_out(&self, id: Bytes32, reason: TxPoolError) {
+    pub fn send_squeezed_out(
+        &self,
+        id: Bytes32,
+        owners: HashSet<Address>,
+        reason: TxPoolError,
+    ) {
         tracing::info!("Transaction {id} squeezed out because {reason}");
-        self.update_sender.send(TxUpdate::new(
+        self.send(
             id,
+            owners,
             TxStatusMessage::Status(TransactionStatus::SqueezedOut {
                 reason: reason.to_string(),
             }),
-        ));
+        );
+    }
+
+    fn send(&self, id: Bytes32, owners: HashSet<Address>, message: TxStatusMessage) {
+        // There may be no filtered subscriptions, so the error is ignored.
+        let _ = self.status_update_sender.send(TxStatusUpdate {
+            tx_id: id,
+            owners,
+            message: message.clone(),
+        });
+        self.update_sender.send(TxUpdate::new(id, message));
+    }
+}
+
+/// The status update of the transaction along with the owners of its inputs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxStatusUpdate {
+    pub tx_id: TxId,
+    /// The owners of the coins and the recipients of the messages spent by the transaction.
+    /// It is empty if the transaction was not inside of the pool.
+    pub owners: HashSet<Address>,
+    pub message: TxStatusMessage,
+}
+
+/// Selects the status updates delivered to the filtered subscription.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TxStatusFilter {
+    /// The updates of the transactions with any of these ids.
+    Ids(HashSet<TxId>),
+    /// The updates of the transactions spending the inputs of the owner.
+    Owner(Address),
+}
+
+impl TxStatusFilter {
+    pub fn matches(&self, update: &TxStatusUpdate) -> bool {
+        match self {
+            TxStatusFilter::Ids(ids) => ids.contains(&update.tx_id),
+            TxStatusFilter::Owner(owner) => update.owners.contains(owner),
+        }
     }
 }
 
@@ -119,9 +205,11 @@ pub struct SharedState<P2P, DB> {
     tx_status_sender: TxStatusChange,
     txpool: Arc<ParkingMutex<TxPool<DB>>>,
     p2p: Arc<P2P>,
-    consensus_params: ConsensusParameters,
+    consensus_params: Arc<ParkingMutex<ConsensusParameters>>,
     db: DB,
     config: Config,
+    /// Set when the service is stopped to reject new transactions.
+    stopped: Arc<AtomicBool>,
 }
 
 impl<P2P, DB: Clone> Clone for SharedState<P2P, DB> {
@@ -133,15 +221,22 @@ impl<P2P, DB: Clone> Clone for SharedState<P2P, DB> {
             consensus_params: self.consensus_params.clone(),
             db: self.db.clone(),
             config: self.config.clone(),
+            stopped: self.stopped.clone(),
         }
     }
 }
 
 pub struct Task<P2P, DB> {
     gossiped_tx_stream: BoxStream<TransactionGossipData>,
+    requested_tx_stream: BoxStream<Vec<Transaction>>,
     committed_block_stream: BoxStream<Arc<ImportResult>>,
+    consensus_parameters_stream: BoxStream<ConsensusParameters>,
+    reorg_stream: BoxStream<Arc<Reorg>>,
     shared: SharedState<P2P, DB>,
     ttl_timer: tokio::time::Interval,
+    gossip_rate_limiter: PeerRateLimiter,
+    /// The gossiped transactions waiting for their parents requested from the peers
+    orphans: OrphanTransactions,
 }
 
 #[async_trait::async_trait]
@@ -165,6 +260,12 @@ where
         _: &StateWatcher,
         _: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
+        if let Some(path) = &self.shared.config.journal_path {
+            match journal::load(path) {
+                Ok(txs) => self.shared.restore(txs).await?,
+                Err(e) => tracing::error!("Unable to load the txpool journal: {e:?}"),
+            }
+        }
         self.ttl_timer.reset();
         Ok(self)
     }
@@ -188,8 +289,13 @@ where
 
             _ = self.ttl_timer.tick() => {
                 let removed = self.shared.txpool.lock().prune_old_txs();
+                self.gossip_rate_limiter.prune(tokio::time::Instant::now());
                 for tx in removed {
-                    self.shared.tx_status_sender.send_squeezed_out(tx.id(), Error::TTLReason);
+                    self.shared.tx_status_sender.send_squeezed_out(
+                        tx.id(),
+                        owners(&tx),
+                        Error::TTLReason,
+                    );
                 }
 
                 should_continue = true
@@ -197,15 +303,24 @@ where
 
             result = self.committed_block_stream.next() => {
                 if let Some(result) = result {
+                    let chain_id = self.shared.consensus_params.lock().chain_id;
+                    let parents = result
+                        .sealed_block
+                        .entity
+                        .transactions()
+                        .iter()
+                        .map(|tx| (tx.id(&chain_id), inputs_and_outputs(tx).1.len()))
+                        .collect();
                     let block = result
                         .sealed_block
                         .entity
-                        .compress(&self.shared.consensus_params.chain_id);
+                        .compress(&chain_id);
                     self.shared.txpool.lock().block_update(
                         &self.shared.tx_status_sender,
                         block.header().height(),
                         block.transactions()
                     );
+                    self.insert_orphans_of(parents);
                     should_continue = true;
                 } else {
                     should_continue = false;
@@ -214,15 +329,35 @@ where
 
             new_transaction = self.gossiped_tx_stream.next() => {
                 if let Some(GossipData { data: Some(tx), message_id, peer_id }) = new_transaction {
-                    let id = tx.id(&self.shared.consensus_params.chain_id);
+                    let id = tx.id(&self.shared.consensus_params.lock().chain_id);
+                    let message_info = GossipsubMessageInfo {
+                        message_id,
+                        peer_id: peer_id.clone(),
+                    };
+
+                    if !self.gossip_rate_limiter.check(&peer_id, tokio::time::Instant::now()) {
+                        self.shared.report_gossiped_tx(
+                            message_info,
+                            GossipsubMessageAcceptance::Ignore,
+                            Some(TransactionGossipReport::RateLimited),
+                        );
+                        return Ok(true)
+                    }
+
                     let current_height = self.shared.db.current_block_height()?;
 
                     // verify tx
-                    let checked_tx = check_single_tx(tx, current_height, &self.shared.config).await;
+                    let consensus_params = self.shared.consensus_params.lock().clone();
+                    let checked_tx = check_single_tx(
+                        tx,
+                        current_height,
+                        &consensus_params,
+                        &self.shared.config,
+                    ).await;
 
-                    let acceptance = match checked_tx {
+                    let (acceptance, report) = match checked_tx {
                         Ok(tx) => {
-                            let txs = vec![tx];
+                            let txs = vec![tx.clone()];
 
                             // insert tx
                             let mut result = tracing::info_span!("Received tx via gossip", %id)
@@ -232,30 +367,100 @@ where
                                         txs
                                     )
                                 });
+                            if self.shared.config.metrics {
+                                metrics::observe_insertion(TxSource::Gossip, &result);
+                            }
 
                             match result.pop() {
-                                Some(Ok(_)) => {
-                                    GossipsubMessageAcceptance::Accept
-                                },
-                                Some(Err(_)) => {
-                                    GossipsubMessageAcceptance::Reject
+                                Some(Ok(inserted)) => {
+                                    self.insert_orphans_of(vec![parent_of(&inserted)]);
+                                    (
+                                        GossipsubMessageAcceptance::Accept,
+                                        Some(TransactionGossipReport::Accepted),
+                                    )
+                                }
+                                Some(Err(err)) => {
+                                    if let Some(missing_input) = missing_input(&err) {
+                                        // The transaction may be valid, but we didn't see its parent yet.
+                                        // Request the parent from the peer that sent the transaction,
+                                        // and insert the transaction again once the parent lands.
+                                        let parent = *missing_input.tx_id();
+                                        self.orphans.insert(id, missing_input, tx);
+                                        tracing::debug!("The transaction {id} waits for the parent {parent}, {} orphans in total", self.orphans.len());
+                                        let result = self.shared.p2p.request_transactions(peer_id.clone(), vec![parent]);
+                                        if let Err(e) = result {
+                                            tracing::error!("Unable to request the parent transaction {parent}, got an {e} error");
+                                        }
+                                        (GossipsubMessageAcceptance::Ignore, None)
+                                    } else {
+                                        (insertion_acceptance(&err), insertion_report(&err))
+                                    }
                                 }
-                                _ => GossipsubMessageAcceptance::Ignore
+                                _ => (GossipsubMessageAcceptance::Ignore, None)
                             }
                         }
-                        Err(_) => {
-                            GossipsubMessageAcceptance::Reject
+                        Err(err) => {
+                            if self.shared.config.metrics {
+                                metrics::observe_rejected(TxSource::Gossip, &err);
+                            }
+                            (insertion_acceptance(&err), insertion_report(&err))
                         }
                     };
 
-                    if acceptance != GossipsubMessageAcceptance::Ignore {
-                        let message_info = GossipsubMessageInfo {
-                            message_id,
-                            peer_id,
-                        };
+                    self.shared.report_gossiped_tx(message_info, acceptance, report);
+
+                    should_continue = true;
+                } else {
+                    should_continue = false;
+                }
+            }
+
+            Some(consensus_params) = self.consensus_parameters_stream.next() => {
+                self.shared.update_consensus_parameters(consensus_params)?;
+                should_continue = true;
+            }
+
+            Some(reorg) = self.reorg_stream.next() => {
+                self.shared.reinsert_reverted(&reorg).await?;
+                should_continue = true;
+            }
 
-                        let _ = self.shared.p2p.notify_gossip_transaction_validity(message_info, acceptance);
+            requested_transactions = self.requested_tx_stream.next() => {
+                if let Some(txs) = requested_transactions {
+                    let current_height = self.shared.db.current_block_height()?;
+                    let txs: Vec<_> = txs.into_iter().map(Arc::new).collect();
+
+                    // The parents are inserted in the order they were received,
+                    // so they can depend on each other.
+                    let consensus_params = self.shared.consensus_params.lock().clone();
+                    let mut checked_txs = vec![];
+                    for result in check_transactions(
+                        &txs,
+                        current_height,
+                        &consensus_params,
+                        &self.shared.config,
+                    ).await {
+                        match result {
+                            Ok(tx) => checked_txs.push(tx),
+                            Err(err) => {
+                                if self.shared.config.metrics {
+                                    metrics::observe_rejected(TxSource::Gossip, &err);
+                                }
+                            }
+                        }
                     }
+                    let result = tracing::info_span!("Received requested txs", count = txs.len())
+                        .in_scope(|| {
+                            self.shared.txpool.lock().insert(
+                                &self.shared.tx_status_sender,
+                                checked_txs
+                            )
+                        });
+                    if self.shared.config.metrics {
+                        metrics::observe_insertion(TxSource::Gossip, &result);
+                    }
+                    let parents = result.iter().flatten().map(parent_of).collect();
+                    self.insert_orphans_of(parents);
 
                     should_continue = true;
                 } else {
@@ -267,14 +472,139 @@ where
     }
 
     async fn shutdown(self) -> anyhow::Result<()> {
-        // Nothing to shut down because we don't have any temporary state that should be dumped,
-        // and we don't spawn any sub-tasks that we need to finish or await.
-        // Maybe we will save and load the previous list of transactions in the future to
-        // avoid losing them.
+        // Stop accepting new transactions. The TTL timer is owned by the task,
+        // so it is dropped together with it.
+        self.shared.stopped.store(true, Ordering::Release);
+        // Close the status subscriptions, so subscribers see the end of the stream
+        // instead of waiting for updates that never come.
+        self.shared.tx_status_sender.update_sender.close();
+        // Save the pending transactions, so they are restored after the restart.
+        if let Some(path) = &self.shared.config.journal_path {
+            let txs = self.shared.pending_transactions();
+            match journal::save(path, &txs) {
+                Ok(count) => {
+                    tracing::info!("Saved {count} transactions to the txpool journal")
+                }
+                Err(e) => tracing::error!("Unable to save the txpool journal: {e:?}"),
+            }
+        }
+        // We don't spawn any sub-tasks that we need to finish or await.
         Ok(())
     }
 }
 
+impl<P2P, DB> Task<P2P, DB>
+where
+    DB: TxPoolDb,
+{
+    /// Inserts the orphans waiting for the outputs of the `parents`, which are
+    /// given with the number of their outputs. The inserted orphans may be
+    /// the parents of other orphans.
+    fn insert_orphans_of(&mut self, mut parents: Vec<(TxId, usize)>) {
+        while !parents.is_empty() {
+            let children: Vec<_> = parents
+                .iter()
+                .flat_map(|(parent, outputs)| {
+                    self.orphans.take_children(parent, *outputs)
+                })
+                .collect();
+            if children.is_empty() {
+                return
+            }
+
+            let result =
+                tracing::info_span!("Received orphan txs", count = children.len())
+                    .in_scope(|| {
+                        self.shared
+                            .txpool
+                            .lock()
+                            .insert(&self.shared.tx_status_sender, children)
+                    });
+            if self.shared.config.metrics {
+                metrics::observe_insertion(TxSource::Gossip, &result);
+            }
+            parents = result.iter().flatten().map(parent_of).collect();
+        }
+    }
+}
+
+/// Returns the id of the inserted transaction with the number of its outputs.
+fn parent_of(result: &InsertionResult) -> (TxId, usize) {
+    (result.inserted.id(), result.inserted.outputs().len())
+}
+
+/// Returns the input spending the output of the unknown transaction
+/// if the insertion failed because of it.
+fn missing_input(error: &anyhow::Error) -> Option<UtxoId> {
+    match error.downcast_ref::<Error>() {
+        Some(Error::NotInsertedInputUtxoIdNotExisting(utxo_id)) => Some(*utxo_id),
+        _ => None,
+    }
+}
+
+/// Returns the verdict about the gossiped transaction that failed the verification
+/// or the insertion.
+///
+/// The transaction is ignored if the peer is not reported for it, or if it is
+/// already known, so it is not propagated further, but the peer is not penalized
+/// by the gossipsub scoring.
+fn insertion_acceptance(error: &anyhow::Error) -> GossipsubMessageAcceptance {
+    match insertion_report(error) {
+        None | Some(TransactionGossipReport::Duplicate) => {
+            GossipsubMessageAcceptance::Ignore
+        }
+        Some(_) => GossipsubMessageAcceptance::Reject,
+    }
+}
+
+/// Returns the report about the peer that gossiped the transaction that failed the
+/// verification or the insertion.
+///
+/// The peer is not reported if the transaction can't be inserted only because of
+/// the state or the local policy of the pool, like the minimal gas price.
+fn insertion_report(error: &anyhow::Error) -> Option<TransactionGossipReport> {
+    match error.downcast_ref::<Error>() {
+        Some(Error::NotInsertedTxKnown(_)) => Some(TransactionGossipReport::Duplicate),
+        Some(
+            Error::NotInsertedGasPriceTooLow { .. }
+            | Error::NotInsertedLimitHit
+            | Error::NotInsertedOwnerLimitHit(_)
+            | Error::NotInsertedTxPoolStopped
+            | Error::NotInsertedContractNotAllowed(_)
+            | Error::NotInsertedOwnerNotAllowed(_)
+            | Error::NotInsertedRejectedByPolicy(_),
+        ) => None,
+        Some(
+            Error::NotInsertedCollision(_, _)
+            | Error::NotInsertedCollisionContractId(_)
+            | Error::NotInsertedCollisionMessageId(_, _)
+            | Error::NotInsertedContractIdAlreadyTaken(_)
+            | Error::NotInsertedInputUtxoIdSpent(_)
+            | Error::NotInsertedInputMessageSpent(_),
+        ) => Some(TransactionGossipReport::Conflicting),
+        _ => Some(TransactionGossipReport::Invalid),
+    }
+}
+
+/// Selects the includable transactions of the `txpool` that fit into the `max_gas`
+/// in the order returned by the `order`.
+fn select_ordered_by<DB, F>(
+    txpool: &mut TxPool<DB>,
+    max_gas: u64,
+    order: F,
+) -> Vec<ArcPoolTx>
+where
+    DB: TxPoolDb,
+    F: FnOnce(Vec<TxInfo>) -> Vec<ArcPoolTx>,
+{
+    let includable: Vec<_> = txpool.includable().collect();
+    let txs = includable
+        .iter()
+        .filter_map(|tx| txpool.find_one(&tx.id()))
+        .collect();
+    select_transactions(order(txs).into_iter(), max_gas)
+}
+
 // TODO: Remove `find` and `find_one` methods from `txpool`. It is used only by GraphQL.
 //  Instead, `fuel-core` can create a `DatabaseWithTxPool` that aggregates `TxPool` and
 //  storage `Database` together. GraphQL will retrieve data from this `DatabaseWithTxPool` via
@@ -291,6 +621,18 @@ where
         self.txpool.lock().consumable_gas()
     }
 
+    pub fn total_bytes_size(&self) -> usize {
+        self.txpool.lock().bytes_size()
+    }
+
+    pub fn pending_number_by_owner(&self) -> HashMap<Address, usize> {
+        self.txpool.lock().pending_number_by_owner()
+    }
+
+    pub fn pending_txs(&self) -> Vec<TxInfo> {
+        self.txpool.lock().txs().values().cloned().collect()
+    }
+
     pub fn remove_txs(&self, ids: Vec<TxId>) -> Vec<ArcPoolTx> {
         self.txpool.lock().remove(&self.tx_status_sender, &ids)
     }
@@ -307,10 +649,126 @@ where
         self.txpool.lock().find_dependent(&ids)
     }
 
+    pub fn find_spenders(&self, utxo_id: UtxoId) -> Vec<TxId> {
+        self.txpool.lock().find_spenders(&utxo_id)
+    }
+
+    /// Returns the pending transactions in the order of their submission, so the
+    /// transactions go after the transactions they depend on.
+    pub fn pending_transactions(&self) -> Vec<Transaction> {
+        let txpool = self.txpool.lock();
+        let mut txs = txpool.txs().values().collect::<Vec<_>>();
+        txs.sort_by_key(|info| info.created());
+        txs.into_iter()
+            .map(|info| info.tx().as_ref().into())
+            .collect()
+    }
+
+    /// Switches to the new `consensus_params` and removes the pending transactions
+    /// that don't satisfy them anymore.
+    fn update_consensus_parameters(
+        &self,
+        consensus_params: ConsensusParameters,
+    ) -> anyhow::Result<()> {
+        let current_height = self.db.current_block_height()?;
+        *self.consensus_params.lock() = consensus_params.clone();
+        let removed = self.txpool.lock().revalidate(
+            &self.tx_status_sender,
+            current_height,
+            &consensus_params,
+        );
+        if !removed.is_empty() {
+            tracing::info!(
+                "Removed {} transactions invalidated by the new consensus parameters",
+                removed.len()
+            );
+        }
+        Ok(())
+    }
+
+    /// Inserts the transactions restored from the journal. The transactions that
+    /// are not valid anymore against the current state are skipped.
+    async fn restore(&self, txs: Vec<Transaction>) -> anyhow::Result<()> {
+        if txs.is_empty() {
+            return Ok(())
+        }
+        let current_height = self.db.current_block_height()?;
+        let txs: Vec<_> = txs.into_iter().map(Arc::new).collect();
+        let consensus_params = self.consensus_params.lock().clone();
+        let checked_txs =
+            check_transactions(&txs, current_height, &consensus_params, &self.config)
+                .await
+                .into_iter()
+                .filter_map(|result| result.ok())
+                .collect();
+        let result = self
+            .txpool
+            .lock()
+            .insert(&self.tx_status_sender, checked_txs);
+        let restored = result.iter().filter(|result| result.is_ok()).count();
+        tracing::info!(
+            "Restored {restored} of {} transactions from the txpool journal",
+            txs.len()
+        );
+        Ok(())
+    }
+
+    /// Inserts the transactions of the blocks reverted by the `reorg`, so they
+    /// can be included into the competing chain. The transactions are inserted
+    /// starting from the lowest block, so they can depend on each other.
+    async fn reinsert_reverted(&self, reorg: &Reorg) -> anyhow::Result<()> {
+        let txs: Vec<_> = reorg
+            .reverted_blocks
+            .iter()
+            .rev()
+            .flat_map(|block| block.entity.transactions())
+            .filter(|tx| !tx.is_mint())
+            .cloned()
+            .map(Arc::new)
+            .collect();
+        if txs.is_empty() {
+            return Ok(())
+        }
+        let current_height = self.db.current_block_height()?;
+        let consensus_params = self.consensus_params.lock().clone();
+        let checked_txs =
+            check_transactions(&txs, current_height, &consensus_params, &self.config)
+                .await
+                .into_iter()
+                .filter_map(|result| result.ok())
+                .collect();
+        let result = self
+            .txpool
+            .lock()
+            .insert(&self.tx_status_sender, checked_txs);
+        let reinserted = result.iter().filter(|result| result.is_ok()).count();
+        tracing::info!(
+            "Returned {reinserted} of {} transactions reverted by the reorg to the txpool",
+            txs.len()
+        );
+        Ok(())
+    }
+
     pub fn select_transactions(&self, max_gas: u64) -> Vec<ArcPoolTx> {
+        self.select_transactions_ordered_by(max_gas, |txs| {
+            txs.into_iter().map(|tx| tx.tx().clone()).collect()
+        })
+    }
+
+    /// Selects the transactions that fit into the `max_gas` in the order returned by
+    /// the `order` and removes them from the pool. The `order` receives all includable
+    /// transactions sorted by the gas price and must return the transactions after
+    /// the ones they depend on.
+    pub fn select_transactions_ordered_by<F>(
+        &self,
+        max_gas: u64,
+        order: F,
+    ) -> Vec<ArcPoolTx>
+    where
+        F: FnOnce(Vec<TxInfo>) -> Vec<ArcPoolTx>,
+    {
         let mut guard = self.txpool.lock();
-        let txs = guard.includable();
-        let sorted_txs = select_transactions(txs, max_gas);
+        let sorted_txs = select_ordered_by(&mut guard, max_gas, order);
 
         for tx in sorted_txs.iter() {
             guard.remove_committed_tx(&tx.id());
@@ -318,6 +776,20 @@ where
         sorted_txs
     }
 
+    /// The same as [`SharedState::select_transactions_ordered_by`], but leaves the
+    /// selected transactions in the pool.
+    pub fn peek_transactions_ordered_by<F>(
+        &self,
+        max_gas: u64,
+        order: F,
+    ) -> Vec<ArcPoolTx>
+    where
+        F: FnOnce(Vec<TxInfo>) -> Vec<ArcPoolTx>,
+    {
+        let mut guard = self.txpool.lock();
+        select_ordered_by(&mut guard, max_gas, order)
+    }
+
     pub fn remove(&self, ids: Vec<TxId>) -> Vec<ArcPoolTx> {
         self.txpool.lock().remove(&self.tx_status_sender, &ids)
     }
@@ -326,7 +798,45 @@ where
         self.tx_status_sender.new_tx_notification_sender.subscribe()
     }
 
+    /// Subscribes to the status updates of all transactions matching the `filter`.
+    /// Unlike [`SharedState::tx_update_subscribe`], the stream doesn't complete after
+    /// the final status of some transaction, and it skips the updates it lagged behind.
+    pub fn tx_update_subscribe_filtered(
+        &self,
+        filter: TxStatusFilter,
+    ) -> BoxStream<TxStatusUpdate> {
+        if self.stopped.load(Ordering::Acquire) {
+            return Box::pin(tokio_stream::empty())
+        }
+        let receiver = self.tx_status_sender.status_update_sender.subscribe();
+        Box::pin(futures::stream::unfold(
+            (receiver, filter),
+            |(mut receiver, filter)| async move {
+                loop {
+                    match receiver.recv().await {
+                        Ok(update) if filter.matches(&update) => {
+                            return Some((update, (receiver, filter)))
+                        }
+                        Ok(_) => continue,
+                        Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                            tracing::warn!(
+                                "The filtered status subscription skipped {skipped} updates"
+                            );
+                        }
+                        Err(broadcast::error::RecvError::Closed) => return None,
+                    }
+                }
+            },
+        ))
+    }
+
+    /// Subscribes to the status updates of the transaction with `tx_id`.
+    /// The stream yields only the updates of this transaction and completes
+    /// once the transaction reaches a final status.
     pub async fn tx_update_subscribe(&self, tx_id: Bytes32) -> TxStatusStream {
+        if self.stopped.load(Ordering::Acquire) {
+            return Box::pin(tokio_stream::empty())
+        }
         self.tx_status_sender
             .update_sender
             .subscribe::<MpscChannel>(tx_id)
@@ -344,6 +854,59 @@ where
         &self,
         txs: Vec<Arc<Transaction>>,
     ) -> Vec<anyhow::Result<InsertionResult>> {
+        self.insert_with_priority(txs, None).await
+    }
+
+    /// Inserts the transactions without broadcasting them to the network, so they
+    /// are only included into the blocks produced by this node.
+    ///
+    /// The same as for the transactions received from the network, the transactions
+    /// are not rebroadcasted.
+    #[tracing::instrument(name = "insert_local_txn", skip_all)]
+    pub async fn insert_local(
+        &self,
+        txs: Vec<Arc<Transaction>>,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        self.insert_and_broadcast(txs, None, false).await
+    }
+
+    /// Inserts the batch of transactions in their order, so later transactions
+    /// may depend on the earlier ones. The whole batch is rejected if some
+    /// transaction depends on the transaction that follows it.
+    pub async fn insert_batch(
+        &self,
+        txs: Vec<Arc<Transaction>>,
+    ) -> anyhow::Result<Vec<anyhow::Result<InsertionResult>>> {
+        check_batch_order(&txs, &self.consensus_params.lock().chain_id)?;
+        Ok(self.insert(txs).await)
+    }
+
+    /// Inserts the transactions with the `priority` that overrides the gas price ordering
+    /// of the block building, so the transactions are selected before others.
+    ///
+    /// It is intended for testing and privileged local submission only. Gossiped
+    /// transactions are never inserted with the priority.
+    pub async fn insert_with_priority(
+        &self,
+        txs: Vec<Arc<Transaction>>,
+        priority: Option<u64>,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        self.insert_and_broadcast(txs, priority, true).await
+    }
+
+    async fn insert_and_broadcast(
+        &self,
+        txs: Vec<Arc<Transaction>>,
+        priority: Option<u64>,
+        broadcast: bool,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        if self.stopped.load(Ordering::Acquire) {
+            return txs
+                .iter()
+                .map(|_| Err(Error::NotInsertedTxPoolStopped.into()))
+                .collect()
+        }
+
         // verify txs
         let block_height = self.db.current_block_height();
         let current_height = match block_height {
@@ -351,15 +914,21 @@ where
             Err(e) => return vec![Err(e.into())],
         };
 
-        let checked_txs = check_transactions(&txs, current_height, &self.config).await;
+        let consensus_params = self.consensus_params.lock().clone();
+        let checked_txs =
+            check_transactions(&txs, current_height, &consensus_params, &self.config)
+                .await;
 
         let mut valid_txs = vec![];
+        let mut valid_raw_txs = vec![];
 
         let checked_txs: Vec<_> = checked_txs
             .into_iter()
-            .map(|tx_check| match tx_check {
+            .zip(txs.into_iter())
+            .map(|(tx_check, raw_tx)| match tx_check {
                 Ok(tx) => {
                     valid_txs.push(tx);
+                    valid_raw_txs.push(raw_tx);
                     None
                 }
                 Err(err) => Some(err),
@@ -367,27 +936,35 @@ where
             .collect();
 
         // insert txs
-        let insertion = { self.txpool.lock().insert(&self.tx_status_sender, valid_txs) };
-
-        for (ret, tx) in insertion.iter().zip(txs.into_iter()) {
-            match ret {
-                Ok(_) => {
-                    let result = self.p2p.broadcast_transaction(tx.clone());
-                    if let Err(e) = result {
-                        // It can be only in the case of p2p being down or requests overloading it.
-                        tracing::error!(
-                            "Unable to broadcast transaction, got an {} error",
-                            e
-                        );
-                    }
-                }
-                Err(_) => {}
+        let insertion = {
+            self.txpool.lock().insert_with_priority(
+                &self.tx_status_sender,
+                valid_txs,
+                priority,
+            )
+        };
+
+        if broadcast {
+            // only transactions accepted by the pool are broadcasted
+            let mut inserted_txs: Vec<_> = insertion
+                .iter()
+                .zip(valid_raw_txs.into_iter())
+                .filter_map(|(ret, tx)| ret.as_ref().ok().map(|_| tx))
+                .collect();
+            let result = match inserted_txs.len() {
+                0 => Ok(()),
+                1 => self.p2p.broadcast_transaction(inserted_txs.remove(0)),
+                _ => self.p2p.broadcast_transactions(inserted_txs),
+            };
+            if let Err(e) = result {
+                // It can be only in the case of p2p being down or requests overloading it.
+                tracing::error!("Unable to broadcast transactions, got an {} error", e);
             }
         }
 
         let mut insertion = insertion.into_iter();
 
-        checked_txs
+        let results: Vec<_> = checked_txs
             .into_iter()
             .map(|check_result| match check_result {
                 None => insertion.next().unwrap_or_else(|| {
@@ -397,7 +974,41 @@ where
                 }),
                 Some(err) => Err(err),
             })
-            .collect()
+            .collect();
+
+        if self.config.metrics {
+            metrics::observe_insertion(TxSource::Local, &results);
+        }
+
+        results
+    }
+
+    /// Reports the validity of the gossiped transaction and, if any, the `report`
+    /// about the peer that sent it.
+    ///
+    /// `Ignore` is also reported, so the message is not propagated further,
+    /// but the reputation of the peer is affected only by the `report`.
+    fn report_gossiped_tx(
+        &self,
+        message_info: GossipsubMessageInfo,
+        acceptance: GossipsubMessageAcceptance,
+        report: Option<TransactionGossipReport>,
+    ) {
+        let peer_id = message_info.peer_id.clone();
+        let result = self
+            .p2p
+            .notify_gossip_transaction_validity(message_info, acceptance);
+        if let Err(e) = result {
+            tracing::error!(
+                "Unable to report the validity of the gossiped transaction, got an {e} error"
+            );
+        }
+
+        if let Some(report) = report {
+            if let Err(e) = self.p2p.report_peer(peer_id, report) {
+                tracing::error!("Unable to report the peer, got an {e} error");
+            }
+        }
     }
 }
 
@@ -440,15 +1051,25 @@ where
 {
     let p2p = Arc::new(p2p);
     let gossiped_tx_stream = p2p.gossiped_transaction_events();
+    let requested_tx_stream = p2p.requested_transaction_events();
     let committed_block_stream = importer.block_events();
+    let consensus_parameters_stream = importer.consensus_parameters_events();
+    let reorg_stream = importer.reorg_events();
     let mut ttl_timer = tokio::time::interval(config.transaction_ttl);
     ttl_timer.set_missed_tick_behavior(MissedTickBehavior::Skip);
-    let consensus_params = config.chain_config.consensus_parameters.clone();
+    let consensus_params = Arc::new(ParkingMutex::new(
+        config.chain_config.consensus_parameters.clone(),
+    ));
     let number_of_active_subscription = config.number_of_active_subscription;
+    let gossip_rate_limiter =
+        PeerRateLimiter::new(config.max_gossiped_tx_per_peer, Duration::from_secs(1));
     let txpool = Arc::new(ParkingMutex::new(TxPool::new(config.clone(), db.clone())));
     let task = Task {
         gossiped_tx_stream,
+        requested_tx_stream,
         committed_block_stream,
+        consensus_parameters_stream,
+        reorg_stream,
         shared: SharedState {
             tx_status_sender: TxStatusChange::new(number_of_active_subscription),
             txpool,
@@ -456,8 +1077,11 @@ where
             consensus_params,
             db,
             config,
+            stopped: Arc::new(AtomicBool::new(false)),
         },
         ttl_timer,
+        gossip_rate_limiter,
+        orphans: OrphanTransactions::new(MAX_ORPHAN_TRANSACTIONS),
     };
 
     Service::new(task)
@@ -0,0 +1,171 @@
+use fuel_core_types::{
+    fuel_tx::{
+        Transaction,
+        TxId,
+        UtxoId,
+    },
+    fuel_vm::checked_transaction::Checked,
+};
+use std::collections::{
+    HashMap,
+    VecDeque,
+};
+
+/// The max number of the gossiped transactions waiting for their parents.
+pub(super) const MAX_ORPHAN_TRANSACTIONS: usize = 256;
+
+/// The gossiped transactions that spend the outputs of the unknown parents.
+/// They are inserted again once the parent lands in the pool.
+/// When the capacity is reached, the oldest orphan is evicted.
+pub(super) struct OrphanTransactions {
+    capacity: usize,
+    /// The ids of the orphans by their missing input.
+    by_missing_input: HashMap<UtxoId, Vec<TxId>>,
+    orphans: HashMap<TxId, (UtxoId, Checked<Transaction>)>,
+    order: VecDeque<TxId>,
+}
+
+impl OrphanTransactions {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            by_missing_input: HashMap::new(),
+            orphans: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    /// Keeps the `tx` until the transaction with the `missing_input` is inserted.
+    pub fn insert(
+        &mut self,
+        tx_id: TxId,
+        missing_input: UtxoId,
+        tx: Checked<Transaction>,
+    ) {
+        if self.orphans.contains_key(&tx_id) {
+            return
+        }
+
+        self.orphans.insert(tx_id, (missing_input, tx));
+        self.by_missing_input
+            .entry(missing_input)
+            .or_default()
+            .push(tx_id);
+        self.order.push_back(tx_id);
+
+        if self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.remove(&oldest);
+            }
+        }
+    }
+
+    /// Takes the orphans spending the outputs of the `parent` with `outputs` outputs.
+    pub fn take_children(
+        &mut self,
+        parent: &TxId,
+        outputs: usize,
+    ) -> Vec<Checked<Transaction>> {
+        let children: Vec<_> = (0..outputs)
+            .filter_map(|index| u8::try_from(index).ok())
+            .filter_map(|index| {
+                self.by_missing_input.remove(&UtxoId::new(*parent, index))
+            })
+            .flatten()
+            .collect();
+
+        if !children.is_empty() {
+            self.order.retain(|tx_id| !children.contains(tx_id));
+        }
+
+        children
+            .into_iter()
+            .filter_map(|tx_id| self.orphans.remove(&tx_id))
+            .map(|(_, tx)| tx)
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.orphans.len()
+    }
+
+    fn remove(&mut self, tx_id: &TxId) {
+        if let Some((missing_input, _)) = self.orphans.remove(tx_id) {
+            if let Some(tx_ids) = self.by_missing_input.get_mut(&missing_input) {
+                tx_ids.retain(|id| id != tx_id);
+                if tx_ids.is_empty() {
+                    self.by_missing_input.remove(&missing_input);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::setup_coin;
+    use fuel_core_types::{
+        fuel_crypto::rand::{
+            rngs::StdRng,
+            SeedableRng,
+        },
+        fuel_tx::{
+            ConsensusParameters,
+            TransactionBuilder,
+        },
+        fuel_vm::checked_transaction::IntoChecked,
+    };
+
+    fn orphan(rng: &mut StdRng) -> (TxId, Checked<Transaction>) {
+        let (_, gas_coin) = setup_coin(rng, None);
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .gas_limit(1000)
+            .add_input(gas_coin)
+            .finalize_as_transaction();
+        let params = ConsensusParameters::default();
+        let checked = tx
+            .into_checked_basic(Default::default(), &params)
+            .expect("the transaction should be valid");
+        (checked.id(), checked)
+    }
+
+    #[test]
+    fn children_are_taken_by_the_outputs_of_the_parent() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut orphans = OrphanTransactions::new(10);
+        let parent = TxId::from([1; 32]);
+        let other_parent = TxId::from([2; 32]);
+        let (first_id, first) = orphan(&mut rng);
+        let (second_id, second) = orphan(&mut rng);
+        let (other_id, other) = orphan(&mut rng);
+        orphans.insert(first_id, UtxoId::new(parent, 0), first);
+        orphans.insert(second_id, UtxoId::new(parent, 1), second);
+        orphans.insert(other_id, UtxoId::new(other_parent, 0), other);
+
+        // The second child spends the output that the parent doesn't have
+        let children = orphans.take_children(&parent, 1);
+
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].id(), first_id);
+        assert_eq!(orphans.len(), 2);
+        assert!(orphans.take_children(&parent, 1).is_empty());
+    }
+
+    #[test]
+    fn the_oldest_orphan_is_evicted() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut orphans = OrphanTransactions::new(1);
+        let parent = TxId::from([1; 32]);
+        let (first_id, first) = orphan(&mut rng);
+        let (second_id, second) = orphan(&mut rng);
+        orphans.insert(first_id, UtxoId::new(parent, 0), first);
+        orphans.insert(second_id, UtxoId::new(parent, 0), second);
+
+        let children = orphans.take_children(&parent, 1);
+
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].id(), second_id);
+        assert_eq!(orphans.len(), 0);
+    }
+}
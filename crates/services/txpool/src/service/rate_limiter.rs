@@ -0,0 +1,75 @@
+use fuel_core_types::services::p2p::PeerId;
+use std::{
+    collections::HashMap,
+    time::Duration,
+};
+use tokio::time::Instant;
+
+/// Limits the number of transactions gossiped by each peer within a time window.
+pub(super) struct PeerRateLimiter {
+    limit: usize,
+    window: Duration,
+    /// The start of the current window and the number of transactions received
+    /// from the peer within it.
+    peers: HashMap<PeerId, (Instant, usize)>,
+}
+
+impl PeerRateLimiter {
+    pub fn new(limit: usize, window: Duration) -> Self {
+        Self {
+            limit,
+            window,
+            peers: HashMap::new(),
+        }
+    }
+
+    /// Registers the transaction received from the `peer_id`.
+    /// Returns `false` if the peer exceeded the limit within the current window.
+    pub fn check(&mut self, peer_id: &PeerId, now: Instant) -> bool {
+        let (window_start, count) = self.peers.entry(peer_id.clone()).or_insert((now, 0));
+        if now.duration_since(*window_start) >= self.window {
+            *window_start = now;
+            *count = 0;
+        }
+        *count = count.saturating_add(1);
+        *count <= self.limit
+    }
+
+    /// Forgets the peers whose window has expired.
+    pub fn prune(&mut self, now: Instant) {
+        let window = self.window;
+        self.peers
+            .retain(|_, (window_start, _)| now.duration_since(*window_start) < window);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn peer_is_limited_within_the_window() {
+        let mut limiter = PeerRateLimiter::new(2, Duration::from_secs(1));
+        let peer = PeerId::from(vec![1]);
+        let other_peer = PeerId::from(vec![2]);
+        let now = Instant::now();
+
+        assert!(limiter.check(&peer, now));
+        assert!(limiter.check(&peer, now));
+        assert!(!limiter.check(&peer, now));
+        assert!(limiter.check(&other_peer, now));
+        assert!(limiter.check(&peer, now + Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn expired_peers_are_pruned() {
+        let mut limiter = PeerRateLimiter::new(1, Duration::from_secs(1));
+        let peer = PeerId::from(vec![1]);
+        let now = Instant::now();
+        limiter.check(&peer, now);
+
+        limiter.prune(now + Duration::from_secs(1));
+
+        assert!(limiter.peers.is_empty());
+    }
+}
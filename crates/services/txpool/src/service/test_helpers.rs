@@ -21,7 +21,10 @@ use fuel_core_types::{
         TransactionBuilder,
         Word,
     },
-    services::p2p::GossipsubMessageAcceptance,
+    services::p2p::{
+        GossipsubMessageAcceptance,
+        PeerId,
+    },
 };
 use std::cell::RefCell;
 
@@ -42,6 +45,10 @@ impl TestContext {
         &self.service
     }
 
+    pub fn mock_db(&self) -> &MockDb {
+        &self.mock_db
+    }
+
     pub fn setup_script_tx(&self, gas_price: Word) -> Transaction {
         let (_, gas_coin) = self.setup_coin();
         let mut tx = TransactionBuilder::script(vec![], vec![])
@@ -68,6 +75,11 @@ mockall::mock! {
 
         fn broadcast_transaction(&self, transaction: Arc<Transaction>) -> anyhow::Result<()>;
 
+        fn broadcast_transactions(
+            &self,
+            transactions: Vec<Arc<Transaction>>,
+        ) -> anyhow::Result<()>;
+
         fn gossiped_transaction_events(&self) -> BoxStream<GossipedTransaction>;
 
         fn notify_gossip_transaction_validity(
@@ -75,11 +87,33 @@ mockall::mock! {
             message_info: GossipsubMessageInfo,
             validity: GossipsubMessageAcceptance,
         ) -> anyhow::Result<()>;
+
+        fn request_transactions(
+            &self,
+            peer_id: PeerId,
+            tx_ids: Vec<TxId>,
+        ) -> anyhow::Result<()>;
+
+        fn report_peer(
+            &self,
+            peer_id: PeerId,
+            report: TransactionGossipReport,
+        ) -> anyhow::Result<()>;
+
+        fn requested_transaction_events(&self) -> BoxStream<Vec<Transaction>>;
     }
 }
 
 impl MockP2P {
     pub fn new_with_txs(txs: Vec<Transaction>) -> Self {
+        let mut p2p = Self::new_with_txs_without_reports(txs);
+        p2p.expect_report_peer().returning(|_, _| Ok(()));
+        p2p
+    }
+
+    /// The same as [`MockP2P::new_with_txs`], but the expectations of the peer
+    /// reports are left to the caller.
+    pub fn new_with_txs_without_reports(txs: Vec<Transaction>) -> Self {
         let mut p2p = MockP2P::default();
         p2p.expect_gossiped_transaction_events().returning(move || {
             let txs_clone = txs.clone();
@@ -95,6 +129,10 @@ impl MockP2P {
         });
         p2p.expect_broadcast_transaction()
             .returning(move |_| Ok(()));
+        p2p.expect_broadcast_transactions()
+            .returning(move |_| Ok(()));
+        p2p.expect_requested_transaction_events()
+            .returning(|| Box::pin(fuel_core_services::stream::pending()));
         p2p
     }
 }
@@ -104,6 +142,10 @@ mockall::mock! {
 
     impl BlockImporter for Importer {
         fn block_events(&self) -> BoxStream<Arc<ImportResult>>;
+
+        fn consensus_parameters_events(&self) -> BoxStream<ConsensusParameters>;
+
+        fn reorg_events(&self) -> BoxStream<Arc<Reorg>>;
     }
 }
 
@@ -126,6 +168,12 @@ impl MockImporter {
             Box::pin(stream)
         });
         importer
+            .expect_consensus_parameters_events()
+            .returning(|| Box::pin(fuel_core_services::stream::pending()));
+        importer
+            .expect_reorg_events()
+            .returning(|| Box::pin(fuel_core_services::stream::pending()));
+        importer
     }
 }
 
@@ -167,6 +215,10 @@ impl TestContextBuilder {
         self.p2p = Some(p2p)
     }
 
+    pub fn with_mock_db(&mut self, mock_db: MockDb) {
+        self.mock_db = mock_db
+    }
+
     pub fn setup_script_tx(&mut self, gas_price: Word) -> Transaction {
         let (_, gas_coin) = self.setup_coin();
         TransactionBuilder::script(vec![], vec![])
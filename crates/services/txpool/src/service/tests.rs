@@ -1,11 +1,19 @@
 use super::*;
 use crate::service::test_helpers::{
+    MockImporter,
     TestContext,
     TestContextBuilder,
 };
 use fuel_core_services::Service as ServiceTrait;
 use fuel_core_types::{
-    fuel_tx::UniqueIdentifier,
+    blockchain::{
+        block::Block,
+        SealedBlock,
+    },
+    fuel_tx::{
+        field::Inputs,
+        UniqueIdentifier,
+    },
     fuel_types::ChainId,
 };
 use std::time::Duration;
@@ -267,3 +275,280 @@ async fn simple_insert_removal_subscription() {
 
     service.stop_and_await().await.unwrap();
 }
+
+#[tokio::test]
+async fn tx_update_subscribe_yields_only_updates_of_the_tx() {
+    let ctx = TestContext::new().await;
+
+    let tx1 = Arc::new(ctx.setup_script_tx(10));
+    let tx2 = Arc::new(ctx.setup_script_tx(20));
+
+    let service = ctx.service();
+    let tx1_updates = service
+        .shared
+        .tx_update_subscribe(tx1.id(&Default::default()))
+        .await;
+
+    let out = service.shared.insert(vec![tx1.clone(), tx2.clone()]).await;
+    assert!(out.iter().all(|result| result.is_ok()), "{out:?}");
+    service.shared.remove(vec![
+        tx1.id(&Default::default()),
+        tx2.id(&Default::default()),
+    ]);
+
+    // the stream completes after the final status of tx1
+    let updates: Vec<_> =
+        tokio::time::timeout(Duration::from_secs(1), tx1_updates.collect())
+            .await
+            .expect("the stream should complete after the final status");
+    assert_eq!(updates.len(), 2, "Got {updates:?}");
+    assert!(matches!(
+        updates[0],
+        TxStatusMessage::Status(TransactionStatus::Submitted { .. })
+    ));
+    assert_eq!(
+        updates[1],
+        TxStatusMessage::Status(TransactionStatus::SqueezedOut {
+            reason: "Transaction removed.".to_string()
+        })
+    );
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn filtered_subscription_yields_only_updates_of_the_owner() {
+    let ctx = TestContext::new().await;
+
+    let tx1 = Arc::new(ctx.setup_script_tx(10));
+    let tx2 = Arc::new(ctx.setup_script_tx(20));
+    let tx1_id = tx1.id(&Default::default());
+    let owner = *tx1.as_script().unwrap().inputs()[0].input_owner().unwrap();
+
+    let service = ctx.service();
+    let mut owner_updates = service
+        .shared
+        .tx_update_subscribe_filtered(TxStatusFilter::Owner(owner));
+
+    let out = service.shared.insert(vec![tx1.clone(), tx2.clone()]).await;
+    assert!(out.iter().all(|result| result.is_ok()), "{out:?}");
+    service
+        .shared
+        .remove(vec![tx2.id(&Default::default()), tx1_id]);
+
+    let submitted = owner_updates.next().await.unwrap();
+    assert_eq!(submitted.tx_id, tx1_id);
+    assert!(submitted.owners.contains(&owner));
+    assert!(matches!(
+        submitted.message,
+        TxStatusMessage::Status(TransactionStatus::Submitted { .. })
+    ));
+    // the updates of tx2 are skipped
+    let squeezed_out = owner_updates.next().await.unwrap();
+    assert_eq!(squeezed_out.tx_id, tx1_id);
+    assert_eq!(
+        squeezed_out.message,
+        TxStatusMessage::Status(TransactionStatus::SqueezedOut {
+            reason: "Transaction removed.".to_string()
+        })
+    );
+    // unlike the per transaction subscription, the stream doesn't complete
+    assert!(
+        tokio::time::timeout(Duration::from_millis(100), owner_updates.next())
+            .await
+            .is_err()
+    );
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn txs_invalidated_by_new_consensus_parameters_are_removed() {
+    let (params_sender, params_receiver) = tokio::sync::mpsc::channel(1);
+    let mut importer = MockImporter::default();
+    importer
+        .expect_block_events()
+        .returning(|| Box::pin(fuel_core_services::stream::pending()));
+    importer
+        .expect_reorg_events()
+        .returning(|| Box::pin(fuel_core_services::stream::pending()));
+    importer
+        .expect_consensus_parameters_events()
+        .return_once(move || {
+            Box::pin(tokio_stream::wrappers::ReceiverStream::new(params_receiver))
+        });
+    let mut ctx_builder = TestContextBuilder::new();
+    ctx_builder.with_importer(importer);
+    let tx = ctx_builder.setup_script_tx(10);
+    let tx_id = tx.id(&Default::default());
+    let ctx = ctx_builder.build_and_start().await;
+
+    let service = ctx.service();
+    let mut tx_updates = service.shared.tx_update_subscribe(tx_id).await;
+    let out = service.shared.insert(vec![Arc::new(tx)]).await;
+    assert!(out[0].is_ok(), "Tx should be OK, got err:{out:?}");
+
+    // the gas limit of the tx exceeds the new maximum
+    let mut consensus_params = ConsensusParameters::default();
+    consensus_params.tx_params.max_gas_per_tx = 100;
+    params_sender.send(consensus_params).await.unwrap();
+
+    assert!(matches!(
+        tx_updates.next().await,
+        Some(TxStatusMessage::Status(TransactionStatus::Submitted { .. }))
+    ));
+    assert!(matches!(
+        tx_updates.next().await,
+        Some(TxStatusMessage::Status(
+            TransactionStatus::SqueezedOut { .. }
+        ))
+    ));
+    assert!(service.shared.find_one(tx_id).is_none());
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn txs_of_reverted_blocks_are_reinserted() {
+    let (reorg_sender, reorg_receiver) = tokio::sync::mpsc::channel(1);
+    let mut importer = MockImporter::default();
+    importer
+        .expect_block_events()
+        .returning(|| Box::pin(fuel_core_services::stream::pending()));
+    importer
+        .expect_consensus_parameters_events()
+        .returning(|| Box::pin(fuel_core_services::stream::pending()));
+    importer.expect_reorg_events().return_once(move || {
+        Box::pin(tokio_stream::wrappers::ReceiverStream::new(reorg_receiver))
+    });
+    let mut ctx_builder = TestContextBuilder::new();
+    ctx_builder.with_importer(importer);
+    let tx = ctx_builder.setup_script_tx(10);
+    let tx_id = tx.id(&Default::default());
+    let ctx = ctx_builder.build_and_start().await;
+
+    let service = ctx.service();
+    let mut tx_updates = service.shared.tx_update_subscribe(tx_id).await;
+    let block = Block::new(Default::default(), vec![tx], &[]);
+    let reorg = Reorg {
+        common_ancestor: Default::default(),
+        reverted_blocks: vec![SealedBlock {
+            entity: block,
+            consensus: Default::default(),
+        }],
+    };
+    reorg_sender.send(Arc::new(reorg)).await.unwrap();
+
+    assert!(matches!(
+        tx_updates.next().await,
+        Some(TxStatusMessage::Status(TransactionStatus::Submitted { .. }))
+    ));
+    assert!(service.shared.find_one(tx_id).is_some());
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn stop_closes_subscriptions_and_rejects_new_txs() {
+    let ctx = TestContext::new().await;
+
+    let tx1 = Arc::new(ctx.setup_script_tx(10));
+    let tx2 = Arc::new(ctx.setup_script_tx(20));
+
+    let service = ctx.service();
+    let mut update = service
+        .shared
+        .tx_update_subscribe(tx1.id(&Default::default()))
+        .await;
+    let out = service.shared.insert(vec![tx1.clone()]).await;
+    assert!(out[0].is_ok(), "Tx1 should be OK, got err:{out:?}");
+
+    let state = service.stop_and_await().await.unwrap();
+    assert!(state.stopped(), "the service should stop without errors");
+
+    // the subscription receives the submitted status and is closed afterwards
+    assert!(matches!(
+        update.next().await,
+        Some(TxStatusMessage::Status(TransactionStatus::Submitted { .. }))
+    ));
+    assert!(tokio::time::timeout(Duration::from_secs(1), update.next())
+        .await
+        .expect("the subscription should be closed")
+        .is_none());
+
+    let out = service.shared.insert(vec![tx2]).await;
+    assert!(matches!(
+        out[0].as_ref().unwrap_err().downcast_ref::<Error>(),
+        Some(Error::NotInsertedTxPoolStopped)
+    ));
+}
+
+#[tokio::test]
+async fn transactions_with_priority_are_selected_first() {
+    let ctx = TestContext::new().await;
+
+    let prioritized = Arc::new(ctx.setup_script_tx(1));
+    let expensive = Arc::new(ctx.setup_script_tx(100));
+
+    let service = ctx.service();
+    let out = service
+        .shared
+        .insert_with_priority(vec![prioritized.clone()], Some(u64::MAX))
+        .await;
+    assert!(out[0].is_ok(), "Tx1 should be OK, got err:{out:?}");
+    let out = service.shared.insert(vec![expensive.clone()]).await;
+    assert!(out[0].is_ok(), "Tx2 should be OK, got err:{out:?}");
+
+    let selected = service.shared.select_transactions(u64::MAX);
+    assert_eq!(selected.len(), 2, "Should be len 2:{selected:?}");
+    assert_eq!(selected[0].id(), prioritized.id(&Default::default()));
+    assert_eq!(selected[1].id(), expensive.id(&Default::default()));
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn pending_txs_are_restored_after_restart() {
+    let journal_path = std::env::temp_dir().join(format!(
+        "fuel-core-txpool-journal-restart-{}",
+        std::process::id()
+    ));
+    let config = Config {
+        journal_path: Some(journal_path.clone()),
+        ..Default::default()
+    };
+    let ctx = TestContextBuilder::new()
+        .with_config(config.clone())
+        .build_and_start()
+        .await;
+
+    let tx1 = Arc::new(ctx.setup_script_tx(10));
+    let tx2 = Arc::new(ctx.setup_script_tx(20));
+    let out = ctx
+        .service()
+        .shared
+        .insert(vec![tx1.clone(), tx2.clone()])
+        .await;
+    assert!(
+        out.iter().all(Result::is_ok),
+        "Txs should be OK, got err:{out:?}"
+    );
+    ctx.service().stop_and_await().await.unwrap();
+
+    // the coin of the second transaction is spent while the node is stopped
+    let spent_coin = *tx2.as_script().unwrap().inputs()[0].utxo_id().unwrap();
+    ctx.mock_db().data.lock().unwrap().coins.remove(&spent_coin);
+
+    let mut builder = TestContextBuilder::new().with_config(config);
+    builder.with_mock_db(ctx.mock_db().clone());
+    let restarted = builder.build_and_start().await;
+    let out = restarted.service().shared.find(vec![
+        tx1.id(&Default::default()),
+        tx2.id(&Default::default()),
+    ]);
+    restarted.service().stop_and_await().await.unwrap();
+    std::fs::remove_file(&journal_path).unwrap();
+
+    assert!(out[0].is_some(), "Tx1 should be restored:{out:?}");
+    assert!(out[1].is_none(), "Tx2 should be skipped:{out:?}");
+}
@@ -1,12 +1,23 @@
 use super::*;
-use crate::service::test_helpers::{
-    MockP2P,
-    TestContextBuilder,
+use crate::{
+    service::test_helpers::{
+        MockP2P,
+        TestContextBuilder,
+    },
+    test_helpers::create_output_and_input,
 };
 use fuel_core_services::Service;
-use fuel_core_types::fuel_tx::{
-    Transaction,
-    UniqueIdentifier,
+use fuel_core_types::{
+    fuel_crypto::rand::{
+        rngs::StdRng,
+        SeedableRng,
+    },
+    fuel_tx::{
+        Transaction,
+        TransactionBuilder,
+        UniqueIdentifier,
+        UtxoId,
+    },
 };
 use std::{
     ops::Deref,
@@ -93,6 +104,77 @@ async fn insert_from_local_broadcasts_to_p2p() {
     }
 }
 
+#[tokio::test]
+async fn insert_local_tx_is_not_broadcasted() {
+    let mut ctx_builder = TestContextBuilder::new();
+    let tx1 = ctx_builder.setup_script_tx(10);
+
+    let mut p2p = MockP2P::default();
+    p2p.expect_gossiped_transaction_events()
+        .returning(|| Box::pin(fuel_core_services::stream::pending()));
+    p2p.expect_requested_transaction_events()
+        .returning(|| Box::pin(fuel_core_services::stream::pending()));
+    p2p.expect_broadcast_transaction().never();
+    p2p.expect_broadcast_transactions().never();
+    ctx_builder.with_p2p(p2p);
+
+    let ctx = ctx_builder.build_and_start().await;
+    let service = ctx.service();
+
+    let out = service
+        .shared
+        .insert_local(vec![Arc::new(tx1.clone())])
+        .await;
+    assert!(out[0].is_ok(), "Tx1 should be OK, got err:{out:?}");
+    assert!(service
+        .shared
+        .find_one(tx1.id(&Default::default()))
+        .is_some());
+}
+
+#[tokio::test]
+async fn insert_batch_from_local_broadcasts_to_p2p_once() {
+    let mut ctx_builder = TestContextBuilder::new();
+    let txs = vec![
+        ctx_builder.setup_script_tx(10),
+        ctx_builder.setup_script_tx(20),
+        ctx_builder.setup_script_tx(30),
+    ];
+
+    let mut p2p = MockP2P::default();
+    p2p.expect_gossiped_transaction_events()
+        .returning(|| Box::pin(fuel_core_services::stream::pending()));
+    p2p.expect_requested_transaction_events()
+        .returning(|| Box::pin(fuel_core_services::stream::pending()));
+    p2p.expect_broadcast_transaction().never();
+    let (send, mut receive) = broadcast::channel::<Vec<Arc<Transaction>>>(2);
+    p2p.expect_broadcast_transactions()
+        .times(1)
+        .returning(move |transactions| {
+            send.send(transactions).unwrap();
+            Ok(())
+        });
+    ctx_builder.with_p2p(p2p);
+
+    let ctx = ctx_builder.build_and_start().await;
+    let service = ctx.service();
+
+    let out = service
+        .shared
+        .insert(txs.iter().cloned().map(Arc::new).collect())
+        .await;
+    assert!(out.iter().all(|result| result.is_ok()));
+
+    let broadcasted = receive.try_recv().expect("the batch should be broadcasted");
+    let broadcasted: Vec<Transaction> =
+        broadcasted.iter().map(|tx| tx.as_ref().clone()).collect();
+    assert_eq!(broadcasted, txs);
+    assert!(
+        receive.try_recv().is_err(),
+        "only one broadcast is expected"
+    );
+}
+
 #[tokio::test]
 async fn test_insert_from_p2p_does_not_broadcast_to_p2p() {
     let mut ctx_builder = TestContextBuilder::new();
@@ -133,3 +215,443 @@ async fn test_insert_from_p2p_does_not_broadcast_to_p2p() {
         "expected a timeout because no broadcast should have occurred"
     )
 }
+
+#[tokio::test]
+async fn insert_from_p2p_requests_unknown_parent() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut ctx_builder = TestContextBuilder::new();
+    let (_, gas_coin) = ctx_builder.setup_coin();
+    let (output, unset_input) = create_output_and_input(&mut rng, 10);
+    let parent = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .gas_limit(1000)
+        .add_input(gas_coin)
+        .add_output(output)
+        .finalize_as_transaction();
+    let parent_id = parent.id(&Default::default());
+
+    // the child spends the output of the parent that is unknown to the txpool
+    let (_, gas_coin) = ctx_builder.setup_coin();
+    let child = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .gas_limit(1000)
+        .add_input(gas_coin)
+        .add_input(unset_input.into_input(UtxoId::new(parent_id, 0)))
+        .finalize_as_transaction();
+
+    let mut p2p = MockP2P::new_with_txs(vec![child]);
+    let (send, mut receive) = broadcast::channel::<Vec<TxId>>(1);
+    p2p.expect_request_transactions()
+        .returning(move |_, tx_ids| {
+            send.send(tx_ids).unwrap();
+            Ok(())
+        });
+    ctx_builder.with_p2p(p2p);
+
+    let _ctx = ctx_builder.build_and_start().await;
+
+    let requested = tokio::time::timeout(Duration::from_secs(1), receive.recv())
+        .await
+        .expect("the parent should be requested")
+        .unwrap();
+    assert_eq!(requested, vec![parent_id]);
+}
+
+#[tokio::test]
+async fn gossiped_orphan_is_inserted_when_the_requested_parent_lands() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut ctx_builder = TestContextBuilder::new();
+    let (_, gas_coin) = ctx_builder.setup_coin();
+    let (output, unset_input) = create_output_and_input(&mut rng, 10);
+    let parent = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .gas_limit(1000)
+        .add_input(gas_coin)
+        .add_output(output)
+        .finalize_as_transaction();
+    let parent_id = parent.id(&Default::default());
+
+    // the child spends the output of the parent that is unknown to the txpool
+    let (_, gas_coin) = ctx_builder.setup_coin();
+    let child = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .gas_limit(1000)
+        .add_input(gas_coin)
+        .add_input(unset_input.into_input(UtxoId::new(parent_id, 0)))
+        .finalize_as_transaction();
+    let child_id = child.id(&Default::default());
+
+    let mut p2p = MockP2P::default();
+    p2p.expect_gossiped_transaction_events().returning(move || {
+        Box::pin(fuel_core_services::stream::unfold(
+            Some(child.clone()),
+            |child| async {
+                match child {
+                    Some(tx) => Some((GossipData::new(tx, vec![], vec![]), None)),
+                    None => core::future::pending().await,
+                }
+            },
+        ))
+    });
+    // The requested parent is delivered after the child is gossiped
+    let (parent_sender, _) = broadcast::channel::<Vec<Transaction>>(1);
+    let requested_sender = parent_sender.clone();
+    p2p.expect_requested_transaction_events()
+        .returning(move || {
+            Box::pin(fuel_core_services::stream::unfold(
+                requested_sender.subscribe(),
+                |mut receiver| async {
+                    let txs = receiver.recv().await.ok()?;
+                    Some((txs, receiver))
+                },
+            ))
+        });
+    p2p.expect_request_transactions()
+        .returning(move |_, tx_ids| {
+            assert_eq!(tx_ids, vec![parent_id]);
+            parent_sender.send(vec![parent.clone()]).unwrap();
+            Ok(())
+        });
+    p2p.expect_notify_gossip_transaction_validity()
+        .returning(|_, _| Ok(()));
+    p2p.expect_report_peer().returning(|_, _| Ok(()));
+    ctx_builder.with_p2p(p2p);
+
+    let ctx = ctx_builder.build();
+    let service = ctx.service();
+    let mut receiver = service.shared.tx_update_subscribe(child_id).await;
+
+    service.start_and_await().await.unwrap();
+
+    let res = tokio::time::timeout(Duration::from_secs(1), receiver.next())
+        .await
+        .expect("the child should be inserted after the parent");
+    assert!(matches!(
+        res,
+        Some(TxStatusMessage::Status(TransactionStatus::Submitted { .. }))
+    ));
+    assert!(service.shared.find_one(parent_id).is_some());
+}
+
+#[tokio::test]
+async fn requested_transactions_are_inserted() {
+    let mut ctx_builder = TestContextBuilder::new();
+    let parent = ctx_builder.setup_script_tx(10);
+
+    let mut p2p = MockP2P::default();
+    p2p.expect_gossiped_transaction_events()
+        .returning(|| Box::pin(fuel_core_services::stream::pending()));
+    let requested = parent.clone();
+    p2p.expect_requested_transaction_events()
+        .returning(move || {
+            let requested = requested.clone();
+            Box::pin(fuel_core_services::stream::unfold(
+                Some(requested),
+                |requested| async {
+                    match requested {
+                        Some(tx) => Some((vec![tx], None)),
+                        None => core::future::pending().await,
+                    }
+                },
+            ))
+        });
+    ctx_builder.with_p2p(p2p);
+
+    let ctx = ctx_builder.build();
+    let service = ctx.service();
+    let mut receiver = service
+        .shared
+        .tx_update_subscribe(parent.id(&Default::default()))
+        .await;
+
+    service.start_and_await().await.unwrap();
+
+    let res = receiver.next().await;
+    assert!(matches!(
+        res,
+        Some(TxStatusMessage::Status(TransactionStatus::Submitted { .. }))
+    ));
+}
+
+#[tokio::test]
+async fn insert_below_min_gas_price_is_rejected_and_not_broadcasted() {
+    let mut ctx_builder = TestContextBuilder::new().with_config(Config {
+        min_gas_price: 10,
+        ..Default::default()
+    });
+    let cheap_tx = ctx_builder.setup_script_tx(1);
+    let tx = ctx_builder.setup_script_tx(10);
+
+    let mut p2p = MockP2P::default();
+    p2p.expect_gossiped_transaction_events()
+        .returning(|| Box::pin(fuel_core_services::stream::pending()));
+    p2p.expect_requested_transaction_events()
+        .returning(|| Box::pin(fuel_core_services::stream::pending()));
+    p2p.expect_broadcast_transactions().never();
+    let expected_tx = tx.clone();
+    p2p.expect_broadcast_transaction()
+        .withf(move |receive: &Arc<Transaction>| **receive == expected_tx)
+        .times(1)
+        .returning(|_| Ok(()));
+    ctx_builder.with_p2p(p2p);
+
+    let ctx = ctx_builder.build_and_start().await;
+    let out = ctx
+        .service()
+        .shared
+        .insert(vec![Arc::new(cheap_tx), Arc::new(tx)])
+        .await;
+
+    assert_eq!(out.len(), 2, "Should be len 2:{out:?}");
+    let err = out[0]
+        .as_ref()
+        .expect_err("Tx with gas price 1 should be rejected");
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedGasPriceTooLow {
+            provided: 1,
+            minimum: 10
+        })
+    ));
+    assert!(
+        out[1].is_ok(),
+        "Tx with gas price 10 should be OK, got err:{out:?}"
+    );
+}
+
+#[tokio::test]
+async fn gossiped_tx_below_min_gas_price_is_ignored_without_report() {
+    let mut ctx_builder = TestContextBuilder::new().with_config(Config {
+        min_gas_price: 10,
+        ..Default::default()
+    });
+    let cheap_tx = ctx_builder.setup_script_tx(1);
+    let cheap_tx_id = cheap_tx.id(&Default::default());
+
+    let mut p2p = MockP2P::new_with_txs_without_reports(vec![cheap_tx]);
+    let (send, mut receive) = broadcast::channel::<GossipsubMessageAcceptance>(1);
+    p2p.expect_notify_gossip_transaction_validity()
+        .returning(move |_, acceptance| {
+            send.send(acceptance).unwrap();
+            Ok(())
+        });
+    // The gas price is the local policy of the node, so the peer is not penalized.
+    p2p.expect_report_peer().never();
+    ctx_builder.with_p2p(p2p);
+
+    let ctx = ctx_builder.build_and_start().await;
+
+    let acceptance = tokio::time::timeout(Duration::from_secs(1), receive.recv())
+        .await
+        .expect("the validity of the gossiped tx should be reported")
+        .unwrap();
+    assert_eq!(acceptance, GossipsubMessageAcceptance::Ignore);
+    assert!(ctx.service().shared.find_one(cheap_tx_id).is_none());
+}
+
+#[tokio::test]
+async fn gossiped_valid_tx_is_accepted() {
+    let mut ctx_builder = TestContextBuilder::new();
+    let tx = ctx_builder.setup_script_tx(10);
+    let tx_id = tx.id(&Default::default());
+
+    let mut p2p = MockP2P::new_with_txs(vec![tx]);
+    let (send, mut receive) = broadcast::channel::<GossipsubMessageAcceptance>(1);
+    p2p.expect_notify_gossip_transaction_validity()
+        .returning(move |_, acceptance| {
+            send.send(acceptance).unwrap();
+            Ok(())
+        });
+    ctx_builder.with_p2p(p2p);
+
+    let ctx = ctx_builder.build_and_start().await;
+
+    let acceptance = tokio::time::timeout(Duration::from_secs(1), receive.recv())
+        .await
+        .expect("the validity of the gossiped tx should be reported")
+        .unwrap();
+    assert_eq!(acceptance, GossipsubMessageAcceptance::Accept);
+    assert!(ctx.service().shared.find_one(tx_id).is_some());
+}
+
+#[tokio::test]
+async fn gossiped_duplicate_tx_is_ignored() {
+    let mut ctx_builder = TestContextBuilder::new();
+    let tx = ctx_builder.setup_script_tx(10);
+
+    let mut p2p = MockP2P::new_with_txs(vec![tx.clone(), tx]);
+    let (send, mut receive) = broadcast::channel::<GossipsubMessageAcceptance>(2);
+    p2p.expect_notify_gossip_transaction_validity()
+        .returning(move |_, acceptance| {
+            send.send(acceptance).unwrap();
+            Ok(())
+        });
+    ctx_builder.with_p2p(p2p);
+
+    let _ctx = ctx_builder.build_and_start().await;
+
+    let mut acceptances = vec![];
+    for _ in 0..2 {
+        let acceptance = tokio::time::timeout(Duration::from_secs(1), receive.recv())
+            .await
+            .expect("the validity of each gossiped tx should be reported")
+            .unwrap();
+        acceptances.push(acceptance);
+    }
+    // The duplicate is valid, so the peer should not be penalized for it.
+    assert_eq!(
+        acceptances,
+        vec![
+            GossipsubMessageAcceptance::Accept,
+            GossipsubMessageAcceptance::Ignore
+        ]
+    );
+}
+
+#[tokio::test]
+async fn gossiped_invalid_tx_is_reported_to_peer_scoring() {
+    let mut ctx_builder = TestContextBuilder::new();
+    let (_, gas_coin) = ctx_builder.setup_coin();
+    // The gas limit above the maximum makes the transaction invalid on any node.
+    let invalid_tx = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .gas_limit(ConsensusParameters::default().tx_params.max_gas_per_tx + 1)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+    let invalid_tx_id = invalid_tx.id(&Default::default());
+
+    let mut p2p = MockP2P::new_with_txs_without_reports(vec![invalid_tx]);
+    let (send_acceptance, mut receive_acceptance) =
+        broadcast::channel::<GossipsubMessageAcceptance>(1);
+    p2p.expect_notify_gossip_transaction_validity()
+        .returning(move |_, acceptance| {
+            send_acceptance.send(acceptance).unwrap();
+            Ok(())
+        });
+    let (send_report, mut receive_report) =
+        broadcast::channel::<TransactionGossipReport>(1);
+    p2p.expect_report_peer().returning(move |_, report| {
+        send_report.send(report).unwrap();
+        Ok(())
+    });
+    ctx_builder.with_p2p(p2p);
+
+    let ctx = ctx_builder.build_and_start().await;
+
+    let acceptance =
+        tokio::time::timeout(Duration::from_secs(1), receive_acceptance.recv())
+            .await
+            .expect("the validity of the gossiped tx should be reported")
+            .unwrap();
+    assert_eq!(acceptance, GossipsubMessageAcceptance::Reject);
+    let report = tokio::time::timeout(Duration::from_secs(1), receive_report.recv())
+        .await
+        .expect("the peer should be reported")
+        .unwrap();
+    assert_eq!(report, TransactionGossipReport::Invalid);
+    assert!(ctx.service().shared.find_one(invalid_tx_id).is_none());
+}
+
+#[tokio::test]
+async fn gossiped_txs_above_the_peer_rate_limit_are_ignored() {
+    let mut ctx_builder = TestContextBuilder::new().with_config(Config {
+        max_gossiped_tx_per_peer: 1,
+        ..Default::default()
+    });
+    let tx1 = ctx_builder.setup_script_tx(10);
+    let tx2 = ctx_builder.setup_script_tx(10);
+    let tx1_id = tx1.id(&Default::default());
+    let tx2_id = tx2.id(&Default::default());
+
+    // Both transactions are gossiped by the same peer.
+    let mut p2p = MockP2P::new_with_txs_without_reports(vec![tx2, tx1]);
+    let (send_acceptance, mut receive_acceptance) =
+        broadcast::channel::<GossipsubMessageAcceptance>(2);
+    p2p.expect_notify_gossip_transaction_validity()
+        .returning(move |_, acceptance| {
+            send_acceptance.send(acceptance).unwrap();
+            Ok(())
+        });
+    let (send_report, mut receive_report) =
+        broadcast::channel::<TransactionGossipReport>(2);
+    p2p.expect_report_peer().returning(move |_, report| {
+        send_report.send(report).unwrap();
+        Ok(())
+    });
+    ctx_builder.with_p2p(p2p);
+
+    let ctx = ctx_builder.build_and_start().await;
+
+    let mut acceptances = vec![];
+    let mut reports = vec![];
+    for _ in 0..2 {
+        let acceptance =
+            tokio::time::timeout(Duration::from_secs(1), receive_acceptance.recv())
+                .await
+                .expect("the validity of each gossiped tx should be reported")
+                .unwrap();
+        acceptances.push(acceptance);
+        let report = tokio::time::timeout(Duration::from_secs(1), receive_report.recv())
+            .await
+            .expect("the peer should be reported for each gossiped tx")
+            .unwrap();
+        reports.push(report);
+    }
+    assert_eq!(
+        acceptances,
+        vec![
+            GossipsubMessageAcceptance::Accept,
+            GossipsubMessageAcceptance::Ignore
+        ]
+    );
+    assert_eq!(
+        reports,
+        vec![
+            TransactionGossipReport::Accepted,
+            TransactionGossipReport::RateLimited
+        ]
+    );
+    assert!(ctx.service().shared.find_one(tx1_id).is_some());
+    assert!(ctx.service().shared.find_one(tx2_id).is_none());
+}
+
+#[tokio::test]
+async fn insert_known_tx_is_rejected_and_not_broadcasted_again() {
+    let mut ctx_builder = TestContextBuilder::new();
+    let tx = ctx_builder.setup_script_tx(10);
+    let tx_id = tx.id(&Default::default());
+
+    let mut p2p = MockP2P::default();
+    p2p.expect_gossiped_transaction_events()
+        .returning(|| Box::pin(fuel_core_services::stream::pending()));
+    p2p.expect_requested_transaction_events()
+        .returning(|| Box::pin(fuel_core_services::stream::pending()));
+    p2p.expect_broadcast_transactions().never();
+    p2p.expect_broadcast_transaction()
+        .times(1)
+        .returning(|_| Ok(()));
+    ctx_builder.with_p2p(p2p);
+
+    let ctx = ctx_builder.build_and_start().await;
+    let service = ctx.service();
+    let mut new_tx_notification = service.shared.new_tx_notification_subscribe();
+
+    let out = service.shared.insert(vec![Arc::new(tx.clone())]).await;
+    assert!(
+        out[0].is_ok(),
+        "First insertion should be OK, got err:{out:?}"
+    );
+
+    let out = service.shared.insert(vec![Arc::new(tx)]).await;
+    let err = out[0]
+        .as_ref()
+        .expect_err("Second insertion should be rejected");
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedTxKnown(id)) if *id == tx_id
+    ));
+
+    // only the first insertion notifies about the new transaction
+    assert_eq!(new_tx_notification.try_recv(), Ok(tx_id));
+    assert!(new_tx_notification.try_recv().is_err());
+}
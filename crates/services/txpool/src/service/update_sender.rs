@@ -262,6 +262,11 @@ impl UpdateSender {
         subscribe::<_, C>(tx_id, &mut (*senders), permit)
     }
 
+    /// Drop all subscribed senders, so the subscribers receive the end of the stream.
+    pub fn close(&self) {
+        self.senders.lock().clear();
+    }
+
     /// Send updates to all subscribed senders.
     pub fn send(&self, update: TxUpdate) {
         // Lock the senders Mutex.
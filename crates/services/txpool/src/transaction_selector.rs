@@ -1,36 +1,62 @@
 use fuel_core_types::{
+    fuel_tx::{
+        input::contract::Contract,
+        Input,
+        Output,
+    },
     fuel_types::Word,
     services::txpool::ArcPoolTx,
 };
+use std::collections::HashSet;
 
-// transaction selection could use a plugin based approach in the
-// future for block producers to customize block building (e.g. alternative priorities besides gas fees)
+// The block producer customizes the order of the transactions with its `TxSelector`.
 
-// Expects sorted by gas price transactions, highest first
+// Expects transactions sorted by the priority of the inclusion, highest first,
+// where each transaction goes after the transactions it depends on.
 pub fn select_transactions(
     includable_txs: impl Iterator<Item = ArcPoolTx>,
     max_gas: u64,
 ) -> Vec<ArcPoolTx> {
-    // Select all txs that fit into the block, preferring ones with higher gas price.
+    // Select all txs that fit into the block, preferring ones with higher priority.
     //
     // Future improvements to this algorithm may take into account the parallel nature of
     // transactions to maximize throughput.
     let mut used_block_space: Word = 0;
+    // The transactions that depend on the skipped ones can't be included either.
+    let mut skipped_txs = HashSet::new();
+    let mut skipped_contracts = HashSet::new();
 
     // Pick as many transactions as we can fit into the block (greedy)
     includable_txs
         .filter(|tx| {
+            let depends_on_skipped = tx.inputs().iter().any(|input| match input {
+                Input::Contract(Contract { contract_id, .. }) => {
+                    skipped_contracts.contains(contract_id)
+                }
+                _ => input
+                    .utxo_id()
+                    .map_or(false, |utxo_id| skipped_txs.contains(utxo_id.tx_id())),
+            });
             let tx_block_space = tx.max_gas();
-            if let Some(new_used_space) = used_block_space.checked_add(tx_block_space) {
-                if new_used_space <= max_gas {
+            let fits = match used_block_space.checked_add(tx_block_space) {
+                Some(new_used_space)
+                    if !depends_on_skipped && new_used_space <= max_gas =>
+                {
                     used_block_space = new_used_space;
                     true
-                } else {
-                    false
                 }
-            } else {
-                false
+                _ => false,
+            };
+            if !fits {
+                skipped_txs.insert(tx.id());
+                skipped_contracts.extend(tx.outputs().iter().filter_map(|output| {
+                    match output {
+                        Output::ContractCreated { contract_id, .. } => Some(*contract_id),
+                        _ => None,
+                    }
+                }));
             }
+            fits
         })
         .collect()
 }
@@ -51,8 +77,10 @@ mod tests {
             FeeParameters,
             Output,
             TransactionBuilder,
+            UtxoId,
         },
         fuel_vm::checked_transaction::builder::TransactionBuilderExt,
+        services::txpool::PoolTransaction,
     };
     use itertools::Itertools;
     use std::sync::Arc;
@@ -197,4 +225,52 @@ mod tests {
             }
         }
     }
+
+    fn script_spending(price: u64, limit: u64, utxo_id: UtxoId) -> ArcPoolTx {
+        let fee_params = FeeParameters {
+            gas_price_factor: 1,
+            ..FeeParameters::default()
+        };
+        let tx: PoolTransaction = TransactionBuilder::script(
+            vec![op::ret(RegId::ONE)].into_iter().collect(),
+            vec![],
+        )
+        .gas_price(price)
+        .gas_limit(limit)
+        .add_unsigned_coin_input(
+            Default::default(),
+            utxo_id,
+            1_000_000,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::Change {
+            to: Default::default(),
+            amount: 0,
+            asset_id: Default::default(),
+        })
+        .with_fee_params(fee_params)
+        .finalize_checked_basic(Default::default())
+        .into();
+        Arc::new(tx)
+    }
+
+    #[test]
+    fn selector_skips_txs_depending_on_skipped_txs() {
+        let mut rng = thread_rng();
+        let parent = script_spending(1, 3000, rng.gen());
+        let child = script_spending(5, 1000, UtxoId::new(parent.id(), 0));
+        let independent = script_spending(2, 1000, rng.gen());
+
+        let selected = select_transactions(
+            vec![parent, child, independent.clone()].into_iter(),
+            2500,
+        );
+
+        assert_eq!(
+            selected.iter().map(|tx| tx.id()).collect_vec(),
+            vec![independent.id()]
+        );
+    }
 }
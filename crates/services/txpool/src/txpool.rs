@@ -1,9 +1,16 @@
 use crate::{
+    admission::{
+        self,
+        TxAdmissionPolicy,
+    },
     containers::{
         dependency::Dependency,
         price_sort::PriceSort,
+        priority_sort::PrioritySort,
+        sort::SortableKey,
         time_sort::TimeSort,
     },
+    metrics,
     ports::TxPoolDb,
     service::TxStatusChange,
     types::*,
@@ -15,10 +22,24 @@ use crate::{
 use fuel_core_metrics::txpool_metrics::TXPOOL_METRICS;
 use fuel_core_types::{
     fuel_tx::{
+        field::{
+            Inputs,
+            Outputs,
+        },
+        input::contract::Contract,
+        Address,
         Chargeable,
+        ConsensusParameters,
+        Input,
+        Output,
         Transaction,
+        UniqueIdentifier,
+        UtxoId,
+    },
+    fuel_types::{
+        BlockHeight,
+        ChainId,
     },
-    fuel_types::BlockHeight,
     fuel_vm::{
         checked_transaction::{
             CheckPredicates,
@@ -40,7 +61,10 @@ use fuel_core_types::{
 use fuel_core_types::fuel_vm::checked_transaction::CheckPredicateParams;
 use std::{
     cmp::Reverse,
-    collections::HashMap,
+    collections::{
+        HashMap,
+        HashSet,
+    },
     ops::Deref,
     sync::Arc,
 };
@@ -50,8 +74,13 @@ use tokio_rayon::AsyncRayonHandle;
 pub struct TxPool<DB> {
     by_hash: HashMap<TxId, TxInfo>,
     by_gas_price: PriceSort,
+    by_priority: PrioritySort,
     by_time: TimeSort,
     by_dependency: Dependency,
+    by_owner: HashMap<Address, HashSet<TxId>>,
+    /// The total metered size of the transactions in the pool.
+    bytes_size: usize,
+    admission_policies: Vec<Arc<dyn TxAdmissionPolicy>>,
     config: Config,
     database: DB,
 }
@@ -62,12 +91,21 @@ where
 {
     pub fn new(config: Config, database: DB) -> Self {
         let max_depth = config.max_depth;
+        let admission_policies = admission::policies(&config);
 
         Self {
             by_hash: HashMap::new(),
             by_gas_price: PriceSort::default(),
+            by_priority: PrioritySort::default(),
             by_time: TimeSort::default(),
-            by_dependency: Dependency::new(max_depth, config.utxo_validation),
+            by_dependency: Dependency::new(
+                max_depth,
+                config.utxo_validation,
+                config.replacement_gas_price_bump_percent,
+            ),
+            by_owner: HashMap::new(),
+            bytes_size: 0,
+            admission_policies,
             config,
             database,
         }
@@ -91,6 +129,14 @@ where
     fn insert_inner(
         &mut self,
         tx: Checked<Transaction>,
+    ) -> anyhow::Result<InsertionResult> {
+        self.insert_inner_with_priority(tx, None)
+    }
+
+    fn insert_inner_with_priority(
+        &mut self,
+        tx: Checked<Transaction>,
+        priority: Option<u64>,
     ) -> anyhow::Result<InsertionResult> {
         let tx: CheckedTransaction = tx.into();
 
@@ -116,7 +162,11 @@ where
         }
 
         if self.by_hash.contains_key(&tx.id()) {
-            return Err(Error::NotInsertedTxKnown.into())
+            return Err(Error::NotInsertedTxKnown(tx.id()).into())
+        }
+
+        for policy in self.admission_policies.iter() {
+            policy.check(&tx)?;
         }
 
         let mut max_limit_hit = false;
@@ -129,6 +179,24 @@ where
                 return Err(Error::NotInsertedLimitHit.into())
             }
         }
+        // check if the owners of the inputs are hitting the limit, and find
+        // the lowest priced transactions of the owners that can be pushed out.
+        let limited_owners = owners(&tx)
+            .into_iter()
+            .filter(|owner| self.is_owner_limit_hit(owner))
+            .collect::<Vec<_>>();
+        let mut owner_limit_hit = Vec::new();
+        if !limited_owners.is_empty() {
+            let ancestors = self.by_dependency.find_ancestors(&tx, &self.by_hash);
+            for owner in limited_owners {
+                match self.owner_lowest_tx(&owner, &ancestors) {
+                    Some(lowest_tx) if lowest_tx.price() < tx.price() => {
+                        owner_limit_hit.push((owner, lowest_tx))
+                    }
+                    _ => return Err(Error::NotInsertedOwnerLimitHit(owner).into()),
+                }
+            }
+        }
         if self.config.metrics {
             TXPOOL_METRICS
                 .gas_price_histogram
@@ -142,19 +210,27 @@ where
         let rem = self
             .by_dependency
             .insert(&self.by_hash, &self.database, &tx)?;
-        let info = TxInfo::new(tx.clone());
+        let info = TxInfo::new_with_priority(tx.clone(), priority);
         let submitted_time = info.submitted_time();
         self.by_gas_price.insert(&info);
+        if info.priority().is_some() {
+            self.by_priority.insert(&info);
+        }
         self.by_time.insert(&info);
         self.by_hash.insert(tx.id(), info);
+        self.bytes_size = self.bytes_size.saturating_add(tx.metered_bytes_size());
+        for owner in owners(&tx) {
+            self.by_owner.entry(owner).or_default().insert(tx.id());
+        }
 
         // if some transaction were removed so we don't need to check limit
-        let removed = if rem.is_empty() {
+        let mut removed = if rem.is_empty() {
             if max_limit_hit {
                 // remove last tx from sort
-                let rem_tx = self.by_gas_price.lowest_tx().unwrap(); // safe to unwrap limit is hit
-                self.remove_inner(&rem_tx);
-                vec![rem_tx]
+                // safe to unwrap limit is hit
+                let rem_tx = self.by_gas_price.lowest_tx().unwrap();
+                // the dependent transactions are pushed out together with the `rem_tx`
+                self.remove_inner(&rem_tx)
             } else {
                 Vec::new()
             }
@@ -166,6 +242,23 @@ where
 
             rem
         };
+        for (owner, lowest_tx) in owner_limit_hit {
+            // the collision could already remove the transaction, or another transaction
+            // of the owner, so the owner doesn't exceed the limit anymore
+            if self.is_owner_limit_exceeded(&owner)
+                && self.by_hash.contains_key(&lowest_tx.id())
+            {
+                removed.extend(self.remove_inner(&lowest_tx));
+            }
+        }
+
+        if self.config.metrics {
+            TXPOOL_METRICS.inserted_tx_counter.inc();
+            TXPOOL_METRICS
+                .evicted_tx_counter
+                .inc_by(removed.len() as u64);
+            metrics::observe_pool_size(self.by_hash.len(), self.bytes_size);
+        }
 
         Ok(InsertionResult {
             inserted: tx,
@@ -174,13 +267,54 @@ where
         })
     }
 
+    /// The size in bytes of all transactions in the pool combined.
+    pub fn bytes_size(&self) -> usize {
+        self.bytes_size
+    }
+
     /// Return all sorted transactions that are includable in next block.
+    /// Transactions with the priority override go first, the rest are sorted by gas price.
+    /// The transaction always goes after the pending transactions it depends on.
     pub fn sorted_includable(&self) -> impl Iterator<Item = ArcPoolTx> + '_ {
-        self.by_gas_price
+        let mut included = HashSet::new();
+        self.sorted_by_priority().flat_map(move |tx| {
+            let mut sorted = Vec::new();
+            self.push_with_ancestors(tx, &mut included, &mut sorted);
+            sorted
+        })
+    }
+
+    /// Pushes the not yet `included` ancestors of the `tx` to the `sorted` in the
+    /// topological order, followed by the `tx` itself.
+    fn push_with_ancestors(
+        &self,
+        tx: ArcPoolTx,
+        included: &mut HashSet<TxId>,
+        sorted: &mut Vec<ArcPoolTx>,
+    ) {
+        if !included.insert(tx.id()) {
+            return
+        }
+        for parent in self.by_dependency.find_parents(&tx, &self.by_hash) {
+            self.push_with_ancestors(parent, included, sorted);
+        }
+        sorted.push(tx);
+    }
+
+    fn sorted_by_priority(&self) -> impl Iterator<Item = ArcPoolTx> + '_ {
+        let prioritized = self.by_priority.sort.iter().rev().map(|(_, tx)| tx.clone());
+        let by_gas_price = self
+            .by_gas_price
             .sort
             .iter()
             .rev()
-            .map(|(_, tx)| tx.clone())
+            .filter(|(key, _)| {
+                self.by_hash
+                    .get(key.tx_id())
+                    .map_or(true, |info| info.priority().is_none())
+            })
+            .map(|(_, tx)| tx.clone());
+        prioritized.chain(by_gas_price)
     }
 
     pub fn remove_inner(&mut self, tx: &ArcPoolTx) -> Vec<ArcPoolTx> {
@@ -207,11 +341,57 @@ where
         if let Some(info) = &info {
             self.by_time.remove(info);
             self.by_gas_price.remove(info);
+            self.by_priority.remove(info);
+            self.bytes_size = self
+                .bytes_size
+                .saturating_sub(info.tx().metered_bytes_size());
+            for owner in owners(info.tx()) {
+                if let Some(txs) = self.by_owner.get_mut(&owner) {
+                    txs.remove(tx_id);
+                    if txs.is_empty() {
+                        self.by_owner.remove(&owner);
+                    }
+                }
+            }
+            if self.config.metrics {
+                metrics::observe_removal(info);
+                metrics::observe_pool_size(self.by_hash.len(), self.bytes_size);
+            }
         }
 
         info
     }
 
+    /// Checks if the `owner` reached the limit of pending transactions.
+    fn is_owner_limit_hit(&self, owner: &Address) -> bool {
+        self.by_owner
+            .get(owner)
+            .map_or(false, |txs| txs.len() >= self.config.max_tx_per_owner)
+    }
+
+    /// Checks if the `owner` has more pending transactions than allowed.
+    fn is_owner_limit_exceeded(&self, owner: &Address) -> bool {
+        self.by_owner
+            .get(owner)
+            .map_or(false, |txs| txs.len() > self.config.max_tx_per_owner)
+    }
+
+    /// Returns the lowest priced transaction of the `owner`. The `ancestors` of
+    /// the inserted transaction can't be pushed out, so they are skipped.
+    fn owner_lowest_tx(
+        &self,
+        owner: &Address,
+        ancestors: &HashMap<TxId, ArcPoolTx>,
+    ) -> Option<ArcPoolTx> {
+        self.by_owner
+            .get(owner)?
+            .iter()
+            .filter(|tx_id| !ancestors.contains_key(tx_id))
+            .filter_map(|tx_id| self.by_hash.get(tx_id))
+            .map(|info| info.tx().clone())
+            .min_by_key(|tx| tx.price())
+    }
+
     /// Removes transaction from `TxPool` with assumption that it is committed into the blockchain.
     // TODO: Don't remove recursively dependent transactions on block commit.
     //  The same logic should be fixed in the `select_transactions`.
@@ -227,13 +407,25 @@ where
         &mut self,
         tx_status_sender: &TxStatusChange,
         txs: Vec<Checked<Transaction>>,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        self.insert_with_priority(tx_status_sender, txs, None)
+    }
+
+    /// Import a set of transactions with the `priority` that overrides the gas price
+    /// ordering of the block building. It is intended for testing and privileged local
+    /// submission only, and should never be used for gossiped transactions.
+    pub fn insert_with_priority(
+        &mut self,
+        tx_status_sender: &TxStatusChange,
+        txs: Vec<Checked<Transaction>>,
+        priority: Option<u64>,
     ) -> Vec<anyhow::Result<InsertionResult>> {
         // Check if that data is okay (witness match input/output, and if recovered signatures ara valid).
         // should be done before transaction comes to txpool, or before it enters RwLocked region.
         let mut res = Vec::new();
 
         for tx in txs.into_iter() {
-            res.push(self.insert_inner(tx));
+            res.push(self.insert_inner_with_priority(tx, priority));
         }
 
         // announce to subscribers
@@ -247,10 +439,15 @@ where
                     for removed in removed {
                         // small todo there is possibility to have removal reason (ReplacedByHigherGas, DependencyRemoved)
                         // but for now it is okay to just use Error::Removed.
-                        tx_status_sender.send_squeezed_out(removed.id(), Error::Removed);
+                        tx_status_sender.send_squeezed_out(
+                            removed.id(),
+                            owners(removed),
+                            Error::Removed,
+                        );
                     }
                     tx_status_sender.send_submitted(
                         inserted.id(),
+                        owners(inserted),
                         Tai64::from_unix(submitted_time.as_secs() as i64),
                     );
                 }
@@ -296,11 +493,34 @@ where
         list
     }
 
+    /// find ids of all txs that spend the `utxo_id`. The collisions are resolved during
+    /// the insertion, so the pool contains at most one spender of the coin.
+    pub fn find_spenders(&self, utxo_id: &UtxoId) -> Vec<TxId> {
+        self.txs()
+            .iter()
+            .filter(|(_, info)| {
+                info.tx()
+                    .inputs()
+                    .iter()
+                    .any(|input| input.utxo_id() == Some(utxo_id))
+            })
+            .map(|(id, _)| *id)
+            .collect()
+    }
+
     /// The number of pending transaction in the pool.
     pub fn pending_number(&self) -> usize {
         self.by_hash.len()
     }
 
+    /// The number of pending transactions in the pool per owner of their inputs.
+    pub fn pending_number_by_owner(&self) -> HashMap<Address, usize> {
+        self.by_owner
+            .iter()
+            .map(|(owner, txs)| (*owner, txs.len()))
+            .collect()
+    }
+
     /// The amount of gas in all includable transactions combined
     pub fn consumable_gas(&self) -> u64 {
         self.by_hash.values().map(|tx| tx.limit()).sum()
@@ -323,7 +543,12 @@ where
         for tx_id in transactions {
             let tx_id = *tx_id;
             let result = self.database.transaction_status(&tx_id);
-            tx_status_sender.send_complete(tx_id, height, result);
+            let tx_owners = self
+                .txs()
+                .get(&tx_id)
+                .map(|info| owners(info.tx()))
+                .unwrap_or_default();
+            tx_status_sender.send_complete(tx_id, tx_owners, height, result);
             self.remove_committed_tx(&tx_id);
         }
     }
@@ -337,12 +562,52 @@ where
         let mut removed = Vec::new();
         for tx_id in tx_ids {
             let rem = self.remove_by_tx_id(tx_id);
-            tx_status_sender.send_squeezed_out(*tx_id, Error::Removed);
+            let tx_owners = rem
+                .iter()
+                .find(|tx| &tx.id() == tx_id)
+                .map(|tx| owners(tx))
+                .unwrap_or_default();
+            tx_status_sender.send_squeezed_out(*tx_id, tx_owners, Error::Removed);
             removed.extend(rem.into_iter());
         }
         removed
     }
 
+    /// Switches to the new `consensus_params` and removes the transactions that don't
+    /// satisfy them anymore along with their dependents.
+    pub fn revalidate(
+        &mut self,
+        tx_status_sender: &TxStatusChange,
+        current_height: BlockHeight,
+        consensus_params: &ConsensusParameters,
+    ) -> Vec<ArcPoolTx> {
+        self.config.chain_config.consensus_parameters = consensus_params.clone();
+
+        let invalid: Vec<_> = self
+            .by_hash
+            .values()
+            .filter_map(|info| {
+                let tx: Transaction = info.tx().as_ref().into();
+                tx.into_checked_basic(current_height, consensus_params)
+                    .err()
+                    .map(|err| (info.tx().id(), err))
+            })
+            .collect();
+
+        let mut removed = Vec::new();
+        for (tx_id, err) in invalid {
+            for tx in self.remove_by_tx_id(&tx_id) {
+                tx_status_sender.send_squeezed_out(
+                    tx.id(),
+                    owners(&tx),
+                    Error::InvalidatedByConsensusParameters(format!("{err:?}")),
+                );
+                removed.push(tx);
+            }
+        }
+        removed
+    }
+
     /// Remove all old transactions from the pool.
     pub fn prune_old_txs(&mut self) -> Vec<ArcPoolTx> {
         let deadline = tokio::time::Instant::now() - self.config.transaction_ttl;
@@ -359,20 +624,85 @@ where
             }
         }
 
+        if self.config.metrics {
+            TXPOOL_METRICS
+                .expired_tx_counter
+                .inc_by(result.len() as u64);
+        }
+
         result
     }
 }
 
+/// Returns the owners of the coins and the recipients of the messages spent by the `tx`.
+pub(crate) fn owners(tx: &PoolTransaction) -> HashSet<Address> {
+    tx.inputs()
+        .iter()
+        .filter_map(Input::input_owner)
+        .copied()
+        .collect()
+}
+
+/// Verifies that the transactions of the batch spend the outputs and use the contracts
+/// only of the transactions preceding them in the batch.
+pub fn check_batch_order(
+    txs: &[Arc<Transaction>],
+    chain_id: &ChainId,
+) -> Result<(), Error> {
+    let ids: Vec<TxId> = txs.iter().map(|tx| tx.id(chain_id)).collect();
+    let positions: HashMap<TxId, usize> =
+        ids.iter().enumerate().map(|(i, id)| (*id, i)).collect();
+    let mut created_contracts: HashMap<ContractId, usize> = HashMap::new();
+    for (i, tx) in txs.iter().enumerate() {
+        for output in inputs_and_outputs(tx).1 {
+            if let Output::ContractCreated { contract_id, .. } = output {
+                created_contracts.insert(*contract_id, i);
+            }
+        }
+    }
+
+    for (i, tx) in txs.iter().enumerate() {
+        for input in inputs_and_outputs(tx).0 {
+            let parent = match input {
+                Input::Contract(Contract { contract_id, .. }) => {
+                    created_contracts.get(contract_id)
+                }
+                _ => input
+                    .utxo_id()
+                    .and_then(|utxo_id| positions.get(utxo_id.tx_id())),
+            };
+            match parent {
+                Some(parent) if *parent >= i => {
+                    return Err(Error::NotInsertedBatchOutOfOrder(ids[*parent]))
+                }
+                _ => {}
+            }
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn inputs_and_outputs(tx: &Transaction) -> (&[Input], &[Output]) {
+    match tx {
+        Transaction::Script(script) => (script.inputs(), script.outputs()),
+        Transaction::Create(create) => (create.inputs(), create.outputs()),
+        Transaction::Mint(_) => (&[], &[]),
+    }
+}
+
 pub async fn check_transactions(
     txs: &[Arc<Transaction>],
     current_height: BlockHeight,
+    consensus_params: &ConsensusParameters,
     config: &Config,
 ) -> Vec<anyhow::Result<Checked<Transaction>>> {
     let mut checked_txs = Vec::with_capacity(txs.len());
 
     for tx in txs.iter() {
-        checked_txs
-            .push(check_single_tx(tx.deref().clone(), current_height, config).await);
+        checked_txs.push(
+            check_single_tx(tx.deref().clone(), current_height, consensus_params, config)
+                .await,
+        );
     }
 
     checked_txs
@@ -381,6 +711,7 @@ pub async fn check_transactions(
 pub async fn check_single_tx(
     tx: Transaction,
     current_height: BlockHeight,
+    consensus_params: &ConsensusParameters,
     config: &Config,
 ) -> anyhow::Result<Checked<Transaction>> {
     if tx.is_mint() {
@@ -390,8 +721,6 @@ pub async fn check_single_tx(
     verify_tx_min_gas_price(&tx, config)?;
 
     let tx: Checked<Transaction> = if config.utxo_validation {
-        let consensus_params = &config.chain_config.consensus_parameters;
-
         let tx = tx
             .into_checked_basic(current_height, consensus_params)?
             .check_signatures(&consensus_params.chain_id)?;
@@ -406,7 +735,7 @@ pub async fn check_single_tx(
 
         tx
     } else {
-        tx.into_checked_basic(current_height, &config.chain_config.consensus_parameters)?
+        tx.into_checked_basic(current_height, consensus_params)?
     };
 
     Ok(tx)
@@ -425,7 +754,10 @@ fn verify_tx_min_gas_price(tx: &Transaction, config: &Config) -> Result<(), Erro
         TXPOOL_METRICS.gas_price_histogram.observe(price as f64);
     }
     if price < config.min_gas_price {
-        return Err(Error::NotInsertedGasPriceTooLow)
+        return Err(Error::NotInsertedGasPriceTooLow {
+            provided: price,
+            minimum: config.min_gas_price,
+        })
     }
     Ok(())
 }
@@ -1,4 +1,8 @@
 use crate::{
+    admission::{
+        AccessList,
+        TxAdmissionPolicy,
+    },
     ports::TxPoolDb,
     test_helpers::{
         add_coin_to_state,
@@ -15,6 +19,7 @@ use crate::{
         create_contract_output,
         create_message_predicate_from_message,
     },
+    types::PoolTransaction,
     Config,
     Error,
     MockDb,
@@ -46,13 +51,19 @@ use fuel_core_types::{
     fuel_vm::checked_transaction::Checked,
 };
 
+use fuel_core_metrics::txpool_metrics::TXPOOL_METRICS;
 use std::{
     cmp::Reverse,
     collections::HashMap,
+    sync::Arc,
+    time::Duration,
     vec,
 };
 
-use super::check_single_tx;
+use super::{
+    check_batch_order,
+    check_single_tx,
+};
 
 const GAS_LIMIT: Word = 1000;
 
@@ -61,9 +72,14 @@ async fn check_unwrap_tx(
     db: MockDb,
     config: &Config,
 ) -> Checked<Transaction> {
-    check_single_tx(tx, db.current_block_height().unwrap(), config)
-        .await
-        .expect("Transaction should be checked")
+    check_single_tx(
+        tx,
+        db.current_block_height().unwrap(),
+        &config.chain_config.consensus_parameters,
+        config,
+    )
+    .await
+    .expect("Transaction should be checked")
 }
 
 async fn check_tx(
@@ -71,7 +87,13 @@ async fn check_tx(
     db: MockDb,
     config: &Config,
 ) -> anyhow::Result<Checked<Transaction>> {
-    check_single_tx(tx, db.current_block_height().unwrap(), config).await
+    check_single_tx(
+        tx,
+        db.current_block_height().unwrap(),
+        &config.chain_config.consensus_parameters,
+        config,
+    )
+    .await
 }
 
 #[tokio::test]
@@ -126,6 +148,42 @@ async fn insert_simple_tx_dependency_chain_succeeds() {
         .expect("Tx2 dependent should be OK, got Err");
 }
 
+#[test]
+fn batch_with_dependent_tx_before_its_parent_is_rejected() {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let (_, gas_coin) = setup_coin(&mut rng, None);
+    let (output, unset_input) = create_output_and_input(&mut rng, 1);
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(1)
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .add_output(output)
+        .finalize_as_transaction();
+    let tx1_id = tx1.id(&Default::default());
+
+    let (_, gas_coin) = setup_coin(&mut rng, None);
+    let input = unset_input.into_input(UtxoId::new(tx1_id, 0));
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(1)
+        .gas_limit(GAS_LIMIT)
+        .add_input(input)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let tx1 = Arc::new(tx1);
+    let tx2 = Arc::new(tx2);
+
+    check_batch_order(&[tx1.clone(), tx2.clone()], &Default::default())
+        .expect("The parent precedes the dependent tx");
+    let err = check_batch_order(&[tx2, tx1], &Default::default())
+        .expect_err("The dependent tx precedes the parent");
+    assert!(
+        matches!(err, Error::NotInsertedBatchOutOfOrder(id) if id == tx1_id),
+        "Got {err:?}"
+    );
+}
+
 #[tokio::test]
 async fn faulty_t2_collided_on_contract_id_from_tx1() {
     let mut rng = StdRng::seed_from_u64(0);
@@ -241,6 +299,7 @@ async fn not_inserted_known_tx() {
 
     let tx = Transaction::default_test_tx();
     let tx = check_unwrap_tx(tx, db.clone(), &txpool.config).await;
+    let tx_id = tx.id();
 
     txpool
         .insert_inner(tx.clone())
@@ -251,7 +310,7 @@ async fn not_inserted_known_tx() {
         .expect_err("Second insertion of Tx1 should be Err, got Ok");
     assert!(matches!(
         err.downcast_ref::<Error>(),
-        Some(Error::NotInsertedTxKnown)
+        Some(Error::NotInsertedTxKnown(id)) if *id == tx_id
     ));
 }
 
@@ -311,6 +370,96 @@ async fn higher_priced_tx_removes_lower_priced_tx() {
     assert_eq!(vec.removed[0].id(), tx1_id, "Tx1 id should be removed");
 }
 
+#[tokio::test]
+async fn replacement_below_min_gas_price_bump_is_rejected() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(
+        Config {
+            replacement_gas_price_bump_percent: 10,
+            ..Default::default()
+        },
+        db.clone(),
+    );
+
+    let (_, coin_input) = setup_coin(&mut rng, Some(&txpool.database));
+
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(100)
+        .gas_limit(GAS_LIMIT)
+        .add_input(coin_input.clone())
+        .finalize_as_transaction();
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(109)
+        .gas_limit(GAS_LIMIT)
+        .add_input(coin_input.clone())
+        .finalize_as_transaction();
+    let tx3 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(110)
+        .gas_limit(GAS_LIMIT)
+        .add_input(coin_input)
+        .finalize_as_transaction();
+
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx1 = check_unwrap_tx(tx1, db.clone(), &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, db.clone(), &txpool.config).await;
+    let tx3 = check_unwrap_tx(tx3, db.clone(), &txpool.config).await;
+
+    txpool.insert_inner(tx1).expect("Tx1 should be Ok, got Err");
+    let err = txpool
+        .insert_inner(tx2)
+        .expect_err("Tx2 should be Err, got Ok");
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedCollision(id, _)) if id == &tx1_id
+    ));
+
+    let vec = txpool.insert_inner(tx3).expect("Tx3 should be Ok, got Err");
+    assert_eq!(vec.removed[0].id(), tx1_id, "Tx1 id should be removed");
+}
+
+#[tokio::test]
+async fn find_spenders_returns_the_winner_of_the_collision() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(Default::default(), db.clone());
+
+    let (_, coin_input) = setup_coin(&mut rng, Some(&txpool.database));
+    let (_, other_coin_input) = setup_coin(&mut rng, Some(&txpool.database));
+    let utxo_id = *coin_input.utxo_id().unwrap();
+
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .gas_limit(GAS_LIMIT)
+        .add_input(coin_input.clone())
+        .finalize_as_transaction();
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(20)
+        .gas_limit(GAS_LIMIT)
+        .add_input(coin_input)
+        .finalize_as_transaction();
+    let tx3 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .gas_limit(GAS_LIMIT)
+        .add_input(other_coin_input)
+        .finalize_as_transaction();
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2_id = tx2.id(&ChainId::default());
+
+    assert!(txpool.find_spenders(&utxo_id).is_empty());
+
+    let tx1 = check_unwrap_tx(tx1, db.clone(), &txpool.config).await;
+    txpool.insert_inner(tx1).expect("Tx1 should be Ok, got Err");
+    let tx3 = check_unwrap_tx(tx3, db.clone(), &txpool.config).await;
+    txpool.insert_inner(tx3).expect("Tx3 should be Ok, got Err");
+    assert_eq!(txpool.find_spenders(&utxo_id), vec![tx1_id]);
+
+    // the collision is resolved during the insertion
+    let tx2 = check_unwrap_tx(tx2, db.clone(), &txpool.config).await;
+    txpool.insert_inner(tx2).expect("Tx2 should be Ok, got Err");
+    assert_eq!(txpool.find_spenders(&utxo_id), vec![tx2_id]);
+}
+
 #[tokio::test]
 async fn underpriced_tx1_not_included_coin_collision() {
     let mut rng = StdRng::seed_from_u64(0);
@@ -577,6 +726,120 @@ async fn tx_limit_hit() {
     ));
 }
 
+#[tokio::test]
+async fn owner_limit_pushes_out_lowest_priced_tx_of_the_owner() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(
+        Config {
+            max_tx_per_owner: 2,
+            ..Default::default()
+        },
+        db.clone(),
+    );
+
+    // all coins are owned by the same predicate
+    let code: Vec<u8> = vec![op::ret(1)].into_iter().collect();
+    let owner = Input::predicate_owner(&code, &ChainId::default());
+    let mut owner_tx = |gas_price| {
+        let coin = custom_predicate(
+            &mut rng,
+            AssetId::BASE,
+            TEST_COIN_AMOUNT,
+            code.clone(),
+            None,
+        )
+        .into_default_estimated();
+        let (_, coin) = add_coin_to_state(coin, Some(&db));
+        TransactionBuilder::script(vec![], vec![])
+            .gas_price(gas_price)
+            .gas_limit(GAS_LIMIT)
+            .add_input(coin)
+            .finalize_as_transaction()
+    };
+    let tx1 = owner_tx(10);
+    let tx2 = owner_tx(20);
+    let tx3 = owner_tx(5);
+    let tx4 = owner_tx(30);
+
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx1 = check_unwrap_tx(tx1, db.clone(), &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, db.clone(), &txpool.config).await;
+    let tx3 = check_unwrap_tx(tx3, db.clone(), &txpool.config).await;
+    let tx4 = check_unwrap_tx(tx4, db.clone(), &txpool.config).await;
+
+    txpool.insert_inner(tx1).expect("Tx1 should be Ok, got Err");
+    txpool.insert_inner(tx2).expect("Tx2 should be Ok, got Err");
+
+    let err = txpool
+        .insert_inner(tx3)
+        .expect_err("Tx3 should be Err, got Ok");
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedOwnerLimitHit(limited)) if limited == &owner
+    ));
+
+    let result = txpool.insert_inner(tx4).expect("Tx4 should be Ok, got Err");
+    assert_eq!(result.removed.len(), 1);
+    assert_eq!(result.removed[0].id(), tx1_id, "Tx1 should be removed");
+    assert_eq!(txpool.pending_number(), 2);
+}
+
+#[tokio::test]
+async fn owner_replacing_own_tx_at_limit_keeps_other_txs() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(
+        Config {
+            max_tx_per_owner: 2,
+            ..Default::default()
+        },
+        db.clone(),
+    );
+
+    // all coins are owned by the same predicate
+    let code: Vec<u8> = vec![op::ret(1)].into_iter().collect();
+    let mut owner_coin = || {
+        let coin = custom_predicate(
+            &mut rng,
+            AssetId::BASE,
+            TEST_COIN_AMOUNT,
+            code.clone(),
+            None,
+        )
+        .into_default_estimated();
+        add_coin_to_state(coin, Some(&db)).1
+    };
+    let coin1 = owner_coin();
+    let coin2 = owner_coin();
+    let tx = |gas_price, coin: &Input| {
+        TransactionBuilder::script(vec![], vec![])
+            .gas_price(gas_price)
+            .gas_limit(GAS_LIMIT)
+            .add_input(coin.clone())
+            .finalize_as_transaction()
+    };
+    let tx1 = tx(10, &coin1);
+    let tx2 = tx(20, &coin2);
+    // spends the coin of the `tx2`
+    let tx3 = tx(40, &coin2);
+
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2_id = tx2.id(&ChainId::default());
+    let tx1 = check_unwrap_tx(tx1, db.clone(), &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, db.clone(), &txpool.config).await;
+    let tx3 = check_unwrap_tx(tx3, db.clone(), &txpool.config).await;
+
+    txpool.insert_inner(tx1).expect("Tx1 should be Ok, got Err");
+    txpool.insert_inner(tx2).expect("Tx2 should be Ok, got Err");
+
+    let result = txpool.insert_inner(tx3).expect("Tx3 should be Ok, got Err");
+    assert_eq!(result.removed.len(), 1);
+    assert_eq!(result.removed[0].id(), tx2_id, "Tx2 should be replaced");
+    assert!(txpool.txs().contains_key(&tx1_id), "Tx1 should stay");
+    assert_eq!(txpool.pending_number(), 2);
+}
+
 #[tokio::test]
 async fn tx_depth_hit() {
     let mut rng = StdRng::seed_from_u64(0);
@@ -674,6 +937,119 @@ async fn sorted_out_tx1_2_4() {
     assert_eq!(txs[2].id(), tx2_id, "Third should be tx2");
 }
 
+#[tokio::test]
+async fn sorted_includable_puts_parent_before_higher_priced_child() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(Default::default(), db.clone());
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let (output, unset_input) = create_output_and_input(&mut rng, 1);
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(1)
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .add_output(output)
+        .finalize_as_transaction();
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&Default::default()), 0));
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(20)
+        .gas_limit(GAS_LIMIT)
+        .add_input(input)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let tx3 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2_id = tx2.id(&ChainId::default());
+    let tx3_id = tx3.id(&ChainId::default());
+
+    let tx1 = check_unwrap_tx(tx1, db.clone(), &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, db.clone(), &txpool.config).await;
+    let tx3 = check_unwrap_tx(tx3, db.clone(), &txpool.config).await;
+
+    txpool.insert_inner(tx1).expect("Tx1 should be Ok, got Err");
+    txpool.insert_inner(tx2).expect("Tx2 should be Ok, got Err");
+    txpool.insert_inner(tx3).expect("Tx3 should be Ok, got Err");
+
+    let txs = txpool.sorted_includable().collect::<Vec<_>>();
+
+    assert_eq!(txs.len(), 3, "Should have 3 txs");
+    assert_eq!(
+        txs[0].id(),
+        tx1_id,
+        "First should be tx1, the parent of tx2"
+    );
+    assert_eq!(txs[1].id(), tx2_id, "Second should be tx2");
+    assert_eq!(txs[2].id(), tx3_id, "Third should be tx3");
+}
+
+#[tokio::test]
+async fn tx_pushed_out_by_limit_removes_dependent_txs() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(
+        Config {
+            max_tx: 2,
+            ..Default::default()
+        },
+        db.clone(),
+    );
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let (output, unset_input) = create_output_and_input(&mut rng, 1);
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(1)
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .add_output(output)
+        .finalize_as_transaction();
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&Default::default()), 0));
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(2)
+        .gas_limit(GAS_LIMIT)
+        .add_input(input)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let tx3 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(5)
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2_id = tx2.id(&ChainId::default());
+
+    let tx1 = check_unwrap_tx(tx1, db.clone(), &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, db.clone(), &txpool.config).await;
+    let tx3 = check_unwrap_tx(tx3, db.clone(), &txpool.config).await;
+
+    txpool.insert_inner(tx1).expect("Tx1 should be Ok, got Err");
+    txpool.insert_inner(tx2).expect("Tx2 should be Ok, got Err");
+    let result = txpool.insert_inner(tx3).expect("Tx3 should be Ok, got Err");
+
+    assert_eq!(
+        result.removed.len(),
+        2,
+        "Tx1 and its dependent tx2 are removed"
+    );
+    assert_eq!(result.removed[0].id(), tx1_id);
+    assert_eq!(result.removed[1].id(), tx2_id);
+    assert_eq!(txpool.pending_number(), 1);
+}
+
 #[tokio::test]
 async fn find_dependent_tx1_tx2() {
     let mut rng = StdRng::seed_from_u64(0);
@@ -779,10 +1155,45 @@ async fn tx_below_min_gas_price_is_not_insertable() {
 
     assert!(matches!(
         err.root_cause().downcast_ref::<Error>().unwrap(),
-        Error::NotInsertedGasPriceTooLow
+        Error::NotInsertedGasPriceTooLow {
+            provided: 10,
+            minimum: 11
+        }
     ));
 }
 
+#[tokio::test(start_paused = true)]
+async fn expired_txs_are_counted_in_metrics() {
+    const TTL: Duration = Duration::from_secs(10);
+
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(
+        Config {
+            transaction_ttl: TTL,
+            metrics: true,
+            ..Default::default()
+        },
+        db.clone(),
+    );
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+    let tx = check_unwrap_tx(tx, db.clone(), &txpool.config).await;
+    txpool.insert_inner(tx).expect("Tx should be Ok, got Err");
+
+    let expired_before = TXPOOL_METRICS.expired_tx_counter.get();
+    tokio::time::advance(TTL + Duration::from_secs(1)).await;
+    let removed = txpool.prune_old_txs();
+
+    assert_eq!(removed.len(), 1);
+    assert!(TXPOOL_METRICS.expired_tx_counter.get() > expired_before);
+}
+
 #[tokio::test]
 async fn tx_inserted_into_pool_when_input_message_id_exists_in_db() {
     let (message, input) = create_message_predicate_from_message(5000, 0);
@@ -1080,3 +1491,121 @@ async fn predicate_that_returns_false_is_invalid() {
         "unexpected error: {err}",
     )
 }
+
+#[tokio::test]
+async fn tx_using_denied_contract_is_rejected() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let contract_id = Contract::EMPTY_CONTRACT_ID;
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(
+        Config {
+            contract_access: AccessList::new([], [contract_id]),
+            ..Default::default()
+        },
+        db.clone(),
+    );
+
+    let (_, gas_funds) = setup_coin(&mut rng, Some(&txpool.database));
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_price(1)
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_funds)
+        .add_input(create_contract_input(
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_output(Output::contract(1, Default::default(), Default::default()))
+        .finalize_as_transaction();
+
+    let tx = check_unwrap_tx(tx, db.clone(), &txpool.config).await;
+    let err = txpool
+        .insert_inner(tx)
+        .expect_err("Tx should be rejected by the contract deny list");
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedContractNotAllowed(id)) if id == &contract_id
+    ));
+}
+
+#[tokio::test]
+async fn tx_spending_inputs_of_not_allowed_owner_is_rejected() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(
+        Config {
+            owner_access: AccessList::new([Address::from([1; 32])], []),
+            ..Default::default()
+        },
+        db.clone(),
+    );
+
+    let (coin, gas_funds) = setup_coin(&mut rng, Some(&txpool.database));
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_price(1)
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_funds)
+        .finalize_as_transaction();
+
+    let tx = check_unwrap_tx(tx, db.clone(), &txpool.config).await;
+    let err = txpool
+        .insert_inner(tx)
+        .expect_err("Tx should be rejected by the owner allow list");
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedOwnerNotAllowed(owner)) if owner == &coin.owner
+    ));
+}
+
+#[tokio::test]
+async fn custom_admission_policy_is_consulted() {
+    #[derive(Debug)]
+    struct MaxGasPrice(Word);
+
+    impl TxAdmissionPolicy for MaxGasPrice {
+        fn check(&self, tx: &PoolTransaction) -> Result<(), Error> {
+            if tx.price() > self.0 {
+                return Err(Error::NotInsertedRejectedByPolicy(
+                    "Gas price is too high".to_string(),
+                ))
+            }
+            Ok(())
+        }
+    }
+
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(
+        Config {
+            admission_policies: vec![Arc::new(MaxGasPrice(10))],
+            ..Default::default()
+        },
+        db.clone(),
+    );
+
+    let (_, gas_funds) = setup_coin(&mut rng, Some(&txpool.database));
+    let cheap_tx = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_funds)
+        .finalize_as_transaction();
+    let (_, gas_funds) = setup_coin(&mut rng, Some(&txpool.database));
+    let expensive_tx = TransactionBuilder::script(vec![], vec![])
+        .gas_price(11)
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_funds)
+        .finalize_as_transaction();
+
+    let cheap_tx = check_unwrap_tx(cheap_tx, db.clone(), &txpool.config).await;
+    txpool
+        .insert_inner(cheap_tx)
+        .expect("Tx should be allowed by the policy");
+    let expensive_tx = check_unwrap_tx(expensive_tx, db.clone(), &txpool.config).await;
+    let err = txpool
+        .insert_inner(expensive_tx)
+        .expect_err("Tx should be rejected by the policy");
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedRejectedByPolicy(_))
+    ));
+}
@@ -106,6 +106,15 @@ impl<T> Block<T> {
 }
 
 impl CompressedBlock {
+    /// Creates the compressed block from the `header` without transactions.
+    /// It is used by the nodes that track only the headers of the chain.
+    pub fn from_header(header: BlockHeader) -> Self {
+        Block {
+            header,
+            transactions: vec![],
+        }
+    }
+
     /// Convert from a compressed block back to a the full block.
     pub fn uncompress(self, transactions: Vec<Transaction>) -> Block<Transaction> {
         // TODO: should we perform an extra validation step to ensure the provided
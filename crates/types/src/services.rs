@@ -5,6 +5,7 @@ pub mod block_producer;
 pub mod executor;
 pub mod graphql_api;
 pub mod p2p;
+pub mod sync;
 pub mod txpool;
 
 // TODO: Define a one common error for all services like
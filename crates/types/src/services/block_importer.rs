@@ -2,9 +2,34 @@
 
 use crate::{
     blockchain::{
+        block::Block,
         header::BlockHeader,
         SealedBlock,
     },
+    entities::coins::coin::Coin,
+    fuel_asm::Word,
+    fuel_tx::{
+        field::{
+            Inputs,
+            Outputs,
+        },
+        input::coin::{
+            CoinPredicate,
+            CoinSigned,
+        },
+        Input,
+        Output,
+        Transaction,
+        TxId,
+        UtxoId,
+    },
+    fuel_types::{
+        Address,
+        AssetId,
+        BlockHeight,
+        Bytes32,
+        ContractId,
+    },
     services::{
         executor::TransactionExecutionStatus,
         Uncommitted,
@@ -25,6 +50,9 @@ pub struct ImportResult {
     pub tx_status: Vec<TransactionExecutionStatus>,
     /// The source producer of the block.
     pub source: Source,
+    /// The changes of the state made by the block.
+    /// Only computed if the importer is configured to do so.
+    pub state_diff: Option<StateDiff>,
 }
 
 /// The source producer of the block.
@@ -47,6 +75,7 @@ impl ImportResult {
             sealed_block,
             tx_status,
             source: Source::Local,
+            state_diff: None,
         }
     }
 
@@ -59,7 +88,185 @@ impl ImportResult {
             sealed_block,
             tx_status,
             source: Source::Network,
+            state_diff: None,
+        }
+    }
+}
+
+/// The changes of the state made by the block.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StateDiff {
+    /// The coins created by the block.
+    pub created_coins: Vec<Coin>,
+    /// The coins spent by the block.
+    pub spent_coins: Vec<Coin>,
+    /// The writes to the storage slots of the contracts.
+    pub contract_storage_writes: Vec<ContractStorageWrite>,
+    /// The changes of the balances of the addresses and of the contracts.
+    pub balance_deltas: Vec<BalanceDelta>,
+}
+
+/// The write to the storage slot of the contract.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ContractStorageWrite {
+    /// The contract owning the storage.
+    pub contract_id: ContractId,
+    /// The key of the storage slot.
+    pub key: Bytes32,
+    /// The new value of the storage slot, `None` if the slot was cleared.
+    pub value: Option<Bytes32>,
+}
+
+/// The owner of the balance.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum BalanceOwner {
+    /// The balance of the coins owned by the address.
+    Address(Address),
+    /// The balance of the contract.
+    Contract(ContractId),
+}
+
+/// The change of the balance of the asset. The balance is increased by the `credit`
+/// and decreased by the `debit`. Only the net change is known for the contracts.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BalanceDelta {
+    /// The owner of the balance.
+    pub owner: BalanceOwner,
+    /// The asset of the balance.
+    pub asset_id: AssetId,
+    /// The amount added to the balance.
+    pub credit: Word,
+    /// The amount removed from the balance.
+    pub debit: Word,
+}
+
+/// The reorganization of the chain. The blocks above the common ancestor
+/// are reverted to import the competing chain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reorg {
+    /// The height of the latest block shared by both chains.
+    pub common_ancestor: BlockHeight,
+    /// The reverted blocks, from the highest to the lowest.
+    pub reverted_blocks: Vec<SealedBlock>,
+}
+
+/// The change of the coin made by the imported or the reverted block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoinEvent {
+    /// The kind of the change.
+    pub kind: CoinEventKind,
+    /// The height of the block that made the change.
+    pub block_height: BlockHeight,
+    /// The id of the coin.
+    pub utxo_id: UtxoId,
+    /// The owner of the coin.
+    pub owner: Address,
+    /// The asset of the coin.
+    pub asset_id: AssetId,
+    /// The amount of the coin.
+    pub amount: Word,
+}
+
+/// The kind of the change of the coin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoinEventKind {
+    /// The coin is created by the imported block.
+    Created,
+    /// The coin is spent by the imported block.
+    Spent,
+    /// The coin created by the reverted block doesn't exist anymore.
+    CreationReverted,
+    /// The coin spent by the reverted block is unspent again.
+    SpendReverted,
+}
+
+impl CoinEvent {
+    /// Returns the events of the coins spent and created by the transactions of the
+    /// imported `block`. The `tx_ids` are the ids of the transactions of the block.
+    pub fn imported(block: &Block, tx_ids: &[TxId]) -> Vec<CoinEvent> {
+        let height = *block.header().height();
+        let mut events = vec![];
+        for (tx, tx_id) in block.transactions().iter().zip(tx_ids) {
+            let (inputs, outputs): (&[Input], &[Output]) = match tx {
+                Transaction::Script(tx) => (tx.inputs(), tx.outputs()),
+                Transaction::Create(tx) => (tx.inputs(), tx.outputs()),
+                Transaction::Mint(tx) => (&[], tx.outputs()),
+            };
+            for input in inputs {
+                match input {
+                    Input::CoinSigned(CoinSigned {
+                        utxo_id,
+                        owner,
+                        amount,
+                        asset_id,
+                        ..
+                    })
+                    | Input::CoinPredicate(CoinPredicate {
+                        utxo_id,
+                        owner,
+                        amount,
+                        asset_id,
+                        ..
+                    }) => events.push(CoinEvent {
+                        kind: CoinEventKind::Spent,
+                        block_height: height,
+                        utxo_id: *utxo_id,
+                        owner: *owner,
+                        asset_id: *asset_id,
+                        amount: *amount,
+                    }),
+                    _ => {}
+                }
+            }
+            for (output_index, output) in outputs.iter().enumerate() {
+                match output {
+                    Output::Coin {
+                        to,
+                        amount,
+                        asset_id,
+                    }
+                    | Output::Change {
+                        to,
+                        amount,
+                        asset_id,
+                    }
+                    | Output::Variable {
+                        to,
+                        amount,
+                        asset_id,
+                    } if *amount > 0 => events.push(CoinEvent {
+                        kind: CoinEventKind::Created,
+                        block_height: height,
+                        utxo_id: UtxoId::new(*tx_id, output_index as u8),
+                        owner: *to,
+                        asset_id: *asset_id,
+                        amount: *amount,
+                    }),
+                    // The outputs without the amount don't create the coins.
+                    _ => {}
+                }
+            }
+        }
+        events
+    }
+
+    /// Returns the events undoing the changes of the coins made by the reverted
+    /// `block`, in the reverse order.
+    pub fn reverted(block: &Block, tx_ids: &[TxId]) -> Vec<CoinEvent> {
+        let mut events = Self::imported(block, tx_ids);
+        events.reverse();
+        for event in events.iter_mut() {
+            event.kind = match event.kind {
+                CoinEventKind::Created => CoinEventKind::CreationReverted,
+                CoinEventKind::Spent => CoinEventKind::SpendReverted,
+                kind => kind,
+            };
         }
+        events
     }
 }
 
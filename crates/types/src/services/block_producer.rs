@@ -1,6 +1,13 @@
 //! Types related to block producer service.
 
-use crate::blockchain::header::PartialBlockHeader;
+use crate::{
+    blockchain::{
+        block::Block,
+        header::PartialBlockHeader,
+    },
+    fuel_tx::TxId,
+    fuel_types::Word,
+};
 
 /// The components required to produce a block.
 #[derive(Debug)]
@@ -14,3 +21,15 @@ pub struct Components<Source> {
     /// The gas limit of the block.
     pub gas_limit: u64,
 }
+
+/// The next block assembled from the transactions of the pool without committing
+/// or signing it.
+#[derive(Debug)]
+pub struct BlockDryRun {
+    /// The block that would be produced.
+    pub block: Block,
+    /// The gas used by the transactions of the block.
+    pub gas_used: Word,
+    /// The transactions of the pool left out of the block with the reasons.
+    pub excluded_transactions: Vec<(TxId, String)>,
+}
@@ -8,12 +8,16 @@ use crate::{
         },
         primitives::BlockId,
     },
+    entities::coins::coin::Coin,
+    fuel_asm::Word,
     fuel_tx::{
         CheckError,
+        Receipt,
         TxId,
         UtxoId,
     },
     fuel_types::{
+        AssetId,
         Bytes32,
         ContractId,
         Nonce,
@@ -71,6 +75,76 @@ pub enum TransactionExecutionResult {
     },
 }
 
+/// The ephemeral changes of the state applied before the dry run. The changes
+/// are visible only to the dry run and are never persisted.
+#[derive(Debug, Default, Clone)]
+pub struct StateOverrides {
+    /// The coins added to the state, for example, to fund the spender.
+    pub coins: Vec<Coin>,
+    /// The new values of the contract storage slots: the contract, the key, and the value.
+    pub contract_state: Vec<(ContractId, Bytes32, Bytes32)>,
+    /// The bytecode that replaces the code of the contract.
+    pub contract_code: Vec<(ContractId, Vec<u8>)>,
+}
+
+/// The structured trace of the transaction execution.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TransactionTrace {
+    /// The receipts produced by the transaction.
+    pub receipts: Vec<Receipt>,
+    /// The contract calls made by the transaction in the order of the calls.
+    pub frames: Vec<CallFrameTrace>,
+    /// The number of executions of each opcode, sorted by the opcode name.
+    pub opcode_counts: Vec<(String, u64)>,
+    /// All executed instructions in the order of the execution.
+    /// It is `None` if the full opcode stream was not requested.
+    pub opcodes: Option<Vec<TracedInstruction>>,
+    /// The values of the registers at the moment of the panic.
+    /// It is `None` if the transaction didn't panic.
+    pub panic_registers: Option<Vec<Word>>,
+}
+
+/// The contract call made during the transaction execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallFrameTrace {
+    /// The nesting level of the call, where the calls from the script are at zero.
+    pub depth: u32,
+    /// The called contract.
+    pub contract_id: ContractId,
+    /// The asset forwarded to the contract.
+    pub asset_id: AssetId,
+    /// The amount of the forwarded asset.
+    pub amount: Word,
+    /// The gas forwarded to the contract.
+    pub gas: Word,
+    /// How the call ended.
+    pub outcome: CallOutcome,
+}
+
+/// The way the contract call ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallOutcome {
+    /// The contract returned to the caller.
+    Return,
+    /// The transaction panicked inside of the call.
+    Panic,
+    /// The transaction reverted inside of the call.
+    Revert,
+    /// The call didn't end, for example, because the transaction ran out of gas.
+    Unfinished,
+}
+
+/// The instruction executed by the VM.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TracedInstruction {
+    /// The program counter of the instruction.
+    pub pc: Word,
+    /// The start of the executed code, the script or the called contract.
+    pub is: Word,
+    /// The name of the opcode, or `None` if the instruction can't be decoded.
+    pub opcode: Option<String>,
+}
+
 /// Execution wrapper where the types
 /// depend on the type of execution.
 #[derive(Debug, Clone, Copy)]
@@ -290,6 +364,8 @@ pub enum Error {
     MessageAlreadySpent(Nonce),
     #[error("Expected input of type {0}")]
     InputTypeMismatch(String),
+    #[error("The execution tracing requires the `debug` feature of the VM")]
+    TracingNotSupported,
 }
 
 impl From<Backtrace> for Error {
@@ -1,9 +1,16 @@
 //! Types related to GraphQL API service.
 
-use crate::fuel_types::{
-    Address,
-    AssetId,
-    ContractId,
+use crate::{
+    fuel_tx::{
+        Receipt,
+        TxId,
+    },
+    fuel_types::{
+        Address,
+        AssetId,
+        BlockHeight,
+        ContractId,
+    },
 };
 
 /// The cumulative balance(`amount`) of the `Owner` of `asset_id`.
@@ -21,3 +28,64 @@ pub type AddressBalance = Balance<Address>;
 
 /// The alias for the `Balance` of the contract.
 pub type ContractBalance = Balance<ContractId>;
+
+/// The position of the receipt in the chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ReceiptPointer {
+    /// The height of the block with the transaction.
+    pub block_height: BlockHeight,
+    /// The index of the transaction in the block.
+    pub tx_idx: u16,
+    /// The index of the receipt in the receipts of the transaction.
+    pub receipt_idx: u16,
+}
+
+/// The receipt emitted by the contract.
+pub struct ContractReceipt {
+    /// The position of the receipt in the chain.
+    pub pointer: ReceiptPointer,
+    /// The transaction that produced the receipt.
+    pub tx_id: TxId,
+    /// The receipt itself.
+    pub receipt: Receipt,
+}
+
+/// The supply of the asset tracked by the index of the assets.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AssetSupply {
+    /// The amount minted by the contracts.
+    pub minted: u64,
+    /// The amount burned by the contracts.
+    pub burned: u64,
+    /// The number of the addresses holding the coins of the asset.
+    pub holders: u64,
+}
+
+impl AssetSupply {
+    /// The amount of the asset in circulation.
+    pub fn total(&self) -> u64 {
+        self.minted.saturating_sub(self.burned)
+    }
+}
+
+/// The rolling statistics of the latest blocks.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ChainStats {
+    /// The number of the blocks the statistics are computed over.
+    pub blocks: u32,
+    /// The number of the transactions in the blocks except the mint transactions.
+    pub transactions: u64,
+    /// The number of the transactions included per second.
+    pub transactions_per_second: f64,
+    /// The average interval between the blocks in seconds.
+    pub average_block_interval: f64,
+    /// The median interval between the blocks in seconds.
+    pub p50_block_interval: u64,
+    /// The 90th percentile of the intervals between the blocks in seconds.
+    pub p90_block_interval: u64,
+    /// The 99th percentile of the intervals between the blocks in seconds.
+    pub p99_block_interval: u64,
+    /// The average gas used by the scripts of the block.
+    pub average_gas_used: u64,
+}
@@ -4,7 +4,16 @@ use crate::{
     fuel_tx::Transaction,
     fuel_types::BlockHeight,
 };
-use std::fmt::Debug;
+use peer_reputation::AppScore;
+use std::{
+    fmt::{
+        Debug,
+        Display,
+        Formatter,
+    },
+    str::FromStr,
+    time::Duration,
+};
 /// Contains types and logic for Peer Reputation
 pub mod peer_reputation;
 
@@ -92,6 +101,25 @@ pub struct BlockHeightHeartbeatData {
     pub block_height: BlockHeight,
 }
 
+/// The information about the connected peer
+#[derive(Debug, Clone, PartialEq)]
+pub struct PeerInfo {
+    /// The id of the peer
+    pub id: PeerId,
+    /// The known addresses of the peer
+    pub peer_addresses: Vec<String>,
+    /// The version of the client run by the peer
+    pub client_version: Option<String>,
+    /// The latest block height reported by the peer
+    pub block_height: Option<BlockHeight>,
+    /// The time passed since the last heartbeat of the peer
+    pub last_heartbeat: Option<Duration>,
+    /// The time passed since the connection with the peer was established
+    pub connection_age: Duration,
+    /// The application score of the peer
+    pub app_score: AppScore,
+}
+
 /// Opaque peer identifier.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -114,3 +142,20 @@ impl From<PeerId> for Vec<u8> {
         peer_id.0
     }
 }
+
+impl Display for PeerId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&bs58::encode(&self.0).into_string())
+    }
+}
+
+impl FromStr for PeerId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        bs58::decode(s)
+            .into_vec()
+            .map(Self)
+            .map_err(|e| format!("Invalid peer id: {e}"))
+    }
+}
@@ -54,3 +54,57 @@ impl PeerReport for PositivePeerReport {
         }
     }
 }
+
+/// The result of the validation of the transaction gossiped by the peer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransactionGossipReport {
+    /// The transaction is valid and was inserted into the pool
+    Accepted,
+    /// The transaction is already known
+    Duplicate,
+    /// The transaction conflicts with the pending or the committed transactions
+    Conflicting,
+    /// The transaction is malformed or its signature is invalid
+    Invalid,
+    /// The peer exceeded the rate limit of gossiped transactions
+    RateLimited,
+}
+
+impl PeerReport for TransactionGossipReport {
+    fn get_score_from_report(&self) -> AppScore {
+        match self {
+            Self::Accepted => PositivePeerReport::Minor.get_score_from_report(),
+            Self::Duplicate => -1.0,
+            Self::Conflicting | Self::RateLimited => {
+                NegativePeerReport::Minor.get_score_from_report()
+            }
+            Self::Invalid => NegativePeerReport::Major.get_score_from_report(),
+        }
+    }
+}
+
+/// The misbehaviour of the peer detected by the p2p protocol itself
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PeerProtocolReport {
+    /// The peer didn't respond to the request in time
+    RequestTimeout,
+    /// The peer responded with the data that can't be decoded
+    InvalidResponse,
+    /// The peer gossiped the message that can't be decoded
+    MalformedGossip,
+    /// The peer exceeded the limits of the inbound requests
+    RequestLimitExceeded,
+}
+
+impl PeerReport for PeerProtocolReport {
+    fn get_score_from_report(&self) -> AppScore {
+        match self {
+            Self::RequestTimeout | Self::RequestLimitExceeded => {
+                NegativePeerReport::Minor.get_score_from_report()
+            }
+            Self::InvalidResponse | Self::MalformedGossip => {
+                NegativePeerReport::Major.get_score_from_report()
+            }
+        }
+    }
+}
@@ -0,0 +1,28 @@
+//! Types related to the sync service.
+
+use crate::fuel_types::BlockHeight;
+use std::time::Duration;
+
+/// The progress of the node towards the highest height seen in the network.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SyncStatus {
+    /// The height of the latest block committed by the node.
+    pub current_height: BlockHeight,
+    /// The highest block height reported by the peers.
+    pub highest_seen_height: BlockHeight,
+    /// The estimated time to reach the `highest_seen_height`.
+    /// `None` if the import rate is not known yet.
+    pub estimated_time_remaining: Option<Duration>,
+}
+
+impl SyncStatus {
+    /// Returns `true` if the node has committed the highest seen height.
+    pub fn is_synced(&self) -> bool {
+        self.current_height >= self.highest_seen_height
+    }
+
+    /// Returns the number of blocks left to reach the `highest_seen_height`.
+    pub fn blocks_remaining(&self) -> u32 {
+        (*self.highest_seen_height).saturating_sub(*self.current_height)
+    }
+}
@@ -19,6 +19,7 @@ use crate::{
         UtxoId,
     },
     fuel_types::{
+        Address,
         ContractId,
         Nonce,
     },
@@ -204,12 +205,14 @@ pub enum Error {
     NoMetadata,
     #[error("TxPool doesn't support this type of transaction.")]
     NotSupportedTransactionType,
-    #[error("Transaction is not inserted. Hash is already known")]
-    NotInsertedTxKnown,
+    #[error("Transaction is not inserted. Hash is already known: {0:#x}")]
+    NotInsertedTxKnown(TxId),
     #[error("Transaction is not inserted. Pool limit is hit, try to increase gas_price")]
     NotInsertedLimitHit,
-    #[error("Transaction is not inserted. The gas price is too low.")]
-    NotInsertedGasPriceTooLow,
+    #[error("Transaction is not inserted. The owner {0:#x} has too many pending transactions, try to increase gas_price")]
+    NotInsertedOwnerLimitHit(Address),
+    #[error("Transaction is not inserted. The gas price {provided} is lower than the minimum {minimum}.")]
+    NotInsertedGasPriceTooLow { provided: Word, minimum: Word },
     #[error(
         "Transaction is not inserted. More priced tx {0:#x} already spend this UTXO output: {1:#x}"
     )]
@@ -258,6 +261,16 @@ pub enum Error {
     NotInsertedIoContractOutput,
     #[error("Transaction is not inserted. Maximum depth of dependent transaction chain reached")]
     NotInsertedMaxDepth,
+    #[error("Transaction is not inserted. It depends on the transaction {0:#x} that follows it in the batch")]
+    NotInsertedBatchOutOfOrder(TxId),
+    #[error("Transaction is not inserted. The contract {0:#x} is not allowed")]
+    NotInsertedContractNotAllowed(ContractId),
+    #[error("Transaction is not inserted. The owner {0:#x} is not allowed")]
+    NotInsertedOwnerNotAllowed(Address),
+    #[error("Transaction is not inserted. It is rejected by the admission policy: {0}")]
+    NotInsertedRejectedByPolicy(String),
+    #[error("Transaction is not inserted. The transaction pool is stopped")]
+    NotInsertedTxPoolStopped,
     #[error("Transaction exceeds the max gas per block limit. Tx gas: {tx_gas}, block limit {block_limit}")]
     NotInsertedMaxGasLimit { tx_gas: Word, block_limit: Word },
     // small todo for now it can pass but in future we should include better messages
@@ -265,6 +278,8 @@ pub enum Error {
     Removed,
     #[error("Transaction expired because it exceeded the configured time to live `tx-pool-ttl`.")]
     TTLReason,
+    #[error("Transaction is no longer valid under the new consensus parameters: {0}")]
+    InvalidatedByConsensusParameters(String),
     #[error("Transaction squeezed out because {0}")]
     SqueezedOut(String),
     // TODO: We need it for now until channels are removed from TxPool.
@@ -0,0 +1,68 @@
+use fuel_core::{
+    fuel_core_graphql_api::auth::{
+        ApiRole,
+        ApiTokens,
+    },
+    service::{
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_types::fuel_tx::*;
+
+async fn node_with_tokens() -> FuelService {
+    let mut config = Config::local_node();
+    config.production_admin_enabled = true;
+    config.api_tokens = ApiTokens::new([
+        ("submitter".to_string(), ApiRole::SubmitTx),
+        ("admin".to_string(), ApiRole::Admin),
+    ]);
+    FuelService::new_node(config).await.unwrap()
+}
+
+fn script_tx() -> Transaction {
+    TransactionBuilder::script(vec![], vec![])
+        .gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction()
+}
+
+#[tokio::test]
+async fn anonymous_requests_are_read_only() {
+    let srv = node_with_tokens().await;
+    let client = FuelClient::from(srv.bound_address);
+
+    assert!(client.chain_info().await.is_ok());
+    let err = client.submit(&script_tx()).await.unwrap_err();
+    assert!(err.to_string().contains("submit-tx"), "{err}");
+}
+
+#[tokio::test]
+async fn token_grants_the_scope_of_its_role() {
+    let srv = node_with_tokens().await;
+    let submitter = FuelClient::from(srv.bound_address).with_api_token("submitter");
+    let admin = FuelClient::from(srv.bound_address).with_api_token("admin");
+
+    submitter
+        .submit_and_await_commit(&script_tx())
+        .await
+        .unwrap();
+    let err = submitter.pause_block_production().await.unwrap_err();
+    assert!(err.to_string().contains("admin"), "{err}");
+
+    assert!(admin.pause_block_production().await.unwrap());
+    assert!(admin.resume_block_production().await.unwrap());
+}
+
+#[tokio::test]
+async fn unknown_token_is_rejected() {
+    let srv = node_with_tokens().await;
+    let client = FuelClient::from(srv.bound_address).with_api_token("unknown");
+
+    let err = client.chain_info().await.unwrap_err();
+    assert!(
+        err.to_string().contains("The API token is invalid"),
+        "{err}"
+    );
+}
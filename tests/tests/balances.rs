@@ -19,6 +19,7 @@ use fuel_core_client::client::{
             Address,
             AssetId,
         },
+        CoinEventKind,
         CoinType,
     },
     FuelClient,
@@ -31,6 +32,7 @@ use fuel_core_types::{
         TransactionBuilder,
     },
 };
+use std::time::Duration;
 
 #[tokio::test]
 async fn balance() {
@@ -139,6 +141,198 @@ async fn balance() {
     assert_eq!(balance, 449);
 }
 
+async fn node_with_coin(owner: Address, asset_id: AssetId, archive: bool) -> FuelService {
+    let mut config = Config::local_node();
+    config.block_importer.archive = archive;
+    config.block_importer.index_assets = true;
+    if !archive {
+        config.block_importer.max_reorg_depth = 0;
+    }
+    config.chain_conf.initial_state = Some(StateConfig {
+        coins: Some(vec![CoinConfig {
+            tx_id: None,
+            output_index: None,
+            tx_pointer_block_height: None,
+            tx_pointer_tx_idx: None,
+            maturity: None,
+            owner,
+            amount: 100,
+            asset_id,
+        }]),
+        ..Default::default()
+    });
+    FuelService::new_node(config).await.unwrap()
+}
+
+async fn transfer(client: &FuelClient, owner: Address, to: Address, asset_id: AssetId) {
+    let coin = client
+        .coins(
+            &owner,
+            Some(&asset_id),
+            PaginationRequest {
+                cursor: None,
+                results: 1,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap()
+        .results
+        .remove(0);
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(1_000_000)
+        .add_input(Input::coin_signed(
+            coin.utxo_id,
+            coin.owner,
+            coin.amount,
+            coin.asset_id,
+            Default::default(),
+            0,
+            coin.maturity.into(),
+        ))
+        .add_output(Output::Coin {
+            to,
+            amount: 30,
+            asset_id,
+        })
+        .add_output(Output::Change {
+            to: owner,
+            amount: 0,
+            asset_id,
+        })
+        .add_witness(Default::default())
+        .finalize_as_transaction();
+    client.submit_and_await_commit(&tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn balance_at_height_in_archive_mode() {
+    let owner = Address::default();
+    let recipient = Address::new([1u8; 32]);
+    let asset_id = AssetId::BASE;
+    let srv = node_with_coin(owner, asset_id, true).await;
+    let client = FuelClient::from(srv.bound_address);
+
+    transfer(&client, owner, recipient, asset_id).await;
+    transfer(&client, owner, recipient, asset_id).await;
+
+    let balances_at = |height: u32| {
+        let client = &client;
+        async move {
+            let owner_balance = client
+                .balance_at_height(&owner, Some(&asset_id), height.into())
+                .await
+                .unwrap();
+            let recipient_balance = client
+                .balance_at_height(&recipient, Some(&asset_id), height.into())
+                .await
+                .unwrap();
+            (owner_balance, recipient_balance)
+        }
+    };
+    assert_eq!(balances_at(0).await, (100, 0));
+    assert_eq!(balances_at(1).await, (70, 30));
+    assert_eq!(balances_at(2).await, (40, 60));
+    assert_eq!(client.balance(&owner, Some(&asset_id)).await.unwrap(), 40);
+}
+
+#[tokio::test]
+async fn balance_at_height_requires_historical_state() {
+    let owner = Address::default();
+    let asset_id = AssetId::BASE;
+    let srv = node_with_coin(owner, asset_id, false).await;
+    let client = FuelClient::from(srv.bound_address);
+
+    transfer(&client, owner, Address::new([1u8; 32]), asset_id).await;
+
+    let result = client
+        .balance_at_height(&owner, Some(&asset_id), 0u32.into())
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn asset_holders_are_sorted_by_balance() {
+    let owner = Address::default();
+    let recipient = Address::new([1u8; 32]);
+    let asset_id = AssetId::BASE;
+    let srv = node_with_coin(owner, asset_id, false).await;
+    let client = FuelClient::from(srv.bound_address);
+
+    // The coin of the genesis is indexed.
+    let supply = client.asset_supply(&asset_id).await.unwrap().unwrap();
+    assert_eq!(supply.holders, 1);
+
+    transfer(&client, owner, recipient, asset_id).await;
+    transfer(&client, owner, recipient, asset_id).await;
+
+    let first_page = client
+        .asset_holders(
+            &asset_id,
+            PaginationRequest {
+                cursor: None,
+                results: 1,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(first_page.results.len(), 1);
+    assert_eq!(first_page.results[0].owner, recipient);
+    assert_eq!(first_page.results[0].amount, 60);
+    assert!(first_page.has_next_page);
+
+    let second_page = client
+        .asset_holders(
+            &asset_id,
+            PaginationRequest {
+                cursor: first_page.cursor,
+                results: 10,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(second_page.results.len(), 1);
+    assert_eq!(second_page.results[0].owner, owner);
+    assert_eq!(second_page.results[0].amount, 40);
+
+    let supply = client.asset_supply(&asset_id).await.unwrap().unwrap();
+    assert_eq!(supply.holders, 2);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn coin_changes_are_streamed_to_the_owner() {
+    use futures::StreamExt;
+
+    let owner = Address::default();
+    let asset_id = AssetId::BASE;
+    let srv = node_with_coin(owner, asset_id, false).await;
+    let client = FuelClient::from(srv.bound_address);
+
+    let changes = client.subscribe_coin_changes(&owner).await.unwrap();
+    transfer(&client, owner, Address::new([1u8; 32]), asset_id).await;
+    let changes: Vec<_> =
+        tokio::time::timeout(Duration::from_secs(10), changes.take(2).collect())
+            .await
+            .expect("The changes should be streamed");
+
+    let changes: Vec<_> = changes
+        .into_iter()
+        .map(|change| {
+            let change = change.unwrap();
+            assert_eq!(change.owner, owner);
+            assert_eq!(change.block_height, 1);
+            (change.kind, change.amount)
+        })
+        .collect();
+    // The recipient's coin isn't streamed to the owner.
+    assert_eq!(
+        changes,
+        vec![(CoinEventKind::Spent, 100), (CoinEventKind::Created, 70)]
+    );
+}
+
 #[tokio::test]
 async fn first_5_balances() {
     let owner = Address::from([10u8; 32]);
@@ -1,4 +1,8 @@
 use fuel_core::{
+    chain_config::{
+        CoinConfig,
+        StateConfig,
+    },
     database::Database,
     service::{
         Config,
@@ -10,7 +14,11 @@ use fuel_core_client::client::{
         PageDirection,
         PaginationRequest,
     },
-    types::TransactionStatus,
+    types::{
+        BalanceDelta,
+        BalanceOwner,
+        TransactionStatus,
+    },
     FuelClient,
 };
 use fuel_core_poa::Trigger;
@@ -26,6 +34,10 @@ use fuel_core_types::{
         block::CompressedBlock,
         consensus::Consensus,
     },
+    fuel_asm::{
+        op,
+        RegId,
+    },
     fuel_tx::*,
     fuel_types::ChainId,
     secrecy::ExposeSecret,
@@ -195,6 +207,217 @@ async fn produce_block_negative() {
     };
 }
 
+#[tokio::test]
+async fn paused_block_production_includes_pending_txs_after_resume() {
+    let mut config = Config::local_node();
+    config.production_admin_enabled = true;
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    assert!(client.pause_block_production().await.unwrap());
+
+    // The pool keeps accepting transactions while the production is paused
+    let tx = Transaction::default_test_tx();
+    let tx_id = tx.id(&ChainId::default());
+    client.submit(&tx).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    assert!(matches!(
+        client.transaction_status(&tx_id).await.unwrap(),
+        TransactionStatus::Submitted { .. }
+    ));
+
+    assert!(client.resume_block_production().await.unwrap());
+    let status = tokio::time::timeout(
+        Duration::from_secs(10),
+        client.await_transaction_commit(&tx_id),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+}
+
+#[tokio::test]
+async fn pause_block_production_negative() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let result = client.pause_block_production().await;
+
+    assert_eq!(
+        "Response errors; Production administration must be enabled to use this endpoint",
+        result.err().unwrap().to_string()
+    );
+}
+
+#[tokio::test]
+async fn dry_run_block_reports_included_and_excluded_txs() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    config.production_admin_enabled = true;
+    // Only one of the transactions fits into the block
+    config.chain_conf.block_gas_limit = 15_000;
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let mut tx_ids = vec![];
+    for gas_price in 1..=2 {
+        let tx = TransactionBuilder::script(
+            op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+            vec![],
+        )
+        .gas_limit(10_000)
+        .gas_price(gas_price)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+        tx_ids.push(client.submit(&tx).await.unwrap());
+    }
+
+    let dry_run = client.dry_run_block().await.unwrap();
+
+    assert_eq!(dry_run.header.height, 1);
+    assert!(dry_run.transactions.contains(&tx_ids[1]));
+    assert!(!dry_run.transactions.contains(&tx_ids[0]));
+    assert!(dry_run.gas_used > 0);
+    assert!(dry_run
+        .excluded_transactions
+        .iter()
+        .any(|(id, _)| *id == tx_ids[0]));
+    // Nothing is committed, and the transactions stay in the pool
+    let chain = client.chain_info().await.unwrap();
+    assert_eq!(chain.latest_block.header.height, 0);
+    assert_eq!(client.tx_pool_info().await.unwrap().pending, 2);
+}
+
+#[tokio::test]
+async fn dry_run_block_negative() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let result = client.dry_run_block().await;
+
+    assert_eq!(
+        "Response errors; Production administration must be enabled to use this endpoint",
+        result.err().unwrap().to_string()
+    );
+}
+
+#[tokio::test]
+async fn state_diff_of_the_block_is_served_and_written_to_the_sink() {
+    let owner = Address::default();
+    let recipient = Address::new([1u8; 32]);
+    let asset_id = AssetId::BASE;
+    let sink = tempfile::NamedTempFile::new().unwrap();
+    let mut config = Config::local_node();
+    config.block_importer.state_diff = true;
+    config.state_diff_sink = Some(sink.path().to_path_buf());
+    config.chain_conf.initial_state = Some(StateConfig {
+        coins: Some(vec![CoinConfig {
+            tx_id: None,
+            output_index: None,
+            tx_pointer_block_height: None,
+            tx_pointer_tx_idx: None,
+            maturity: None,
+            owner,
+            amount: 100,
+            asset_id,
+        }]),
+        ..Default::default()
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let coin = client
+        .coins(
+            &owner,
+            Some(&asset_id),
+            PaginationRequest {
+                cursor: None,
+                results: 1,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap()
+        .results
+        .remove(0);
+
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(1_000_000)
+        .add_input(Input::coin_signed(
+            coin.utxo_id,
+            coin.owner,
+            coin.amount,
+            coin.asset_id,
+            Default::default(),
+            0,
+            coin.maturity.into(),
+        ))
+        .add_output(Output::Coin {
+            to: recipient,
+            amount: 30,
+            asset_id,
+        })
+        .add_output(Output::Change {
+            to: owner,
+            amount: 0,
+            asset_id,
+        })
+        .add_witness(Default::default())
+        .finalize_as_transaction();
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    let state_diff = client
+        .state_diff(1)
+        .await
+        .unwrap()
+        .expect("The diff is stored");
+    assert_eq!(state_diff.spent_coins, vec![coin]);
+    let mut created: Vec<_> = state_diff
+        .created_coins
+        .iter()
+        .map(|coin| (coin.owner, coin.amount))
+        .collect();
+    created.sort();
+    assert_eq!(created, vec![(owner, 70), (recipient, 30)]);
+    assert_eq!(
+        state_diff.balance_deltas,
+        vec![
+            BalanceDelta {
+                owner: BalanceOwner::Address(owner),
+                asset_id,
+                credit: 70,
+                debit: 100,
+            },
+            BalanceDelta {
+                owner: BalanceOwner::Address(recipient),
+                asset_id,
+                credit: 30,
+                debit: 0,
+            },
+        ]
+    );
+
+    // The sink writes the diff after the block is committed.
+    let mut lines = String::new();
+    for _ in 0..50 {
+        lines = std::fs::read_to_string(sink.path()).unwrap();
+        if !lines.is_empty() {
+            break
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    let record: serde_json::Value = serde_json::from_str(lines.trim()).unwrap();
+    assert_eq!(record["height"], 1);
+}
+
 #[tokio::test]
 async fn produce_block_custom_time() {
     let db = Database::default();
@@ -416,3 +639,28 @@ mod full_block {
         assert_eq!(block.transactions.len(), 2 /* mint + our tx */);
     }
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn subscribe_blocks_yields_imported_headers() {
+    use futures::StreamExt;
+
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Interval {
+        block_time: Duration::from_millis(100),
+    };
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let headers = client.subscribe_blocks().await.unwrap();
+    let headers: Vec<_> =
+        tokio::time::timeout(Duration::from_secs(10), headers.take(2).collect())
+            .await
+            .expect("The blocks should be imported");
+
+    let heights: Vec<_> = headers
+        .into_iter()
+        .map(|header| header.unwrap().height)
+        .collect();
+    assert_eq!(heights.len(), 2);
+    assert_eq!(heights[1], heights[0] + 1);
+}
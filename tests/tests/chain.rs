@@ -3,6 +3,9 @@ use fuel_core::service::{
     FuelService,
 };
 use fuel_core_client::client::FuelClient;
+use fuel_core_poa::Trigger;
+use fuel_core_types::tai64::Tai64;
+use std::time::Duration;
 
 #[tokio::test]
 async fn chain_info() {
@@ -23,3 +26,39 @@ async fn chain_info() {
         chain_info.consensus_parameters.gas_costs.into()
     );
 }
+
+#[tokio::test]
+async fn chain_stats_follow_produced_blocks() {
+    let mut node_config = Config::local_node();
+    node_config.manual_blocks_enabled = true;
+    node_config.block_production = Trigger::Interval {
+        block_time: Duration::from_secs(10),
+    };
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    client
+        .produce_blocks(5, Some(Tai64::UNIX_EPOCH.0 + 100))
+        .await
+        .unwrap();
+
+    // The statistics are collected in the background after the import.
+    let mut stats = client.chain_stats(Some(5)).await.unwrap();
+    for _ in 0..100 {
+        if stats.p99_block_interval == 10 && stats.p50_block_interval == 10 {
+            break
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        stats = client.chain_stats(Some(5)).await.unwrap();
+    }
+
+    assert_eq!(stats.blocks, 5);
+    assert_eq!(stats.transactions, 0);
+    assert_eq!(stats.transactions_per_second, 0.0);
+    assert_eq!(stats.average_block_interval, 10.0);
+    assert_eq!(stats.p50_block_interval, 10);
+    assert_eq!(stats.p99_block_interval, 10);
+    assert_eq!(stats.pool_depth, 0);
+
+    assert!(client.chain_stats(Some(0)).await.is_err());
+}
@@ -11,7 +11,10 @@ use fuel_core_client::client::{
         PageDirection,
         PaginationRequest,
     },
-    types::TransactionStatus,
+    types::{
+        Receipts,
+        TransactionStatus,
+    },
     FuelClient,
 };
 use fuel_core_types::{
@@ -23,6 +26,11 @@ use fuel_core_types::{
     },
     fuel_vm::*,
 };
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
 use rstest::rstest;
 
 const SEED: u64 = 2322;
@@ -109,6 +117,63 @@ async fn test_5_contract_balances(
     }
 }
 
+#[tokio::test]
+async fn contract_slots_are_paginated_in_key_order() {
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let (_, contract_id) = test_builder.setup_contract(vec![], None, None, None);
+    test_builder.contracts.get_mut(&contract_id).unwrap().state =
+        Some((1..=5).rev().map(|i| (key(i), key(i * 10))).collect());
+
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    let first_page = client
+        .contract_slots(
+            &contract_id,
+            Some(&key(2)),
+            Some(&key(5)),
+            PaginationRequest {
+                cursor: None,
+                results: 2,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+    let slots: Vec<_> = first_page
+        .results
+        .iter()
+        .map(|slot| (slot.key, slot.value))
+        .collect();
+    assert_eq!(slots, vec![(key(2), key(20)), (key(3), key(30))]);
+    assert!(first_page.has_next_page);
+
+    // The slot with the `end_key` is excluded.
+    let second_page = client
+        .contract_slots(
+            &contract_id,
+            Some(&key(2)),
+            Some(&key(5)),
+            PaginationRequest {
+                cursor: first_page.cursor,
+                results: 2,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+    let slots: Vec<_> = second_page
+        .results
+        .iter()
+        .map(|slot| (slot.key, slot.value))
+        .collect();
+    assert_eq!(slots, vec![(key(4), key(40))]);
+    assert!(!second_page.has_next_page);
+}
+
 fn key(i: u8) -> Bytes32 {
     Bytes32::new(
         [0u8; 31]
@@ -306,3 +371,74 @@ async fn can_get_message_proof() {
     assert_eq!(log[1].rb().unwrap(), 1);
     assert_eq!(logd.data().unwrap(), db_data);
 }
+
+#[tokio::test]
+async fn can_read_logged_data_from_receipts() {
+    let mut rng = StdRng::seed_from_u64(SEED);
+    let mut test_builder = TestSetupBuilder::new(SEED);
+
+    // The contract logs a register and its own id, which is stored at `$fp`.
+    let contract = vec![
+        op::movi(0x10, 0xca),
+        op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::movi(0x11, ContractId::LEN.try_into().unwrap()),
+        op::logd(RegId::ZERO, RegId::ZERO, RegId::FP, 0x11),
+        op::ret(RegId::ONE),
+    ];
+    let (_, contract_id) = test_builder.setup_contract(
+        contract.into_iter().collect::<Vec<u8>>(),
+        None,
+        None,
+        None,
+    );
+
+    let script = vec![
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let secret: SecretKey = rng.gen();
+    let tx = TransactionBuilder::script(
+        script.into_iter().collect(),
+        Call::new(contract_id, 0, 0).to_bytes(),
+    )
+    .gas_limit(1_000_000)
+    .add_unsigned_coin_input(
+        secret,
+        rng.gen(),
+        1000,
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    )
+    .add_input(Input::contract(
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        contract_id,
+    ))
+    .add_output(Output::contract(1, Default::default(), Default::default()))
+    .finalize();
+    test_builder.config_coin_inputs_from_transactions(&[&tx]);
+
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    let tx: Transaction = tx.into();
+    let status = client.submit_and_await_commit(&tx).await.unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+
+    let receipts: Receipts = client
+        .receipts(&tx.id(&ChainId::default()))
+        .await
+        .unwrap()
+        .unwrap()
+        .into();
+    assert_eq!(receipts.logged_values(), vec![[0xca, 0, 0, 0]]);
+    assert_eq!(receipts.logged_data(), vec![contract_id.as_ref()]);
+    assert_eq!(receipts.revert_reason(), None);
+}
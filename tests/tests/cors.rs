@@ -0,0 +1,45 @@
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use reqwest::header::{
+    ACCESS_CONTROL_ALLOW_ORIGIN,
+    ORIGIN,
+};
+
+async fn allowed_origin(srv: &FuelService, origin: &str) -> Option<String> {
+    let response = reqwest::Client::new()
+        .get(format!("http://{}/health", srv.bound_address))
+        .header(ORIGIN, origin)
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_success());
+    response
+        .headers()
+        .get(ACCESS_CONTROL_ALLOW_ORIGIN)
+        .map(|value| value.to_str().unwrap().to_string())
+}
+
+#[tokio::test]
+async fn all_origins_are_allowed_by_default() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+
+    assert_eq!(
+        allowed_origin(&srv, "https://app.example.com").await,
+        Some("*".to_string())
+    );
+}
+
+#[tokio::test]
+async fn only_configured_origins_are_allowed() {
+    let mut config = Config::local_node();
+    config.cors_allowed_origins = vec!["https://app.example.com".to_string()];
+    let srv = FuelService::new_node(config).await.unwrap();
+
+    assert_eq!(
+        allowed_origin(&srv, "https://app.example.com").await,
+        Some("https://app.example.com".to_string())
+    );
+    assert_eq!(allowed_origin(&srv, "https://evil.example.com").await, None);
+}
@@ -0,0 +1,136 @@
+use fuel_core::{
+    producer::GasPriceConfig,
+    service::{
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_client::client::{
+    types::FeeConfidence,
+    FuelClient,
+};
+use fuel_core_poa::Trigger;
+use fuel_core_types::{
+    fuel_asm::*,
+    fuel_tx::{
+        TransactionBuilder,
+        TxParameters,
+    },
+};
+use std::time::Duration;
+
+fn tx(gas_price: u64, gas_limit: u64) -> fuel_core_types::fuel_tx::Transaction {
+    TransactionBuilder::script(
+        op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+        vec![],
+    )
+    .gas_price(gas_price)
+    .gas_limit(gas_limit)
+    .add_random_fee_input()
+    .finalize_as_transaction()
+}
+
+#[tokio::test]
+async fn latest_gas_price_is_static_by_default() {
+    let mut config = Config::local_node();
+    config.txpool.min_gas_price = 7;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let latest = client.latest_gas_price().await.unwrap();
+    let estimate = client.estimate_gas_price(10).await.unwrap();
+
+    assert_eq!(latest.gas_price, 7);
+    assert_eq!(latest.block_height, 0);
+    assert_eq!(estimate.gas_price, 7);
+}
+
+#[tokio::test]
+async fn full_blocks_increase_min_accepted_gas_price() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    config.manual_blocks_enabled = true;
+    config.block_producer.dynamic_gas_price = Some(GasPriceConfig::default());
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    assert_eq!(client.latest_gas_price().await.unwrap().gas_price, 0);
+
+    // The block gas limit fits 10 transactions with the maximum gas limit,
+    // so 6 of them make the block fuller than the 50% target.
+    let max_gas_per_tx = TxParameters::DEFAULT.max_gas_per_tx;
+    for _ in 0..6 {
+        client.submit(&tx(0, max_gas_per_tx)).await.unwrap();
+    }
+    client.produce_blocks(1, None).await.unwrap();
+
+    // The gas price is updated once the committed block is broadcast by the importer
+    let latest = tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            let latest = client.latest_gas_price().await.unwrap();
+            if latest.block_height == 1 && latest.gas_price > 0 {
+                break latest
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("The gas price should be updated by the committed block");
+    assert_eq!(latest.gas_price, 1);
+    assert_eq!(latest.block_height, 1);
+    let estimate = client.estimate_gas_price(10).await.unwrap();
+    assert!(estimate.gas_price > latest.gas_price);
+
+    // The transactions paying less than the new minimum are rejected
+    let result = client.submit(&tx(0, 1000)).await;
+    assert!(result.is_err());
+    client.submit(&tx(1, 1000)).await.unwrap();
+}
+
+#[tokio::test]
+async fn fee_estimate_follows_min_gas_price_without_recent_transactions() {
+    let mut config = Config::local_node();
+    config.txpool.min_gas_price = 7;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let tx = tx(0, TxParameters::DEFAULT.max_gas_per_tx);
+
+    let low = client.estimate_fees(&tx, FeeConfidence::Low).await.unwrap();
+    let high = client
+        .estimate_fees(&tx, FeeConfidence::High)
+        .await
+        .unwrap();
+
+    assert_eq!(low.confidence, FeeConfidence::Low);
+    assert_eq!(low.gas_price, 7);
+    assert_eq!(high.gas_price, 7);
+    assert!(low.gas_used > 0);
+    assert_eq!(low.gas_limit, low.gas_used);
+    assert!(high.gas_limit > high.gas_used);
+}
+
+#[tokio::test]
+async fn fee_estimate_follows_gas_prices_of_recent_transactions() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    for gas_price in [10, 20, 30, 40, 50] {
+        client
+            .submit_and_await_commit(&tx(gas_price, 1000))
+            .await
+            .unwrap();
+    }
+    let tx = tx(0, 1000);
+
+    let low = client.estimate_fees(&tx, FeeConfidence::Low).await.unwrap();
+    let medium = client
+        .estimate_fees(&tx, FeeConfidence::Medium)
+        .await
+        .unwrap();
+    let high = client
+        .estimate_fees(&tx, FeeConfidence::High)
+        .await
+        .unwrap();
+
+    assert_eq!(low.gas_price, 20);
+    assert_eq!(medium.gas_price, 30);
+    assert_eq!(high.gas_price, 40);
+}
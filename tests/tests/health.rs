@@ -5,7 +5,21 @@ use fuel_core::{
         FuelService,
     },
 };
-use fuel_core_client::client::FuelClient;
+use fuel_core_client::client::{
+    retry::RetryPolicy,
+    FuelClient,
+};
+use reqwest::StatusCode;
+use std::time::Duration;
+
+async fn get_json(srv: &FuelService, path: &str) -> (StatusCode, serde_json::Value) {
+    let response = reqwest::get(format!("http://{}{}", srv.bound_address, path))
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = response.text().await.unwrap();
+    (status, serde_json::from_str(&body).unwrap())
+}
 
 #[tokio::test]
 async fn health() {
@@ -18,6 +32,82 @@ async fn health() {
     assert!(health);
 }
 
+#[tokio::test]
+async fn liveness_endpoint_reports_up() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+
+    let (status, body) = get_json(&srv, "/health/live").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["up"], true);
+}
+
+#[tokio::test]
+async fn readiness_endpoint_reports_state_of_node() {
+    let mut config = Config::local_node();
+    config.manual_blocks_enabled = true;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(2, None).await.unwrap();
+
+    let (status, body) = get_json(&srv, "/health/ready").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["ready"], true);
+    assert_eq!(body["database"]["writable"], true);
+    assert_eq!(body["latest_block"]["height"], 2);
+    assert!(body["latest_block"]["time"].is_i64());
+}
+
+#[tokio::test]
+async fn await_healthy_times_out_for_unstarted_node() {
+    // reserve a free port and release it, so nothing listens on it
+    let addr = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let client = FuelClient::from(addr);
+
+    let err = client
+        .await_healthy(Duration::from_millis(500))
+        .await
+        .unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::TimedOut);
+}
+
+#[tokio::test]
+async fn query_is_retried_until_node_starts() {
+    // reserve a free port and release it, so the node starts on it later
+    let addr = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let client = FuelClient::from(addr)
+        .with_retry_policy(RetryPolicy::exponential(10, Duration::from_millis(100)));
+    let node = tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        let mut config = Config::local_node();
+        config.addr = addr;
+        FuelService::new_node(config).await.unwrap()
+    });
+
+    let health = client.health().await.unwrap();
+
+    assert!(health);
+    node.await.unwrap();
+}
+
+#[tokio::test]
+async fn query_is_not_retried_by_default() {
+    let addr = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let client = FuelClient::from(addr);
+
+    assert!(client.health().await.is_err());
+}
+
 #[cfg(feature = "default")]
 #[tokio::test]
 async fn can_restart_node() {
@@ -27,7 +117,7 @@ async fn can_restart_node() {
     // start node once
     {
         use fuel_core::service::ServiceTrait;
-        let database = Database::open(tmp_dir.path(), None).unwrap();
+        let database = Database::open(tmp_dir.path(), None, &Default::default()).unwrap();
         let first_startup = FuelService::from_database(database, Config::local_node())
             .await
             .unwrap();
@@ -35,7 +125,7 @@ async fn can_restart_node() {
     }
 
     {
-        let database = Database::open(tmp_dir.path(), None).unwrap();
+        let database = Database::open(tmp_dir.path(), None, &Default::default()).unwrap();
         let _second_startup = FuelService::from_database(database, Config::local_node())
             .await
             .unwrap();
@@ -1,19 +1,23 @@
 #![deny(unused_must_use)]
 
+mod auth;
 mod balances;
 mod blocks;
 mod chain;
 mod coin;
 mod coins;
 mod contract;
+mod cors;
 mod dap;
 mod debugger;
 mod deployment;
+mod gas_price;
 mod health;
 mod helpers;
 mod messages;
 mod node_info;
 mod poa;
+mod query_limits;
 #[cfg(feature = "relayer")]
 mod relayer;
 mod snapshot;
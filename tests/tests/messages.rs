@@ -29,7 +29,10 @@ use fuel_core_types::{
         Word,
         *,
     },
-    fuel_types::ChainId,
+    fuel_types::{
+        ChainId,
+        MessageId,
+    },
 };
 use rstest::rstest;
 use std::ops::Deref;
@@ -406,6 +409,10 @@ async fn can_get_message_proof() {
             // Check message id is the same as the one passed in.
             assert_eq!(generated_message_id, message_id);
 
+            // The proof is valid against the chain of the node.
+            assert!(result.verify());
+            assert!(client.verify_message_proof(&result).await.unwrap());
+
             // 2. Generate the block id. (full header)
             let mut hasher = Hasher::default();
             hasher.input(result.message_block_header.prev_root.as_ref());
@@ -467,6 +474,38 @@ async fn can_get_message_proof() {
                 blocks_count as u64,
             ));
         }
+
+        // Request the proofs of all messages at once.
+        let messages: Vec<_> = message_ids
+            .iter()
+            .map(|message_id| (transaction_id, *message_id))
+            .chain([(transaction_id, MessageId::zeroed())])
+            .collect();
+        let proofs = client
+            .message_proofs(&messages, None, Some(last_height))
+            .await
+            .unwrap();
+        assert_eq!(proofs.len(), messages.len());
+        // The unknown message can't be proven.
+        assert!(proofs.last().unwrap().is_none());
+        assert!(proofs
+            .iter()
+            .take(message_ids.len())
+            .all(|proof| matches!(proof, Some(proof) if proof.verify())));
+
+        // The messages of the same block share its proof in the batch.
+        let batch = client
+            .message_proof_batch(&messages[..message_ids.len()], None, Some(last_height))
+            .await
+            .unwrap();
+        assert!(batch.verify());
+        assert_eq!(batch.message_blocks.len(), 1);
+        assert_eq!(batch.messages.len(), message_ids.len());
+        assert!(batch.messages.iter().all(|m| m.message_block_index == 0));
+        assert!(client
+            .message_proof_batch(&messages, None, Some(last_height))
+            .await
+            .is_err());
     }
 }
 
@@ -28,3 +28,106 @@ async fn node_info() {
     assert_eq!(max_depth, node_config.txpool.max_depth as u64);
     assert_eq!(max_tx, node_config.txpool.max_tx as u64);
 }
+
+#[tokio::test]
+async fn peers_info_is_empty_without_p2p() {
+    let node_config = Config::local_node();
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let peers = client.connected_peers_info().await.unwrap();
+
+    assert!(peers.is_empty());
+}
+
+#[tokio::test]
+async fn sync_status_is_none_without_p2p() {
+    let node_config = Config::local_node();
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let status = client.sync_status().await.unwrap();
+
+    assert!(status.is_none());
+}
+
+#[tokio::test]
+async fn ban_peer_requires_peer_admin_enabled() {
+    let node_config = Config::local_node();
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let err = client
+        .ban_peer("16Uiu2HAmHmBGRhmSJfwzwLCNyKJXbvRL2ofZhvu7sBSqyYu8p9Ss")
+        .await
+        .expect_err("Peer administration is disabled");
+
+    assert!(err
+        .to_string()
+        .contains("Peer administration must be enabled"));
+}
+
+#[tokio::test]
+async fn compact_database_requires_database_admin_enabled() {
+    let node_config = Config::local_node();
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let err = client
+        .compact_database(None)
+        .await
+        .expect_err("Database administration is disabled");
+
+    assert!(err
+        .to_string()
+        .contains("Database administration must be enabled"));
+}
+
+#[tokio::test]
+async fn compact_database_rejects_unknown_column() {
+    let mut node_config = Config::local_node();
+    node_config.database_admin_enabled = true;
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    assert!(client.compact_database(Some("receipts")).await.unwrap());
+    assert!(client.compact_database(Some("unknown")).await.is_err());
+}
+
+#[cfg(feature = "default")]
+#[tokio::test]
+async fn database_checkpoint_is_opened_separately() {
+    use fuel_core::database::Database;
+    use tempfile::TempDir;
+
+    let tmp_dir = TempDir::new().unwrap();
+    let database = Database::open(tmp_dir.path(), None, &Default::default()).unwrap();
+    let mut node_config = Config::local_node();
+    node_config.manual_blocks_enabled = true;
+    node_config.database_admin_enabled = true;
+    node_config.database_checkpoint_dir = Some(tmp_dir.path().to_path_buf());
+    let srv = FuelService::from_database(database, node_config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(2, None).await.unwrap();
+    let checkpoint_dir = tmp_dir.path().join("checkpoint");
+
+    assert!(client
+        .create_database_checkpoint(checkpoint_dir.to_str().unwrap())
+        .await
+        .unwrap());
+    // The checkpoint can't overwrite the existing directory
+    assert!(client
+        .create_database_checkpoint(checkpoint_dir.to_str().unwrap())
+        .await
+        .is_err());
+    // The checkpoint can't be created outside of the checkpoint directory
+    assert!(client
+        .create_database_checkpoint("../outside")
+        .await
+        .is_err());
+
+    let checkpoint = Database::open(&checkpoint_dir, None, &Default::default()).unwrap();
+    assert_eq!(checkpoint.latest_height().unwrap(), 2u32.into());
+}
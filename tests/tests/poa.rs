@@ -1,6 +1,7 @@
 use fuel_core::{
     database::Database,
     service::{
+        config::RemoteSigner,
         Config,
         FuelService,
     },
@@ -14,15 +15,40 @@ use fuel_core_types::{
         consensus::Consensus,
         primitives::BlockId,
     },
-    fuel_crypto::SecretKey,
-    fuel_tx::Transaction,
+    fuel_crypto::{
+        SecretKey,
+        Signature,
+    },
+    fuel_tx::{
+        Input,
+        Transaction,
+    },
+    fuel_types::Bytes64,
     secrecy::Secret,
 };
+use hyper::{
+    service::{
+        make_service_fn,
+        service_fn,
+    },
+    Body,
+    Request,
+    Response,
+    Server,
+};
 use rand::{
     rngs::StdRng,
     SeedableRng,
 };
-use std::str::FromStr;
+use serde_json::json;
+use std::{
+    convert::Infallible,
+    net::{
+        Ipv4Addr,
+        SocketAddr,
+    },
+    str::FromStr,
+};
 
 #[tokio::test]
 async fn can_get_sealed_block_from_poa_produced_block() {
@@ -85,6 +111,69 @@ async fn can_get_sealed_block_from_poa_produced_block() {
         .expect("failed to verify signature");
 }
 
+#[tokio::test]
+async fn can_produce_blocks_with_remote_signer() {
+    let mut rng = StdRng::seed_from_u64(11);
+    let poa_secret = SecretKey::random(&mut rng);
+    let poa_public = poa_secret.public_key();
+
+    // The signing sidecar holds the key and signs the ids of the blocks.
+    let make_service = make_service_fn(move |_| async move {
+        Ok::<_, Infallible>(service_fn(move |req: Request<Body>| async move {
+            let body = hyper::body::to_bytes(req).await.unwrap();
+            let request: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            assert_eq!(request["method"], "sign");
+            let block_id =
+                BlockId::from_str(request["params"][0].as_str().unwrap()).unwrap();
+            let signature = Signature::sign(&poa_secret, &block_id.into_message());
+            let response = json!({
+                "id": request["id"],
+                "jsonrpc": "2.0",
+                "result": format!("{:#x}", Bytes64::new(*signature)),
+            });
+            Ok::<_, Infallible>(Response::new(Body::from(response.to_string())))
+        }))
+    });
+    let server =
+        Server::bind(&SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0)).serve(make_service);
+    let signer_url = format!("http://{}", server.local_addr());
+    tokio::spawn(server);
+
+    let db = Database::default();
+    let mut config = Config::local_node();
+    config.consensus_key = None;
+    config.remote_signer = Some(RemoteSigner::new(signer_url, Input::owner(&poa_public)));
+    let srv = FuelService::from_database(db.clone(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let status = client
+        .submit_and_await_commit(&Transaction::default_test_tx())
+        .await
+        .unwrap();
+    let block_id = match status {
+        TransactionStatus::Success { block_id, .. } => block_id,
+        _ => {
+            panic!("unexpected result")
+        }
+    };
+    let block_id = BlockId::from_str(&block_id).unwrap();
+
+    // verify the signature of the remote signer
+    let sealed_block_header = db
+        .get_sealed_block_header(&block_id)
+        .unwrap()
+        .expect("expected sealed header to be available");
+    let signature = match sealed_block_header.consensus {
+        Consensus::PoA(poa) => poa.signature,
+        _ => panic!("Not expected consensus"),
+    };
+    signature
+        .verify(&poa_public, &block_id.into_message())
+        .expect("failed to verify signature");
+}
+
 #[cfg(feature = "p2p")]
 mod p2p {
     use super::*;
@@ -101,7 +190,6 @@ mod p2p {
         service::ServiceTrait,
     };
     use fuel_core_poa::Trigger;
-    use fuel_core_types::fuel_tx::Input;
     use std::time::Duration;
 
     // Starts first_producer which creates some blocks
@@ -0,0 +1,50 @@
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use serde_json::{
+    json,
+    Value,
+};
+
+async fn execute(srv: &FuelService, query: &str) -> Value {
+    reqwest::Client::new()
+        .post(format!("http://{}/graphql", srv.bound_address))
+        .json(&json!({ "query": query }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap()
+}
+
+fn error_message(response: &Value) -> Option<&str> {
+    response["errors"][0]["message"].as_str()
+}
+
+#[tokio::test]
+async fn too_deep_query_is_rejected() {
+    let mut config = Config::local_node();
+    config.query_limits.max_depth = 3;
+    let srv = FuelService::new_node(config).await.unwrap();
+
+    let shallow = execute(&srv, "{ chain { latestBlock { id } } }").await;
+    assert_eq!(error_message(&shallow), None);
+
+    let deep = execute(&srv, "{ chain { latestBlock { header { height } } } }").await;
+    assert_eq!(error_message(&deep), Some("Query is nested too deep."));
+}
+
+#[tokio::test]
+async fn connection_complexity_scales_with_the_page_size() {
+    let mut config = Config::local_node();
+    config.query_limits.max_complexity = 100;
+    let srv = FuelService::new_node(config).await.unwrap();
+
+    let small_page = execute(&srv, "{ blocks(first: 10) { nodes { id } } }").await;
+    assert_eq!(error_message(&small_page), None);
+
+    let large_page = execute(&srv, "{ blocks(first: 1000) { nodes { id } } }").await;
+    assert_eq!(error_message(&large_page), Some("Query is too complex."));
+}
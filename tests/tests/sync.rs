@@ -1,4 +1,5 @@
 use fuel_core::p2p_test_helpers::*;
+use fuel_core_client::client::FuelClient;
 use fuel_core_types::{
     fuel_crypto::SecretKey,
     fuel_tx::Input,
@@ -18,6 +19,7 @@ use std::{
         Hash,
         Hasher,
     },
+    time::Duration,
 };
 use test_case::test_case;
 
@@ -60,6 +62,101 @@ async fn test_producer_getting_own_blocks_back() {
     validator.consistency_10s(&expected).await;
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_sync_status_reports_progress() {
+    let mut rng = StdRng::seed_from_u64(line!() as u64);
+
+    let secret = SecretKey::random(&mut rng);
+    let pub_key = Input::owner(&secret.public_key());
+    let Nodes {
+        mut producers,
+        mut validators,
+        bootstrap_nodes: _dont_drop,
+    } = make_nodes(
+        [Some(BootstrapSetup::new(pub_key))],
+        [Some(
+            ProducerSetup::new(secret).with_txs(1).with_name("Alice"),
+        )],
+        [Some(ValidatorSetup::new(pub_key).with_name("Bob"))],
+    )
+    .await;
+
+    let mut producer = producers.pop().unwrap();
+    let mut validator = validators.pop().unwrap();
+    let sync = validator.node.shared.sync.clone().expect("P2P is enabled");
+    let mut status_updates = sync.subscribe();
+
+    let expected = producer.insert_txs().await;
+    producer.consistency_10s(&expected).await;
+    validator.consistency_10s(&expected).await;
+
+    // The validator broadcasts the status changes while syncing.
+    let update = status_updates.recv().await.unwrap();
+    assert!(update.highest_seen_height > 0u32.into());
+
+    let client = FuelClient::from(validator.node.bound_address);
+    let status = client
+        .sync_status()
+        .await
+        .unwrap()
+        .expect("The sync is enabled");
+    let height = validator.node.shared.database.latest_height().unwrap();
+    assert_eq!(status.current_height, *height);
+    assert!(status.is_synced());
+    assert_eq!(status.estimated_time_remaining, Some(Duration::ZERO));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_light_client_syncs_only_headers() {
+    let mut rng = StdRng::seed_from_u64(line!() as u64);
+
+    let secret = SecretKey::random(&mut rng);
+    let pub_key = Input::owner(&secret.public_key());
+    let Nodes {
+        mut producers,
+        mut validators,
+        bootstrap_nodes: _dont_drop,
+    } = make_nodes(
+        [Some(BootstrapSetup::new(pub_key))],
+        [Some(
+            ProducerSetup::new(secret).with_txs(1).with_name("Alice"),
+        )],
+        [Some(
+            ValidatorSetup::new(pub_key)
+                .with_name("Bob")
+                .with_light_client(),
+        )],
+    )
+    .await;
+
+    let mut producer = producers.pop().unwrap();
+    let validator = validators.pop().unwrap();
+
+    let expected = producer.insert_txs().await;
+    producer.consistency_10s(&expected).await;
+    let height = producer.node.shared.database.latest_height().unwrap();
+
+    // Wait up to 10 seconds for the light client to sync the headers.
+    validator.wait_for_height_10s(height).await;
+
+    let producer_client = FuelClient::from(producer.node.bound_address);
+    let light_client = FuelClient::from(validator.node.bound_address);
+    for height in 1..=*height {
+        let block = producer_client
+            .block_by_height(height as u64)
+            .await
+            .unwrap()
+            .unwrap();
+        let header = light_client
+            .block_by_height(height as u64)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(header.id, block.id);
+        assert!(header.transactions.is_empty());
+    }
+}
+
 #[test_case(1)]
 #[test_case(10)]
 #[test_case(100)]
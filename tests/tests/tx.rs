@@ -25,10 +25,18 @@ use fuel_core_types::{
             PartialBlockHeader,
         },
     },
+    entities::coins::coin::Coin,
     fuel_asm::*,
-    fuel_tx::*,
+    fuel_crypto::SecretKey,
+    fuel_tx::{
+        field::Inputs,
+        *,
+    },
     fuel_types::ChainId,
-    services::executor::ExecutionBlock,
+    services::executor::{
+        ExecutionBlock,
+        StateOverrides,
+    },
     tai64::Tai64,
 };
 use itertools::Itertools;
@@ -142,6 +150,129 @@ async fn dry_run_create() {
     assert_eq!(err.kind(), NotFound);
 }
 
+#[tokio::test]
+async fn dry_run_with_overrides_spends_injected_coin() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut config = Config::local_node();
+    config.utxo_validation = true;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = TransactionBuilder::script(
+        vec![op::ret(RegId::ONE)].into_iter().collect(),
+        vec![],
+    )
+    .gas_limit(1_000_000)
+    .add_unsigned_coin_input(
+        SecretKey::random(&mut rng),
+        rng.gen(),
+        1000,
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    )
+    .add_output(Output::change(rng.gen(), 0, Default::default()))
+    .finalize();
+    let input = tx.inputs()[0].clone();
+    let tx = tx.into();
+
+    // The coin doesn't exist on the chain
+    let result = client.dry_run_opt(&tx, Some(true)).await;
+    assert!(result.is_err());
+
+    let overrides = StateOverrides {
+        coins: vec![Coin {
+            utxo_id: *input.utxo_id().unwrap(),
+            owner: *input.input_owner().unwrap(),
+            amount: 1000,
+            asset_id: Default::default(),
+            maturity: Default::default(),
+            tx_pointer: Default::default(),
+        }],
+        ..Default::default()
+    };
+    let receipts = client
+        .dry_run_with_overrides(&tx, Some(true), overrides)
+        .await
+        .unwrap();
+    assert!(matches!(receipts[0], Receipt::Return { val: 1, .. }));
+
+    // The injected coin isn't persisted
+    let coin = client.coin(input.utxo_id().unwrap()).await.unwrap();
+    assert!(coin.is_none());
+}
+
+#[tokio::test]
+async fn trace_transaction_returns_executed_opcodes() {
+    let mut config = Config::local_node();
+    config.vm.tracing = true;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let script = vec![
+        op::movi(0x10, 0xca),
+        op::movi(0x11, 0xba),
+        op::log(0x10, 0x11, RegId::ZERO, RegId::ZERO),
+        op::div(0x12, 0x10, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+
+    let trace = client.trace_transaction(&tx, None, true).await.unwrap();
+
+    let opcodes: Vec<_> = trace
+        .opcodes
+        .unwrap()
+        .into_iter()
+        .map(|instruction| instruction.opcode.unwrap())
+        .collect();
+    assert_eq!(opcodes, vec!["MOVI", "MOVI", "LOG", "DIV"]);
+    assert_eq!(
+        trace.opcode_counts,
+        vec![
+            ("DIV".to_string(), 1),
+            ("LOG".to_string(), 1),
+            ("MOVI".to_string(), 2)
+        ]
+    );
+    assert!(trace.frames.is_empty());
+    let registers = trace.panic_registers.expect("The script panics");
+    assert_eq!(registers[0x10], 0xca);
+    assert_eq!(registers[0x11], 0xba);
+    assert!(matches!(
+        trace.receipts.revert_reason(),
+        Some(fuel_core_client::client::types::RevertReason::Panic(_))
+    ));
+
+    // ensure the tx isn't available in the blockchain history
+    let err = client
+        .transaction_status(&tx.id(&Default::default()))
+        .await
+        .unwrap_err();
+    assert_eq!(err.kind(), NotFound);
+}
+
+#[tokio::test]
+async fn trace_transaction_requires_vm_tracing() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = TransactionBuilder::script(
+        vec![op::ret(RegId::ONE)].into_iter().collect(),
+        vec![],
+    )
+    .gas_limit(1_000_000)
+    .add_random_fee_input()
+    .finalize_as_transaction();
+
+    let result = client.trace_transaction(&tx, None, false).await;
+
+    assert!(result.is_err());
+}
+
 #[tokio::test]
 async fn submit() {
     let srv = FuelService::new_node(Config::local_node()).await.unwrap();
@@ -694,6 +825,7 @@ fn get_executor_and_db() -> (Executor<MaybeRelayerAdapter>, Database) {
         relayer,
         database: db.clone(),
         config: Default::default(),
+        cache: Default::default(),
     };
 
     (executor, db)
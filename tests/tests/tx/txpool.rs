@@ -4,11 +4,30 @@ use crate::helpers::{
     TestContext,
     TestSetupBuilder,
 };
+use fuel_core::{
+    producer::TxSelection,
+    service::{
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_client::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    types::TxPoolOwnerInfo,
+    FuelClient,
+};
+use fuel_core_poa::Trigger;
 use fuel_core_types::{
     fuel_asm::*,
     fuel_crypto::*,
     fuel_tx,
-    fuel_tx::*,
+    fuel_tx::{
+        field::Inputs,
+        *,
+    },
 };
 use itertools::Itertools;
 use rand::{
@@ -75,3 +94,158 @@ async fn txs_max_gas_limit() {
         transactions.len() + 1 // coinbase
     )
 }
+
+#[tokio::test]
+async fn txpool_content_is_visible_via_graphql() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let transactions = (1..=3)
+        .map(|i| {
+            TransactionBuilder::script(
+                op::movi(0x10, i).to_bytes().into_iter().collect(),
+                vec![],
+            )
+            .gas_limit(1000 * i as u64)
+            .add_random_fee_input()
+            .finalize()
+        })
+        .collect_vec();
+    for tx in &transactions {
+        client.submit(&tx.clone().into()).await.unwrap();
+    }
+
+    let info = client.tx_pool_info().await.unwrap();
+    assert_eq!(info.pending, 3);
+    assert_eq!(info.total_gas, 1000 + 2000 + 3000);
+    let mut expected_owners = transactions
+        .iter()
+        .flat_map(|tx| tx.inputs().iter().filter_map(|input| input.input_owner()))
+        .map(|owner| TxPoolOwnerInfo {
+            owner: *owner,
+            pending: 1,
+        })
+        .collect_vec();
+    expected_owners.sort_by_key(|info| info.owner);
+    assert_eq!(info.owners, expected_owners);
+
+    let first_page = client
+        .tx_pool_transactions(PaginationRequest {
+            cursor: None,
+            results: 2,
+            direction: PageDirection::Forward,
+        })
+        .await
+        .unwrap();
+    assert_eq!(first_page.results.len(), 2);
+    assert!(first_page.has_next_page);
+    let second_page = client
+        .tx_pool_transactions(PaginationRequest {
+            cursor: first_page.cursor,
+            results: 2,
+            direction: PageDirection::Forward,
+        })
+        .await
+        .unwrap();
+    assert_eq!(second_page.results.len(), 1);
+    assert!(!second_page.has_next_page);
+
+    let mut pending = first_page
+        .results
+        .into_iter()
+        .chain(second_page.results)
+        .map(|response| response.transaction)
+        .collect_vec();
+    let mut expected = transactions
+        .into_iter()
+        .map(fuel_tx::Transaction::from)
+        .collect_vec();
+    pending.sort_by_key(|tx| tx.id(&Default::default()));
+    expected.sort_by_key(|tx| tx.id(&Default::default()));
+    assert_eq!(pending, expected);
+}
+
+#[tokio::test]
+async fn submit_batch_inserts_dependent_txs_in_order() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let secret = SecretKey::random(&mut rng);
+    let owner = Input::owner(&secret.public_key());
+    let amount = 1000;
+    let parent: fuel_tx::Transaction = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(1000)
+        .add_random_fee_input()
+        .add_output(Output::coin(owner, amount, AssetId::BASE))
+        .finalize()
+        .into();
+    let parent_id = parent.id(&Default::default());
+    let child: fuel_tx::Transaction = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(1000)
+        .add_unsigned_coin_input(
+            secret,
+            UtxoId::new(parent_id, 0),
+            amount,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+        )
+        .finalize()
+        .into();
+    let child_id = child.id(&Default::default());
+
+    // the child precedes its parent, so the whole batch is rejected
+    client
+        .submit_batch(&[child.clone(), parent.clone()])
+        .await
+        .expect_err("The batch should be rejected");
+    assert_eq!(client.tx_pool_info().await.unwrap().pending, 0);
+
+    let results = client.submit_batch(&[parent, child]).await.unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].id, parent_id);
+    assert_eq!(results[0].error, None);
+    assert_eq!(results[1].id, child_id);
+    assert_eq!(results[1].error, None);
+    assert_eq!(client.tx_pool_info().await.unwrap().pending, 2);
+}
+
+#[tokio::test]
+async fn oldest_first_selection_includes_txs_in_submission_order() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    config.manual_blocks_enabled = true;
+    config.block_producer.tx_selection = TxSelection::OldestFirst;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // The later transactions pay more, so the gas price ordering would reverse them
+    let mut tx_ids = vec![];
+    for gas_price in 1..=3 {
+        let tx = TransactionBuilder::script(
+            op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+            vec![],
+        )
+        .gas_limit(10_000)
+        .gas_price(gas_price)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+        tx_ids.push(client.submit(&tx).await.unwrap());
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    client.produce_blocks(1, None).await.unwrap();
+
+    let block = client.block_by_height(1).await.unwrap().unwrap();
+    let included = block
+        .transactions
+        .into_iter()
+        .filter(|id| tx_ids.contains(id))
+        .collect_vec();
+    assert_eq!(included, tx_ids);
+}
@@ -131,7 +131,7 @@ async fn submit_utxo_verified_tx_below_min_gas_price_fails() {
         .err()
         .unwrap()
         .to_string()
-        .contains("The gas price is too low"));
+        .contains("The gas price 1 is lower than the minimum 10"));
 }
 
 // verify that dry run can disable utxo_validation by simulating a transaction with unsigned